@@ -1,9 +1,20 @@
 //! Build-time utilities for [eq_ui](https://crates.io/crates/eq_ui).
 //!
 //! This crate is intended for use as a **build dependency** (`[build-dependencies]`).
-//! It has zero dependencies and provides a single function that scans a folder of
-//! SVG files and generates a Rust source file with `pub const` path data entries,
-//! ready for use with `EqIcon { path: ... }`.
+//! It has zero dependencies and scans a folder of SVG files to generate a Rust
+//! source file with one `pub const` per icon:
+//!
+//! - [`generate_icon_paths`] - a combined path data `&str` for
+//!   `EqIcon { path: ... }`. Best for single-colour filled icons drawn on
+//!   eq_ui's default `0 0 256 256` grid.
+//! - [`generate_icon_data`] - a structured `IconData` for
+//!   `EqIcon { icon: ... }` that keeps the icon's own `viewBox`, every
+//!   element and its styling (stroke, `fill-rule`, transforms).
+//! - [`generate_icon_sprite`] - the `IconData` constants plus a single
+//!   SVG sprite of `<symbol>`s and a typed `enum Icon` listing every file.
+//!
+//! Each function has a `_with` variant taking [`IconOptions`] to round
+//! path data and switch it to relative commands.
 //!
 //! # Setup
 //!
@@ -37,36 +48,170 @@
 //! include!(concat!(env!("OUT_DIR"), "/my_icon_paths.rs"));
 //! ```
 //!
+//! For structured icons, call [`generate_icon_data`] instead and bring the
+//! types into scope before the `include!`:
+//!
+//! ```ignore
+//! use eq_ui::atoms::{IconData, IconPath, IconStyle};
+//! include!(concat!(env!("OUT_DIR"), "/my_icons.rs"));
+//!
+//! rsx! { EqIcon { icon: CLOCK } }
+//! ```
+//!
+//! # Icon enum and sprite
+//!
+//! [`generate_icon_sprite`] adds an `Icon` enum on top of the `IconData`
+//! constants, so icon names are checked by the compiler and can be
+//! listed:
+//!
+//! ```ignore
+//! // build.rs
+//! eq_ui_build::generate_icon_sprite_with(
+//!     "assets/icons",
+//!     "icons.rs",
+//!     &eq_ui_build::IconOptions::new().precision(2).relative(),
+//! );
+//!
+//! // src/icons.rs
+//! use eq_ui::atoms::{IconData, IconPath, IconStyle};
+//! include!(concat!(env!("OUT_DIR"), "/icons.rs"));
+//!
+//! rsx! {
+//!     EqIcon { icon: Icon::Star }
+//!     for icon in Icon::ALL {
+//!         EqIcon { icon: *icon, aria_label: icon.name() }
+//!     }
+//! }
+//! ```
+//!
+//! `Icon` has `name()` (the file name), `path()`, `view_box()`, `data()`
+//! and `id()`, and converts into `IconData`. The same file holds
+//! `ICON_SPRITE`, an `<svg>` with one `<symbol id="icon-…">` per file:
+//! mount it once and draw icons by reference with
+//! `EqIcon { symbol: Icon::Star.id() }`, so each icon's markup is in the
+//! page only once.
+//!
 //! # How it works
 //!
-//! The function reads every `.svg` file in the given folder, extracts the `d`
-//! attribute from the first `<path>` element, and writes a `pub const` entry
-//! for each one. The constant name is derived from the file name:
+//! The generator reads every `.svg` file in the given folder and parses it:
+//! `<path>`, `<circle>`, `<ellipse>`, `<rect>` (including rounded corners),
+//! `<line>`, `<polyline>` and `<polygon>` are converted to path data, `<g>`
+//! groups pass their presentation attributes and transforms down to their
+//! children, and non-rendered content (`<defs>`, `<clipPath>`, `<title>`, …)
+//! is skipped. It then writes a `pub const` entry for each file. The constant
+//! name is derived from the file name:
 //!
-//! | SVG file | Constant |
-//! |---|---|
-//! | `arrow-right.svg` | `ARROW_RIGHT` |
-//! | `caret-up-down.svg` | `CARET_UP_DOWN` |
-//! | `star.svg` | `STAR` |
-//! | `my_custom_icon.svg` | `MY_CUSTOM_ICON` |
+//! | SVG file | Constant | Enum variant |
+//! |---|---|---|
+//! | `arrow-right.svg` | `ARROW_RIGHT` | `Icon::ArrowRight` |
+//! | `caret-up-down.svg` | `CARET_UP_DOWN` | `Icon::CaretUpDown` |
+//! | `star.svg` | `STAR` | `Icon::Star` |
+//! | `my_custom_icon.svg` | `MY_CUSTOM_ICON` | `Icon::MyCustomIcon` |
+//! | `24-hours.svg` | `ICON_24_HOURS` | `Icon::Icon24Hours` |
 //!
-//! The naming rule: hyphens and underscores in the file name become underscores,
-//! and the result is uppercased. You can use any file name you like.
+//! The naming rule: any character other than an ASCII letter or digit
+//! becomes a word break (an underscore in constants), constants are
+//! uppercased and variants are PascalCase. Names starting with a digit
+//! get an `ICON_` / `Icon` prefix.
 //!
 //! # Notes
 //!
 //! - Only `.svg` files are processed; other files in the folder are ignored.
-//! - The SVG must contain at least one `<path d="...">` element.
+//! - Files that cannot be read or parsed, contain no shape or produce a
+//!   name already taken are skipped with a `cargo:warning`; the build
+//!   goes on with the remaining icons.
+//! - The combined path of [`generate_icon_paths`] is drawn filled, so
+//!   stroke-based icons (Lucide, Feather, Heroicons outline), per-element
+//!   transforms and viewboxes other than `0 0 256 256` need
+//!   [`generate_icon_data`].
 //! - If the folder does not exist, an empty file is generated (no error).
-//! - The build script reruns automatically when the folder contents change.
+//! - The build script reruns when a file is added to or removed from the
+//!   folder, and when any of the SVG files changes.
+
+mod path;
+mod svg;
 
 use std::env;
 use std::fs;
 use std::path::Path;
 
+/// `viewBox` used when an SVG has neither a `viewBox` nor a size -
+/// the grid `EqIcon` draws path data on.
+const DEFAULT_VIEW_BOX: &str = "0 0 256 256";
+
+/// Settings shared by the `generate_*_with` functions.
+///
+/// The default copies path data as converted from the SVG and names
+/// the generated enum `Icon`.
+///
+/// ```
+/// let options = eq_ui_build::IconOptions::new().precision(2).relative();
+/// # let _ = options;
+/// ```
+#[derive(Clone, Debug)]
+pub struct IconOptions {
+    precision: Option<usize>,
+    relative: bool,
+    enum_name: String,
+    id_prefix: String,
+}
+
+impl Default for IconOptions {
+    fn default() -> Self {
+        Self {
+            precision: None,
+            relative: false,
+            enum_name: "Icon".into(),
+            id_prefix: "icon-".into(),
+        }
+    }
+}
+
+impl IconOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Round every number in the path data to `decimals` places.
+    /// One or two are plenty for icons drawn on a 24 or 256 unit grid.
+    pub fn precision(mut self, decimals: usize) -> Self {
+        self.precision = Some(decimals);
+        self
+    }
+
+    /// Write path data with relative commands (`l`, `h`, `c`, …), which
+    /// are usually shorter than absolute ones.
+    pub fn relative(mut self) -> Self {
+        self.relative = true;
+        self
+    }
+
+    /// Name of the enum written by [`generate_icon_sprite_with`]
+    /// (default `Icon`). The sprite constant follows it: `Icon` →
+    /// `ICON_SPRITE`, `BoldIcon` → `BOLD_ICON_SPRITE`.
+    pub fn enum_name(mut self, name: impl Into<String>) -> Self {
+        self.enum_name = name.into();
+        self
+    }
+
+    /// Prefix of the sprite's `<symbol>` ids (default `icon-`). Give
+    /// each sprite its own prefix when several are mounted on a page.
+    pub fn id_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.id_prefix = prefix.into();
+        self
+    }
+
+    fn optimizes(&self) -> bool {
+        self.precision.is_some() || self.relative
+    }
+}
+
 /// Scan a folder of SVG files and generate a Rust source file with
 /// `pub const` path data entries.
 ///
+/// Every shape of an icon is converted to path data and joined into
+/// one string.
+///
 /// # Arguments
 ///
 /// * `svg_folder` - path to the folder containing `.svg` files,
@@ -77,75 +222,477 @@ use std::path::Path;
 ///
 /// # Panics
 ///
-/// Panics if a `.svg` file is found but does not contain a valid
-/// `<path d="...">` attribute.
+/// Panics if `OUT_DIR` is not set (not called from a build script) or
+/// the output file cannot be written. Invalid SVG files are skipped
+/// with a warning.
 pub fn generate_icon_paths(svg_folder: &str, output_file: &str) {
+    generate_icon_paths_with(svg_folder, output_file, &IconOptions::default());
+}
+
+/// [`generate_icon_paths`] with path optimization.
+pub fn generate_icon_paths_with(svg_folder: &str, output_file: &str, options: &IconOptions) {
+    let icons = load(svg_folder, options);
+    let mut output = String::from(HEADER);
+    for icon in &icons {
+        output.push_str(&format!("pub const {}: &str =\n    {};\n\n", icon.const_name, quote(&icon.path())));
+    }
+    write(output_file, &output);
+}
+
+/// Scan a folder of SVG files and generate a Rust source file with
+/// `pub const` `IconData` entries.
+///
+/// Each constant keeps the icon's `viewBox`, the styling of its root
+/// `<svg>` and one `IconPath` per shape with its own fill, stroke,
+/// `fill-rule`, opacity and transform. The generated code names
+/// `IconData`, `IconPath` and `IconStyle` unqualified, so import them
+/// from `eq_ui::atoms` next to the `include!`.
+///
+/// Arguments are the same as for [`generate_icon_paths`].
+///
+/// # Panics
+///
+/// Same as [`generate_icon_paths`].
+pub fn generate_icon_data(svg_folder: &str, output_file: &str) {
+    generate_icon_data_with(svg_folder, output_file, &IconOptions::default());
+}
+
+/// [`generate_icon_data`] with path optimization.
+pub fn generate_icon_data_with(svg_folder: &str, output_file: &str, options: &IconOptions) {
+    let icons = load(svg_folder, options);
+    let mut output = String::from(HEADER);
+    for icon in &icons {
+        output.push_str(&icon_data_const(&icon.const_name, &icon.svg));
+    }
+    write(output_file, &output);
+}
+
+/// Scan a folder of SVG files and generate a Rust source file with the
+/// `IconData` constants of [`generate_icon_data`], an `ICON_SPRITE`
+/// holding every icon as an SVG `<symbol>`, and an `Icon` enum with one
+/// variant per file.
+///
+/// Arguments are the same as for [`generate_icon_paths`]; the
+/// imports needed are those of [`generate_icon_data`].
+///
+/// # Panics
+///
+/// Same as [`generate_icon_paths`].
+pub fn generate_icon_sprite(svg_folder: &str, output_file: &str) {
+    generate_icon_sprite_with(svg_folder, output_file, &IconOptions::default());
+}
+
+/// [`generate_icon_sprite`] with path optimization and custom enum
+/// and symbol names.
+pub fn generate_icon_sprite_with(svg_folder: &str, output_file: &str, options: &IconOptions) {
+    let icons = load(svg_folder, options);
+    let mut output = String::from(HEADER);
+    output.push_str(&sprite_const(&icons, options));
+    for icon in &icons {
+        output.push_str(&icon_data_const(&icon.const_name, &icon.svg));
+    }
+    output.push_str(&icon_enum(&icons, svg_folder, options));
+    write(output_file, &output);
+}
+
+const HEADER: &str = "// Auto-generated by eq_ui_build - do not edit manually.\n\n";
+
+/// A parsed SVG file with the names generated for it.
+struct Icon {
+    /// File name without `.svg`.
+    stem: String,
+    const_name: String,
+    variant: String,
+    svg: svg::ParsedSvg,
+}
+
+impl Icon {
+    /// Every shape's path data, joined.
+    fn path(&self) -> String {
+        let d: Vec<&str> = self.svg.shapes.iter().map(|s| s.d.as_str()).collect();
+        d.join(" ")
+    }
+
+    fn view_box(&self) -> &str {
+        self.svg.view_box.as_deref().unwrap_or(DEFAULT_VIEW_BOX)
+    }
+}
+
+/// Report a problem without failing the build.
+fn warn(message: &str) {
+    println!("cargo:warning=eq_ui_build: {}", message);
+}
+
+/// Parse every SVG file in `svg_folder`, sorted by file name, skipping
+/// (with a warning) the ones that cannot be used.
+fn load(svg_folder: &str, options: &IconOptions) -> Vec<Icon> {
+    // Rerun when files are added or removed...
+    println!("cargo:rerun-if-changed={}", svg_folder);
+
     let icons_dir = Path::new(svg_folder);
+    if !icons_dir.exists() {
+        return Vec::new();
+    }
+    let mut entries: Vec<_> = match fs::read_dir(icons_dir) {
+        Ok(dir) => dir
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.extension().map(|ext| ext == "svg").unwrap_or(false))
+            .collect(),
+        Err(e) => {
+            warn(&format!("failed to read folder {}: {}", svg_folder, e));
+            return Vec::new();
+        }
+    };
+
+    // Sort for deterministic output.
+    entries.sort();
+
+    let mut icons: Vec<Icon> = Vec::new();
+    for path in entries {
+        // ...and when one of them is edited.
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+        let skip = |reason: String| warn(&format!("skipping {}: {}", path.display(), reason));
+
+        let (Some(const_name), Some(variant)) = (const_name(&stem), variant_name(&stem)) else {
+            skip("the file name has no letters or digits".into());
+            continue;
+        };
+        if let Some(other) = icons.iter().find(|i| i.const_name == const_name || i.variant == variant) {
+            skip(format!("its name clashes with {}.svg", other.stem));
+            continue;
+        }
+        let source = match fs::read_to_string(&path) {
+            Ok(source) => source,
+            Err(e) => {
+                skip(e.to_string());
+                continue;
+            }
+        };
+        let mut svg = match svg::parse(&source) {
+            Ok(svg) => svg,
+            Err(e) => {
+                skip(e);
+                continue;
+            }
+        };
+        if svg.shapes.is_empty() {
+            skip("no shapes found".into());
+            continue;
+        }
+        if options.optimizes() {
+            for shape in &mut svg.shapes {
+                match path::optimize(&shape.d, options.precision, options.relative) {
+                    Ok(d) => shape.d = d,
+                    Err(e) => warn(&format!("{}: keeping path data as-is: {}", path.display(), e)),
+                }
+            }
+        }
+        icons.push(Icon { stem, const_name, variant, svg });
+    }
+    icons
+}
+
+/// Write `contents` to `OUT_DIR/output_file`.
+fn write(output_file: &str, contents: &str) {
     let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set - this function must be called from build.rs");
     let dest = Path::new(&out_dir).join(output_file);
+    fs::write(&dest, contents).expect("failed to write generated icon file");
+}
 
-    // Tell Cargo to rerun when the icon folder changes.
-    println!("cargo:rerun-if-changed={}", svg_folder);
+/// Words of a file name: runs of ASCII letters and digits.
+fn words(stem: &str) -> impl Iterator<Item = &str> {
+    stem.split(|c: char| !c.is_ascii_alphanumeric()).filter(|w| !w.is_empty())
+}
 
-    let mut output = String::from(
-        "// Auto-generated by eq_ui_build - do not edit manually.\n\n",
-    );
+/// `arrow-right` → `ARROW_RIGHT`; `None` when nothing usable is left.
+fn const_name(stem: &str) -> Option<String> {
+    let name = words(stem).collect::<Vec<_>>().join("_").to_uppercase();
+    match name.chars().next() {
+        None => None,
+        Some(c) if c.is_ascii_digit() => Some(format!("ICON_{}", name)),
+        Some(_) => Some(name),
+    }
+}
 
-    if !icons_dir.exists() {
-        fs::write(&dest, &output).expect("failed to write generated icon file");
-        return;
-    }
-
-    let mut entries: Vec<_> = fs::read_dir(icons_dir)
-        .unwrap_or_else(|_| panic!("failed to read folder: {}", svg_folder))
-        .filter_map(|e| e.ok())
-        .filter(|e| {
-            e.path()
-                .extension()
-                .map(|ext| ext == "svg")
-                .unwrap_or(false)
-        })
+/// `arrow-right` → `ArrowRight`; `None` when nothing usable is left.
+fn variant_name(stem: &str) -> Option<String> {
+    let name: String = words(stem)
+        .map(|w| w[..1].to_ascii_uppercase() + &w[1..])
         .collect();
+    match name.as_str() {
+        "" => None,
+        // The one PascalCase keyword.
+        "Self" => Some("SelfIcon".into()),
+        _ if name.starts_with(|c: char| c.is_ascii_digit()) => Some(format!("Icon{}", name)),
+        _ => Some(name),
+    }
+}
 
-    // Sort for deterministic output.
-    entries.sort_by_key(|e| e.file_name());
-
-    for entry in entries {
-        let path = entry.path();
-        let stem = path
-            .file_stem()
-            .expect("no file stem")
-            .to_string_lossy()
-            .to_string();
-
-        let svg = fs::read_to_string(&path)
-            .unwrap_or_else(|_| panic!("failed to read {}", path.display()));
-
-        let d = extract_path_d(&svg)
-            .unwrap_or_else(|| panic!(
-                "could not extract path `d` attribute from {}",
-                path.display()
-            ));
-
-        let const_name = stem.replace('-', "_").to_uppercase();
-        output.push_str(&format!(
-            "pub const {}: &str =\n    \"{}\";\n\n",
-            const_name,
-            d.replace('\\', "\\\\").replace('"', "\\\""),
+/// `BoldIcon` → `BOLD_ICON`.
+fn screaming_case(name: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            out.push('_');
+        }
+        out.push(c.to_ascii_uppercase());
+    }
+    out
+}
+
+/// `<symbol>` id: the prefix plus the file name, lowercased, with
+/// anything but letters and digits as `-`.
+fn symbol_id(prefix: &str, stem: &str) -> String {
+    format!("{}{}", prefix, words(stem).collect::<Vec<_>>().join("-").to_lowercase())
+}
+
+/// A Rust string literal.
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Text escaped for an XML attribute value.
+fn escape_attr(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('"', "&quot;")
+}
+
+/// Name and value of every attribute set in `style`, as SVG spells them.
+fn style_attributes(style: &svg::Style) -> Vec<(&'static str, &str)> {
+    [
+        ("fill", &style.fill),
+        ("stroke", &style.stroke),
+        ("stroke-width", &style.stroke_width),
+        ("stroke-linecap", &style.stroke_linecap),
+        ("stroke-linejoin", &style.stroke_linejoin),
+        ("fill-rule", &style.fill_rule),
+        ("opacity", &style.opacity),
+        ("transform", &style.transform),
+    ]
+    .into_iter()
+    .filter_map(|(name, value)| value.as_deref().map(|v| (name, v)))
+    .collect()
+}
+
+/// An `IconStyle { ... }` literal listing only the attributes that are set.
+fn style_literal(style: &svg::Style) -> String {
+    let set: Vec<String> = style_attributes(style)
+        .iter()
+        .map(|(name, value)| format!("{}: {}, ", name.replace('-', "_"), quote(value)))
+        .collect();
+    if set.is_empty() {
+        "IconStyle::INHERIT".into()
+    } else {
+        format!("IconStyle {{ {}..IconStyle::INHERIT }}", set.concat())
+    }
+}
+
+fn icon_data_const(name: &str, icon: &svg::ParsedSvg) -> String {
+    let mut out = format!(
+        "pub const {}: IconData = IconData {{\n    view_box: {},\n    style: {},\n    paths: &[\n",
+        name,
+        quote(icon.view_box.as_deref().unwrap_or(DEFAULT_VIEW_BOX)),
+        style_literal(&icon.root),
+    );
+    for shape in &icon.shapes {
+        out.push_str(&format!(
+            "        IconPath {{ d: {}, style: {} }},\n",
+            quote(&shape.d),
+            style_literal(&shape.style),
         ));
     }
+    out.push_str("    ],\n};\n\n");
+    out
+}
 
-    fs::write(&dest, &output).expect("failed to write generated icon file");
+/// ` name="value"` for each attribute set in `style`.
+fn attribute_text(style: &svg::Style) -> String {
+    style_attributes(style)
+        .iter()
+        .map(|(name, value)| format!(" {}=\"{}\"", name, escape_attr(value)))
+        .collect()
 }
 
-/// Extract the `d` attribute value from the first `<path d="...">` in an SVG string.
-fn extract_path_d(svg: &str) -> Option<String> {
-    let path_start = svg.find("<path")?;
-    let after_path = &svg[path_start..];
+/// The sprite constant: a hidden `<svg>` with one `<symbol>` per icon.
+fn sprite_const(icons: &[Icon], options: &IconOptions) -> String {
+    let mut svg = String::from(r#"<svg xmlns="http://www.w3.org/2000/svg" style="display:none">"#);
+    for icon in icons {
+        svg.push_str(&format!(
+            r#"<symbol id="{}" viewBox="{}">"#,
+            escape_attr(&symbol_id(&options.id_prefix, &icon.stem)),
+            escape_attr(icon.view_box()),
+        ));
+        // `<symbol>` ignores `transform`, so root styling goes on a group.
+        let root = attribute_text(&icon.svg.root);
+        if !root.is_empty() {
+            svg.push_str(&format!("<g{}>", root));
+        }
+        for shape in &icon.svg.shapes {
+            svg.push_str(&format!(r#"<path d="{}"{}/>"#, escape_attr(&shape.d), attribute_text(&shape.style)));
+        }
+        if !root.is_empty() {
+            svg.push_str("</g>");
+        }
+        svg.push_str("</symbol>");
+    }
+    svg.push_str("</svg>");
+    format!(
+        "/// Every `{}` as an SVG `<symbol>`. Mount it once per page and\n\
+         /// reference icons with `EqIcon {{ symbol: {}::Star.id() }}`.\n\
+         pub const {}_SPRITE: &str =\n    {};\n\n",
+        options.enum_name,
+        options.enum_name,
+        screaming_case(&options.enum_name),
+        quote(&svg),
+    )
+}
 
-    let d_start = after_path.find("d=\"")?;
-    let d_value = &after_path[d_start + 3..];
+/// The icon enum and its lookup methods.
+fn icon_enum(icons: &[Icon], svg_folder: &str, options: &IconOptions) -> String {
+    let ty = &options.enum_name;
+    // One `Variant => value,` arm per icon.
+    let arms = |value: &dyn Fn(&Icon) -> String| -> String {
+        icons
+            .iter()
+            .map(|i| format!("            {}::{} => {},\n", ty, i.variant, value(i)))
+            .collect()
+    };
+    let method = |doc: &str, name: &str, ret: &str, value: &dyn Fn(&Icon) -> String| {
+        format!(
+            "    /// {}\n    pub const fn {}(self) -> {} {{\n        match self {{\n{}        }}\n    }}\n\n",
+            doc,
+            name,
+            ret,
+            arms(value),
+        )
+    };
 
-    let d_end = d_value.find('"')?;
-    Some(d_value[..d_end].to_string())
+    let mut out = format!(
+        "/// Icons generated from `{}`, one per SVG file.\n\
+         #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]\n\
+         pub enum {} {{\n",
+        svg_folder, ty,
+    );
+    for icon in icons {
+        out.push_str(&format!("    /// `{}.svg`\n    {},\n", icon.stem, icon.variant));
+    }
+    out.push_str(&format!("}}\n\nimpl {} {{\n    /// Every icon, in file name order.\n    pub const ALL: &'static [{}] = &[\n", ty, ty));
+    for icon in icons {
+        out.push_str(&format!("        {}::{},\n", ty, icon.variant));
+    }
+    out.push_str("    ];\n\n");
+    out.push_str(&method("SVG file name without the extension.", "name", "&'static str", &|i| quote(&i.stem)));
+    out.push_str(&method(
+        &format!("`id` of the icon's `<symbol>` in `{}_SPRITE`.", screaming_case(ty)),
+        "id",
+        "&'static str",
+        &|i| quote(&symbol_id(&options.id_prefix, &i.stem)),
+    ));
+    out.push_str(&method("The icon's `viewBox`.", "view_box", "&'static str", &|i| quote(i.view_box())));
+    out.push_str(&method(
+        "Path data of every shape, joined - for `EqIcon { path: ... }`.",
+        "path",
+        "&'static str",
+        &|i| quote(&i.path()),
+    ));
+    out.push_str(&method("The full icon, as `EqIcon { icon: ... }` takes it.", "data", "IconData", &|i| {
+        i.const_name.clone()
+    }));
+    // Drop the blank line after the last method.
+    out.truncate(out.len() - 1);
+    // `EqIcon`'s `icon` prop is an `Option<IconData>`; props marked
+    // `into` convert straight into the field type.
+    out.push_str(&format!(
+        "}}\n\nimpl From<{ty}> for IconData {{\n    fn from(icon: {ty}) -> Self {{\n        icon.data()\n    }}\n}}\n\n\
+         impl From<{ty}> for Option<IconData> {{\n    fn from(icon: {ty}) -> Self {{\n        Some(icon.data())\n    }}\n}}\n",
+    ));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn icon(stem: &str, source: &str) -> Icon {
+        Icon {
+            stem: stem.into(),
+            const_name: const_name(stem).unwrap(),
+            variant: variant_name(stem).unwrap(),
+            svg: svg::parse(source).unwrap(),
+        }
+    }
+
+    #[test]
+    fn icon_data_keeps_view_box_and_styles() {
+        let icon = svg::parse(
+            r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
+                <circle cx="12" cy="12" r="10"/><path d="M12 6v6" stroke-linecap="round"/></svg>"#,
+        )
+        .unwrap();
+        let code = icon_data_const("CLOCK", &icon);
+        assert!(code.starts_with("pub const CLOCK: IconData = IconData {\n    view_box: \"0 0 24 24\","));
+        assert!(code.contains(
+            "style: IconStyle { fill: \"none\", stroke: \"currentColor\", stroke_width: \"2\", ..IconStyle::INHERIT },"
+        ));
+        assert!(code.contains("IconPath { d: \"M2,12a10,10 0 1,0 20,0a10,10 0 1,0 -20,0Z\", style: IconStyle::INHERIT },"));
+        assert!(code.contains("style: IconStyle { stroke_linecap: \"round\", ..IconStyle::INHERIT } },"));
+    }
+
+    #[test]
+    fn names_and_literals() {
+        assert_eq!(const_name("caret-up-down").as_deref(), Some("CARET_UP_DOWN"));
+        assert_eq!(const_name("my_icon").as_deref(), Some("MY_ICON"));
+        assert_eq!(const_name("24 hours").as_deref(), Some("ICON_24_HOURS"));
+        assert_eq!(const_name("--"), None);
+        assert_eq!(variant_name("caret-up-down").as_deref(), Some("CaretUpDown"));
+        assert_eq!(variant_name("24-hours").as_deref(), Some("Icon24Hours"));
+        assert_eq!(variant_name("self").as_deref(), Some("SelfIcon"));
+        assert_eq!(screaming_case("BoldIcon"), "BOLD_ICON");
+        assert_eq!(symbol_id("icon-", "Arrow Right"), "icon-arrow-right");
+        assert_eq!(quote(r#"a"b\c"#), r#""a\"b\\c""#);
+        assert_eq!(escape_attr(r#"a<b&"c""#), "a&lt;b&amp;&quot;c&quot;");
+    }
+
+    #[test]
+    fn sprite_wraps_root_styling_in_a_group() {
+        let icons = [
+            icon("dot", r#"<svg viewBox="0 0 24 24" fill="none" stroke="red"><circle cx="12" cy="12" r="2"/></svg>"#),
+            icon("bar", r#"<svg viewBox="0 0 10 10"><path d="M0 0H10" opacity=".5"/></svg>"#),
+        ];
+        let code = sprite_const(&icons, &IconOptions::default());
+        assert!(code.contains("pub const ICON_SPRITE: &str ="));
+        assert!(code.contains(
+            r#"<symbol id=\"icon-dot\" viewBox=\"0 0 24 24\"><g fill=\"none\" stroke=\"red\"><path d=\"M10,12a2,2 0 1,0 4,0a2,2 0 1,0 -4,0Z\"/></g></symbol>"#
+        ));
+        assert!(code.contains(r#"<symbol id=\"icon-bar\" viewBox=\"0 0 10 10\"><path d=\"M0 0H10\" opacity=\".5\"/></symbol>"#));
+    }
+
+    #[test]
+    fn enum_lists_every_icon() {
+        let icons = [
+            icon("arrow-right", r#"<svg><path d="M0 0L1 1"/></svg>"#),
+            icon("star", r#"<svg viewBox="0 0 24 24"><path d="M1 1H2"/><path d="M3 3V4"/></svg>"#),
+        ];
+        let options = IconOptions::new().enum_name("Glyph").id_prefix("g-");
+        let code = icon_enum(&icons, "assets/icons", &options);
+        assert!(code.contains("pub enum Glyph {\n    /// `arrow-right.svg`\n    ArrowRight,\n    /// `star.svg`\n    Star,\n}"));
+        assert!(code.contains("pub const ALL: &'static [Glyph] = &[\n        Glyph::ArrowRight,\n        Glyph::Star,\n    ];"));
+        assert!(code.contains("Glyph::ArrowRight => \"arrow-right\","));
+        assert!(code.contains("Glyph::Star => \"g-star\","));
+        assert!(code.contains("Glyph::ArrowRight => \"0 0 256 256\","));
+        assert!(code.contains("Glyph::Star => \"M1 1H2 M3 3V4\","));
+        assert!(code.contains("Glyph::Star => STAR,"));
+        assert!(code.contains("`id` of the icon's `<symbol>` in `GLYPH_SPRITE`."));
+        assert!(code.contains("impl From<Glyph> for IconData {\n    fn from(icon: Glyph) -> Self {\n        icon.data()\n    }\n}\n"));
+        assert!(code.ends_with("impl From<Glyph> for Option<IconData> {\n    fn from(icon: Glyph) -> Self {\n        Some(icon.data())\n    }\n}\n"));
+    }
+
+    #[test]
+    fn empty_enum_still_compiles_to_exhaustive_matches() {
+        let code = icon_enum(&[], "icons", &IconOptions::default());
+        assert!(code.contains("pub enum Icon {\n}"));
+        assert!(code.contains("match self {\n        }"));
+    }
 }
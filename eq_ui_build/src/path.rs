@@ -0,0 +1,338 @@
+//! Path data optimizer.
+//!
+//! Parses SVG path data, then writes it back with rounded numbers and,
+//! optionally, relative commands. Rounding happens on absolute
+//! positions before relative offsets are taken, so errors do not add
+//! up along a path.
+
+/// One command in absolute coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Seg {
+    M(f64, f64),
+    L(f64, f64),
+    H(f64),
+    V(f64),
+    C(f64, f64, f64, f64, f64, f64),
+    S(f64, f64, f64, f64),
+    Q(f64, f64, f64, f64),
+    T(f64, f64),
+    A(f64, f64, f64, bool, bool, f64, f64),
+    Z,
+}
+
+/// Decimals kept when no precision is asked for - enough to hide
+/// floating point noise from the relative conversion.
+const DEFAULT_PRECISION: usize = 6;
+
+/// Rewrite `d` with numbers rounded to `precision` decimals (when
+/// given) and relative commands (when `relative`). Redundant command
+/// letters and separators are dropped.
+pub(crate) fn optimize(d: &str, precision: Option<usize>, relative: bool) -> Result<String, String> {
+    let segs = parse(d)?;
+    let scale = 10f64.powi(precision.unwrap_or(DEFAULT_PRECISION) as i32);
+    let r = |v: f64| (v * scale).round() / scale;
+
+    let mut out = Writer::default();
+    let (mut cx, mut cy) = (0.0, 0.0);
+    let (mut sx, mut sy) = (0.0, 0.0);
+
+    for (i, seg) in segs.iter().enumerate() {
+        // Offsets from the current (already rounded) point.
+        let (ox, oy) = if relative { (cx, cy) } else { (0.0, 0.0) };
+        let x = |v: f64| r(r(v) - ox);
+        let y = |v: f64| r(r(v) - oy);
+        let cmd = |c: char| if relative { c.to_ascii_lowercase() } else { c };
+
+        match *seg {
+            Seg::M(px, py) => {
+                // The first moveto is absolute either way.
+                let letter = if i == 0 { 'M' } else { cmd('M') };
+                if i == 0 {
+                    out.push(letter, &[r(px), r(py)]);
+                } else {
+                    out.push(letter, &[x(px), y(py)]);
+                }
+                (cx, cy) = (r(px), r(py));
+                (sx, sy) = (cx, cy);
+            }
+            Seg::L(px, py) => {
+                if relative && r(py) == cy {
+                    out.push('h', &[x(px)]);
+                } else if relative && r(px) == cx {
+                    out.push('v', &[y(py)]);
+                } else {
+                    out.push(cmd('L'), &[x(px), y(py)]);
+                }
+                (cx, cy) = (r(px), r(py));
+            }
+            Seg::H(px) => {
+                out.push(cmd('H'), &[x(px)]);
+                cx = r(px);
+            }
+            Seg::V(py) => {
+                out.push(cmd('V'), &[y(py)]);
+                cy = r(py);
+            }
+            Seg::C(x1, y1, x2, y2, px, py) => {
+                out.push(cmd('C'), &[x(x1), y(y1), x(x2), y(y2), x(px), y(py)]);
+                (cx, cy) = (r(px), r(py));
+            }
+            Seg::S(x2, y2, px, py) => {
+                out.push(cmd('S'), &[x(x2), y(y2), x(px), y(py)]);
+                (cx, cy) = (r(px), r(py));
+            }
+            Seg::Q(x1, y1, px, py) => {
+                out.push(cmd('Q'), &[x(x1), y(y1), x(px), y(py)]);
+                (cx, cy) = (r(px), r(py));
+            }
+            Seg::T(px, py) => {
+                out.push(cmd('T'), &[x(px), y(py)]);
+                (cx, cy) = (r(px), r(py));
+            }
+            Seg::A(rx, ry, rot, large, sweep, px, py) => {
+                let flag = |f: bool| if f { 1.0 } else { 0.0 };
+                out.push(cmd('A'), &[r(rx), r(ry), r(rot), flag(large), flag(sweep), x(px), y(py)]);
+                (cx, cy) = (r(px), r(py));
+            }
+            Seg::Z => {
+                out.push(cmd('Z'), &[]);
+                (cx, cy) = (sx, sy);
+            }
+        }
+    }
+    Ok(out.text)
+}
+
+/// Appends commands, skipping repeated letters and spaces that the
+/// path grammar does not need.
+#[derive(Default)]
+struct Writer {
+    text: String,
+    last: Option<char>,
+    /// Whether the last number written contains a `.`.
+    last_has_dot: bool,
+}
+
+impl Writer {
+    fn push(&mut self, letter: char, args: &[f64]) {
+        // After a moveto, repeated pairs mean lineto, so an M can never
+        // be left implicit - and neither can anything after it.
+        let implicit = self.last == Some(letter)
+            && !matches!(letter, 'M' | 'm' | 'Z' | 'z')
+            && !args.is_empty();
+        if !implicit {
+            self.text.push(letter);
+            self.last_has_dot = false;
+        }
+        for (i, value) in args.iter().enumerate() {
+            let n = number(*value);
+            let needs_space = if i == 0 && !implicit {
+                false
+            } else {
+                !(n.starts_with('-') || (n.starts_with('.') && self.last_has_dot))
+            };
+            if needs_space {
+                self.text.push(' ');
+            }
+            self.last_has_dot = n.contains('.');
+            self.text.push_str(&n);
+        }
+        self.last = Some(letter);
+    }
+}
+
+/// Shortest decimal form: no trailing zeros, no leading zero.
+fn number(value: f64) -> String {
+    let s = if value == 0.0 { "0".to_string() } else { value.to_string() };
+    if let Some(rest) = s.strip_prefix("0.") {
+        format!(".{rest}")
+    } else if let Some(rest) = s.strip_prefix("-0.") {
+        format!("-.{rest}")
+    } else {
+        s
+    }
+}
+
+// ── Parser ────────────────────────────────────────────────────────
+
+struct Cursor<'a> {
+    s: &'a [u8],
+    i: usize,
+}
+
+impl Cursor<'_> {
+    fn skip_separators(&mut self) {
+        while self.i < self.s.len() && (self.s[self.i].is_ascii_whitespace() || self.s[self.i] == b',') {
+            self.i += 1;
+        }
+    }
+
+    fn at_number(&mut self) -> bool {
+        self.skip_separators();
+        matches!(self.s.get(self.i), Some(b'0'..=b'9' | b'-' | b'+' | b'.'))
+    }
+
+    fn number(&mut self) -> Result<f64, String> {
+        self.skip_separators();
+        let start = self.i;
+        let mut seen_dot = false;
+        let mut seen_exp = false;
+        if matches!(self.s.get(self.i), Some(b'-' | b'+')) {
+            self.i += 1;
+        }
+        while let Some(&c) = self.s.get(self.i) {
+            match c {
+                b'0'..=b'9' => {}
+                b'.' if !seen_dot && !seen_exp => seen_dot = true,
+                b'e' | b'E' if !seen_exp => {
+                    seen_exp = true;
+                    if matches!(self.s.get(self.i + 1), Some(b'-' | b'+')) {
+                        self.i += 1;
+                    }
+                }
+                _ => break,
+            }
+            self.i += 1;
+        }
+        std::str::from_utf8(&self.s[start..self.i])
+            .ok()
+            .and_then(|n| n.parse().ok())
+            .ok_or_else(|| format!("expected a number at offset {start}"))
+    }
+
+    /// Arc flags are a single `0` or `1` and may touch the next number.
+    fn flag(&mut self) -> Result<bool, String> {
+        self.skip_separators();
+        let flag = match self.s.get(self.i) {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(format!("expected an arc flag at offset {}", self.i)),
+        };
+        self.i += 1;
+        Ok(flag)
+    }
+}
+
+fn parse(d: &str) -> Result<Vec<Seg>, String> {
+    let mut cur = Cursor { s: d.as_bytes(), i: 0 };
+    let mut segs = Vec::new();
+    let (mut cx, mut cy) = (0.0, 0.0);
+    let (mut sx, mut sy) = (0.0, 0.0);
+    let mut command: Option<u8> = None;
+
+    loop {
+        cur.skip_separators();
+        let Some(&c) = cur.s.get(cur.i) else { break };
+        let letter = if c.is_ascii_alphabetic() {
+            cur.i += 1;
+            c
+        } else {
+            // Implicit repeat; after a moveto it becomes a lineto.
+            match command {
+                Some(b'M') => b'L',
+                Some(b'm') => b'l',
+                Some(b'Z' | b'z') | None => return Err(format!("unexpected {:?} at offset {}", c as char, cur.i)),
+                Some(other) => other,
+            }
+        };
+        let rel = letter.is_ascii_lowercase();
+        let (ox, oy) = if rel { (cx, cy) } else { (0.0, 0.0) };
+
+        let seg = match letter.to_ascii_uppercase() {
+            b'M' => Seg::M(ox + cur.number()?, oy + cur.number()?),
+            b'L' => Seg::L(ox + cur.number()?, oy + cur.number()?),
+            b'H' => Seg::H(ox + cur.number()?),
+            b'V' => Seg::V(oy + cur.number()?),
+            b'C' => Seg::C(
+                ox + cur.number()?, oy + cur.number()?,
+                ox + cur.number()?, oy + cur.number()?,
+                ox + cur.number()?, oy + cur.number()?,
+            ),
+            b'S' => Seg::S(ox + cur.number()?, oy + cur.number()?, ox + cur.number()?, oy + cur.number()?),
+            b'Q' => Seg::Q(ox + cur.number()?, oy + cur.number()?, ox + cur.number()?, oy + cur.number()?),
+            b'T' => Seg::T(ox + cur.number()?, oy + cur.number()?),
+            b'A' => Seg::A(
+                cur.number()?, cur.number()?, cur.number()?,
+                cur.flag()?, cur.flag()?,
+                ox + cur.number()?, oy + cur.number()?,
+            ),
+            b'Z' => Seg::Z,
+            _ => return Err(format!("unknown command {:?}", letter as char)),
+        };
+
+        match seg {
+            Seg::M(x, y) => {
+                (cx, cy) = (x, y);
+                (sx, sy) = (x, y);
+            }
+            Seg::H(x) => cx = x,
+            Seg::V(y) => cy = y,
+            Seg::Z => (cx, cy) = (sx, sy),
+            Seg::L(x, y) | Seg::T(x, y) | Seg::C(.., x, y) | Seg::S(.., x, y) | Seg::Q(.., x, y) | Seg::A(.., x, y) => {
+                (cx, cy) = (x, y)
+            }
+        }
+        segs.push(seg);
+        command = Some(letter);
+
+        if matches!(seg, Seg::Z) && cur.at_number() {
+            return Err(format!("numbers after closepath at offset {}", cur.i));
+        }
+    }
+
+    if !matches!(segs.first(), None | Some(Seg::M(..))) {
+        return Err("path data must start with a moveto".into());
+    }
+    Ok(segs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounds_and_trims_numbers() {
+        assert_eq!(
+            optimize("M 10.0004 0.5 L 20.25 -0.75", Some(2), false).unwrap(),
+            "M10 .5L20.25-.75"
+        );
+        assert_eq!(number(-0.5), "-.5");
+        assert_eq!(number(0.0), "0");
+    }
+
+    #[test]
+    fn converts_to_relative_and_shortens_lines() {
+        assert_eq!(
+            optimize("M10 10 L20 10 L20 30 L25 35 Z M40 40 40 50", None, true).unwrap(),
+            "M10 10h10v20l5 5zm30 30v10"
+        );
+    }
+
+    #[test]
+    fn rounding_does_not_drift_in_relative_mode() {
+        // Three steps of 0.4 land on 11.2, which rounds to 11 - not 10
+        // as rounding each 0.4 offset would.
+        assert_eq!(
+            optimize("M10 0L10.4 1L10.8 2L11.2 3", Some(0), true).unwrap(),
+            "M10 0v1l1 1v1"
+        );
+    }
+
+    #[test]
+    fn parses_compact_arcs_and_implicit_repeats() {
+        assert_eq!(
+            optimize("M0,0a5,5,0,1,0,10,0c0-1.5.5-2 1-2.5", None, false).unwrap(),
+            "M0 0A5 5 0 1 0 10 0C10-1.5 10.5-2 11-2.5"
+        );
+        assert_eq!(optimize("M0 0a5 5 0 1010 0", None, true).unwrap(), "M0 0a5 5 0 1 0 10 0");
+        assert_eq!(optimize("M1 1 2 2 3 3", None, false).unwrap(), "M1 1L2 2 3 3");
+    }
+
+    #[test]
+    fn rejects_malformed_data() {
+        assert!(optimize("L10 10", None, false).is_err());
+        assert!(optimize("M0 0 Q1", None, false).is_err());
+        assert!(optimize("M0 0 X1 1", None, false).is_err());
+        assert!(optimize("M0 0a5 5 0 2 0 1 1", None, false).is_err());
+    }
+}
@@ -0,0 +1,429 @@
+//! Minimal SVG reader for icon files.
+//!
+//! Not a general XML parser - it understands what icon sets ship:
+//! a root `<svg>` with a `viewBox`, nested `<g>` groups, the basic
+//! shapes and presentation attributes (also inside `style="..."`).
+//! Shapes are converted to path data so every element can be drawn
+//! with a single `<path>`; group styling and transforms are folded
+//! into each shape. Content of `<defs>`, `<clipPath>`, `<mask>`,
+//! `<title>` and similar non-rendered elements is skipped.
+
+/// Presentation attributes carried to the generated code. `None`
+/// means "inherit from the `<svg>` the icon is rendered in".
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Style {
+    pub fill: Option<String>,
+    pub stroke: Option<String>,
+    pub stroke_width: Option<String>,
+    pub stroke_linecap: Option<String>,
+    pub stroke_linejoin: Option<String>,
+    pub fill_rule: Option<String>,
+    pub opacity: Option<String>,
+    pub transform: Option<String>,
+}
+
+impl Style {
+    /// Apply an element's own attributes on top of the inherited style.
+    fn cascade(&self, attrs: &[(String, String)]) -> Style {
+        let mut style = self.clone();
+        // Transforms compose and opacity multiplies; the rest override.
+        style.transform = None;
+        style.opacity = None;
+
+        let mut own_transform = None;
+        let mut own_opacity = None;
+        let declarations = attrs
+            .iter()
+            .filter(|(name, _)| name == "style")
+            .flat_map(|(_, css)| css.split(';'))
+            .filter_map(|decl| {
+                let (name, value) = decl.split_once(':')?;
+                Some((name.trim().to_string(), value.trim().to_string()))
+            });
+        // `style` wins over attributes, so it is applied last.
+        let ordered: Vec<(String, String)> = attrs
+            .iter()
+            .filter(|(name, _)| name != "style")
+            .cloned()
+            .chain(declarations)
+            .collect();
+
+        for (name, value) in ordered {
+            match name.as_str() {
+                "fill" => style.fill = Some(value),
+                "stroke" => style.stroke = Some(value),
+                "stroke-width" => style.stroke_width = Some(value),
+                "stroke-linecap" => style.stroke_linecap = Some(value),
+                "stroke-linejoin" => style.stroke_linejoin = Some(value),
+                "fill-rule" => style.fill_rule = Some(value),
+                "opacity" => own_opacity = Some(value),
+                "transform" => own_transform = Some(value),
+                _ => {}
+            }
+        }
+
+        style.transform = match (&self.transform, own_transform) {
+            (Some(outer), Some(inner)) => Some(format!("{outer} {inner}")),
+            (outer, inner) => inner.or_else(|| outer.clone()),
+        };
+        style.opacity = match (&self.opacity, own_opacity) {
+            (Some(outer), Some(inner)) => match (outer.parse::<f64>(), inner.parse::<f64>()) {
+                (Ok(a), Ok(b)) => Some(num(a * b)),
+                _ => Some(inner),
+            },
+            (outer, inner) => inner.or_else(|| outer.clone()),
+        };
+        style
+    }
+}
+
+/// One drawable element, as path data plus its resolved style.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Shape {
+    pub d: String,
+    pub style: Style,
+}
+
+/// A parsed icon file.
+#[derive(Debug, PartialEq)]
+pub(crate) struct ParsedSvg {
+    /// The root `viewBox`, or one built from `width` / `height`.
+    pub view_box: Option<String>,
+    /// Presentation attributes set on the root `<svg>`.
+    pub root: Style,
+    pub shapes: Vec<Shape>,
+}
+
+/// Elements whose content never renders directly.
+const SKIPPED: &[&str] = &[
+    "defs", "clipPath", "mask", "pattern", "symbol", "marker",
+    "linearGradient", "radialGradient", "title", "desc", "metadata", "style",
+];
+
+/// Parse an SVG document into shapes.
+pub(crate) fn parse(svg: &str) -> Result<ParsedSvg, String> {
+    let mut parsed = ParsedSvg { view_box: None, root: Style::default(), shapes: Vec::new() };
+    // Inherited style per open container; the root `<svg>` style is
+    // kept separately, since the renderer sets it on its own `<svg>`.
+    let mut stack: Vec<Style> = Vec::new();
+    let mut skip_depth = 0usize;
+    let mut seen_root = false;
+    let mut rest = svg;
+
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start..];
+        if let Some(after) = rest.strip_prefix("<!--") {
+            let end = after.find("-->").ok_or("unterminated comment")?;
+            rest = &after[end + 3..];
+            continue;
+        }
+        if rest.starts_with("<?") || rest.starts_with("<!") {
+            let end = rest.find('>').ok_or("unterminated declaration")?;
+            rest = &rest[end + 1..];
+            continue;
+        }
+        let end = tag_end(rest).ok_or("unterminated tag")?;
+        let tag = Tag::parse(&rest[1..end]);
+        rest = &rest[end + 1..];
+
+        if tag.closing {
+            if skip_depth > 0 {
+                skip_depth -= 1;
+            } else if matches!(tag.name.as_str(), "g" | "svg" | "a") {
+                stack.pop();
+            }
+            continue;
+        }
+        if skip_depth > 0 {
+            if !tag.self_closing {
+                skip_depth += 1;
+            }
+            continue;
+        }
+        if SKIPPED.contains(&tag.name.as_str()) {
+            if !tag.self_closing {
+                skip_depth = 1;
+            }
+            continue;
+        }
+
+        let inherited = stack.last().cloned().unwrap_or_default();
+        match tag.name.as_str() {
+            "svg" if !seen_root => {
+                seen_root = true;
+                parsed.view_box = tag.attr("viewBox").map(normalize_view_box).or_else(|| {
+                    let w = tag.attr("width")?.trim_end_matches("px").parse::<f64>().ok()?;
+                    let h = tag.attr("height")?.trim_end_matches("px").parse::<f64>().ok()?;
+                    Some(format!("0 0 {} {}", num(w), num(h)))
+                });
+                parsed.root = Style::default().cascade(&tag.attrs);
+                if !tag.self_closing {
+                    stack.push(Style::default());
+                }
+            }
+            "g" | "svg" | "a" => {
+                if !tag.self_closing {
+                    stack.push(inherited.cascade(&tag.attrs));
+                }
+            }
+            name => {
+                if let Some(d) = shape_path(name, &tag)? {
+                    parsed.shapes.push(Shape { d, style: inherited.cascade(&tag.attrs) });
+                }
+                // Content of shapes (e.g. `<path><title>`) is not drawn.
+                if !tag.self_closing {
+                    skip_depth = 1;
+                }
+            }
+        }
+    }
+
+    if !seen_root {
+        return Err("no <svg> element".into());
+    }
+    Ok(parsed)
+}
+
+/// Index of the `>` closing the tag at the start of `s`, ignoring `>`
+/// inside quoted attribute values.
+fn tag_end(s: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if c == q => quote = None,
+            (None, '>') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+struct Tag {
+    name: String,
+    attrs: Vec<(String, String)>,
+    closing: bool,
+    self_closing: bool,
+}
+
+impl Tag {
+    /// Parse the inside of `<...>`.
+    fn parse(inner: &str) -> Tag {
+        let closing = inner.starts_with('/');
+        let self_closing = inner.ends_with('/');
+        let body = inner.trim_start_matches('/').trim_end_matches('/').trim();
+        let name_end = body.find(|c: char| c.is_whitespace()).unwrap_or(body.len());
+        let name = body[..name_end].rsplit(':').next().unwrap_or("").to_string();
+
+        let mut attrs = Vec::new();
+        let mut rest = body[name_end..].trim_start();
+        while let Some(eq) = rest.find('=') {
+            let key = rest[..eq].trim().to_string();
+            let after = rest[eq + 1..].trim_start();
+            let Some(quote) = after.chars().next().filter(|c| *c == '"' || *c == '\'') else {
+                break;
+            };
+            let Some(close) = after[1..].find(quote) else { break };
+            attrs.push((key, after[1..1 + close].to_string()));
+            rest = after[close + 2..].trim_start();
+        }
+        Tag { name, attrs, closing, self_closing }
+    }
+
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str())
+    }
+
+    fn number(&self, name: &str) -> Result<f64, String> {
+        match self.attr(name) {
+            None => Ok(0.0),
+            Some(v) => v
+                .trim()
+                .trim_end_matches("px")
+                .parse()
+                .map_err(|_| format!("<{}> has a non-numeric `{name}`: {v:?}", self.name)),
+        }
+    }
+}
+
+/// Path data for a shape element, or `None` for elements that do not
+/// draw anything (`<use>`, `<text>`, unknown tags, empty shapes).
+fn shape_path(name: &str, tag: &Tag) -> Result<Option<String>, String> {
+    let d = match name {
+        "path" => tag.attr("d").map(|d| d.split_whitespace().collect::<Vec<_>>().join(" ")),
+        "circle" => {
+            let (cx, cy, r) = (tag.number("cx")?, tag.number("cy")?, tag.number("r")?);
+            (r > 0.0).then(|| ellipse(cx, cy, r, r))
+        }
+        "ellipse" => {
+            let (cx, cy) = (tag.number("cx")?, tag.number("cy")?);
+            let (rx, ry) = (tag.number("rx")?, tag.number("ry")?);
+            (rx > 0.0 && ry > 0.0).then(|| ellipse(cx, cy, rx, ry))
+        }
+        "rect" => {
+            let (x, y) = (tag.number("x")?, tag.number("y")?);
+            let (w, h) = (tag.number("width")?, tag.number("height")?);
+            // A missing radius takes the other one's value.
+            let (rx, ry) = match (tag.attr("rx"), tag.attr("ry")) {
+                (Some(_), None) => (tag.number("rx")?, tag.number("rx")?),
+                (None, Some(_)) => (tag.number("ry")?, tag.number("ry")?),
+                _ => (tag.number("rx")?, tag.number("ry")?),
+            };
+            (w > 0.0 && h > 0.0).then(|| rect(x, y, w, h, rx.min(w / 2.0), ry.min(h / 2.0)))
+        }
+        "line" => Some(format!(
+            "M{},{}L{},{}",
+            num(tag.number("x1")?),
+            num(tag.number("y1")?),
+            num(tag.number("x2")?),
+            num(tag.number("y2")?),
+        )),
+        "polyline" | "polygon" => {
+            let points = tag.attr("points").map(points).unwrap_or_default();
+            (points.len() >= 2).then(|| {
+                let mut d = String::new();
+                for (i, (x, y)) in points.iter().enumerate() {
+                    d.push_str(&format!("{}{},{}", if i == 0 { "M" } else { "L" }, num(*x), num(*y)));
+                }
+                if name == "polygon" {
+                    d.push('Z');
+                }
+                d
+            })
+        }
+        _ => None,
+    };
+    Ok(d.filter(|d| !d.is_empty()))
+}
+
+fn ellipse(cx: f64, cy: f64, rx: f64, ry: f64) -> String {
+    format!(
+        "M{},{}a{},{} 0 1,0 {},0a{},{} 0 1,0 {},0Z",
+        num(cx - rx),
+        num(cy),
+        num(rx),
+        num(ry),
+        num(2.0 * rx),
+        num(rx),
+        num(ry),
+        num(-2.0 * rx),
+    )
+}
+
+fn rect(x: f64, y: f64, w: f64, h: f64, rx: f64, ry: f64) -> String {
+    if rx <= 0.0 || ry <= 0.0 {
+        return format!("M{},{}h{}v{}h{}Z", num(x), num(y), num(w), num(h), num(-w));
+    }
+    let (iw, ih) = (w - 2.0 * rx, h - 2.0 * ry);
+    let arc = |dx: f64, dy: f64| format!("a{},{} 0 0 1 {},{}", num(rx), num(ry), num(dx), num(dy));
+    format!(
+        "M{},{}h{}{}v{}{}h{}{}v{}{}Z",
+        num(x + rx),
+        num(y),
+        num(iw),
+        arc(rx, ry),
+        num(ih),
+        arc(-rx, ry),
+        num(-iw),
+        arc(-rx, -ry),
+        num(-ih),
+        arc(rx, -ry),
+    )
+}
+
+/// `points="1,2 3 4"` → `[(1, 2), (3, 4)]`.
+fn points(list: &str) -> Vec<(f64, f64)> {
+    let numbers: Vec<f64> = list
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter_map(|n| n.parse().ok())
+        .collect();
+    numbers.chunks_exact(2).map(|p| (p[0], p[1])).collect()
+}
+
+fn normalize_view_box(view_box: &str) -> String {
+    view_box
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|n| !n.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Compact number: at most three decimals, no trailing zeros.
+fn num(value: f64) -> String {
+    let rounded = (value * 1000.0).round() / 1000.0;
+    if rounded == 0.0 { "0".into() } else { rounded.to_string() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_every_shape_and_the_view_box() {
+        let svg = r#"<?xml version="1.0"?>
+            <!-- exported -->
+            <svg xmlns="http://www.w3.org/2000/svg" viewBox="0,0,24,24" fill="none" stroke="currentColor">
+              <title>Clock</title>
+              <circle cx="12" cy="12" r="10"/>
+              <polyline points="12 6 12 12 16 14"></polyline>
+              <path d="M1 2
+                       L3 4"/>
+            </svg>"#;
+        let parsed = parse(svg).unwrap();
+        assert_eq!(parsed.view_box.as_deref(), Some("0 0 24 24"));
+        assert_eq!(parsed.root.fill.as_deref(), Some("none"));
+        let d: Vec<&str> = parsed.shapes.iter().map(|s| s.d.as_str()).collect();
+        assert_eq!(d, vec![
+            "M2,12a10,10 0 1,0 20,0a10,10 0 1,0 -20,0Z",
+            "M12,6L12,12L16,14",
+            "M1 2 L3 4",
+        ]);
+    }
+
+    #[test]
+    fn converts_rects_lines_and_polygons() {
+        let svg = r#"<svg width="32px" height="16"><rect x="1" y="2" width="10" height="4"/>
+            <rect width="8" height="8" rx="2"/><line x1="0" y1="0" x2="5" y2="5"/>
+            <polygon points="0,0 4,0 2,3"/><ellipse cx="5" cy="5" rx="0" ry="2"/></svg>"#;
+        let parsed = parse(svg).unwrap();
+        assert_eq!(parsed.view_box.as_deref(), Some("0 0 32 16"));
+        let d: Vec<&str> = parsed.shapes.iter().map(|s| s.d.as_str()).collect();
+        assert_eq!(d, vec![
+            "M1,2h10v4h-10Z",
+            "M2,0h4a2,2 0 0 1 2,2v4a2,2 0 0 1 -2,2h-4a2,2 0 0 1 -2,-2v-4a2,2 0 0 1 2,-2Z",
+            "M0,0L5,5",
+            "M0,0L4,0L2,3Z",
+        ]);
+    }
+
+    #[test]
+    fn groups_cascade_styles_and_compose_transforms() {
+        let svg = r#"<svg viewBox="0 0 16 16">
+            <defs><path id="hidden" d="M0 0"/></defs>
+            <g fill="red" transform="translate(1 1)" opacity="0.5">
+              <g style="fill: blue; stroke-width: 2">
+                <path d="M1 1" fill-rule="evenodd" transform="scale(2)" opacity="0.5"/>
+              </g>
+              <path d="M2 2" style="fill:none"/>
+            </g>
+            <path d="M3 3"/>
+        </svg>"#;
+        let shapes = parse(svg).unwrap().shapes;
+        assert_eq!(shapes.len(), 3);
+        let inner = &shapes[0].style;
+        assert_eq!(inner.fill.as_deref(), Some("blue"));
+        assert_eq!(inner.stroke_width.as_deref(), Some("2"));
+        assert_eq!(inner.fill_rule.as_deref(), Some("evenodd"));
+        assert_eq!(inner.transform.as_deref(), Some("translate(1 1) scale(2)"));
+        assert_eq!(inner.opacity.as_deref(), Some("0.25"));
+        assert_eq!(shapes[1].style.fill.as_deref(), Some("none"));
+        assert_eq!(shapes[2].style, Style::default());
+    }
+
+    #[test]
+    fn rejects_non_svg_and_bad_numbers() {
+        assert!(parse("<html></html>").is_err());
+        assert!(parse(r#"<svg><circle r="ten"/></svg>"#).is_err());
+        assert!(parse(r#"<svg><path d="M0 0"/></svg>"#).unwrap().view_box.is_none());
+    }
+}
@@ -98,7 +98,7 @@ fn find_bool_handler_pairs(props: &[PropInfo]) -> Vec<(Ident, Ident)> {
 /// Generate the `__PlaygroundDemo{Name}` component.
 ///
 /// Creates `use_signal` for each non-skipped prop, PropToggle/PropSelect/PropInput
-/// controls, a live preview instance with handler wiring, and a CodeBlock with examples.
+/// controls, a live preview instance with handler wiring, and an EqCodeBlock with examples.
 pub fn gen_demo(
     comp_name: &Ident,
     props: &[PropInfo],
@@ -315,7 +315,11 @@ pub fn gen_demo(
             .collect::<Vec<_>>()
             .join("\n\n");
         quote! {
-            CodeBlock { code: #combined_code.to_string() }
+            crate::atoms::EqCodeBlock {
+                code: #combined_code.to_string(),
+                language: crate::atoms::CodeLanguage::Rust,
+                title: "Example Usage",
+            }
         }
     };
 
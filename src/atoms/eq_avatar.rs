@@ -49,9 +49,11 @@ use dioxus::prelude::*;
 
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropSelect, PropToggle, PropInput, StyleInfo, format_catalog,
+    DemoSection, PropSelect, PropToggle, PropInput, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
 #[cfg(feature = "playground")]
 use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
@@ -383,7 +385,7 @@ fn DemoEqAvatar() -> Element {
             }
 
             StyleInfo { file: "eq_avatar_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
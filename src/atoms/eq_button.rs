@@ -12,9 +12,11 @@ use dioxus::prelude::*;
 
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropSelect, PropToggle, PropInput, StyleInfo, format_catalog,
+    DemoSection, PropSelect, PropToggle, PropInput, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
 #[cfg(feature = "playground")]
 use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
@@ -333,7 +335,7 @@ fn DemoEqButton() -> Element {
             }
 
             StyleInfo { file: "eq_button_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
@@ -10,15 +10,17 @@ use dioxus::prelude::*;
 
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropSelect, PropToggle, PropInput, StyleInfo, format_catalog,
+    DemoSection, PropSelect, PropToggle, PropInput, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
 #[cfg(feature = "playground")]
 use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
 
 /// Visual state of the checkbox.
-#[derive(Clone, Copy, PartialEq, Default, PlaygroundEnum)]
+#[derive(Clone, Copy, Debug, PartialEq, Default, PlaygroundEnum)]
 pub enum CheckboxState {
     /// Empty square.
     #[default]
@@ -58,6 +60,12 @@ pub fn EqCheckbox(
     /// Disables interaction and dims the visual.
     #[props(default = false)]
     disabled: bool,
+    /// Whether the checkbox is its own tab stop. Set to `false` inside
+    /// composite widgets that manage focus and expose the checked state
+    /// themselves (e.g. EqTree rows) - clicks still work, but the checkbox
+    /// takes no focus and is hidden from assistive tech.
+    #[props(default = true)]
+    focusable: bool,
     /// Icon size override.
     #[props(default = IconSize::Sm)]
     size: IconSize,
@@ -89,7 +97,8 @@ pub fn EqCheckbox(
             role: "checkbox",
             "aria-checked": "{aria_checked}",
             "aria-disabled": "{disabled}",
-            tabindex: if disabled { "-1" } else { "0" },
+            "aria-hidden": if !focusable { Some("true") } else { None },
+            tabindex: if !focusable { None } else if disabled { Some("-1") } else { Some("0") },
             onclick: move |evt| {
                 evt.stop_propagation();
                 if disabled { return; }
@@ -101,7 +110,7 @@ pub fn EqCheckbox(
                 handler.call(next);
             },
             onkeydown: move |evt: Event<KeyboardData>| {
-                if disabled { return; }
+                if disabled || !focusable { return; }
                 let key = evt.key();
                 if key == Key::Character(" ".into()) {
                     evt.prevent_default();
@@ -252,7 +261,7 @@ fn DemoEqCheckbox() -> Element {
             }
 
             StyleInfo { file: "eq_checkbox_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
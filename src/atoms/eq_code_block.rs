@@ -0,0 +1,483 @@
+//! EqCodeBlock — syntax-highlighted code block atom.
+//!
+//! Highlights Rust, TOML, JSON, shell, HTML and CSS with a Rust-side
+//! tokenizer (no JS highlighter) and colours tokens from the theme's
+//! `--color-code-*` variables. Optional line numbers, highlighted line
+//! ranges, a copy-to-clipboard button and a unified-diff mode that
+//! tints `+` / `-` lines.
+//!
+//! ```no_run
+//! use eq_ui::prelude::*;
+//! use eq_ui::atoms::{EqCodeBlock, CodeLanguage};
+//!
+//! let _: Element = rsx! {
+//!     EqCodeBlock {
+//!         code: "let answer = 42;\nassert_eq!(answer, 42);",
+//!         language: CodeLanguage::Rust,
+//!         line_numbers: true,
+//!         highlight: vec![2..=2],
+//!     }
+//! };
+//! ```
+
+use super::eq_code_block_styles as s;
+use super::eq_code_block_tokenizer::{Token, TokenKind, tokenize_lines};
+use crate::theme::merge_classes;
+use crate::{PlaygroundEnum, playground};
+use dioxus::prelude::*;
+use std::ops::RangeInclusive;
+
+#[cfg(feature = "playground")]
+use crate::playground::playground_helpers::{
+    DemoSection, PropInput, PropSelect, PropToggle, StyleInfo, format_catalog,
+};
+#[cfg(feature = "playground")]
+use crate::atoms::{EqText, TextVariant};
+#[cfg(feature = "playground")]
+use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
+
+/// Language used to tokenize the code.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, PlaygroundEnum)]
+pub enum CodeLanguage {
+    Rust,
+    Toml,
+    Json,
+    Shell,
+    Html,
+    Css,
+    /// No highlighting.
+    #[default]
+    Plain,
+}
+
+impl CodeLanguage {
+    /// Maps a Markdown fence info string or file extension (`rs`,
+    /// `bash`, `xml`, …) to a language. Unknown hints give `Plain`.
+    pub fn from_hint(hint: &str) -> Self {
+        match hint.trim().to_ascii_lowercase().as_str() {
+            "rust" | "rs" => Self::Rust,
+            "toml" => Self::Toml,
+            "json" | "jsonc" | "json5" => Self::Json,
+            "sh" | "shell" | "bash" | "zsh" | "console" | "shell-session" => Self::Shell,
+            "html" | "htm" | "xml" | "svg" | "rsx" => Self::Html,
+            "css" => Self::Css,
+            _ => Self::Plain,
+        }
+    }
+
+    /// Short label shown in the header.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Rust => "rust",
+            Self::Toml => "toml",
+            Self::Json => "json",
+            Self::Shell => "shell",
+            Self::Html => "html",
+            Self::Css => "css",
+            Self::Plain => "text",
+        }
+    }
+}
+
+/// Parses a line-range spec such as `"1,3-5"` or `"{2, 7-8}"` into
+/// 1-based inclusive ranges. Malformed parts are skipped.
+pub fn parse_line_ranges(spec: &str) -> Vec<RangeInclusive<usize>> {
+    spec.trim()
+        .trim_start_matches('{')
+        .trim_end_matches('}')
+        .split(',')
+        .filter_map(|part| {
+            let part = part.trim();
+            let (a, b) = part.split_once('-').unwrap_or((part, part));
+            let (a, b) = (a.trim().parse().ok()?, b.trim().parse().ok()?);
+            (a >= 1 && a <= b).then_some(a..=b)
+        })
+        .collect()
+}
+
+/// How a line is rendered.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum LineKind {
+    Normal,
+    Added,
+    Removed,
+    /// Diff metadata: `@@ … @@`, `+++ b/…`, `--- a/…`, `diff …`.
+    Header,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+struct CodeLine {
+    kind: LineKind,
+    tokens: Vec<Token>,
+}
+
+fn is_diff_header(line: &str) -> bool {
+    line.starts_with("@@") || line.starts_with("+++ ") || line.starts_with("--- ") || line.starts_with("diff ")
+}
+
+/// Splits and tokenizes `code`. In diff mode the `+` / `-` / ` ` marker
+/// column is stripped before tokenizing so the language lexer sees
+/// plain source.
+fn build_lines(code: &str, language: CodeLanguage, diff: bool) -> Vec<CodeLine> {
+    let code = code.trim_end_matches('\n');
+    if !diff {
+        return tokenize_lines(code, language)
+            .into_iter()
+            .map(|tokens| CodeLine { kind: LineKind::Normal, tokens })
+            .collect();
+    }
+
+    let mut kinds = Vec::new();
+    let mut body = Vec::new();
+    for line in code.split('\n') {
+        if is_diff_header(line) {
+            kinds.push((LineKind::Header, line));
+            continue;
+        }
+        let (kind, rest) = match line.chars().next() {
+            Some('+') => (LineKind::Added, &line[1..]),
+            Some('-') => (LineKind::Removed, &line[1..]),
+            Some(' ') => (LineKind::Normal, &line[1..]),
+            _ => (LineKind::Normal, line),
+        };
+        kinds.push((kind, line));
+        body.push(rest);
+    }
+
+    let mut tokenized = tokenize_lines(&body.join("\n"), language).into_iter();
+    kinds
+        .into_iter()
+        .map(|(kind, raw)| {
+            let tokens = if kind == LineKind::Header {
+                vec![Token { kind: TokenKind::Text, text: raw.to_string() }]
+            } else {
+                tokenized.next().unwrap_or_default()
+            };
+            CodeLine { kind, tokens }
+        })
+        .collect()
+}
+
+/// Text placed on the clipboard. In diff mode this is the post-change
+/// version: context and added lines, markers and headers removed.
+fn copy_text(code: &str, diff: bool) -> String {
+    if !diff {
+        return code.to_string();
+    }
+    code.trim_end_matches('\n')
+        .split('\n')
+        .filter(|line| !is_diff_header(line) && !line.starts_with('-'))
+        .map(|line| line.strip_prefix(['+', ' ']).unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn token_class(kind: TokenKind) -> &'static str {
+    match kind {
+        TokenKind::Text => "",
+        TokenKind::Keyword => s::TOKEN_KEYWORD,
+        TokenKind::String => s::TOKEN_STRING,
+        TokenKind::Comment => s::TOKEN_COMMENT,
+        TokenKind::Number => s::TOKEN_NUMBER,
+        TokenKind::Type => s::TOKEN_TYPE,
+        TokenKind::Function => s::TOKEN_FUNCTION,
+        TokenKind::Attribute => s::TOKEN_ATTRIBUTE,
+    }
+}
+
+/// Syntax-highlighted code block atom.
+///
+/// Tokenizes on every render; tokens are rendered as plain `span`s, so
+/// no HTML from `code` ever reaches the DOM unescaped.
+#[playground(
+    category = Atom,
+    description = "Syntax-highlighted code block (Rust, TOML, JSON, shell, HTML, CSS) \
+                   with theme token colours, line numbers, highlighted ranges, copy button and diff mode.",
+    examples = [
+        ("Basic", "EqCodeBlock {\n    code: \"let x = 42;\",\n    language: CodeLanguage::Rust,\n}"),
+        ("Line numbers + highlight", "EqCodeBlock {\n    code: SOURCE,\n    language: CodeLanguage::Toml,\n    line_numbers: true,\n    highlight: parse_line_ranges(\"2-3\"),\n}"),
+        ("Diff", "EqCodeBlock {\n    code: \"-let a = 1;\\n+let a = 2;\",\n    language: CodeLanguage::Rust,\n    diff: true,\n}"),
+    ],
+    custom_demo,
+    custom_gallery,
+)]
+#[component]
+pub fn EqCodeBlock(
+    /// Source code to display.
+    #[props(into)]
+    code: String,
+    /// Language used for highlighting.
+    #[props(default)]
+    language: CodeLanguage,
+    /// Header title (e.g. a file name). Defaults to the language label.
+    #[props(into, default)]
+    title: String,
+    /// Show a line-number gutter.
+    #[props(default = false)]
+    line_numbers: bool,
+    /// 1-based inclusive line ranges to emphasise.
+    #[props(default)]
+    highlight: Vec<RangeInclusive<usize>>,
+    /// Treat `code` as a unified diff: `+` / `-` lines are tinted and
+    /// `@@` hunk headers dimmed.
+    #[props(default = false)]
+    diff: bool,
+    /// Show the copy-to-clipboard button.
+    #[props(default = true)]
+    copyable: bool,
+    /// Optional class override - extend or replace default wrapper styles.
+    #[props(into, default)]
+    class: String,
+) -> Element {
+    let mut copied = use_signal(|| false);
+    let lines = build_lines(&code, language, diff);
+    let cls = merge_classes(s::WRAPPER, &class);
+    let label = if title.is_empty() { language.label().to_string() } else { title.clone() };
+    let payload = copy_text(&code, diff);
+
+    let copy = move |_| {
+        let payload = payload.clone();
+        spawn(async move {
+            let text = serde_json::to_string(&payload).unwrap_or_default();
+            let js = format!("await navigator.clipboard.writeText({text}); return true;");
+            if document::eval(&js).await.is_ok() {
+                copied.set(true);
+                let _ = document::eval("await new Promise(r => setTimeout(r, 1500)); return true;").await;
+                copied.set(false);
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "{cls}",
+            div { class: s::HEADER,
+                span { class: s::HEADER_LABEL, "{label}" }
+                if copyable {
+                    button {
+                        r#type: "button",
+                        class: s::COPY_BTN,
+                        "aria-label": "Copy code",
+                        onclick: copy,
+                        if copied() { "Copied" } else { "Copy" }
+                    }
+                    span { class: "sr-only", role: "status",
+                        if copied() { "Copied to clipboard" }
+                    }
+                }
+            }
+            pre { class: s::PRE,
+                code { class: s::CODE,
+                    for (i, line) in lines.iter().enumerate() {
+                        {render_line(i + 1, line, line_numbers, diff, highlight.iter().any(|r| r.contains(&(i + 1))))}
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_line(number: usize, line: &CodeLine, line_numbers: bool, diff: bool, highlighted: bool) -> Element {
+    let kind_cls = match line.kind {
+        LineKind::Normal => "",
+        LineKind::Added => s::LINE_ADDED,
+        LineKind::Removed => s::LINE_REMOVED,
+        LineKind::Header => s::LINE_HUNK,
+    };
+    let hl_cls = if highlighted { s::LINE_HIGHLIGHT } else { "" };
+    let (marker, marker_cls) = match line.kind {
+        LineKind::Added => ("+", s::DIFF_MARKER_ADDED),
+        LineKind::Removed => ("-", s::DIFF_MARKER_REMOVED),
+        _ => (" ", ""),
+    };
+
+    rsx! {
+        span { class: "{s::LINE} {kind_cls} {hl_cls}",
+            if line_numbers {
+                span { class: s::GUTTER, "aria-hidden": "true", "{number}" }
+            }
+            if diff && line.kind != LineKind::Header {
+                span { class: "{s::DIFF_MARKER} {marker_cls}", "{marker}" }
+            }
+            span {
+                if line.tokens.is_empty() {
+                    " "
+                }
+                for token in line.tokens.iter() {
+                    span { class: token_class(token.kind), "{token.text}" }
+                }
+            }
+        }
+    }
+}
+
+// ── Interactive demo ───────────────────────────────────────────────
+
+#[cfg(feature = "playground")]
+fn demo_sample(language: CodeLanguage) -> &'static str {
+    match language {
+        CodeLanguage::Rust => "use eq_ui::atoms::EqButton;\n\n/// Renders a greeting.\n#[component]\nfn Greeting(name: String) -> Element {\n    let count = 42u32;\n    rsx! { \"Hello, {name}!\" }\n}",
+        CodeLanguage::Toml => "[package]\nname = \"my-app\"\nversion = \"0.1.0\"\n\n[dependencies]\neq_ui = { version = \"0.5\", features = [\"icons\"] } # themed UI",
+        CodeLanguage::Json => "{\n  \"name\": \"eq_ui\",\n  \"stars\": 1200,\n  \"stable\": true,\n  \"tags\": [\"dioxus\", \"ui\"]\n}",
+        CodeLanguage::Shell => "# install the CLI\n$ cargo install dioxus-cli --locked\n$ export RUST_LOG=info\n$ dx serve --platform web | tee $HOME/dx.log",
+        CodeLanguage::Html => "<!-- card -->\n<div class=\"card\" hidden>\n  <a href=\"/docs\">Docs &amp; guides</a>\n</div>",
+        CodeLanguage::Css => "@media (min-width: 640px) {\n  .card:hover {\n    color: var(--color-accent-primary);\n    padding: 1.5rem !important;\n  }\n}",
+        CodeLanguage::Plain => "Plain text is shown as-is.\nNo highlighting applied.",
+    }
+}
+
+#[cfg(feature = "playground")]
+#[component]
+fn DemoEqCodeBlock() -> Element {
+    let mut language_str = use_signal(|| "Rust".to_string());
+    let mut line_numbers = use_signal(|| true);
+    let mut highlight = use_signal(|| "4-5".to_string());
+    let mut diff = use_signal(|| false);
+    let mut copyable = use_signal(|| true);
+
+    let language = <CodeLanguage as crate::playground_enum_trait::PlaygroundEnumInfo>::from_name(&language_str());
+    let code_sample = if diff() {
+        "@@ -1,4 +1,4 @@\n fn main() {\n-    let total = 1 + 1;\n+    let total = 40 + 2;\n     println!(\"{total}\");\n }".to_string()
+    } else {
+        demo_sample(language).to_string()
+    };
+
+    let code = r#"use eq_ui::atoms::{EqCodeBlock, CodeLanguage, parse_line_ranges};
+
+EqCodeBlock {
+    code: source,
+    language: CodeLanguage::Rust,
+    title: "main.rs",
+    line_numbers: true,
+    highlight: parse_line_ranges("4-5"),
+}
+
+// Unified diff: + / - lines are tinted, copy gives the new version
+EqCodeBlock { code: patch, language: CodeLanguage::Rust, diff: true }"#
+        .to_string();
+
+    rsx! {
+        DemoSection { title: "EqCodeBlock",
+            div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-3",
+                EqText {
+                    variant: TextVariant::Caption,
+                    class: "font-semibold uppercase tracking-wider",
+                    "Props"
+                }
+                PropSelect {
+                    label: "language",
+                    value: language_str(),
+                    options: vec!["Rust", "Toml", "Json", "Shell", "Html", "Css", "Plain"],
+                    onchange: move |v: String| language_str.set(v),
+                }
+                PropToggle { label: "line_numbers", value: line_numbers(), onchange: move |v: bool| line_numbers.set(v) }
+                PropInput {
+                    label: "highlight",
+                    value: highlight(),
+                    placeholder: "e.g. 1,3-5",
+                    onchange: move |v: String| highlight.set(v),
+                }
+                PropToggle { label: "diff", value: diff(), onchange: move |v: bool| diff.set(v) }
+                PropToggle { label: "copyable", value: copyable(), onchange: move |v: bool| copyable.set(v) }
+            }
+            EqCodeBlock {
+                code: code_sample,
+                language,
+                line_numbers: line_numbers(),
+                highlight: parse_line_ranges(&highlight()),
+                diff: diff(),
+                copyable: copyable(),
+            }
+            StyleInfo { file: "eq_code_block_styles.rs", styles: format_catalog(&s::catalog()) }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
+        }
+    }
+}
+
+// ── Gallery (compact showcase) ─────────────────────────────────────
+
+#[cfg(feature = "playground")]
+#[component]
+fn GalleryEqCodeBlock() -> Element {
+    rsx! {
+        div { class: "space-y-3",
+            EqCodeBlock {
+                code: demo_sample(CodeLanguage::Toml),
+                language: CodeLanguage::Toml,
+                title: "Cargo.toml",
+                line_numbers: true,
+                highlight: vec![6..=6],
+            }
+            EqCodeBlock {
+                code: "-color: red;\n+color: var(--color-error);",
+                language: CodeLanguage::Css,
+                diff: true,
+                copyable: false,
+            }
+        }
+    }
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smoke_renders() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                EqCodeBlock {
+                    code: "fn main() {{}}\n\nlet x = 1;",
+                    language: CodeLanguage::Rust,
+                    line_numbers: true,
+                    highlight: vec![1..=2],
+                }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn smoke_renders_diff() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! { EqCodeBlock { code: "@@ -1 +1 @@\n-a\n+b", diff: true, copyable: false } }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn language_hints() {
+        assert_eq!(CodeLanguage::from_hint("rs"), CodeLanguage::Rust);
+        assert_eq!(CodeLanguage::from_hint(" Bash "), CodeLanguage::Shell);
+        assert_eq!(CodeLanguage::from_hint("xml"), CodeLanguage::Html);
+        assert_eq!(CodeLanguage::from_hint("python"), CodeLanguage::Plain);
+    }
+
+    #[test]
+    fn line_range_parsing() {
+        assert_eq!(parse_line_ranges("{1, 3-5}"), vec![1..=1, 3..=5]);
+        assert_eq!(parse_line_ranges("0,x,5-2,7"), vec![7..=7]);
+        assert!(parse_line_ranges("").is_empty());
+    }
+
+    #[test]
+    fn diff_lines_are_classified_and_stripped() {
+        let lines = build_lines("@@ -1 +1 @@\n ctx\n-old\n+new\n", CodeLanguage::Plain, true);
+        let kinds: Vec<LineKind> = lines.iter().map(|l| l.kind).collect();
+        assert_eq!(kinds, vec![LineKind::Header, LineKind::Normal, LineKind::Removed, LineKind::Added]);
+        assert_eq!(lines[3].tokens[0].text, "new");
+        assert_eq!(lines[0].tokens[0].text, "@@ -1 +1 @@");
+    }
+
+    #[test]
+    fn diff_copy_is_new_version() {
+        assert_eq!(copy_text("@@ x @@\n keep\n-old\n+new", true), "keep\nnew");
+        assert_eq!(copy_text("-raw", false), "-raw");
+    }
+
+    #[test]
+    fn trailing_newline_adds_no_empty_line() {
+        assert_eq!(build_lines("a\nb\n", CodeLanguage::Plain, false).len(), 2);
+    }
+}
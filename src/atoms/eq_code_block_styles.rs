@@ -0,0 +1,91 @@
+//! Style constants for EqCodeBlock.
+
+/// Outer frame.
+pub const WRAPPER: &str = "rounded-lg overflow-hidden border border-[var(--color-card-border)] \
+     bg-[var(--color-code-bg)] text-[var(--color-code-text)]";
+
+/// Header bar (title / language label + copy button).
+pub const HEADER: &str = "flex items-center justify-between gap-2 px-4 py-1.5 \
+     border-b border-[var(--color-card-border)]";
+
+/// Title or language label in the header.
+pub const HEADER_LABEL: &str = "text-[10px] uppercase tracking-wider font-mono \
+     text-[var(--color-code-comment)] truncate";
+
+/// Copy-to-clipboard button.
+pub const COPY_BTN: &str = "text-xs font-mono px-2 py-0.5 rounded cursor-pointer \
+     text-[var(--color-code-comment)] hover:text-[var(--color-code-text)] \
+     hover:bg-[var(--color-code-text)]/10 transition-colors \
+     focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-[var(--color-focus-ring)]";
+
+/// Scroll container.
+pub const PRE: &str = "py-3 overflow-x-auto text-xs leading-relaxed font-mono";
+
+/// `<code>` element - lines stack as blocks so backgrounds span the width.
+pub const CODE: &str = "block min-w-max";
+
+/// One source line.
+pub const LINE: &str = "flex px-4 whitespace-pre";
+
+/// Line inside a highlighted range.
+pub const LINE_HIGHLIGHT: &str = "bg-[var(--color-accent-primary)]/10 \
+     shadow-[inset_3px_0_0_var(--color-accent-primary)]";
+
+/// Diff: added line.
+pub const LINE_ADDED: &str = "bg-[var(--color-success)]/10";
+
+/// Diff: removed line.
+pub const LINE_REMOVED: &str = "bg-[var(--color-error)]/10";
+
+/// Diff: hunk header (`@@ … @@`) or file header line.
+pub const LINE_HUNK: &str = "text-[var(--color-code-comment)] bg-[var(--color-code-text)]/5";
+
+/// Line-number gutter cell.
+pub const GUTTER: &str = "w-8 shrink-0 pr-4 text-right select-none text-[var(--color-code-comment)]";
+
+/// Diff marker cell (`+`, `-` or blank).
+pub const DIFF_MARKER: &str = "w-4 shrink-0 select-none";
+
+/// Diff marker colour for added lines.
+pub const DIFF_MARKER_ADDED: &str = "text-[var(--color-success)]";
+
+/// Diff marker colour for removed lines.
+pub const DIFF_MARKER_REMOVED: &str = "text-[var(--color-error)]";
+
+// ── Token colours ───────────────────────────────────────────────────
+
+pub const TOKEN_KEYWORD: &str = "text-[var(--color-code-keyword)]";
+pub const TOKEN_STRING: &str = "text-[var(--color-code-string)]";
+pub const TOKEN_COMMENT: &str = "text-[var(--color-code-comment)] italic";
+pub const TOKEN_NUMBER: &str = "text-[var(--color-code-number)]";
+pub const TOKEN_TYPE: &str = "text-[var(--color-code-type)]";
+pub const TOKEN_FUNCTION: &str = "text-[var(--color-code-function)]";
+pub const TOKEN_ATTRIBUTE: &str = "text-[var(--color-code-attribute)]";
+
+/// All style tokens for playground introspection.
+pub fn catalog() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("WRAPPER", WRAPPER),
+        ("HEADER", HEADER),
+        ("HEADER_LABEL", HEADER_LABEL),
+        ("COPY_BTN", COPY_BTN),
+        ("PRE", PRE),
+        ("CODE", CODE),
+        ("LINE", LINE),
+        ("LINE_HIGHLIGHT", LINE_HIGHLIGHT),
+        ("LINE_ADDED", LINE_ADDED),
+        ("LINE_REMOVED", LINE_REMOVED),
+        ("LINE_HUNK", LINE_HUNK),
+        ("GUTTER", GUTTER),
+        ("DIFF_MARKER", DIFF_MARKER),
+        ("DIFF_MARKER_ADDED", DIFF_MARKER_ADDED),
+        ("DIFF_MARKER_REMOVED", DIFF_MARKER_REMOVED),
+        ("TOKEN_KEYWORD", TOKEN_KEYWORD),
+        ("TOKEN_STRING", TOKEN_STRING),
+        ("TOKEN_COMMENT", TOKEN_COMMENT),
+        ("TOKEN_NUMBER", TOKEN_NUMBER),
+        ("TOKEN_TYPE", TOKEN_TYPE),
+        ("TOKEN_FUNCTION", TOKEN_FUNCTION),
+        ("TOKEN_ATTRIBUTE", TOKEN_ATTRIBUTE),
+    ]
+}
@@ -0,0 +1,769 @@
+//! Syntax tokenizer for EqCodeBlock.
+//!
+//! Small hand-written lexers for Rust, TOML, JSON, shell, HTML and CSS.
+//! They aim for "looks right in documentation", not for a full grammar:
+//! every input produces tokens (unknown constructs fall through as
+//! [`TokenKind::Text`]) and concatenating the token texts always gives
+//! back the original code.
+
+use super::eq_code_block::CodeLanguage;
+
+/// Semantic class of a token. Each maps to a `--color-code-*` variable.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TokenKind {
+    Text,
+    Keyword,
+    String,
+    Comment,
+    Number,
+    Type,
+    Function,
+    Attribute,
+}
+
+/// A run of source text with one highlight class.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub text: String,
+}
+
+/// Tokenizes `code` in the given language.
+pub fn tokenize(code: &str, language: CodeLanguage) -> Vec<Token> {
+    let mut lx = Lexer::new(code);
+    match language {
+        CodeLanguage::Rust => lex_rust(&mut lx),
+        CodeLanguage::Toml => lex_toml(&mut lx),
+        CodeLanguage::Json => lex_json(&mut lx),
+        CodeLanguage::Shell => lex_shell(&mut lx),
+        CodeLanguage::Html => lex_html(&mut lx),
+        CodeLanguage::Css => lex_css(&mut lx),
+        CodeLanguage::Plain => {
+            let rest = lx.rest();
+            lx.push(TokenKind::Text, rest);
+        }
+    }
+    lx.out
+}
+
+/// Tokenizes `code` and splits the result into lines. Tokens spanning a
+/// newline (block comments, multi-line strings) are cut at each line end.
+pub fn tokenize_lines(code: &str, language: CodeLanguage) -> Vec<Vec<Token>> {
+    let mut lines = vec![Vec::new()];
+    for token in tokenize(code, language) {
+        let mut parts = token.text.split('\n');
+        if let Some(first) = parts.next()
+            && !first.is_empty()
+        {
+            lines.last_mut().expect("never empty").push(Token { kind: token.kind, text: first.to_string() });
+        }
+        for part in parts {
+            let mut line = Vec::new();
+            if !part.is_empty() {
+                line.push(Token { kind: token.kind, text: part.to_string() });
+            }
+            lines.push(line);
+        }
+    }
+    lines
+}
+
+// ── Lexer core ──────────────────────────────────────────────────────
+
+struct Lexer {
+    chars: Vec<char>,
+    pos: usize,
+    out: Vec<Token>,
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_ident(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+impl Lexer {
+    fn new(code: &str) -> Self {
+        Self { chars: code.chars().collect(), pos: 0, out: Vec::new() }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn prev(&self) -> Option<char> {
+        self.pos.checked_sub(1).map(|i| self.chars[i])
+    }
+
+    fn starts_with(&self, s: &str) -> bool {
+        s.chars().enumerate().all(|(i, c)| self.peek_at(i) == Some(c))
+    }
+
+    /// Next non-whitespace character at or after `pos + offset`.
+    fn next_significant(&self, offset: usize) -> Option<char> {
+        self.chars[(self.pos + offset).min(self.chars.len())..].iter().copied().find(|c| !c.is_whitespace())
+    }
+
+    fn rest(&mut self) -> String {
+        let s: String = self.chars[self.pos..].iter().collect();
+        self.pos = self.chars.len();
+        s
+    }
+
+    /// Appends text, merging with the previous token of the same kind.
+    fn push(&mut self, kind: TokenKind, text: String) {
+        if text.is_empty() {
+            return;
+        }
+        match self.out.last_mut() {
+            Some(last) if last.kind == kind => last.text.push_str(&text),
+            _ => self.out.push(Token { kind, text }),
+        }
+    }
+
+    /// Consumes `n` characters as one token.
+    fn take(&mut self, kind: TokenKind, n: usize) {
+        let end = (self.pos + n).min(self.chars.len());
+        let text = self.chars[self.pos..end].iter().collect();
+        self.pos = end;
+        self.push(kind, text);
+    }
+
+    fn take_while(&mut self, pred: impl Fn(char) -> bool) -> String {
+        let start = self.pos;
+        while self.peek().is_some_and(&pred) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    /// Consumes up to (and including) `end`, or to end of input.
+    fn take_until(&mut self, kind: TokenKind, end: &str) {
+        let start = self.pos;
+        while self.pos < self.chars.len() && !self.starts_with(end) {
+            self.pos += 1;
+        }
+        self.pos = (self.pos + end.chars().count()).min(self.chars.len());
+        let text = self.chars[start..self.pos].iter().collect();
+        self.push(kind, text);
+    }
+
+    fn line_comment(&mut self) {
+        let text = self.take_while(|c| c != '\n');
+        self.push(TokenKind::Comment, text);
+    }
+
+    /// A quoted string starting at the current quote character.
+    fn string(&mut self, kind: TokenKind, escapes: bool) {
+        let quote = self.chars[self.pos];
+        let start = self.pos;
+        self.pos += 1;
+        while let Some(c) = self.peek() {
+            self.pos += 1;
+            if escapes && c == '\\' {
+                self.pos = (self.pos + 1).min(self.chars.len());
+            } else if c == quote {
+                break;
+            }
+        }
+        let text = self.chars[start..self.pos].iter().collect();
+        self.push(kind, text);
+    }
+
+    /// Numeric literal: digits plus any trailing alphanumerics, `.` and
+    /// `_` (covers `0x1F`, `1_000u32`, `2.5e3`, `12px`).
+    fn number(&mut self) {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            let dot = c == '.' && self.peek_at(1).is_some_and(|n| n.is_ascii_digit());
+            if c.is_alphanumeric() || c == '_' || dot {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        let text = self.chars[start..self.pos].iter().collect();
+        self.push(TokenKind::Number, text);
+    }
+
+    fn text_char(&mut self) {
+        self.take(TokenKind::Text, 1);
+    }
+}
+
+// ── Rust ────────────────────────────────────────────────────────────
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+    "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+    "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true",
+    "type", "unsafe", "use", "where", "while", "yield",
+];
+
+const RUST_PRIMITIVES: &[&str] = &[
+    "bool", "char", "str", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64",
+    "i128", "isize", "f32", "f64",
+];
+
+fn lex_rust(lx: &mut Lexer) {
+    while let Some(c) = lx.peek() {
+        match c {
+            '/' if lx.peek_at(1) == Some('/') => lx.line_comment(),
+            '/' if lx.peek_at(1) == Some('*') => lx.take_until(TokenKind::Comment, "*/"),
+            '"' => lx.string(TokenKind::String, true),
+            'r' if lx.peek_at(1) == Some('"') || (lx.peek_at(1) == Some('#') && matches!(lx.peek_at(2), Some('"' | '#'))) => {
+                // Raw string: r"…", r#"…"#, r##"…"##
+                let hashes = lx.chars[lx.pos + 1..].iter().take_while(|c| **c == '#').count();
+                if lx.peek_at(1 + hashes) != Some('"') {
+                    lx.text_char();
+                    continue;
+                }
+                let start = lx.pos;
+                lx.pos += 2 + hashes;
+                let close: String = std::iter::once('"').chain(std::iter::repeat_n('#', hashes)).collect();
+                while lx.pos < lx.chars.len() && !lx.starts_with(&close) {
+                    lx.pos += 1;
+                }
+                lx.pos = (lx.pos + close.len()).min(lx.chars.len());
+                let text = lx.chars[start..lx.pos].iter().collect();
+                lx.push(TokenKind::String, text);
+            }
+            'b' if lx.peek_at(1) == Some('"') => {
+                lx.take(TokenKind::String, 1);
+                lx.string(TokenKind::String, true);
+            }
+            '\'' => {
+                // Char literal ('a', '\n') or lifetime ('a).
+                if lx.peek_at(1) == Some('\\') || lx.peek_at(2) == Some('\'') {
+                    lx.string(TokenKind::String, true);
+                } else {
+                    lx.take(TokenKind::Type, 1);
+                    let name = lx.take_while(is_ident);
+                    lx.push(TokenKind::Type, name);
+                }
+            }
+            '#' if matches!(lx.peek_at(1), Some('[' | '!')) => {
+                let start = lx.pos;
+                let mut depth = 0;
+                while let Some(c) = lx.peek() {
+                    lx.pos += 1;
+                    match c {
+                        '[' => depth += 1,
+                        ']' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        '\n' if depth == 0 => break,
+                        _ => {}
+                    }
+                }
+                let text = lx.chars[start..lx.pos].iter().collect();
+                lx.push(TokenKind::Attribute, text);
+            }
+            c if c.is_ascii_digit() && !lx.prev().is_some_and(is_ident) => lx.number(),
+            c if is_ident_start(c) => {
+                let word = lx.take_while(is_ident);
+                let kind = if lx.peek() == Some('!') && lx.peek_at(1) != Some('=') {
+                    lx.pos += 1;
+                    lx.push(TokenKind::Function, format!("{word}!"));
+                    continue;
+                } else if RUST_KEYWORDS.contains(&word.as_str()) {
+                    TokenKind::Keyword
+                } else if RUST_PRIMITIVES.contains(&word.as_str())
+                    || word.starts_with(|c: char| c.is_uppercase())
+                {
+                    TokenKind::Type
+                } else if lx.peek() == Some('(') || lx.starts_with("::<") || after_fn_keyword(lx) {
+                    TokenKind::Function
+                } else {
+                    TokenKind::Text
+                };
+                lx.push(kind, word);
+            }
+            _ => lx.text_char(),
+        }
+    }
+}
+
+/// Whether the previous significant token is the `fn` keyword.
+fn after_fn_keyword(lx: &Lexer) -> bool {
+    let mut tokens = lx.out.iter().rev();
+    let last = match tokens.next() {
+        Some(t) if t.kind == TokenKind::Text && t.text.trim().is_empty() => tokens.next(),
+        other => other,
+    };
+    last.is_some_and(|t| t.kind == TokenKind::Keyword && t.text.ends_with("fn"))
+}
+
+// ── TOML ────────────────────────────────────────────────────────────
+
+fn lex_toml(lx: &mut Lexer) {
+    let mut line_start = true;
+    while let Some(c) = lx.peek() {
+        match c {
+            '\n' => {
+                lx.text_char();
+                line_start = true;
+                continue;
+            }
+            ' ' | '\t' => {
+                lx.text_char();
+                continue;
+            }
+            '#' => lx.line_comment(),
+            '[' if line_start => {
+                // [table] or [[array.of.tables]]
+                let text = lx.take_while(|c| c != '\n' && c != '#');
+                let header = text.trim_end();
+                lx.push(TokenKind::Type, header.to_string());
+                lx.push(TokenKind::Text, text[header.len()..].to_string());
+            }
+            '"' | '\'' => {
+                let triple: String = std::iter::repeat_n(c, 3).collect();
+                let kind = if line_start { TokenKind::Attribute } else { TokenKind::String };
+                if lx.starts_with(&triple) {
+                    lx.take(TokenKind::String, 3);
+                    lx.take_until(TokenKind::String, &triple);
+                } else {
+                    lx.string(kind, c == '"');
+                }
+            }
+            c if c.is_ascii_digit() || ((c == '+' || c == '-') && lx.peek_at(1).is_some_and(|n| n.is_ascii_digit())) => {
+                let text = lx.take_while(|c| c.is_alphanumeric() || "._-:+".contains(c));
+                lx.push(TokenKind::Number, text);
+            }
+            c if is_ident_start(c) => {
+                let word = lx.take_while(|c| is_ident(c) || c == '-' || c == '.');
+                let kind = if lx.next_significant(0) == Some('=') {
+                    TokenKind::Attribute
+                } else if matches!(word.as_str(), "true" | "false" | "inf" | "nan") {
+                    TokenKind::Keyword
+                } else {
+                    TokenKind::Text
+                };
+                lx.push(kind, word);
+            }
+            _ => lx.text_char(),
+        }
+        line_start = false;
+    }
+}
+
+// ── JSON ────────────────────────────────────────────────────────────
+
+fn lex_json(lx: &mut Lexer) {
+    while let Some(c) = lx.peek() {
+        match c {
+            '"' => {
+                let kind = if json_key_follows(lx) { TokenKind::Attribute } else { TokenKind::String };
+                lx.string(kind, true);
+            }
+            '/' if lx.peek_at(1) == Some('/') => lx.line_comment(),
+            '/' if lx.peek_at(1) == Some('*') => lx.take_until(TokenKind::Comment, "*/"),
+            c if c.is_ascii_digit() || (c == '-' && lx.peek_at(1).is_some_and(|n| n.is_ascii_digit())) => {
+                let text = lx.take_while(|c| c.is_ascii_alphanumeric() || "+-.".contains(c));
+                lx.push(TokenKind::Number, text);
+            }
+            c if c.is_ascii_alphabetic() => {
+                let word = lx.take_while(|c| c.is_ascii_alphanumeric());
+                let kind = if matches!(word.as_str(), "true" | "false" | "null") {
+                    TokenKind::Keyword
+                } else {
+                    TokenKind::Text
+                };
+                lx.push(kind, word);
+            }
+            _ => lx.text_char(),
+        }
+    }
+}
+
+/// Whether the string starting at the cursor is an object key.
+fn json_key_follows(lx: &Lexer) -> bool {
+    let mut i = lx.pos + 1;
+    while let Some(&c) = lx.chars.get(i) {
+        i += 1;
+        match c {
+            '\\' => i += 1,
+            '"' => break,
+            _ => {}
+        }
+    }
+    lx.chars[i.min(lx.chars.len())..].iter().find(|c| !c.is_whitespace()) == Some(&':')
+}
+
+// ── Shell ───────────────────────────────────────────────────────────
+
+const SHELL_KEYWORDS: &[&str] = &[
+    "if", "then", "else", "elif", "fi", "for", "in", "do", "done", "while", "until", "case",
+    "esac", "function", "return", "export", "local", "source", "sudo", "time", "exit",
+];
+
+fn lex_shell(lx: &mut Lexer) {
+    // Whether the next word is in command position.
+    let mut command = true;
+    let mut line_start = true;
+    while let Some(c) = lx.peek() {
+        let at_line_start = line_start;
+        line_start = false;
+        match c {
+            '\n' => {
+                lx.text_char();
+                command = true;
+                line_start = true;
+            }
+            ' ' | '\t' => {
+                lx.text_char();
+                line_start = at_line_start;
+            }
+            // Console prompt: `$ cargo build`
+            '$' | '>' if at_line_start && lx.peek_at(1) == Some(' ') => lx.take(TokenKind::Comment, 2),
+            '#' if lx.prev().is_none_or(char::is_whitespace) => lx.line_comment(),
+            '"' => {
+                lx.string(TokenKind::String, true);
+                command = false;
+            }
+            '\'' => {
+                lx.string(TokenKind::String, false);
+                command = false;
+            }
+            '$' if lx.peek_at(1) == Some('{') => lx.take_until(TokenKind::Type, "}"),
+            '$' if lx.peek_at(1).is_some_and(|n| is_ident(n) || "?@#$!*".contains(n)) => {
+                lx.take(TokenKind::Type, 1);
+                let name = if lx.peek().is_some_and(is_ident) {
+                    lx.take_while(is_ident)
+                } else {
+                    lx.pos += 1;
+                    lx.chars[lx.pos - 1].to_string()
+                };
+                lx.push(TokenKind::Type, name);
+                command = false;
+            }
+            '|' | ';' | '&' | '(' | '`' => {
+                lx.text_char();
+                command = true;
+            }
+            '-' if lx.prev().is_none_or(char::is_whitespace) && !command => {
+                let flag = lx.take_while(|c| !c.is_whitespace() && c != '=' && c != ';' && c != '|');
+                lx.push(TokenKind::Attribute, flag);
+            }
+            c if c.is_ascii_digit() && lx.prev().is_none_or(|p| !is_ident(p) && p != '-' && p != '.') => {
+                let text = lx.take_while(|c| c.is_ascii_digit());
+                lx.push(TokenKind::Number, text);
+            }
+            c if is_ident_start(c) || c == '.' || c == '/' || c == '~' => {
+                let word = lx.take_while(|c| !c.is_whitespace() && !"|;&()`\"'$=".contains(c));
+                if lx.peek() == Some('=') && command {
+                    // Variable assignment: NAME=value
+                    lx.push(TokenKind::Type, word);
+                    lx.text_char();
+                } else if SHELL_KEYWORDS.contains(&word.as_str()) {
+                    lx.push(TokenKind::Keyword, word);
+                    command = true;
+                } else if command {
+                    lx.push(TokenKind::Function, word);
+                    command = false;
+                } else {
+                    lx.push(TokenKind::Text, word);
+                }
+            }
+            _ => lx.text_char(),
+        }
+    }
+}
+
+// ── HTML ────────────────────────────────────────────────────────────
+
+fn lex_html(lx: &mut Lexer) {
+    while let Some(c) = lx.peek() {
+        if lx.starts_with("<!--") {
+            lx.take_until(TokenKind::Comment, "-->");
+        } else if c == '<' && lx.peek_at(1) == Some('!') {
+            lx.take_until(TokenKind::Comment, ">");
+        } else if c == '<' && lx.peek_at(1).is_some_and(|n| n == '/' || n.is_ascii_alphabetic()) {
+            lx.text_char();
+            if lx.peek() == Some('/') {
+                lx.text_char();
+            }
+            let name = lx.take_while(|c| c.is_ascii_alphanumeric() || c == '-');
+            lx.push(TokenKind::Keyword, name);
+            html_attributes(lx);
+        } else if c == '&' {
+            let len = lx.chars[lx.pos..].iter().take(12).position(|c| *c == ';');
+            match len {
+                Some(n) if n > 1 => lx.take(TokenKind::Number, n + 1),
+                _ => lx.text_char(),
+            }
+        } else {
+            let text = lx.take_while(|c| c != '<' && c != '&');
+            if text.is_empty() {
+                // A `<` that opens no tag, e.g. `a < b`.
+                lx.text_char();
+            } else {
+                lx.push(TokenKind::Text, text);
+            }
+        }
+    }
+}
+
+/// Attributes inside a tag, up to and including the closing `>`.
+fn html_attributes(lx: &mut Lexer) {
+    while let Some(c) = lx.peek() {
+        match c {
+            '>' => {
+                lx.text_char();
+                return;
+            }
+            '"' | '\'' => lx.string(TokenKind::String, false),
+            '=' => {
+                lx.text_char();
+                if lx.peek().is_some_and(|n| !n.is_whitespace() && n != '"' && n != '\'' && n != '>') {
+                    let value = lx.take_while(|c| !c.is_whitespace() && c != '>');
+                    lx.push(TokenKind::String, value);
+                }
+            }
+            c if c.is_whitespace() || c == '/' => lx.text_char(),
+            _ => {
+                let name = lx.take_while(|c| !c.is_whitespace() && !"=>/\"'".contains(c));
+                if name.is_empty() {
+                    lx.text_char();
+                } else {
+                    lx.push(TokenKind::Attribute, name);
+                }
+            }
+        }
+    }
+}
+
+// ── CSS ─────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, PartialEq)]
+enum CssState {
+    /// Start of a rule or declaration; not yet classified.
+    Start,
+    Selector,
+    Property,
+    Value,
+}
+
+fn lex_css(lx: &mut Lexer) {
+    let mut state = CssState::Start;
+    while let Some(c) = lx.peek() {
+        if lx.starts_with("/*") {
+            lx.take_until(TokenKind::Comment, "*/");
+            continue;
+        }
+        if c.is_whitespace() {
+            lx.text_char();
+            continue;
+        }
+        if state == CssState::Start {
+            // A rule if `{` comes before `;` / `}`, otherwise a declaration.
+            let next = lx.chars[lx.pos..].iter().find(|c| matches!(c, '{' | ';' | '}'));
+            state = if next == Some(&'{') || c == '@' { CssState::Selector } else { CssState::Property };
+        }
+        match c {
+            '{' | ';' | '}' => {
+                lx.text_char();
+                state = CssState::Start;
+            }
+            '"' | '\'' => lx.string(TokenKind::String, true),
+            '@' => {
+                lx.pos += 1;
+                let word = lx.take_while(|c| c.is_alphanumeric() || c == '-');
+                lx.push(TokenKind::Keyword, format!("@{word}"));
+            }
+            ':' if state == CssState::Property => {
+                lx.text_char();
+                state = CssState::Value;
+            }
+            '!' if state == CssState::Value => {
+                lx.pos += 1;
+                let word = lx.take_while(|c| c.is_alphanumeric());
+                lx.push(TokenKind::Keyword, format!("!{word}"));
+            }
+            '#' if state == CssState::Value => {
+                let start = lx.pos;
+                lx.pos += 1;
+                lx.take_while(|c| c.is_ascii_hexdigit());
+                let text = lx.chars[start..lx.pos].iter().collect();
+                lx.push(TokenKind::Number, text);
+            }
+            c if state == CssState::Value
+                && (c.is_ascii_digit()
+                    || ((c == '.' || c == '-') && lx.peek_at(1).is_some_and(|n| n.is_ascii_digit()))) =>
+            {
+                lx.pos += 1;
+                let rest = lx.take_while(|c| c.is_alphanumeric() || c == '.' || c == '%');
+                lx.push(TokenKind::Number, format!("{c}{rest}"));
+            }
+            _ => match state {
+                CssState::Selector => {
+                    let text = lx.take_while(|c| !c.is_whitespace() && !"{,>+~;}".contains(c));
+                    if text.is_empty() {
+                        lx.text_char();
+                    } else {
+                        lx.push(TokenKind::Type, text);
+                    }
+                }
+                CssState::Property => {
+                    let text = lx.take_while(|c| !c.is_whitespace() && !":;}".contains(c));
+                    if text.is_empty() {
+                        lx.text_char();
+                    } else {
+                        lx.push(TokenKind::Attribute, text);
+                    }
+                }
+                _ => {
+                    if c.is_alphabetic() || c == '-' {
+                        let word = lx.take_while(|c| c.is_alphanumeric() || c == '-' || c == '_');
+                        let kind = if lx.peek() == Some('(') { TokenKind::Function } else { TokenKind::Text };
+                        lx.push(kind, word);
+                    } else {
+                        lx.text_char();
+                    }
+                }
+            },
+        }
+    }
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(code: &str, lang: CodeLanguage) -> Vec<(TokenKind, String)> {
+        tokenize(code, lang)
+            .into_iter()
+            .filter(|t| t.kind != TokenKind::Text)
+            .map(|t| (t.kind, t.text))
+            .collect()
+    }
+
+    fn has(code: &str, lang: CodeLanguage, kind: TokenKind, text: &str) -> bool {
+        kinds(code, lang).iter().any(|(k, t)| *k == kind && t == text)
+    }
+
+    #[test]
+    fn round_trips_every_language() {
+        let samples = [
+            (CodeLanguage::Rust, "fn main() { let s = r#\"x\"#; /* c */ 'a'; }"),
+            (CodeLanguage::Toml, "[pkg]\nname = \"x\" # c\nv = 1.0\ns = \"\"\"\nmulti\n\"\"\""),
+            (CodeLanguage::Json, "{\"a\": [1, -2.5e3, true, null]}"),
+            (CodeLanguage::Shell, "$ FOO=1 cargo build --release | grep \"x\" # note"),
+            (CodeLanguage::Html, "<!-- c --><a href=\"/\" data-x=y>&amp; hi</a>"),
+            (CodeLanguage::Css, "@media (min-width: 10px) { .a:hover { color: #fff !important; } }"),
+            (CodeLanguage::Plain, "anything <at> all"),
+            (CodeLanguage::Rust, "unterminated \"string and /* comment"),
+            (CodeLanguage::Toml, "[tab]\t\nk = 1"),
+        ];
+        for (lang, code) in samples {
+            let joined: String = tokenize(code, lang).into_iter().map(|t| t.text).collect();
+            assert_eq!(joined, code, "{lang:?}");
+        }
+    }
+
+    #[test]
+    fn rust_tokens() {
+        let code = "#[derive(Debug)]\npub fn run<'a>(x: &'a str) -> u32 { println!(\"hi\"); Vec::new(); 42 } // done";
+        let r = CodeLanguage::Rust;
+        assert!(has(code, r, TokenKind::Attribute, "#[derive(Debug)]"));
+        assert!(has(code, r, TokenKind::Keyword, "pub"));
+        assert!(has(code, r, TokenKind::Function, "run"));
+        assert!(has(code, r, TokenKind::Type, "'a"));
+        assert!(has(code, r, TokenKind::Type, "u32"));
+        assert!(has(code, r, TokenKind::Function, "println!"));
+        assert!(has(code, r, TokenKind::String, "\"hi\""));
+        assert!(has(code, r, TokenKind::Type, "Vec"));
+        assert!(has(code, r, TokenKind::Number, "42"));
+        assert!(has(code, r, TokenKind::Comment, "// done"));
+    }
+
+    #[test]
+    fn rust_char_literal_vs_lifetime() {
+        assert!(has("let c = 'x';", CodeLanguage::Rust, TokenKind::String, "'x'"));
+        assert!(has("let c = '\\n';", CodeLanguage::Rust, TokenKind::String, "'\\n'"));
+        assert!(has("&'static str", CodeLanguage::Rust, TokenKind::Type, "'static"));
+    }
+
+    #[test]
+    fn toml_tokens() {
+        let code = "[dependencies]\ndioxus = { version = \"0.7\", features = [\"web\"] }\ndebug = true";
+        let t = CodeLanguage::Toml;
+        assert!(has(code, t, TokenKind::Type, "[dependencies]"));
+        assert!(has(code, t, TokenKind::Attribute, "dioxus"));
+        assert!(has(code, t, TokenKind::Attribute, "version"));
+        assert!(has(code, t, TokenKind::String, "\"0.7\""));
+        assert!(has(code, t, TokenKind::Keyword, "true"));
+    }
+
+    #[test]
+    fn json_keys_are_attributes() {
+        let code = "{ \"name\": \"eq\", \"n\": 3 }";
+        assert!(has(code, CodeLanguage::Json, TokenKind::Attribute, "\"name\""));
+        assert!(has(code, CodeLanguage::Json, TokenKind::String, "\"eq\""));
+        assert!(has(code, CodeLanguage::Json, TokenKind::Number, "3"));
+    }
+
+    #[test]
+    fn shell_tokens() {
+        let code = "$ export PATH=$HOME/bin\n$ cargo run --release && echo \"ok\"";
+        let s = CodeLanguage::Shell;
+        assert!(has(code, s, TokenKind::Comment, "$ "));
+        assert!(has(code, s, TokenKind::Keyword, "export"));
+        assert!(has(code, s, TokenKind::Type, "$HOME"));
+        assert!(has(code, s, TokenKind::Function, "cargo"));
+        assert!(has(code, s, TokenKind::Attribute, "--release"));
+        assert!(has(code, s, TokenKind::Function, "echo"));
+        assert!(!has(code, s, TokenKind::Function, "run"));
+    }
+
+    #[test]
+    fn html_tokens() {
+        let code = "<div class=\"a\" hidden>x &lt; y</div>";
+        let h = CodeLanguage::Html;
+        assert!(has(code, h, TokenKind::Keyword, "div"));
+        assert!(has(code, h, TokenKind::Attribute, "class"));
+        assert!(has(code, h, TokenKind::Attribute, "hidden"));
+        assert!(has(code, h, TokenKind::String, "\"a\""));
+        assert!(has(code, h, TokenKind::Number, "&lt;"));
+    }
+
+    #[test]
+    fn html_bare_less_than_is_text() {
+        for code in ["a < b", "x<3", "<", "<<p>"] {
+            let text: String = tokenize(code, CodeLanguage::Html).into_iter().map(|t| t.text).collect();
+            assert_eq!(text, code);
+        }
+    }
+
+    #[test]
+    fn css_tokens() {
+        let code = ".btn:hover, a { color: var(--x); margin: -2px 1.5rem; }";
+        let c = CodeLanguage::Css;
+        assert!(has(code, c, TokenKind::Type, ".btn:hover"));
+        assert!(has(code, c, TokenKind::Type, "a"));
+        assert!(has(code, c, TokenKind::Attribute, "color"));
+        assert!(has(code, c, TokenKind::Function, "var"));
+        assert!(has(code, c, TokenKind::Number, "-2px"));
+        assert!(has(code, c, TokenKind::Number, "1.5rem"));
+    }
+
+    #[test]
+    fn lines_split_multiline_tokens() {
+        let lines = tokenize_lines("a /* one\ntwo */ b\n", CodeLanguage::Rust);
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[1][0], Token { kind: TokenKind::Comment, text: "two */".into() });
+        assert!(lines[2].is_empty());
+    }
+}
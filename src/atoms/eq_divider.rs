@@ -5,7 +5,7 @@ use dioxus::prelude::*;
 
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropSelect, StyleInfo, format_catalog,
+    DemoSection, PropSelect, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
@@ -5,9 +5,11 @@ use crate::{PlaygroundEnum, playground};
 
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropSelect, PropToggle, StyleInfo, format_catalog,
+    DemoSection, PropSelect, PropToggle, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
 #[cfg(feature = "playground")]
 use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
@@ -21,12 +23,81 @@ pub enum IconSize {
     Lg,
 }
 
-/// Atomic icon wrapper with two rendering modes:
+/// Presentation attributes of an [`IconData`] or one of its paths.
+/// Empty strings inherit (from the icon, then from the wrapper's
+/// `fill="currentColor"`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IconStyle {
+    pub fill: &'static str,
+    pub stroke: &'static str,
+    pub stroke_width: &'static str,
+    pub stroke_linecap: &'static str,
+    pub stroke_linejoin: &'static str,
+    pub fill_rule: &'static str,
+    pub opacity: &'static str,
+    pub transform: &'static str,
+}
+
+impl IconStyle {
+    /// Nothing set - everything inherits.
+    pub const INHERIT: IconStyle = IconStyle {
+        fill: "",
+        stroke: "",
+        stroke_width: "",
+        stroke_linecap: "",
+        stroke_linejoin: "",
+        fill_rule: "",
+        opacity: "",
+        transform: "",
+    };
+}
+
+impl Default for IconStyle {
+    fn default() -> Self {
+        Self::INHERIT
+    }
+}
+
+/// One shape of an [`IconData`], as path data.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IconPath {
+    pub d: &'static str,
+    pub style: IconStyle,
+}
+
+/// A complete icon: its own `viewBox`, root styling and every shape.
+///
+/// Usually generated from SVG files by `eq_ui_build::generate_icon_data`,
+/// which converts circles, rects, polylines and the like to paths and
+/// folds group styling into each one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IconData {
+    pub view_box: &'static str,
+    pub style: IconStyle,
+    pub paths: &'static [IconPath],
+}
+
+/// `Some(value)` for attributes that are set.
+fn set(value: &'static str) -> Option<&'static str> {
+    (!value.is_empty()).then_some(value)
+}
+
+/// Atomic icon wrapper with four rendering modes:
 ///
 /// **Path mode** - pass an SVG path data string via the `path` prop.
 /// The component renders an inline `<svg>` with `fill="currentColor"`,
 /// so color is controlled by the wrapper's text color class.
 ///
+/// **Icon mode** - pass an [`IconData`] via the `icon` prop. Multi-path,
+/// stroke-based and non-square icons render with their own `viewBox`
+/// and styling; unset colours still follow `currentColor`. Anything
+/// converting into `IconData` works too, such as the `Icon` enum from
+/// `eq_ui_build::generate_icon_sprite`.
+///
+/// **Symbol mode** - pass the id of a `<symbol>` in a sprite mounted
+/// elsewhere on the page via the `symbol` prop; the icon is drawn with
+/// `<use>`, so its markup is not repeated.
+///
 /// **Children mode** - pass any element (custom SVG, `<img>`, etc.)
 /// as children. The wrapper applies consistent sizing and color.
 #[playground(
@@ -35,6 +106,8 @@ pub enum IconSize {
                    Configurable sizes with optional muted variant.",
     examples = [
         ("With path", "EqIcon { path: \"M12 4.5v15m7.5-7.5h-15\", size: IconSize::Sm }"),
+        ("With IconData", "use eq_ui::atoms::{IconData, IconPath, IconStyle};\ninclude!(concat!(env!(\"OUT_DIR\"), \"/my_icons.rs\"));\n\nEqIcon { icon: CLOCK, size: IconSize::Lg }"),
+        ("With icon enum", "// build.rs: eq_ui_build::generate_icon_sprite(\"assets/icons\", \"icons.rs\")\nEqIcon { icon: Icon::Star }\n\n// Or by reference into the mounted ICON_SPRITE\nEqIcon { symbol: Icon::Star.id() }"),
         ("With SVG children", "EqIcon { size: IconSize::Md,\n    svg { /* custom svg */ }\n}"),
         ("Muted", "EqIcon { size: IconSize::Lg, muted: true,\n    svg { /* icon */ }\n}"),
     ],
@@ -47,6 +120,15 @@ pub fn EqIcon(
     /// rendered automatically. Mutually exclusive with children.
     #[props(into, default)]
     path: String,
+    /// Structured icon with its own `viewBox` and per-path styling.
+    /// Takes precedence over `symbol`, `path` and children.
+    #[props(default, into)]
+    icon: Option<IconData>,
+    /// Id of a sprite `<symbol>` to reference, e.g. `Icon::Star.id()`.
+    /// The sprite itself (`ICON_SPRITE`) must be mounted once, for
+    /// example with `div { dangerous_inner_html: ICON_SPRITE }`.
+    #[props(into, default)]
+    symbol: String,
     #[props(default)]
     size: IconSize,
     #[props(default = false)]
@@ -92,7 +174,43 @@ pub fn EqIcon(
             "aria-hidden": if is_decorative { "true" } else { "" },
             "aria-label": if !is_decorative { "{aria_label}" } else { "" },
             role: if !is_decorative { "img" } else { "" },
-            if !path.is_empty() {
+            if let Some(icon) = icon {
+                svg {
+                    xmlns: "http://www.w3.org/2000/svg",
+                    view_box: "{icon.view_box}",
+                    fill: set(icon.style.fill).unwrap_or("currentColor"),
+                    stroke: set(icon.style.stroke),
+                    stroke_width: set(icon.style.stroke_width),
+                    stroke_linecap: set(icon.style.stroke_linecap),
+                    stroke_linejoin: set(icon.style.stroke_linejoin),
+                    fill_rule: set(icon.style.fill_rule),
+                    opacity: set(icon.style.opacity),
+                    transform: set(icon.style.transform),
+                    class: "w-full h-full",
+                    "aria-hidden": "true",
+                    for p in icon.paths.iter() {
+                        path {
+                            d: p.d,
+                            fill: set(p.style.fill),
+                            stroke: set(p.style.stroke),
+                            stroke_width: set(p.style.stroke_width),
+                            stroke_linecap: set(p.style.stroke_linecap),
+                            stroke_linejoin: set(p.style.stroke_linejoin),
+                            fill_rule: set(p.style.fill_rule),
+                            opacity: set(p.style.opacity),
+                            transform: set(p.style.transform),
+                        }
+                    }
+                }
+            } else if !symbol.is_empty() {
+                svg {
+                    xmlns: "http://www.w3.org/2000/svg",
+                    fill: "currentColor",
+                    class: "w-full h-full",
+                    "aria-hidden": "true",
+                    r#use { href: "#{symbol}" }
+                }
+            } else if !path.is_empty() {
                 svg {
                     xmlns: "http://www.w3.org/2000/svg",
                     view_box: "0 0 256 256",
@@ -110,6 +228,25 @@ pub fn EqIcon(
 
 // ── Custom demo (SVG children needed) ────────────────────────────
 
+/// A stroke-based, multi-element icon on a 24px grid - what
+/// `eq_ui_build::generate_icon_data` emits for a Lucide-style clock.
+#[cfg(feature = "playground")]
+const DEMO_CLOCK: IconData = IconData {
+    view_box: "0 0 24 24",
+    style: IconStyle {
+        fill: "none",
+        stroke: "currentColor",
+        stroke_width: "2",
+        stroke_linecap: "round",
+        stroke_linejoin: "round",
+        ..IconStyle::INHERIT
+    },
+    paths: &[
+        IconPath { d: "M2,12a10,10 0 1,0 20,0a10,10 0 1,0 -20,0Z", style: IconStyle::INHERIT },
+        IconPath { d: "M12,6L12,12L16,14", style: IconStyle::INHERIT },
+    ],
+};
+
 #[cfg(feature = "playground")]
 #[component]
 fn DemoEqIcon() -> Element {
@@ -122,7 +259,7 @@ fn DemoEqIcon() -> Element {
         _ => IconSize::Md,
     };
 
-    let code = "EqIcon { size: IconSize::Sm,\n    svg { /* your SVG icon */ }\n}\n\nEqIcon { size: IconSize::Lg, muted: true,\n    svg { /* dimmed icon */ }\n}".to_string();
+    let code = "EqIcon { size: IconSize::Sm,\n    svg { /* your SVG icon */ }\n}\n\nEqIcon { size: IconSize::Lg, muted: true,\n    svg { /* dimmed icon */ }\n}\n\n// Generated by eq_ui_build::generate_icon_data\nEqIcon { icon: CLOCK, size: IconSize::Lg }".to_string();
 
     rsx! {
         DemoSection { title: "EqIcon",
@@ -144,7 +281,8 @@ fn DemoEqIcon() -> Element {
                     onchange: move |v: bool| muted.set(v),
                 }
             }
-            div { class: "rounded-lg border border-dashed border-[var(--color-card-border)] p-6",
+            div { class: "rounded-lg border border-dashed border-[var(--color-card-border)] p-6 flex items-center gap-6",
+                EqIcon { size: size.clone(), muted: muted(), icon: DEMO_CLOCK }
                 EqIcon { size, muted: muted(),
                     svg {
                         xmlns: "http://www.w3.org/2000/svg",
@@ -157,7 +295,7 @@ fn DemoEqIcon() -> Element {
                 }
             }
             StyleInfo { file: "eq_icon_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
@@ -201,6 +339,55 @@ mod tests {
         dom.rebuild_in_place();
     }
 
+    #[test]
+    fn smoke_renders_icon_data() {
+        const ICON: IconData = IconData {
+            view_box: "0 0 24 24",
+            style: IconStyle { fill: "none", stroke: "currentColor", ..IconStyle::INHERIT },
+            paths: &[
+                IconPath { d: "M2,12a10,10 0 1,0 20,0a10,10 0 1,0 -20,0Z", style: IconStyle::INHERIT },
+                IconPath {
+                    d: "M12,6L12,12",
+                    style: IconStyle { fill_rule: "evenodd", transform: "rotate(45 12 12)", ..IconStyle::INHERIT },
+                },
+            ],
+        };
+        let mut dom = VirtualDom::new(|| rsx! { EqIcon { icon: ICON, aria_label: "Clock" } });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn smoke_renders_converted_icon_and_symbol() {
+        #[derive(Clone, Copy)]
+        enum Icon {
+            Dot,
+        }
+        impl From<Icon> for IconData {
+            fn from(icon: Icon) -> Self {
+                match icon {
+                    Icon::Dot => IconData {
+                        view_box: "0 0 10 10",
+                        style: IconStyle::INHERIT,
+                        paths: &[IconPath { d: "M4 5h2", style: IconStyle::INHERIT }],
+                    },
+                }
+            }
+        }
+        // What `eq_ui_build` generates next to the enum.
+        impl From<Icon> for Option<IconData> {
+            fn from(icon: Icon) -> Self {
+                Some(icon.into())
+            }
+        }
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                EqIcon { icon: Icon::Dot }
+                EqIcon { symbol: "icon-dot", aria_label: "Dot" }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
     #[test]
     fn default_size_is_md() {
         let s: IconSize = Default::default();
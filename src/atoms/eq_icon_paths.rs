@@ -3,8 +3,8 @@
 //! ## Bundled icons
 //!
 //! Auto-generated at compile time by `build.rs` from the SVG files in
-//! `assets/icons/`. Each constant contains the `d` attribute value from
-//! the corresponding SVG `<path>` element.
+//! `assets/icons/`. Each constant contains the path data of every shape
+//! in the corresponding SVG file.
 //!
 //! To add a new icon to the library:
 //! 1. Copy the SVG file into `assets/icons/`
@@ -50,6 +50,14 @@
 //! The constant names are derived from the SVG file names:
 //! `arrow-right.svg` → `ARROW_RIGHT`, `star.svg` → `STAR`, etc.
 //!
+//! For stroke-based or multi-colour icons, or icons drawn on another
+//! grid than `0 0 256 256`, use `eq_ui_build::generate_icon_data`
+//! instead: it emits [`IconData`](super::IconData) constants for
+//! `EqIcon { icon: ... }` that keep the `viewBox` and each shape's
+//! styling. `eq_ui_build::generate_icon_sprite` adds a typed `Icon`
+//! enum (`EqIcon { icon: Icon::Star }`) and a `<symbol>` sprite for
+//! `EqIcon { symbol: ... }`.
+//!
 //! See the `eq_ui_build` crate documentation for full details.
 //!
 //! ## Runtime alternative
@@ -80,6 +88,9 @@ include!(concat!(env!("OUT_DIR"), "/icon_paths.rs"));
 /// rsx! { EqIcon { path: icon } }
 /// ```
 ///
+/// Only the first `<path>` is read; multi-element icons need
+/// `eq_ui_build::generate_icon_data`.
+///
 /// Returns an empty string if no path data is found.
 pub fn extract_svg_path(svg: &str) -> String {
     let Some(path_start) = svg.find("<path") else {
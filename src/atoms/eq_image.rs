@@ -5,9 +5,11 @@ use dioxus::prelude::*;
 
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropSelect, PropToggle, StyleInfo, format_catalog,
+    DemoSection, PropSelect, PropToggle, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
 #[cfg(feature = "playground")]
 use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
@@ -204,7 +206,7 @@ fn DemoEqImage() -> Element {
                 }
             }
             StyleInfo { file: "eq_image_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
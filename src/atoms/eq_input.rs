@@ -5,7 +5,7 @@ use crate::{PlaygroundEnum, playground};
 
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropSelect, PropToggle, PropInput, StyleInfo, format_catalog,
+    DemoSection, PropSelect, PropToggle, PropInput, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
@@ -5,7 +5,7 @@ use crate::playground;
 
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropInput, StyleInfo, format_catalog,
+    DemoSection, PropInput, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
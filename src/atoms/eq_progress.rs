@@ -37,9 +37,11 @@ use dioxus::prelude::*;
 
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropSelect, PropToggle, StyleInfo, format_catalog,
+    DemoSection, PropSelect, PropToggle, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
 #[cfg(feature = "playground")]
 use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
@@ -298,7 +300,7 @@ fn DemoEqProgress() -> Element {
             StyleInfo { file: "eq_progress_styles.rs", styles: format_catalog(&s::catalog()) }
 
             // Usage code
-            CodeBlock { code: code }
+            EqCodeBlock { code: code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
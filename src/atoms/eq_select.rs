@@ -30,22 +30,27 @@
 //! ```
 
 use super::eq_select_styles as s;
+use crate::floating::{use_floating, Align, FloatingOptions, MatchWidth, Placement, Side};
+use crate::overlay::EqPortal;
 use crate::theme::merge_classes;
 use crate::{PlaygroundEnum, playground};
 use dioxus::prelude::*;
 
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropSelect, PropToggle, StyleInfo, format_catalog,
+    DemoSection, PropSelect, PropToggle, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
 #[cfg(feature = "playground")]
 use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
 
 // ── Types ─────────────────────────────────────────────────────────
 
-/// Where the listbox opens relative to the trigger.
+/// Where the listbox prefers to open relative to the trigger. It flips
+/// to the other side when there is no room.
 #[derive(Clone, Copy, PartialEq, Default, PlaygroundEnum)]
 pub enum SelectPosition {
     /// Below the trigger (default).
@@ -55,6 +60,15 @@ pub enum SelectPosition {
     Top,
 }
 
+impl From<SelectPosition> for Placement {
+    fn from(position: SelectPosition) -> Self {
+        match position {
+            SelectPosition::Bottom => Placement::new(Side::Bottom, Align::Start),
+            SelectPosition::Top => Placement::new(Side::Top, Align::Start),
+        }
+    }
+}
+
 /// A single option in the select.
 #[derive(Clone, PartialEq)]
 pub struct SelectOption {
@@ -147,6 +161,18 @@ pub fn EqSelect(
     let mut open = use_signal(|| false);
     let mut search = use_signal(String::new);
     let mut focused_idx = use_signal(|| None::<usize>);
+    let mut floating = use_floating(open(), FloatingOptions {
+        placement: position.into(),
+        match_width: MatchWidth::Exact,
+        ..Default::default()
+    });
+
+    // Filtering changes the listbox height; re-place it (a flipped
+    // listbox would otherwise float away from the trigger).
+    use_effect(move || {
+        search.read();
+        floating.update();
+    });
 
     let wrapper_cls = merge_classes(s::WRAPPER, &class);
 
@@ -193,21 +219,10 @@ pub fn EqSelect(
         div {
             class: "{wrapper_cls}",
 
-            // Close on outside click.
-            if open() {
-                div {
-                    class: "fixed inset-0 z-40",
-                    onclick: move |_| {
-                        open.set(false);
-                        search.set(String::new());
-                        focused_idx.set(None);
-                    },
-                }
-            }
-
             // Trigger
             button {
                 class: "{trigger_cls}",
+                onmounted: move |e| floating.set_anchor(e),
                 r#type: "button",
                 disabled: disabled,
                 role: "combobox",
@@ -267,131 +282,146 @@ pub fn EqSelect(
             }
 
             // Listbox
-            div {
-                class: "{s::LISTBOX} {pos_cls} {listbox_state}",
-                role: "listbox",
-                "aria-label": "{placeholder}",
-                onkeydown: move |evt: KeyboardEvent| {
-                    match evt.key() {
-                        Key::Escape => {
-                            open.set(false);
-                            search.set(String::new());
-                            focused_idx.set(None);
-                        }
-                        Key::ArrowDown => {
-                            evt.prevent_default();
-                            let current = focused_idx();
-                            let next = match current {
-                                Some(cur) => {
-                                    selectable2.iter()
-                                        .position(|&i| i == cur)
-                                        .map(|pos| selectable2[(pos + 1) % selectable2.len()])
-                                        .unwrap_or(selectable2.first().copied().unwrap_or(0))
-                                }
-                                None => selectable2.first().copied().unwrap_or(0),
-                            };
-                            focused_idx.set(Some(next));
-                        }
-                        Key::ArrowUp => {
-                            evt.prevent_default();
-                            let current = focused_idx();
-                            let next = match current {
-                                Some(cur) => {
-                                    selectable2.iter()
-                                        .position(|&i| i == cur)
-                                        .map(|pos| {
-                                            if pos == 0 {
-                                                selectable2[selectable2.len() - 1]
-                                            } else {
-                                                selectable2[pos - 1]
-                                            }
-                                        })
-                                        .unwrap_or(selectable2.last().copied().unwrap_or(0))
+            EqPortal {
+                active: open(),
+                on_escape: move |_| {
+                    open.set(false);
+                    search.set(String::new());
+                    focused_idx.set(None);
+                },
+                on_outside_click: move |_| {
+                    open.set(false);
+                    search.set(String::new());
+                    focused_idx.set(None);
+                },
+                div {
+                    class: "{s::LISTBOX} {pos_cls} {listbox_state}",
+                    style: floating.style(),
+                    onmounted: move |e| floating.set_floating(e),
+                    role: "listbox",
+                    "aria-label": "{placeholder}",
+                    onkeydown: move |evt: KeyboardEvent| {
+                        match evt.key() {
+                            Key::Escape => {
+                                open.set(false);
+                                search.set(String::new());
+                                focused_idx.set(None);
+                            }
+                            Key::ArrowDown => {
+                                evt.prevent_default();
+                                let current = focused_idx();
+                                let next = match current {
+                                    Some(cur) => {
+                                        selectable2.iter()
+                                            .position(|&i| i == cur)
+                                            .map(|pos| selectable2[(pos + 1) % selectable2.len()])
+                                            .unwrap_or(selectable2.first().copied().unwrap_or(0))
+                                    }
+                                    None => selectable2.first().copied().unwrap_or(0),
+                                };
+                                focused_idx.set(Some(next));
+                            }
+                            Key::ArrowUp => {
+                                evt.prevent_default();
+                                let current = focused_idx();
+                                let next = match current {
+                                    Some(cur) => {
+                                        selectable2.iter()
+                                            .position(|&i| i == cur)
+                                            .map(|pos| {
+                                                if pos == 0 {
+                                                    selectable2[selectable2.len() - 1]
+                                                } else {
+                                                    selectable2[pos - 1]
+                                                }
+                                            })
+                                            .unwrap_or(selectable2.last().copied().unwrap_or(0))
+                                    }
+                                    None => selectable2.last().copied().unwrap_or(0),
+                                };
+                                focused_idx.set(Some(next));
+                            }
+                            Key::Enter => {
+                                let Some(idx) = focused_idx() else { return; };
+                                let Some(opt) = options.get(idx) else { return; };
+                                if opt.disabled { return; }
+                                if let Some(handler) = &on_select {
+                                    handler.call(opt.id.clone());
                                 }
-                                None => selectable2.last().copied().unwrap_or(0),
-                            };
-                            focused_idx.set(Some(next));
-                        }
-                        Key::Enter => {
-                            let Some(idx) = focused_idx() else { return; };
-                            let Some(opt) = options.get(idx) else { return; };
-                            if opt.disabled { return; }
-                            if let Some(handler) = &on_select {
-                                handler.call(opt.id.clone());
+                                open.set(false);
+                                search.set(String::new());
+                                focused_idx.set(None);
                             }
-                            open.set(false);
-                            search.set(String::new());
-                            focused_idx.set(None);
+                            _ => {}
                         }
-                        _ => {}
-                    }
-                },
+                    },
 
-                // Search input
-                if searchable {
-                    input {
-                        class: "{s::SEARCH}",
-                        r#type: "text",
-                        placeholder: "Search...",
-                        value: "{search()}",
-                        oninput: move |evt: FormEvent| {
-                            search.set(evt.value());
-                            focused_idx.set(None);
-                        },
+                    // Search input
+                    if searchable {
+                        input {
+                            class: "{s::SEARCH}",
+                            r#type: "text",
+                            placeholder: "Search...",
+                            value: "{search()}",
+                            oninput: move |evt: FormEvent| {
+                                search.set(evt.value());
+                                focused_idx.set(None);
+                            },
+                        }
                     }
-                }
 
-                // Options
-                if filtered.is_empty() {
-                    div { class: "{s::EMPTY}", "No results found" }
-                }
+                    // Options
+                    if filtered.is_empty() {
+                        div { class: "{s::EMPTY}", "No results found" }
+                    }
 
-                for (idx , opt) in filtered.iter() {
-                    {
-                        let is_selected = selected.as_ref() == Some(&opt.id);
-                        let is_focused = focused_idx() == Some(*idx);
-                        let selected_cls = if is_selected { s::OPTION_SELECTED } else { "" };
-                        let disabled_cls = if opt.disabled { s::OPTION_DISABLED } else { "" };
-                        let focus_cls = if is_focused { "bg-[var(--color-accent-primary)]/10" } else { "" };
-                        let opt_id = opt.id.clone();
-
-                        rsx! {
-                            button {
-                                key: "{opt_id}",
-                                class: "{s::OPTION} {selected_cls} {disabled_cls} {focus_cls}",
-                                r#type: "button",
-                                role: "option",
-                                "aria-selected": "{is_selected}",
-                                "aria-disabled": if opt.disabled { "true" } else { "false" },
-                                tabindex: "-1",
-                                disabled: opt.disabled,
-                                onclick: {
-                                    let id = opt_id.clone();
-                                    move |_| {
-                                        if let Some(handler) = &on_select {
-                                            handler.call(id.clone());
+                    for (idx , opt) in filtered.iter() {
+                        {
+                            let is_selected = selected.as_ref() == Some(&opt.id);
+                            let is_focused = focused_idx() == Some(*idx);
+                            let selected_cls = if is_selected { s::OPTION_SELECTED } else { "" };
+                            let disabled_cls = if opt.disabled { s::OPTION_DISABLED } else { "" };
+                            let focus_cls = if is_focused { "bg-[var(--color-accent-primary)]/10" } else { "" };
+                            let opt_id = opt.id.clone();
+
+                            rsx! {
+                                button {
+                                    key: "{opt_id}",
+                                    class: "{s::OPTION} {selected_cls} {disabled_cls} {focus_cls}",
+                                    r#type: "button",
+                                    role: "option",
+                                    "aria-selected": "{is_selected}",
+                                    "aria-disabled": if opt.disabled { "true" } else { "false" },
+                                    tabindex: "-1",
+                                    disabled: opt.disabled,
+                                    onclick: {
+                                        let id = opt_id.clone();
+                                        move |_| {
+                                            if let Some(handler) = &on_select {
+                                                handler.call(id.clone());
+                                            }
+                                            open.set(false);
+                                            search.set(String::new());
+                                            focused_idx.set(None);
                                         }
-                                        open.set(false);
-                                        search.set(String::new());
-                                        focused_idx.set(None);
-                                    }
-                                },
-
-                                span { class: "flex-1 text-left", "{opt.label}" }
-
-                                if is_selected {
-                                    svg {
-                                        class: "{s::CHECK}",
-                                        xmlns: "http://www.w3.org/2000/svg",
-                                        view_box: "0 0 20 20",
-                                        fill: "currentColor",
-                                        width: "16",
-                                        height: "16",
-                                        "aria-hidden": "true",
-                                        path {
-                                            fill_rule: "evenodd",
-                                            clip_rule: "evenodd",
-                                            d: CHECK_PATH,
+                                    },
+
+                                    span { class: "flex-1 text-left", "{opt.label}" }
+
+                                    if is_selected {
+                                        svg {
+                                            class: "{s::CHECK}",
+                                            xmlns: "http://www.w3.org/2000/svg",
+                                            view_box: "0 0 20 20",
+                                            fill: "currentColor",
+                                            width: "16",
+                                            height: "16",
+                                            "aria-hidden": "true",
+                                            path {
+                                                fill_rule: "evenodd",
+                                                clip_rule: "evenodd",
+                                                d: CHECK_PATH,
+                                            }
                                         }
                                     }
                                 }
@@ -503,7 +533,7 @@ EqSelect {{
             }
 
             StyleInfo { file: "eq_select_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
@@ -1,5 +1,7 @@
 //! Style constants for EqSelect.
 
+use crate::floating::{FALLBACK_MENU_BOTTOM, FALLBACK_MENU_TOP};
+
 /// Wrapper — relative anchor for the listbox.
 pub const WRAPPER: &str = "relative inline-flex flex-col";
 
@@ -43,9 +45,9 @@ pub const LISTBOX_OPEN: &str = "visible";
 pub const LISTBOX_CLOSED: &str = "invisible pointer-events-none";
 
 /// Position: below trigger (default).
-pub const POS_BOTTOM: &str = "top-full left-0";
+pub const POS_BOTTOM: &str = FALLBACK_MENU_BOTTOM;
 /// Position: above trigger.
-pub const POS_TOP: &str = "bottom-full left-0 mb-1 mt-0";
+pub const POS_TOP: &str = FALLBACK_MENU_TOP;
 
 /// Search input inside the listbox.
 pub const SEARCH: &str =
@@ -30,9 +30,11 @@ use dioxus::prelude::*;
 
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropSelect, PropToggle, PropInput, StyleInfo, format_catalog,
+    DemoSection, PropSelect, PropToggle, PropInput, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
 #[cfg(feature = "playground")]
 use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
@@ -48,6 +50,116 @@ pub enum SliderSize {
     Lg,
 }
 
+/// Layout axis of the slider.
+#[derive(Clone, Copy, PartialEq, Default, PlaygroundEnum)]
+pub enum SliderOrientation {
+    /// Left-to-right track (default).
+    #[default]
+    Horizontal,
+    /// Bottom-to-top track — `min` sits at the bottom.
+    Vertical,
+}
+
+/// A tick mark on the slider track, optionally labelled.
+///
+/// When `snap_to_marks` is enabled on [`EqSlider`], marks also act as
+/// the only values the thumb can settle on.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SliderMark {
+    /// Position of the mark, in the same units as `value`.
+    pub value: f64,
+    /// Text shown under the tick. `None` renders a bare tick.
+    pub label: Option<String>,
+}
+
+impl SliderMark {
+    /// Create an unlabelled tick at `value`.
+    pub fn new(value: f64) -> Self {
+        Self { value, label: None }
+    }
+
+    /// Builder: attach a text label to the tick.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+// ── Value math ────────────────────────────────────────────────────
+
+/// Position of `value` along the track as a percentage (0–100),
+/// clamped to the `[min, max]` interval.
+pub fn value_to_percent(value: f64, min: f64, max: f64) -> f64 {
+    if max <= min {
+        return 0.0;
+    }
+    ((value - min) / (max - min) * 100.0).clamp(0.0, 100.0)
+}
+
+/// Round `value` to the nearest `step` counted from `min`, then clamp
+/// to `[min, max]`. A non-positive `step` only clamps.
+pub fn snap_to_step(value: f64, min: f64, max: f64, step: f64) -> f64 {
+    let v = if step > 0.0 {
+        min + ((value - min) / step).round() * step
+    } else {
+        value
+    };
+    v.clamp(min, max.max(min))
+}
+
+/// Value of the mark closest to `value`, or `None` when there are no marks.
+pub fn nearest_mark(value: f64, marks: &[SliderMark]) -> Option<f64> {
+    marks
+        .iter()
+        .map(|m| m.value)
+        .min_by(|a, b| (a - value).abs().total_cmp(&(b - value).abs()))
+}
+
+/// Applies a move of one range thumb and returns the new `(low, high)`
+/// pair. `thumb` is `0` for the low thumb and `1` for the high thumb.
+///
+/// The moved thumb is clamped so the thumbs never cross and stay at
+/// least `min_distance` apart; the other thumb is left untouched.
+pub fn apply_range_thumb(
+    current: (f64, f64),
+    thumb: usize,
+    value: f64,
+    min: f64,
+    max: f64,
+    min_distance: f64,
+) -> (f64, f64) {
+    let (lo, hi) = current;
+    let gap = min_distance.max(0.0);
+    if thumb == 0 {
+        (value.min(hi - gap).clamp(min, max.max(min)), hi)
+    } else {
+        (lo, value.max(lo + gap).clamp(min, max.max(min)))
+    }
+}
+
+/// Default value text: an integer when `step >= 1`, otherwise one
+/// decimal place.
+pub fn default_format(value: f64, step: f64) -> String {
+    if step >= 1.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.1}", value)
+    }
+}
+
+/// Inline style that places a floating element (tooltip, tick) at
+/// `pct` along the track, compensating for the thumb's own width so
+/// the element stays centred over it at both ends.
+fn track_offset_style(pct: f64, vertical: bool) -> String {
+    // Thumbs are ~1rem wide; the centre travels from 0.5rem to 100% - 0.5rem.
+    let shift = 0.5 - pct / 100.0;
+    if vertical {
+        format!("bottom: calc({pct}% + {shift}rem);")
+    } else {
+        format!("left: calc({pct}% + {shift}rem);")
+    }
+}
+
 // ── Component ─────────────────────────────────────────────────────
 
 /// Themed range slider.
@@ -56,20 +168,37 @@ pub enum SliderSize {
 /// size variants, and an optional numeric label. Controlled component
 /// pattern: pass `value` + `on_change`.
 ///
+/// **Range mode** — pass `range_value` + `on_range_change` to get two
+/// thumbs (e.g. a price filter). Two native inputs are overlaid on a
+/// shared track so each thumb keeps native keyboard support; the
+/// thumbs never cross and stay `min_distance` apart.
+///
+/// **Marks** — `marks` draws tick marks with optional labels. With
+/// `snap_to_marks`, the value always settles on the nearest mark.
+///
+/// **Formatting** — `format_value` turns a value into display text
+/// (e.g. currency or percent). It is used for the value label, the
+/// drag tooltip, and `aria-valuetext`.
+///
 /// **Accessibility** — uses native `<input type="range">` which
 /// provides built-in keyboard navigation (arrow keys) and screen
 /// reader support. An `aria-label` prop is available for custom
-/// accessible names. `aria-valuemin`, `aria-valuemax`, and
-/// `aria-valuenow` are set automatically.
+/// accessible names. `aria-valuemin`, `aria-valuemax`,
+/// `aria-valuenow`, `aria-valuetext` and `aria-orientation` are set
+/// automatically on every thumb.
 #[playground(
     category = Atom,
     description = "Range slider wrapping native <input type=\"range\"> with \
                    accent-color theming, three sizes, optional value label, \
-                   and disabled state.",
+                   dual-thumb range mode, tick marks with snapping, vertical \
+                   orientation, drag tooltip, and value formatting.",
     examples = [
         ("Basic", "let mut val = use_signal(|| 50.0);\n\nEqSlider {\n    value: val(),\n    on_change: move |v| val.set(v),\n}"),
         ("With label", "EqSlider {\n    value: brightness(),\n    min: 0.0,\n    max: 100.0,\n    show_label: true,\n    on_change: move |v| brightness.set(v),\n}"),
         ("Custom range", "EqSlider {\n    value: temperature(),\n    min: -20.0,\n    max: 45.0,\n    step: 0.5,\n    size: SliderSize::Lg,\n    show_label: true,\n    on_change: move |v| temperature.set(v),\n}"),
+        ("Price range", "let mut price = use_signal(|| (20.0, 80.0));\n\nEqSlider {\n    range_value: price(),\n    min_distance: 5.0,\n    show_tooltip: true,\n    format_value: move |v: f64| format!(\"${v:.0}\"),\n    on_range_change: move |r| price.set(r),\n}"),
+        ("Marks + snapping", "EqSlider {\n    value: level(),\n    marks: vec![\n        SliderMark::new(0.0).label(\"Off\"),\n        SliderMark::new(50.0).label(\"Half\"),\n        SliderMark::new(100.0).label(\"Full\"),\n    ],\n    snap_to_marks: true,\n    on_change: move |v| level.set(v),\n}"),
+        ("Vertical", "EqSlider {\n    value: gain(),\n    orientation: SliderOrientation::Vertical,\n    on_change: move |v| gain.set(v),\n}"),
     ],
     custom_demo,
     custom_gallery,
@@ -91,6 +220,33 @@ pub fn EqSlider(
     /// Fired when the user moves the slider. Receives the new value.
     #[props(default)]
     on_change: Option<EventHandler<f64>>,
+    /// Switches to dual-thumb range mode when set. Holds the current
+    /// `(low, high)` pair; `value` and `on_change` are ignored.
+    #[props(default)]
+    range_value: Option<(f64, f64)>,
+    /// Fired when either range thumb moves. Receives the new `(low, high)`.
+    #[props(default)]
+    on_range_change: Option<EventHandler<(f64, f64)>>,
+    /// Minimum gap kept between the two range thumbs.
+    #[props(default = 0.0)]
+    min_distance: f64,
+    /// Tick marks drawn along the track.
+    #[props(default)]
+    marks: Vec<SliderMark>,
+    /// Snap the value to the nearest entry in `marks` instead of `step`.
+    #[props(default = false)]
+    snap_to_marks: bool,
+    /// Horizontal (default) or vertical track.
+    #[props(default)]
+    orientation: SliderOrientation,
+    /// Show a floating value bubble above the thumb while it is
+    /// dragged or focused.
+    #[props(default = false)]
+    show_tooltip: bool,
+    /// Formats a value for the label, tooltip, and `aria-valuetext`.
+    /// Defaults to an integer (step ≥ 1) or one decimal place.
+    #[props(default)]
+    format_value: Option<Callback<f64, String>>,
     /// Disables interaction and dims the visual.
     #[props(default = false)]
     disabled: bool,
@@ -100,55 +256,227 @@ pub fn EqSlider(
     /// Show a numeric value label beside the slider.
     #[props(default = false)]
     show_label: bool,
-    /// Accessible label for screen readers.
+    /// Accessible label for screen readers. In range mode each thumb
+    /// gets this label suffixed with "minimum" / "maximum".
     #[props(into, default)]
     aria_label: String,
     /// Optional class override on the wrapper element.
     #[props(into, default)]
     class: String,
 ) -> Element {
-    let wrapper_cls = merge_classes(s::WRAPPER, &class);
+    // Thumb currently being dragged or focused (0 = low/single, 1 = high).
+    let mut active_thumb: Signal<Option<usize>> = use_signal(|| None);
+
+    let vertical = orientation == SliderOrientation::Vertical;
+    let wrapper_base = if vertical { s::WRAPPER_VERTICAL } else { s::WRAPPER };
+    let wrapper_cls = merge_classes(wrapper_base, &class);
+    let area_cls = if vertical { s::TRACK_AREA_VERTICAL } else { s::TRACK_AREA };
+    let orientation_attr = if vertical { "vertical" } else { "horizontal" };
 
-    let size_cls = match size {
-        SliderSize::Sm => s::SM,
-        SliderSize::Md => s::MD,
-        SliderSize::Lg => s::LG,
+    let size_cls = match (size, vertical) {
+        (SliderSize::Sm, false) => s::SM,
+        (SliderSize::Md, false) => s::MD,
+        (SliderSize::Lg, false) => s::LG,
+        (SliderSize::Sm, true) => s::SM_VERTICAL,
+        (SliderSize::Md, true) => s::MD_VERTICAL,
+        (SliderSize::Lg, true) => s::LG_VERTICAL,
     };
+    let disabled_cls = if disabled { s::DISABLED } else { "" };
 
-    let input_cls = if disabled {
-        format!("{} {} {}", s::INPUT, size_cls, s::DISABLED)
+    let format = move |v: f64| match &format_value {
+        Some(f) => f.call(v),
+        None => default_format(v, step),
+    };
+
+    // Marks-snapping uses `step="any"` so the browser doesn't fight the
+    // snap; the value is corrected in the input handler instead.
+    let snapping = snap_to_marks && !marks.is_empty();
+    let step_attr = if snapping { "any".to_string() } else { step.to_string() };
+    let marks_snap = marks.clone();
+    let settle = move |raw: f64| -> f64 {
+        if snapping {
+            nearest_mark(raw, &marks_snap).unwrap_or(raw).clamp(min, max.max(min))
+        } else {
+            snap_to_step(raw, min, max, step)
+        }
+    };
+
+    let aria = if aria_label.is_empty() { None } else { Some(aria_label.clone()) };
+    let tooltip_pos_cls = if vertical { s::TOOLTIP_VERTICAL } else { s::TOOLTIP_HORIZONTAL };
+
+    // ── Tick marks ───────────────────────────────────────────────
+    let (marks_cls, tick_cls, mark_label_cls) = if vertical {
+        (s::MARKS_VERTICAL, s::MARK_TICK_VERTICAL, s::MARK_LABEL_VERTICAL)
     } else {
-        format!("{} {}", s::INPUT, size_cls)
+        (s::MARKS, s::MARK_TICK_HORIZONTAL, s::MARK_LABEL_HORIZONTAL)
     };
+    let mark_views: Vec<(String, Option<String>)> = marks
+        .iter()
+        .map(|m| {
+            let pct = value_to_percent(m.value, min, max);
+            (track_offset_style(pct, vertical), m.label.clone())
+        })
+        .collect();
 
-    // Format label: show integer if step >= 1, otherwise one decimal place.
-    let label_text = if step >= 1.0 {
-        format!("{}", value as i64)
+    let marks_el = rsx! {
+        if !mark_views.is_empty() {
+            div { class: "{marks_cls}", "aria-hidden": "true",
+                for (i, (style, label)) in mark_views.into_iter().enumerate() {
+                    span { key: "tick-{i}", class: "{s::MARK_TICK} {tick_cls}", style: "{style}" }
+                    if let Some(text) = label {
+                        span { key: "mark-{i}", class: "{s::MARK_LABEL} {mark_label_cls}", style: "{style}", "{text}" }
+                    }
+                }
+            }
+        }
+    };
+
+    // ── Range (dual-thumb) mode ──────────────────────────────────
+    if let Some((lo, hi)) = range_value {
+        let lo_pct = value_to_percent(lo, min, max);
+        let hi_pct = value_to_percent(hi, min, max);
+        let (track_pos, area_size) = if vertical {
+            (s::RANGE_TRACK_VERTICAL, format!("{} h-full", s::RANGE_AREA_VERTICAL))
+        } else {
+            (s::RANGE_TRACK_HORIZONTAL, format!("{} w-full", s::RANGE_AREA))
+        };
+        let fill_style = if vertical {
+            format!("bottom: {lo_pct}%; height: {}%; left: 50%; transform: translateX(-50%);", hi_pct - lo_pct)
+        } else {
+            format!("left: {lo_pct}%; width: {}%; top: 50%; transform: translateY(-50%);", hi_pct - lo_pct)
+        };
+        let input_cls = if vertical {
+            format!("{} {}", s::RANGE_INPUT, s::INPUT_VERTICAL)
+        } else {
+            s::RANGE_INPUT.to_string()
+        };
+
+        let lo_text = format(lo);
+        let hi_text = format(hi);
+        let label_text = format!("{lo_text} – {hi_text}");
+        let (lo_label, hi_label) = if aria_label.is_empty() {
+            ("Minimum value".to_string(), "Maximum value".to_string())
+        } else {
+            (format!("{aria_label} minimum"), format!("{aria_label} maximum"))
+        };
+
+        // When both thumbs sit at the max end, the low thumb must be on
+        // top or it could never be dragged back down.
+        let lo_z = if lo_pct > 50.0 { "z-[3]" } else { "z-[2]" };
+
+        let thumbs = [
+            (0usize, lo, lo_text.clone(), lo_label, lo_pct, lo_z),
+            (1usize, hi, hi_text.clone(), hi_label, hi_pct, "z-[2]"),
+        ];
+        let settle_range = settle.clone();
+
+        return rsx! {
+            div { class: "{wrapper_cls} {disabled_cls}",
+                div { class: "{area_cls}",
+                    div { class: "relative {area_size}",
+                        div { class: "{s::RANGE_TRACK} {track_pos} {size_cls}" }
+                        div { class: "{s::RANGE_FILL} {size_cls}", style: "{fill_style}" }
+                        for (thumb, val, text, label, pct, z) in thumbs {
+                            input {
+                                key: "thumb-{thumb}",
+                                r#type: "range",
+                                class: "{input_cls} {z}",
+                                min: "{min}",
+                                max: "{max}",
+                                step: "{step_attr}",
+                                value: "{val}",
+                                disabled: disabled,
+                                "aria-label": "{label}",
+                                "aria-orientation": orientation_attr,
+                                "aria-valuemin": "{min}",
+                                "aria-valuemax": "{max}",
+                                "aria-valuenow": "{val}",
+                                "aria-valuetext": "{text}",
+                                onpointerdown: move |_| active_thumb.set(Some(thumb)),
+                                onpointerup: move |_| active_thumb.set(None),
+                                onfocus: move |_| active_thumb.set(Some(thumb)),
+                                onblur: move |_| active_thumb.set(None),
+                                oninput: {
+                                    let settle_range = settle_range.clone();
+                                    move |evt: Event<FormData>| {
+                                        let Some(handler) = &on_range_change else { return; };
+                                        let Ok(v) = evt.value().parse::<f64>() else { return; };
+                                        let v = settle_range(v);
+                                        handler.call(apply_range_thumb((lo, hi), thumb, v, min, max, min_distance));
+                                    }
+                                },
+                            }
+                            if show_tooltip && active_thumb() == Some(thumb) {
+                                div {
+                                    key: "tip-{thumb}",
+                                    class: "{s::TOOLTIP} {tooltip_pos_cls}",
+                                    style: "{track_offset_style(pct, vertical)}",
+                                    "aria-hidden": "true",
+                                    "{text}"
+                                }
+                            }
+                        }
+                    }
+                    {marks_el}
+                }
+                if show_label {
+                    span { class: "{s::LABEL}", "{label_text}" }
+                }
+            }
+        };
+    }
+
+    // ── Single-thumb mode ────────────────────────────────────────
+    let input_cls = if vertical {
+        format!("{} {} {}", s::INPUT, s::INPUT_VERTICAL, size_cls)
     } else {
-        format!("{:.1}", value)
+        format!("{} {}", s::INPUT, size_cls)
+    };
+    let input_cls = if disabled {
+        format!("{} {}", input_cls, s::DISABLED)
+    } else {
+        input_cls
     };
 
-    let aria = if aria_label.is_empty() { None } else { Some(aria_label.clone()) };
+    let label_text = format(value);
+    let pct = value_to_percent(value, min, max);
 
     rsx! {
         div { class: "{wrapper_cls}",
-            input {
-                r#type: "range",
-                class: "{input_cls}",
-                min: "{min}",
-                max: "{max}",
-                step: "{step}",
-                value: "{value}",
-                disabled: disabled,
-                "aria-label": aria,
-                "aria-valuemin": "{min}",
-                "aria-valuemax": "{max}",
-                "aria-valuenow": "{value}",
-                oninput: move |evt: Event<FormData>| {
-                    let Some(handler) = &on_change else { return; };
-                    let Ok(v) = evt.value().parse::<f64>() else { return; };
-                    handler.call(v);
-                },
+            div { class: "{area_cls}",
+                input {
+                    r#type: "range",
+                    class: "{input_cls}",
+                    min: "{min}",
+                    max: "{max}",
+                    step: "{step_attr}",
+                    value: "{value}",
+                    disabled: disabled,
+                    "aria-label": aria,
+                    "aria-orientation": orientation_attr,
+                    "aria-valuemin": "{min}",
+                    "aria-valuemax": "{max}",
+                    "aria-valuenow": "{value}",
+                    "aria-valuetext": "{label_text}",
+                    onpointerdown: move |_| active_thumb.set(Some(0)),
+                    onpointerup: move |_| active_thumb.set(None),
+                    onfocus: move |_| active_thumb.set(Some(0)),
+                    onblur: move |_| active_thumb.set(None),
+                    oninput: move |evt: Event<FormData>| {
+                        let Some(handler) = &on_change else { return; };
+                        let Ok(v) = evt.value().parse::<f64>() else { return; };
+                        handler.call(settle(v));
+                    },
+                }
+                if show_tooltip && active_thumb().is_some() {
+                    div {
+                        class: "{s::TOOLTIP} {tooltip_pos_cls}",
+                        style: "{track_offset_style(pct, vertical)}",
+                        "aria-hidden": "true",
+                        "{label_text}"
+                    }
+                }
+                {marks_el}
             }
             if show_label {
                 span { class: "{s::LABEL}", "{label_text}" }
@@ -163,11 +491,17 @@ pub fn EqSlider(
 #[component]
 fn DemoEqSlider() -> Element {
     let mut value = use_signal(|| 50.0);
+    let mut range = use_signal(|| (25.0, 75.0));
     let mut min = use_signal(|| "0".to_string());
     let mut max = use_signal(|| "100".to_string());
     let mut step = use_signal(|| "1".to_string());
     let mut size_str = use_signal(|| "Md".to_string());
+    let mut orientation_str = use_signal(|| "Horizontal".to_string());
     let mut show_label = use_signal(|| true);
+    let mut show_tooltip = use_signal(|| true);
+    let mut range_mode = use_signal(|| false);
+    let mut with_marks = use_signal(|| false);
+    let mut snap = use_signal(|| false);
     let mut disabled = use_signal(|| false);
 
     let size = match size_str().as_str() {
@@ -175,30 +509,69 @@ fn DemoEqSlider() -> Element {
         "Lg" => SliderSize::Lg,
         _ => SliderSize::Md,
     };
+    let orientation = match orientation_str().as_str() {
+        "Vertical" => SliderOrientation::Vertical,
+        _ => SliderOrientation::Horizontal,
+    };
 
     let min_val: f64 = min().parse().unwrap_or(0.0);
     let max_val: f64 = max().parse().unwrap_or(100.0);
     let step_val: f64 = step().parse().unwrap_or(1.0);
 
+    let marks = if with_marks() {
+        let quarter = (max_val - min_val) / 4.0;
+        (0..=4)
+            .map(|i| {
+                let v = min_val + quarter * i as f64;
+                SliderMark::new(v).label(default_format(v, step_val))
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let value_lines = if range_mode() {
+        "    range_value: range(),\n    on_range_change: move |r| range.set(r),".to_string()
+    } else {
+        "    value: val(),\n    on_change: move |v| val.set(v),".to_string()
+    };
+    let signal_line = if range_mode() {
+        format!("let mut range = use_signal(|| ({:?}, {:?}));", range().0, range().1)
+    } else {
+        format!("let mut val = use_signal(|| {:?});", value())
+    };
+    let marks_line = if with_marks() {
+        format!(
+            "\n    marks: (0..=4).map(|i| SliderMark::new({mn} + {q} * i as f64)).collect(),\n    snap_to_marks: {snap},",
+            mn = min_val,
+            q = (max_val - min_val) / 4.0,
+            snap = snap(),
+        )
+    } else {
+        String::new()
+    };
+
     let code = format!(
-        r#"let mut val = use_signal(|| {val});
+        r#"{signal_line}
 
 EqSlider {{
-    value: val(),
+{value_lines}
     min: {mn},
     max: {mx},
     step: {st},
     size: SliderSize::{sz},
+    orientation: SliderOrientation::{or},
     show_label: {sl},
+    show_tooltip: {tt},{marks_line}
     disabled: {dis},
-    on_change: move |v| val.set(v),
 }}"#,
-        val = value(),
         mn = min_val,
         mx = max_val,
         st = step_val,
         sz = size_str(),
+        or = orientation_str(),
         sl = show_label(),
+        tt = show_tooltip(),
         dis = disabled(),
     );
 
@@ -216,6 +589,12 @@ EqSlider {{
                     options: vec!["Sm", "Md", "Lg"],
                     onchange: move |v: String| size_str.set(v),
                 }
+                PropSelect {
+                    label: "orientation",
+                    value: orientation_str(),
+                    options: vec!["Horizontal", "Vertical"],
+                    onchange: move |v: String| orientation_str.set(v),
+                }
                 PropInput {
                     label: "min",
                     value: min(),
@@ -234,11 +613,31 @@ EqSlider {{
                     placeholder: "1",
                     onchange: move |v: String| step.set(v),
                 }
+                PropToggle {
+                    label: "range mode (two thumbs)",
+                    value: range_mode(),
+                    onchange: move |v: bool| range_mode.set(v),
+                }
+                PropToggle {
+                    label: "marks",
+                    value: with_marks(),
+                    onchange: move |v: bool| with_marks.set(v),
+                }
+                PropToggle {
+                    label: "snap_to_marks",
+                    value: snap(),
+                    onchange: move |v: bool| snap.set(v),
+                }
                 PropToggle {
                     label: "show_label",
                     value: show_label(),
                     onchange: move |v: bool| show_label.set(v),
                 }
+                PropToggle {
+                    label: "show_tooltip",
+                    value: show_tooltip(),
+                    onchange: move |v: bool| show_tooltip.set(v),
+                }
                 PropToggle {
                     label: "disabled",
                     value: disabled(),
@@ -248,17 +647,38 @@ EqSlider {{
 
             div { class: "rounded-lg border border-dashed border-[var(--color-card-border)] p-6 space-y-6",
                 // Main interactive slider
-                div { class: "space-y-2",
+                div { class: "space-y-2 pt-6",
                     EqText { variant: TextVariant::Muted, "Drag the slider:" }
-                    EqSlider {
-                        value: value(),
-                        min: min_val,
-                        max: max_val,
-                        step: step_val,
-                        size,
-                        show_label: show_label(),
-                        disabled: disabled(),
-                        on_change: move |v: f64| value.set(v),
+                    if range_mode() {
+                        EqSlider {
+                            range_value: range(),
+                            min: min_val,
+                            max: max_val,
+                            step: step_val,
+                            size,
+                            orientation,
+                            marks: marks.clone(),
+                            snap_to_marks: snap(),
+                            show_label: show_label(),
+                            show_tooltip: show_tooltip(),
+                            disabled: disabled(),
+                            on_range_change: move |r: (f64, f64)| range.set(r),
+                        }
+                    } else {
+                        EqSlider {
+                            value: value(),
+                            min: min_val,
+                            max: max_val,
+                            step: step_val,
+                            size,
+                            orientation,
+                            marks: marks.clone(),
+                            snap_to_marks: snap(),
+                            show_label: show_label(),
+                            show_tooltip: show_tooltip(),
+                            disabled: disabled(),
+                            on_change: move |v: f64| value.set(v),
+                        }
                     }
                 }
 
@@ -283,7 +703,7 @@ EqSlider {{
             }
 
             StyleInfo { file: "eq_slider_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
@@ -296,6 +716,9 @@ fn GalleryEqSlider() -> Element {
     let mut vol = use_signal(|| 70.0);
     let mut temp = use_signal(|| 22.0);
     let mut progress = use_signal(|| 33.0);
+    let mut price = use_signal(|| (120.0, 480.0));
+    let mut quality = use_signal(|| 50.0);
+    let mut gain = use_signal(|| 60.0);
 
     rsx! {
         div { class: "space-y-4",
@@ -338,6 +761,53 @@ fn GalleryEqSlider() -> Element {
                             on_change: move |v: f64| progress.set(v),
                         }
                     }
+
+                    // Price range (dual thumb + currency formatter)
+                    div { class: "space-y-1 pt-6",
+                        EqText { variant: TextVariant::Muted, "Price range" }
+                        EqSlider {
+                            range_value: price(),
+                            min: 0.0,
+                            max: 1000.0,
+                            step: 10.0,
+                            min_distance: 50.0,
+                            show_label: true,
+                            show_tooltip: true,
+                            aria_label: "Price",
+                            format_value: move |v: f64| format!("${v:.0}"),
+                            on_range_change: move |r: (f64, f64)| price.set(r),
+                        }
+                    }
+
+                    // Quality (labelled marks, snapping, percent)
+                    div { class: "space-y-1",
+                        EqText { variant: TextVariant::Muted, "Quality (snaps to marks)" }
+                        EqSlider {
+                            value: quality(),
+                            marks: vec![
+                                SliderMark::new(0.0).label("Low"),
+                                SliderMark::new(50.0).label("Medium"),
+                                SliderMark::new(75.0).label("High"),
+                                SliderMark::new(100.0).label("Max"),
+                            ],
+                            snap_to_marks: true,
+                            show_label: true,
+                            format_value: move |v: f64| format!("{v:.0}%"),
+                            on_change: move |v: f64| quality.set(v),
+                        }
+                    }
+
+                    // Vertical
+                    div { class: "space-y-1",
+                        EqText { variant: TextVariant::Muted, "Vertical" }
+                        EqSlider {
+                            value: gain(),
+                            orientation: SliderOrientation::Vertical,
+                            show_label: true,
+                            show_tooltip: true,
+                            on_change: move |v: f64| gain.set(v),
+                        }
+                    }
                 }
             }
         }
@@ -356,9 +826,87 @@ mod tests {
         dom.rebuild_in_place();
     }
 
+    #[test]
+    fn smoke_renders_range_vertical_with_marks() {
+        let mut dom = VirtualDom::new(|| rsx! {
+            EqSlider {
+                range_value: (20.0, 80.0),
+                orientation: SliderOrientation::Vertical,
+                marks: vec![SliderMark::new(0.0).label("Min"), SliderMark::new(100.0)],
+                show_tooltip: true,
+                format_value: move |v: f64| format!("${v}"),
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
     #[test]
     fn default_size_is_md() {
         let s: SliderSize = Default::default();
         assert!(matches!(s, SliderSize::Md));
     }
+
+    #[test]
+    fn default_orientation_is_horizontal() {
+        let o: SliderOrientation = Default::default();
+        assert!(matches!(o, SliderOrientation::Horizontal));
+    }
+
+    #[test]
+    fn percent_is_clamped_and_handles_empty_range() {
+        assert_eq!(value_to_percent(50.0, 0.0, 200.0), 25.0);
+        assert_eq!(value_to_percent(-10.0, 0.0, 100.0), 0.0);
+        assert_eq!(value_to_percent(150.0, 0.0, 100.0), 100.0);
+        assert_eq!(value_to_percent(5.0, 10.0, 10.0), 0.0);
+    }
+
+    #[test]
+    fn snap_to_step_rounds_from_min() {
+        assert_eq!(snap_to_step(12.0, 0.0, 100.0, 5.0), 10.0);
+        assert_eq!(snap_to_step(13.0, 0.0, 100.0, 5.0), 15.0);
+        assert_eq!(snap_to_step(4.0, 1.0, 100.0, 2.0), 5.0);
+        assert_eq!(snap_to_step(120.0, 0.0, 100.0, 5.0), 100.0);
+    }
+
+    #[test]
+    fn nearest_mark_picks_closest() {
+        let marks = vec![SliderMark::new(0.0), SliderMark::new(50.0), SliderMark::new(100.0)];
+        assert_eq!(nearest_mark(20.0, &marks), Some(0.0));
+        assert_eq!(nearest_mark(30.0, &marks), Some(50.0));
+        assert_eq!(nearest_mark(99.0, &marks), Some(100.0));
+        assert_eq!(nearest_mark(5.0, &[]), None);
+    }
+
+    #[test]
+    fn range_thumbs_never_cross() {
+        assert_eq!(apply_range_thumb((20.0, 80.0), 0, 90.0, 0.0, 100.0, 0.0), (80.0, 80.0));
+        assert_eq!(apply_range_thumb((20.0, 80.0), 1, 10.0, 0.0, 100.0, 0.0), (20.0, 20.0));
+    }
+
+    #[test]
+    fn range_thumbs_respect_min_distance_and_bounds() {
+        assert_eq!(apply_range_thumb((20.0, 80.0), 0, 78.0, 0.0, 100.0, 10.0), (70.0, 80.0));
+        assert_eq!(apply_range_thumb((20.0, 80.0), 1, 22.0, 0.0, 100.0, 10.0), (20.0, 30.0));
+        assert_eq!(apply_range_thumb((20.0, 80.0), 0, -5.0, 0.0, 100.0, 0.0), (0.0, 80.0));
+        assert_eq!(apply_range_thumb((20.0, 80.0), 1, 105.0, 0.0, 100.0, 0.0), (20.0, 100.0));
+    }
+
+    #[test]
+    fn range_thumbs_tolerate_inverted_bounds() {
+        assert_eq!(apply_range_thumb((20.0, 80.0), 0, 50.0, 100.0, 0.0, 0.0), (100.0, 80.0));
+        assert_eq!(apply_range_thumb((20.0, 80.0), 1, 50.0, 100.0, 0.0, 0.0), (20.0, 100.0));
+    }
+
+    #[test]
+    fn default_format_matches_step() {
+        assert_eq!(default_format(42.7, 1.0), "42");
+        assert_eq!(default_format(42.75, 0.5), "42.8");
+    }
+
+    #[test]
+    fn slider_mark_builder_sets_label() {
+        let m = SliderMark::new(10.0).label("Ten");
+        assert_eq!(m.value, 10.0);
+        assert_eq!(m.label.as_deref(), Some("Ten"));
+    }
 }
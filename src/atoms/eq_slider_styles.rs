@@ -3,6 +3,15 @@
 /// Outermost wrapper (contains slider + optional label).
 pub const WRAPPER: &str = "flex items-center gap-3 w-full";
 
+/// Outermost wrapper in vertical orientation.
+pub const WRAPPER_VERTICAL: &str = "inline-flex flex-col-reverse items-center gap-3 h-48";
+
+/// Positioning context for the input(s), tooltip, and marks.
+pub const TRACK_AREA: &str = "relative flex-1 w-full";
+
+/// Positioning context in vertical orientation.
+pub const TRACK_AREA_VERTICAL: &str = "relative flex-1 h-full flex justify-center";
+
 /// The native range input, themed via accent color.
 pub const INPUT: &str =
     "w-full h-2 appearance-none rounded-full cursor-pointer \
@@ -10,6 +19,11 @@ pub const INPUT: &str =
      accent-[var(--color-accent-primary)] \
      focus:outline-none focus:ring-2 focus:ring-[var(--color-accent-primary)]/40";
 
+/// Vertical orientation for the native input — the browser lays the
+/// range out bottom-to-top with min at the bottom.
+pub const INPUT_VERTICAL: &str =
+    "!w-2 h-full [writing-mode:vertical-lr] [direction:rtl]";
+
 /// Small size track.
 pub const SM: &str = "h-1";
 /// Medium size track (default).
@@ -17,11 +31,98 @@ pub const MD: &str = "h-2";
 /// Large size track.
 pub const LG: &str = "h-3";
 
+/// Small size track (vertical).
+pub const SM_VERTICAL: &str = "!w-1";
+/// Medium size track (vertical).
+pub const MD_VERTICAL: &str = "!w-2";
+/// Large size track (vertical).
+pub const LG_VERTICAL: &str = "!w-3";
+
+/// Background track drawn behind the two range-mode inputs.
+pub const RANGE_TRACK: &str =
+    "absolute rounded-full bg-[var(--color-tertiary-dark)] pointer-events-none";
+
+/// Horizontal placement of the range track.
+pub const RANGE_TRACK_HORIZONTAL: &str = "inset-x-0 top-1/2 -translate-y-1/2";
+
+/// Vertical placement of the range track.
+pub const RANGE_TRACK_VERTICAL: &str = "inset-y-0 left-1/2 -translate-x-1/2";
+
+/// Filled segment between the two thumbs.
+pub const RANGE_FILL: &str =
+    "absolute rounded-full bg-[var(--color-accent-primary)] pointer-events-none";
+
+/// A range-mode input. The track is transparent and ignores the
+/// pointer so both overlaid inputs stay draggable by their thumbs.
+pub const RANGE_INPUT: &str =
+    "absolute inset-0 w-full h-full m-0 appearance-none bg-transparent \
+     pointer-events-none cursor-pointer \
+     accent-[var(--color-accent-primary)] focus:outline-none \
+     [&::-webkit-slider-thumb]:pointer-events-auto \
+     [&::-webkit-slider-thumb]:appearance-none \
+     [&::-webkit-slider-thumb]:h-4 [&::-webkit-slider-thumb]:w-4 \
+     [&::-webkit-slider-thumb]:rounded-full \
+     [&::-webkit-slider-thumb]:bg-[var(--color-accent-primary)] \
+     [&::-webkit-slider-thumb]:border-2 \
+     [&::-webkit-slider-thumb]:border-[var(--color-label-primary)] \
+     [&::-moz-range-thumb]:pointer-events-auto \
+     [&::-moz-range-thumb]:h-4 [&::-moz-range-thumb]:w-4 \
+     [&::-moz-range-thumb]:rounded-full \
+     [&::-moz-range-thumb]:bg-[var(--color-accent-primary)] \
+     focus-visible:[&::-webkit-slider-thumb]:ring-2 \
+     focus-visible:[&::-webkit-slider-thumb]:ring-[var(--color-accent-primary)]/40";
+
+/// Height of the range-mode hit area (horizontal).
+pub const RANGE_AREA: &str = "h-5";
+
+/// Width of the range-mode hit area (vertical).
+pub const RANGE_AREA_VERTICAL: &str = "w-5";
+
 /// Value label displayed beside the slider.
 pub const LABEL: &str =
     "shrink-0 min-w-[3ch] text-right text-sm tabular-nums \
      text-[var(--color-label-secondary)]";
 
+/// Floating value bubble shown while a thumb is dragged or focused.
+pub const TOOLTIP: &str =
+    "absolute z-10 px-2 py-0.5 rounded-md text-xs tabular-nums whitespace-nowrap \
+     pointer-events-none shadow-md \
+     bg-[var(--color-tertiary-dark)] text-[var(--color-label-primary)] \
+     border border-[var(--color-card-border)]";
+
+/// Tooltip placement above a horizontal track.
+pub const TOOLTIP_HORIZONTAL: &str = "bottom-full mb-2 -translate-x-1/2";
+
+/// Tooltip placement beside a vertical track.
+pub const TOOLTIP_VERTICAL: &str = "left-full ml-3 translate-y-1/2";
+
+/// Container for tick marks (horizontal).
+pub const MARKS: &str = "relative w-full h-5 mt-1";
+
+/// Container for tick marks (vertical).
+pub const MARKS_VERTICAL: &str = "absolute inset-y-0 left-full ml-2 w-12";
+
+/// A single tick mark line.
+pub const MARK_TICK: &str =
+    "absolute block bg-[var(--color-label-secondary)]/60";
+
+/// Tick line orientation (horizontal track).
+pub const MARK_TICK_HORIZONTAL: &str = "top-0 w-px h-1.5 -translate-x-1/2";
+
+/// Tick line orientation (vertical track).
+pub const MARK_TICK_VERTICAL: &str = "left-0 h-px w-1.5 translate-y-1/2";
+
+/// A tick mark's text label.
+pub const MARK_LABEL: &str =
+    "absolute text-[10px] leading-none whitespace-nowrap tabular-nums \
+     text-[var(--color-label-secondary)]";
+
+/// Label offset (horizontal track).
+pub const MARK_LABEL_HORIZONTAL: &str = "top-2 -translate-x-1/2";
+
+/// Label offset (vertical track).
+pub const MARK_LABEL_VERTICAL: &str = "left-3 translate-y-1/2";
+
 /// Disabled state.
 pub const DISABLED: &str = "opacity-50 cursor-not-allowed";
 
@@ -29,11 +130,36 @@ pub const DISABLED: &str = "opacity-50 cursor-not-allowed";
 pub fn catalog() -> Vec<(&'static str, &'static str)> {
     vec![
         ("WRAPPER", WRAPPER),
+        ("WRAPPER_VERTICAL", WRAPPER_VERTICAL),
+        ("TRACK_AREA", TRACK_AREA),
+        ("TRACK_AREA_VERTICAL", TRACK_AREA_VERTICAL),
         ("INPUT", INPUT),
+        ("INPUT_VERTICAL", INPUT_VERTICAL),
         ("SM", SM),
         ("MD", MD),
         ("LG", LG),
+        ("SM_VERTICAL", SM_VERTICAL),
+        ("MD_VERTICAL", MD_VERTICAL),
+        ("LG_VERTICAL", LG_VERTICAL),
+        ("RANGE_TRACK", RANGE_TRACK),
+        ("RANGE_TRACK_HORIZONTAL", RANGE_TRACK_HORIZONTAL),
+        ("RANGE_TRACK_VERTICAL", RANGE_TRACK_VERTICAL),
+        ("RANGE_FILL", RANGE_FILL),
+        ("RANGE_INPUT", RANGE_INPUT),
+        ("RANGE_AREA", RANGE_AREA),
+        ("RANGE_AREA_VERTICAL", RANGE_AREA_VERTICAL),
         ("LABEL", LABEL),
+        ("TOOLTIP", TOOLTIP),
+        ("TOOLTIP_HORIZONTAL", TOOLTIP_HORIZONTAL),
+        ("TOOLTIP_VERTICAL", TOOLTIP_VERTICAL),
+        ("MARKS", MARKS),
+        ("MARKS_VERTICAL", MARKS_VERTICAL),
+        ("MARK_TICK", MARK_TICK),
+        ("MARK_TICK_HORIZONTAL", MARK_TICK_HORIZONTAL),
+        ("MARK_TICK_VERTICAL", MARK_TICK_VERTICAL),
+        ("MARK_LABEL", MARK_LABEL),
+        ("MARK_LABEL_HORIZONTAL", MARK_LABEL_HORIZONTAL),
+        ("MARK_LABEL_VERTICAL", MARK_LABEL_VERTICAL),
         ("DISABLED", DISABLED),
     ]
 }
@@ -1,8 +1,9 @@
 //! EqTooltip — hover/focus tooltip atom.
 //!
 //! Wraps any child element and shows a small text bubble on hover or
-//! keyboard focus. Pure CSS positioning (no JS), four placement options,
-//! and optional delay.
+//! keyboard focus. Four preferred placements; the shared
+//! [`floating`](crate::floating) engine flips and shifts the bubble to
+//! keep it on screen and can point an arrow at the trigger.
 //!
 //! ```no_run
 //! use eq_ui::prelude::*;
@@ -17,15 +18,19 @@
 //! ```
 
 use super::eq_tooltip_styles as s;
+use crate::floating::{use_floating, Align, FloatingOptions, Placement, Side};
+use crate::overlay::EqPortal;
 use crate::theme::merge_classes;
 use crate::{PlaygroundEnum, playground};
 use dioxus::prelude::*;
 
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropSelect, PropInput, StyleInfo, format_catalog,
+    DemoSection, PropSelect, PropInput, PropToggle, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant, EqButton, ButtonVariant};
 #[cfg(feature = "playground")]
 use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
@@ -46,23 +51,38 @@ pub enum TooltipPosition {
     Right,
 }
 
+impl From<TooltipPosition> for Placement {
+    fn from(position: TooltipPosition) -> Self {
+        let side = match position {
+            TooltipPosition::Top => Side::Top,
+            TooltipPosition::Bottom => Side::Bottom,
+            TooltipPosition::Left => Side::Left,
+            TooltipPosition::Right => Side::Right,
+        };
+        Placement::new(side, Align::Center)
+    }
+}
+
 // ── Component ─────────────────────────────────────────────────────
 
 /// Hover/focus tooltip.
 ///
 /// Wraps its children and displays a positioned text bubble on
 /// `mouseenter` / `focusin` and hides on `mouseleave` / `focusout`.
+/// `position` is a preference: the bubble flips to the opposite side
+/// when it would leave the viewport.
 ///
 /// **Accessibility** — the tooltip text is connected to the trigger
 /// via `aria-describedby`, and the tooltip element uses `role="tooltip"`.
 /// The tooltip is keyboard-accessible through focus.
 #[playground(
     category = Atom,
-    description = "Hover/focus tooltip with four positions, \
-                   pure CSS positioning, and ARIA describedby.",
+    description = "Hover/focus tooltip with four positions, viewport-aware \
+                   flip/shift, optional arrow, and ARIA describedby.",
     examples = [
         ("Basic", "EqTooltip {\n    text: \"Save changes\",\n    EqButton { on_click: move |_| {}, \"Save\" }\n}"),
         ("Bottom", "EqTooltip {\n    text: \"More options\",\n    position: TooltipPosition::Bottom,\n    EqButton { on_click: move |_| {}, \"Options\" }\n}"),
+        ("With arrow", "EqTooltip {\n    text: \"Copy link\",\n    arrow: true,\n    EqButton { on_click: move |_| {}, \"Share\" }\n}"),
     ],
     custom_demo,
     custom_gallery,
@@ -75,6 +95,9 @@ pub fn EqTooltip(
     /// Placement relative to the trigger element.
     #[props(default)]
     position: TooltipPosition,
+    /// Show an arrow pointing at the trigger.
+    #[props(default = false)]
+    arrow: bool,
     /// Optional class override on the wrapper element.
     #[props(into, default)]
     class: String,
@@ -82,6 +105,12 @@ pub fn EqTooltip(
     children: Element,
 ) -> Element {
     let mut visible = use_signal(|| false);
+    let mut floating = use_floating(visible(), FloatingOptions {
+        placement: position.into(),
+        offset: 8.0,
+        arrow_padding: arrow.then_some(8.0),
+        ..Default::default()
+    });
 
     let pos_cls = match position {
         TooltipPosition::Top => s::POS_TOP,
@@ -107,14 +136,23 @@ pub fn EqTooltip(
             onmouseleave: move |_| visible.set(false),
             onfocusin: move |_| visible.set(true),
             onfocusout: move |_| visible.set(false),
+            onmounted: move |e| floating.set_anchor(e),
 
             {children}
 
-            div {
-                id: "{tooltip_id}",
-                class: "{s::TOOLTIP} {pos_cls} {vis_cls}",
-                role: "tooltip",
-                "{text}"
+            // Tooltip
+            EqPortal { active: visible(), passive: true,
+                div {
+                    id: "{tooltip_id}",
+                    class: "{s::TOOLTIP} {pos_cls} {vis_cls}",
+                    style: floating.style(),
+                    role: "tooltip",
+                    onmounted: move |e| floating.set_floating(e),
+                    "{text}"
+                    if arrow {
+                        span { class: s::ARROW, style: floating.arrow_style(), "aria-hidden": "true" }
+                    }
+                }
             }
         }
     }
@@ -127,6 +165,7 @@ pub fn EqTooltip(
 fn DemoEqTooltip() -> Element {
     let mut text = use_signal(|| "Tooltip text".to_string());
     let mut position_str = use_signal(|| "Top".to_string());
+    let mut arrow = use_signal(|| true);
 
     let position = match position_str().as_str() {
         "Bottom" => TooltipPosition::Bottom,
@@ -139,10 +178,12 @@ fn DemoEqTooltip() -> Element {
         r#"EqTooltip {{
     text: "{text}",
     position: TooltipPosition::{pos},
+    arrow: {arrow},
     EqButton {{ on_click: move |_| {{}}, "Hover me" }}
 }}"#,
         text = text(),
         pos = position_str(),
+        arrow = arrow(),
     );
 
     rsx! {
@@ -165,6 +206,7 @@ fn DemoEqTooltip() -> Element {
                     options: vec!["Top", "Bottom", "Left", "Right"],
                     onchange: move |v: String| position_str.set(v),
                 }
+                PropToggle { label: "arrow", value: arrow(), onchange: move |v: bool| arrow.set(v) }
             }
 
             div { class: "rounded-lg border border-dashed border-[var(--color-card-border)] p-6 space-y-6",
@@ -173,6 +215,7 @@ fn DemoEqTooltip() -> Element {
                     EqTooltip {
                         text: text(),
                         position,
+                        arrow: arrow(),
                         EqButton { on_click: move |_| {}, "Hover me" }
                     }
                 }
@@ -203,10 +246,24 @@ fn DemoEqTooltip() -> Element {
                         }
                     }
                 }
+
+                // Clipping container - the bubble escapes it and flips at the edge
+                div { class: "space-y-3",
+                    EqText { variant: TextVariant::Caption, class: "font-semibold uppercase tracking-wider", "Inside a scroll container" }
+                    div { class: "h-20 overflow-auto rounded-md border border-[var(--color-card-border)] p-2",
+                        div { class: "h-40 flex items-start",
+                            EqTooltip {
+                                text: "Flipped below, not clipped",
+                                arrow: arrow(),
+                                EqButton { variant: ButtonVariant::Outline, on_click: move |_| {}, "Top edge" }
+                            }
+                        }
+                    }
+                }
             }
 
             StyleInfo { file: "eq_tooltip_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
@@ -261,4 +318,10 @@ mod tests {
         let p: TooltipPosition = Default::default();
         assert!(matches!(p, TooltipPosition::Top));
     }
+
+    #[test]
+    fn positions_map_to_centred_placements() {
+        assert_eq!(Placement::from(TooltipPosition::Left), Placement::new(Side::Left, Align::Center));
+        assert_eq!(Placement::from(TooltipPosition::Bottom).side, Side::Bottom);
+    }
 }
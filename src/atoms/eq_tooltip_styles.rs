@@ -1,5 +1,9 @@
 //! Style constants for EqTooltip.
 
+use crate::floating::{
+    FALLBACK_CENTERED_BOTTOM, FALLBACK_CENTERED_LEFT, FALLBACK_CENTERED_RIGHT, FALLBACK_CENTERED_TOP,
+};
+
 /// Wrapper around the trigger element. Relative positioning anchor.
 pub const WRAPPER: &str = "relative inline-flex";
 
@@ -17,17 +21,17 @@ pub const VISIBLE: &str = "opacity-100";
 pub const HIDDEN: &str = "opacity-0";
 
 /// Position: top (default).
-pub const POS_TOP: &str =
-    "bottom-full left-1/2 -translate-x-1/2 mb-2";
+pub const POS_TOP: &str = FALLBACK_CENTERED_TOP;
 /// Position: bottom.
-pub const POS_BOTTOM: &str =
-    "top-full left-1/2 -translate-x-1/2 mt-2";
+pub const POS_BOTTOM: &str = FALLBACK_CENTERED_BOTTOM;
 /// Position: left.
-pub const POS_LEFT: &str =
-    "right-full top-1/2 -translate-y-1/2 mr-2";
+pub const POS_LEFT: &str = FALLBACK_CENTERED_LEFT;
 /// Position: right.
-pub const POS_RIGHT: &str =
-    "left-full top-1/2 -translate-y-1/2 ml-2";
+pub const POS_RIGHT: &str = FALLBACK_CENTERED_RIGHT;
+
+/// Arrow - a small rotated square placed by the floating engine.
+pub const ARROW: &str =
+    "w-2 h-2 rotate-45 bg-[var(--color-surface-overlay)]";
 
 /// All style tokens for playground introspection.
 pub fn catalog() -> Vec<(&'static str, &'static str)> {
@@ -40,5 +44,6 @@ pub fn catalog() -> Vec<(&'static str, &'static str)> {
         ("POS_BOTTOM", POS_BOTTOM),
         ("POS_LEFT", POS_LEFT),
         ("POS_RIGHT", POS_RIGHT),
+        ("ARROW", ARROW),
     ]
 }
@@ -21,7 +21,7 @@ pub use eq_text::{EqText, TextVariant};
 pub use eq_label::EqLabel;
 pub use eq_link::EqLink;
 pub use eq_input::{EqInput, InputKind};
-pub use eq_icon::{EqIcon, IconSize};
+pub use eq_icon::{EqIcon, IconData, IconPath, IconSize, IconStyle};
 pub use eq_image::{EqImage, AtomImageSize, AspectRatio, ObjectFit};
 
 pub mod eq_scrollable_space;
@@ -72,7 +72,7 @@ pub use eq_switch::{EqSwitch, SwitchSize};
 pub mod eq_slider;
 pub mod eq_slider_styles;
 
-pub use eq_slider::{EqSlider, SliderSize};
+pub use eq_slider::{EqSlider, SliderSize, SliderOrientation, SliderMark};
 
 pub mod eq_avatar;
 pub mod eq_avatar_styles;
@@ -88,3 +88,9 @@ pub mod eq_select;
 pub mod eq_select_styles;
 
 pub use eq_select::{EqSelect, SelectOption, SelectPosition};
+
+pub mod eq_code_block;
+pub mod eq_code_block_styles;
+pub mod eq_code_block_tokenizer;
+
+pub use eq_code_block::{EqCodeBlock, CodeLanguage, parse_line_ranges};
@@ -0,0 +1,604 @@
+//! Floating-element positioning shared by popups (tooltips, menus,
+//! listboxes, pickers).
+//!
+//! The placement math is plain Rust: given the anchor's rect, the
+//! floating element's size and the viewport, [`compute_position`]
+//! picks a side (flipping to the opposite side when the preferred one
+//! overflows), shifts along the cross axis to stay on screen, and
+//! reports where an optional arrow should point.
+//!
+//! [`use_floating`] wires that to the DOM: it measures both elements
+//! with `MountedEvent::get_client_rect`, positions the floating element
+//! with `position: fixed` (so scroll containers no longer clip it) and
+//! re-measures on scroll and resize while open. Until the first
+//! measurement lands - and on renderers without JS - the element keeps
+//! whatever static CSS placement its component gives it.
+//!
+//! An ancestor with `transform`, `filter` or `contain` becomes the
+//! containing block for fixed elements, which offsets the popup. The
+//! built-in popups avoid this by rendering through
+//! [`EqPortal`](crate::overlay::EqPortal) when an
+//! [`EqOverlayRoot`](crate::overlay::EqOverlayRoot) is mounted.
+//!
+//! ```no_run
+//! use eq_ui::prelude::*;
+//! use eq_ui::floating::{use_floating, FloatingOptions, Placement, Side, Align};
+//!
+//! fn app() -> Element {
+//!     let mut open = use_signal(|| false);
+//!     let mut floating = use_floating(open(), FloatingOptions {
+//!         placement: Placement::new(Side::Bottom, Align::Start),
+//!         ..Default::default()
+//!     });
+//!
+//!     rsx! {
+//!         button {
+//!             onmounted: move |e| floating.set_anchor(e),
+//!             onclick: move |_| open.toggle(),
+//!             "Menu"
+//!         }
+//!         div {
+//!             class: "absolute",
+//!             style: floating.style(),
+//!             onmounted: move |e| floating.set_floating(e),
+//!             "Popup"
+//!         }
+//!     }
+//! }
+//! ```
+
+use dioxus::core::Task;
+use dioxus::prelude::*;
+
+/// Side of the anchor the floating element sits on.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Side {
+    Top,
+    #[default]
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Side {
+    /// The side across the anchor.
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    fn is_vertical(self) -> bool {
+        matches!(self, Self::Top | Self::Bottom)
+    }
+}
+
+/// Alignment along the anchor's edge.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Align {
+    /// Left edges (top / bottom sides) or top edges (left / right sides) line up.
+    Start,
+    #[default]
+    Center,
+    End,
+}
+
+/// Preferred placement: a side plus an alignment on that side.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Placement {
+    pub side: Side,
+    pub align: Align,
+}
+
+impl Placement {
+    pub const fn new(side: Side, align: Align) -> Self {
+        Self { side, align }
+    }
+}
+
+/// How the floating element's width relates to the anchor's.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum MatchWidth {
+    /// Size to content.
+    #[default]
+    None,
+    /// At least as wide as the anchor (menus).
+    Min,
+    /// Exactly as wide as the anchor (listboxes).
+    Exact,
+}
+
+/// Positioning options.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FloatingOptions {
+    /// Preferred placement.
+    pub placement: Placement,
+    /// Gap between anchor and floating element, in px.
+    pub offset: f64,
+    /// Minimum distance kept from the viewport edges, in px.
+    pub padding: f64,
+    /// Move to the opposite side when the preferred side overflows.
+    pub flip: bool,
+    /// Slide along the anchor's edge to stay inside the viewport.
+    pub shift: bool,
+    /// Compute an arrow position. The arrow stays at least this many
+    /// px from the floating element's corners; `None` disables it.
+    pub arrow_padding: Option<f64>,
+    /// Width relation to the anchor.
+    pub match_width: MatchWidth,
+}
+
+impl Default for FloatingOptions {
+    fn default() -> Self {
+        Self {
+            placement: Placement::default(),
+            offset: 4.0,
+            padding: 8.0,
+            flip: true,
+            shift: true,
+            arrow_padding: None,
+            match_width: MatchWidth::None,
+        }
+    }
+}
+
+/// An axis-aligned rectangle in viewport px.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    pub const fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self { x, y, width, height }
+    }
+
+    fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+}
+
+/// Result of [`compute_position`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FloatingLayout {
+    /// Viewport x of the floating element's left edge.
+    pub x: f64,
+    /// Viewport y of the floating element's top edge.
+    pub y: f64,
+    /// Placement actually used (after flipping).
+    pub placement: Placement,
+    /// Arrow offset along the edge facing the anchor, from the
+    /// floating element's left (top / bottom sides) or top (left /
+    /// right sides). `None` when the arrow is disabled.
+    pub arrow: Option<f64>,
+}
+
+fn coords(anchor: Rect, width: f64, height: f64, placement: Placement, offset: f64) -> (f64, f64) {
+    let cross = |start: f64, anchor_len: f64, len: f64| match placement.align {
+        Align::Start => start,
+        Align::Center => start + (anchor_len - len) / 2.0,
+        Align::End => start + anchor_len - len,
+    };
+    match placement.side {
+        Side::Top => (cross(anchor.x, anchor.width, width), anchor.y - height - offset),
+        Side::Bottom => (cross(anchor.x, anchor.width, width), anchor.bottom() + offset),
+        Side::Left => (anchor.x - width - offset, cross(anchor.y, anchor.height, height)),
+        Side::Right => (anchor.right() + offset, cross(anchor.y, anchor.height, height)),
+    }
+}
+
+/// How far (px) the element overflows the viewport on its main side.
+/// Zero or negative means it fits.
+fn main_overflow(side: Side, x: f64, y: f64, width: f64, height: f64, viewport: Rect, padding: f64) -> f64 {
+    match side {
+        Side::Top => viewport.y + padding - y,
+        Side::Bottom => y + height - (viewport.bottom() - padding),
+        Side::Left => viewport.x + padding - x,
+        Side::Right => x + width - (viewport.right() - padding),
+    }
+}
+
+/// Clamps `pos` so `[pos, pos + len]` stays within `[min, max]`; pins to
+/// `min` when it cannot fit.
+fn clamp_span(pos: f64, len: f64, min: f64, max: f64) -> f64 {
+    pos.min(max - len).max(min)
+}
+
+/// Places a `width` × `height` floating element next to `anchor`
+/// inside `viewport`.
+pub fn compute_position(
+    anchor: Rect,
+    width: f64,
+    height: f64,
+    viewport: Rect,
+    options: &FloatingOptions,
+) -> FloatingLayout {
+    let mut placement = options.placement;
+    let (mut x, mut y) = coords(anchor, width, height, placement, options.offset);
+
+    if options.flip {
+        let overflow = main_overflow(placement.side, x, y, width, height, viewport, options.padding);
+        if overflow > 0.0 {
+            let flipped = Placement { side: placement.side.opposite(), ..placement };
+            let (fx, fy) = coords(anchor, width, height, flipped, options.offset);
+            let flipped_overflow = main_overflow(flipped.side, fx, fy, width, height, viewport, options.padding);
+            // Neither side fits: take the one with more room.
+            if flipped_overflow < overflow {
+                placement = flipped;
+                (x, y) = (fx, fy);
+            }
+        }
+    }
+
+    if options.shift {
+        let p = options.padding;
+        if placement.side.is_vertical() {
+            x = clamp_span(x, width, viewport.x + p, viewport.right() - p);
+        } else {
+            y = clamp_span(y, height, viewport.y + p, viewport.bottom() - p);
+        }
+    }
+
+    let arrow = options.arrow_padding.map(|pad| {
+        let (center, start, len) = if placement.side.is_vertical() {
+            (anchor.x + anchor.width / 2.0, x, width)
+        } else {
+            (anchor.y + anchor.height / 2.0, y, height)
+        };
+        let pad = pad.min(len / 2.0);
+        (center - start).clamp(pad, len - pad)
+    });
+
+    FloatingLayout { x, y, placement, arrow }
+}
+
+/// Inline style that pins the floating element at `layout`. Clears any
+/// static placement classes (`inset`, margins, translate) it carries.
+pub fn floating_style(layout: &FloatingLayout, anchor_width: f64, match_width: MatchWidth) -> String {
+    let width = match match_width {
+        MatchWidth::None => String::new(),
+        MatchWidth::Min => format!(" min-width: {anchor_width:.1}px;"),
+        MatchWidth::Exact => format!(" width: {anchor_width:.1}px;"),
+    };
+    format!(
+        "position: fixed; inset: auto; margin: 0; translate: none; left: {:.1}px; top: {:.1}px;{width}",
+        layout.x, layout.y
+    )
+}
+
+/// Inline style for an arrow element inside the floating element: it
+/// is centred on the edge facing the anchor. Pair with a small rotated
+/// square (`rotate-45`).
+pub fn arrow_style(layout: &FloatingLayout) -> String {
+    let Some(at) = layout.arrow else { return "display: none;".into() };
+    let edge = match layout.placement.side {
+        Side::Top => format!("left: {at:.1}px; top: 100%;"),
+        Side::Bottom => format!("left: {at:.1}px; top: 0;"),
+        Side::Left => format!("top: {at:.1}px; left: 100%;"),
+        Side::Right => format!("top: {at:.1}px; left: 0;"),
+    };
+    format!("position: absolute; {edge} translate: -50% -50%;")
+}
+
+// ── Static fallback ───────────────────────────────────────────────
+//
+// Tailwind classes that place an `absolute` popup against its
+// `relative` wrapper with CSS alone. Components apply them until
+// `use_floating` has measured, and on renderers without JS.
+
+/// Menu or listbox below its trigger, start-aligned. The gap comes
+/// from the menu's own top margin.
+pub const FALLBACK_MENU_BOTTOM: &str = "top-full left-0";
+/// Menu or listbox above its trigger; its top margin moves below it.
+pub const FALLBACK_MENU_TOP: &str = "bottom-full left-0 mb-1 mt-0";
+
+/// Panel above its trigger, start-aligned with a gap.
+pub const FALLBACK_PANEL_TOP: &str = "bottom-full left-0 mb-2";
+/// Panel below its trigger, start-aligned with a gap.
+pub const FALLBACK_PANEL_BOTTOM: &str = "top-full left-0 mt-2";
+/// Panel left of its trigger, top-aligned with a gap.
+pub const FALLBACK_PANEL_LEFT: &str = "right-full top-0 mr-2";
+/// Panel right of its trigger, top-aligned with a gap.
+pub const FALLBACK_PANEL_RIGHT: &str = "left-full top-0 ml-2";
+
+/// Tooltip centred above its trigger.
+pub const FALLBACK_CENTERED_TOP: &str = "bottom-full left-1/2 -translate-x-1/2 mb-2";
+/// Tooltip centred below its trigger.
+pub const FALLBACK_CENTERED_BOTTOM: &str = "top-full left-1/2 -translate-x-1/2 mt-2";
+/// Tooltip centred left of its trigger.
+pub const FALLBACK_CENTERED_LEFT: &str = "right-full top-1/2 -translate-y-1/2 mr-2";
+/// Tooltip centred right of its trigger.
+pub const FALLBACK_CENTERED_RIGHT: &str = "left-full top-1/2 -translate-y-1/2 ml-2";
+
+// ── Hook ──────────────────────────────────────────────────────────
+
+/// Listens for scroll (any container) and resize while a popup is
+/// open and sends the viewport size, at most once per frame. Stops
+/// when Rust sends a message.
+const TRACK_JS: &str = r#"
+let queued = false;
+const tick = () => {
+    if (queued) return;
+    queued = true;
+    requestAnimationFrame(() => {
+        queued = false;
+        const d = document.documentElement;
+        dioxus.send([d.clientWidth, d.clientHeight]);
+    });
+};
+window.addEventListener('scroll', tick, true);
+window.addEventListener('resize', tick);
+tick();
+await dioxus.recv();
+window.removeEventListener('scroll', tick, true);
+window.removeEventListener('resize', tick);
+"#;
+
+/// What the floating element is placed against.
+#[derive(Clone)]
+enum Anchor {
+    /// A mounted element, measured on every update.
+    Element(MountedEvent),
+    /// A fixed viewport rectangle, e.g. the pointer position.
+    Rect(Rect),
+}
+
+/// Handle returned by [`use_floating`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseFloating {
+    anchor: Signal<Option<Anchor>>,
+    floating: Signal<Option<MountedEvent>>,
+    viewport: Signal<Option<Rect>>,
+    layout: Signal<Option<(FloatingLayout, f64)>>,
+    options: Signal<FloatingOptions>,
+    tracker: Signal<Option<(document::Eval, Task)>>,
+    portaled: bool,
+}
+
+impl UseFloating {
+    /// Register the anchor element (pass from its `onmounted`).
+    pub fn set_anchor(&mut self, element: MountedEvent) {
+        self.anchor.set(Some(Anchor::Element(element)));
+    }
+
+    /// Anchor to a viewport rectangle instead of an element - a
+    /// zero-size rect at the pointer for context menus.
+    pub fn set_anchor_rect(&mut self, rect: Rect) {
+        self.anchor.set(Some(Anchor::Rect(rect)));
+        self.update();
+    }
+
+    /// Register the floating element (pass from its `onmounted`).
+    pub fn set_floating(&mut self, element: MountedEvent) {
+        self.floating.set(Some(element));
+    }
+
+    /// Latest computed layout, once measured.
+    pub fn layout(&self) -> Option<FloatingLayout> {
+        (self.layout)().map(|(layout, _)| layout)
+    }
+
+    /// Placement in use - the preferred one until measured.
+    pub fn placement(&self) -> Placement {
+        self.layout().map_or(self.options.peek().placement, |l| l.placement)
+    }
+
+    /// Inline style for the floating element. Until measured it is
+    /// empty, or hides the element when it renders in an overlay root
+    /// (where the static CSS placement has no anchor to sit against).
+    pub fn style(&self) -> String {
+        let match_width = self.options.peek().match_width;
+        let unmeasured = if self.portaled { "visibility: hidden;" } else { "" };
+        (self.layout)()
+            .map_or_else(|| unmeasured.into(), |(layout, aw)| floating_style(&layout, aw, match_width))
+    }
+
+    /// Inline style for the arrow element; hidden until measured.
+    pub fn arrow_style(&self) -> String {
+        self.layout().map_or_else(|| "display: none;".into(), |l| arrow_style(&l))
+    }
+
+    /// Re-measure now, e.g. after the floating content changed size.
+    pub fn update(&self) {
+        if let Some(viewport) = *self.viewport.peek() {
+            spawn(measure(*self, viewport));
+        }
+    }
+
+    fn start(&mut self) {
+        let mut eval = document::eval(TRACK_JS);
+        let handle = *self;
+        let mut viewport = self.viewport;
+        let task = spawn(async move {
+            while let Ok((w, h)) = eval.recv::<(f64, f64)>().await {
+                let rect = Rect::new(0.0, 0.0, w, h);
+                viewport.set(Some(rect));
+                measure(handle, rect).await;
+            }
+        });
+        self.stop();
+        self.tracker.set(Some((eval, task)));
+    }
+
+    fn stop(&mut self) {
+        if let Some((eval, task)) = self.tracker.write().take() {
+            let _ = eval.send(true);
+            task.cancel();
+        }
+    }
+}
+
+async fn measure(mut handle: UseFloating, viewport: Rect) {
+    let anchor = handle.anchor.peek().clone();
+    let floating = handle.floating.peek().clone();
+    let (Some(anchor), Some(floating)) = (anchor, floating) else { return };
+    let anchor = match anchor {
+        Anchor::Element(element) => {
+            let Ok(a) = element.get_client_rect().await else { return };
+            Rect::new(a.origin.x, a.origin.y, a.width(), a.height())
+        }
+        Anchor::Rect(rect) => rect,
+    };
+    let Ok(f) = floating.get_client_rect().await else { return };
+    let options = *handle.options.peek();
+    let width = match options.match_width {
+        MatchWidth::Exact => anchor.width,
+        MatchWidth::Min => f.width().max(anchor.width),
+        MatchWidth::None => f.width(),
+    };
+    let layout = compute_position(anchor, width, f.height(), viewport, &options);
+    if *handle.layout.peek() != Some((layout, anchor.width)) {
+        handle.layout.set(Some((layout, anchor.width)));
+    }
+}
+
+/// Positions a floating element against an anchor while `open`.
+///
+/// Register both elements through `onmounted` (or anchor to a point
+/// with [`UseFloating::set_anchor_rect`]) and apply
+/// [`UseFloating::style`] to the floating element. Tracking starts when
+/// `open` turns true and stops when it turns false or the component
+/// unmounts; the last layout is kept while closed so exit transitions
+/// do not jump.
+pub fn use_floating(open: bool, options: FloatingOptions) -> UseFloating {
+    let handle = UseFloating {
+        anchor: use_signal(|| None),
+        floating: use_signal(|| None),
+        viewport: use_signal(|| None),
+        layout: use_signal(|| None),
+        options: use_signal(|| options),
+        tracker: use_signal(|| None),
+        portaled: crate::overlay::use_overlay().is_some(),
+    };
+    let mut was_open = use_signal(|| false);
+
+    use_effect(use_reactive((&open, &options), move |(open, options)| {
+        let mut handle = handle;
+        if *handle.options.peek() != options {
+            handle.options.set(options);
+            handle.update();
+        }
+        if open != *was_open.peek() {
+            was_open.set(open);
+            if open {
+                handle.start();
+            } else {
+                handle.stop();
+            }
+        }
+    }));
+
+    use_drop(move || {
+        let mut handle = handle;
+        handle.stop();
+    });
+
+    handle
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: Rect = Rect::new(0.0, 0.0, 800.0, 600.0);
+
+    fn opts(side: Side, align: Align) -> FloatingOptions {
+        FloatingOptions { placement: Placement::new(side, align), ..Default::default() }
+    }
+
+    #[test]
+    fn places_on_each_side() {
+        let anchor = Rect::new(300.0, 200.0, 100.0, 40.0);
+        let at = |side, align| {
+            let l = compute_position(anchor, 60.0, 20.0, VIEWPORT, &opts(side, align));
+            (l.x, l.y)
+        };
+        assert_eq!(at(Side::Bottom, Align::Start), (300.0, 244.0));
+        assert_eq!(at(Side::Bottom, Align::Center), (320.0, 244.0));
+        assert_eq!(at(Side::Bottom, Align::End), (340.0, 244.0));
+        assert_eq!(at(Side::Top, Align::Start), (300.0, 176.0));
+        assert_eq!(at(Side::Left, Align::Center), (236.0, 210.0));
+        assert_eq!(at(Side::Right, Align::End), (404.0, 220.0));
+    }
+
+    #[test]
+    fn flips_when_preferred_side_overflows() {
+        let anchor = Rect::new(100.0, 560.0, 80.0, 30.0);
+        let l = compute_position(anchor, 120.0, 200.0, VIEWPORT, &opts(Side::Bottom, Align::Start));
+        assert_eq!(l.placement.side, Side::Top);
+        assert_eq!(l.y, 560.0 - 200.0 - 4.0);
+
+        let anchor = Rect::new(10.0, 300.0, 40.0, 20.0);
+        let l = compute_position(anchor, 100.0, 20.0, VIEWPORT, &opts(Side::Left, Align::Center));
+        assert_eq!(l.placement.side, Side::Right);
+    }
+
+    #[test]
+    fn no_flip_when_disabled_or_when_opposite_is_worse() {
+        let anchor = Rect::new(100.0, 560.0, 80.0, 30.0);
+        let mut o = opts(Side::Bottom, Align::Start);
+        o.flip = false;
+        assert_eq!(compute_position(anchor, 120.0, 200.0, VIEWPORT, &o).placement.side, Side::Bottom);
+
+        // Anchor near the top; a tall popup overflows both ways but
+        // there is more room below.
+        let anchor = Rect::new(100.0, 100.0, 80.0, 30.0);
+        let l = compute_position(anchor, 120.0, 700.0, VIEWPORT, &opts(Side::Bottom, Align::Start));
+        assert_eq!(l.placement.side, Side::Bottom);
+    }
+
+    #[test]
+    fn shifts_along_cross_axis() {
+        let anchor = Rect::new(760.0, 100.0, 30.0, 20.0);
+        let l = compute_position(anchor, 200.0, 40.0, VIEWPORT, &opts(Side::Bottom, Align::Center));
+        assert_eq!(l.x, 800.0 - 8.0 - 200.0);
+
+        let anchor = Rect::new(0.0, 100.0, 30.0, 20.0);
+        let l = compute_position(anchor, 200.0, 40.0, VIEWPORT, &opts(Side::Top, Align::End));
+        assert_eq!(l.x, 8.0);
+
+        let mut o = opts(Side::Bottom, Align::Center);
+        o.shift = false;
+        let l = compute_position(Rect::new(760.0, 100.0, 30.0, 20.0), 200.0, 40.0, VIEWPORT, &o);
+        assert_eq!(l.x, 675.0);
+    }
+
+    #[test]
+    fn arrow_tracks_anchor_centre_and_is_clamped() {
+        let mut o = opts(Side::Bottom, Align::Center);
+        o.arrow_padding = Some(10.0);
+        let l = compute_position(Rect::new(300.0, 100.0, 100.0, 20.0), 60.0, 30.0, VIEWPORT, &o);
+        assert_eq!(l.arrow, Some(30.0));
+
+        // Shifted away from the anchor: the arrow still points at it,
+        // but never leaves the padded edge.
+        let l = compute_position(Rect::new(780.0, 100.0, 20.0, 20.0), 200.0, 30.0, VIEWPORT, &o);
+        assert_eq!(l.arrow, Some(190.0));
+
+        assert_eq!(compute_position(Rect::new(300.0, 100.0, 100.0, 20.0), 60.0, 30.0, VIEWPORT, &opts(Side::Top, Align::Center)).arrow, None);
+    }
+
+    #[test]
+    fn styles() {
+        let layout = FloatingLayout { x: 10.0, y: 20.5, placement: Placement::new(Side::Top, Align::Start), arrow: Some(12.0) };
+        let style = floating_style(&layout, 90.0, MatchWidth::Exact);
+        assert!(style.contains("position: fixed;"));
+        assert!(style.contains("left: 10.0px; top: 20.5px;"));
+        assert!(style.ends_with("width: 90.0px;"));
+        assert!(!floating_style(&layout, 90.0, MatchWidth::None).contains("width"));
+        assert!(arrow_style(&layout).contains("left: 12.0px; top: 100%;"));
+        assert_eq!(arrow_style(&FloatingLayout { arrow: None, ..layout }), "display: none;");
+    }
+}
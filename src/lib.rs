@@ -18,6 +18,11 @@ pub const UI_BUTTONS_CSS: Asset = asset!("/assets/theme/buttons.css");
 #[cfg(feature = "playground")]
 pub mod playground;
 pub mod theme;
+pub mod floating;
+pub mod overlay;
+pub mod timer;
+pub mod sanitize;
+pub mod shortcut;
 pub mod atoms;
 pub mod molecules;
 pub mod organisms;
@@ -60,6 +65,7 @@ pub fn all_component_descriptors() -> Vec<ComponentDescriptor> {
         atoms::eq_avatar::descriptor(),
         atoms::eq_tooltip::descriptor(),
         atoms::eq_select::descriptor(),
+        atoms::eq_code_block::descriptor(),
         // Molecules
         molecules::eq_card::descriptor(),
         molecules::eq_image_card::descriptor(),
@@ -70,11 +76,19 @@ pub fn all_component_descriptors() -> Vec<ComponentDescriptor> {
         molecules::eq_cta::descriptor(),
         molecules::eq_modal::descriptor(),
         molecules::eq_toast::descriptor(),
+        molecules::eq_toaster::descriptor(),
+        molecules::eq_dialogs::descriptor(),
         molecules::eq_dropdown::descriptor(),
+        molecules::eq_context_menu::descriptor(),
+        molecules::eq_popover::descriptor(),
+        molecules::eq_hover_card::descriptor(),
         molecules::eq_date_picker::descriptor(),
         molecules::eq_calendar::descriptor(),
         molecules::eq_virtual_list::descriptor(),
+        molecules::eq_virtual_grid::descriptor(),
         molecules::eq_device_frame::descriptor(),
+        molecules::eq_markdown::descriptor(),
+        molecules::eq_color_picker::descriptor(),
         // Organisms
         organisms::eq_header::descriptor(),
         organisms::eq_footer::descriptor(),
@@ -88,6 +102,9 @@ pub fn all_component_descriptors() -> Vec<ComponentDescriptor> {
         organisms::eq_toolbar::descriptor(),
         organisms::eq_bottom_nav::descriptor(),
         organisms::eq_mobile_app_shell::descriptor(),
+        organisms::eq_rich_text_editor::editor::descriptor(),
+        organisms::eq_command_palette::palette::descriptor(),
+        overlay::root::descriptor(),
         // Theming
         playground::theme_showcase::descriptor(),
     ]
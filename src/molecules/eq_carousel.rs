@@ -1,13 +1,17 @@
 use super::eq_carousel_styles as s;
 use crate::theme::merge_classes;
+use crate::timer::sleep;
 use crate::{PlaygroundEnum, playground};
+use dioxus::core::Task;
 use dioxus::prelude::*;
 
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropSelect, PropInput, StyleInfo, format_catalog,
+    DemoSection, PropSelect, PropInput, PropToggle, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant, AtomImageSize, AspectRatio};
 #[cfg(feature = "playground")]
 use crate::molecules::eq_image_card::{EqImageCard, CaptionMode};
@@ -29,6 +33,37 @@ pub enum CarouselMode {
     Peek,
 }
 
+// ---------------------------------------------------------------------------
+// CarouselIndicators / CarouselBreakpoint
+// ---------------------------------------------------------------------------
+
+/// Pagination shown under the slides.
+#[derive(Clone, PartialEq, Default, PlaygroundEnum)]
+pub enum CarouselIndicators {
+    /// One dot per position.
+    #[default]
+    Dots,
+    /// The `thumbnails` elements as buttons.
+    Thumbnails,
+    /// No pagination; arrows, keys and swipes only.
+    Hidden,
+}
+
+/// Slides per view from a carousel width up.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CarouselBreakpoint {
+    /// Carousel width in pixels from which this applies.
+    pub min_width: f64,
+    /// Slides shown at once.
+    pub per_view: usize,
+}
+
+impl CarouselBreakpoint {
+    pub fn new(min_width: f64, per_view: usize) -> Self {
+        Self { min_width, per_view }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Internal arrow button helper
 // ---------------------------------------------------------------------------
@@ -62,6 +97,148 @@ fn CarouselArrow(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Track geometry
+// ---------------------------------------------------------------------------
+
+/// Share of a slide a slow swipe must cover to change slides.
+const SWIPE_THRESHOLD: f64 = 0.2;
+
+/// Frames a released swipe keeps travelling at its last speed when
+/// picking the slide to settle on.
+const MOMENTUM_FRAMES: f64 = 8.0;
+
+/// Pointer travel (px) below which a press counts as a click.
+const CLICK_SLOP: f64 = 6.0;
+
+/// Slides per view for a carousel `width` pixels wide: the breakpoint
+/// with the largest `min_width` the width reaches, else `per_view`.
+fn per_view_at(width: f64, per_view: usize, breakpoints: &[CarouselBreakpoint]) -> usize {
+    breakpoints
+        .iter()
+        .filter(|b| width >= b.min_width)
+        .max_by(|a, b| a.min_width.total_cmp(&b.min_width))
+        .map_or(per_view, |b| b.per_view)
+        .max(1)
+}
+
+/// Slides to move after a swipe of `drag` px released moving at
+/// `velocity` px per frame; positive is forward. Pointer moves arrive
+/// about once a frame, so the last move's distance is the velocity.
+fn swipe_steps(drag: f64, velocity: f64, slide_span: f64) -> isize {
+    if slide_span <= 0.0 || drag.abs() < CLICK_SLOP {
+        return 0;
+    }
+    let projected = drag + velocity * MOMENTUM_FRAMES;
+    let steps = (-projected / slide_span).round() as isize;
+    if steps == 0 && drag.abs() >= slide_span * SWIPE_THRESHOLD {
+        if drag < 0.0 { 1 } else { -1 }
+    } else {
+        steps
+    }
+}
+
+/// Positions of the slide strip. In a looping carousel the strip is
+/// padded with `clones` copies of the slides from the other end, and a
+/// move onto a copy is followed by a jump, without animation, to the
+/// real slide it shows.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Track {
+    len: usize,
+    per_view: usize,
+    clones: usize,
+}
+
+impl Track {
+    fn new(len: usize, per_view: usize, infinite: bool, peek: bool) -> Self {
+        let per_view = per_view.clamp(1, len.max(1));
+        // Peek shows part of one more slide on each side.
+        let clones = if infinite && len > per_view { per_view + usize::from(peek) } else { 0 };
+        Self { len, per_view, clones }
+    }
+
+    /// Slides in the strip, copies included.
+    fn total(&self) -> usize {
+        self.len + 2 * self.clones
+    }
+
+    /// Last position a non-looping carousel can start at.
+    fn max_start(&self) -> usize {
+        self.len.saturating_sub(self.per_view)
+    }
+
+    /// Index the pagination tracks: every slide when looping, every
+    /// valid first slide otherwise.
+    fn pages(&self) -> usize {
+        if self.clones > 0 { self.len } else { self.max_start() + 1 }
+    }
+
+    /// Slide shown at strip `position`.
+    fn slide_at(&self, position: usize) -> usize {
+        if self.len == 0 {
+            return 0;
+        }
+        (position as isize - self.clones as isize).rem_euclid(self.len as isize) as usize
+    }
+
+    /// Strip position of the first view starting at slide `index`.
+    fn position_of(&self, index: usize) -> usize {
+        if self.clones > 0 { index % self.len.max(1) + self.clones } else { index.min(self.max_start()) }
+    }
+
+    /// The real position for a copy, if `position` is one.
+    fn normalize(&self, position: usize) -> Option<usize> {
+        if self.clones == 0 {
+            return None;
+        }
+        let real = self.position_of(self.slide_at(position));
+        (real != position).then_some(real)
+    }
+
+    /// Position after moving `delta` slides from `position`. Looping
+    /// carousels run on into the copies; the others rewind from the
+    /// last view to the first and back.
+    fn step(&self, position: usize, delta: isize) -> usize {
+        if self.clones > 0 {
+            let position = self.normalize(position).unwrap_or(position);
+            let last = (self.total() - self.per_view) as isize;
+            return (position as isize + delta).clamp(0, last) as usize;
+        }
+        let max = self.max_start() as isize;
+        let next = position as isize + delta;
+        if next > max {
+            if position as isize == max { 0 } else { max as usize }
+        } else if next < 0 {
+            if position == 0 { max as usize } else { 0 }
+        } else {
+            next as usize
+        }
+    }
+}
+
+/// Inline styles of the strip and of each slide showing `per_view`
+/// slides from strip `position`, shifted by `drag` px.
+fn strip_style(position: usize, per_view: usize, gap: u32, peek: bool, drag: f64) -> (String, String) {
+    // Gap only matters when more than one slide can be seen.
+    let gap = if peek || per_view > 1 { gap } else { 0 };
+    let (view, lead) = if peek { (80, 10) } else { (100, 0) };
+    let gaps = (per_view as u32 - 1) * gap;
+    let slide = format!("(({view}% - {gaps}px) / {per_view})");
+    let strip = format!(
+        "gap: {gap}px; transform: translateX(calc({lead}% - {position} * ({slide} + {gap}px) + {drag}px));"
+    );
+    (strip, format!("width: calc{slide};"))
+}
+
+/// A pointer swipe in progress.
+#[derive(Clone, Copy, PartialEq)]
+struct Drag {
+    start_x: f64,
+    last_x: f64,
+    /// Px per pointer move, smoothed.
+    velocity: f64,
+}
+
 // ---------------------------------------------------------------------------
 // EqCarousel
 // ---------------------------------------------------------------------------
@@ -69,15 +246,36 @@ fn CarouselArrow(
 /// Generic carousel molecule.
 ///
 /// Cycles through any content passed as a `Vec<Element>`.
-/// Shows previous/next arrows and dot indicators when there are multiple slides.
-/// Supports two modes: `Default` (full-width) and `Peek` (show neighbours).
+/// Shows previous/next arrows and dot (or thumbnail) indicators when
+/// there are multiple slides. Supports two modes: `Default` (full-width)
+/// and `Peek` (show neighbours), either showing `per_view` slides at a
+/// time, with `breakpoints` changing that count by carousel width.
+///
+/// **Autoplay** — with `autoplay` set the carousel advances every that
+/// many milliseconds. It pauses while hovered or focused, never starts
+/// when the user prefers reduced motion, and shows a pause button.
+///
+/// **Swipe** — touch and mouse drags move the strip; a fast flick
+/// carries on over several slides.
+///
+/// **Loop** — with `infinite` the last slide is followed by the first
+/// without rewinding. Without it, the carousel rewinds from the last
+/// view to the first.
+///
+/// **Controlled** — pass `current` to drive the slide from outside;
+/// every move is reported through `on_change` either way.
 #[playground(
     category = Molecule,
-    description = "Carousel molecule cycling through slides with arrow navigation and dot indicators. \
-                   Supports default full-width and peek (neighbours visible) modes.",
+    description = "Carousel molecule cycling through slides with arrow navigation and dot or thumbnail \
+                   indicators. Default full-width and peek modes, several slides per view with \
+                   responsive breakpoints, autoplay with a pause control, swipe with momentum, \
+                   infinite loop and a controlled index.",
     examples = [
         ("Default mode", "EqCarousel {\n    slides: vec![\n        rsx! { /* slide content */ },\n        rsx! { /* slide content */ },\n    ],\n}"),
         ("Peek mode", "EqCarousel {\n    mode: CarouselMode::Peek,\n    gap: 24,\n    slides: vec![ /* ... */ ],\n}"),
+        ("Autoplay loop", "EqCarousel {\n    autoplay: 5000,\n    infinite: true,\n    slides: vec![ /* ... */ ],\n}"),
+        ("Responsive", "EqCarousel {\n    per_view: 1,\n    breakpoints: vec![\n        CarouselBreakpoint::new(640.0, 2),\n        CarouselBreakpoint::new(1024.0, 4),\n    ],\n    gap: 16,\n    slides: vec![ /* ... */ ],\n}"),
+        ("Controlled", "EqCarousel {\n    current: slide(),\n    on_change: move |i| slide.set(i),\n    indicators: CarouselIndicators::Thumbnails,\n    thumbnails: vec![ /* one per slide */ ],\n    slides: vec![ /* ... */ ],\n}"),
     ],
     custom_demo,
     custom_gallery,
@@ -89,9 +287,38 @@ pub fn EqCarousel(
     /// Visual mode - `Default` or `Peek`.
     #[props(default)]
     mode: CarouselMode,
-    /// Gap between slides in pixels (only applies in Peek mode).
+    /// Gap between slides in pixels (Peek mode, or more than one slide
+    /// per view).
     #[props(default = 12)]
     gap: u32,
+    /// Slides shown at once when no breakpoint applies.
+    #[props(default = 1)]
+    per_view: usize,
+    /// Slides per view by carousel width; the largest `min_width` the
+    /// carousel reaches wins.
+    #[props(default)]
+    breakpoints: Vec<CarouselBreakpoint>,
+    /// Advance every this many milliseconds. Pauses on hover and focus;
+    /// off when the user prefers reduced motion.
+    #[props(default)]
+    autoplay: Option<u64>,
+    /// Follow the last slide with the first instead of rewinding.
+    #[props(default = false)]
+    infinite: bool,
+    /// Controlled index of the first visible slide. Leave unset to let
+    /// the carousel keep its own.
+    #[props(default)]
+    current: Option<usize>,
+    /// Called with the new first visible slide after every move.
+    #[props(default)]
+    on_change: Option<EventHandler<usize>>,
+    /// Pagination under the slides.
+    #[props(default)]
+    indicators: CarouselIndicators,
+    /// Thumbnail content for `CarouselIndicators::Thumbnails`, one per
+    /// slide. Falls back to dots when empty.
+    #[props(default)]
+    thumbnails: Vec<Element>,
     /// Accessible label for screen readers (e.g. "Product images",
     /// "Featured articles"). Announced as "{label}, carousel".
     #[props(into, default = "Carousel".to_string())]
@@ -100,176 +327,254 @@ pub fn EqCarousel(
     #[props(into, default)]
     class: String,
 ) -> Element {
-    let mut current = use_signal(|| 0usize);
     let len = slides.len();
+    let peek = mode == CarouselMode::Peek;
+
+    // ── Size ─────────────────────────────────────────────────────
+    let mut width = use_signal(|| 0.0_f64);
+    let track = Track::new(len, per_view_at(width(), per_view, &breakpoints), infinite, peek);
+
+    // ── Position ─────────────────────────────────────────────────
+    let mut position = use_signal(|| track.position_of(current.unwrap_or(0)));
+    // Off for the jump from a copy to its real slide.
+    let mut animate = use_signal(|| true);
+    let mut drag = use_signal(|| None::<Drag>);
+
+    // Clamped, as the slide count or slides per view may have shrunk.
+    let last = track.total().saturating_sub(track.per_view);
+    let here = move || (*position.peek()).min(last);
+    let at = position().min(last);
+    let index = track.slide_at(at);
+
+    // Follow the controlled index.
+    use_effect(use_reactive((&current, &track), move |(current, track)| {
+        if let Some(current) = current
+            && track.slide_at(here()) != current
+        {
+            position.set(track.position_of(current));
+        }
+    }));
 
-    let go_prev = move |_| {
-        current.set(if current() == 0 { len - 1 } else { current() - 1 });
-    };
-    let go_next = move |_| {
-        current.set(if current() + 1 >= len { 0 } else { current() + 1 });
+    let mut go = move |to: usize| {
+        if to == here() {
+            return;
+        }
+        let from = track.slide_at(here());
+        animate.set(true);
+        position.set(to);
+        let next = track.slide_at(to);
+        if let (true, Some(handler)) = (next != from, on_change) {
+            handler.call(next);
+        }
     };
+    let mut go_by = move |delta: isize| go(track.step(here(), delta));
+
+    // ── Autoplay ─────────────────────────────────────────────────
+    let mut hovered = use_signal(|| false);
+    let mut focused = use_signal(|| false);
+    let mut stopped = use_signal(|| false);
+    let mut reduced_motion = use_signal(|| false);
+    use_effect(move || {
+        spawn(async move {
+            let query = "return window.matchMedia('(prefers-reduced-motion: reduce)').matches;";
+            if let Ok(reduce) = document::eval(query).join::<bool>().await {
+                reduced_motion.set(reduce);
+            }
+        });
+    });
+    let playing = autoplay.is_some() && len > 1 && !stopped() && !reduced_motion();
+    let running = playing && !hovered() && !focused() && drag().is_none();
+    let mut timer = use_signal(|| None::<Task>);
+    // Re-armed after every move, so manual navigation restarts the wait.
+    use_effect(use_reactive((&running, &autoplay, &index), move |(running, autoplay, _)| {
+        if let Some(task) = timer.take() {
+            task.cancel();
+        }
+        if let (true, Some(ms)) = (running, autoplay) {
+            timer.set(Some(spawn(async move {
+                sleep(ms).await;
+                go_by(1);
+            })));
+        }
+    }));
 
+    // ── Render ───────────────────────────────────────────────────
     let show_controls = len > 1;
-    let _slide_label = format!("Slide {} of {}", current() + 1, len);
-
-    match mode {
-        CarouselMode::Default => {
-            let offset = format!("transform: translateX(-{}%);", current() * 100);
-
-            let cls = merge_classes(s::CAROUSEL, &class);
-            rsx! {
-                div {
-                    class: "{cls}",
-                    role: "region",
-                    "aria-roledescription": "carousel",
-                    "aria-label": "{aria_label}",
-                    onkeydown: move |evt: Event<KeyboardData>| {
-                        match evt.key() {
-                            Key::ArrowLeft => {
-                                evt.prevent_default();
-                                current.set(if current() == 0 { len - 1 } else { current() - 1 });
-                            }
-                            Key::ArrowRight => {
-                                evt.prevent_default();
-                                current.set(if current() + 1 >= len { 0 } else { current() + 1 });
-                            }
-                            _ => {}
-                        }
-                    },
+    let drag_px = drag().map_or(0.0, |d| d.last_x - d.start_x);
+    let (strip_pos, slide_width) = strip_style(at, track.per_view, gap, peek, drag_px);
+    let strip_motion = if animate() && drag().is_none() { "" } else { "transition: none;" };
+    let slide_span = {
+        let view = if peek { 0.8 } else { 1.0 };
+        let gap = if peek || track.per_view > 1 { gap as f64 } else { 0.0 };
+        (width() * view - gap * (track.per_view - 1) as f64) / track.per_view as f64 + gap
+    };
+    let visible = at..at + track.per_view;
+
+    // Strip order: copies of the last slides, the slides, copies of the first.
+    let strip: Vec<(usize, Element)> = (0..track.total())
+        .map(|pos| (pos, slides[track.slide_at(pos)].clone()))
+        .collect();
+    let use_thumbnails = indicators == CarouselIndicators::Thumbnails && thumbnails.len() >= len;
+
+    let (root_cls, strip_cls, slide_cls) = if peek {
+        (s::CAROUSEL_PEEK, s::SLIDE_STRIP_PEEK, s::SLIDE_PEEK)
+    } else {
+        (s::CAROUSEL, s::SLIDE_STRIP, s::SLIDE)
+    };
+    let cls = merge_classes(root_cls, &class);
+    let fade_left = "background: linear-gradient(to right, var(--color-background) 0%, transparent 100%);";
+    let fade_right = "background: linear-gradient(to left, var(--color-background) 0%, transparent 100%);";
+
+    let mut end_drag = move || {
+        if let Some(d) = drag.take() {
+            let steps = swipe_steps(d.last_x - d.start_x, d.velocity, slide_span);
+            go_by(steps.clamp(-(len as isize), len as isize));
+        }
+    };
 
-                    // Slide strip with live region
-                    div {
-                        class: s::SLIDE_STRIP,
-                        style: "{offset}",
-                        "aria-live": "polite",
-                        "aria-atomic": "true",
-                        for (i, slide) in slides.into_iter().enumerate() {
-                            div {
-                                key: "{i}",
-                                class: s::SLIDE,
-                                role: "group",
-                                "aria-roledescription": "slide",
-                                "aria-label": "Slide {i + 1} of {len}",
-                                "aria-hidden": if i != current() { "true" } else { "" },
-                                {slide}
-                            }
-                        }
+    rsx! {
+        div {
+            class: "{cls}",
+            role: "region",
+            "aria-roledescription": "carousel",
+            "aria-label": "{aria_label}",
+            onresize: move |evt: Event<ResizeData>| {
+                if let Ok(size) = evt.get_content_box_size()
+                    && size.width != *width.peek()
+                {
+                    width.set(size.width);
+                }
+            },
+            onmouseenter: move |_| hovered.set(true),
+            onmouseleave: move |_| hovered.set(false),
+            onfocusin: move |_| focused.set(true),
+            onfocusout: move |_| focused.set(false),
+            onkeydown: move |evt: Event<KeyboardData>| {
+                match evt.key() {
+                    Key::ArrowLeft => {
+                        evt.prevent_default();
+                        go_by(-1);
                     }
-
-                    // Arrows
-                    if show_controls {
-                        CarouselArrow { position: s::ARROW_LEFT, chevron: "M15.75 19.5 8.25 12l7.5-7.5", label: "Previous slide", onclick: go_prev }
-                        CarouselArrow { position: s::ARROW_RIGHT, chevron: "m8.25 4.5 7.5 7.5-7.5 7.5", label: "Next slide", onclick: go_next }
+                    Key::ArrowRight => {
+                        evt.prevent_default();
+                        go_by(1);
                     }
-
-                    // Dots
-                    if show_controls {
-                        div {
-                            class: s::DOTS,
-                            role: "tablist",
-                            "aria-label": "Slide controls",
-                            for i in 0..len {
-                                button {
-                                    key: "{i}",
-                                    class: if current() == i { s::DOT_ACTIVE } else { s::DOT },
-                                    role: "tab",
-                                    "aria-selected": if current() == i { "true" } else { "false" },
-                                    "aria-label": "Go to slide {i + 1}",
-                                    onclick: move |_| current.set(i),
-                                }
-                            }
-                        }
+                    _ => {}
+                }
+            },
+
+            // Slide strip - announced politely unless autoplay is running
+            div {
+                class: strip_cls,
+                style: "{strip_pos} {strip_motion}",
+                "aria-live": if playing { "off" } else { "polite" },
+                "aria-atomic": "true",
+                ondragstart: move |evt| evt.prevent_default(),
+                onpointerdown: move |evt: PointerEvent| {
+                    if show_controls && evt.is_primary() {
+                        let x = evt.client_coordinates().x;
+                        drag.set(Some(Drag { start_x: x, last_x: x, velocity: 0.0 }));
+                    }
+                },
+                onpointermove: move |evt: PointerEvent| {
+                    if let Some(mut d) = drag() {
+                        let x = evt.client_coordinates().x;
+                        d.velocity = 0.6 * (x - d.last_x) + 0.4 * d.velocity;
+                        d.last_x = x;
+                        drag.set(Some(d));
+                    }
+                },
+                onpointerup: move |_| end_drag(),
+                onpointercancel: move |_| end_drag(),
+                onpointerleave: move |_| end_drag(),
+                ontransitionend: move |_| {
+                    if let Some(real) = track.normalize(here()) {
+                        animate.set(false);
+                        position.set(real);
+                        spawn(async move {
+                            sleep(30).await;
+                            animate.set(true);
+                        });
+                    }
+                },
+                for (pos, slide) in strip {
+                    div {
+                        key: "{pos}",
+                        class: slide_cls,
+                        style: "{slide_width}",
+                        role: "group",
+                        "aria-roledescription": "slide",
+                        "aria-label": "Slide {track.slide_at(pos) + 1} of {len}",
+                        "aria-hidden": if !visible.contains(&pos) { "true" } else { "" },
+                        {slide}
                     }
                 }
             }
-        }
-
-        CarouselMode::Peek => {
-            let idx = current() as u32;
-            let offset = format!(
-                "transform: translateX(calc(-{}% + 10% - {}px));",
-                idx * 80,
-                idx * gap,
-            );
-            let strip_gap = format!("gap: {}px;", gap);
 
-            let fade_left = "background: linear-gradient(to right, var(--color-background) 0%, transparent 100%);";
-            let fade_right = "background: linear-gradient(to left, var(--color-background) 0%, transparent 100%);";
-
-            let cls = merge_classes(s::CAROUSEL_PEEK, &class);
-            rsx! {
+            // Fade masks (decorative)
+            if peek {
                 div {
-                    class: "{cls}",
-                    role: "region",
-                    "aria-roledescription": "carousel",
-                    "aria-label": "{aria_label}",
-                    onkeydown: move |evt: Event<KeyboardData>| {
-                        match evt.key() {
-                            Key::ArrowLeft => {
-                                evt.prevent_default();
-                                current.set(if current() == 0 { len - 1 } else { current() - 1 });
-                            }
-                            Key::ArrowRight => {
-                                evt.prevent_default();
-                                current.set(if current() + 1 >= len { 0 } else { current() + 1 });
-                            }
-                            _ => {}
-                        }
-                    },
+                    class: s::PEEK_FADE_LEFT,
+                    style: "width: 12%; {fade_left}",
+                    "aria-hidden": "true",
+                }
+                div {
+                    class: s::PEEK_FADE_RIGHT,
+                    style: "width: 12%; {fade_right}",
+                    "aria-hidden": "true",
+                }
+            }
 
-                    // Slide strip
-                    div {
-                        class: s::SLIDE_STRIP_PEEK,
-                        style: "{strip_gap} {offset}",
-                        "aria-live": "polite",
-                        "aria-atomic": "true",
-                        for (i, slide) in slides.into_iter().enumerate() {
-                            div {
-                                key: "{i}",
-                                class: s::SLIDE_PEEK,
-                                style: "width: 80%;",
-                                role: "group",
-                                "aria-roledescription": "slide",
-                                "aria-label": "Slide {i + 1} of {len}",
-                                "aria-hidden": if i != current() { "true" } else { "" },
-                                {slide}
+            // Arrows
+            if show_controls {
+                CarouselArrow { position: s::ARROW_LEFT, chevron: "M15.75 19.5 8.25 12l7.5-7.5", label: "Previous slide", onclick: move |_| go_by(-1) }
+                CarouselArrow { position: s::ARROW_RIGHT, chevron: "m8.25 4.5 7.5 7.5-7.5 7.5", label: "Next slide", onclick: move |_| go_by(1) }
+            }
+
+            // Pause control and pagination
+            if show_controls && (autoplay.is_some() || indicators != CarouselIndicators::Hidden) {
+                div { class: s::DOTS,
+                    if autoplay.is_some() {
+                        button {
+                            r#type: "button",
+                            class: s::PAUSE,
+                            "aria-label": if stopped() { "Start autoplay" } else { "Pause autoplay" },
+                            onclick: move |_| stopped.toggle(),
+                            svg {
+                                class: s::PAUSE_ICON,
+                                xmlns: "http://www.w3.org/2000/svg",
+                                view_box: "0 0 24 24",
+                                fill: "currentColor",
+                                "aria-hidden": "true",
+                                if stopped() {
+                                    path { d: "M8 5.14v13.72a1 1 0 0 0 1.5.86l11-6.86a1 1 0 0 0 0-1.72l-11-6.86A1 1 0 0 0 8 5.14Z" }
+                                } else {
+                                    path { d: "M7 5h3.5v14H7zM13.5 5H17v14h-3.5z" }
+                                }
                             }
                         }
                     }
-
-                    // Fade masks (decorative)
-                    div {
-                        class: s::PEEK_FADE_LEFT,
-                        style: "width: 12%; {fade_left}",
-                        "aria-hidden": "true",
-                    }
-                    div {
-                        class: s::PEEK_FADE_RIGHT,
-                        style: "width: 12%; {fade_right}",
-                        "aria-hidden": "true",
-                    }
-
-                    // Arrows
-                    if show_controls {
-                        CarouselArrow { position: s::ARROW_LEFT, chevron: "M15.75 19.5 8.25 12l7.5-7.5", label: "Previous slide", onclick: go_prev }
-                        CarouselArrow { position: s::ARROW_RIGHT, chevron: "m8.25 4.5 7.5 7.5-7.5 7.5", label: "Next slide", onclick: go_next }
-                    }
-
-                    // Dots
-                    if show_controls {
+                    if indicators != CarouselIndicators::Hidden {
                         div {
-                            class: s::DOTS,
+                            class: if use_thumbnails { s::THUMBS } else { s::DOT_LIST },
                             role: "tablist",
                             "aria-label": "Slide controls",
-                            for i in 0..len {
+                            for i in 0..track.pages() {
                                 button {
                                     key: "{i}",
-                                    class: if current() == i { s::DOT_ACTIVE } else { s::DOT },
+                                    class: match (use_thumbnails, index == i) {
+                                        (true, true) => s::THUMB_ACTIVE,
+                                        (true, false) => s::THUMB,
+                                        (false, true) => s::DOT_ACTIVE,
+                                        (false, false) => s::DOT,
+                                    },
                                     role: "tab",
-                                    "aria-selected": if current() == i { "true" } else { "false" },
+                                    "aria-selected": if index == i { "true" } else { "false" },
                                     "aria-label": "Go to slide {i + 1}",
-                                    onclick: move |_| current.set(i),
+                                    onclick: move |_| go(track.position_of(i)),
+                                    if use_thumbnails {
+                                        {thumbnails[i].clone()}
+                                    }
                                 }
                             }
                         }
@@ -287,12 +592,40 @@ pub fn EqCarousel(
 fn DemoEqCarousel() -> Element {
     let mut mode_str = use_signal(|| "Default".to_string());
     let mut gap_str = use_signal(|| "12".to_string());
+    let mut per_view_str = use_signal(|| "1".to_string());
+    let mut responsive = use_signal(|| false);
+    let mut autoplay_on = use_signal(|| false);
+    let mut interval_str = use_signal(|| "4000".to_string());
+    let mut infinite = use_signal(|| false);
+    let mut indicators_str = use_signal(|| "Dots".to_string());
+    let mut slide = use_signal(|| 0usize);
 
     let mode = match mode_str().as_str() {
         "Peek" => CarouselMode::Peek,
         _ => CarouselMode::Default,
     };
     let gap_val: u32 = gap_str().parse().unwrap_or(12);
+    let per_view: usize = per_view_str().parse().unwrap_or(1).max(1);
+    let breakpoints = if responsive() {
+        vec![CarouselBreakpoint::new(480.0, 2), CarouselBreakpoint::new(800.0, 3)]
+    } else {
+        Vec::new()
+    };
+    let autoplay = autoplay_on().then(|| interval_str().parse().unwrap_or(4000_u64).max(500));
+    let indicators = match indicators_str().as_str() {
+        "Thumbnails" => CarouselIndicators::Thumbnails,
+        "Hidden" => CarouselIndicators::Hidden,
+        _ => CarouselIndicators::Dots,
+    };
+    let thumbnails: Vec<Element> = (1..=4)
+        .map(|i| rsx! {
+            img {
+                class: "w-full h-full object-cover",
+                src: "https://picsum.photos/seed/carousel{i}/160/100",
+                alt: "",
+            }
+        })
+        .collect();
 
     let slides = vec![
         rsx! {
@@ -345,7 +678,7 @@ fn DemoEqCarousel() -> Element {
         },
     ];
 
-    let code = "// Default carousel with slide animation\nEqCarousel {\n    slides: vec![\n        rsx! { /* slide content */ },\n        rsx! { /* slide content */ },\n    ],\n}\n\n// Peek mode - shows neighbours with fade\nEqCarousel {\n    mode: CarouselMode::Peek,\n    gap: 24,  // default 12px\n    slides: vec![ /* ... */ ],\n}".to_string();
+    let code = "// Default carousel with slide animation\nEqCarousel {\n    slides: vec![\n        rsx! { /* slide content */ },\n        rsx! { /* slide content */ },\n    ],\n}\n\n// Peek mode - shows neighbours with fade\nEqCarousel {\n    mode: CarouselMode::Peek,\n    gap: 24,  // default 12px\n    slides: vec![ /* ... */ ],\n}\n\n// Autoplay, loop, responsive slides per view, controlled index\nEqCarousel {\n    autoplay: 4000,\n    infinite: true,\n    breakpoints: vec![\n        CarouselBreakpoint::new(480.0, 2),\n        CarouselBreakpoint::new(800.0, 3),\n    ],\n    current: slide(),\n    on_change: move |i| slide.set(i),\n    slides: vec![ /* ... */ ],\n}".to_string();
 
     rsx! {
         DemoSection { title: "EqCarousel",
@@ -367,12 +700,72 @@ fn DemoEqCarousel() -> Element {
                     placeholder: "12",
                     onchange: move |v: String| gap_str.set(v),
                 }
+                PropInput {
+                    label: "per_view",
+                    value: per_view_str(),
+                    placeholder: "1",
+                    onchange: move |v: String| per_view_str.set(v),
+                }
+                PropToggle {
+                    label: "breakpoints (2 from 480px, 3 from 800px)",
+                    value: responsive(),
+                    onchange: move |v: bool| responsive.set(v),
+                }
+                PropToggle {
+                    label: "autoplay",
+                    value: autoplay_on(),
+                    onchange: move |v: bool| autoplay_on.set(v),
+                }
+                if autoplay_on() {
+                    PropInput {
+                        label: "interval (ms)",
+                        value: interval_str(),
+                        placeholder: "4000",
+                        onchange: move |v: String| interval_str.set(v),
+                    }
+                }
+                PropToggle {
+                    label: "infinite",
+                    value: infinite(),
+                    onchange: move |v: bool| infinite.set(v),
+                }
+                PropSelect {
+                    label: "indicators",
+                    value: indicators_str(),
+                    options: vec!["Dots", "Thumbnails", "Hidden"],
+                    onchange: move |v: String| indicators_str.set(v),
+                }
             }
-            div { class: "rounded-lg border border-dashed border-[var(--color-card-border)] p-6",
-                EqCarousel { mode, gap: gap_val, slides }
+            div { class: "rounded-lg border border-dashed border-[var(--color-card-border)] p-6 space-y-3",
+                EqCarousel {
+                    mode,
+                    gap: gap_val,
+                    per_view,
+                    breakpoints,
+                    autoplay,
+                    infinite: infinite(),
+                    indicators,
+                    thumbnails,
+                    current: slide(),
+                    on_change: move |i: usize| slide.set(i),
+                    slides,
+                }
+                div { class: "flex items-center justify-center gap-2",
+                    EqText { variant: TextVariant::Muted, "Controlled: slide {slide() + 1}" }
+                    for i in 0..4usize {
+                        button {
+                            key: "{i}",
+                            r#type: "button",
+                            class: "px-2 py-0.5 text-xs rounded border border-[var(--color-card-border)] \
+                                    text-[var(--color-label-primary)] hover:border-[var(--color-accent-primary)]",
+                            onclick: move |_| slide.set(i),
+                            "{i + 1}"
+                        }
+                    }
+                }
             }
             StyleInfo { file: "eq_carousel_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
@@ -418,7 +811,12 @@ fn GalleryEqCarousel() -> Element {
 
             div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-4",
                 EqText { variant: TextVariant::Caption, class: "font-semibold uppercase tracking-wider", "Peek Mode" }
-                EqCarousel { mode: CarouselMode::Peek, gap: 16, slides }
+                EqCarousel { mode: CarouselMode::Peek, gap: 16, slides: slides.clone() }
+            }
+
+            div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-4",
+                EqText { variant: TextVariant::Caption, class: "font-semibold uppercase tracking-wider", "Two per view, looping autoplay" }
+                EqCarousel { per_view: 2, infinite: true, autoplay: 5000, slides }
             }
         }
     }
@@ -438,6 +836,97 @@ mod tests {
         dom.rebuild_in_place();
     }
 
+    #[test]
+    fn smoke_renders_every_option() {
+        let mut dom = VirtualDom::new(|| {
+            let slides: Vec<Element> = (0..5).map(|i| rsx! { div { "slide {i}" } }).collect();
+            let thumbs: Vec<Element> = (0..5).map(|i| rsx! { span { "{i}" } }).collect();
+            rsx! {
+                EqCarousel {
+                    mode: CarouselMode::Peek,
+                    per_view: 2,
+                    breakpoints: vec![CarouselBreakpoint::new(600.0, 3)],
+                    autoplay: 3000,
+                    infinite: true,
+                    current: 4,
+                    on_change: move |_| {},
+                    indicators: CarouselIndicators::Thumbnails,
+                    thumbnails: thumbs,
+                    slides: slides.clone(),
+                }
+                EqCarousel { indicators: CarouselIndicators::Hidden, slides: vec![] }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn breakpoints_pick_the_widest_reached() {
+        let bps = [CarouselBreakpoint::new(1024.0, 4), CarouselBreakpoint::new(640.0, 2)];
+        assert_eq!(per_view_at(0.0, 1, &bps), 1);
+        assert_eq!(per_view_at(700.0, 1, &bps), 2);
+        assert_eq!(per_view_at(1200.0, 1, &bps), 4);
+        assert_eq!(per_view_at(500.0, 0, &[]), 1);
+    }
+
+    #[test]
+    fn swipes_move_by_distance_and_momentum() {
+        // 300px slides: a slow 100px drag left moves one forward...
+        assert_eq!(swipe_steps(-100.0, 0.0, 300.0), 1);
+        // ...a 40px one does not, nor does a click.
+        assert_eq!(swipe_steps(-40.0, 0.0, 300.0), 0);
+        assert_eq!(swipe_steps(3.0, 40.0, 300.0), 0);
+        assert_eq!(swipe_steps(200.0, 0.0, 300.0), -1);
+        // A flick carries on over several slides.
+        assert_eq!(swipe_steps(-120.0, -90.0, 300.0), 3);
+        assert_eq!(swipe_steps(-100.0, 0.0, 0.0), 0);
+    }
+
+    #[test]
+    fn non_looping_track_rewinds_at_the_ends() {
+        // 5 slides, 2 per view: first slides 0..=3.
+        let t = Track::new(5, 2, false, false);
+        assert_eq!((t.total(), t.pages()), (5, 4));
+        assert_eq!(t.step(2, 1), 3);
+        assert_eq!(t.step(3, 1), 0);
+        assert_eq!(t.step(0, -1), 3);
+        // A big step stops at the end before rewinding.
+        assert_eq!(t.step(1, 5), 3);
+        assert_eq!(t.position_of(4), 3);
+        assert_eq!(t.normalize(2), None);
+    }
+
+    #[test]
+    fn looping_track_runs_into_copies_and_jumps_back() {
+        // 4 slides, 1 per view: strip is [3] 0 1 2 3 [0].
+        let t = Track::new(4, 1, true, false);
+        assert_eq!((t.clones, t.total(), t.pages()), (1, 6, 4));
+        assert_eq!(t.position_of(0), 1);
+        assert_eq!(t.slide_at(0), 3);
+        assert_eq!(t.slide_at(5), 0);
+        // Last slide forward lands on the copy of the first...
+        assert_eq!(t.step(4, 1), 5);
+        // ...which stands for the real first slide.
+        assert_eq!(t.normalize(5), Some(1));
+        assert_eq!(t.normalize(0), Some(4));
+        assert_eq!(t.normalize(2), None);
+        // Stepping from a copy starts from its real slide.
+        assert_eq!(t.step(5, 1), 2);
+        // Peek adds a copy each side; too few slides do not loop.
+        assert_eq!(Track::new(4, 1, true, true).clones, 2);
+        assert_eq!(Track::new(2, 2, true, false).clones, 0);
+    }
+
+    #[test]
+    fn strip_style_matches_the_single_slide_layouts() {
+        let (strip, slide) = strip_style(2, 1, 12, false, 0.0);
+        assert_eq!(strip, "gap: 0px; transform: translateX(calc(0% - 2 * (((100% - 0px) / 1) + 0px) + 0px));");
+        assert_eq!(slide, "width: calc((100% - 0px) / 1);");
+        let (strip, slide) = strip_style(1, 2, 10, true, -15.0);
+        assert_eq!(strip, "gap: 10px; transform: translateX(calc(10% - 1 * (((80% - 10px) / 2) + 10px) + -15px));");
+        assert_eq!(slide, "width: calc((80% - 10px) / 2);");
+    }
+
     #[test]
     fn default_mode_is_default() {
         let m: CarouselMode = Default::default();
@@ -6,7 +6,8 @@ pub const CAROUSEL: &str = "relative w-full overflow-hidden";
 /// The horizontal strip that holds all slides side-by-side.
 /// `translateX` is set dynamically via inline style.
 pub const SLIDE_STRIP: &str =
-    "flex transition-transform duration-[var(--transition-normal)] ease-in-out";
+    "flex transition-transform duration-[var(--transition-normal)] ease-in-out \
+     motion-reduce:transition-none touch-pan-y select-none";
 
 /// A single slide within the strip - full width of the carousel.
 pub const SLIDE: &str = "w-full shrink-0";
@@ -18,7 +19,8 @@ pub const CAROUSEL_PEEK: &str = "relative w-full overflow-hidden";
 
 /// Peek strip - like SLIDE_STRIP but slides are narrower (80%).
 pub const SLIDE_STRIP_PEEK: &str =
-    "flex transition-transform duration-[var(--transition-normal)] ease-in-out";
+    "flex transition-transform duration-[var(--transition-normal)] ease-in-out \
+     motion-reduce:transition-none touch-pan-y select-none";
 
 /// Each slide in peek mode is 80% width so neighbours are visible.
 pub const SLIDE_PEEK: &str = "shrink-0";
@@ -53,8 +55,11 @@ pub const ARROW_ICON: &str = "size-5";
 
 // ── Dot indicators ───────────────────────────────────────────────
 
-/// Dot indicator container
-pub const DOTS: &str = "flex justify-center items-center gap-2 mt-4";
+/// Row under the slides holding the pause button and the pagination
+pub const DOTS: &str = "flex justify-center items-center gap-3 mt-4";
+
+/// Dot indicator list
+pub const DOT_LIST: &str = "flex items-center gap-2";
 
 /// Inactive dot
 pub const DOT: &str =
@@ -67,6 +72,33 @@ pub const DOT_ACTIVE: &str =
     "size-3 md:size-2.5 rounded-full bg-[var(--color-label-bold)] \
      cursor-pointer";
 
+// ── Thumbnails ───────────────────────────────────────────────────
+
+/// Thumbnail indicator list
+pub const THUMBS: &str = "flex items-center gap-2 overflow-x-auto p-1";
+
+/// Inactive thumbnail button
+pub const THUMB: &str =
+    "w-16 h-10 shrink-0 rounded-md overflow-hidden cursor-pointer \
+     opacity-60 hover:opacity-100 transition \
+     ring-2 ring-transparent";
+
+/// Active thumbnail button
+pub const THUMB_ACTIVE: &str =
+    "w-16 h-10 shrink-0 rounded-md overflow-hidden cursor-pointer \
+     ring-2 ring-[var(--color-accent-primary)]";
+
+// ── Autoplay ─────────────────────────────────────────────────────
+
+/// Pause / play button for autoplay
+pub const PAUSE: &str =
+    "flex items-center justify-center size-7 rounded-full \
+     text-[var(--color-label-secondary)] hover:text-[var(--color-label-bold)] \
+     border border-[var(--color-card-border)] transition cursor-pointer";
+
+/// Pause / play icon sizing
+pub const PAUSE_ICON: &str = "size-3.5";
+
 /// All style tokens for playground introspection.
 pub fn catalog() -> Vec<(&'static str, &'static str)> {
     vec![
@@ -83,7 +115,13 @@ pub fn catalog() -> Vec<(&'static str, &'static str)> {
         ("ARROW_RIGHT", ARROW_RIGHT),
         ("ARROW_ICON", ARROW_ICON),
         ("DOTS", DOTS),
+        ("DOT_LIST", DOT_LIST),
         ("DOT", DOT),
         ("DOT_ACTIVE", DOT_ACTIVE),
+        ("THUMBS", THUMBS),
+        ("THUMB", THUMB),
+        ("THUMB_ACTIVE", THUMB_ACTIVE),
+        ("PAUSE", PAUSE),
+        ("PAUSE_ICON", PAUSE_ICON),
     ]
 }
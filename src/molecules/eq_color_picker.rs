@@ -0,0 +1,588 @@
+//! EqColorPicker — colour picker molecule.
+//!
+//! Saturation / value pad, hue and alpha sliders, hex / RGB / HSL text
+//! entry, preset swatches (the current theme's palette by default) and
+//! an eyedropper button where the browser supports the `EyeDropper`
+//! API. Parsing and conversion live in plain Rust
+//! ([`Color`], [`Hsva`]); only the eyedropper and swatch resolution of
+//! `var(--…)` presets touch JS.
+//!
+//! ```no_run
+//! use eq_ui::prelude::*;
+//! use eq_ui::molecules::{EqColorPicker, ColorFormat};
+//!
+//! fn app() -> Element {
+//!     let mut color = use_signal(|| "#ff6b9d".to_string());
+//!
+//!     rsx! {
+//!         EqColorPicker {
+//!             value: color(),
+//!             format: ColorFormat::Hex,
+//!             on_change: move |c| color.set(c),
+//!         }
+//!     }
+//! }
+//! ```
+
+use super::eq_color_picker_color::{Color, ColorFormat, Hsva};
+use super::eq_color_picker_styles as s;
+use crate::atoms::{EqInput, EqTab, TabItem, TabSize, TabVariant};
+use crate::playground;
+use crate::theme::merge_classes;
+use dioxus::prelude::*;
+
+#[cfg(feature = "playground")]
+use crate::playground::playground_helpers::{
+    DemoSection, PropSelect, PropToggle, StyleInfo, format_catalog,
+};
+#[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
+use crate::atoms::{EqText, TextVariant};
+#[cfg(feature = "playground")]
+use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
+
+/// Theme variables offered as swatches when no `presets` are given -
+/// the plain-colour tokens from the theme showcase, as
+/// `(label, variable name)`.
+pub const THEME_SWATCHES: &[(&str, &str)] = &[
+    ("Accent Primary", "color-accent-primary"),
+    ("Accent Secondary", "color-accent-secondary"),
+    ("Accent Muted", "color-accent-muted"),
+    ("Success", "color-success"),
+    ("Warning", "color-warning"),
+    ("Error", "color-error"),
+    ("Info", "color-info"),
+    ("Primary", "color-primary"),
+    ("Gradient Start", "color-gradient-default-start"),
+    ("Gradient Mid", "color-gradient-default-mid"),
+    ("Gradient End", "color-gradient-default-end"),
+    ("Label Bold", "color-label-bold"),
+    ("Label Secondary", "color-label-secondary"),
+    ("Tertiary Dark", "color-tertiary-dark"),
+    ("Card", "color-card"),
+    ("Background", "color-background"),
+];
+
+/// Checkerboard layer drawn under translucent colours.
+const CHECKER: &str = "repeating-conic-gradient(#ccc 0 25%, #fff 0 50%) 0 0 / 8px 8px";
+
+const HUE_TRACK: &str =
+    "linear-gradient(to right, #f00 0%, #ff0 17%, #0f0 33%, #0ff 50%, #00f 67%, #f0f 83%, #f00 100%)";
+
+/// Maps a pointer position on the pad to `(saturation, value)`.
+/// `None` until the pad has been measured.
+fn pad_position(x: f64, y: f64, width: f64, height: f64) -> Option<(f32, f32)> {
+    if width <= 0.0 || height <= 0.0 {
+        return None;
+    }
+    Some(((x / width).clamp(0.0, 1.0) as f32, (1.0 - y / height).clamp(0.0, 1.0) as f32))
+}
+
+/// Arrow-key movement on the pad: left / right change saturation,
+/// up / down change value. 1% per press, 10% with Shift.
+fn nudge(hsva: Hsva, key: &Key, shift: bool) -> Option<Hsva> {
+    let step = if shift { 0.1 } else { 0.01 };
+    let (ds, dv) = match key {
+        Key::ArrowLeft => (-step, 0.0),
+        Key::ArrowRight => (step, 0.0),
+        Key::ArrowUp => (0.0, step),
+        Key::ArrowDown => (0.0, -step),
+        Key::Home => (-1.0, 0.0),
+        Key::End => (1.0, 0.0),
+        _ => return None,
+    };
+    Some(Hsva { s: (hsva.s + ds).clamp(0.0, 1.0), v: (hsva.v + dv).clamp(0.0, 1.0), ..hsva })
+}
+
+/// Resolves a preset to a colour: literal colours are parsed in Rust,
+/// anything else (`var(--…)`, `color-mix(…)`) is resolved by the
+/// browser through a throwaway element's computed `color`.
+async fn resolve_css_color(css: String) -> Option<Color> {
+    if let Some(c) = Color::parse(&css) {
+        return Some(c);
+    }
+    let css = serde_json::to_string(&css).ok()?;
+    let js = format!(
+        "const p = document.createElement('span'); p.style.color = {css}; \
+         document.body.appendChild(p); const c = getComputedStyle(p).color; p.remove(); return c;"
+    );
+    let val = document::eval(&js).await.ok()?;
+    Color::parse(val.as_str()?)
+}
+
+/// Colour picker molecule.
+///
+/// `value` seeds the picker and is followed whenever the parent changes
+/// it; every edit calls `on_change` with the colour formatted in
+/// `format`. The text field can switch between hex, RGB and HSL
+/// independently of `format`.
+#[playground(
+    category = Molecule,
+    description = "Colour picker with saturation/value pad, hue and alpha sliders, hex/RGB/HSL \
+                   entry, theme swatches and an eyedropper where supported.",
+    examples = [
+        ("Basic", "let mut color = use_signal(|| \"#ff6b9d\".to_string());\n\nEqColorPicker {\n    value: color(),\n    on_change: move |c| color.set(c),\n}"),
+        ("RGB, opaque", "EqColorPicker {\n    value: color(),\n    format: ColorFormat::Rgb,\n    alpha: false,\n    on_change: move |c| color.set(c),\n}"),
+        ("Custom presets", "EqColorPicker {\n    value: color(),\n    presets: vec![\"#ef4444\".into(), \"#22c55e\".into(), \"var(--color-info)\".into()],\n    on_change: move |c| color.set(c),\n}"),
+    ],
+    custom_demo,
+    custom_gallery,
+)]
+#[component]
+pub fn EqColorPicker(
+    /// Current colour as any CSS colour string the parser accepts.
+    /// Unparsable values fall back to black.
+    #[props(into, default)]
+    value: String,
+    /// Format of the string passed to `on_change`.
+    #[props(default)]
+    format: ColorFormat,
+    /// Show the alpha slider. When false, emitted colours are opaque.
+    #[props(default = true)]
+    alpha: bool,
+    /// Swatch colours (literal colours or `var(--…)` references).
+    /// `None` shows the theme palette; an empty list hides swatches.
+    #[props(default)]
+    presets: Option<Vec<String>>,
+    /// Show the eyedropper button when the browser supports it.
+    #[props(default = true)]
+    eyedropper: bool,
+    /// Fired on every edit with the formatted colour.
+    #[props(default)]
+    on_change: Option<EventHandler<String>>,
+    /// Accessible name for the picker group.
+    #[props(into, default = "Color picker".to_string())]
+    aria_label: String,
+    /// Optional class override on the wrapper element.
+    #[props(into, default)]
+    class: String,
+) -> Element {
+    let mut hsva = use_signal(|| Color::parse(&value).unwrap_or_default().to_hsva());
+    let mut seen_value = use_signal(|| value.clone());
+    let mut entry_format = use_signal(|| format);
+    let mut draft = use_signal(|| None::<String>);
+    let mut pad_element: Signal<Option<MountedEvent>> = use_signal(|| None);
+    let mut pad_size = use_signal(|| (0.0_f64, 0.0_f64));
+    let mut drag_origin = use_signal(|| None::<(f64, f64)>);
+    let mut can_eyedrop = use_signal(|| false);
+
+    // Follow the parent's value. Skipped when it is just our own
+    // emission echoing back, so the hue of greys is not lost.
+    if value != *seen_value.peek() {
+        seen_value.set(value.clone());
+        let current = *hsva.peek();
+        if let Some(c) = Color::parse(&value)
+            && c != current.to_color()
+        {
+            hsva.set(current.follow(c));
+        }
+    }
+
+    // Measure the pad once mounted; pointer positions are scaled by it.
+    use_effect(move || {
+        if let Some(el) = pad_element() {
+            spawn(async move {
+                if let Ok(rect) = el.get_client_rect().await {
+                    pad_size.set((rect.width(), rect.height()));
+                }
+            });
+        }
+    });
+
+    use_effect(move || {
+        if eyedropper {
+            spawn(async move {
+                if let Ok(val) = document::eval("return 'EyeDropper' in window;").await {
+                    can_eyedrop.set(val.as_bool().unwrap_or(false));
+                }
+            });
+        }
+    });
+
+    let mut commit = move |mut next: Hsva| {
+        if !alpha {
+            next.a = 1.0;
+        }
+        hsva.set(next);
+        if let Some(handler) = on_change {
+            handler.call(next.to_color().format(format));
+        }
+    };
+
+    let mut pick = move |x: f64, y: f64| {
+        let (w, h) = pad_size();
+        if let Some((sat, val)) = pad_position(x, y, w, h) {
+            commit(Hsva { s: sat, v: val, ..hsva() });
+        }
+    };
+
+    let current = hsva();
+    let color = current.to_color();
+    let opaque = color.with_alpha(1.0);
+    let hue_css = Hsva { s: 1.0, v: 1.0, a: 1.0, ..current }.to_color().to_hex();
+    let pad_style = format!(
+        "background: linear-gradient(to top, #000, transparent), \
+         linear-gradient(to right, #fff, transparent), {hue_css};"
+    );
+    let thumb_style = format!(
+        "left: {:.2}%; top: {:.2}%; background: {};",
+        current.s * 100.0,
+        (1.0 - current.v) * 100.0,
+        opaque.to_hex()
+    );
+    let alpha_style = format!(
+        "background: linear-gradient(to right, {}, {}), {CHECKER};",
+        opaque.with_alpha(0.0).to_rgb_string(),
+        opaque.to_rgb_string()
+    );
+    let preview_style = format!("background: linear-gradient({0}, {0}), {CHECKER};", color.to_rgb_string());
+    let value_text = format!(
+        "Saturation {:.0}%, brightness {:.0}%",
+        current.s * 100.0,
+        current.v * 100.0
+    );
+
+    let entry_text = draft().unwrap_or_else(|| color.format(entry_format()));
+    let entry_invalid = draft().is_some_and(|d| Color::parse(&d).is_none());
+    let entry_cls = if entry_invalid { s::ENTRY_INVALID } else { "" };
+    let format_index = match entry_format() {
+        ColorFormat::Hex => 0,
+        ColorFormat::Rgb => 1,
+        ColorFormat::Hsl => 2,
+    };
+
+    let swatches: Vec<(String, String)> = match presets {
+        Some(list) => list.into_iter().map(|p| (p.clone(), p)).collect(),
+        None => THEME_SWATCHES
+            .iter()
+            .map(|(label, var)| (label.to_string(), format!("var(--{var})")))
+            .collect(),
+    };
+
+    let cls = merge_classes(s::WRAPPER, &class);
+
+    rsx! {
+        div { class: "{cls}", role: "group", "aria-label": "{aria_label}",
+
+            if drag_origin().is_some() {
+                div {
+                    class: s::DRAG_OVERLAY,
+                    "aria-hidden": "true",
+                    onpointermove: move |e: PointerEvent| {
+                        if let Some((ox, oy)) = drag_origin() {
+                            let c = e.client_coordinates();
+                            pick(c.x - ox, c.y - oy);
+                        }
+                    },
+                    onpointerup: move |_| drag_origin.set(None),
+                    onpointercancel: move |_| drag_origin.set(None),
+                }
+            }
+
+            div {
+                class: s::PAD,
+                style: "{pad_style}",
+                tabindex: "0",
+                role: "slider",
+                "aria-label": "Saturation and brightness",
+                "aria-valuetext": "{value_text}",
+                onmounted: move |e: MountedEvent| pad_element.set(Some(e)),
+                onpointerdown: move |e: PointerEvent| {
+                    let p = e.element_coordinates();
+                    let c = e.client_coordinates();
+                    drag_origin.set(Some((c.x - p.x, c.y - p.y)));
+                    pick(p.x, p.y);
+                },
+                onkeydown: move |e: KeyboardEvent| {
+                    if let Some(next) = nudge(hsva(), &e.key(), e.modifiers().shift()) {
+                        e.prevent_default();
+                        commit(next);
+                    }
+                },
+                div { class: s::PAD_THUMB, style: "{thumb_style}" }
+            }
+
+            div { class: s::CONTROLS,
+                div { class: s::PREVIEW, style: "{preview_style}", "aria-hidden": "true" }
+                div { class: s::SLIDERS,
+                    input {
+                        r#type: "range",
+                        class: s::CHANNEL,
+                        style: "background: {HUE_TRACK};",
+                        min: "0",
+                        max: "359",
+                        step: "1",
+                        value: "{current.h.round()}",
+                        "aria-label": "Hue",
+                        oninput: move |e: FormEvent| {
+                            if let Ok(h) = e.value().parse::<f32>() {
+                                commit(Hsva { h, ..hsva() });
+                            }
+                        },
+                    }
+                    if alpha {
+                        input {
+                            r#type: "range",
+                            class: s::CHANNEL,
+                            style: "{alpha_style}",
+                            min: "0",
+                            max: "100",
+                            step: "1",
+                            value: "{(current.a * 100.0).round()}",
+                            "aria-label": "Opacity",
+                            oninput: move |e: FormEvent| {
+                                if let Ok(a) = e.value().parse::<f32>() {
+                                    commit(Hsva { a: a / 100.0, ..hsva() });
+                                }
+                            },
+                        }
+                    }
+                }
+                if eyedropper && can_eyedrop() {
+                    button {
+                        r#type: "button",
+                        class: s::EYEDROPPER_BTN,
+                        "aria-label": "Pick a color from the screen",
+                        onclick: move |_| {
+                            spawn(async move {
+                                let js = "try { const r = await new EyeDropper().open(); return r.sRGBHex; } \
+                                          catch (e) { return null; }";
+                                let Ok(val) = document::eval(js).await else { return };
+                                if let Some(c) = val.as_str().and_then(Color::parse) {
+                                    commit(hsva().follow(c));
+                                }
+                            });
+                        },
+                        svg {
+                            xmlns: "http://www.w3.org/2000/svg",
+                            fill: "none",
+                            view_box: "0 0 24 24",
+                            stroke_width: "1.8",
+                            stroke: "currentColor",
+                            width: "18",
+                            height: "18",
+                            "aria-hidden": "true",
+                            path { d: "m14 7 3 3M5 19l1-4 9-9a2.1 2.1 0 0 1 3 3l-9 9-4 1Z" }
+                        }
+                    }
+                }
+            }
+
+            div {
+                class: s::ENTRY,
+                onfocusout: move |_| draft.set(None),
+                EqTab {
+                    tabs: vec![TabItem::new("HEX"), TabItem::new("RGB"), TabItem::new("HSL")],
+                    active: format_index,
+                    variant: TabVariant::Pill,
+                    size: TabSize::Sm,
+                    on_change: move |i: usize| {
+                        draft.set(None);
+                        entry_format.set(match i {
+                            1 => ColorFormat::Rgb,
+                            2 => ColorFormat::Hsl,
+                            _ => ColorFormat::Hex,
+                        });
+                    },
+                }
+                label {
+                    span { class: "sr-only", "Color value" }
+                    EqInput {
+                        value: entry_text,
+                        class: entry_cls,
+                        oninput: move |e: FormEvent| {
+                            let text = e.value();
+                            if let Some(c) = Color::parse(&text) {
+                                commit(hsva().follow(c));
+                            }
+                            draft.set(Some(text));
+                        },
+                    }
+                }
+            }
+
+            if !swatches.is_empty() {
+                div { class: s::SWATCHES,
+                    for (label, css) in swatches {
+                        button {
+                            key: "{css}",
+                            r#type: "button",
+                            class: s::SWATCH,
+                            style: "background: linear-gradient({css}, {css}), {CHECKER};",
+                            title: "{label}",
+                            "aria-label": "{label}",
+                            onclick: {
+                                let css = css.clone();
+                                move |_| {
+                                    let css = css.clone();
+                                    spawn(async move {
+                                        if let Some(c) = resolve_css_color(css).await {
+                                            draft.set(None);
+                                            commit(hsva().follow(c));
+                                        }
+                                    });
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── Interactive demo ───────────────────────────────────────────────
+
+#[cfg(feature = "playground")]
+#[component]
+fn DemoEqColorPicker() -> Element {
+    let mut format_str = use_signal(|| "Hex".to_string());
+    let mut alpha = use_signal(|| true);
+    let mut theme_presets = use_signal(|| true);
+    let mut eyedropper = use_signal(|| true);
+    let mut color = use_signal(|| "#ff6b9d".to_string());
+
+    let format = <ColorFormat as crate::playground_enum_trait::PlaygroundEnumInfo>::from_name(&format_str());
+    let presets = if theme_presets() {
+        None
+    } else {
+        Some(vec![
+            "#ef4444".to_string(),
+            "#f59e0b".to_string(),
+            "#22c55e".to_string(),
+            "#06b6d4".to_string(),
+            "#3b82f6".to_string(),
+            "#8b5cf6".to_string(),
+            "#ec4899".to_string(),
+            "#64748b".to_string(),
+        ])
+    };
+
+    let code = r##"use eq_ui::molecules::{EqColorPicker, ColorFormat, Color};
+
+let mut color = use_signal(|| "#ff6b9d".to_string());
+
+EqColorPicker {
+    value: color(),
+    format: ColorFormat::Hex,
+    alpha: true,
+    on_change: move |c| color.set(c),
+}
+
+// Parsing and conversion are plain Rust
+let c = Color::parse("hsl(0 100% 50% / 0.5)").unwrap();
+assert_eq!(c.to_hex(), "#ff000080");"##
+        .to_string();
+
+    rsx! {
+        DemoSection { title: "EqColorPicker",
+            div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-3",
+                EqText {
+                    variant: TextVariant::Caption,
+                    class: "font-semibold uppercase tracking-wider",
+                    "Props"
+                }
+                PropSelect {
+                    label: "format",
+                    value: format_str(),
+                    options: vec!["Hex", "Rgb", "Hsl"],
+                    onchange: move |v: String| format_str.set(v),
+                }
+                PropToggle { label: "alpha", value: alpha(), onchange: move |v: bool| alpha.set(v) }
+                PropToggle { label: "theme presets", value: theme_presets(), onchange: move |v: bool| theme_presets.set(v) }
+                PropToggle { label: "eyedropper", value: eyedropper(), onchange: move |v: bool| eyedropper.set(v) }
+            }
+            div { class: "flex flex-wrap items-start gap-6",
+                EqColorPicker {
+                    value: color(),
+                    format,
+                    alpha: alpha(),
+                    presets,
+                    eyedropper: eyedropper(),
+                    on_change: move |c| color.set(c),
+                }
+                div { class: "space-y-2",
+                    div {
+                        class: "w-24 h-24 rounded-xl border border-[var(--color-card-border)]",
+                        style: "background: {color};",
+                    }
+                    EqText { variant: TextVariant::Mono, "{color}" }
+                }
+            }
+            StyleInfo { file: "eq_color_picker_styles.rs", styles: format_catalog(&s::catalog()) }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
+        }
+    }
+}
+
+// ── Gallery (compact showcase) ─────────────────────────────────────
+
+#[cfg(feature = "playground")]
+#[component]
+fn GalleryEqColorPicker() -> Element {
+    let mut a = use_signal(|| "#44c8ff".to_string());
+    let mut b = use_signal(|| "rgb(107, 255, 166)".to_string());
+
+    rsx! {
+        div { class: "flex flex-wrap gap-4",
+            EqColorPicker { value: a(), on_change: move |c| a.set(c) }
+            EqColorPicker {
+                value: b(),
+                format: ColorFormat::Rgb,
+                alpha: false,
+                presets: Vec::new(),
+                on_change: move |c| b.set(c),
+            }
+        }
+    }
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smoke_renders() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! { EqColorPicker { value: "rgba(255, 107, 157, 0.5)" } }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn smoke_renders_custom_presets() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                EqColorPicker {
+                    value: "not a colour",
+                    format: ColorFormat::Hsl,
+                    alpha: false,
+                    presets: vec!["#fff".to_string(), "var(--color-info)".to_string()],
+                }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn pad_maps_pointer_to_saturation_and_value() {
+        assert_eq!(pad_position(0.0, 0.0, 200.0, 100.0), Some((0.0, 1.0)));
+        assert_eq!(pad_position(100.0, 75.0, 200.0, 100.0), Some((0.5, 0.25)));
+        assert_eq!(pad_position(-20.0, 400.0, 200.0, 100.0), Some((0.0, 0.0)));
+        assert_eq!(pad_position(10.0, 10.0, 0.0, 100.0), None);
+    }
+
+    #[test]
+    fn arrow_keys_nudge_and_clamp() {
+        let base = Hsva { h: 120.0, s: 0.5, v: 0.95, a: 1.0 };
+        let right = nudge(base, &Key::ArrowRight, false).unwrap();
+        assert!((right.s - 0.51).abs() < 1e-6);
+        assert_eq!(right.h, 120.0);
+        assert_eq!(nudge(base, &Key::ArrowUp, true).unwrap().v, 1.0);
+        assert_eq!(nudge(base, &Key::Home, false).unwrap().s, 0.0);
+        assert!(nudge(base, &Key::Enter, false).is_none());
+    }
+}
@@ -0,0 +1,401 @@
+//! Colour model for EqColorPicker.
+//!
+//! Plain-Rust parsing, conversion and formatting - no JS involved, so
+//! everything here is unit-tested. Accepts the CSS forms people
+//! actually type: `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa` (with or
+//! without `#`), `rgb()` / `rgba()` and `hsl()` / `hsla()` in both the
+//! comma and the space-separated `/ alpha` syntax, plus a handful of
+//! named colours.
+
+use crate::PlaygroundEnum;
+
+/// Text format used for entry and for the `on_change` value.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, PlaygroundEnum)]
+pub enum ColorFormat {
+    /// `#rrggbb`, or `#rrggbbaa` when not opaque.
+    #[default]
+    Hex,
+    /// `rgb(r, g, b)` / `rgba(r, g, b, a)`.
+    Rgb,
+    /// `hsl(h, s%, l%)` / `hsla(h, s%, l%, a)`.
+    Hsl,
+}
+
+/// An sRGB colour with straight (non-premultiplied) alpha in `0.0..=1.0`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: f32,
+}
+
+/// Hue / saturation / value / alpha - the picker's working space.
+///
+/// `h` is in degrees (`0.0..360.0`), the rest in `0.0..=1.0`. Kept
+/// separately from [`Color`] so the hue survives while saturation or
+/// value sit at zero.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Hsva {
+    pub h: f32,
+    pub s: f32,
+    pub v: f32,
+    pub a: f32,
+}
+
+impl Default for Color {
+    fn default() -> Self {
+        Self::rgb(0, 0, 0)
+    }
+}
+
+impl Color {
+    /// Opaque colour from channels.
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b, a: 1.0 }
+    }
+
+    /// Colour from channels and alpha (clamped to `0.0..=1.0`).
+    pub fn rgba(r: u8, g: u8, b: u8, a: f32) -> Self {
+        Self { r, g, b, a: a.clamp(0.0, 1.0) }
+    }
+
+    /// Parses a CSS colour string. Returns `None` for anything it does
+    /// not understand (including `var(…)` references).
+    pub fn parse(input: &str) -> Option<Self> {
+        let s = input.trim().to_ascii_lowercase();
+        if let Some(hex) = s.strip_prefix('#') {
+            return parse_hex(hex);
+        }
+        if let Some((name, args)) = s.strip_suffix(')').and_then(|s| s.split_once('(')) {
+            let args = split_args(args)?;
+            return match name.trim() {
+                "rgb" | "rgba" => parse_rgb_args(&args),
+                "hsl" | "hsla" => parse_hsl_args(&args),
+                _ => None,
+            };
+        }
+        named(&s).or_else(|| parse_hex(&s))
+    }
+
+    /// Converts from HSL (`h` in degrees, `s` / `l` in `0.0..=1.0`).
+    pub fn from_hsl(h: f32, s: f32, l: f32, a: f32) -> Self {
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+        let v = l + s * l.min(1.0 - l);
+        let sv = if v == 0.0 { 0.0 } else { 2.0 * (1.0 - l / v) };
+        Hsva { h, s: sv, v, a }.to_color()
+    }
+
+    /// HSL components: hue in degrees, saturation and lightness in `0.0..=1.0`.
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let hsv = self.to_hsva();
+        let l = hsv.v * (1.0 - hsv.s / 2.0);
+        let s = if l == 0.0 || l == 1.0 { 0.0 } else { (hsv.v - l) / l.min(1.0 - l) };
+        (hsv.h, s, l)
+    }
+
+    /// HSV(A) equivalent. Greys get a hue of `0.0`.
+    pub fn to_hsva(self) -> Hsva {
+        let (r, g, b) = (self.r as f32 / 255.0, self.g as f32 / 255.0, self.b as f32 / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let d = max - min;
+        let h = if d == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / d).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / d + 2.0)
+        } else {
+            60.0 * ((r - g) / d + 4.0)
+        };
+        let s = if max == 0.0 { 0.0 } else { d / max };
+        Hsva { h, s, v: max, a: self.a }
+    }
+
+    /// `#rrggbb`, or `#rrggbbaa` when alpha is below 1.
+    pub fn to_hex(self) -> String {
+        let Color { r, g, b, .. } = self;
+        if self.is_opaque() {
+            format!("#{r:02x}{g:02x}{b:02x}")
+        } else {
+            format!("#{r:02x}{g:02x}{b:02x}{:02x}", (self.a * 255.0).round() as u8)
+        }
+    }
+
+    /// `rgb(r, g, b)`, or `rgba(r, g, b, a)` when alpha is below 1.
+    pub fn to_rgb_string(self) -> String {
+        let Color { r, g, b, .. } = self;
+        if self.is_opaque() {
+            format!("rgb({r}, {g}, {b})")
+        } else {
+            format!("rgba({r}, {g}, {b}, {})", format_alpha(self.a))
+        }
+    }
+
+    /// `hsl(h, s%, l%)`, or `hsla(h, s%, l%, a)` when alpha is below 1.
+    /// Components are rounded to whole numbers.
+    pub fn to_hsl_string(self) -> String {
+        let (h, s, l) = self.to_hsl();
+        let (h, s, l) = (h.round() as u32 % 360, (s * 100.0).round(), (l * 100.0).round());
+        if self.is_opaque() {
+            format!("hsl({h}, {s}%, {l}%)")
+        } else {
+            format!("hsla({h}, {s}%, {l}%, {})", format_alpha(self.a))
+        }
+    }
+
+    /// Formats in the given text format.
+    pub fn format(self, format: ColorFormat) -> String {
+        match format {
+            ColorFormat::Hex => self.to_hex(),
+            ColorFormat::Rgb => self.to_rgb_string(),
+            ColorFormat::Hsl => self.to_hsl_string(),
+        }
+    }
+
+    /// Same colour with a different alpha.
+    pub fn with_alpha(self, a: f32) -> Self {
+        Self::rgba(self.r, self.g, self.b, a)
+    }
+
+    /// True when alpha rounds to fully opaque at 8-bit precision.
+    pub fn is_opaque(self) -> bool {
+        (self.a * 255.0).round() >= 255.0
+    }
+}
+
+impl Default for Hsva {
+    fn default() -> Self {
+        Self { h: 0.0, s: 0.0, v: 0.0, a: 1.0 }
+    }
+}
+
+impl Hsva {
+    /// Converts to an 8-bit sRGB colour.
+    pub fn to_color(self) -> Color {
+        let h = self.h.rem_euclid(360.0) / 60.0;
+        let s = self.s.clamp(0.0, 1.0);
+        let v = self.v.clamp(0.0, 1.0);
+        let c = v * s;
+        let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+        let (r, g, b) = match h as u32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        let m = v - c;
+        let to_u8 = |f: f32| ((f + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Color::rgba(to_u8(r), to_u8(g), to_u8(b), self.a)
+    }
+
+    /// Moves to `color`, keeping the current hue when the new colour is
+    /// grey (where hue is undefined) and the current saturation when it
+    /// is black.
+    pub fn follow(self, color: Color) -> Self {
+        let mut next = color.to_hsva();
+        if next.s == 0.0 || next.v == 0.0 {
+            next.h = self.h;
+        }
+        if next.v == 0.0 {
+            next.s = self.s;
+        }
+        next
+    }
+}
+
+/// Alpha with at most two decimals and no trailing zeros.
+fn format_alpha(a: f32) -> String {
+    let s = format!("{:.2}", a.clamp(0.0, 1.0));
+    s.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let digit = |i: usize| u8::from_str_radix(&hex[i..=i], 16).ok().map(|d| d * 17);
+    let pair = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    match hex.len() {
+        3 | 4 => {
+            let a = if hex.len() == 4 { digit(3)? } else { 255 };
+            Some(Color::rgba(digit(0)?, digit(1)?, digit(2)?, a as f32 / 255.0))
+        }
+        6 | 8 => {
+            let a = if hex.len() == 8 { pair(6)? } else { 255 };
+            Some(Color::rgba(pair(0)?, pair(2)?, pair(4)?, a as f32 / 255.0))
+        }
+        _ => None,
+    }
+}
+
+/// Splits function arguments on commas, whitespace and the `/` alpha
+/// separator. Mixed comma / space syntax is tolerated.
+fn split_args(args: &str) -> Option<Vec<&str>> {
+    let parts: Vec<&str> = args
+        .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|p| !p.is_empty())
+        .collect();
+    (3..=4).contains(&parts.len()).then_some(parts)
+}
+
+/// A number, optionally with a `%` suffix (`percent_scale` is what 100%
+/// maps to).
+fn parse_number(s: &str, percent_scale: f32) -> Option<f32> {
+    let value = match s.strip_suffix('%') {
+        Some(p) => p.parse::<f32>().ok()? / 100.0 * percent_scale,
+        None => s.parse::<f32>().ok()?,
+    };
+    value.is_finite().then_some(value)
+}
+
+fn parse_alpha(args: &[&str]) -> Option<f32> {
+    match args.get(3) {
+        Some(a) => Some(parse_number(a, 1.0)?.clamp(0.0, 1.0)),
+        None => Some(1.0),
+    }
+}
+
+fn parse_rgb_args(args: &[&str]) -> Option<Color> {
+    let channel = |s: &str| Some(parse_number(s, 255.0)?.round().clamp(0.0, 255.0) as u8);
+    Some(Color::rgba(channel(args[0])?, channel(args[1])?, channel(args[2])?, parse_alpha(args)?))
+}
+
+fn parse_hsl_args(args: &[&str]) -> Option<Color> {
+    let h = args[0];
+    let hue = if let Some(d) = h.strip_suffix("deg") {
+        d.parse::<f32>().ok()?
+    } else if let Some(t) = h.strip_suffix("turn") {
+        t.parse::<f32>().ok()? * 360.0
+    } else if let Some(r) = h.strip_suffix("rad") {
+        r.parse::<f32>().ok()?.to_degrees()
+    } else {
+        h.parse::<f32>().ok()?
+    };
+    // Bare numbers are read as percentages, as in legacy `hsl()`.
+    let pct = |s: &str| Some(s.trim_end_matches('%').parse::<f32>().ok()? / 100.0);
+    Some(Color::from_hsl(hue.rem_euclid(360.0), pct(args[1])?, pct(args[2])?, parse_alpha(args)?))
+}
+
+fn named(name: &str) -> Option<Color> {
+    let c = match name {
+        "transparent" => Color::rgba(0, 0, 0, 0.0),
+        "black" => Color::rgb(0, 0, 0),
+        "white" => Color::rgb(255, 255, 255),
+        "red" => Color::rgb(255, 0, 0),
+        "green" => Color::rgb(0, 128, 0),
+        "lime" => Color::rgb(0, 255, 0),
+        "blue" => Color::rgb(0, 0, 255),
+        "yellow" => Color::rgb(255, 255, 0),
+        "cyan" | "aqua" => Color::rgb(0, 255, 255),
+        "magenta" | "fuchsia" => Color::rgb(255, 0, 255),
+        "orange" => Color::rgb(255, 165, 0),
+        "purple" => Color::rgb(128, 0, 128),
+        "gray" | "grey" => Color::rgb(128, 128, 128),
+        "silver" => Color::rgb(192, 192, 192),
+        "navy" => Color::rgb(0, 0, 128),
+        "teal" => Color::rgb(0, 128, 128),
+        "maroon" => Color::rgb(128, 0, 0),
+        "olive" => Color::rgb(128, 128, 0),
+        _ => return None,
+    };
+    Some(c)
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_hex_forms() {
+        assert_eq!(Color::parse("#f80"), Some(Color::rgb(255, 136, 0)));
+        assert_eq!(Color::parse("#FF8800"), Some(Color::rgb(255, 136, 0)));
+        assert_eq!(Color::parse("ff8800"), Some(Color::rgb(255, 136, 0)));
+        assert_eq!(Color::parse("#ff880080").map(|c| c.to_hex()), Some("#ff880080".into()));
+        assert_eq!(Color::parse("#0000").map(|c| c.a), Some(0.0));
+        assert_eq!(Color::parse("#ff88"), Some(Color::rgba(255, 255, 136, 136.0 / 255.0)));
+        assert_eq!(Color::parse("#12345"), None);
+        assert_eq!(Color::parse("#ggg"), None);
+    }
+
+    #[test]
+    fn parses_rgb_forms() {
+        assert_eq!(Color::parse("rgb(10, 20, 30)"), Some(Color::rgb(10, 20, 30)));
+        assert_eq!(Color::parse("rgba(10,20,30,0.5)"), Some(Color::rgba(10, 20, 30, 0.5)));
+        assert_eq!(Color::parse("rgb(10 20 30 / 50%)"), Some(Color::rgba(10, 20, 30, 0.5)));
+        assert_eq!(Color::parse("rgb(100%, 0%, 50%)"), Some(Color::rgb(255, 0, 128)));
+        assert_eq!(Color::parse("rgb(300, -5, 0)"), Some(Color::rgb(255, 0, 0)));
+        assert_eq!(Color::parse("rgb(1, 2)"), None);
+        assert_eq!(Color::parse("var(--color-accent-primary)"), None);
+    }
+
+    #[test]
+    fn parses_hsl_forms() {
+        assert_eq!(Color::parse("hsl(0, 100%, 50%)"), Some(Color::rgb(255, 0, 0)));
+        assert_eq!(Color::parse("hsl(120deg 100% 25%)"), Some(Color::rgb(0, 128, 0)));
+        assert_eq!(Color::parse("hsl(0.5turn, 100%, 50%)"), Some(Color::rgb(0, 255, 255)));
+        assert_eq!(Color::parse("hsla(240, 100%, 50%, 0.25)"), Some(Color::rgba(0, 0, 255, 0.25)));
+        assert_eq!(Color::parse("hsl(-120, 100%, 50%)"), Some(Color::rgb(0, 0, 255)));
+    }
+
+    #[test]
+    fn parses_names() {
+        assert_eq!(Color::parse(" White "), Some(Color::rgb(255, 255, 255)));
+        assert_eq!(Color::parse("transparent").map(|c| c.a), Some(0.0));
+        assert_eq!(Color::parse("notacolor"), None);
+    }
+
+    #[test]
+    fn formats() {
+        let c = Color::rgb(255, 136, 0);
+        assert_eq!(c.format(ColorFormat::Hex), "#ff8800");
+        assert_eq!(c.format(ColorFormat::Rgb), "rgb(255, 136, 0)");
+        assert_eq!(c.format(ColorFormat::Hsl), "hsl(32, 100%, 50%)");
+        let t = c.with_alpha(0.5);
+        assert_eq!(t.to_hex(), "#ff880080");
+        assert_eq!(t.to_rgb_string(), "rgba(255, 136, 0, 0.5)");
+        assert_eq!(t.to_hsl_string(), "hsla(32, 100%, 50%, 0.5)");
+        assert_eq!(Color::rgb(0, 0, 0).with_alpha(0.333).to_rgb_string(), "rgba(0, 0, 0, 0.33)");
+    }
+
+    #[test]
+    fn hsv_round_trips_every_hex_step() {
+        for r in (0..=255u8).step_by(51) {
+            for g in (0..=255u8).step_by(17) {
+                for b in (0..=255u8).step_by(85) {
+                    let c = Color::rgb(r, g, b);
+                    assert_eq!(c.to_hsva().to_color(), c, "{}", c.to_hex());
+                    let (h, s, l) = c.to_hsl();
+                    assert_eq!(Color::from_hsl(h, s, l, 1.0), c, "{}", c.to_hex());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hsv_primaries() {
+        let red = Color::rgb(255, 0, 0).to_hsva();
+        assert_eq!((red.h, red.s, red.v), (0.0, 1.0, 1.0));
+        let blue = Color::rgb(0, 0, 255).to_hsva();
+        assert_eq!(blue.h, 240.0);
+        assert_eq!(Hsva { h: 360.0, s: 1.0, v: 1.0, a: 1.0 }.to_color(), Color::rgb(255, 0, 0));
+    }
+
+    #[test]
+    fn follow_keeps_hue_on_greys() {
+        let current = Hsva { h: 200.0, s: 0.8, v: 0.6, a: 1.0 };
+        let grey = current.follow(Color::rgb(128, 128, 128));
+        assert_eq!(grey.h, 200.0);
+        assert_eq!(grey.s, 0.0);
+        let black = current.follow(Color::rgb(0, 0, 0));
+        assert_eq!((black.h, black.s, black.v), (200.0, 0.8, 0.0));
+        let red = current.follow(Color::rgb(255, 0, 0));
+        assert_eq!(red.h, 0.0);
+    }
+}
@@ -0,0 +1,78 @@
+//! Style constants for EqColorPicker.
+
+/// Outer panel.
+pub const WRAPPER: &str = "flex flex-col gap-3 w-64 p-3 rounded-xl \
+     bg-[var(--color-card)] border border-[var(--color-card-border)]";
+
+/// Saturation / value pad. The hue and the white / black gradients are
+/// set inline as layered backgrounds.
+pub const PAD: &str = "relative w-full h-40 rounded-lg cursor-crosshair touch-none select-none \
+     focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-[var(--color-focus-ring)]";
+
+/// Draggable marker on the pad (positioned inline, ignores the pointer).
+pub const PAD_THUMB: &str = "absolute w-4 h-4 -translate-x-1/2 -translate-y-1/2 rounded-full \
+     border-2 border-white shadow-[0_0_0_1px_rgba(0,0,0,0.4)] pointer-events-none";
+
+/// Transparent full-viewport layer that keeps receiving pointer events
+/// while the pad is being dragged.
+pub const DRAG_OVERLAY: &str = "fixed inset-0 z-50 cursor-crosshair touch-none";
+
+/// Row holding the preview chip, the sliders and the eyedropper.
+pub const CONTROLS: &str = "flex items-center gap-3";
+
+/// Column stacking the hue and alpha sliders.
+pub const SLIDERS: &str = "flex flex-1 flex-col gap-2 min-w-0";
+
+/// Hue / alpha range input. The gradient track is set inline as the
+/// element background; only the thumb is styled here.
+pub const CHANNEL: &str = "w-full h-3 appearance-none rounded-full cursor-pointer \
+     focus:outline-none focus-visible:ring-2 focus-visible:ring-[var(--color-focus-ring)] \
+     [&::-webkit-slider-thumb]:appearance-none [&::-webkit-slider-thumb]:w-4 \
+     [&::-webkit-slider-thumb]:h-4 [&::-webkit-slider-thumb]:rounded-full \
+     [&::-webkit-slider-thumb]:bg-white [&::-webkit-slider-thumb]:border \
+     [&::-webkit-slider-thumb]:border-black/30 [&::-webkit-slider-thumb]:shadow \
+     [&::-moz-range-thumb]:w-4 [&::-moz-range-thumb]:h-4 [&::-moz-range-thumb]:rounded-full \
+     [&::-moz-range-thumb]:bg-white [&::-moz-range-thumb]:border [&::-moz-range-thumb]:border-black/30 \
+     [&::-moz-range-track]:bg-transparent";
+
+/// Current-colour preview chip.
+pub const PREVIEW: &str = "w-9 h-9 shrink-0 rounded-lg border border-[var(--color-card-border)]";
+
+/// Eyedropper button.
+pub const EYEDROPPER_BTN: &str = "w-8 h-8 shrink-0 inline-flex items-center justify-center rounded-lg \
+     cursor-pointer text-[var(--color-label-secondary)] \
+     hover:text-[var(--color-label-primary)] hover:bg-[var(--color-hover-button)] transition-colors \
+     focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-[var(--color-focus-ring)]";
+
+/// Format tabs + text field.
+pub const ENTRY: &str = "flex flex-col gap-2";
+
+/// Text field showing an unparsable draft.
+pub const ENTRY_INVALID: &str = "!border-[var(--color-error)]";
+
+/// Preset swatch grid.
+pub const SWATCHES: &str = "grid grid-cols-8 gap-1.5";
+
+/// One preset swatch button.
+pub const SWATCH: &str = "aspect-square w-full rounded-md cursor-pointer \
+     border border-[var(--color-card-border)] hover:scale-110 transition-transform \
+     focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-[var(--color-focus-ring)]";
+
+/// All style tokens for playground introspection.
+pub fn catalog() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("WRAPPER", WRAPPER),
+        ("PAD", PAD),
+        ("PAD_THUMB", PAD_THUMB),
+        ("DRAG_OVERLAY", DRAG_OVERLAY),
+        ("CONTROLS", CONTROLS),
+        ("SLIDERS", SLIDERS),
+        ("CHANNEL", CHANNEL),
+        ("PREVIEW", PREVIEW),
+        ("EYEDROPPER_BTN", EYEDROPPER_BTN),
+        ("ENTRY", ENTRY),
+        ("ENTRY_INVALID", ENTRY_INVALID),
+        ("SWATCHES", SWATCHES),
+        ("SWATCH", SWATCH),
+    ]
+}
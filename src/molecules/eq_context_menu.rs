@@ -0,0 +1,361 @@
+//! EqContextMenu — right-click menu molecule.
+//!
+//! Wraps an area of the page; right-clicking it (or pressing the
+//! context-menu key / Shift+F10 inside it) opens a menu at the pointer.
+//! Items use the shared [`MenuItem`] model: icons, shortcut hints,
+//! checkbox and radio items, separators and nested submenus that open
+//! on hover or ArrowRight.
+//!
+//! ```no_run
+//! use eq_ui::prelude::*;
+//! use eq_ui::molecules::{EqContextMenu, MenuItem};
+//!
+//! fn app() -> Element {
+//!     let items = vec![
+//!         MenuItem::new("cut", "Cut").shortcut("Ctrl+X"),
+//!         MenuItem::new("copy", "Copy").shortcut("Ctrl+C"),
+//!         MenuItem::separator(),
+//!         MenuItem::new("share", "Share").submenu(vec![
+//!             MenuItem::new("link", "Copy link"),
+//!             MenuItem::new("email", "Email"),
+//!         ]),
+//!     ];
+//!     let mut last = use_signal(String::new);
+//!
+//!     rsx! {
+//!         EqContextMenu {
+//!             items,
+//!             on_select: move |id: String| last.set(id),
+//!             div { class: "p-8 border border-dashed", "Right-click here" }
+//!         }
+//!     }
+//! }
+//! ```
+
+use super::eq_context_menu_styles as s;
+use super::eq_menu::{MenuFocus, MenuItem, MenuList};
+use crate::floating::{use_floating, Align, FloatingOptions, Placement, Rect, Side};
+use crate::overlay::EqPortal;
+use crate::theme::merge_classes;
+use crate::playground;
+use dioxus::prelude::*;
+
+#[cfg(feature = "playground")]
+use crate::playground::playground_helpers::{
+    DemoSection, PropToggle, StyleInfo, format_catalog,
+};
+#[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
+use super::eq_menu::toggle_item;
+#[cfg(feature = "playground")]
+use crate::atoms::{eq_icon_paths, EqText, TextVariant};
+#[cfg(feature = "playground")]
+use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
+
+/// Whether a key press should open the menu from the keyboard.
+fn is_menu_key(key: &Key, shift: bool) -> bool {
+    *key == Key::ContextMenu || (shift && *key == Key::F10)
+}
+
+// ── Component ─────────────────────────────────────────────────────
+
+/// Right-click context menu around `children`.
+///
+/// Opening from the pointer places the menu at the click (flipping up
+/// and shifting sideways near the viewport edges) and focuses the menu;
+/// opening from the keyboard places it at the area's top-left corner
+/// and focuses the first item. Selecting an item fires `on_select` with
+/// its `id` and closes the menu.
+///
+/// **Accessibility** — the menu uses `role="menu"` with `menuitem`,
+/// `menuitemcheckbox` or `menuitemradio` items; submenu items carry
+/// `aria-haspopup` and `aria-expanded`. Arrow keys navigate, ArrowRight
+/// opens a submenu and ArrowLeft closes it, and Escape closes and
+/// returns focus to where it was.
+#[playground(
+    category = Molecule,
+    description = "Right-click menu opened at the pointer, with nested submenus (hover or \
+                   ArrowRight), icons, shortcut hints, and checkbox / radio items.",
+    examples = [
+        ("Basic", "let items = vec![\n    MenuItem::new(\"copy\", \"Copy\").shortcut(\"Ctrl+C\"),\n    MenuItem::new(\"paste\", \"Paste\").shortcut(\"Ctrl+V\"),\n    MenuItem::separator(),\n    MenuItem::new(\"delete\", \"Delete\").disabled(),\n];\n\nEqContextMenu {\n    items,\n    on_select: move |id: String| log::info!(\"{id}\"),\n    div { \"Right-click me\" }\n}"),
+        ("Submenu", "MenuItem::new(\"sort\", \"Sort by\").submenu(vec![\n    MenuItem::new(\"name\", \"Name\").radio(\"sort\", true),\n    MenuItem::new(\"date\", \"Date\").radio(\"sort\", false),\n])"),
+        ("Checkable", "let mut items = use_signal(|| vec![\n    MenuItem::new(\"grid\", \"Show grid\").checkbox(true),\n]);\n\nEqContextMenu {\n    items: items(),\n    on_select: move |id: String| { toggle_item(&mut items.write(), &id); },\n    div { \"Canvas\" }\n}"),
+    ],
+    custom_demo,
+    custom_gallery,
+)]
+#[component]
+pub fn EqContextMenu(
+    /// Menu items (and separators).
+    items: Vec<MenuItem>,
+    /// Fired when an enabled item without a submenu is activated.
+    /// Receives the item's `id`.
+    #[props(default)]
+    on_select: Option<EventHandler<String>>,
+    /// Leave the browser's own context menu in place.
+    #[props(default = false)]
+    disabled: bool,
+    /// Accessible label for the menu.
+    #[props(into, default = "Context menu".to_string())]
+    label: String,
+    /// Optional class override on the target wrapper.
+    #[props(into, default)]
+    class: String,
+    /// The area that opens the menu.
+    children: Element,
+) -> Element {
+    let mut open = use_signal(|| false);
+    let mut open_focus = use_signal(|| MenuFocus::Menu);
+    let mut target = use_signal(|| None::<MountedEvent>);
+    let mut floating = use_floating(open(), FloatingOptions {
+        placement: Placement::new(Side::Bottom, Align::Start),
+        offset: 0.0,
+        ..Default::default()
+    });
+
+    let target_cls = merge_classes(s::TARGET, &class);
+    let menu_state = if open() { s::MENU_OPEN } else { s::MENU_CLOSED };
+
+    rsx! {
+        div {
+            class: "{target_cls}",
+            onmounted: move |e| target.set(Some(e)),
+            oncontextmenu: move |evt: MouseEvent| {
+                if disabled {
+                    return;
+                }
+                evt.prevent_default();
+                let at = evt.client_coordinates();
+                floating.set_anchor_rect(Rect::new(at.x, at.y, 0.0, 0.0));
+                open_focus.set(MenuFocus::Menu);
+                open.set(true);
+            },
+            onkeydown: move |evt: KeyboardEvent| {
+                if disabled || !is_menu_key(&evt.key(), evt.modifiers().shift()) {
+                    return;
+                }
+                evt.prevent_default();
+                let Some(area) = target.peek().clone() else { return };
+                spawn(async move {
+                    if let Ok(r) = area.get_client_rect().await {
+                        floating.set_anchor_rect(Rect::new(r.origin.x, r.origin.y, 0.0, 0.0));
+                    }
+                    open_focus.set(MenuFocus::First);
+                    open.set(true);
+                });
+            },
+
+            {children}
+        }
+
+        // Beside the target rather than inside it, so events in the
+        // menu never bubble back into the target when rendered in place.
+        EqPortal {
+            active: open(),
+            restore_focus: true,
+            on_escape: move |_| open.set(false),
+            on_outside_click: move |_| open.set(false),
+            div {
+                class: "{s::MENU} {menu_state}",
+                style: floating.style(),
+                onmounted: move |e| floating.set_floating(e),
+                oncontextmenu: move |evt: MouseEvent| evt.prevent_default(),
+                if open() {
+                    MenuList {
+                        items: items.clone(),
+                        initial_focus: open_focus(),
+                        label: label.clone(),
+                        on_select: move |id: String| {
+                            if let Some(handler) = &on_select {
+                                handler.call(id);
+                            }
+                            open.set(false);
+                        },
+                        on_close: move |_| open.set(false),
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── Interactive demo ───────────────────────────────────────────────
+
+#[cfg(feature = "playground")]
+fn demo_items() -> Vec<MenuItem> {
+    vec![
+        MenuItem::new("cut", "Cut").shortcut("Ctrl+X"),
+        MenuItem::new("copy", "Copy").shortcut("Ctrl+C"),
+        MenuItem::new("paste", "Paste").shortcut("Ctrl+V").disabled(),
+        MenuItem::separator(),
+        MenuItem::new("find", "Find…").icon(eq_icon_paths::MAGNIFYING_GLASS).shortcut("Ctrl+F"),
+        MenuItem::new("filter", "Filter").icon(eq_icon_paths::FUNNEL).submenu(vec![
+            MenuItem::new("all", "All files").radio("filter", true),
+            MenuItem::new("images", "Images").radio("filter", false),
+            MenuItem::new("docs", "Documents").radio("filter", false),
+            MenuItem::separator(),
+            MenuItem::new("more", "More").submenu(vec![
+                MenuItem::new("archives", "Archives"),
+                MenuItem::new("video", "Video"),
+            ]),
+        ]),
+        MenuItem::separator(),
+        MenuItem::new("grid", "Show grid").checkbox(true),
+        MenuItem::new("rulers", "Show rulers").checkbox(false),
+    ]
+}
+
+#[cfg(feature = "playground")]
+#[component]
+fn DemoEqContextMenu() -> Element {
+    let mut items = use_signal(demo_items);
+    let mut disabled = use_signal(|| false);
+    let mut last = use_signal(|| "(none)".to_string());
+
+    let code = r#"let mut items = use_signal(|| vec![
+    MenuItem::new("cut", "Cut").shortcut("Ctrl+X"),
+    MenuItem::new("copy", "Copy").shortcut("Ctrl+C"),
+    MenuItem::separator(),
+    MenuItem::new("find", "Find…").icon(eq_icon_paths::MAGNIFYING_GLASS),
+    MenuItem::new("filter", "Filter").submenu(vec![
+        MenuItem::new("all", "All files").radio("filter", true),
+        MenuItem::new("images", "Images").radio("filter", false),
+    ]),
+    MenuItem::separator(),
+    MenuItem::new("grid", "Show grid").checkbox(true),
+]);
+
+EqContextMenu {
+    items: items(),
+    on_select: move |id: String| {
+        toggle_item(&mut items.write(), &id);
+    },
+    div { "Right-click anywhere in this area" }
+}"#
+    .to_string();
+
+    rsx! {
+        DemoSection { title: "EqContextMenu",
+            div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-3",
+                EqText {
+                    variant: TextVariant::Caption,
+                    class: "font-semibold uppercase tracking-wider",
+                    "Props"
+                }
+                PropToggle {
+                    label: "disabled",
+                    value: disabled(),
+                    onchange: move |v: bool| disabled.set(v),
+                }
+            }
+
+            EqContextMenu {
+                items: items(),
+                disabled: disabled(),
+                on_select: move |id: String| {
+                    toggle_item(&mut items.write(), &id);
+                    last.set(id);
+                },
+                div {
+                    class: "rounded-lg border border-dashed border-[var(--color-card-border)] p-10 text-center space-y-2",
+                    tabindex: "0",
+                    EqText { "Right-click anywhere in this area (or focus it and press Shift+F10)." }
+                    EqText { variant: TextVariant::Muted, "Last selected: {last}" }
+                }
+            }
+
+            StyleInfo { file: "eq_context_menu_styles.rs", styles: format_catalog(&s::catalog()) }
+            StyleInfo { file: "eq_menu_styles.rs", styles: format_catalog(&super::eq_menu_styles::catalog()) }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
+        }
+    }
+}
+
+// ── Gallery ───────────────────────────────────────────────────────
+
+#[cfg(feature = "playground")]
+#[component]
+fn GalleryEqContextMenu() -> Element {
+    let file_items = vec![
+        MenuItem::new("open", "Open"),
+        MenuItem::new("rename", "Rename").shortcut("F2"),
+        MenuItem::new("move", "Move to").submenu(vec![
+            MenuItem::new("docs", "Documents"),
+            MenuItem::new("archive", "Archive"),
+        ]),
+        MenuItem::separator(),
+        MenuItem::new("delete", "Delete").shortcut("Del"),
+    ];
+
+    rsx! {
+        div { class: "space-y-4",
+            div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-4",
+                EqText { variant: TextVariant::Caption, class: "font-semibold uppercase tracking-wider", "Context Menu Gallery" }
+
+                div { class: "grid grid-cols-2 gap-4",
+                    EqContextMenu {
+                        items: file_items,
+                        div { class: "rounded-md border border-dashed border-[var(--color-card-border)] p-6 text-center",
+                            EqText { variant: TextVariant::Muted, "report.pdf - right-click" }
+                        }
+                    }
+                    EqContextMenu {
+                        items: vec![MenuItem::new("copy", "Copy")],
+                        disabled: true,
+                        div { class: "rounded-md border border-dashed border-[var(--color-card-border)] p-6 text-center",
+                            EqText { variant: TextVariant::Muted, "Disabled - native menu" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── Smoke tests ─────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn menu_keys() {
+        assert!(is_menu_key(&Key::ContextMenu, false));
+        assert!(is_menu_key(&Key::F10, true));
+        assert!(!is_menu_key(&Key::F10, false));
+        assert!(!is_menu_key(&Key::Enter, true));
+    }
+
+    #[test]
+    fn smoke_renders() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                EqContextMenu {
+                    items: vec![
+                        MenuItem::new("a", "Alpha"),
+                        MenuItem::new("b", "Beta").submenu(vec![MenuItem::new("c", "Gamma")]),
+                    ],
+                    div { "target" }
+                }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn smoke_renders_in_overlay_root() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                crate::overlay::EqOverlayRoot {
+                    EqContextMenu {
+                        items: vec![MenuItem::new("a", "Alpha").checkbox(true)],
+                        disabled: true,
+                        div { "target" }
+                    }
+                }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+}
@@ -0,0 +1,27 @@
+//! Style constants for EqContextMenu.
+
+/// Wrapper around the right-click target area.
+pub const TARGET: &str = "relative";
+
+/// The menu panel. It is pinned at the pointer by the floating engine;
+/// `fixed` keeps it out of the page flow until then.
+pub const MENU: &str =
+    "fixed z-50 min-w-[12rem] rounded-md py-1 \
+     bg-[var(--color-card)] \
+     border border-[var(--color-card-border)] \
+     shadow-2xl shadow-black/40";
+
+/// Menu open.
+pub const MENU_OPEN: &str = "visible";
+/// Menu closed.
+pub const MENU_CLOSED: &str = "invisible pointer-events-none";
+
+/// All style tokens for playground introspection.
+pub fn catalog() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("TARGET", TARGET),
+        ("MENU", MENU),
+        ("MENU_OPEN", MENU_OPEN),
+        ("MENU_CLOSED", MENU_CLOSED),
+    ]
+}
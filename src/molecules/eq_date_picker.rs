@@ -22,15 +22,19 @@
 //! ```
 
 use super::eq_date_picker_styles as s;
+use crate::floating::{use_floating, Align, FloatingOptions, Placement, Side};
+use crate::overlay::EqPortal;
 use crate::theme::merge_classes;
 use crate::{PlaygroundEnum, playground};
 use dioxus::prelude::*;
 
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropSelect, PropToggle, StyleInfo, format_catalog,
+    DemoSection, PropSelect, PropToggle, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
 #[cfg(feature = "playground")]
 use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
@@ -64,7 +68,8 @@ impl DateValue {
     }
 }
 
-/// Position of the calendar popup.
+/// Preferred position of the calendar popup. It flips to the other
+/// side when there is no room.
 #[derive(Clone, Copy, PartialEq, Default, PlaygroundEnum)]
 pub enum DatePickerPosition {
     #[default]
@@ -72,6 +77,15 @@ pub enum DatePickerPosition {
     Top,
 }
 
+impl From<DatePickerPosition> for Placement {
+    fn from(position: DatePickerPosition) -> Self {
+        match position {
+            DatePickerPosition::Bottom => Placement::new(Side::Bottom, Align::Start),
+            DatePickerPosition::Top => Placement::new(Side::Top, Align::Start),
+        }
+    }
+}
+
 // ── Date math helpers (no external deps) ──────────────────────────
 
 const MONTH_NAMES: [&str; 12] = [
@@ -278,6 +292,16 @@ pub fn EqDatePicker(
         value.map(|d| d.month).unwrap_or(today.month)
     });
     let mut open = use_signal(|| false);
+    let mut floating = use_floating(open(), FloatingOptions {
+        placement: position.into(),
+        ..Default::default()
+    });
+
+    // Months span five or six rows; re-place the popup when it changes.
+    use_effect(move || {
+        view_month.read();
+        floating.update();
+    });
 
     let wrapper_cls = merge_classes(s::WRAPPER, &class);
     let trigger_cls = if disabled { s::TRIGGER_DISABLED } else { s::TRIGGER };
@@ -307,17 +331,10 @@ pub fn EqDatePicker(
         div {
             class: "{wrapper_cls}",
 
-            // Close on outside click.
-            if open() {
-                div {
-                    class: "fixed inset-0 z-40",
-                    onclick: move |_| open.set(false),
-                }
-            }
-
             // Trigger
             button {
                 class: "{trigger_cls}",
+                onmounted: move |e| floating.set_anchor(e),
                 r#type: "button",
                 disabled: disabled,
                 role: "combobox",
@@ -359,122 +376,129 @@ pub fn EqDatePicker(
             }
 
             // Calendar panel
-            div {
-                class: "{s::PANEL} {pos_cls} {panel_state}",
-                role: "dialog",
-                "aria-label": "Date picker",
-                onkeydown: move |evt: KeyboardEvent| {
-                    if evt.key() == Key::Escape {
-                        open.set(false);
-                    }
-                },
-
-                // Month/year header
-                div { class: "{s::HEADER}",
-                    button {
-                        class: "{s::NAV_BUTTON}",
-                        r#type: "button",
-                        "aria-label": "Previous month",
-                        onclick: move |_| {
-                            let m = view_month();
-                            if m == 1 {
-                                view_month.set(12);
-                                view_year.set(view_year() - 1);
-                            } else {
-                                view_month.set(m - 1);
-                            }
-                        },
-                        svg {
-                            xmlns: "http://www.w3.org/2000/svg",
-                            view_box: "0 0 20 20",
-                            fill: "currentColor",
-                            width: "16",
-                            height: "16",
-                            "aria-hidden": "true",
-                            path {
-                                fill_rule: "evenodd",
-                                clip_rule: "evenodd",
-                                d: CHEVRON_LEFT,
+            EqPortal {
+                active: open(),
+                on_escape: move |_| open.set(false),
+                on_outside_click: move |_| open.set(false),
+                div {
+                    class: "{s::PANEL} {pos_cls} {panel_state}",
+                    style: floating.style(),
+                    onmounted: move |e| floating.set_floating(e),
+                    role: "dialog",
+                    "aria-label": "Date picker",
+                    onkeydown: move |evt: KeyboardEvent| {
+                        if evt.key() == Key::Escape {
+                            open.set(false);
+                        }
+                    },
+
+                    // Month/year header
+                    div { class: "{s::HEADER}",
+                        button {
+                            class: "{s::NAV_BUTTON}",
+                            r#type: "button",
+                            "aria-label": "Previous month",
+                            onclick: move |_| {
+                                let m = view_month();
+                                if m == 1 {
+                                    view_month.set(12);
+                                    view_year.set(view_year() - 1);
+                                } else {
+                                    view_month.set(m - 1);
+                                }
+                            },
+                            svg {
+                                xmlns: "http://www.w3.org/2000/svg",
+                                view_box: "0 0 20 20",
+                                fill: "currentColor",
+                                width: "16",
+                                height: "16",
+                                "aria-hidden": "true",
+                                path {
+                                    fill_rule: "evenodd",
+                                    clip_rule: "evenodd",
+                                    d: CHEVRON_LEFT,
+                                }
                             }
                         }
-                    }
 
-                    span { class: "{s::HEADER_LABEL}", "{month_label}" }
-
-                    button {
-                        class: "{s::NAV_BUTTON}",
-                        r#type: "button",
-                        "aria-label": "Next month",
-                        onclick: move |_| {
-                            let m = view_month();
-                            if m == 12 {
-                                view_month.set(1);
-                                view_year.set(view_year() + 1);
-                            } else {
-                                view_month.set(m + 1);
-                            }
-                        },
-                        svg {
-                            xmlns: "http://www.w3.org/2000/svg",
-                            view_box: "0 0 20 20",
-                            fill: "currentColor",
-                            width: "16",
-                            height: "16",
-                            "aria-hidden": "true",
-                            path {
-                                fill_rule: "evenodd",
-                                clip_rule: "evenodd",
-                                d: CHEVRON_RIGHT,
+                        span { class: "{s::HEADER_LABEL}", "{month_label}" }
+
+                        button {
+                            class: "{s::NAV_BUTTON}",
+                            r#type: "button",
+                            "aria-label": "Next month",
+                            onclick: move |_| {
+                                let m = view_month();
+                                if m == 12 {
+                                    view_month.set(1);
+                                    view_year.set(view_year() + 1);
+                                } else {
+                                    view_month.set(m + 1);
+                                }
+                            },
+                            svg {
+                                xmlns: "http://www.w3.org/2000/svg",
+                                view_box: "0 0 20 20",
+                                fill: "currentColor",
+                                width: "16",
+                                height: "16",
+                                "aria-hidden": "true",
+                                path {
+                                    fill_rule: "evenodd",
+                                    clip_rule: "evenodd",
+                                    d: CHEVRON_RIGHT,
+                                }
                             }
                         }
                     }
-                }
 
-                // Weekday headers
-                div { class: "{s::WEEKDAYS}",
-                    for wd in WEEKDAY_SHORT.iter() {
-                        span { class: "{s::WEEKDAY}", "{wd}" }
+                    // Weekday headers
+                    div { class: "{s::WEEKDAYS}",
+                        for wd in WEEKDAY_SHORT.iter() {
+                            span { class: "{s::WEEKDAY}", "{wd}" }
+                        }
                     }
-                }
 
-                // Day grid
-                div { class: "{s::DAYS_GRID}", role: "grid",
-                    for cell in cells.iter() {
-                        {
-                            let mut extra = String::new();
-                            if !cell.is_current_month {
-                                extra.push_str(s::DAY_OUTSIDE);
-                                extra.push(' ');
-                            }
-                            if cell.is_today && !cell.is_selected {
-                                extra.push_str(s::DAY_TODAY);
-                                extra.push(' ');
-                            }
-                            if cell.is_selected {
-                                extra.push_str(s::DAY_SELECTED);
-                                extra.push(' ');
-                            }
+                    // Day grid
+                    div { class: "{s::DAYS_GRID}", role: "grid",
+                        for cell in cells.iter() {
+                            {
+                                let mut extra = String::new();
+                                if !cell.is_current_month {
+                                    extra.push_str(s::DAY_OUTSIDE);
+                                    extra.push(' ');
+                                }
+                                if cell.is_today && !cell.is_selected {
+                                    extra.push_str(s::DAY_TODAY);
+                                    extra.push(' ');
+                                }
+                                if cell.is_selected {
+                                    extra.push_str(s::DAY_SELECTED);
+                                    extra.push(' ');
+                                }
 
-                            let cy = cell.year;
-                            let cm = cell.month;
-                            let cd = cell.day;
-
-                            rsx! {
-                                button {
-                                    key: "{cy}-{cm}-{cd}",
-                                    class: "{s::DAY} {extra}",
-                                    r#type: "button",
-                                    role: "gridcell",
-                                    "aria-selected": "{cell.is_selected}",
-                                    tabindex: "-1",
-                                    onclick: move |_| {
-                                        let new_date = DateValue::new(cy, cm, cd);
-                                        if let Some(handler) = &on_change {
-                                            handler.call(new_date);
-                                        }
-                                        open.set(false);
-                                    },
-                                    "{cd}"
+                                let cy = cell.year;
+                                let cm = cell.month;
+                                let cd = cell.day;
+
+                                rsx! {
+                                    button {
+                                        key: "{cy}-{cm}-{cd}",
+                                        class: "{s::DAY} {extra}",
+                                        r#type: "button",
+                                        role: "gridcell",
+                                        "aria-selected": "{cell.is_selected}",
+                                        tabindex: "-1",
+                                        onclick: move |_| {
+                                            let new_date = DateValue::new(cy, cm, cd);
+                                            if let Some(handler) = &on_change {
+                                                handler.call(new_date);
+                                            }
+                                            open.set(false);
+                                        },
+                                        "{cd}"
+                                    }
                                 }
                             }
                         }
@@ -554,7 +578,7 @@ EqDatePicker {{
             }
 
             StyleInfo { file: "eq_date_picker_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
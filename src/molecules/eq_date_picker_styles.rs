@@ -1,5 +1,7 @@
 //! Style constants for EqDatePicker.
 
+use crate::floating::{FALLBACK_MENU_BOTTOM, FALLBACK_MENU_TOP};
+
 /// Wrapper — relative anchor for the calendar popup.
 pub const WRAPPER: &str = "relative inline-flex flex-col";
 
@@ -40,9 +42,9 @@ pub const PANEL_OPEN: &str = "visible";
 pub const PANEL_CLOSED: &str = "invisible pointer-events-none";
 
 /// Position: below trigger (default).
-pub const POS_BOTTOM: &str = "top-full left-0";
+pub const POS_BOTTOM: &str = FALLBACK_MENU_BOTTOM;
 /// Position: above trigger.
-pub const POS_TOP: &str = "bottom-full left-0 mb-1 mt-0";
+pub const POS_TOP: &str = FALLBACK_MENU_TOP;
 
 /// Month/year header row.
 pub const HEADER: &str =
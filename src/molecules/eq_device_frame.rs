@@ -41,9 +41,11 @@ use dioxus::prelude::*;
 use crate::atoms::{EqText, TextVariant};
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropSelect, PropToggle, StyleInfo, format_catalog,
+    DemoSection, PropSelect, PropToggle, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::playground::playground_types::{ComponentCategory, ComponentDescriptor, UsageExample};
 
 /// iPhone model variants supported by `EqDeviceFrame`.
@@ -417,7 +419,7 @@ fn DemoEqDeviceFrame() -> Element {
                 file: "eq_device_frame_styles.rs",
                 styles: format_catalog(&s::catalog()),
             }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
@@ -0,0 +1,803 @@
+//! EqDialogHost — async confirm and prompt dialogs.
+//!
+//! Mount `EqDialogHost` once near the app root; anywhere below it,
+//! [`use_dialogs`] returns a [`Dialogs`] handle whose
+//! [`confirm`](Dialogs::confirm) and [`prompt`](Dialogs::prompt)
+//! open a themed [`EqModal`] and resolve when the user answers, so an
+//! "Are you sure?" no longer needs its own bool signal and `on_close`:
+//!
+//! - **Danger** — [`ConfirmOptions::danger`] renders the confirm
+//!   button with [`ButtonVariant::Danger`].
+//! - **Type to confirm** — [`ConfirmOptions::require_text`] keeps the
+//!   confirm button disabled until the user types the given text.
+//! - **Prompt** — [`PromptOptions`] asks for a value, with optional
+//!   `required` and a validator whose message shows below the input.
+//! - **Keyboard** — Enter in the input confirms (when allowed);
+//!   Escape and the backdrop cancel.
+//! - **Queue** — dialogs opened while another is showing wait their
+//!   turn.
+//!
+//! Answers resolve the future returned by `confirm` / `prompt`, so
+//! await it inside a task:
+//!
+//! ```no_run
+//! use eq_ui::prelude::*;
+//! use eq_ui::molecules::{ConfirmOptions, EqDialogHost, use_dialogs};
+//!
+//! fn app() -> Element {
+//!     rsx! {
+//!         EqDialogHost { DeleteButton {} }
+//!     }
+//! }
+//!
+//! #[component]
+//! fn DeleteButton() -> Element {
+//!     let dialogs = use_dialogs();
+//!     rsx! {
+//!         EqButton {
+//!             variant: ButtonVariant::Danger,
+//!             on_click: move |_| {
+//!                 spawn(async move {
+//!                     let sure = dialogs
+//!                         .confirm(
+//!                             ConfirmOptions::new("Delete project?")
+//!                                 .message("This cannot be undone.")
+//!                                 .confirm_label("Delete")
+//!                                 .danger()
+//!                                 .require_text("acme-site"),
+//!                         )
+//!                         .await;
+//!                     if sure { /* delete */ }
+//!                 });
+//!             },
+//!             "Delete"
+//!         }
+//!     }
+//! }
+//! ```
+
+use super::eq_dialogs_styles as s;
+use super::eq_modal::{EqModal, ModalSize};
+use crate::atoms::eq_input_styles;
+use crate::atoms::{ButtonVariant, EqButton};
+use crate::overlay::FocusTarget;
+use crate::playground;
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::future::Future;
+use std::rc::Rc;
+use std::task::{Poll, Waker};
+
+#[cfg(feature = "playground")]
+use crate::playground::playground_helpers::{DemoSection, StyleInfo, format_catalog};
+#[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
+use crate::atoms::{EqText, TextVariant};
+#[cfg(feature = "playground")]
+use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
+
+// ── Options ───────────────────────────────────────────────────────
+
+/// What [`Dialogs::confirm`] shows.
+#[derive(Clone, PartialEq)]
+pub struct ConfirmOptions {
+    /// Dialog title.
+    pub title: String,
+    /// Text below the title.
+    pub message: String,
+    /// Confirm button label. Default: "Confirm".
+    pub confirm_label: String,
+    /// Cancel button label. Default: "Cancel".
+    pub cancel_label: String,
+    /// Render the confirm button with the danger variant.
+    pub danger: bool,
+    /// Text the user must type before the confirm button enables.
+    pub require_text: Option<String>,
+}
+
+impl ConfirmOptions {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            message: String::new(),
+            confirm_label: "Confirm".into(),
+            cancel_label: "Cancel".into(),
+            danger: false,
+            require_text: None,
+        }
+    }
+
+    /// Builder: set the message.
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    /// Builder: set the confirm button label.
+    pub fn confirm_label(mut self, label: impl Into<String>) -> Self {
+        self.confirm_label = label.into();
+        self
+    }
+
+    /// Builder: set the cancel button label.
+    pub fn cancel_label(mut self, label: impl Into<String>) -> Self {
+        self.cancel_label = label.into();
+        self
+    }
+
+    /// Builder: mark the action as destructive.
+    pub fn danger(mut self) -> Self {
+        self.danger = true;
+        self
+    }
+
+    /// Builder: require the user to type `text` (e.g. the name of the
+    /// thing being deleted) to confirm.
+    pub fn require_text(mut self, text: impl Into<String>) -> Self {
+        self.require_text = Some(text.into());
+        self
+    }
+}
+
+/// Validator for [`PromptOptions::validate`]: `Err` carries the
+/// message shown below the input.
+pub type PromptValidator = Rc<dyn Fn(&str) -> Result<(), String>>;
+
+/// What [`Dialogs::prompt`] shows.
+#[derive(Clone)]
+pub struct PromptOptions {
+    /// Dialog title.
+    pub title: String,
+    /// Text below the title.
+    pub message: String,
+    /// Input label. When empty, the title labels the input.
+    pub label: String,
+    /// Input placeholder.
+    pub placeholder: String,
+    /// Initial input value.
+    pub default_value: String,
+    /// Confirm button label. Default: "OK".
+    pub confirm_label: String,
+    /// Cancel button label. Default: "Cancel".
+    pub cancel_label: String,
+    /// Disable the confirm button while the input is blank.
+    pub required: bool,
+    /// Optional validator run on every change.
+    pub validate: Option<PromptValidator>,
+}
+
+impl PromptOptions {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            message: String::new(),
+            label: String::new(),
+            placeholder: String::new(),
+            default_value: String::new(),
+            confirm_label: "OK".into(),
+            cancel_label: "Cancel".into(),
+            required: false,
+            validate: None,
+        }
+    }
+
+    /// Builder: set the message.
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    /// Builder: set the input label.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Builder: set the input placeholder.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Builder: set the initial value.
+    pub fn default_value(mut self, value: impl Into<String>) -> Self {
+        self.default_value = value.into();
+        self
+    }
+
+    /// Builder: set the confirm button label.
+    pub fn confirm_label(mut self, label: impl Into<String>) -> Self {
+        self.confirm_label = label.into();
+        self
+    }
+
+    /// Builder: set the cancel button label.
+    pub fn cancel_label(mut self, label: impl Into<String>) -> Self {
+        self.cancel_label = label.into();
+        self
+    }
+
+    /// Builder: refuse a blank value.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Builder: validate the value on every change.
+    pub fn validate(mut self, validate: impl Fn(&str) -> Result<(), String> + 'static) -> Self {
+        self.validate = Some(Rc::new(validate));
+        self
+    }
+}
+
+impl PartialEq for PromptOptions {
+    fn eq(&self, other: &Self) -> bool {
+        self.title == other.title
+            && self.message == other.message
+            && self.label == other.label
+            && self.placeholder == other.placeholder
+            && self.default_value == other.default_value
+            && self.confirm_label == other.confirm_label
+            && self.cancel_label == other.cancel_label
+            && self.required == other.required
+            && match (&self.validate, &other.validate) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+enum DialogKind {
+    Confirm(ConfirmOptions),
+    Prompt(PromptOptions),
+}
+
+impl DialogKind {
+    fn has_input(&self) -> bool {
+        match self {
+            DialogKind::Confirm(o) => o.require_text.is_some(),
+            DialogKind::Prompt(_) => true,
+        }
+    }
+
+    fn initial_value(&self) -> String {
+        match self {
+            DialogKind::Confirm(_) => String::new(),
+            DialogKind::Prompt(o) => o.default_value.clone(),
+        }
+    }
+
+    /// Whether `input` may be confirmed. An empty `Err` only disables
+    /// the button; a non-empty one is shown to the user.
+    fn check(&self, input: &str) -> Result<(), String> {
+        match self {
+            DialogKind::Confirm(o) => match &o.require_text {
+                Some(expected) if input.trim() != expected.trim() => Err(String::new()),
+                _ => Ok(()),
+            },
+            DialogKind::Prompt(o) => {
+                if o.required && input.trim().is_empty() {
+                    return Err(String::new());
+                }
+                match &o.validate {
+                    Some(validate) => validate(input),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+// ── Queue ─────────────────────────────────────────────────────────
+
+#[derive(Default)]
+struct ReplyState {
+    answer: Option<Option<String>>,
+    waker: Option<Waker>,
+}
+
+/// One-shot channel from the host back to the awaiting caller.
+#[derive(Clone, Default)]
+struct Reply(Rc<RefCell<ReplyState>>);
+
+impl Reply {
+    fn send(&self, answer: Option<String>) {
+        let waker = {
+            let mut state = self.0.borrow_mut();
+            state.answer = Some(answer);
+            state.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+
+    fn recv(self) -> impl Future<Output = Option<String>> {
+        std::future::poll_fn(move |cx| {
+            let mut state = self.0.borrow_mut();
+            match state.answer.take() {
+                Some(answer) => Poll::Ready(answer),
+                None => {
+                    state.waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        })
+    }
+}
+
+struct PendingDialog {
+    id: u64,
+    kind: DialogKind,
+    reply: Reply,
+}
+
+/// Dialogs in the order they were opened; the first one is on screen.
+#[derive(Default)]
+struct DialogQueue {
+    entries: Vec<PendingDialog>,
+    next_id: u64,
+}
+
+impl DialogQueue {
+    fn push(&mut self, kind: DialogKind) -> Reply {
+        let reply = Reply::default();
+        self.next_id += 1;
+        self.entries.push(PendingDialog { id: self.next_id, kind, reply: reply.clone() });
+        reply
+    }
+
+    fn front(&self) -> Option<(u64, DialogKind)> {
+        self.entries.first().map(|e| (e.id, e.kind.clone()))
+    }
+
+    /// Answer dialog `id` and drop it from the queue.
+    fn resolve(&mut self, id: u64, answer: Option<String>) {
+        if let Some(i) = self.entries.iter().position(|e| e.id == id) {
+            self.entries.remove(i).reply.send(answer);
+        }
+    }
+}
+
+// ── Handle ────────────────────────────────────────────────────────
+
+/// Handle to the nearest [`EqDialogHost`], returned by [`use_dialogs`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct Dialogs {
+    queue: Signal<DialogQueue>,
+}
+
+impl Dialogs {
+    /// Ask a yes/no question. Resolves to `true` when the user
+    /// confirms and `false` on cancel, Escape or a backdrop click.
+    ///
+    /// The dialog opens immediately; the returned future only waits
+    /// for the answer.
+    pub fn confirm(&self, options: ConfirmOptions) -> impl Future<Output = bool> + use<> {
+        let answer = self.open(DialogKind::Confirm(options));
+        async move { answer.await.is_some() }
+    }
+
+    /// Ask for a value. Resolves to the entered text, or `None` when
+    /// the user cancels.
+    pub fn prompt(&self, options: PromptOptions) -> impl Future<Output = Option<String>> + use<> {
+        self.open(DialogKind::Prompt(options))
+    }
+
+    /// Number of dialogs open or waiting.
+    pub fn pending(&self) -> usize {
+        self.queue.read().entries.len()
+    }
+
+    fn open(&self, kind: DialogKind) -> impl Future<Output = Option<String>> + use<> {
+        let mut queue = self.queue;
+        let reply = queue.write().push(kind);
+        reply.recv()
+    }
+
+    fn resolve(&self, id: u64, answer: Option<String>) {
+        let mut queue = self.queue;
+        queue.write().resolve(id, answer);
+    }
+}
+
+/// The [`Dialogs`] of the nearest [`EqDialogHost`].
+///
+/// # Panics
+///
+/// When called outside an `EqDialogHost`.
+pub fn use_dialogs() -> Dialogs {
+    use_context::<Dialogs>()
+}
+
+// ── Component ─────────────────────────────────────────────────────
+
+/// Provides [`Dialogs`] to its children and renders the dialog at the
+/// front of its queue.
+///
+/// **Accessibility** — each dialog is an [`EqModal`]: `role="dialog"`,
+/// a focus trap and Escape to cancel. Focus starts in the input when
+/// there is one, otherwise on the confirm button; invalid input sets
+/// `aria-invalid` and its message is announced as an alert.
+#[playground(
+    category = Molecule,
+    description = "Async confirm and prompt dialogs on EqModal: use_dialogs() with \
+                   confirm / prompt, danger styling, type-to-confirm, validation and \
+                   Enter / Escape handling.",
+    examples = [
+        ("Mount", "EqDialogHost {\n    App {}\n}"),
+        ("Confirm", "let dialogs = use_dialogs();\n\nspawn(async move {\n    let sure = dialogs\n        .confirm(ConfirmOptions::new(\"Discard changes?\").danger())\n        .await;\n});"),
+        ("Type to confirm", "dialogs.confirm(\n    ConfirmOptions::new(\"Delete repository?\")\n        .confirm_label(\"Delete\")\n        .danger()\n        .require_text(\"eq_ui\"),\n)"),
+        ("Prompt", "let name = dialogs\n    .prompt(PromptOptions::new(\"Rename\").default_value(old).required())\n    .await;"),
+    ],
+    custom_demo,
+    custom_gallery,
+)]
+#[component]
+pub fn EqDialogHost(children: Element) -> Element {
+    let queue = use_signal(DialogQueue::default);
+    let dialogs = use_context_provider(|| Dialogs { queue });
+    let front = queue.read().front();
+
+    rsx! {
+        {children}
+
+        if let Some((id, kind)) = front {
+            DialogView {
+                key: "{id}",
+                id,
+                kind,
+                on_answer: move |answer| dialogs.resolve(id, answer),
+            }
+        }
+    }
+}
+
+/// One open dialog. Keyed by id so the input starts fresh each time.
+#[component]
+fn DialogView(id: u64, kind: DialogKind, on_answer: EventHandler<Option<String>>) -> Element {
+    let mut input = use_signal(|| kind.initial_value());
+    let mut touched = use_signal(|| false);
+
+    let check = kind.check(&input.read());
+    let allowed = check.is_ok();
+    let error = check.err().filter(|e| touched() && !e.is_empty());
+    let invalid = error.is_some();
+    let has_input = kind.has_input();
+    let returns_text = matches!(kind, DialogKind::Prompt(_));
+
+    let submit = move || {
+        if allowed {
+            on_answer.call(Some(if returns_text { input() } else { String::new() }));
+        }
+    };
+
+    let (title, message, confirm_label, cancel_label, danger) = match &kind {
+        DialogKind::Confirm(o) => (&o.title, &o.message, &o.confirm_label, &o.cancel_label, o.danger),
+        DialogKind::Prompt(o) => (&o.title, &o.message, &o.confirm_label, &o.cancel_label, false),
+    };
+    let placeholder = match &kind {
+        DialogKind::Confirm(o) => o.require_text.clone().unwrap_or_default(),
+        DialogKind::Prompt(o) => o.placeholder.clone(),
+    };
+    let input_id = format!("eq-dialog-{id}-input");
+    let error_id = format!("eq-dialog-{id}-error");
+
+    let label = match &kind {
+        DialogKind::Confirm(o) => o.require_text.as_ref().map(|expected| rsx! {
+            "Type "
+            span { class: s::EXPECTED, "{expected}" }
+            " to confirm"
+        }),
+        DialogKind::Prompt(o) if !o.label.is_empty() => Some(rsx! { "{o.label}" }),
+        DialogKind::Prompt(_) => None,
+    };
+    let aria_label = if label.is_none() { title.clone() } else { String::new() };
+
+    rsx! {
+        EqModal {
+            open: true,
+            on_close: move |_| on_answer.call(None),
+            title: title.clone(),
+            size: ModalSize::Sm,
+            show_close: false,
+            initial_focus: if has_input { FocusTarget::First } else { FocusTarget::Last },
+            body: rsx! {
+                div {
+                    class: s::BODY,
+                    // Buttons handle Enter themselves; only the input
+                    // needs it wired to confirm.
+                    onkeydown: move |evt: KeyboardEvent| {
+                        if has_input && evt.key() == Key::Enter {
+                            evt.prevent_default();
+                            submit();
+                        }
+                    },
+
+                    if !message.is_empty() {
+                        p { class: s::MESSAGE, "{message}" }
+                    }
+                    if has_input {
+                        div { class: s::FIELD,
+                            if let Some(label) = label {
+                                label { r#for: "{input_id}", class: s::LABEL, {label} }
+                            }
+                            input {
+                                id: "{input_id}",
+                                class: eq_input_styles::CONTROL,
+                                r#type: "text",
+                                value: "{input}",
+                                placeholder: "{placeholder}",
+                                autocomplete: "off",
+                                spellcheck: "false",
+                                "aria-label": if !aria_label.is_empty() { "{aria_label}" },
+                                "aria-invalid": if invalid { "true" },
+                                "aria-describedby": if invalid { "{error_id}" },
+                                oninput: move |evt: FormEvent| {
+                                    input.set(evt.value());
+                                    touched.set(true);
+                                },
+                            }
+                            if let Some(error) = error {
+                                p { id: "{error_id}", class: s::ERROR, role: "alert", "{error}" }
+                            }
+                        }
+                    }
+                }
+            },
+            footer: rsx! {
+                EqButton {
+                    variant: ButtonVariant::Ghost,
+                    on_click: move |_| on_answer.call(None),
+                    "{cancel_label}"
+                }
+                EqButton {
+                    variant: if danger { ButtonVariant::Danger } else { ButtonVariant::Primary },
+                    disabled: !allowed,
+                    on_click: move |_| submit(),
+                    "{confirm_label}"
+                }
+            },
+        }
+    }
+}
+
+// ── Interactive demo ───────────────────────────────────────────────
+
+#[cfg(feature = "playground")]
+#[component]
+fn DemoEqDialogHost() -> Element {
+    let code = r#"// Once, near the root
+EqDialogHost { App {} }
+
+// Anywhere below it
+let dialogs = use_dialogs();
+
+EqButton {
+    variant: ButtonVariant::Danger,
+    on_click: move |_| {
+        spawn(async move {
+            let sure = dialogs
+                .confirm(
+                    ConfirmOptions::new("Delete project?")
+                        .message("All deployments will be removed.")
+                        .confirm_label("Delete")
+                        .danger()
+                        .require_text("acme-site"),
+                )
+                .await;
+            if sure { delete_project(); }
+        });
+    },
+    "Delete project"
+}
+
+// Prompt with validation
+let name = dialogs
+    .prompt(
+        PromptOptions::new("Rename file")
+            .label("New name")
+            .default_value("notes.md")
+            .required()
+            .validate(|v| if v.contains('/') {
+                Err("Names cannot contain '/'".into())
+            } else {
+                Ok(())
+            }),
+    )
+    .await;"#
+        .to_string();
+
+    rsx! {
+        DemoSection { title: "EqDialogHost",
+            EqDialogHost {
+                DialogDemoButtons {}
+            }
+
+            StyleInfo { file: "eq_dialogs_styles.rs", styles: format_catalog(&s::catalog()) }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
+        }
+    }
+}
+
+#[cfg(feature = "playground")]
+#[component]
+fn DialogDemoButtons() -> Element {
+    let dialogs = use_dialogs();
+    let mut last = use_signal(|| "No answer yet".to_string());
+
+    rsx! {
+        div { class: "rounded-lg border border-dashed border-[var(--color-card-border)] p-6 space-y-3",
+            div { class: "flex flex-wrap gap-3",
+                EqButton {
+                    on_click: move |_| {
+                        spawn(async move {
+                            let sure = dialogs
+                                .confirm(
+                                    ConfirmOptions::new("Publish changes?")
+                                        .message("Everyone with access will see the new version."),
+                                )
+                                .await;
+                            last.set(format!("confirm → {sure}"));
+                        });
+                    },
+                    "Confirm"
+                }
+                EqButton {
+                    variant: ButtonVariant::Danger,
+                    on_click: move |_| {
+                        spawn(async move {
+                            let sure = dialogs
+                                .confirm(
+                                    ConfirmOptions::new("Delete project?")
+                                        .message("All deployments will be removed. This cannot be undone.")
+                                        .confirm_label("Delete")
+                                        .danger()
+                                        .require_text("acme-site"),
+                                )
+                                .await;
+                            last.set(format!("delete → {sure}"));
+                        });
+                    },
+                    "Type to confirm"
+                }
+                EqButton {
+                    variant: ButtonVariant::Outline,
+                    on_click: move |_| {
+                        spawn(async move {
+                            let name = dialogs
+                                .prompt(
+                                    PromptOptions::new("Rename file")
+                                        .label("New name")
+                                        .default_value("notes.md")
+                                        .required()
+                                        .validate(|v| {
+                                            if v.contains('/') {
+                                                Err("Names cannot contain '/'".into())
+                                            } else {
+                                                Ok(())
+                                            }
+                                        }),
+                                )
+                                .await;
+                            last.set(match name {
+                                Some(name) => format!("prompt → {name:?}"),
+                                None => "prompt → cancelled".into(),
+                            });
+                        });
+                    },
+                    "Prompt"
+                }
+            }
+            EqText { variant: TextVariant::Muted, "{last}" }
+        }
+    }
+}
+
+// ── Gallery ───────────────────────────────────────────────────────
+
+#[cfg(feature = "playground")]
+#[component]
+fn GalleryEqDialogHost() -> Element {
+    rsx! {
+        div { class: "space-y-4",
+            div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-4",
+                EqText { variant: TextVariant::Caption, class: "font-semibold uppercase tracking-wider", "Dialogs Gallery" }
+                EqText { variant: TextVariant::Muted, "Awaitable confirm and prompt dialogs from any component below the host" }
+                EqDialogHost {
+                    DialogDemoButtons {}
+                }
+            }
+        }
+    }
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::pin::pin;
+    use std::task::Context;
+
+    fn poll<F: Future>(future: std::pin::Pin<&mut F>) -> Poll<F::Output> {
+        future.poll(&mut Context::from_waker(Waker::noop()))
+    }
+
+    #[test]
+    fn type_to_confirm_enables_on_exact_text() {
+        let kind = DialogKind::Confirm(ConfirmOptions::new("Delete?").require_text("acme"));
+        assert!(kind.has_input());
+        assert!(kind.check("").is_err());
+        assert!(kind.check("acm").is_err());
+        assert!(kind.check("Acme").is_err());
+        assert!(kind.check(" acme ").is_ok());
+        assert!(DialogKind::Confirm(ConfirmOptions::new("Sure?")).check("").is_ok());
+    }
+
+    #[test]
+    fn prompt_required_and_validator() {
+        let kind = DialogKind::Prompt(
+            PromptOptions::new("Rename")
+                .required()
+                .validate(|v| if v.contains('/') { Err("no slashes".into()) } else { Ok(()) }),
+        );
+        // Blank only disables the button; the validator's message shows.
+        assert_eq!(kind.check("  "), Err(String::new()));
+        assert_eq!(kind.check("a/b"), Err("no slashes".into()));
+        assert!(kind.check("notes.md").is_ok());
+    }
+
+    #[test]
+    fn queue_shows_first_and_resolves_out_of_order() {
+        let mut queue = DialogQueue::default();
+        let first = queue.push(DialogKind::Confirm(ConfirmOptions::new("a")));
+        let second = queue.push(DialogKind::Prompt(PromptOptions::new("b")));
+        let (front, _) = queue.front().unwrap();
+        assert_eq!(front, 1);
+
+        let mut first = pin!(first.recv());
+        let mut second = pin!(second.recv());
+        assert!(poll(first.as_mut()).is_pending());
+
+        queue.resolve(2, Some("name".into()));
+        queue.resolve(1, None);
+        assert!(queue.front().is_none());
+        assert_eq!(poll(first.as_mut()), Poll::Ready(None));
+        assert_eq!(poll(second.as_mut()), Poll::Ready(Some("name".into())));
+    }
+
+    #[test]
+    fn smoke_renders() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! { EqDialogHost { "app" } }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn smoke_renders_open_dialog() {
+        #[component]
+        fn Asker() -> Element {
+            let dialogs = use_dialogs();
+            use_hook(move || {
+                spawn(async move {
+                    dialogs
+                        .confirm(ConfirmOptions::new("Delete?").danger().require_text("x"))
+                        .await;
+                })
+            });
+            rsx! { "app" }
+        }
+
+        let mut dom = VirtualDom::new(|| {
+            rsx! { EqDialogHost { Asker {} } }
+        });
+        dom.rebuild_in_place();
+        dom.process_events();
+        dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+    }
+}
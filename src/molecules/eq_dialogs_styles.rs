@@ -0,0 +1,33 @@
+//! Style constants for EqDialogHost.
+
+/// Body wrapper - message above the optional input.
+pub const BODY: &str = "space-y-4";
+
+/// Message text.
+pub const MESSAGE: &str = "text-sm text-[var(--color-label-secondary)]";
+
+/// Label + input + feedback stack.
+pub const FIELD: &str = "space-y-2";
+
+/// Field label.
+pub const LABEL: &str = "block text-sm text-[var(--color-label-primary)]";
+
+/// The text the user has to type to confirm.
+pub const EXPECTED: &str =
+    "font-mono font-semibold px-1 rounded \
+     bg-[var(--color-card-border)]/40 text-[var(--color-label-primary)]";
+
+/// Validation message below the input.
+pub const ERROR: &str = "text-sm text-red-400";
+
+/// All style tokens for playground introspection.
+pub fn catalog() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("BODY", BODY),
+        ("MESSAGE", MESSAGE),
+        ("FIELD", FIELD),
+        ("LABEL", LABEL),
+        ("EXPECTED", EXPECTED),
+        ("ERROR", ERROR),
+    ]
+}
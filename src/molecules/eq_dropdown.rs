@@ -1,7 +1,8 @@
 //! EqDropdown — dropdown menu molecule.
 //!
 //! A trigger button that opens a positioned menu of selectable items.
-//! Supports single selection, disabled items, separators, keyboard
+//! Supports single selection, disabled items, separators, icons,
+//! shortcut hints, checkable items, nested submenus, keyboard
 //! navigation, and close-on-Escape / close-on-outside-click.
 //!
 //! ```no_run
@@ -30,22 +31,30 @@
 //! ```
 
 use super::eq_dropdown_styles as s;
+use super::eq_menu::{MenuFocus, MenuItem, MenuList};
+use crate::floating::{use_floating, Align, FloatingOptions, MatchWidth, Placement, Side};
+use crate::overlay::EqPortal;
 use crate::theme::merge_classes;
 use crate::{PlaygroundEnum, playground};
 use dioxus::prelude::*;
 
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropSelect, StyleInfo, format_catalog,
+    DemoSection, PropSelect, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
+use super::eq_menu::toggle_item;
+#[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
 #[cfg(feature = "playground")]
 use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
 
 // ── Types ─────────────────────────────────────────────────────────
 
-/// Where the menu opens relative to the trigger.
+/// Where the menu prefers to open relative to the trigger. It flips to
+/// the other side when there is no room.
 #[derive(Clone, Copy, PartialEq, Default, PlaygroundEnum)]
 pub enum DropdownPosition {
     /// Below the trigger (default).
@@ -55,47 +64,19 @@ pub enum DropdownPosition {
     Top,
 }
 
-/// A single item (or separator) in the dropdown menu.
-#[derive(Clone, PartialEq)]
-pub struct DropdownItem {
-    /// Unique identifier returned on selection. Empty for separators.
-    pub id: String,
-    /// Display label. Empty for separators.
-    pub label: String,
-    /// Whether this item is disabled.
-    pub disabled: bool,
-    /// Whether this entry is a visual separator.
-    pub is_separator: bool,
-}
-
-impl DropdownItem {
-    /// Create a selectable item.
-    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
-        Self {
-            id: id.into(),
-            label: label.into(),
-            disabled: false,
-            is_separator: false,
+impl From<DropdownPosition> for Placement {
+    fn from(position: DropdownPosition) -> Self {
+        match position {
+            DropdownPosition::Bottom => Placement::new(Side::Bottom, Align::Start),
+            DropdownPosition::Top => Placement::new(Side::Top, Align::Start),
         }
     }
-
-    /// Create a separator line.
-    pub fn separator() -> Self {
-        Self {
-            id: String::new(),
-            label: String::new(),
-            disabled: false,
-            is_separator: true,
-        }
-    }
-
-    /// Builder: mark this item as disabled.
-    pub fn disabled(mut self) -> Self {
-        self.disabled = true;
-        self
-    }
 }
 
+/// A dropdown item - the shared [`MenuItem`] model, so dropdown items
+/// support icons, shortcut hints, checkable items and submenus.
+pub type DropdownItem = MenuItem;
+
 // ── Chevron SVG path ──────────────────────────────────────────────
 
 /// Heroicons chevron-down (mini, 20×20).
@@ -108,15 +89,17 @@ const CHEVRON_PATH: &str = "m5.23 7.21a.75.75 0 0 1 1.06.02L10 11.168l3.71-3.938
 /// Click the trigger to toggle the menu. Selecting an item fires
 /// `on_select` with the item's `id` and closes the menu.
 ///
-/// **Accessibility** — the trigger uses `aria-haspopup="listbox"` and
-/// `aria-expanded`. The menu uses `role="listbox"` with
-/// `role="option"` on each item. Arrow keys navigate items, Enter
-/// selects, and Escape closes. Items marked `disabled` receive
-/// `aria-disabled`.
+/// **Accessibility** — the trigger uses `aria-haspopup="menu"` and
+/// `aria-expanded`. The menu uses `role="menu"` with `menuitem`,
+/// `menuitemcheckbox` or `menuitemradio` on each item. Opening moves
+/// focus into the menu; arrow keys navigate items, ArrowRight opens a
+/// submenu, Enter selects, and Escape closes and returns focus to the
+/// trigger. Items marked `disabled` receive `aria-disabled`.
 #[playground(
     category = Molecule,
-    description = "Dropdown menu with selectable items, separators, disabled state, \
-                   keyboard navigation (arrows/Enter/Escape), and two positions.",
+    description = "Dropdown menu with selectable items, separators, disabled state, icons, \
+                   shortcut hints, checkable items, submenus, keyboard navigation \
+                   (arrows/Enter/Escape), and two positions.",
     examples = [
         ("Basic", "let items = vec![\n    DropdownItem::new(\"a\", \"Option A\"),\n    DropdownItem::new(\"b\", \"Option B\"),\n];\n\nEqDropdown {\n    label: \"Choose\",\n    items,\n    on_select: move |id: String| log::info!(\"Selected {id}\"),\n}"),
         ("With separators", "let items = vec![\n    DropdownItem::new(\"edit\", \"Edit\"),\n    DropdownItem::new(\"copy\", \"Copy\"),\n    DropdownItem::separator(),\n    DropdownItem::new(\"delete\", \"Delete\").disabled(),\n];\n\nEqDropdown { label: \"Actions\", items, on_select: move |id| {} }"),
@@ -145,7 +128,12 @@ pub fn EqDropdown(
     class: String,
 ) -> Element {
     let mut open = use_signal(|| false);
-    let mut focused_idx = use_signal(|| None::<usize>);
+    let mut floating = use_floating(open(), FloatingOptions {
+        placement: position.into(),
+        match_width: MatchWidth::Min,
+        ..Default::default()
+    });
+    let mut open_focus = use_signal(|| MenuFocus::First);
 
     let wrapper_cls = merge_classes(s::WRAPPER, &class);
 
@@ -157,68 +145,30 @@ pub fn EqDropdown(
     let menu_state = if open() { s::MENU_OPEN } else { s::MENU_CLOSED };
     let chevron_rot = if open() { s::CHEVRON_OPEN } else { "" };
 
-    // Collect indices of selectable (non-separator, non-disabled) items.
-    let selectable_indices: Vec<usize> = items
-        .iter()
-        .enumerate()
-        .filter(|(_, it)| !it.is_separator && !it.disabled)
-        .map(|(i, _)| i)
-        .collect();
-    let selectable_indices2 = selectable_indices.clone();
-
     rsx! {
         div {
             class: "{wrapper_cls}",
 
-            // Close on outside click — a backdrop behind everything.
-            if open() {
-                div {
-                    class: "fixed inset-0 z-40",
-                    onclick: move |_| {
-                        open.set(false);
-                        focused_idx.set(None);
-                    },
-                }
-            }
-
             // Trigger button
             button {
                 class: "{s::TRIGGER}",
+                onmounted: move |e| floating.set_anchor(e),
                 r#type: "button",
-                "aria-haspopup": "listbox",
+                "aria-haspopup": "menu",
                 "aria-expanded": "{open()}",
                 onclick: move |_| {
-                    let next = !open();
-                    open.set(next);
-                    if !next { focused_idx.set(None); }
+                    open_focus.set(MenuFocus::First);
+                    open.toggle();
                 },
                 onkeydown: move |evt: KeyboardEvent| {
-                    match evt.key() {
-                        Key::Escape => {
-                            open.set(false);
-                            focused_idx.set(None);
-                        }
-                        Key::ArrowDown => {
-                            evt.prevent_default();
-                            if !open() {
-                                open.set(true);
-                            }
-                            // Focus first selectable item.
-                            if let Some(&first) = selectable_indices.first() {
-                                focused_idx.set(Some(first));
-                            }
-                        }
-                        Key::ArrowUp => {
-                            evt.prevent_default();
-                            if !open() {
-                                open.set(true);
-                            }
-                            if let Some(&last) = selectable_indices.last() {
-                                focused_idx.set(Some(last));
-                            }
-                        }
-                        _ => {}
-                    }
+                    let focus = match evt.key() {
+                        Key::ArrowDown => MenuFocus::First,
+                        Key::ArrowUp => MenuFocus::Last,
+                        _ => return,
+                    };
+                    evt.prevent_default();
+                    open_focus.set(focus);
+                    open.set(true);
                 },
 
                 "{label}"
@@ -238,104 +188,31 @@ pub fn EqDropdown(
                 }
             }
 
-            // Menu
-            div {
-                class: "{s::MENU} {pos_cls} {menu_state}",
-                role: "listbox",
-                "aria-label": "{label}",
-                onkeydown: move |evt: KeyboardEvent| {
-                    match evt.key() {
-                        Key::Escape => {
-                            open.set(false);
-                            focused_idx.set(None);
-                        }
-                        Key::ArrowDown => {
-                            evt.prevent_default();
-                            let current = focused_idx();
-                            let next = match current {
-                                Some(cur) => {
-                                    selectable_indices2.iter()
-                                        .position(|&i| i == cur)
-                                        .map(|pos| selectable_indices2[(pos + 1) % selectable_indices2.len()])
-                                        .unwrap_or(selectable_indices2.first().copied().unwrap_or(0))
-                                }
-                                None => selectable_indices2.first().copied().unwrap_or(0),
-                            };
-                            focused_idx.set(Some(next));
-                        }
-                        Key::ArrowUp => {
-                            evt.prevent_default();
-                            let current = focused_idx();
-                            let next = match current {
-                                Some(cur) => {
-                                    selectable_indices2.iter()
-                                        .position(|&i| i == cur)
-                                        .map(|pos| {
-                                            if pos == 0 {
-                                                selectable_indices2[selectable_indices2.len() - 1]
-                                            } else {
-                                                selectable_indices2[pos - 1]
-                                            }
-                                        })
-                                        .unwrap_or(selectable_indices2.last().copied().unwrap_or(0))
-                                }
-                                None => selectable_indices2.last().copied().unwrap_or(0),
-                            };
-                            focused_idx.set(Some(next));
-                        }
-                        Key::Enter => {
-                            let Some(idx) = focused_idx() else { return; };
-                            let Some(item) = items.get(idx) else { return; };
-                            if item.disabled || item.is_separator { return; }
-                            if let Some(handler) = &on_select {
-                                handler.call(item.id.clone());
-                            }
-                            open.set(false);
-                            focused_idx.set(None);
-                        }
-                        _ => {}
-                    }
-                },
-
-                for (idx , item) in items.iter().enumerate() {
-                    if item.is_separator {
-                        div {
-                            key: "sep-{idx}",
-                            class: "{s::SEPARATOR}",
-                            role: "separator",
-                        }
-                    } else {
-                        {
-                            let is_active = selected.as_ref() == Some(&item.id);
-                            let is_focused = focused_idx() == Some(idx);
-                            let active_cls = if is_active { s::ITEM_ACTIVE } else { "" };
-                            let disabled_cls = if item.disabled { s::ITEM_DISABLED } else { "" };
-                            let focus_cls = if is_focused { "bg-[var(--color-accent-primary)]/10" } else { "" };
-                            let item_id = item.id.clone();
-
-                            rsx! {
-                                button {
-                                    key: "{item_id}",
-                                    class: "{s::ITEM} {active_cls} {disabled_cls} {focus_cls}",
-                                    r#type: "button",
-                                    role: "option",
-                                    "aria-selected": "{is_active}",
-                                    "aria-disabled": if item.disabled { "true" } else { "false" },
-                                    tabindex: "-1",
-                                    disabled: item.disabled,
-                                    onclick: {
-                                        let id = item_id.clone();
-                                        move |_| {
-                                            if let Some(handler) = &on_select {
-                                                handler.call(id.clone());
-                                            }
-                                            open.set(false);
-                                            focused_idx.set(None);
-                                        }
-                                    },
-                                    "{item.label}"
+            // Menu — portaled so it stacks above modals and drawers;
+            // Escape and outside clicks close it while it is topmost.
+            // The list mounts on open so focus moves into it.
+            EqPortal {
+                active: open(),
+                restore_focus: true,
+                on_escape: move |_| open.set(false),
+                on_outside_click: move |_| open.set(false),
+                div {
+                    class: "{s::MENU} {pos_cls} {menu_state}",
+                    style: floating.style(),
+                    onmounted: move |e| floating.set_floating(e),
+                    if open() {
+                        MenuList {
+                            items: items.clone(),
+                            selected: selected.clone(),
+                            initial_focus: open_focus(),
+                            label: label.clone(),
+                            on_select: move |id: String| {
+                                if let Some(handler) = &on_select {
+                                    handler.call(id);
                                 }
-                            }
+                                open.set(false);
+                            },
+                            on_close: move |_| open.set(false),
                         }
                     }
                 }
@@ -358,11 +235,14 @@ fn DemoEqDropdown() -> Element {
     };
 
     let items = vec![
-        DropdownItem::new("edit", "Edit"),
-        DropdownItem::new("duplicate", "Duplicate"),
-        DropdownItem::new("rename", "Rename"),
+        DropdownItem::new("edit", "Edit").shortcut("E"),
+        DropdownItem::new("duplicate", "Duplicate").shortcut("Ctrl+D"),
+        DropdownItem::new("rename", "Rename").shortcut("F2"),
         DropdownItem::separator(),
-        DropdownItem::new("archive", "Archive"),
+        DropdownItem::new("move", "Move to").submenu(vec![
+            DropdownItem::new("inbox", "Inbox"),
+            DropdownItem::new("archive", "Archive"),
+        ]),
         DropdownItem::new("delete", "Delete").disabled(),
     ];
 
@@ -371,11 +251,14 @@ fn DemoEqDropdown() -> Element {
         .unwrap_or_else(|| "(none)".to_string());
 
     let code = r#"let items = vec![
-    DropdownItem::new("edit", "Edit"),
-    DropdownItem::new("duplicate", "Duplicate"),
-    DropdownItem::new("rename", "Rename"),
+    DropdownItem::new("edit", "Edit").shortcut("E"),
+    DropdownItem::new("duplicate", "Duplicate").shortcut("Ctrl+D"),
+    DropdownItem::new("rename", "Rename").shortcut("F2"),
     DropdownItem::separator(),
-    DropdownItem::new("archive", "Archive"),
+    DropdownItem::new("move", "Move to").submenu(vec![
+        DropdownItem::new("inbox", "Inbox"),
+        DropdownItem::new("archive", "Archive"),
+    ]),
     DropdownItem::new("delete", "Delete").disabled(),
 ];
 
@@ -419,7 +302,8 @@ EqDropdown {
             }
 
             StyleInfo { file: "eq_dropdown_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            StyleInfo { file: "eq_menu_styles.rs", styles: format_catalog(&super::eq_menu_styles::catalog()) }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
@@ -431,6 +315,13 @@ EqDropdown {
 fn GalleryEqDropdown() -> Element {
     let mut sort_sel = use_signal(|| Some("name".to_string()));
     let mut action_sel = use_signal(|| None::<String>);
+    let mut view_items = use_signal(|| vec![
+        DropdownItem::new("sidebar", "Sidebar").checkbox(true),
+        DropdownItem::new("minimap", "Minimap").checkbox(false),
+        DropdownItem::separator(),
+        DropdownItem::new("compact", "Compact").radio("density", false),
+        DropdownItem::new("comfortable", "Comfortable").radio("density", true),
+    ]);
 
     let sort_items = vec![
         DropdownItem::new("name", "Name"),
@@ -464,6 +355,13 @@ fn GalleryEqDropdown() -> Element {
                         selected: action_sel(),
                         on_select: move |id: String| action_sel.set(Some(id)),
                     }
+                    EqDropdown {
+                        label: "View",
+                        items: view_items(),
+                        on_select: move |id: String| {
+                            toggle_item(&mut view_items.write(), &id);
+                        },
+                    }
                 }
             }
         }
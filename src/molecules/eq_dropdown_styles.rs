@@ -1,5 +1,7 @@
 //! Style constants for EqDropdown.
 
+use crate::floating::{FALLBACK_MENU_BOTTOM, FALLBACK_MENU_TOP};
+
 /// Wrapper — relative anchor for the menu.
 pub const WRAPPER: &str = "relative inline-flex";
 
@@ -25,7 +27,7 @@ pub const MENU: &str =
     "absolute z-50 mt-1 min-w-full rounded-md py-1 \
      bg-[var(--color-card)] \
      border border-[var(--color-card-border)] \
-     shadow-2xl shadow-black/40 overflow-hidden";
+     shadow-2xl shadow-black/40";
 
 /// Menu open.
 pub const MENU_OPEN: &str = "visible";
@@ -33,29 +35,11 @@ pub const MENU_OPEN: &str = "visible";
 pub const MENU_CLOSED: &str = "invisible pointer-events-none";
 
 /// Position: below trigger (default).
-pub const POS_BOTTOM: &str = "top-full left-0";
+pub const POS_BOTTOM: &str = FALLBACK_MENU_BOTTOM;
 /// Position: above trigger.
-pub const POS_TOP: &str = "bottom-full left-0 mb-1 mt-0";
-
-/// A single dropdown item.
-pub const ITEM: &str =
-    "w-full px-3 py-2 text-left text-sm cursor-pointer \
-     text-[var(--color-label-primary)] \
-     hover:bg-[var(--color-accent-primary)]/10 \
-     focus:bg-[var(--color-accent-primary)]/10 \
-     focus:outline-none transition-colors duration-100";
-
-/// Active / selected item.
-pub const ITEM_ACTIVE: &str =
-    "bg-[var(--color-accent-primary)]/10 font-medium";
-
-/// Disabled item.
-pub const ITEM_DISABLED: &str =
-    "opacity-40 cursor-not-allowed pointer-events-none";
+pub const POS_TOP: &str = FALLBACK_MENU_TOP;
 
-/// Separator between item groups.
-pub const SEPARATOR: &str =
-    "my-1 border-t border-[var(--color-card-border)]";
+// Item styles live in `eq_menu_styles`.
 
 /// All style tokens for playground introspection.
 pub fn catalog() -> Vec<(&'static str, &'static str)> {
@@ -69,9 +53,5 @@ pub fn catalog() -> Vec<(&'static str, &'static str)> {
         ("MENU_CLOSED", MENU_CLOSED),
         ("POS_BOTTOM", POS_BOTTOM),
         ("POS_TOP", POS_TOP),
-        ("ITEM", ITEM),
-        ("ITEM_ACTIVE", ITEM_ACTIVE),
-        ("ITEM_DISABLED", ITEM_DISABLED),
-        ("SEPARATOR", SEPARATOR),
     ]
 }
@@ -0,0 +1,541 @@
+//! EqHoverCard — rich preview card opened by hovering a trigger.
+//!
+//! Opens after `open_delay` when the pointer rests on (or keyboard
+//! focus reaches) the trigger, and closes `close_delay` after both the
+//! pointer and focus have left. The card can hold links and buttons:
+//! while the pointer travels from the trigger towards the card it
+//! stays inside a *grace area* — the convex hull of the exit point and
+//! the card — and the card stays open, so diagonal moves across other
+//! content do not dismiss it. Placement uses the shared
+//! [`floating`](crate::floating) engine.
+//!
+//! ```no_run
+//! use eq_ui::prelude::*;
+//! use eq_ui::molecules::EqHoverCard;
+//!
+//! let _: Element = rsx! {
+//!     EqHoverCard {
+//!         trigger: rsx! { a { href: "/users/ada", "@ada" } },
+//!         div { class: "space-y-1",
+//!             strong { "Ada Lovelace" }
+//!             p { "Analyst. Writes notes longer than the paper." }
+//!             a { href: "/users/ada/follow", "Follow" }
+//!         }
+//!     }
+//! };
+//! ```
+
+use super::eq_hover_card_styles as s;
+use super::eq_popover::{use_open_state, OpenState, PopoverPosition};
+use crate::floating::{use_floating, FloatingOptions, Rect};
+use crate::overlay::EqPortal;
+use crate::playground;
+use crate::theme::merge_classes;
+use crate::timer::sleep;
+use dioxus::core::Task;
+use dioxus::document;
+use dioxus::prelude::*;
+
+#[cfg(feature = "playground")]
+use crate::playground::playground_helpers::{
+    DemoSection, PropInput, PropSelect, PropToggle, StyleInfo, format_catalog,
+};
+#[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
+use crate::atoms::{EqAvatar, AvatarSize, EqButton, ButtonVariant, EqLink, EqText, TextVariant};
+#[cfg(feature = "playground")]
+use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
+
+// ── Grace area ────────────────────────────────────────────────────
+
+type Point = (f64, f64);
+
+/// Half-size of the square around the exit point, so a pointer that
+/// wobbles back over the trigger edge stays inside.
+const EXIT_PAD: f64 = 5.0;
+
+fn cross(o: Point, a: Point, b: Point) -> f64 {
+    (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+}
+
+/// Convex hull (monotone chain), in order around the boundary.
+fn convex_hull(mut points: Vec<Point>) -> Vec<Point> {
+    points.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+    let mut hull: Vec<Point> = Vec::with_capacity(points.len() * 2);
+    for pass in [points.clone(), points.into_iter().rev().collect()] {
+        let start = hull.len();
+        for p in pass {
+            while hull.len() >= start + 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        hull.pop();
+    }
+    hull
+}
+
+/// Area the pointer may cross between leaving the trigger at `exit`
+/// and reaching `card` without the card closing.
+fn grace_area(exit: Point, card: Rect) -> Vec<Point> {
+    let (x, y) = exit;
+    let right = card.x + card.width;
+    let bottom = card.y + card.height;
+    convex_hull(vec![
+        (x - EXIT_PAD, y - EXIT_PAD),
+        (x + EXIT_PAD, y - EXIT_PAD),
+        (x + EXIT_PAD, y + EXIT_PAD),
+        (x - EXIT_PAD, y + EXIT_PAD),
+        (card.x, card.y),
+        (right, card.y),
+        (right, bottom),
+        (card.x, bottom),
+    ])
+}
+
+/// Whether `p` lies inside (or on the edge of) a convex polygon.
+fn polygon_contains(polygon: &[Point], p: Point) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+    let edges = polygon.iter().zip(polygon.iter().cycle().skip(1));
+    let sides: Vec<f64> = edges.map(|(&a, &b)| cross(a, b, p)).collect();
+    sides.iter().all(|&c| c >= 0.0) || sides.iter().all(|&c| c <= 0.0)
+}
+
+/// Streams pointer positions, at most once per frame, until told to
+/// stop.
+const POINTER_JS: &str = r#"
+let queued = null;
+const onMove = (e) => {
+    const first = queued === null;
+    queued = [e.clientX, e.clientY];
+    if (first) requestAnimationFrame(() => { dioxus.send(queued); queued = null; });
+};
+document.addEventListener('pointermove', onMove);
+await dioxus.recv();
+document.removeEventListener('pointermove', onMove);
+"#;
+
+// ── Timers ────────────────────────────────────────────────────────
+
+/// Delayed open / close plus grace-area tracking.
+#[derive(Clone, Copy)]
+struct Hover {
+    state: OpenState,
+    pending: Signal<Option<Task>>,
+    tracker: Signal<Option<(document::Eval, Task)>>,
+}
+
+impl Hover {
+    fn cancel(&mut self) {
+        if let Ok(mut pending) = self.pending.try_write()
+            && let Some(task) = pending.take()
+        {
+            task.cancel();
+        }
+    }
+
+    /// Open or close after `delay` ms, replacing any pending change.
+    fn schedule(&mut self, open: bool, delay: u64) {
+        self.cancel();
+        if delay == 0 {
+            self.apply(open);
+            return;
+        }
+        let mut this = *self;
+        let task = spawn(async move {
+            sleep(delay).await;
+            this.pending.set(None);
+            this.apply(open);
+        });
+        self.pending.set(Some(task));
+    }
+
+    fn apply(&mut self, open: bool) {
+        if !open {
+            self.stop_tracking();
+        }
+        self.state.set(open);
+    }
+
+    /// Keep the card open while the pointer moves inside `area`; once
+    /// it leaves, the close already scheduled runs out.
+    fn track(&mut self, area: Vec<Point>, close_delay: u64) {
+        self.stop_tracking();
+        let mut eval = document::eval(POINTER_JS);
+        let mut this = *self;
+        let task = spawn(async move {
+            while let Ok((x, y)) = eval.recv::<(f64, f64)>().await {
+                if !polygon_contains(&area, (x, y)) {
+                    break;
+                }
+                this.schedule(false, close_delay);
+            }
+            this.stop_tracking();
+        });
+        self.tracker.set(Some((eval, task)));
+    }
+
+    fn stop_tracking(&mut self) {
+        if let Ok(mut tracker) = self.tracker.try_write()
+            && let Some((eval, task)) = tracker.take()
+        {
+            let _ = eval.send(true);
+            task.cancel();
+        }
+    }
+}
+
+// ── Component ─────────────────────────────────────────────────────
+
+/// Hover card with interactive content.
+///
+/// `trigger` is usually a link or an avatar. Hovering it for
+/// `open_delay` ms — or focusing it from the keyboard — opens the card;
+/// leaving both the trigger and the card for `close_delay` ms closes
+/// it, and Escape closes it at once. Open state is owned by the card
+/// unless `open` is given, in which case `on_open_change` reports what
+/// the card wants.
+///
+/// **Accessibility** — hover cards preview content that is also
+/// reachable elsewhere (the trigger's link target), so the card is not
+/// announced as a dialog. Keyboard users open it by focusing the
+/// trigger and can Tab into it; the layer is passive, so it never
+/// takes Escape or outside clicks from modals underneath.
+#[playground(
+    category = Molecule,
+    description = "Hover-triggered preview card with open/close delays, a grace area for the \
+                   pointer path into the card, interactive content, keyboard focus support, \
+                   and controlled or uncontrolled open state.",
+    examples = [
+        ("Basic", "EqHoverCard {\n    trigger: rsx! { EqLink { href: \"/users/ada\", \"@ada\" } },\n    EqText { \"Ada Lovelace\" }\n    EqButton { on_click: move |_| follow(), \"Follow\" }\n}"),
+        ("Delays", "EqHoverCard {\n    trigger: rsx! { \"Hover me\" },\n    open_delay: 200,\n    close_delay: 150,\n    position: PopoverPosition::Right,\n    \"Quick preview\"\n}"),
+        ("Controlled", "let mut open = use_signal(|| false);\n\nEqHoverCard {\n    trigger: rsx! { \"Details\" },\n    open: open(),\n    on_open_change: move |v: bool| open.set(v),\n    \"Card\"\n}"),
+    ],
+    custom_demo,
+    custom_gallery,
+)]
+#[component]
+pub fn EqHoverCard(
+    /// The element that opens the card on hover or focus.
+    trigger: Element,
+    /// Controlled open state. Leave unset to let the card manage it.
+    #[props(into, default)]
+    open: Option<bool>,
+    /// Initial open state when uncontrolled.
+    #[props(default = false)]
+    default_open: bool,
+    /// Fired when the card asks to open or close.
+    #[props(default)]
+    on_open_change: Option<EventHandler<bool>>,
+    /// Milliseconds the pointer must rest on the trigger before opening.
+    #[props(default = 500)]
+    open_delay: u64,
+    /// Milliseconds after leaving before closing.
+    #[props(default = 300)]
+    close_delay: u64,
+    /// Preferred side relative to the trigger.
+    #[props(default)]
+    position: PopoverPosition,
+    /// Show an arrow pointing at the trigger.
+    #[props(default = false)]
+    arrow: bool,
+    /// Optional class override on the card.
+    #[props(into, default)]
+    class: String,
+    /// Card content.
+    children: Element,
+) -> Element {
+    let state = use_open_state(open, default_open, on_open_change);
+    let is_open = state.get();
+    let mut floating = use_floating(is_open, FloatingOptions {
+        placement: position.into(),
+        offset: 8.0,
+        arrow_padding: arrow.then_some(10.0),
+        ..Default::default()
+    });
+    let mut hover = Hover { state, pending: use_signal(|| None), tracker: use_signal(|| None) };
+    let mut card = use_signal(|| None::<MountedEvent>);
+
+    use_drop(move || {
+        let mut hover = hover;
+        hover.cancel();
+        hover.stop_tracking();
+    });
+
+    let pos_cls = match position {
+        PopoverPosition::Top => s::POS_TOP,
+        PopoverPosition::Bottom => s::POS_BOTTOM,
+        PopoverPosition::Left => s::POS_LEFT,
+        PopoverPosition::Right => s::POS_RIGHT,
+    };
+    let card_state = if is_open { s::CARD_OPEN } else { s::CARD_CLOSED };
+    let card_cls = merge_classes(&format!("{} {pos_cls} {card_state}", s::CARD), &class);
+
+    let on_escape = move |evt: KeyboardEvent| {
+        if evt.key() == Key::Escape && hover.state.get() {
+            evt.stop_propagation();
+            hover.schedule(false, 0);
+        }
+    };
+
+    rsx! {
+        span {
+            class: s::TRIGGER,
+            onmounted: move |e| floating.set_anchor(e),
+            onmouseenter: move |_| {
+                hover.stop_tracking();
+                hover.schedule(true, if hover.state.get() { 0 } else { open_delay });
+            },
+            onmouseleave: move |evt: MouseEvent| {
+                hover.schedule(false, close_delay);
+                let Some(card) = card.peek().clone() else { return };
+                if !hover.state.get() {
+                    return;
+                }
+                let exit = evt.client_coordinates();
+                spawn(async move {
+                    if let Ok(r) = card.get_client_rect().await {
+                        let rect = Rect::new(r.origin.x, r.origin.y, r.width(), r.height());
+                        hover.track(grace_area((exit.x, exit.y), rect), close_delay);
+                    }
+                });
+            },
+            onfocusin: move |_| hover.schedule(true, open_delay),
+            onfocusout: move |_| hover.schedule(false, close_delay),
+            onkeydown: on_escape,
+
+            {trigger}
+        }
+
+        EqPortal { active: is_open, passive: true,
+            div {
+                class: "{card_cls}",
+                style: floating.style(),
+                onmounted: move |e| {
+                    card.set(Some(e.clone()));
+                    floating.set_floating(e);
+                },
+                onmouseenter: move |_| {
+                    hover.stop_tracking();
+                    hover.cancel();
+                },
+                onmouseleave: move |_| hover.schedule(false, close_delay),
+                onfocusin: move |_| hover.cancel(),
+                onfocusout: move |_| hover.schedule(false, close_delay),
+                onkeydown: on_escape,
+
+                {children}
+                if arrow {
+                    span { class: s::ARROW, style: floating.arrow_style(), "aria-hidden": "true" }
+                }
+            }
+        }
+    }
+}
+
+// ── Interactive demo ───────────────────────────────────────────────
+
+#[cfg(feature = "playground")]
+#[component]
+fn ProfilePreview(name: String, handle: String) -> Element {
+    let mut following = use_signal(|| false);
+    rsx! {
+        div { class: "space-y-3",
+            div { class: "flex items-center gap-3",
+                EqAvatar { name: name.clone(), size: AvatarSize::Md }
+                div {
+                    EqText { class: "font-semibold", "{name}" }
+                    EqText { variant: TextVariant::Muted, "@{handle}" }
+                }
+            }
+            EqText { variant: TextVariant::Muted, "Works on the rendering pipeline. Writes long commit messages." }
+            div { class: "flex items-center gap-2",
+                EqButton {
+                    variant: if following() { ButtonVariant::Outline } else { ButtonVariant::Primary },
+                    on_click: move |_| following.toggle(),
+                    if following() { "Following" } else { "Follow" }
+                }
+                EqLink { href: "#", "Profile" }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "playground")]
+#[component]
+fn DemoEqHoverCard() -> Element {
+    let mut position_str = use_signal(|| "Bottom".to_string());
+    let mut arrow = use_signal(|| true);
+    let mut open_delay = use_signal(|| "500".to_string());
+    let mut close_delay = use_signal(|| "300".to_string());
+    let mut opened = use_signal(|| false);
+
+    let position = match position_str().as_str() {
+        "Top" => PopoverPosition::Top,
+        "Left" => PopoverPosition::Left,
+        "Right" => PopoverPosition::Right,
+        _ => PopoverPosition::Bottom,
+    };
+    let open_ms = open_delay().parse().unwrap_or(500);
+    let close_ms = close_delay().parse().unwrap_or(300);
+
+    let code = format!(
+        r#"EqHoverCard {{
+    trigger: rsx! {{ EqLink {{ href: "/users/ada", "@ada" }} }},
+    open_delay: {open_ms},
+    close_delay: {close_ms},
+    position: PopoverPosition::{pos},
+    arrow: {arrow},
+    ProfilePreview {{ name: "Ada Lovelace", handle: "ada" }}
+}}"#,
+        pos = position_str(),
+        arrow = arrow(),
+    );
+
+    rsx! {
+        DemoSection { title: "EqHoverCard",
+            div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-3",
+                EqText {
+                    variant: TextVariant::Caption,
+                    class: "font-semibold uppercase tracking-wider",
+                    "Props"
+                }
+                PropSelect {
+                    label: "position",
+                    value: position_str(),
+                    options: vec!["Top", "Bottom", "Left", "Right"],
+                    onchange: move |v: String| position_str.set(v),
+                }
+                PropInput {
+                    label: "open_delay",
+                    value: open_delay(),
+                    placeholder: "500",
+                    onchange: move |v: String| open_delay.set(v),
+                }
+                PropInput {
+                    label: "close_delay",
+                    value: close_delay(),
+                    placeholder: "300",
+                    onchange: move |v: String| close_delay.set(v),
+                }
+                PropToggle { label: "arrow", value: arrow(), onchange: move |v: bool| arrow.set(v) }
+            }
+
+            div { class: "rounded-lg border border-dashed border-[var(--color-card-border)] p-6 space-y-3",
+                EqText {
+                    "Reviewed by "
+                    EqHoverCard {
+                        trigger: rsx! { EqLink { href: "#", "@ada" } },
+                        open_delay: open_ms,
+                        close_delay: close_ms,
+                        position,
+                        arrow: arrow(),
+                        on_open_change: move |v: bool| opened.set(v),
+                        ProfilePreview { name: "Ada Lovelace", handle: "ada" }
+                    }
+                    " - move the pointer diagonally into the card; it stays open."
+                }
+                EqText { variant: TextVariant::Muted, "Card open: {opened}" }
+            }
+
+            StyleInfo { file: "eq_hover_card_styles.rs", styles: format_catalog(&s::catalog()) }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
+        }
+    }
+}
+
+// ── Gallery ───────────────────────────────────────────────────────
+
+#[cfg(feature = "playground")]
+#[component]
+fn GalleryEqHoverCard() -> Element {
+    rsx! {
+        div { class: "space-y-4",
+            div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-4",
+                EqText { variant: TextVariant::Caption, class: "font-semibold uppercase tracking-wider", "Hover Card Gallery" }
+
+                div { class: "flex items-center justify-center gap-8 py-6",
+                    EqHoverCard {
+                        trigger: rsx! { EqAvatar { name: "Grace Hopper", size: AvatarSize::Md } },
+                        ProfilePreview { name: "Grace Hopper", handle: "grace" }
+                    }
+                    EqHoverCard {
+                        trigger: rsx! { EqLink { href: "#", "eq_ui v0.5" } },
+                        position: PopoverPosition::Right,
+                        arrow: true,
+                        open_delay: 200,
+                        EqText { variant: TextVariant::Muted, "Dioxus component library with 25 themes." }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── Smoke tests ─────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smoke_renders() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                EqHoverCard {
+                    trigger: rsx! { a { href: "#", "@ada" } },
+                    "Preview"
+                }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn smoke_renders_open_in_overlay_root() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                crate::overlay::EqOverlayRoot {
+                    EqHoverCard {
+                        trigger: rsx! { "Hover" },
+                        default_open: true,
+                        arrow: true,
+                        button { "Follow" }
+                    }
+                }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn hull_drops_interior_points() {
+        let hull = convex_hull(vec![(0.0, 0.0), (4.0, 0.0), (2.0, 1.0), (4.0, 4.0), (0.0, 4.0), (0.0, 0.0)]);
+        assert_eq!(hull.len(), 4);
+        assert!(!hull.contains(&(2.0, 1.0)));
+    }
+
+    #[test]
+    fn grace_area_covers_the_path_to_the_card() {
+        // Left the bottom of a trigger at (100, 50); card below and to the right.
+        let area = grace_area((100.0, 50.0), Rect::new(120.0, 60.0, 200.0, 100.0));
+        assert!(polygon_contains(&area, (100.0, 50.0)));
+        assert!(polygon_contains(&area, (115.0, 56.0)));
+        assert!(polygon_contains(&area, (200.0, 100.0)));
+        // Heading away from the card.
+        assert!(!polygon_contains(&area, (60.0, 56.0)));
+        assert!(!polygon_contains(&area, (100.0, 150.0)));
+    }
+
+    #[test]
+    fn degenerate_polygons_contain_nothing() {
+        assert!(!polygon_contains(&[(0.0, 0.0), (1.0, 1.0)], (0.5, 0.5)));
+    }
+}
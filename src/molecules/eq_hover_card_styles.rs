@@ -0,0 +1,48 @@
+//! Style constants for EqHoverCard.
+
+use crate::floating::{
+    FALLBACK_PANEL_BOTTOM, FALLBACK_PANEL_LEFT, FALLBACK_PANEL_RIGHT, FALLBACK_PANEL_TOP,
+};
+
+/// Wrapper around the trigger. Relative positioning anchor.
+pub const TRIGGER: &str = "relative inline-flex";
+
+/// The card.
+pub const CARD: &str =
+    "absolute z-50 w-72 rounded-lg p-4 text-sm \
+     bg-[var(--color-card)] text-[var(--color-label-primary)] \
+     border border-[var(--color-card-border)] \
+     shadow-2xl shadow-black/40 \
+     transition-opacity duration-150";
+
+/// Card open.
+pub const CARD_OPEN: &str = "opacity-100 visible";
+/// Card closed.
+pub const CARD_CLOSED: &str = "opacity-0 invisible pointer-events-none";
+
+/// Position: above the trigger.
+pub const POS_TOP: &str = FALLBACK_PANEL_TOP;
+/// Position: below the trigger (default).
+pub const POS_BOTTOM: &str = FALLBACK_PANEL_BOTTOM;
+/// Position: left of the trigger.
+pub const POS_LEFT: &str = FALLBACK_PANEL_LEFT;
+/// Position: right of the trigger.
+pub const POS_RIGHT: &str = FALLBACK_PANEL_RIGHT;
+
+/// Arrow - a small rotated square placed by the floating engine.
+pub const ARROW: &str = "w-2.5 h-2.5 rotate-45 bg-[var(--color-card)]";
+
+/// All style tokens for playground introspection.
+pub fn catalog() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("TRIGGER", TRIGGER),
+        ("CARD", CARD),
+        ("CARD_OPEN", CARD_OPEN),
+        ("CARD_CLOSED", CARD_CLOSED),
+        ("POS_TOP", POS_TOP),
+        ("POS_BOTTOM", POS_BOTTOM),
+        ("POS_LEFT", POS_LEFT),
+        ("POS_RIGHT", POS_RIGHT),
+        ("ARROW", ARROW),
+    ]
+}
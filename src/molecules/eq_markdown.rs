@@ -0,0 +1,334 @@
+//! EqMarkdown — Markdown renderer molecule.
+//!
+//! Parses CommonMark with the GFM extensions (tables, task lists,
+//! strikethrough, bare-URL autolinks) and renders the result with
+//! eq_ui atoms: headings and paragraphs through `EqText`, links through
+//! `EqLink`, thematic breaks through `EqDivider` and task markers
+//! through `EqCheckbox`, so the output follows the active theme.
+//! Tables use the `EqGrid` styles and fenced code renders through
+//! `EqCodeBlock` with the fence's language. Raw HTML is sanitized
+//! before it reaches the page.
+//!
+//! ```no_run
+//! use eq_ui::prelude::*;
+//! use eq_ui::molecules::EqMarkdown;
+//!
+//! let _: Element = rsx! {
+//!     EqMarkdown { source: "# Hello\n\nSome **bold** text." }
+//! };
+//! ```
+
+use super::eq_markdown_parser::{MdAlign, MdBlock, MdInline, MdList, MdTable, parse_markdown};
+use super::eq_markdown_styles as s;
+use crate::atoms::{
+    CheckboxState, CodeLanguage, EqCheckbox, EqCodeBlock, EqDivider, EqLink, EqText, TextVariant,
+};
+use crate::organisms::eq_grid::styles as grid;
+use crate::theme::merge_classes;
+use crate::playground;
+use dioxus::prelude::*;
+
+#[cfg(feature = "playground")]
+use crate::playground::playground_helpers::{DemoSection, StyleInfo, format_catalog};
+#[cfg(feature = "playground")]
+use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
+
+/// Markdown renderer molecule.
+///
+/// Re-parses `source` on every render; the parser is linear and
+/// allocation-light, so this is fine for documents of typical size.
+#[playground(
+    category = Molecule,
+    description = "Renders CommonMark + GFM (tables, task lists, strikethrough) \
+                   through eq_ui atoms. Raw HTML is sanitized.",
+    examples = [
+        ("Basic", "EqMarkdown { source: \"# Title\\n\\nSome *emphasis* and a [link](https://dioxuslabs.com).\" }"),
+        ("Task list", "EqMarkdown { source: \"- [x] Parse\\n- [ ] Render\" }"),
+    ],
+    custom_demo,
+    custom_gallery,
+)]
+#[component]
+pub fn EqMarkdown(
+    /// Markdown source text.
+    #[props(into)]
+    source: String,
+    /// Optional class override - extend or replace default wrapper styles.
+    #[props(into, default)]
+    class: String,
+) -> Element {
+    let blocks = parse_markdown(&source);
+    let cls = merge_classes(s::MARKDOWN, &class);
+
+    rsx! {
+        div { class: "{cls}",
+            for block in blocks.iter() {
+                {render_block(block, false)}
+            }
+        }
+    }
+}
+
+// ── Block rendering ─────────────────────────────────────────────────
+
+/// Render one block. `tight` is set for blocks directly inside a tight
+/// list item, whose paragraphs drop their spacing.
+fn render_block(block: &MdBlock, tight: bool) -> Element {
+    match block {
+        MdBlock::Heading { level, content } => {
+            let (variant, class) = match level {
+                1 => (TextVariant::H1, ""),
+                2 => (TextVariant::H2, ""),
+                3 => (TextVariant::H3, ""),
+                _ => (TextVariant::H3, s::HEADING_MINOR),
+            };
+            rsx! { EqText { variant, class, {render_inlines(content)} } }
+        }
+        MdBlock::Paragraph(content) => {
+            let class = if tight { s::TIGHT_PARAGRAPH } else { "" };
+            rsx! { EqText { variant: TextVariant::Body, class, {render_inlines(content)} } }
+        }
+        MdBlock::Quote(blocks) => rsx! {
+            blockquote { class: s::QUOTE,
+                for b in blocks.iter() {
+                    {render_block(b, false)}
+                }
+            }
+        },
+        MdBlock::List(list) => render_list(list),
+        MdBlock::CodeBlock { lang, code } => render_code(lang.as_deref(), code),
+        MdBlock::Table(table) => render_table(table),
+        MdBlock::ThematicBreak => rsx! { EqDivider {} },
+        MdBlock::Html(html) => rsx! {
+            div { class: s::HTML_BLOCK, dangerous_inner_html: "{html}" }
+        },
+    }
+}
+
+fn render_list(list: &MdList) -> Element {
+    let is_task = list.items.iter().any(|i| i.checked.is_some());
+    let cls = match (is_task, list.ordered) {
+        (true, _) => s::LIST_TASK,
+        (false, true) => s::LIST_ORDERED,
+        (false, false) => s::LIST_BULLET,
+    };
+    let items = rsx! {
+        for item in list.items.iter() {
+            if let Some(checked) = item.checked {
+                li { class: s::TASK_ITEM,
+                    EqCheckbox {
+                        state: if checked { CheckboxState::Checked } else { CheckboxState::Unchecked },
+                        disabled: true,
+                        class: s::TASK_CHECKBOX,
+                    }
+                    div { class: s::LIST_ITEM,
+                        for b in item.blocks.iter() {
+                            {render_block(b, list.tight)}
+                        }
+                    }
+                }
+            } else {
+                li { class: s::LIST_ITEM,
+                    for b in item.blocks.iter() {
+                        {render_block(b, list.tight)}
+                    }
+                }
+            }
+        }
+    };
+    if list.ordered {
+        rsx! { ol { class: cls, start: "{list.start}", {items} } }
+    } else {
+        rsx! { ul { class: cls, {items} } }
+    }
+}
+
+fn render_code(lang: Option<&str>, code: &str) -> Element {
+    let language = lang.map(CodeLanguage::from_hint).unwrap_or_default();
+    rsx! {
+        EqCodeBlock {
+            code: code.to_string(),
+            language,
+            title: lang.unwrap_or_default().to_string(),
+        }
+    }
+}
+
+fn align_class(align: MdAlign) -> &'static str {
+    match align {
+        MdAlign::None | MdAlign::Left => grid::ALIGN_LEFT,
+        MdAlign::Center => grid::ALIGN_CENTER,
+        MdAlign::Right => grid::ALIGN_RIGHT,
+    }
+}
+
+fn render_table(table: &MdTable) -> Element {
+    let table_cls = format!("{} {}", grid::TABLE, s::TABLE_AUTO);
+    let row_cls = format!("{} {} {}", grid::TR, grid::TR_HOVER, grid::TR_STRIPED);
+    rsx! {
+        div { class: grid::GRID_WRAPPER,
+            div { class: grid::GRID_CONTAINER,
+                table { class: "{table_cls}",
+                    thead { class: grid::THEAD,
+                        tr {
+                            for (cell, align) in table.header.iter().zip(table.align.iter()) {
+                                th { class: "{grid::TH} {align_class(*align)}", {render_inlines(cell)} }
+                            }
+                        }
+                    }
+                    tbody {
+                        for row in table.rows.iter() {
+                            tr { class: "{row_cls}",
+                                for (cell, align) in row.iter().zip(table.align.iter()) {
+                                    td { class: "{grid::TD} {align_class(*align)}", {render_inlines(cell)} }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── Inline rendering ────────────────────────────────────────────────
+
+fn render_inlines(inlines: &[MdInline]) -> Element {
+    rsx! {
+        for inline in inlines.iter() {
+            {render_inline(inline)}
+        }
+    }
+}
+
+fn render_inline(inline: &MdInline) -> Element {
+    match inline {
+        MdInline::Text(t) => rsx! { "{t}" },
+        MdInline::Code(c) => rsx! { EqText { variant: TextVariant::Mono, class: s::INLINE_CODE, "{c}" } },
+        MdInline::Emphasis(c) => rsx! { em { {render_inlines(c)} } },
+        MdInline::Strong(c) => rsx! { strong { class: s::STRONG, {render_inlines(c)} } },
+        MdInline::Strike(c) => rsx! { del { class: s::STRIKE, {render_inlines(c)} } },
+        MdInline::Link { href, children } => rsx! {
+            EqLink { href: href.clone(), {render_inlines(children)} }
+        },
+        MdInline::Image { src, alt } => rsx! { img { class: s::IMAGE, src: "{src}", alt: "{alt}" } },
+        MdInline::SoftBreak => rsx! { " " },
+        MdInline::HardBreak => rsx! { br {} },
+    }
+}
+
+// ── Interactive demo ───────────────────────────────────────────────
+
+#[cfg(feature = "playground")]
+const DEMO_SOURCE: &str = r#"# Release notes
+
+EqMarkdown renders **CommonMark** with *GFM* extras: ~~nothing~~ everything
+you need for docs. Links like [Dioxus](https://dioxuslabs.com) and bare
+URLs such as https://github.com are themed through `EqLink`.
+
+## Checklist
+
+- [x] Tables
+- [x] Task lists
+- [ ] World domination
+
+## Data
+
+| Component | Layer | Status |
+|:----------|:-----:|-------:|
+| EqText    | Atom  | Stable |
+| EqGrid    | Organism | Stable |
+
+> Block quotes can contain *anything*,
+> including `inline code`.
+
+```rust
+fn main() {
+    println!("Hello, markdown!");
+}
+```
+
+---
+
+<p>Raw HTML is sanitized: <script>alert(1)</script><b>safe tags stay</b>.</p>
+"#;
+
+#[cfg(feature = "playground")]
+#[component]
+fn DemoEqMarkdown() -> Element {
+    let mut source = use_signal(|| DEMO_SOURCE.to_string());
+
+    let code = r##"use eq_ui::molecules::EqMarkdown;
+
+EqMarkdown {
+    source: "# Title\n\n- [x] done\n- [ ] todo",
+}"##
+    .to_string();
+
+    rsx! {
+        DemoSection { title: "EqMarkdown",
+            div { class: "grid gap-4 md:grid-cols-2",
+                div { class: "space-y-2",
+                    EqText {
+                        variant: TextVariant::Caption,
+                        class: "font-semibold uppercase tracking-wider",
+                        "Source"
+                    }
+                    textarea {
+                        class: "w-full h-[28rem] p-3 rounded-lg font-mono text-xs \
+                                bg-[var(--color-code-bg)] text-[var(--color-code-text)] \
+                                border border-[var(--color-card-border)] outline-none",
+                        value: source(),
+                        oninput: move |evt| source.set(evt.value()),
+                    }
+                }
+                div { class: "rounded-lg border border-dashed border-[var(--color-card-border)] p-4 overflow-auto h-[30rem]",
+                    EqMarkdown { source: source() }
+                }
+            }
+            StyleInfo { file: "eq_markdown_styles.rs", styles: format_catalog(&s::catalog()) }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
+        }
+    }
+}
+
+// ── Gallery (compact showcase) ─────────────────────────────────────
+
+#[cfg(feature = "playground")]
+#[component]
+fn GalleryEqMarkdown() -> Element {
+    rsx! {
+        div { class: "space-y-4",
+            EqMarkdown {
+                source: "### Getting started\n\nInstall with `cargo add eq_ui`, then:\n\n1. Pick a theme\n2. Render **components**\n\n- [x] Themed\n- [ ] Done",
+            }
+        }
+    }
+}
+
+// ── Smoke tests ─────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smoke_renders() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! { EqMarkdown { source: "# Hi\n\nText with [a link](/x)." } }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn smoke_renders_every_block_kind() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                EqMarkdown {
+                    source: "#### Minor\n\n> quote\n\n1. a\n2. b\n\n- [x] t\n\n```rs\nx\n```\n\n| a | b |\n|---|--:|\n| 1 | 2 |\n\n***\n\n<div>ok</div>\n\n![i](/i.png) ~~s~~ **b** *e* `c`  \nend",
+                }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+}
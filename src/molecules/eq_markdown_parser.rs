@@ -0,0 +1,1303 @@
+//! Markdown parser for EqMarkdown.
+//!
+//! Turns CommonMark (plus the GFM extensions tables, task lists,
+//! strikethrough and bare-URL autolinks) into a small AST of
+//! [`MdBlock`]s and [`MdInline`]s that the component renders with
+//! eq_ui atoms. Pure Rust, no browser needed.
+//!
+//! Raw HTML never reaches the page unchecked: HTML blocks are reduced
+//! to an allowlist by [`sanitize_html`], inline tags are stripped
+//! (`<br>` becomes a hard break), and every link or image URL must pass
+//! [`safe_url`]. Both live in [`crate::sanitize`], shared with
+//! EqRichTextEditor.
+
+use std::collections::HashMap;
+
+use crate::sanitize::{decode_entities, safe_url, sanitize_html};
+
+// ── AST ────────────────────────────────────────────────────────────
+
+/// Column alignment in a GFM table.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum MdAlign {
+    #[default]
+    None,
+    Left,
+    Center,
+    Right,
+}
+
+/// A block-level Markdown element.
+#[derive(Clone, PartialEq, Debug)]
+pub enum MdBlock {
+    /// `#` … `######` or setext heading.
+    Heading { level: u8, content: Vec<MdInline> },
+    Paragraph(Vec<MdInline>),
+    /// `>` block quote.
+    Quote(Vec<MdBlock>),
+    List(MdList),
+    /// Fenced or indented code. `lang` is the first word of the info string.
+    CodeBlock { lang: Option<String>, code: String },
+    Table(MdTable),
+    /// `---`, `***` or `___`.
+    ThematicBreak,
+    /// An HTML block, already sanitized.
+    Html(String),
+}
+
+/// A bullet or ordered list.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MdList {
+    pub ordered: bool,
+    /// First number of an ordered list.
+    pub start: u64,
+    /// Tight lists render item paragraphs without paragraph spacing.
+    pub tight: bool,
+    pub items: Vec<MdListItem>,
+}
+
+/// One list item.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MdListItem {
+    /// `Some(checked)` for GFM task items (`- [ ]` / `- [x]`).
+    pub checked: Option<bool>,
+    pub blocks: Vec<MdBlock>,
+}
+
+/// A GFM table.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MdTable {
+    pub align: Vec<MdAlign>,
+    pub header: Vec<Vec<MdInline>>,
+    pub rows: Vec<Vec<Vec<MdInline>>>,
+}
+
+/// An inline Markdown element.
+#[derive(Clone, PartialEq, Debug)]
+pub enum MdInline {
+    Text(String),
+    Code(String),
+    Emphasis(Vec<MdInline>),
+    Strong(Vec<MdInline>),
+    Strike(Vec<MdInline>),
+    /// A link whose `href` passed [`safe_url`].
+    Link { href: String, children: Vec<MdInline> },
+    /// An image whose `src` passed [`safe_url`].
+    Image { src: String, alt: String },
+    SoftBreak,
+    HardBreak,
+}
+
+impl MdInline {
+    /// Plain text content (used for image alt text).
+    pub fn plain_text(inlines: &[MdInline]) -> String {
+        let mut out = String::new();
+        for inline in inlines {
+            match inline {
+                MdInline::Text(t) | MdInline::Code(t) => out.push_str(t),
+                MdInline::Emphasis(c) | MdInline::Strong(c) | MdInline::Strike(c) => {
+                    out.push_str(&Self::plain_text(c))
+                }
+                MdInline::Link { children, .. } => out.push_str(&Self::plain_text(children)),
+                MdInline::Image { alt, .. } => out.push_str(alt),
+                MdInline::SoftBreak | MdInline::HardBreak => out.push(' '),
+            }
+        }
+        out
+    }
+}
+
+// ── Entry point ────────────────────────────────────────────────────
+
+/// Parses a Markdown document.
+pub fn parse_markdown(source: &str) -> Vec<MdBlock> {
+    let source = source.replace("\r\n", "\n").replace('\r', "\n").replace('\t', "    ");
+    let (lines, refs) = collect_references(&source);
+    Parser { refs }.blocks(&lines)
+}
+
+struct Parser {
+    /// Link reference definitions: normalized label → URL.
+    refs: HashMap<String, String>,
+}
+
+// ── Line helpers ───────────────────────────────────────────────────
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+/// Drops up to `n` leading spaces.
+fn strip_indent(line: &str, n: usize) -> String {
+    let drop = indent_of(line).min(n);
+    line[drop..].to_string()
+}
+
+/// Opening code fence: `(fence char, fence length, info string)`.
+fn fence_open(t: &str) -> Option<(char, usize, &str)> {
+    let ch = t.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let len = t.len() - t.trim_start_matches(ch).len();
+    if len < 3 {
+        return None;
+    }
+    let info = t[len..].trim();
+    if ch == '`' && info.contains('`') {
+        return None;
+    }
+    Some((ch, len, info))
+}
+
+fn fence_close(line: &str, ch: char, len: usize) -> bool {
+    if indent_of(line) >= 4 {
+        return false;
+    }
+    let t = line.trim();
+    let run = t.len() - t.trim_start_matches(ch).len();
+    run >= len && run == t.len()
+}
+
+fn atx_heading(t: &str) -> Option<(u8, &str)> {
+    let level = t.len() - t.trim_start_matches('#').len();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    let rest = &t[level..];
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+    let mut text = rest.trim();
+    // Optional closing sequence: spaces then #s.
+    let without = text.trim_end_matches('#');
+    if without.is_empty() || without.ends_with(' ') {
+        text = without.trim_end();
+    }
+    Some((level as u8, text))
+}
+
+fn is_thematic_break(t: &str) -> bool {
+    let compact: String = t.chars().filter(|c| *c != ' ').collect();
+    let Some(first) = compact.chars().next() else { return false };
+    matches!(first, '*' | '-' | '_') && compact.len() >= 3 && compact.chars().all(|c| c == first)
+}
+
+fn is_setext(t: &str, ch: char) -> bool {
+    let t = t.trim();
+    !t.is_empty() && t.chars().all(|c| c == ch)
+}
+
+struct ListMarker {
+    ordered: bool,
+    /// Bullet char, or the `.` / `)` delimiter of an ordered marker.
+    symbol: char,
+    start: u64,
+    /// Column where the item's content begins.
+    content_offset: usize,
+    /// Nothing follows the marker on its line.
+    empty: bool,
+}
+
+fn list_marker(line: &str) -> Option<ListMarker> {
+    let indent = indent_of(line);
+    if indent >= 4 {
+        return None;
+    }
+    let t = &line[indent..];
+    let bytes = t.as_bytes();
+    let (ordered, symbol, start, width) = match bytes.first()? {
+        b'-' | b'+' | b'*' => (false, bytes[0] as char, 0, 1),
+        b'0'..=b'9' => {
+            let digits = bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+            if digits > 9 {
+                return None;
+            }
+            let delim = *bytes.get(digits)?;
+            if delim != b'.' && delim != b')' {
+                return None;
+            }
+            (true, delim as char, t[..digits].parse().ok()?, digits + 1)
+        }
+        _ => return None,
+    };
+    let rest = &t[width..];
+    if rest.trim().is_empty() {
+        return Some(ListMarker { ordered, symbol, start, content_offset: indent + width + 1, empty: true });
+    }
+    let spaces = indent_of(rest);
+    if spaces == 0 {
+        return None;
+    }
+    // Five or more spaces: the content is indented code after one space.
+    let spaces = if spaces > 4 { 1 } else { spaces };
+    Some(ListMarker { ordered, symbol, start, content_offset: indent + width + spaces, empty: false })
+}
+
+fn is_html_block_start(t: &str) -> bool {
+    const TAGS: &[&str] = &[
+        "address", "article", "aside", "blockquote", "details", "dialog", "div", "dl",
+        "fieldset", "figcaption", "figure", "footer", "form", "h1", "h2", "h3", "h4", "h5",
+        "h6", "header", "hr", "iframe", "li", "main", "nav", "noscript", "ol", "p", "pre",
+        "script", "section", "style", "summary", "table", "tbody", "td", "tfoot", "th",
+        "thead", "tr", "ul", "center", "object", "embed", "template", "textarea",
+    ];
+    if t.starts_with("<!--") {
+        return true;
+    }
+    let Some(rest) = t.strip_prefix('<') else { return false };
+    let rest = rest.strip_prefix('/').unwrap_or(rest);
+    let name: String = rest.chars().take_while(|c| c.is_ascii_alphanumeric()).collect();
+    if name.is_empty() {
+        return false;
+    }
+    let after = rest[name.len()..].chars().next();
+    TAGS.contains(&name.to_ascii_lowercase().as_str())
+        && matches!(after, None | Some(' ') | Some('>') | Some('/'))
+}
+
+/// Whether `line` starts a block that interrupts a paragraph.
+fn starts_block(line: &str) -> bool {
+    let indent = indent_of(line);
+    if indent >= 4 {
+        return false;
+    }
+    let t = &line[indent..];
+    fence_open(t).is_some()
+        || atx_heading(t).is_some()
+        || is_thematic_break(t)
+        || t.starts_with('>')
+        || is_html_block_start(t)
+        || list_marker(line).is_some_and(|m| !m.empty && (!m.ordered || m.start == 1))
+}
+
+// ── GFM tables ─────────────────────────────────────────────────────
+
+/// Splits a table row into trimmed cells, honouring `\|` escapes.
+fn split_row(line: &str) -> Vec<String> {
+    let t = line.trim();
+    let t = t.strip_prefix('|').unwrap_or(t);
+    let t = if t.ends_with('|') && !t.ends_with("\\|") { &t[..t.len() - 1] } else { t };
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = t.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'|') => {
+                cell.push('|');
+                chars.next();
+            }
+            '|' => cells.push(std::mem::take(&mut cell).trim().to_string()),
+            _ => cell.push(c),
+        }
+    }
+    cells.push(cell.trim().to_string());
+    cells
+}
+
+/// Parses a delimiter row (`| :--- | :-: |`) into column alignments.
+fn delimiter_row(line: &str) -> Option<Vec<MdAlign>> {
+    if !line.contains('-') || indent_of(line) >= 4 {
+        return None;
+    }
+    split_row(line)
+        .iter()
+        .map(|cell| {
+            let left = cell.starts_with(':');
+            let right = cell.ends_with(':');
+            let dashes = cell.trim_matches(':');
+            if dashes.is_empty() || !dashes.chars().all(|c| c == '-') {
+                return None;
+            }
+            Some(match (left, right) {
+                (true, true) => MdAlign::Center,
+                (true, false) => MdAlign::Left,
+                (false, true) => MdAlign::Right,
+                (false, false) => MdAlign::None,
+            })
+        })
+        .collect()
+}
+
+// ── Link reference definitions ─────────────────────────────────────
+
+fn normalize_label(label: &str) -> String {
+    label.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// Parses `[label]: destination "optional title"`.
+fn reference_definition(line: &str) -> Option<(String, String)> {
+    if indent_of(line) >= 4 {
+        return None;
+    }
+    let t = line.trim().strip_prefix('[')?;
+    let close = t.find("]:")?;
+    let label = normalize_label(&t[..close]);
+    if label.is_empty() || t[..close].contains('[') {
+        return None;
+    }
+    let rest = t[close + 2..].trim();
+    let dest = if let Some(inner) = rest.strip_prefix('<') {
+        inner.split('>').next()?.to_string()
+    } else {
+        rest.split_whitespace().next()?.to_string()
+    };
+    Some((label, unescape(&dest)))
+}
+
+/// Removes definition lines (outside code fences and only where a
+/// paragraph could start) and returns them as a label map.
+fn collect_references(source: &str) -> (Vec<String>, HashMap<String, String>) {
+    let mut refs = HashMap::new();
+    let mut lines = Vec::new();
+    let mut fence: Option<(char, usize)> = None;
+    let mut can_define = true;
+    for line in source.split('\n') {
+        if let Some((ch, len)) = fence {
+            if fence_close(line, ch, len) {
+                fence = None;
+            }
+            lines.push(line.to_string());
+            continue;
+        }
+        if indent_of(line) < 4
+            && let Some((ch, len, _)) = fence_open(line.trim_start())
+        {
+            fence = Some((ch, len));
+            lines.push(line.to_string());
+            can_define = false;
+            continue;
+        }
+        if can_define && let Some((label, url)) = reference_definition(line) {
+            refs.entry(label).or_insert(url);
+            continue;
+        }
+        can_define = is_blank(line);
+        lines.push(line.to_string());
+    }
+    (lines, refs)
+}
+
+// ── Block parsing ──────────────────────────────────────────────────
+
+impl Parser {
+    fn blocks(&self, lines: &[String]) -> Vec<MdBlock> {
+        let mut out = Vec::new();
+        let mut i = 0;
+        while i < lines.len() {
+            let line = &lines[i];
+            if is_blank(line) {
+                i += 1;
+                continue;
+            }
+            let indent = indent_of(line);
+
+            // Indented code.
+            if indent >= 4 {
+                let mut code = Vec::new();
+                while i < lines.len() && (is_blank(&lines[i]) || indent_of(&lines[i]) >= 4) {
+                    code.push(strip_indent(&lines[i], 4));
+                    i += 1;
+                }
+                while code.last().is_some_and(|l| l.trim().is_empty()) {
+                    code.pop();
+                }
+                out.push(MdBlock::CodeBlock { lang: None, code: code.join("\n") });
+                continue;
+            }
+
+            let t = &line[indent..];
+
+            if let Some((ch, len, info)) = fence_open(t) {
+                let lang = info.split_whitespace().next().map(unescape).filter(|w| !w.is_empty());
+                i += 1;
+                let mut code = Vec::new();
+                while i < lines.len() {
+                    if fence_close(&lines[i], ch, len) {
+                        i += 1;
+                        break;
+                    }
+                    code.push(strip_indent(&lines[i], indent));
+                    i += 1;
+                }
+                out.push(MdBlock::CodeBlock { lang, code: code.join("\n") });
+                continue;
+            }
+
+            if let Some((level, text)) = atx_heading(t) {
+                out.push(MdBlock::Heading { level, content: self.inlines(text) });
+                i += 1;
+                continue;
+            }
+
+            if is_thematic_break(t) {
+                out.push(MdBlock::ThematicBreak);
+                i += 1;
+                continue;
+            }
+
+            if t.starts_with('>') {
+                let mut inner: Vec<String> = Vec::new();
+                while i < lines.len() {
+                    let l = &lines[i];
+                    let ind = indent_of(l);
+                    if ind < 4 && l[ind..].starts_with('>') {
+                        let rest = &l[ind + 1..];
+                        inner.push(rest.strip_prefix(' ').unwrap_or(rest).to_string());
+                    } else if !is_blank(l)
+                        && inner.last().is_some_and(|p| !is_blank(p))
+                        && !starts_block(l)
+                    {
+                        // Lazy paragraph continuation.
+                        inner.push(l.clone());
+                    } else {
+                        break;
+                    }
+                    i += 1;
+                }
+                out.push(MdBlock::Quote(self.blocks(&inner)));
+                continue;
+            }
+
+            if list_marker(line).is_some() {
+                let (list, next) = self.list(lines, i);
+                out.push(MdBlock::List(list));
+                i = next;
+                continue;
+            }
+
+            if is_html_block_start(t) {
+                let mut html = Vec::new();
+                while i < lines.len() && !is_blank(&lines[i]) {
+                    html.push(lines[i].as_str());
+                    i += 1;
+                }
+                let clean = sanitize_html(&html.join("\n"));
+                if !clean.trim().is_empty() {
+                    out.push(MdBlock::Html(clean));
+                }
+                continue;
+            }
+
+            if line.contains('|')
+                && let Some(align) = lines.get(i + 1).and_then(|l| delimiter_row(l))
+                && split_row(line).len() == align.len()
+            {
+                let header = split_row(line).iter().map(|c| self.inlines(c)).collect();
+                i += 2;
+                let mut rows = Vec::new();
+                while i < lines.len() && !is_blank(&lines[i]) && !starts_block(&lines[i]) {
+                    let mut cells: Vec<Vec<MdInline>> =
+                        split_row(&lines[i]).iter().map(|c| self.inlines(c)).collect();
+                    cells.resize(align.len(), Vec::new());
+                    rows.push(cells);
+                    i += 1;
+                }
+                out.push(MdBlock::Table(MdTable { align, header, rows }));
+                continue;
+            }
+
+            // Paragraph (possibly turned into a setext heading).
+            let mut para = vec![t.to_string()];
+            let mut setext = None;
+            i += 1;
+            while i < lines.len() {
+                let l = &lines[i];
+                if is_blank(l) {
+                    break;
+                }
+                if indent_of(l) < 4 {
+                    if is_setext(l, '=') {
+                        setext = Some(1);
+                    } else if is_setext(l, '-') {
+                        setext = Some(2);
+                    }
+                    if setext.is_some() {
+                        i += 1;
+                        break;
+                    }
+                }
+                if starts_block(l) {
+                    break;
+                }
+                para.push(l.trim_start().to_string());
+                i += 1;
+            }
+            let text = para.join("\n");
+            let content = self.inlines(text.trim_end());
+            out.push(match setext {
+                Some(level) => MdBlock::Heading { level, content },
+                None => MdBlock::Paragraph(content),
+            });
+        }
+        out
+    }
+
+    /// Parses a list starting at `lines[start]`. Returns the list and
+    /// the index of the first line after it.
+    fn list(&self, lines: &[String], start: usize) -> (MdList, usize) {
+        let first = list_marker(&lines[start]).expect("caller checked for a list marker");
+        let mut items = Vec::new();
+        let mut tight = true;
+        let mut i = start;
+
+        loop {
+            let marker = list_marker(&lines[i]).expect("loop only continues on a marker");
+            let first_line = lines[i].get(marker.content_offset..).unwrap_or("").to_string();
+            let mut item_lines = vec![first_line];
+            i += 1;
+            let mut fence: Option<(char, usize)> = None;
+            let mut internal_blank = false;
+            while i < lines.len() {
+                let l = &lines[i];
+                if is_blank(l) {
+                    item_lines.push(String::new());
+                } else if indent_of(l) >= marker.content_offset {
+                    let content = l[marker.content_offset..].to_string();
+                    if item_lines.last().is_some_and(|p| p.is_empty()) && fence.is_none() {
+                        internal_blank = true;
+                    }
+                    match fence {
+                        Some((ch, len)) if fence_close(&content, ch, len) => fence = None,
+                        None => {
+                            if let Some((ch, len, _)) = fence_open(content.trim_start()) {
+                                fence = Some((ch, len));
+                            }
+                        }
+                        _ => {}
+                    }
+                    item_lines.push(content);
+                } else if item_lines.last().is_some_and(|p| !p.trim().is_empty())
+                    && !starts_block(l)
+                    && fence.is_none()
+                {
+                    // Lazy paragraph continuation.
+                    item_lines.push(l.trim_start().to_string());
+                } else {
+                    break;
+                }
+                i += 1;
+            }
+
+            let mut trailing_blank = false;
+            while item_lines.len() > 1 && item_lines.last().is_some_and(|l| l.trim().is_empty()) {
+                item_lines.pop();
+                trailing_blank = true;
+            }
+            if internal_blank {
+                tight = false;
+            }
+
+            let mut checked = None;
+            if let Some(first) = item_lines.first_mut() {
+                for (prefix, state) in [("[ ]", false), ("[x]", true), ("[X]", true)] {
+                    if let Some(rest) = first.strip_prefix(prefix)
+                        && (rest.is_empty() || rest.starts_with(' '))
+                    {
+                        checked = Some(state);
+                        *first = rest.trim_start().to_string();
+                        break;
+                    }
+                }
+            }
+
+            items.push(MdListItem { checked, blocks: self.blocks(&item_lines) });
+
+            let next = lines.get(i).and_then(|l| {
+                let t = l.trim_start();
+                if is_thematic_break(t) { None } else { list_marker(l) }
+            });
+            match next {
+                Some(n) if n.ordered == first.ordered && n.symbol == first.symbol => {
+                    if trailing_blank {
+                        tight = false;
+                    }
+                }
+                _ => break,
+            }
+        }
+
+        (MdList { ordered: first.ordered, start: first.start, tight, items }, i)
+    }
+
+    // ── Inline parsing ─────────────────────────────────────────────
+
+    fn inlines(&self, text: &str) -> Vec<MdInline> {
+        let mut p = InlineParser {
+            chars: text.chars().collect(),
+            pos: 0,
+            toks: Vec::new(),
+            text: String::new(),
+            parser: self,
+        };
+        p.run();
+        let mut toks = std::mem::take(&mut p.toks);
+        process_emphasis(&mut toks);
+        finalize(toks)
+    }
+}
+
+enum Tok {
+    Text(String),
+    Node(MdInline),
+    Delim { ch: char, count: usize, open: bool, close: bool },
+}
+
+struct InlineParser<'a> {
+    chars: Vec<char>,
+    pos: usize,
+    toks: Vec<Tok>,
+    text: String,
+    parser: &'a Parser,
+}
+
+fn is_punct(c: char) -> bool {
+    c.is_ascii_punctuation() || (!c.is_ascii() && !c.is_alphanumeric() && !c.is_whitespace())
+}
+
+impl InlineParser<'_> {
+    fn at(&self, i: usize) -> Option<char> {
+        self.chars.get(i).copied()
+    }
+
+    fn flush_text(&mut self) {
+        if !self.text.is_empty() {
+            self.toks.push(Tok::Text(std::mem::take(&mut self.text)));
+        }
+    }
+
+    fn push_node(&mut self, node: MdInline) {
+        self.flush_text();
+        self.toks.push(Tok::Node(node));
+    }
+
+    fn run(&mut self) {
+        while let Some(c) = self.at(self.pos) {
+            match c {
+                '\\' => self.backslash(),
+                '`' => self.code_span(),
+                '*' | '_' | '~' => self.delimiter_run(c),
+                '!' if self.at(self.pos + 1) == Some('[') => {
+                    if !self.link(true) {
+                        self.text.push('!');
+                        self.pos += 1;
+                    }
+                }
+                '[' => {
+                    if !self.link(false) {
+                        self.text.push('[');
+                        self.pos += 1;
+                    }
+                }
+                '<' => self.angle(),
+                '&' => self.entity(),
+                '\n' => self.line_break(),
+                'h' | 'w' if self.bare_url() => {}
+                _ => {
+                    self.text.push(c);
+                    self.pos += 1;
+                }
+            }
+        }
+        self.flush_text();
+    }
+
+    fn backslash(&mut self) {
+        match self.at(self.pos + 1) {
+            Some('\n') => {
+                self.push_node(MdInline::HardBreak);
+                self.pos += 2;
+                self.skip_spaces();
+            }
+            Some(n) if n.is_ascii_punctuation() => {
+                self.text.push(n);
+                self.pos += 2;
+            }
+            _ => {
+                self.text.push('\\');
+                self.pos += 1;
+            }
+        }
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.at(self.pos) == Some(' ') {
+            self.pos += 1;
+        }
+    }
+
+    fn run_length(&self, from: usize, ch: char) -> usize {
+        self.chars[from..].iter().take_while(|c| **c == ch).count()
+    }
+
+    fn code_span(&mut self) {
+        let n = self.run_length(self.pos, '`');
+        let start = self.pos + n;
+        let mut j = start;
+        while j < self.chars.len() {
+            if self.chars[j] == '`' {
+                let m = self.run_length(j, '`');
+                if m == n {
+                    let raw: String = self.chars[start..j].iter().collect();
+                    let mut code = raw.replace('\n', " ");
+                    if code.len() >= 2 && code.starts_with(' ') && code.ends_with(' ') && !code.trim().is_empty() {
+                        code = code[1..code.len() - 1].to_string();
+                    }
+                    self.push_node(MdInline::Code(code));
+                    self.pos = j + n;
+                    return;
+                }
+                j += m;
+            } else {
+                j += 1;
+            }
+        }
+        // No closer: the backticks are literal.
+        self.text.extend(std::iter::repeat_n('`', n));
+        self.pos += n;
+    }
+
+    fn delimiter_run(&mut self, ch: char) {
+        let count = self.run_length(self.pos, ch);
+        let before = if self.pos == 0 { ' ' } else { self.chars[self.pos - 1] };
+        let after = self.at(self.pos + count).unwrap_or(' ');
+        if ch == '~' && count > 2 {
+            self.text.extend(std::iter::repeat_n('~', count));
+            self.pos += count;
+            return;
+        }
+        let left = !after.is_whitespace() && (!is_punct(after) || before.is_whitespace() || is_punct(before));
+        let right = !before.is_whitespace() && (!is_punct(before) || after.is_whitespace() || is_punct(after));
+        let (open, close) = if ch == '_' {
+            (left && (!right || is_punct(before)), right && (!left || is_punct(after)))
+        } else {
+            (left, right)
+        };
+        self.flush_text();
+        self.toks.push(Tok::Delim { ch, count, open, close });
+        self.pos += count;
+    }
+
+    /// Index of the `]` matching the `[` at `open`.
+    fn matching_bracket(&self, open: usize) -> Option<usize> {
+        let mut depth = 0;
+        let mut j = open;
+        while j < self.chars.len() {
+            match self.chars[j] {
+                '\\' => j += 1,
+                '`' => {
+                    let n = self.run_length(j, '`');
+                    j += n - 1;
+                }
+                '[' => depth += 1,
+                ']' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(j);
+                    }
+                }
+                _ => {}
+            }
+            j += 1;
+        }
+        None
+    }
+
+    /// Parses `(destination "title")` starting at `from` (the `(`).
+    /// Returns the destination and the index after `)`.
+    fn inline_destination(&self, from: usize) -> Option<(String, usize)> {
+        let mut j = from + 1;
+        let skip_ws = |j: &mut usize| {
+            while self.chars.get(*j).is_some_and(|c| c.is_whitespace()) {
+                *j += 1;
+            }
+        };
+        skip_ws(&mut j);
+        let mut dest = String::new();
+        if self.at(j) == Some('<') {
+            j += 1;
+            while let Some(c) = self.at(j) {
+                if c == '>' {
+                    break;
+                }
+                if c == '\n' || c == '<' {
+                    return None;
+                }
+                dest.push(c);
+                j += 1;
+            }
+            self.at(j)?;
+            j += 1;
+        } else {
+            let mut parens = 0;
+            while let Some(c) = self.at(j) {
+                if c.is_whitespace() {
+                    break;
+                }
+                if c == '\\' && self.at(j + 1).is_some_and(|n| n.is_ascii_punctuation()) {
+                    dest.push(c);
+                    dest.push(self.chars[j + 1]);
+                    j += 2;
+                    continue;
+                }
+                if c == '(' {
+                    parens += 1;
+                } else if c == ')' {
+                    if parens == 0 {
+                        break;
+                    }
+                    parens -= 1;
+                }
+                dest.push(c);
+                j += 1;
+            }
+        }
+        skip_ws(&mut j);
+        // Optional title.
+        if let Some(q) = self.at(j).filter(|c| matches!(c, '"' | '\'' | '(')) {
+            let close = if q == '(' { ')' } else { q };
+            j += 1;
+            while let Some(c) = self.at(j) {
+                if c == '\\' {
+                    j += 2;
+                    continue;
+                }
+                if c == close {
+                    break;
+                }
+                j += 1;
+            }
+            self.at(j)?;
+            j += 1;
+            skip_ws(&mut j);
+        }
+        (self.at(j) == Some(')')).then(|| (unescape(&dest), j + 1))
+    }
+
+    /// Tries to parse a link (or image) at the current position.
+    fn link(&mut self, image: bool) -> bool {
+        let open = if image { self.pos + 1 } else { self.pos };
+        let Some(close) = self.matching_bracket(open) else { return false };
+        let label: String = self.chars[open + 1..close].iter().collect();
+
+        let target = if self.at(close + 1) == Some('(') {
+            self.inline_destination(close + 1)
+        } else {
+            None
+        };
+        let target = target.or_else(|| {
+            // Full, collapsed or shortcut reference.
+            let (key, end) = if self.at(close + 1) == Some('[') {
+                let ref_close = (close + 2..self.chars.len()).find(|&k| self.chars[k] == ']')?;
+                let key: String = self.chars[close + 2..ref_close].iter().collect();
+                let key = if key.trim().is_empty() { label.clone() } else { key };
+                (key, ref_close + 1)
+            } else {
+                (label.clone(), close + 1)
+            };
+            self.parser.refs.get(&normalize_label(&key)).map(|url| (url.clone(), end))
+        });
+        let Some((url, end)) = target else { return false };
+
+        let children = self.parser.inlines(&label);
+        match (image, safe_url(&url)) {
+            (true, Some(src)) => {
+                let alt = MdInline::plain_text(&children);
+                self.push_node(MdInline::Image { src, alt });
+            }
+            (true, None) => self.text.push_str(&MdInline::plain_text(&children)),
+            (false, Some(href)) => self.push_node(MdInline::Link { href, children }),
+            (false, None) => {
+                // Unsafe or empty target: keep the text, drop the link.
+                self.flush_text();
+                self.toks.extend(children.into_iter().map(Tok::Node));
+            }
+        }
+        self.pos = end;
+        true
+    }
+
+    /// `<…>`: autolink, inline HTML (stripped) or a literal `<`.
+    fn angle(&mut self) {
+        let rest: String = self.chars[self.pos..].iter().take(2048).collect();
+        if let Some(end) = rest.find('>') {
+            let inner = &rest[1..end];
+            let len = inner.chars().count() + 2;
+            if !inner.contains(char::is_whitespace) && !inner.contains('<') {
+                let scheme = inner.split(':').next().unwrap_or("");
+                let is_uri = inner.contains(':')
+                    && scheme.len() >= 2
+                    && scheme.chars().all(|c| c.is_ascii_alphanumeric() || "+.-".contains(c));
+                let is_email = !inner.contains(':') && inner.contains('@') && !inner.starts_with('@');
+                if is_uri || is_email {
+                    let href = if is_email { format!("mailto:{inner}") } else { inner.to_string() };
+                    match safe_url(&href) {
+                        Some(href) => self.push_node(MdInline::Link {
+                            href,
+                            children: vec![MdInline::Text(inner.to_string())],
+                        }),
+                        None => self.text.push_str(inner),
+                    }
+                    self.pos += len;
+                    return;
+                }
+            }
+            if let Some(after) = inner.strip_prefix("!--") {
+                let _ = after;
+                if let Some(c) = rest.find("-->") {
+                    self.pos += rest[..c + 3].chars().count();
+                    return;
+                }
+            }
+            let tag = inner.strip_prefix('/').unwrap_or(inner);
+            let name: String = tag.chars().take_while(|c| c.is_ascii_alphanumeric() || *c == '-').collect();
+            if !name.is_empty() && tag.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                if name.eq_ignore_ascii_case("br") {
+                    self.push_node(MdInline::HardBreak);
+                }
+                self.pos += len;
+                return;
+            }
+        }
+        self.text.push('<');
+        self.pos += 1;
+    }
+
+    fn entity(&mut self) {
+        let rest: String = self.chars[self.pos..].iter().take(12).collect();
+        if let Some(end) = rest.find(';') {
+            let decoded = decode_entities(&rest[..=end]);
+            if decoded != rest[..=end] {
+                self.text.push_str(&decoded);
+                self.pos += rest[..=end].chars().count();
+                return;
+            }
+        }
+        self.text.push('&');
+        self.pos += 1;
+    }
+
+    fn line_break(&mut self) {
+        let trailing = self.text.len() - self.text.trim_end_matches(' ').len();
+        self.text.truncate(self.text.len() - trailing);
+        self.push_node(if trailing >= 2 { MdInline::HardBreak } else { MdInline::SoftBreak });
+        self.pos += 1;
+        self.skip_spaces();
+    }
+
+    /// GFM bare autolinks: `https://…`, `http://…` and `www.…`.
+    fn bare_url(&mut self) -> bool {
+        if self.pos > 0 && self.chars[self.pos - 1].is_alphanumeric() {
+            return false;
+        }
+        let rest: String = self.chars[self.pos..].iter().take_while(|c| !c.is_whitespace() && **c != '<').collect();
+        let prefix = ["https://", "http://", "www."].into_iter().find(|p| rest.starts_with(p));
+        let Some(prefix) = prefix else { return false };
+        let mut url = rest.as_str();
+        // Trailing punctuation is not part of the URL.
+        loop {
+            let trimmed = url.trim_end_matches(['?', '!', '.', ',', ':', '*', '_', '~', '\'', '"']);
+            let trimmed = if trimmed.ends_with(')') && trimmed.matches(')').count() > trimmed.matches('(').count() {
+                &trimmed[..trimmed.len() - 1]
+            } else {
+                trimmed
+            };
+            if trimmed.len() == url.len() {
+                break;
+            }
+            url = trimmed;
+        }
+        if url.len() <= prefix.len() {
+            return false;
+        }
+        let href = if prefix == "www." { format!("http://{url}") } else { url.to_string() };
+        let Some(href) = safe_url(&href) else { return false };
+        self.push_node(MdInline::Link { href, children: vec![MdInline::Text(url.to_string())] });
+        self.pos += url.chars().count();
+        true
+    }
+}
+
+/// CommonMark's delimiter matching, applied to the token list in place.
+fn process_emphasis(toks: &mut Vec<Tok>) {
+    let mut i = 0;
+    while i < toks.len() {
+        let Tok::Delim { ch, count, close: true, open: closer_open } = toks[i] else {
+            i += 1;
+            continue;
+        };
+        if count == 0 {
+            i += 1;
+            continue;
+        }
+        let opener = (0..i).rev().find(|&j| match toks[j] {
+            Tok::Delim { ch: c, count: n, open: true, close: opener_close } if c == ch && n > 0 => {
+                if ch == '~' {
+                    return n == count;
+                }
+                // Rule of three.
+                !((opener_close || closer_open) && (n + count) % 3 == 0 && !(n % 3 == 0 && count % 3 == 0))
+            }
+            _ => false,
+        });
+        let Some(j) = opener else {
+            i += 1;
+            continue;
+        };
+        let Tok::Delim { count: opener_count, .. } = toks[j] else { unreachable!() };
+        let used = if ch == '~' || (count >= 2 && opener_count >= 2) { 2.min(count) } else { 1 };
+
+        let mut inner: Vec<Tok> = toks.drain(j + 1..i).collect();
+        process_emphasis(&mut inner);
+        let children = finalize(inner);
+        let node = match (ch, used) {
+            ('~', _) => MdInline::Strike(children),
+            (_, 2) => MdInline::Strong(children),
+            _ => MdInline::Emphasis(children),
+        };
+        toks.insert(j + 1, Tok::Node(node));
+        // Closer is now at j + 2.
+        if let Tok::Delim { count, .. } = &mut toks[j] {
+            *count -= used;
+        }
+        if let Tok::Delim { count, .. } = &mut toks[j + 2] {
+            *count -= used;
+        }
+        i = j + 2;
+        if matches!(toks[j], Tok::Delim { count: 0, .. }) {
+            toks.remove(j);
+            i -= 1;
+        }
+        if matches!(toks[i], Tok::Delim { count: 0, .. }) {
+            toks.remove(i);
+        }
+    }
+}
+
+/// Converts leftover tokens to inline nodes, merging adjacent text.
+fn finalize(toks: Vec<Tok>) -> Vec<MdInline> {
+    let mut out: Vec<MdInline> = Vec::new();
+    for tok in toks {
+        let node = match tok {
+            Tok::Text(t) => MdInline::Text(t),
+            Tok::Delim { ch, count, .. } => MdInline::Text(std::iter::repeat_n(ch, count).collect()),
+            Tok::Node(n) => n,
+        };
+        match (out.last_mut(), node) {
+            (Some(MdInline::Text(prev)), MdInline::Text(t)) => prev.push_str(&t),
+            (_, MdInline::Text(t)) if t.is_empty() => {}
+            (_, node) => out.push(node),
+        }
+    }
+    out
+}
+
+// ── Escapes ────────────────────────────────────────────────────────
+
+/// Resolves backslash escapes and entities in a URL or info string.
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' && chars.peek().is_some_and(|n| n.is_ascii_punctuation()) {
+            out.push(chars.next().unwrap_or('\\'));
+        } else {
+            out.push(c);
+        }
+    }
+    decode_entities(&out)
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(s: &str) -> MdInline {
+        MdInline::Text(s.to_string())
+    }
+
+    fn para(src: &str) -> Vec<MdInline> {
+        match parse_markdown(src).into_iter().next() {
+            Some(MdBlock::Paragraph(p)) => p,
+            other => panic!("expected paragraph, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn headings_atx_and_setext() {
+        let blocks = parse_markdown("# One #\n\nTwo\n---\n\n###### Six\n\n####### not");
+        assert_eq!(blocks[0], MdBlock::Heading { level: 1, content: vec![text("One")] });
+        assert_eq!(blocks[1], MdBlock::Heading { level: 2, content: vec![text("Two")] });
+        assert_eq!(blocks[2], MdBlock::Heading { level: 6, content: vec![text("Six")] });
+        assert!(matches!(blocks[3], MdBlock::Paragraph(_)));
+    }
+
+    #[test]
+    fn emphasis_strong_and_strike() {
+        assert_eq!(
+            para("*a* **b** ***c*** ~~d~~ snake_case_word"),
+            vec![
+                MdInline::Emphasis(vec![text("a")]),
+                text(" "),
+                MdInline::Strong(vec![text("b")]),
+                text(" "),
+                MdInline::Emphasis(vec![MdInline::Strong(vec![text("c")])]),
+                text(" "),
+                MdInline::Strike(vec![text("d")]),
+                text(" snake_case_word"),
+            ]
+        );
+    }
+
+    #[test]
+    fn unmatched_delimiters_are_literal() {
+        assert_eq!(para("2 * 3 * 4 and **open"), vec![text("2 * 3 * 4 and **open")]);
+    }
+
+    #[test]
+    fn code_spans_and_escapes() {
+        assert_eq!(
+            para("`` a`b `` \\*not\\* `x`"),
+            vec![MdInline::Code("a`b".into()), text(" *not* "), MdInline::Code("x".into())]
+        );
+    }
+
+    #[test]
+    fn links_inline_reference_and_autolinks() {
+        let p = para("[ref]: https://r.io\n\n[a](/x \"t\") [b][ref] [ref] <https://e.com> https://bare.io/x. www.w.org");
+        let links: Vec<&str> = p
+            .iter()
+            .filter_map(|i| match i {
+                MdInline::Link { href, .. } => Some(href.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(links, vec!["/x", "https://r.io", "https://r.io", "https://e.com", "https://bare.io/x", "http://www.w.org"]);
+        assert_eq!(parse_markdown("[ref]: https://r.io\n\n[ref]").len(), 1);
+    }
+
+    #[test]
+    fn unsafe_urls_are_dropped() {
+        let p = para("[click](javascript:alert(1)) ![img](data:text/html,x) <javascript:alert(1)>");
+        assert!(!p.iter().any(|i| matches!(i, MdInline::Link { .. } | MdInline::Image { .. })));
+        assert_eq!(MdInline::plain_text(&p), "click img javascript:alert(1)");
+    }
+
+    #[test]
+    fn images() {
+        assert_eq!(para("![a *b*](/i.png)"), vec![MdInline::Image { src: "/i.png".into(), alt: "a b".into() }]);
+    }
+
+    #[test]
+    fn breaks() {
+        assert_eq!(
+            para("a  \nb\\\nc\nd"),
+            vec![text("a"), MdInline::HardBreak, text("b"), MdInline::HardBreak, text("c"), MdInline::SoftBreak, text("d")]
+        );
+    }
+
+    #[test]
+    fn fenced_and_indented_code() {
+        let blocks = parse_markdown("```rust title\nfn main() {\n\n}\n```\n\n    indented\n    code\n");
+        assert_eq!(blocks[0], MdBlock::CodeBlock { lang: Some("rust".into()), code: "fn main() {\n\n}".into() });
+        assert_eq!(blocks[1], MdBlock::CodeBlock { lang: None, code: "indented\ncode".into() });
+    }
+
+    #[test]
+    fn unclosed_fence_runs_to_end() {
+        let blocks = parse_markdown("~~~\ncode\n# not a heading");
+        assert_eq!(blocks, vec![MdBlock::CodeBlock { lang: None, code: "code\n# not a heading".into() }]);
+    }
+
+    #[test]
+    fn block_quotes_nest_and_continue_lazily() {
+        let blocks = parse_markdown("> a\nlazy\n> > b");
+        let MdBlock::Quote(inner) = &blocks[0] else { panic!() };
+        assert_eq!(inner[0], MdBlock::Paragraph(vec![text("a"), MdInline::SoftBreak, text("lazy")]));
+        assert!(matches!(inner[1], MdBlock::Quote(_)));
+    }
+
+    #[test]
+    fn tight_and_loose_lists() {
+        let MdBlock::List(tight) = &parse_markdown("- a\n- b\n  - nested\n- c")[0] else { panic!() };
+        assert!(tight.tight && !tight.ordered);
+        assert_eq!(tight.items.len(), 3);
+        assert!(matches!(tight.items[1].blocks[1], MdBlock::List(_)));
+
+        let MdBlock::List(loose) = &parse_markdown("3. a\n\n4. b")[0] else { panic!() };
+        assert!(!loose.tight && loose.ordered);
+        assert_eq!(loose.start, 3);
+    }
+
+    #[test]
+    fn list_types_split_lists() {
+        let blocks = parse_markdown("- a\n* b\n1. c");
+        assert_eq!(blocks.len(), 3);
+    }
+
+    #[test]
+    fn thematic_break_is_not_a_list() {
+        let blocks = parse_markdown("- a\n* * *\n- b");
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[1], MdBlock::ThematicBreak);
+    }
+
+    #[test]
+    fn task_lists() {
+        let MdBlock::List(list) = &parse_markdown("- [ ] todo\n- [x] done\n- plain")[0] else { panic!() };
+        assert_eq!(list.items[0].checked, Some(false));
+        assert_eq!(list.items[1].checked, Some(true));
+        assert_eq!(list.items[2].checked, None);
+        assert_eq!(list.items[1].blocks[0], MdBlock::Paragraph(vec![text("done")]));
+    }
+
+    #[test]
+    fn gfm_tables() {
+        let blocks = parse_markdown("| a | b | c |\n|:--|:-:|--:|\n| 1 | `x\\|y` |\n\nafter");
+        let MdBlock::Table(t) = &blocks[0] else { panic!("{blocks:?}") };
+        assert_eq!(t.align, vec![MdAlign::Left, MdAlign::Center, MdAlign::Right]);
+        assert_eq!(t.header[2], vec![text("c")]);
+        assert_eq!(t.rows[0][1], vec![MdInline::Code("x|y".into())]);
+        assert!(t.rows[0][2].is_empty());
+        assert!(matches!(blocks[1], MdBlock::Paragraph(_)));
+    }
+
+    #[test]
+    fn table_needs_matching_delimiter_row() {
+        let blocks = parse_markdown("a | b\n--- | --- | ---");
+        assert!(!matches!(blocks[0], MdBlock::Table(_)));
+    }
+
+    #[test]
+    fn html_blocks_are_sanitized() {
+        let blocks = parse_markdown(
+            "<div onclick=\"x()\"><script>alert(1)</script><a href=\"javascript:x\">a</a><b>ok</b></div>",
+        );
+        assert_eq!(blocks, vec![MdBlock::Html("<div><a>a</a><b>ok</b></div>".into())]);
+    }
+
+    #[test]
+    fn inline_html_is_stripped() {
+        assert_eq!(
+            para("a <span style=\"x\">b</span><br>c <img src=x onerror=alert(1)>"),
+            vec![text("a b"), MdInline::HardBreak, text("c ")]
+        );
+    }
+
+    #[test]
+    fn html_blocks_with_non_ascii_text() {
+        assert_eq!(
+            parse_markdown("</div><br></div>é"),
+            vec![MdBlock::Html("<br>é".into())]
+        );
+        assert_eq!(parse_markdown("<p>élan 🎉</p>"), vec![MdBlock::Html("<p>élan 🎉</p>".into())]);
+    }
+
+    #[test]
+    fn entities_decode() {
+        assert_eq!(para("&copy; &#65; &#x42; &bogus; AT&T"), vec![text("© A B &bogus; AT&T")]);
+    }
+}
@@ -0,0 +1,75 @@
+//! Style constants for EqMarkdown.
+
+/// Outer wrapper - vertical rhythm between blocks.
+pub const MARKDOWN: &str = "flex flex-col gap-3 min-w-0 break-words";
+
+/// Heading levels 4-6 (rendered through `TextVariant::H3`).
+pub const HEADING_MINOR: &str = "!text-base";
+
+/// Paragraph inside a tight list item - no extra spacing.
+pub const TIGHT_PARAGRAPH: &str = "leading-normal";
+
+/// Inline code span (applied over `TextVariant::Mono`).
+pub const INLINE_CODE: &str = "px-1 py-0.5 rounded text-xs bg-[var(--color-tertiary-dark)]";
+
+/// Strong emphasis.
+pub const STRONG: &str = "font-semibold text-[var(--color-label-bold)]";
+
+/// Strikethrough.
+pub const STRIKE: &str = "line-through opacity-80";
+
+/// Inline image.
+pub const IMAGE: &str = "inline-block max-w-full rounded-lg";
+
+/// Block quote.
+pub const QUOTE: &str = "flex flex-col gap-2 border-l-4 border-[var(--color-accent-primary)]/50 \
+     pl-4 text-[var(--color-label-secondary)]";
+
+/// Bullet list.
+pub const LIST_BULLET: &str = "list-disc pl-6 flex flex-col gap-1 text-[var(--color-label-primary)] \
+     marker:text-[var(--color-label-secondary)]";
+
+/// Ordered list.
+pub const LIST_ORDERED: &str = "list-decimal pl-6 flex flex-col gap-1 text-[var(--color-label-primary)] \
+     marker:text-[var(--color-label-secondary)]";
+
+/// Task list (no bullets - the checkbox is the marker).
+pub const LIST_TASK: &str = "pl-1 flex flex-col gap-1 text-[var(--color-label-primary)]";
+
+/// List item wrapper.
+pub const LIST_ITEM: &str = "flex flex-col gap-1";
+
+/// Task list item - checkbox beside the content.
+pub const TASK_ITEM: &str = "flex items-start gap-2 list-none";
+
+/// Checkbox in a task item.
+pub const TASK_CHECKBOX: &str = "mt-1 shrink-0";
+
+/// Sanitized raw HTML block.
+pub const HTML_BLOCK: &str = "text-base leading-relaxed text-[var(--color-label-primary)] \
+     [&_a]:text-[var(--color-accent-primary)] [&_a]:underline";
+
+/// Table cells drop the grid's fixed layout so columns size to content.
+pub const TABLE_AUTO: &str = "!table-auto";
+
+/// All style tokens for playground introspection.
+pub fn catalog() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("MARKDOWN", MARKDOWN),
+        ("HEADING_MINOR", HEADING_MINOR),
+        ("TIGHT_PARAGRAPH", TIGHT_PARAGRAPH),
+        ("INLINE_CODE", INLINE_CODE),
+        ("STRONG", STRONG),
+        ("STRIKE", STRIKE),
+        ("IMAGE", IMAGE),
+        ("QUOTE", QUOTE),
+        ("LIST_BULLET", LIST_BULLET),
+        ("LIST_ORDERED", LIST_ORDERED),
+        ("LIST_TASK", LIST_TASK),
+        ("LIST_ITEM", LIST_ITEM),
+        ("TASK_ITEM", TASK_ITEM),
+        ("TASK_CHECKBOX", TASK_CHECKBOX),
+        ("HTML_BLOCK", HTML_BLOCK),
+        ("TABLE_AUTO", TABLE_AUTO),
+    ]
+}
@@ -0,0 +1,573 @@
+//! Menu items and the menu list shared by EqDropdown and EqContextMenu.
+//!
+//! A [`MenuItem`] is an action, a checkbox or radio item, a separator,
+//! or a submenu holding its own items. Items can carry a leading
+//! [`EqIcon`](crate::atoms::EqIcon) path and a shortcut hint.
+//!
+//! Menus fire `on_select` with the item's `id`; checkable items are
+//! controlled. Apply the selection with [`toggle_item`] (or your own
+//! state) and pass the updated items back:
+//!
+//! ```no_run
+//! use eq_ui::prelude::*;
+//! use eq_ui::molecules::{toggle_item, EqContextMenu, MenuItem};
+//!
+//! fn app() -> Element {
+//!     let mut items = use_signal(|| vec![
+//!         MenuItem::new("copy", "Copy").shortcut("Ctrl+C"),
+//!         MenuItem::separator(),
+//!         MenuItem::new("grid", "Show grid").checkbox(true),
+//!         MenuItem::new("sort", "Sort by").submenu(vec![
+//!             MenuItem::new("name", "Name").radio("sort", true),
+//!             MenuItem::new("date", "Date").radio("sort", false),
+//!         ]),
+//!     ]);
+//!
+//!     rsx! {
+//!         EqContextMenu {
+//!             items: items(),
+//!             on_select: move |id: String| {
+//!                 toggle_item(&mut items.write(), &id);
+//!             },
+//!             div { "Right-click me" }
+//!         }
+//!     }
+//! }
+//! ```
+
+use super::eq_menu_styles as s;
+use crate::atoms::{eq_icon_paths, EqIcon, IconSize};
+use crate::floating::{use_floating, Align, FloatingOptions, Placement, Side};
+use crate::overlay::{EvalFocusDom, FocusDom, FocusTarget};
+use dioxus::prelude::*;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// ── Types ─────────────────────────────────────────────────────────
+
+/// What selecting an item does.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub enum MenuItemKind {
+    /// Plain action (default).
+    #[default]
+    Action,
+    /// Toggles on and off.
+    Checkbox { checked: bool },
+    /// One of a group of mutually exclusive items. Groups are scoped to
+    /// the items of one menu level.
+    Radio { group: String, checked: bool },
+}
+
+/// A single item (or separator) in a menu.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MenuItem {
+    /// Unique identifier returned on selection. Empty for separators.
+    pub id: String,
+    /// Display label. Empty for separators.
+    pub label: String,
+    /// Whether this item is disabled.
+    pub disabled: bool,
+    /// Whether this entry is a visual separator.
+    pub is_separator: bool,
+    /// SVG path data for a leading icon.
+    pub icon: Option<String>,
+    /// Shortcut hint shown on the right. Display only - bind the keys
+    /// yourself.
+    pub shortcut: Option<String>,
+    /// Action, checkbox or radio item.
+    pub kind: MenuItemKind,
+    /// Submenu items. An item with children opens them instead of
+    /// being selected.
+    pub children: Vec<MenuItem>,
+}
+
+impl MenuItem {
+    /// Create a selectable item.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            disabled: false,
+            is_separator: false,
+            icon: None,
+            shortcut: None,
+            kind: MenuItemKind::Action,
+            children: Vec::new(),
+        }
+    }
+
+    /// Create a separator line.
+    pub fn separator() -> Self {
+        Self { is_separator: true, ..Self::new("", "") }
+    }
+
+    /// Builder: mark this item as disabled.
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+
+    /// Builder: leading icon (SVG path data, e.g. from
+    /// [`eq_icon_paths`]).
+    pub fn icon(mut self, path: impl Into<String>) -> Self {
+        self.icon = Some(path.into());
+        self
+    }
+
+    /// Builder: shortcut hint such as `"Ctrl+C"`.
+    pub fn shortcut(mut self, keys: impl Into<String>) -> Self {
+        self.shortcut = Some(keys.into());
+        self
+    }
+
+    /// Builder: make this a checkbox item.
+    pub fn checkbox(mut self, checked: bool) -> Self {
+        self.kind = MenuItemKind::Checkbox { checked };
+        self
+    }
+
+    /// Builder: make this a radio item in `group`.
+    pub fn radio(mut self, group: impl Into<String>, checked: bool) -> Self {
+        self.kind = MenuItemKind::Radio { group: group.into(), checked };
+        self
+    }
+
+    /// Builder: open `items` as a submenu.
+    pub fn submenu(mut self, items: Vec<MenuItem>) -> Self {
+        self.children = items;
+        self
+    }
+
+    /// Whether this item opens a submenu.
+    pub fn has_submenu(&self) -> bool {
+        !self.children.is_empty()
+    }
+
+    /// Whether this is a checked checkbox or radio item.
+    pub fn is_checked(&self) -> bool {
+        matches!(
+            self.kind,
+            MenuItemKind::Checkbox { checked: true } | MenuItemKind::Radio { checked: true, .. }
+        )
+    }
+
+    fn is_checkable(&self) -> bool {
+        self.kind != MenuItemKind::Action
+    }
+
+    fn is_focusable(&self) -> bool {
+        !self.is_separator && !self.disabled
+    }
+
+    fn role(&self) -> &'static str {
+        match self.kind {
+            MenuItemKind::Action => "menuitem",
+            MenuItemKind::Checkbox { .. } => "menuitemcheckbox",
+            MenuItemKind::Radio { .. } => "menuitemradio",
+        }
+    }
+}
+
+/// Applies selecting `id` (searched through submenus) to checkable
+/// items: a checkbox flips, a radio item becomes the only checked item
+/// of its group. Returns whether anything changed.
+pub fn toggle_item(items: &mut [MenuItem], id: &str) -> bool {
+    let Some(pos) = items.iter().position(|it| it.id == id && !it.is_separator) else {
+        return items.iter_mut().any(|it| toggle_item(&mut it.children, id));
+    };
+    match items[pos].kind.clone() {
+        MenuItemKind::Action => false,
+        MenuItemKind::Checkbox { checked } => {
+            items[pos].kind = MenuItemKind::Checkbox { checked: !checked };
+            true
+        }
+        MenuItemKind::Radio { checked: true, .. } => false,
+        MenuItemKind::Radio { group, .. } => {
+            for item in items.iter_mut() {
+                if let MenuItemKind::Radio { group: g, checked } = &mut item.kind
+                    && *g == group
+                {
+                    *checked = item.id == id;
+                }
+            }
+            true
+        }
+    }
+}
+
+/// Next focusable item after `from` (before it when `!forward`),
+/// wrapping around. Starts at either end when nothing is focused.
+fn step_focus(items: &[MenuItem], from: Option<usize>, forward: bool) -> Option<usize> {
+    let focusable: Vec<usize> = (0..items.len()).filter(|&i| items[i].is_focusable()).collect();
+    let len = focusable.len();
+    let pos = from.and_then(|cur| focusable.iter().position(|&i| i == cur));
+    let next = match (pos, forward) {
+        (Some(p), true) => (p + 1) % len.max(1),
+        (Some(p), false) => (p + len - 1) % len.max(1),
+        (None, true) => 0,
+        (None, false) => len.saturating_sub(1),
+    };
+    focusable.get(next).copied()
+}
+
+/// Which item a menu list focuses when it mounts.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub(crate) enum MenuFocus {
+    /// Leave focus alone (submenus opened by hover).
+    #[default]
+    None,
+    /// The list itself; arrow keys then enter it.
+    Menu,
+    /// First focusable item.
+    First,
+    /// Last focusable item.
+    Last,
+}
+
+// ── Components ────────────────────────────────────────────────────
+
+/// A `role="menu"` list of items with roving focus.
+///
+/// Arrow keys, Home and End move between items; ArrowRight, Enter or
+/// Space open a submenu and ArrowLeft or Escape close it again. Hovering
+/// an item focuses it and opens its submenu. Escape in a top-level list
+/// is left to the surrounding portal.
+#[component]
+pub(crate) fn MenuList(
+    items: Vec<MenuItem>,
+    /// Item shown as selected.
+    #[props(default)]
+    selected: Option<String>,
+    /// An item without a submenu was activated.
+    on_select: EventHandler<String>,
+    /// Focus left the menu (Tab); the owner should close it.
+    on_close: EventHandler<()>,
+    /// Set on submenus: close this level and return to the parent item.
+    #[props(default)]
+    on_back: Option<EventHandler<()>>,
+    #[props(default)]
+    initial_focus: MenuFocus,
+    #[props(into, default)]
+    label: String,
+) -> Element {
+    let id = use_hook(|| {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        format!("eq-menu-{}", COUNTER.fetch_add(1, Ordering::Relaxed))
+    });
+    let mut focused = use_signal(|| match initial_focus {
+        MenuFocus::First => step_focus(&items, None, true),
+        MenuFocus::Last => step_focus(&items, None, false),
+        MenuFocus::None | MenuFocus::Menu => None,
+    });
+    // Open submenu: (item index, focus its first item).
+    let mut open_sub = use_signal(|| None::<(usize, bool)>);
+    let mut refs = use_signal(HashMap::<usize, MountedEvent>::new);
+
+    // The list may still be hidden while the floating engine measures
+    // it; the eval retries until focus lands.
+    use_hook(|| {
+        let target = match initial_focus {
+            MenuFocus::None => return,
+            MenuFocus::Menu => FocusTarget::Container,
+            // Only the focused item is tabbable (roving tabindex).
+            MenuFocus::First | MenuFocus::Last => FocusTarget::First,
+        };
+        EvalFocusDom.focus(&id, &target);
+    });
+
+    let mut move_to = move |idx: usize| {
+        focused.set(Some(idx));
+        if let Some(el) = refs.peek().get(&idx).cloned() {
+            spawn(async move {
+                let _ = el.set_focus(true).await;
+            });
+        }
+    };
+
+    let has_indicator = items.iter().any(MenuItem::is_checkable);
+    let key_items = items.clone();
+
+    rsx! {
+        div {
+            id: "{id}",
+            class: s::LIST,
+            role: "menu",
+            tabindex: "-1",
+            "aria-label": if !label.is_empty() { "{label}" },
+            onkeydown: move |evt: KeyboardEvent| {
+                let current = focused();
+                let next = match evt.key() {
+                    Key::ArrowDown => step_focus(&key_items, current, true),
+                    Key::ArrowUp => step_focus(&key_items, current, false),
+                    Key::Home => step_focus(&key_items, None, true),
+                    Key::End => step_focus(&key_items, None, false),
+                    Key::ArrowRight => {
+                        if let Some(idx) = current
+                            && key_items[idx].has_submenu()
+                        {
+                            evt.prevent_default();
+                            evt.stop_propagation();
+                            open_sub.set(Some((idx, true)));
+                        }
+                        return;
+                    }
+                    Key::ArrowLeft | Key::Escape => {
+                        if let Some(back) = on_back {
+                            evt.prevent_default();
+                            evt.stop_propagation();
+                            back.call(());
+                        }
+                        return;
+                    }
+                    Key::Tab => {
+                        on_close.call(());
+                        return;
+                    }
+                    _ => return,
+                };
+                evt.prevent_default();
+                evt.stop_propagation();
+                if let Some(idx) = next {
+                    move_to(idx);
+                }
+            },
+
+            for (idx , item) in items.iter().enumerate() {
+                if item.is_separator {
+                    div {
+                        key: "sep-{idx}",
+                        class: s::SEPARATOR,
+                        role: "separator",
+                    }
+                } else if item.has_submenu() {
+                    Submenu {
+                        key: "{item.id}",
+                        item: item.clone(),
+                        open: open_sub().map(|(i, _)| i) == Some(idx),
+                        focus_first: open_sub().is_some_and(|(i, f)| i == idx && f),
+                        tabbable: focused() == Some(idx),
+                        has_indicator,
+                        selected: selected.clone(),
+                        on_hover: move |_| {
+                            move_to(idx);
+                            open_sub.set(Some((idx, false)));
+                        },
+                        on_open: move |_| open_sub.set(Some((idx, true))),
+                        on_back: move |_| {
+                            open_sub.set(None);
+                            move_to(idx);
+                        },
+                        on_select,
+                        on_close,
+                        on_mounted: move |e| {
+                            refs.write().insert(idx, e);
+                        },
+                    }
+                } else {
+                    {
+                        let active = selected.as_ref() == Some(&item.id);
+                        let item_id = item.id.clone();
+                        rsx! {
+                            button {
+                                key: "{item.id}",
+                                class: item_class(item, active, false),
+                                r#type: "button",
+                                role: item.role(),
+                                "aria-checked": if item.is_checkable() { "{item.is_checked()}" },
+                                "aria-current": if active { "true" },
+                                "aria-disabled": if item.disabled { "true" } else { "false" },
+                                tabindex: if focused() == Some(idx) { "0" } else { "-1" },
+                                disabled: item.disabled,
+                                onmounted: move |e| {
+                                    refs.write().insert(idx, e);
+                                },
+                                onmouseenter: move |_| {
+                                    move_to(idx);
+                                    open_sub.set(None);
+                                },
+                                onclick: move |_| on_select.call(item_id.clone()),
+                                {item_body(item, has_indicator)}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// An item that opens a nested [`MenuList`] beside itself.
+#[component]
+fn Submenu(
+    item: MenuItem,
+    open: bool,
+    focus_first: bool,
+    tabbable: bool,
+    has_indicator: bool,
+    selected: Option<String>,
+    on_hover: EventHandler<()>,
+    on_open: EventHandler<()>,
+    on_back: EventHandler<()>,
+    on_select: EventHandler<String>,
+    on_close: EventHandler<()>,
+    on_mounted: EventHandler<MountedEvent>,
+) -> Element {
+    let mut floating = use_floating(open, FloatingOptions {
+        placement: Placement::new(Side::Right, Align::Start),
+        offset: 2.0,
+        ..Default::default()
+    });
+
+    rsx! {
+        div {
+            class: s::SUB_WRAPPER,
+            onmouseenter: move |_| on_hover.call(()),
+            button {
+                class: item_class(&item, false, open),
+                r#type: "button",
+                role: item.role(),
+                "aria-haspopup": "menu",
+                "aria-expanded": "{open}",
+                "aria-disabled": if item.disabled { "true" } else { "false" },
+                tabindex: if tabbable { "0" } else { "-1" },
+                disabled: item.disabled,
+                onmounted: move |e| {
+                    floating.set_anchor(e.clone());
+                    on_mounted.call(e);
+                },
+                onclick: move |_| on_open.call(()),
+                {item_body(&item, has_indicator)}
+            }
+            if open {
+                div {
+                    class: s::PANEL,
+                    style: floating.style(),
+                    onmounted: move |e| floating.set_floating(e),
+                    MenuList {
+                        items: item.children.clone(),
+                        selected,
+                        on_select,
+                        on_close,
+                        on_back,
+                        initial_focus: if focus_first { MenuFocus::First } else { MenuFocus::None },
+                        label: item.label.clone(),
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn item_class(item: &MenuItem, active: bool, open: bool) -> String {
+    let active_cls = if active { s::ITEM_ACTIVE } else { "" };
+    let open_cls = if open { s::ITEM_OPEN } else { "" };
+    let disabled_cls = if item.disabled { s::ITEM_DISABLED } else { "" };
+    format!("{} {active_cls} {open_cls} {disabled_cls}", s::ITEM)
+}
+
+/// Indicator, icon, label, and shortcut or submenu chevron.
+fn item_body(item: &MenuItem, has_indicator: bool) -> Element {
+    rsx! {
+        if has_indicator {
+            span { class: s::INDICATOR, "aria-hidden": "true",
+                {match item.kind {
+                    MenuItemKind::Checkbox { checked: true } => rsx! {
+                        EqIcon { path: eq_icon_paths::CHECK, size: IconSize::Sm }
+                    },
+                    MenuItemKind::Radio { checked: true, .. } => rsx! {
+                        span { class: s::RADIO_DOT }
+                    },
+                    _ => rsx! {},
+                }}
+            }
+        }
+        if let Some(icon) = &item.icon {
+            EqIcon { path: icon.clone(), size: IconSize::Sm, class: s::ICON }
+        }
+        span { class: s::LABEL, "{item.label}" }
+        if item.has_submenu() {
+            EqIcon { path: eq_icon_paths::CARET_RIGHT, size: IconSize::Sm, muted: true, class: s::SUB_CHEVRON }
+        } else if let Some(keys) = &item.shortcut {
+            kbd { class: s::SHORTCUT, "{keys}" }
+        }
+    }
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Vec<MenuItem> {
+        vec![
+            MenuItem::new("cut", "Cut").disabled(),
+            MenuItem::new("copy", "Copy").shortcut("Ctrl+C"),
+            MenuItem::separator(),
+            MenuItem::new("grid", "Show grid").checkbox(false),
+            MenuItem::new("sort", "Sort by").submenu(vec![
+                MenuItem::new("name", "Name").radio("sort", true),
+                MenuItem::new("date", "Date").radio("sort", false),
+                MenuItem::new("size", "Size").radio("sort", false),
+            ]),
+        ]
+    }
+
+    #[test]
+    fn step_focus_skips_separators_and_disabled() {
+        let items = sample();
+        assert_eq!(step_focus(&items, None, true), Some(1));
+        assert_eq!(step_focus(&items, None, false), Some(4));
+        assert_eq!(step_focus(&items, Some(1), true), Some(3));
+        assert_eq!(step_focus(&items, Some(4), true), Some(1));
+        assert_eq!(step_focus(&items, Some(1), false), Some(4));
+        assert_eq!(step_focus(&[MenuItem::separator()], None, true), None);
+    }
+
+    #[test]
+    fn toggle_flips_checkboxes() {
+        let mut items = sample();
+        assert!(toggle_item(&mut items, "grid"));
+        assert!(items[3].is_checked());
+        assert!(toggle_item(&mut items, "grid"));
+        assert!(!items[3].is_checked());
+        assert!(!toggle_item(&mut items, "copy"));
+        assert!(!toggle_item(&mut items, "missing"));
+    }
+
+    #[test]
+    fn toggle_checks_one_radio_per_group_in_submenus() {
+        let mut items = sample();
+        assert!(toggle_item(&mut items, "date"));
+        let checked: Vec<&str> = items[4]
+            .children
+            .iter()
+            .filter(|it| it.is_checked())
+            .map(|it| it.id.as_str())
+            .collect();
+        assert_eq!(checked, ["date"]);
+        assert!(!toggle_item(&mut items, "date"));
+    }
+
+    #[test]
+    fn roles_follow_kind() {
+        let items = sample();
+        assert_eq!(items[1].role(), "menuitem");
+        assert_eq!(items[3].role(), "menuitemcheckbox");
+        assert_eq!(items[4].children[0].role(), "menuitemradio");
+    }
+
+    #[test]
+    fn smoke_renders_list() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                MenuList {
+                    items: sample(),
+                    on_select: |_| {},
+                    on_close: |_| {},
+                }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+}
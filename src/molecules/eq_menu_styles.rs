@@ -0,0 +1,78 @@
+//! Style constants for menu lists (EqDropdown, EqContextMenu).
+
+/// A menu list - the `role="menu"` container.
+pub const LIST: &str = "flex flex-col focus:outline-none";
+
+/// Submenu panel. The static placement is used until the floating
+/// engine has measured the parent item.
+pub const PANEL: &str =
+    "absolute left-full top-0 z-50 min-w-[10rem] rounded-md py-1 \
+     bg-[var(--color-card)] \
+     border border-[var(--color-card-border)] \
+     shadow-2xl shadow-black/40";
+
+/// Wrapper around an item that opens a submenu.
+pub const SUB_WRAPPER: &str = "relative";
+
+/// A single menu item.
+pub const ITEM: &str =
+    "flex w-full items-center gap-2 px-3 py-2 text-left text-sm cursor-pointer \
+     text-[var(--color-label-primary)] \
+     hover:bg-[var(--color-accent-primary)]/10 \
+     focus:bg-[var(--color-accent-primary)]/10 \
+     focus:outline-none transition-colors duration-100";
+
+/// Active / selected item.
+pub const ITEM_ACTIVE: &str =
+    "bg-[var(--color-accent-primary)]/10 font-medium";
+
+/// Item whose submenu is open.
+pub const ITEM_OPEN: &str = "bg-[var(--color-accent-primary)]/10";
+
+/// Disabled item.
+pub const ITEM_DISABLED: &str =
+    "opacity-40 cursor-not-allowed pointer-events-none";
+
+/// Check / radio indicator slot, reserved on every item of a list
+/// that has checkable items so labels stay aligned.
+pub const INDICATOR: &str = "inline-flex size-4 shrink-0 items-center justify-center";
+
+/// Dot shown in a checked radio item.
+pub const RADIO_DOT: &str = "size-2 rounded-full bg-current";
+
+/// Leading icon.
+pub const ICON: &str = "shrink-0 text-[var(--color-label-secondary)]";
+
+/// Item label.
+pub const LABEL: &str = "flex-1 truncate";
+
+/// Shortcut hint on the right.
+pub const SHORTCUT: &str =
+    "ml-6 text-xs tracking-wide text-[var(--color-label-secondary)]";
+
+/// Chevron on items that open a submenu.
+pub const SUB_CHEVRON: &str = "ml-4 shrink-0";
+
+/// Separator between item groups.
+pub const SEPARATOR: &str =
+    "my-1 border-t border-[var(--color-card-border)]";
+
+/// All style tokens for playground introspection.
+pub fn catalog() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("LIST", LIST),
+        ("PANEL", PANEL),
+        ("SUB_WRAPPER", SUB_WRAPPER),
+        ("ITEM", ITEM),
+        ("ITEM_ACTIVE", ITEM_ACTIVE),
+        ("ITEM_OPEN", ITEM_OPEN),
+        ("ITEM_DISABLED", ITEM_DISABLED),
+        ("INDICATOR", INDICATOR),
+        ("RADIO_DOT", RADIO_DOT),
+        ("ICON", ICON),
+        ("LABEL", LABEL),
+        ("SHORTCUT", SHORTCUT),
+        ("SUB_CHEVRON", SUB_CHEVRON),
+        ("SEPARATOR", SEPARATOR),
+    ]
+}
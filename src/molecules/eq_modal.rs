@@ -7,7 +7,8 @@
 //!
 //! **Accessibility** — implements the WAI-ARIA [Dialog (Modal)][dlg]
 //! pattern: `role="dialog"`, `aria-modal="true"`, `aria-labelledby`
-//! linking to the title, focus trap via JS, and Escape to close.
+//! linking to the title, a focus trap with an inert background
+//! (see [`crate::overlay::focus_trap`]), and Escape to close.
 //!
 //! [dlg]: https://www.w3.org/WAI/ARIA/apg/patterns/dialog-modal/
 //!
@@ -36,16 +37,18 @@
 //! ```
 
 use super::eq_modal_styles as s;
+use crate::overlay::{EqFocusGuard, EqPortal, FocusEdge, FocusTarget, use_focus_trap};
 use crate::theme::merge_classes;
 use crate::{PlaygroundEnum, playground};
-use dioxus::document;
 use dioxus::prelude::*;
 
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropSelect, PropToggle, StyleInfo, format_catalog,
+    DemoSection, PropSelect, PropToggle, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant, EqButton, ButtonVariant};
 #[cfg(feature = "playground")]
 use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
@@ -111,16 +114,19 @@ pub fn EqModal(
     /// Close when the user presses Escape.
     #[props(default = true)]
     close_on_escape: bool,
+    /// Element focused when the modal opens. Defaults to the first
+    /// tabbable element; `FocusTarget::Container` focuses the panel.
+    #[props(default)]
+    initial_focus: FocusTarget,
     /// Optional class override on the panel element.
     #[props(into, default)]
     class: String,
 ) -> Element {
     // Stable unique ID for aria-labelledby linking.
-    let modal_id = use_hook(|| {
-        static COUNTER: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
-        let id = COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
-        format!("eq-modal-{id}")
-    });
+    // Focus trap: inert background, initial focus, Tab wrapping. Its
+    // container id doubles as the stable id for aria-labelledby.
+    let trap = use_focus_trap(open, initial_focus);
+    let modal_id = trap.id();
 
     let title_id = format!("{}-title", modal_id);
     let has_title = !title.is_empty();
@@ -141,95 +147,87 @@ pub fn EqModal(
     let panel_base = format!("{} {}", s::PANEL, size_cls);
     let panel_cls = merge_classes(&panel_base, &class);
 
-    // Focus trap: when opened, focus the panel; when pressing Tab,
-    // cycle within the dialog.
-    let panel_id_focus = modal_id.clone();
-    use_effect(move || {
-        if open {
-            // Focus the dialog panel itself so keyboard events are captured.
-            let dom_id = panel_id_focus.to_string();
-            document::eval(&format!(
-                "setTimeout(() => document.getElementById('{dom_id}')?.focus(), 50)"
-            ));
-        }
-    });
+    let on_escape = close_on_escape.then_some(on_close);
 
     rsx! {
-        div {
-            class: "{s::BACKDROP} {backdrop_anim}",
-            // Backdrop click → close
-            onclick: move |_| {
-                if close_on_backdrop {
-                    on_close.call(());
-                }
-            },
+        EqPortal {
+            active: open,
+            lock_scroll: true,
+            restore_focus: true,
+            on_escape,
 
             div {
-                id: "{modal_id}",
-                class: "{panel_cls} {panel_anim}",
-                role: "dialog",
-                "aria-modal": "true",
-                "aria-labelledby": if has_title { "{title_id}" } else { "" },
-                tabindex: "-1",
-
-                // Stop clicks inside the panel from bubbling to the backdrop
-                onclick: move |evt| { evt.stop_propagation(); },
-
-                // Escape key
-                onkeydown: move |evt: Event<KeyboardData>| {
-                    if close_on_escape && evt.key() == Key::Escape {
-                        evt.prevent_default();
+                class: "{s::BACKDROP} {backdrop_anim}",
+                // Backdrop click → close
+                onclick: move |_| {
+                    if close_on_backdrop {
                         on_close.call(());
                     }
                 },
 
-                // Header
-                if show_header {
-                    div { class: "{s::HEADER}",
-                        if has_title {
-                            h2 {
-                                id: "{title_id}",
-                                class: "{s::HEADER_TITLE}",
-                                "{title}"
+                EqFocusGuard { trap, edge: FocusEdge::Start }
+
+                div {
+                    id: "{modal_id}",
+                    class: "{panel_cls} {panel_anim}",
+                    role: "dialog",
+                    "aria-modal": "true",
+                    "aria-labelledby": if has_title { "{title_id}" } else { "" },
+                    tabindex: "-1",
+
+                    // Stop clicks inside the panel from bubbling to the backdrop
+                    onclick: move |evt| { evt.stop_propagation(); },
+
+                    // Header
+                    if show_header {
+                        div { class: "{s::HEADER}",
+                            if has_title {
+                                h2 {
+                                    id: "{title_id}",
+                                    class: "{s::HEADER_TITLE}",
+                                    "{title}"
+                                }
+                            } else {
+                                // Spacer so close button stays on the right
+                                div {}
                             }
-                        } else {
-                            // Spacer so close button stays on the right
-                            div {}
-                        }
-                        if show_close {
-                            button {
-                                class: "{s::CLOSE_BUTTON}",
-                                "aria-label": "Close dialog",
-                                onclick: move |_| on_close.call(()),
-                                svg {
-                                    xmlns: "http://www.w3.org/2000/svg",
-                                    fill: "none",
-                                    view_box: "0 0 24 24",
-                                    stroke_width: "2",
-                                    stroke: "currentColor",
-                                    width: "20",
-                                    height: "20",
-                                    "aria-hidden": "true",
-                                    path { d: "M6 18 18 6M6 6l12 12" }
+                            if show_close {
+                                button {
+                                    class: "{s::CLOSE_BUTTON}",
+                                    "aria-label": "Close dialog",
+                                    onclick: move |_| on_close.call(()),
+                                    svg {
+                                        xmlns: "http://www.w3.org/2000/svg",
+                                        fill: "none",
+                                        view_box: "0 0 24 24",
+                                        stroke_width: "2",
+                                        stroke: "currentColor",
+                                        width: "20",
+                                        height: "20",
+                                        "aria-hidden": "true",
+                                        path { d: "M6 18 18 6M6 6l12 12" }
+                                    }
                                 }
                             }
                         }
                     }
-                }
 
-                // Body
-                if let Some(body_content) = body {
-                    div { class: "{s::BODY}",
-                        {body_content}
+                    // Body
+                    if let Some(body_content) = body {
+                        div { class: "{s::BODY}",
+                            {body_content}
+                        }
                     }
-                }
 
-                // Footer
-                if let Some(footer_content) = footer {
-                    div { class: "{s::FOOTER}",
-                        {footer_content}
+                    // Footer
+                    if let Some(footer_content) = footer {
+                        div { class: "{s::FOOTER}",
+                            {footer_content}
+                        }
                     }
                 }
+
+                EqFocusGuard { trap, edge: FocusEdge::End }
             }
         }
     }
@@ -245,6 +243,13 @@ fn DemoEqModal() -> Element {
     let mut show_close = use_signal(|| true);
     let mut close_on_backdrop = use_signal(|| true);
     let mut close_on_escape = use_signal(|| true);
+    let mut focus_str = use_signal(|| "First".to_string());
+
+    let initial_focus = match focus_str().as_str() {
+        "Container" => FocusTarget::Container,
+        "Last" => FocusTarget::Last,
+        _ => FocusTarget::First,
+    };
 
     let size = match size_str().as_str() {
         "Sm" => ModalSize::Sm,
@@ -311,6 +316,12 @@ EqModal {
                         value: close_on_escape(),
                         onchange: move |v: bool| close_on_escape.set(v),
                     }
+                    PropSelect {
+                        label: "initial_focus",
+                        value: focus_str(),
+                        options: vec!["First", "Last", "Container"],
+                        onchange: move |v: String| focus_str.set(v),
+                    }
                 }
             }
 
@@ -330,10 +341,11 @@ EqModal {
                 show_close: show_close(),
                 close_on_backdrop: close_on_backdrop(),
                 close_on_escape: close_on_escape(),
+                initial_focus,
                 body: rsx! {
                     p { "Are you sure you want to proceed? This action may have consequences." }
                     p { class: "mt-3 text-[var(--color-label-secondary)]",
-                        "This is a live modal dialog. Try pressing Escape, clicking the backdrop, or using the close button to dismiss it."
+                        "This is a live modal dialog. Tab cycles within it while the page behind is inert. Try pressing Escape, clicking the backdrop, or using the close button to dismiss it."
                     }
                 },
                 footer: rsx! {
@@ -351,7 +363,7 @@ EqModal {
             }
 
             StyleInfo { file: "eq_modal_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
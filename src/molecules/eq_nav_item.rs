@@ -8,9 +8,11 @@ use crate::{PlaygroundEnum, playground};
 use crate::atoms::eq_icon_paths;
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropInput, PropToggle, StyleInfo, format_catalog,
+    DemoSection, PropInput, PropToggle, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
 #[cfg(feature = "playground")]
 use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
@@ -254,7 +256,7 @@ li { EqNavItem { icon: eq_icon_paths::MAGNIFYING_GLASS, label: "Search", href: "
                 }
             }
             StyleInfo { file: "eq_nav_item_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
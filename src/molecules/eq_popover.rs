@@ -0,0 +1,437 @@
+//! EqPopover — click-triggered popup with interactive content.
+//!
+//! Unlike [`EqTooltip`](crate::atoms::EqTooltip), the panel can hold
+//! buttons, links and form fields. Opening moves focus into it;
+//! Escape or a click outside closes it and returns focus to the
+//! trigger. Placement uses the shared [`floating`](crate::floating)
+//! engine. Open state is either owned by the popover or controlled by
+//! the parent through `open` + `on_open_change`.
+//!
+//! ```no_run
+//! use eq_ui::prelude::*;
+//! use eq_ui::molecules::EqPopover;
+//!
+//! let _: Element = rsx! {
+//!     EqPopover {
+//!         trigger: rsx! { "Share" },
+//!         label: "Share",
+//!         div { class: "space-y-2",
+//!             p { "Anyone with the link can view." }
+//!             button { "Copy link" }
+//!         }
+//!     }
+//! };
+//! ```
+
+use super::eq_popover_styles as s;
+use crate::floating::{use_floating, Align, FloatingOptions, Placement, Side};
+use crate::overlay::{EqFocusGuard, EqPortal, EvalFocusDom, FocusDom, FocusEdge, FocusTarget, use_focus_trap};
+use crate::theme::merge_classes;
+use crate::{PlaygroundEnum, playground};
+use dioxus::prelude::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[cfg(feature = "playground")]
+use crate::playground::playground_helpers::{
+    DemoSection, PropSelect, PropToggle, StyleInfo, format_catalog,
+};
+#[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
+use crate::atoms::{EqButton, ButtonVariant, EqInput, EqText, TextVariant};
+#[cfg(feature = "playground")]
+use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
+
+// ── Types ─────────────────────────────────────────────────────────
+
+/// Where a popover or hover card prefers to open relative to its
+/// trigger. It flips to the opposite side when there is no room.
+#[derive(Clone, Copy, PartialEq, Default, PlaygroundEnum)]
+pub enum PopoverPosition {
+    /// Above the trigger.
+    Top,
+    /// Below the trigger (default).
+    #[default]
+    Bottom,
+    /// Left of the trigger.
+    Left,
+    /// Right of the trigger.
+    Right,
+}
+
+impl From<PopoverPosition> for Placement {
+    fn from(position: PopoverPosition) -> Self {
+        let side = match position {
+            PopoverPosition::Top => Side::Top,
+            PopoverPosition::Bottom => Side::Bottom,
+            PopoverPosition::Left => Side::Left,
+            PopoverPosition::Right => Side::Right,
+        };
+        Placement::new(side, Align::Center)
+    }
+}
+
+/// Open state that either the component owns (uncontrolled) or the
+/// parent passes in (controlled). Shared by EqPopover and EqHoverCard.
+#[derive(Clone, Copy)]
+pub(crate) struct OpenState {
+    own: Signal<bool>,
+    controlled: Option<bool>,
+    on_change: Option<EventHandler<bool>>,
+}
+
+impl OpenState {
+    pub(crate) fn get(&self) -> bool {
+        self.controlled.unwrap_or_else(|| (self.own)())
+    }
+
+    /// Request a change. Uncontrolled state updates itself; either way
+    /// `on_change` hears about it.
+    pub(crate) fn set(&mut self, open: bool) {
+        let current = self.controlled.unwrap_or_else(|| *self.own.peek());
+        if current == open {
+            return;
+        }
+        if self.controlled.is_none() {
+            self.own.set(open);
+        }
+        if let Some(handler) = self.on_change {
+            handler.call(open);
+        }
+    }
+}
+
+pub(crate) fn use_open_state(
+    open: Option<bool>,
+    default_open: bool,
+    on_change: Option<EventHandler<bool>>,
+) -> OpenState {
+    let own = use_signal(|| default_open);
+    OpenState { own, controlled: open, on_change }
+}
+
+// ── Component ─────────────────────────────────────────────────────
+
+/// Click-triggered popover.
+///
+/// `trigger` is rendered inside the popover's own `button`, so pass
+/// text or an icon rather than another button. `position` is a
+/// preference: the panel flips and shifts to stay in the viewport.
+///
+/// By default the popover is non-modal: focus moves to
+/// `initial_focus` inside the panel, but the page stays interactive
+/// and clicking elsewhere closes it. With `modal` the panel also traps
+/// Tab and makes the background inert.
+///
+/// **Accessibility** — the trigger carries `aria-haspopup="dialog"`,
+/// `aria-expanded` and `aria-controls`; the panel is `role="dialog"`
+/// labelled by `label`. Escape closes and returns focus to the
+/// trigger.
+#[playground(
+    category = Molecule,
+    description = "Click-triggered popover for interactive content: focus moves in, \
+                   Escape / outside click close and return focus, optional modal trap, \
+                   viewport-aware placement, controlled or uncontrolled.",
+    examples = [
+        ("Basic", "EqPopover {\n    trigger: rsx! { \"Share\" },\n    label: \"Share\",\n    p { \"Anyone with the link can view.\" }\n    EqButton { on_click: move |_| copy(), \"Copy link\" }\n}"),
+        ("Controlled", "let mut open = use_signal(|| false);\n\nEqPopover {\n    trigger: rsx! { \"Filters\" },\n    open: open(),\n    on_open_change: move |v: bool| open.set(v),\n    EqButton { on_click: move |_| open.set(false), \"Apply\" }\n}"),
+        ("Modal", "EqPopover {\n    trigger: rsx! { \"Rename\" },\n    modal: true,\n    position: PopoverPosition::Right,\n    EqInput { value: name(), oninput: move |e: FormEvent| name.set(e.value()) }\n}"),
+    ],
+    custom_demo,
+    custom_gallery,
+)]
+#[component]
+pub fn EqPopover(
+    /// Content of the trigger button.
+    trigger: Element,
+    /// Controlled open state. Leave unset to let the popover manage it.
+    #[props(into, default)]
+    open: Option<bool>,
+    /// Initial open state when uncontrolled.
+    #[props(default = false)]
+    default_open: bool,
+    /// Fired when the popover asks to open or close.
+    #[props(default)]
+    on_open_change: Option<EventHandler<bool>>,
+    /// Preferred side relative to the trigger.
+    #[props(default)]
+    position: PopoverPosition,
+    /// Show an arrow pointing at the trigger.
+    #[props(default = false)]
+    arrow: bool,
+    /// Trap focus inside the panel and make the background inert.
+    #[props(default = false)]
+    modal: bool,
+    /// Where focus goes when the popover opens.
+    #[props(default)]
+    initial_focus: FocusTarget,
+    /// Accessible name for the panel.
+    #[props(into, default)]
+    label: String,
+    /// Optional class override on the panel.
+    #[props(into, default)]
+    class: String,
+    /// Panel content.
+    children: Element,
+) -> Element {
+    let mut state = use_open_state(open, default_open, on_open_change);
+    let is_open = state.get();
+    let mut floating = use_floating(is_open, FloatingOptions {
+        placement: position.into(),
+        offset: 8.0,
+        arrow_padding: arrow.then_some(10.0),
+        ..Default::default()
+    });
+
+    // The trap's id doubles as the panel id; it only traps when modal.
+    let trap = use_focus_trap(is_open && modal, initial_focus.clone());
+    let panel_id = trap.id();
+
+    // Non-modal popovers still move focus in on open.
+    let was_open = use_hook(|| Rc::new(Cell::new(false)));
+    if is_open != was_open.get() {
+        was_open.set(is_open);
+        if is_open && !modal {
+            EvalFocusDom.focus(&panel_id, &initial_focus);
+        }
+    }
+
+    let pos_cls = match position {
+        PopoverPosition::Top => s::POS_TOP,
+        PopoverPosition::Bottom => s::POS_BOTTOM,
+        PopoverPosition::Left => s::POS_LEFT,
+        PopoverPosition::Right => s::POS_RIGHT,
+    };
+    let panel_state = if is_open { s::PANEL_OPEN } else { s::PANEL_CLOSED };
+    let panel_cls = merge_classes(&format!("{} {pos_cls} {panel_state}", s::PANEL), &class);
+
+    rsx! {
+        div { class: s::WRAPPER,
+            button {
+                class: s::TRIGGER,
+                r#type: "button",
+                "aria-haspopup": "dialog",
+                "aria-expanded": "{is_open}",
+                "aria-controls": "{panel_id}",
+                onmounted: move |e| floating.set_anchor(e),
+                onclick: move |_| state.set(!is_open),
+                {trigger}
+            }
+
+            EqPortal {
+                active: is_open,
+                restore_focus: true,
+                on_escape: move |_| state.set(false),
+                on_outside_click: move |_| state.set(false),
+
+                EqFocusGuard { trap, edge: FocusEdge::Start }
+                div {
+                    id: "{panel_id}",
+                    class: "{panel_cls}",
+                    style: floating.style(),
+                    role: "dialog",
+                    "aria-modal": if modal { "true" },
+                    "aria-label": if !label.is_empty() { "{label}" },
+                    tabindex: "-1",
+                    onmounted: move |e| floating.set_floating(e),
+                    {children}
+                    if arrow {
+                        span { class: s::ARROW, style: floating.arrow_style(), "aria-hidden": "true" }
+                    }
+                }
+                EqFocusGuard { trap, edge: FocusEdge::End }
+            }
+        }
+    }
+}
+
+// ── Interactive demo ───────────────────────────────────────────────
+
+#[cfg(feature = "playground")]
+#[component]
+fn DemoEqPopover() -> Element {
+    let mut position_str = use_signal(|| "Bottom".to_string());
+    let mut arrow = use_signal(|| true);
+    let mut modal = use_signal(|| false);
+    let mut controlled = use_signal(|| false);
+    let mut name = use_signal(|| "Quarterly report".to_string());
+    let mut saved = use_signal(|| "Quarterly report".to_string());
+
+    let position = match position_str().as_str() {
+        "Top" => PopoverPosition::Top,
+        "Left" => PopoverPosition::Left,
+        "Right" => PopoverPosition::Right,
+        _ => PopoverPosition::Bottom,
+    };
+
+    let code = format!(
+        r#"let mut open = use_signal(|| false);
+
+EqPopover {{
+    trigger: rsx! {{ "Rename" }},
+    label: "Rename",
+    open: open(),
+    on_open_change: move |v: bool| open.set(v),
+    position: PopoverPosition::{pos},
+    arrow: {arrow},
+    modal: {modal},
+    EqInput {{ value: name(), oninput: move |e: FormEvent| name.set(e.value()) }}
+    EqButton {{ on_click: move |_| {{ save(); open.set(false); }}, "Save" }}
+}}"#,
+        pos = position_str(),
+        arrow = arrow(),
+        modal = modal(),
+    );
+
+    rsx! {
+        DemoSection { title: "EqPopover",
+            div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-3",
+                EqText {
+                    variant: TextVariant::Caption,
+                    class: "font-semibold uppercase tracking-wider",
+                    "Props"
+                }
+                PropSelect {
+                    label: "position",
+                    value: position_str(),
+                    options: vec!["Top", "Bottom", "Left", "Right"],
+                    onchange: move |v: String| position_str.set(v),
+                }
+                PropToggle { label: "arrow", value: arrow(), onchange: move |v: bool| arrow.set(v) }
+                PropToggle { label: "modal", value: modal(), onchange: move |v: bool| modal.set(v) }
+            }
+
+            div { class: "rounded-lg border border-dashed border-[var(--color-card-border)] p-6 space-y-3",
+                div { class: "flex items-center justify-center gap-4 py-8",
+                    EqPopover {
+                        trigger: rsx! { EqText { "Rename \u{201c}{saved}\u{201d}" } },
+                        label: "Rename",
+                        open: controlled(),
+                        on_open_change: move |v: bool| controlled.set(v),
+                        position,
+                        arrow: arrow(),
+                        modal: modal(),
+                        div { class: "space-y-3",
+                            EqInput { value: name(), oninput: move |e: FormEvent| name.set(e.value()) }
+                            div { class: "flex justify-end gap-2",
+                                EqButton {
+                                    variant: ButtonVariant::Ghost,
+                                    on_click: move |_| controlled.set(false),
+                                    "Cancel"
+                                }
+                                EqButton {
+                                    on_click: move |_| {
+                                        saved.set(name());
+                                        controlled.set(false);
+                                    },
+                                    "Save"
+                                }
+                            }
+                        }
+                    }
+                }
+                EqText { variant: TextVariant::Muted, "Controlled: open = {controlled}" }
+            }
+
+            StyleInfo { file: "eq_popover_styles.rs", styles: format_catalog(&s::catalog()) }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
+        }
+    }
+}
+
+// ── Gallery ───────────────────────────────────────────────────────
+
+#[cfg(feature = "playground")]
+#[component]
+fn GalleryEqPopover() -> Element {
+    rsx! {
+        div { class: "space-y-4",
+            div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-4",
+                EqText { variant: TextVariant::Caption, class: "font-semibold uppercase tracking-wider", "Popover Gallery" }
+
+                div { class: "flex items-center justify-center gap-6 py-6",
+                    EqPopover {
+                        trigger: rsx! { EqText { "Share" } },
+                        label: "Share",
+                        arrow: true,
+                        div { class: "space-y-2",
+                            EqText { variant: TextVariant::Muted, "Anyone with the link can view." }
+                            EqButton { on_click: move |_| {}, "Copy link" }
+                        }
+                    }
+                    EqPopover {
+                        trigger: rsx! { EqText { "Details" } },
+                        label: "Details",
+                        position: PopoverPosition::Right,
+                        initial_focus: FocusTarget::Container,
+                        EqText { "Created 3 days ago by the build bot." }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── Smoke tests ─────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smoke_renders() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                EqPopover {
+                    trigger: rsx! { "Open" },
+                    label: "Details",
+                    button { "Inside" }
+                }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn smoke_renders_open_modal_in_overlay_root() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                crate::overlay::EqOverlayRoot {
+                    EqPopover {
+                        trigger: rsx! { "Open" },
+                        default_open: true,
+                        modal: true,
+                        arrow: true,
+                        "Content"
+                    }
+                }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn positions_map_to_centred_placements() {
+        assert_eq!(Placement::from(PopoverPosition::default()), Placement::new(Side::Bottom, Align::Center));
+        assert_eq!(Placement::from(PopoverPosition::Left).side, Side::Left);
+    }
+
+    #[test]
+    fn controlled_state_reports_without_changing() {
+        let mut dom = VirtualDom::new(|| {
+            let mut requested = use_signal(|| None::<bool>);
+            let mut controlled = use_open_state(Some(false), false, Some(EventHandler::new(move |v| requested.set(Some(v)))));
+            let mut own = use_open_state(None, false, None);
+            use_hook(|| {
+                controlled.set(true);
+                assert!(!controlled.get());
+                assert_eq!(*requested.peek(), Some(true));
+                own.set(true);
+                assert!(*own.own.peek());
+            });
+            rsx! {}
+        });
+        dom.rebuild_in_place();
+    }
+}
@@ -0,0 +1,57 @@
+//! Style constants for EqPopover.
+
+use crate::floating::{
+    FALLBACK_PANEL_BOTTOM, FALLBACK_PANEL_LEFT, FALLBACK_PANEL_RIGHT, FALLBACK_PANEL_TOP,
+};
+
+/// Wrapper — relative anchor for the panel.
+pub const WRAPPER: &str = "relative inline-flex";
+
+/// The trigger button. Unstyled apart from focus, so the trigger
+/// content decides how it looks.
+pub const TRIGGER: &str =
+    "inline-flex items-center cursor-pointer rounded-md \
+     focus:outline-none focus-visible:ring-2 focus-visible:ring-[var(--color-accent-primary)]/40";
+
+/// The popover panel.
+pub const PANEL: &str =
+    "absolute z-50 min-w-48 max-w-sm rounded-lg p-4 text-sm \
+     bg-[var(--color-card)] text-[var(--color-label-primary)] \
+     border border-[var(--color-card-border)] \
+     shadow-2xl shadow-black/40 focus:outline-none \
+     transition-opacity duration-150";
+
+/// Panel open.
+pub const PANEL_OPEN: &str = "opacity-100 visible";
+/// Panel closed.
+pub const PANEL_CLOSED: &str = "opacity-0 invisible pointer-events-none";
+
+/// Position: above the trigger.
+pub const POS_TOP: &str = FALLBACK_PANEL_TOP;
+/// Position: below the trigger (default).
+pub const POS_BOTTOM: &str = FALLBACK_PANEL_BOTTOM;
+/// Position: left of the trigger.
+pub const POS_LEFT: &str = FALLBACK_PANEL_LEFT;
+/// Position: right of the trigger.
+pub const POS_RIGHT: &str = FALLBACK_PANEL_RIGHT;
+
+/// Arrow - a small rotated square placed by the floating engine.
+pub const ARROW: &str =
+    "w-2.5 h-2.5 rotate-45 bg-[var(--color-card)] \
+     border-[var(--color-card-border)]";
+
+/// All style tokens for playground introspection.
+pub fn catalog() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("WRAPPER", WRAPPER),
+        ("TRIGGER", TRIGGER),
+        ("PANEL", PANEL),
+        ("PANEL_OPEN", PANEL_OPEN),
+        ("PANEL_CLOSED", PANEL_CLOSED),
+        ("POS_TOP", POS_TOP),
+        ("POS_BOTTOM", POS_BOTTOM),
+        ("POS_LEFT", POS_LEFT),
+        ("POS_RIGHT", POS_RIGHT),
+        ("ARROW", ARROW),
+    ]
+}
@@ -7,7 +7,10 @@
 //!   position, auto-dismissing each after its duration expires.
 //!
 //! The caller manages a `Signal<Vec<ToastData>>` and pushes new entries.
-//! `EqToastList` handles rendering and cleanup.
+//! `EqToastList` handles rendering and cleanup. For an imperative API
+//! without caller-owned state (dedup, queueing, promise toasts), mount
+//! [`EqToaster`](super::EqToaster) and call
+//! [`use_toaster`](super::use_toaster) instead.
 //!
 //! ```no_run
 //! use eq_ui::prelude::*;
@@ -30,15 +33,21 @@
 //! ```
 
 use super::eq_toast_styles as s;
+use crate::overlay::EqPortal;
+use crate::timer::{now_ms, sleep};
 use crate::{PlaygroundEnum, playground};
-use dioxus::document;
+use dioxus::core::Task;
 use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
 
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropSelect, StyleInfo, format_catalog,
+    DemoSection, PropSelect, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant, EqButton, ButtonVariant};
 #[cfg(feature = "playground")]
 use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
@@ -57,6 +66,8 @@ pub enum ToastSeverity {
     Warning,
     /// Error or failure.
     Error,
+    /// Work in progress (spinner, never auto-dismisses).
+    Loading,
 }
 
 /// Position anchor for the toast stack.
@@ -84,6 +95,38 @@ pub struct ToastData {
     pub message: String,
     /// Auto-dismiss duration in milliseconds. 0 = no auto-dismiss.
     pub duration_ms: u64,
+    /// Deduplication key: showing a toast whose key is already on
+    /// screen (or queued) replaces that toast instead of adding one.
+    pub key: Option<String>,
+    /// Optional action button (e.g. "Undo").
+    pub action: Option<ToastAction>,
+}
+
+/// Action button rendered inside a toast. Clicking it runs the handler
+/// and dismisses the toast.
+///
+/// The handler is a plain closure rather than an `EventHandler`: the
+/// toast outlives the component that showed it, and can be built
+/// outside any Dioxus runtime.
+#[derive(Clone)]
+pub struct ToastAction {
+    /// Button label.
+    pub label: String,
+    /// Fired when the button is clicked.
+    pub on_click: Rc<RefCell<dyn FnMut()>>,
+}
+
+impl ToastAction {
+    /// Run the click handler.
+    pub fn call(&self) {
+        (self.on_click.borrow_mut())();
+    }
+}
+
+impl PartialEq for ToastAction {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label && Rc::ptr_eq(&self.on_click, &other.on_click)
+    }
 }
 
 impl ToastData {
@@ -93,48 +136,41 @@ impl ToastData {
         COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
     }
 
-    /// Create an info toast.
-    pub fn info(title: impl Into<String>, message: impl Into<String>) -> Self {
+    fn new(severity: ToastSeverity, title: String, message: String, duration_ms: u64) -> Self {
         Self {
             id: Self::next_id(),
-            severity: ToastSeverity::Info,
-            title: title.into(),
-            message: message.into(),
-            duration_ms: 4000,
+            severity,
+            title,
+            message,
+            duration_ms,
+            key: None,
+            action: None,
         }
     }
 
+    /// Create an info toast.
+    pub fn info(title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(ToastSeverity::Info, title.into(), message.into(), 4000)
+    }
+
     /// Create a success toast.
     pub fn success(title: impl Into<String>, message: impl Into<String>) -> Self {
-        Self {
-            id: Self::next_id(),
-            severity: ToastSeverity::Success,
-            title: title.into(),
-            message: message.into(),
-            duration_ms: 3000,
-        }
+        Self::new(ToastSeverity::Success, title.into(), message.into(), 3000)
     }
 
     /// Create a warning toast.
     pub fn warning(title: impl Into<String>, message: impl Into<String>) -> Self {
-        Self {
-            id: Self::next_id(),
-            severity: ToastSeverity::Warning,
-            title: title.into(),
-            message: message.into(),
-            duration_ms: 5000,
-        }
+        Self::new(ToastSeverity::Warning, title.into(), message.into(), 5000)
     }
 
     /// Create an error toast.
     pub fn error(title: impl Into<String>, message: impl Into<String>) -> Self {
-        Self {
-            id: Self::next_id(),
-            severity: ToastSeverity::Error,
-            title: title.into(),
-            message: message.into(),
-            duration_ms: 6000,
-        }
+        Self::new(ToastSeverity::Error, title.into(), message.into(), 6000)
+    }
+
+    /// Create a loading toast. It stays until updated or dismissed.
+    pub fn loading(title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(ToastSeverity::Loading, title.into(), message.into(), 0)
     }
 
     /// Builder: set custom duration (0 = sticky).
@@ -142,6 +178,19 @@ impl ToastData {
         self.duration_ms = ms;
         self
     }
+
+    /// Builder: set the deduplication key.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// Builder: add an action button.
+    pub fn action(mut self, label: impl Into<String>, mut on_click: impl FnMut(()) + 'static) -> Self {
+        let on_click = Rc::new(RefCell::new(move || on_click(())));
+        self.action = Some(ToastAction { label: label.into(), on_click });
+        self
+    }
 }
 
 // ── SVG icon paths per severity ─────────────────────────────────────
@@ -156,6 +205,8 @@ fn severity_icon(severity: ToastSeverity) -> &'static str {
         ToastSeverity::Warning => "M12 9v3.75m-9.303 3.376c-.866 1.5.217 3.374 1.948 3.374h14.71c1.73 0 2.813-1.874 1.948-3.374L13.949 3.378c-.866-1.5-3.032-1.5-3.898 0L2.697 16.126ZM12 15.75h.007v.008H12v-.008Z",
         // X circle
         ToastSeverity::Error => "m9.75 9.75 4.5 4.5m0-4.5-4.5 4.5M21 12a9 9 0 1 1-18 0 9 9 0 0 1 18 0Z",
+        // Open arc (spun via ICON_SPIN)
+        ToastSeverity::Loading => "M12 3a9 9 0 1 0 9 9",
     }
 }
 
@@ -163,7 +214,8 @@ fn severity_icon(severity: ToastSeverity) -> &'static str {
 
 /// Renders a positioned stack of toast notifications.
 ///
-/// Manages auto-dismiss timers internally. Each toast fades in on
+/// Manages auto-dismiss timers internally (in Rust; they pause while a
+/// toast is hovered or focused). Each toast fades in on
 /// mount and is removed from the signal after its duration expires.
 ///
 /// **Accessibility** — the container uses `role="status"` and
@@ -199,17 +251,19 @@ pub fn EqToastList(
     };
 
     rsx! {
-        div {
-            class: "{s::CONTAINER} {pos_cls}",
-            role: "status",
-            "aria-live": "polite",
-            "aria-relevant": "additions",
-
-            for toast in toasts().iter().cloned() {
-                EqToastItem {
-                    key: "{toast.id}",
-                    toast: toast,
-                    toasts: toasts,
+        EqPortal { passive: true,
+            div {
+                class: "{s::CONTAINER} {pos_cls}",
+                role: "status",
+                "aria-live": "polite",
+                "aria-relevant": "additions",
+
+                for toast in toasts().iter().cloned() {
+                    EqToastItem {
+                        key: "{toast.id}",
+                        toast: toast,
+                        toasts: toasts,
+                    }
                 }
             }
         }
@@ -224,41 +278,82 @@ fn EqToastItem(
     toasts: Signal<Vec<ToastData>>,
 ) -> Element {
     let id = toast.id;
-    let severity = toast.severity;
     let duration_ms = toast.duration_ms;
+    let mut paused = use_signal(|| false);
+
+    // Auto-dismiss timer; counts down only while not hovered or focused.
+    // One sleep per run: pausing cancels it and banks the time left,
+    // resuming sleeps for that remainder.
+    let mut toasts_dismiss = toasts;
+    let mut remaining = use_signal(|| duration_ms);
+    let mut timer = use_signal(|| None::<(Task, u64)>);
+    use_effect(move || {
+        let running = duration_ms > 0 && !paused();
+        if let Some((task, started)) = timer.take() {
+            task.cancel();
+            let left = remaining.peek().saturating_sub(now_ms().saturating_sub(started));
+            remaining.set(left);
+        }
+        if running {
+            let ms = *remaining.peek();
+            let task = spawn(async move {
+                sleep(ms).await;
+                toasts_dismiss.write().retain(|t| t.id != id);
+            });
+            timer.set(Some((task, now_ms())));
+        }
+    });
+
+    rsx! {
+        ToastCard {
+            toast,
+            on_dismiss: move |_| toasts.write().retain(|t| t.id != id),
+            on_pause: move |p| paused.set(p),
+        }
+    }
+}
+
+/// One rendered toast. Reports hover / focus through `on_pause` so the
+/// owner can hold its timer.
+#[component]
+pub(crate) fn ToastCard(
+    toast: ToastData,
+    on_dismiss: EventHandler<()>,
+    on_pause: EventHandler<bool>,
+) -> Element {
+    let mut hovered = use_signal(|| false);
+    let mut focused = use_signal(|| false);
+    let mut report = move |hover: bool, focus: bool| {
+        hovered.set(hover);
+        focused.set(focus);
+        on_pause.call(hover || focus);
+    };
 
+    let severity = toast.severity;
     let severity_cls = match severity {
-        ToastSeverity::Info => s::INFO,
+        ToastSeverity::Info | ToastSeverity::Loading => s::INFO,
         ToastSeverity::Success => s::SUCCESS,
         ToastSeverity::Warning => s::WARNING,
         ToastSeverity::Error => s::ERROR,
     };
+    let icon_cls = if severity == ToastSeverity::Loading { s::ICON_SPIN } else { "" };
 
     let icon_path = severity_icon(severity);
     let has_message = !toast.message.is_empty();
 
-    // Auto-dismiss timer via JS setTimeout promise.
-    let mut toasts_dismiss = toasts;
-    use_effect(move || {
-        if duration_ms > 0 {
-            spawn(async move {
-                let js = format!(
-                    "await new Promise(r => setTimeout(r, {duration_ms}))"
-                );
-                let _ = document::eval(&js).await;
-                toasts_dismiss.write().retain(|t| t.id != id);
-            });
-        }
-    });
-
     rsx! {
         div {
             class: "{s::TOAST} {severity_cls} {s::TOAST_ENTER}",
             role: "alert",
+            "aria-busy": if severity == ToastSeverity::Loading { "true" } else { "false" },
+            onmouseenter: move |_| report(true, focused()),
+            onmouseleave: move |_| report(false, focused()),
+            onfocusin: move |_| report(hovered(), true),
+            onfocusout: move |_| report(hovered(), false),
 
             // Icon
             svg {
-                class: "{s::ICON}",
+                class: "{s::ICON} {icon_cls}",
                 xmlns: "http://www.w3.org/2000/svg",
                 fill: "none",
                 view_box: "0 0 24 24",
@@ -278,13 +373,24 @@ fn EqToastItem(
                 }
             }
 
+            // Action
+            if let Some(action) = toast.action.clone() {
+                button {
+                    class: "{s::ACTION}",
+                    r#type: "button",
+                    onclick: move |_| {
+                        action.call();
+                        on_dismiss.call(());
+                    },
+                    "{action.label}"
+                }
+            }
+
             // Close button
             button {
                 class: "{s::CLOSE}",
                 "aria-label": "Dismiss notification",
-                onclick: move |_| {
-                    toasts.write().retain(|t| t.id != id);
-                },
+                onclick: move |_| on_dismiss.call(()),
                 svg {
                     xmlns: "http://www.w3.org/2000/svg",
                     fill: "none",
@@ -393,7 +499,7 @@ EqToastList { toasts, position: ToastPosition::TopRight }"#
             EqToastList { toasts, position }
 
             StyleInfo { file: "eq_toast_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
@@ -499,4 +605,17 @@ mod tests {
         let t = ToastData::info("a", "b").duration(0);
         assert_eq!(t.duration_ms, 0);
     }
+
+    #[test]
+    fn toast_data_action_runs_outside_a_runtime() {
+        let count = Rc::new(std::cell::Cell::new(0));
+        let seen = count.clone();
+        let t = ToastData::info("Archived", "").action("Undo", move |_| seen.set(seen.get() + 1));
+        let action = t.action.clone().expect("action set");
+        assert_eq!(action.label, "Undo");
+        action.call();
+        action.call();
+        assert_eq!(count.get(), 2);
+        assert!(t.action == Some(action));
+    }
 }
@@ -43,12 +43,20 @@ pub const ERROR: &str =
 
 pub const ICON: &str = "shrink-0 mt-0.5";
 
+/// Spinning icon for loading toasts.
+pub const ICON_SPIN: &str = "animate-spin";
+
 pub const CONTENT: &str = "flex-1 min-w-0";
 
 pub const TITLE: &str = "text-sm font-semibold";
 
 pub const MESSAGE: &str = "text-sm opacity-80 mt-0.5";
 
+/// Action button (e.g. "Undo").
+pub const ACTION: &str =
+    "shrink-0 self-center px-2 py-1 rounded text-xs font-semibold \
+     border border-current/30 hover:bg-white/10 transition-colors cursor-pointer";
+
 pub const CLOSE: &str =
     "shrink-0 p-0.5 rounded opacity-60 hover:opacity-100 \
      transition-opacity cursor-pointer";
@@ -66,6 +74,8 @@ pub fn catalog() -> Vec<(&'static str, &'static str)> {
         ("CONTENT", CONTENT),
         ("TITLE", TITLE),
         ("MESSAGE", MESSAGE),
+        ("ICON_SPIN", ICON_SPIN),
+        ("ACTION", ACTION),
         ("CLOSE", CLOSE),
     ]
 }
@@ -0,0 +1,695 @@
+//! EqToaster — imperative toast service.
+//!
+//! Mount `EqToaster` once near the app root; anywhere below it,
+//! [`use_toaster`] returns a [`Toaster`] handle for showing, updating
+//! and dismissing toasts without owning any state:
+//!
+//! - **Deduplication** — a toast with a [`ToastData::key`] already on
+//!   screen (or waiting) replaces that toast instead of stacking.
+//! - **Queue** — at most `max_visible` toasts show at once; the rest
+//!   wait in order and their timers start when they appear.
+//! - **Actions** — [`ToastData::action`] adds a button such as "Undo".
+//! - **Promises** — [`Toaster::promise`] shows a loading toast and turns
+//!   it into a success or error toast when the future resolves.
+//! - **Timers** run in Rust (see [`crate::timer`]) and pause while a
+//!   toast is hovered or has focus.
+//!
+//! ```no_run
+//! use eq_ui::prelude::*;
+//! use eq_ui::molecules::{EqToaster, ToastData, use_toaster};
+//!
+//! fn app() -> Element {
+//!     rsx! {
+//!         EqToaster { SaveButton {} }
+//!     }
+//! }
+//!
+//! #[component]
+//! fn SaveButton() -> Element {
+//!     let toaster = use_toaster();
+//!     rsx! {
+//!         EqButton {
+//!             on_click: move |_| {
+//!                 toaster.show(
+//!                     ToastData::success("Saved", "Draft stored.")
+//!                         .key("save")
+//!                         .action("Undo", move |_| { /* revert */ }),
+//!                 );
+//!             },
+//!             "Save"
+//!         }
+//!     }
+//! }
+//! ```
+
+use super::eq_toast::{ToastCard, ToastData, ToastPosition};
+use super::eq_toast_styles as s;
+use crate::overlay::EqPortal;
+use crate::playground;
+use crate::timer::{now_ms, sleep};
+use dioxus::core::{Runtime, Task, current_scope_id};
+use dioxus::prelude::*;
+use std::future::Future;
+
+#[cfg(feature = "playground")]
+use crate::playground::playground_helpers::{
+    DemoSection, PropInput, PropSelect, StyleInfo, format_catalog,
+};
+#[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
+use crate::atoms::{EqText, TextVariant, EqButton, ButtonVariant};
+#[cfg(feature = "playground")]
+use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
+
+// ── Queue ─────────────────────────────────────────────────────────
+
+#[derive(Clone, PartialEq)]
+pub(crate) struct ToastEntry {
+    pub toast: ToastData,
+    pub remaining_ms: u64,
+    pub paused: bool,
+}
+
+/// Ordered toasts; the first `max_visible` are on screen.
+#[derive(Clone, PartialEq)]
+pub(crate) struct ToastQueue {
+    entries: Vec<ToastEntry>,
+    max_visible: usize,
+}
+
+impl ToastQueue {
+    pub fn new(max_visible: usize) -> Self {
+        Self { entries: Vec::new(), max_visible: max_visible.max(1) }
+    }
+
+    pub fn set_max_visible(&mut self, max_visible: usize) {
+        self.max_visible = max_visible.max(1);
+    }
+
+    /// Add a toast, or replace the one sharing its key. Returns the id
+    /// the toast is shown under.
+    pub fn show(&mut self, toast: ToastData) -> u64 {
+        let existing = toast
+            .key
+            .as_ref()
+            .and_then(|key| self.entries.iter().position(|e| e.toast.key.as_ref() == Some(key)));
+        match existing {
+            Some(i) => {
+                let id = self.entries[i].toast.id;
+                self.replace(i, toast);
+                id
+            }
+            None => {
+                let id = toast.id;
+                self.entries.push(ToastEntry { remaining_ms: toast.duration_ms, toast, paused: false });
+                id
+            }
+        }
+    }
+
+    /// Replace the content of toast `id`, restarting its timer.
+    pub fn update(&mut self, id: u64, toast: ToastData) -> bool {
+        match self.entries.iter().position(|e| e.toast.id == id) {
+            Some(i) => {
+                self.replace(i, toast);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn replace(&mut self, i: usize, mut toast: ToastData) {
+        let entry = &mut self.entries[i];
+        toast.id = entry.toast.id;
+        if toast.key.is_none() {
+            toast.key = entry.toast.key.take();
+        }
+        entry.remaining_ms = toast.duration_ms;
+        entry.toast = toast;
+    }
+
+    pub fn dismiss(&mut self, id: u64) {
+        self.entries.retain(|e| e.toast.id != id);
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn set_paused(&mut self, id: u64, paused: bool) {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.toast.id == id) {
+            entry.paused = paused;
+        }
+    }
+
+    pub fn visible(&self) -> &[ToastEntry] {
+        &self.entries[..self.entries.len().min(self.max_visible)]
+    }
+
+    pub fn queued(&self) -> usize {
+        self.entries.len().saturating_sub(self.max_visible)
+    }
+
+    /// Milliseconds until the first visible, unpaused toast runs out,
+    /// or `None` when no countdown is running.
+    pub fn next_deadline(&self) -> Option<u64> {
+        self.visible()
+            .iter()
+            .filter(|e| e.toast.duration_ms > 0 && !e.paused)
+            .map(|e| e.remaining_ms)
+            .min()
+    }
+
+    /// Advance visible, unpaused countdowns by `elapsed_ms` and drop
+    /// the toasts that ran out.
+    pub fn tick(&mut self, elapsed_ms: u64) {
+        let visible = self.entries.len().min(self.max_visible);
+        for entry in &mut self.entries[..visible] {
+            if entry.toast.duration_ms > 0 && !entry.paused {
+                entry.remaining_ms = entry.remaining_ms.saturating_sub(elapsed_ms);
+            }
+        }
+        let mut index = 0;
+        self.entries.retain(|e| {
+            let keep = index >= visible || e.toast.duration_ms == 0 || e.remaining_ms > 0;
+            index += 1;
+            keep
+        });
+    }
+}
+
+// ── Handle ────────────────────────────────────────────────────────
+
+/// Handle to the nearest [`EqToaster`], returned by [`use_toaster`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct Toaster {
+    queue: Signal<ToastQueue>,
+    /// The pending countdown sleep and when it started.
+    driver: Signal<Option<(Task, u64)>>,
+    scope: ScopeId,
+}
+
+impl Toaster {
+    /// Show a toast and return its id. A toast whose key is already
+    /// present replaces that one and returns its id.
+    pub fn show(&self, toast: ToastData) -> u64 {
+        self.settle();
+        let mut queue = self.queue;
+        let id = queue.write().show(toast);
+        self.drive();
+        id
+    }
+
+    /// Replace toast `id` with new content, restarting its timer.
+    /// Returns `false` if it was already dismissed.
+    pub fn update(&self, id: u64, toast: ToastData) -> bool {
+        self.settle();
+        let mut queue = self.queue;
+        let found = queue.write().update(id, toast);
+        self.drive();
+        found
+    }
+
+    /// Remove toast `id`.
+    pub fn dismiss(&self, id: u64) {
+        self.settle();
+        let mut queue = self.queue;
+        queue.write().dismiss(id);
+        self.drive();
+    }
+
+    /// Remove every toast, shown or waiting.
+    pub fn dismiss_all(&self) {
+        self.settle();
+        let mut queue = self.queue;
+        queue.write().clear();
+    }
+
+    /// Show `loading`, await `future`, then turn the toast into
+    /// `success(&value)` or `error(&err)`. Returns the toast id.
+    ///
+    /// The future runs in the toaster's scope, so it still resolves
+    /// the toast if the component that started it unmounts.
+    pub fn promise<T: 'static, E: 'static>(
+        &self,
+        future: impl Future<Output = Result<T, E>> + 'static,
+        loading: ToastData,
+        success: impl FnOnce(&T) -> ToastData + 'static,
+        error: impl FnOnce(&E) -> ToastData + 'static,
+    ) -> u64 {
+        let id = self.show(loading);
+        let this = *self;
+        Runtime::current().in_scope(self.scope, || {
+            spawn(async move {
+                let next = match future.await {
+                    Ok(value) => success(&value),
+                    Err(err) => error(&err),
+                };
+                this.update(id, next);
+            })
+        });
+        id
+    }
+
+    /// Toasts currently on screen, oldest first.
+    pub fn visible(&self) -> Vec<ToastData> {
+        self.queue.read().visible().iter().map(|e| e.toast.clone()).collect()
+    }
+
+    /// Number of toasts waiting for a free slot.
+    pub fn queued(&self) -> usize {
+        self.queue.read().queued()
+    }
+
+    /// Change the on-screen limit. Toasts promoted out of the queue
+    /// start counting down right away.
+    fn set_max_visible(&self, max_visible: usize) {
+        self.settle();
+        let mut queue = self.queue;
+        queue.write().set_max_visible(max_visible);
+        self.drive();
+    }
+
+    fn set_paused(&self, id: u64, paused: bool) {
+        self.settle();
+        let mut queue = self.queue;
+        queue.write().set_paused(id, paused);
+        self.drive();
+    }
+
+    /// Cancel the pending countdown sleep and charge the time it ran
+    /// to the visible toasts, so the queue can change under a fresh one.
+    fn settle(&self) {
+        let mut driver = self.driver;
+        if let Some((task, started)) = driver.take() {
+            task.cancel();
+            let mut queue = self.queue;
+            queue.write().tick(now_ms().saturating_sub(started));
+        }
+    }
+
+    /// Sleep until the nearest countdown runs out, then drop the
+    /// toasts that expired and re-arm for the next one. Every change to
+    /// the queue settles first and calls this again.
+    fn drive(&self) {
+        if self.driver.peek().is_some() {
+            return;
+        }
+        let Some(ms) = self.queue.peek().next_deadline() else {
+            return;
+        };
+        let this = *self;
+        let task = Runtime::current().in_scope(self.scope, || {
+            spawn(async move {
+                sleep(ms).await;
+                let mut driver = this.driver;
+                driver.set(None);
+                let mut queue = this.queue;
+                queue.write().tick(ms);
+                this.drive();
+            })
+        });
+        let mut driver = self.driver;
+        driver.set(Some((task, now_ms())));
+    }
+}
+
+/// The [`Toaster`] of the nearest [`EqToaster`].
+///
+/// # Panics
+///
+/// When called outside an `EqToaster`.
+pub fn use_toaster() -> Toaster {
+    use_context::<Toaster>()
+}
+
+// ── Component ─────────────────────────────────────────────────────
+
+/// Provides a [`Toaster`] to its children and renders the toasts it
+/// holds.
+///
+/// **Accessibility** — like `EqToastList`, the container is a polite
+/// live region; loading toasts set `aria-busy`.
+#[playground(
+    category = Molecule,
+    description = "Imperative toast service: use_toaster() with show / update / dismiss, \
+                   dedup keys, action buttons, promise toasts, a max-visible queue and \
+                   timers that pause on hover or focus.",
+    examples = [
+        ("Mount", "EqToaster { max_visible: 3,\n    App {}\n}"),
+        ("Show with Undo", "let toaster = use_toaster();\n\ntoaster.show(\n    ToastData::info(\"Archived\", \"1 conversation\")\n        .key(\"archive\")\n        .action(\"Undo\", move |_| restore()),\n);"),
+        ("Promise", "toaster.promise(\n    save(doc()),\n    ToastData::loading(\"Saving…\", \"\"),\n    |_| ToastData::success(\"Saved\", \"\"),\n    |e: &String| ToastData::error(\"Save failed\", e.clone()),\n);"),
+    ],
+    custom_demo,
+    custom_gallery,
+)]
+#[component]
+pub fn EqToaster(
+    /// Viewport anchor position.
+    #[props(default)]
+    position: ToastPosition,
+    /// Toasts shown at once; further toasts wait in a queue.
+    #[props(default = 3)]
+    max_visible: usize,
+    children: Element,
+) -> Element {
+    let queue = use_signal(|| ToastQueue::new(max_visible));
+    let driver = use_signal(|| None::<(Task, u64)>);
+    let toaster = use_context_provider(|| Toaster { queue, driver, scope: current_scope_id() });
+
+    // Synced after render: writing the queue while rendering would
+    // queue another render of this component.
+    use_effect(use_reactive((&max_visible,), move |(max_visible,)| {
+        if queue.peek().max_visible != max_visible.max(1) {
+            toaster.set_max_visible(max_visible);
+        }
+    }));
+
+    let pos_cls = match position {
+        ToastPosition::TopRight => s::POS_TOP_RIGHT,
+        ToastPosition::TopLeft => s::POS_TOP_LEFT,
+        ToastPosition::TopCenter => s::POS_TOP_CENTER,
+        ToastPosition::BottomRight => s::POS_BOTTOM_RIGHT,
+        ToastPosition::BottomLeft => s::POS_BOTTOM_LEFT,
+        ToastPosition::BottomCenter => s::POS_BOTTOM_CENTER,
+    };
+
+    rsx! {
+        {children}
+
+        EqPortal { passive: true,
+            div {
+                class: "{s::CONTAINER} {pos_cls}",
+                role: "status",
+                "aria-live": "polite",
+                "aria-relevant": "additions",
+
+                for toast in toaster.visible() {
+                    {
+                        let id = toast.id;
+                        rsx! {
+                            ToastCard {
+                                key: "{id}",
+                                toast,
+                                on_dismiss: move |_| toaster.dismiss(id),
+                                on_pause: move |p| toaster.set_paused(id, p),
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── Interactive demo ───────────────────────────────────────────────
+
+#[cfg(feature = "playground")]
+#[component]
+fn DemoEqToaster() -> Element {
+    let mut max_visible = use_signal(|| "3".to_string());
+    let mut position_str = use_signal(|| "BottomRight".to_string());
+
+    let position = match position_str().as_str() {
+        "TopRight" => ToastPosition::TopRight,
+        "TopLeft" => ToastPosition::TopLeft,
+        "TopCenter" => ToastPosition::TopCenter,
+        "BottomLeft" => ToastPosition::BottomLeft,
+        "BottomCenter" => ToastPosition::BottomCenter,
+        _ => ToastPosition::BottomRight,
+    };
+    let max = max_visible().parse::<usize>().unwrap_or(3);
+
+    let code = format!(
+        r#"EqToaster {{
+    position: ToastPosition::{pos},
+    max_visible: {max},
+    App {{}}
+}}
+
+// Anywhere below:
+let toaster = use_toaster();
+
+let id = toaster.show(ToastData::info("Uploading", "report.pdf").duration(0));
+toaster.update(id, ToastData::success("Uploaded", "report.pdf"));
+toaster.dismiss(id);
+
+// Same key → replaces instead of stacking
+toaster.show(ToastData::warning("Offline", "Retrying…").key("network"));
+
+// Loading → success / error
+toaster.promise(
+    upload(file),
+    ToastData::loading("Uploading…", ""),
+    |url: &String| ToastData::success("Uploaded", url.clone()),
+    |err: &String| ToastData::error("Upload failed", err.clone()),
+);"#,
+        pos = position_str(),
+    );
+
+    rsx! {
+        DemoSection { title: "EqToaster",
+            div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-3",
+                EqText {
+                    variant: TextVariant::Caption,
+                    class: "font-semibold uppercase tracking-wider",
+                    "Props"
+                }
+                PropSelect {
+                    label: "position",
+                    value: position_str(),
+                    options: vec!["TopRight", "TopLeft", "TopCenter", "BottomRight", "BottomLeft", "BottomCenter"],
+                    onchange: move |v: String| position_str.set(v),
+                }
+                PropInput {
+                    label: "max_visible",
+                    value: max_visible(),
+                    placeholder: "3",
+                    onchange: move |v: String| max_visible.set(v),
+                }
+            }
+
+            EqToaster { position, max_visible: max,
+                ToasterDemoButtons {}
+            }
+
+            StyleInfo { file: "eq_toast_styles.rs", styles: format_catalog(&s::catalog()) }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
+        }
+    }
+}
+
+#[cfg(feature = "playground")]
+#[component]
+fn ToasterDemoButtons() -> Element {
+    let toaster = use_toaster();
+    let mut archived = use_signal(|| 0u32);
+    let mut flip = use_signal(|| false);
+
+    rsx! {
+        div { class: "rounded-lg border border-dashed border-[var(--color-card-border)] p-6 space-y-3",
+            div { class: "flex flex-wrap gap-3",
+                EqButton {
+                    on_click: move |_| {
+                        toaster.show(ToastData::info("Hello", "Hover me to pause the timer."));
+                    },
+                    "Show"
+                }
+                EqButton {
+                    variant: ButtonVariant::Outline,
+                    on_click: move |_| {
+                        archived += 1;
+                        toaster.show(
+                            ToastData::info("Archived", "The conversation moved to the archive.")
+                                .action("Undo", move |_| archived -= 1),
+                        );
+                    },
+                    "Archive (with Undo)"
+                }
+                EqButton {
+                    variant: ButtonVariant::Outline,
+                    on_click: move |_| {
+                        toaster.show(
+                            ToastData::warning("Offline", "Click again: this toast is replaced, not stacked.")
+                                .key("network"),
+                        );
+                    },
+                    "Deduplicated"
+                }
+                EqButton {
+                    variant: ButtonVariant::Primary,
+                    on_click: move |_| {
+                        let ok = !flip();
+                        flip.set(ok);
+                        toaster.promise(
+                            async move {
+                                sleep(1500).await;
+                                if ok { Ok(42) } else { Err("Server returned 503".to_string()) }
+                            },
+                            ToastData::loading("Saving…", "Talking to the server."),
+                            |n: &i32| ToastData::success("Saved", format!("Revision {n} stored.")),
+                            |e: &String| ToastData::error("Save failed", e.clone()),
+                        );
+                    },
+                    "Promise"
+                }
+                EqButton {
+                    variant: ButtonVariant::Ghost,
+                    on_click: move |_| {
+                        for i in 1..=5 {
+                            toaster.show(ToastData::success("Queued", format!("Toast {i} of 5")));
+                        }
+                    },
+                    "Burst of 5"
+                }
+                EqButton {
+                    variant: ButtonVariant::Ghost,
+                    on_click: move |_| toaster.dismiss_all(),
+                    "Dismiss all"
+                }
+            }
+            EqText { variant: TextVariant::Caption,
+                "Archived: {archived} · Waiting in queue: {toaster.queued()}"
+            }
+        }
+    }
+}
+
+// ── Gallery ───────────────────────────────────────────────────────
+
+#[cfg(feature = "playground")]
+#[component]
+fn GalleryEqToaster() -> Element {
+    rsx! {
+        div { class: "space-y-4",
+            div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-4",
+                EqText { variant: TextVariant::Caption, class: "font-semibold uppercase tracking-wider", "Toaster Gallery" }
+                EqText { variant: TextVariant::Muted, "Imperative toasts from any component below the toaster (bottom-right corner)" }
+                EqToaster { position: ToastPosition::BottomRight,
+                    ToasterDemoButtons {}
+                }
+            }
+        }
+    }
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn titles(queue: &ToastQueue) -> Vec<String> {
+        queue.entries.iter().map(|e| e.toast.title.clone()).collect()
+    }
+
+    #[test]
+    fn dedup_by_key_replaces_in_place() {
+        let mut queue = ToastQueue::new(3);
+        let first = queue.show(ToastData::info("a", "").key("net"));
+        queue.show(ToastData::info("b", ""));
+        let again = queue.show(ToastData::warning("a2", "").key("net"));
+        assert_eq!(first, again);
+        assert_eq!(titles(&queue), vec!["a2", "b"]);
+    }
+
+    #[test]
+    fn queue_limits_visible_and_promotes() {
+        let mut queue = ToastQueue::new(2);
+        for t in ["a", "b", "c"] {
+            queue.show(ToastData::info(t, "").duration(200));
+        }
+        assert_eq!(queue.visible().len(), 2);
+        assert_eq!(queue.queued(), 1);
+
+        // Queued toasts do not count down until they are visible.
+        queue.tick(200);
+        assert_eq!(titles(&queue), vec!["c"]);
+        assert_eq!(queue.entries[0].remaining_ms, 200);
+    }
+
+    #[test]
+    fn paused_and_sticky_toasts_keep_their_time() {
+        let mut queue = ToastQueue::new(3);
+        let paused = queue.show(ToastData::info("paused", "").duration(100));
+        queue.show(ToastData::error("sticky", "").duration(0));
+        queue.show(ToastData::info("timed", "").duration(100));
+        queue.set_paused(paused, true);
+        queue.tick(100);
+        assert_eq!(titles(&queue), vec!["paused", "sticky"]);
+        queue.set_paused(paused, false);
+        queue.tick(100);
+        assert_eq!(titles(&queue), vec!["sticky"]);
+        assert_eq!(queue.next_deadline(), None);
+    }
+
+    #[test]
+    fn next_deadline_skips_paused_sticky_and_queued() {
+        let mut queue = ToastQueue::new(2);
+        let slow = queue.show(ToastData::info("slow", "").duration(300));
+        queue.show(ToastData::error("sticky", "").duration(0));
+        queue.show(ToastData::info("queued", "").duration(50));
+        assert_eq!(queue.next_deadline(), Some(300));
+        queue.set_paused(slow, true);
+        assert_eq!(queue.next_deadline(), None);
+    }
+
+    #[test]
+    fn update_keeps_id_and_key_and_restarts_timer() {
+        let mut queue = ToastQueue::new(3);
+        let id = queue.show(ToastData::loading("Saving", "").key("save"));
+        queue.tick(5_000);
+        assert!(queue.update(id, ToastData::success("Saved", "")));
+        let entry = &queue.entries[0];
+        assert_eq!(entry.toast.id, id);
+        assert_eq!(entry.toast.key.as_deref(), Some("save"));
+        assert_eq!(entry.remaining_ms, entry.toast.duration_ms);
+        assert!(!queue.update(id + 1_000, ToastData::info("x", "")));
+    }
+
+    #[test]
+    fn smoke_renders() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! { EqToaster { "app" } }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn raising_the_limit_arms_promoted_toasts() {
+        use std::cell::Cell;
+        thread_local! {
+            static HANDLES: Cell<Option<(Toaster, Signal<usize>)>> = const { Cell::new(None) };
+        }
+
+        #[component]
+        fn Probe(limit: Signal<usize>) -> Element {
+            let toaster = use_toaster();
+            HANDLES.with(|h| h.set(Some((toaster, limit))));
+            rsx! {}
+        }
+
+        let mut dom = VirtualDom::new(|| {
+            let limit = use_signal(|| 1);
+            rsx! { EqToaster { max_visible: limit(), Probe { limit } } }
+        });
+        dom.rebuild_in_place();
+        let (toaster, mut limit) = HANDLES.with(Cell::get).expect("probe rendered");
+
+        dom.in_runtime(|| {
+            toaster.show(ToastData::error("sticky", "").duration(0));
+            toaster.show(ToastData::info("timed", "").duration(200));
+            assert!(toaster.driver.peek().is_none());
+            limit.set(2);
+        });
+        dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+        dom.process_events();
+
+        dom.in_runtime(|| {
+            assert_eq!(toaster.queue.peek().max_visible, 2);
+            assert_eq!(toaster.visible().len(), 2);
+            assert!(toaster.driver.peek().is_some());
+        });
+    }
+}
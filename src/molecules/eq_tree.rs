@@ -1,15 +1,21 @@
 use super::eq_tree_styles as s;
+use super::eq_virtual_list::{EqVirtualList, ScrollAlign};
+use crate::atoms::{CheckboxState, EqCheckbox};
 use crate::theme::merge_classes;
 use crate::playground;
+use dioxus::core::Task;
 use dioxus::document;
 use dioxus::prelude::*;
 use std::collections::HashSet;
+use std::rc::Rc;
 
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropToggle, StyleInfo, format_catalog,
+    DemoSection, PropInput, PropToggle, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant, EqScrollableSpace};
 #[cfg(feature = "playground")]
 use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
@@ -22,12 +28,17 @@ use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory
 ///
 /// Each node carries an `id`, a display `label`, an optional `parent_id`
 /// (`None` for root-level nodes), and a list of `children`.
+///
+/// A `lazy` node is a branch whose children have not been fetched yet:
+/// expanding it in [`EqTree`] fires `on_load_children`, and the consumer
+/// fills it in with [`TreeNode::set_children`].
 #[derive(Clone, Debug, PartialEq)]
 pub struct TreeNode {
     pub id: String,
     pub label: String,
     pub parent_id: Option<String>,
     pub children: Vec<TreeNode>,
+    pub lazy: bool,
 }
 
 impl TreeNode {
@@ -40,6 +51,15 @@ impl TreeNode {
             label: label.into(),
             parent_id: None,
             children: Vec::new(),
+            lazy: false,
+        }
+    }
+
+    /// Create a branch whose children are loaded on first expand.
+    pub fn new_lazy(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            lazy: true,
+            ..Self::new(id, label)
         }
     }
 
@@ -63,6 +83,7 @@ impl TreeNode {
             label: label.into(),
             parent_id: None,
             children,
+            lazy: false,
         }
     }
 
@@ -81,6 +102,16 @@ impl TreeNode {
         None
     }
 
+    /// Mutable variant of [`find_by_id`](Self::find_by_id).
+    pub fn find_by_id_mut(&mut self, target: &str) -> Option<&mut TreeNode> {
+        if self.id == target {
+            return Some(self);
+        }
+        self.children
+            .iter_mut()
+            .find_map(|child| child.find_by_id_mut(target))
+    }
+
     /// Find the direct parent of the node with the given id.
     /// Returns `None` if the id is the current node or not found.
     pub fn find_parent_of(&self, target: &str) -> Option<&TreeNode> {
@@ -136,9 +167,14 @@ impl TreeNode {
         None
     }
 
-    /// Whether this node has no children.
+    /// Whether this node has no children (and none still to load).
     pub fn is_leaf(&self) -> bool {
-        self.children.is_empty()
+        self.children.is_empty() && !self.lazy
+    }
+
+    /// Whether this is a lazy branch whose children have not arrived yet.
+    pub fn is_pending(&self) -> bool {
+        self.lazy && self.children.is_empty()
     }
 
     /// Count all leaf nodes in this subtree.
@@ -191,6 +227,17 @@ impl TreeNode {
         None
     }
 
+    /// Replace this node's children, e.g. once a lazy load resolves.
+    /// Sets each child's `parent_id` and clears the `lazy` flag, so an
+    /// empty result turns the node into a plain leaf.
+    pub fn set_children(&mut self, children: Vec<TreeNode>) {
+        self.lazy = false;
+        self.children.clear();
+        for child in children {
+            self.add_child(child);
+        }
+    }
+
     /// Remove all children from this node, turning it into a leaf.
     /// Returns the removed children.
     pub fn empty_node(&mut self) -> Vec<TreeNode> {
@@ -210,7 +257,7 @@ impl TreeNode {
         let cycle = self
             .find_by_id(node_id)
             .is_some_and(|node| node.find_by_id(new_parent_id).is_some());
-        if cycle {
+        if cycle || self.find_by_id(new_parent_id).is_none() {
             return false;
         }
         // Remove the node first, then insert under the new parent
@@ -223,24 +270,324 @@ impl TreeNode {
 }
 
 // ---------------------------------------------------------------------------
-// ARIA helpers – visible-node collection & parent lookup
+// Drag-and-drop
 // ---------------------------------------------------------------------------
 
-/// Collect all currently visible nodes (depth-first) given the set of expanded
-/// branch IDs. Returns `(node_id, depth, is_leaf)` tuples.
-fn collect_visible(
-    nodes: &[TreeNode],
-    expanded: &HashSet<String>,
+/// Id of the synthetic root [`TreeDrop::apply`] wraps the forest in.
+const FOREST_ROOT: &str = "\u{0}eq-tree-forest";
+
+/// Where a dragged node lands relative to the node it is dropped on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DropPosition {
+    /// As the previous sibling of the target.
+    Before,
+    /// As the last child of the target.
+    Inside,
+    /// As the next sibling of the target.
+    After,
+}
+
+/// A completed drag-and-drop gesture, emitted by `EqTree`'s `on_drop`.
+///
+/// The tree does not own its nodes, so it only reports the gesture —
+/// call [`TreeDrop::apply`] on your own `Vec<TreeNode>` to perform it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreeDrop {
+    /// The node being moved.
+    pub node_id: String,
+    /// The node it was dropped on.
+    pub target_id: String,
+    /// Where it lands relative to the target.
+    pub position: DropPosition,
+}
+
+impl TreeDrop {
+    /// Apply the move to a forest of root nodes through
+    /// [`TreeNode::move_node`]. Returns `false` and leaves the forest as it
+    /// was when a node is missing or the move would nest a node in itself.
+    pub fn apply(&self, nodes: &mut Vec<TreeNode>) -> bool {
+        // A synthetic root lets root-level moves take the same path as
+        // nested ones.
+        let mut root = TreeNode::new_with_children(FOREST_ROOT, "", std::mem::take(nodes));
+        let moved = match self.position {
+            DropPosition::Inside => root.move_node(&self.node_id, &self.target_id),
+            DropPosition::Before | DropPosition::After => self.move_beside(&mut root),
+        };
+        *nodes = root.children;
+        for node in nodes.iter_mut() {
+            node.parent_id = None;
+        }
+        moved
+    }
+
+    /// Re-parent the node under the target's parent, then slide it from
+    /// the end (where `move_node` appends) to just before / after the target.
+    fn move_beside(&self, root: &mut TreeNode) -> bool {
+        if self.node_id == self.target_id {
+            return false;
+        }
+        let Some(parent_id) = root.find_parent_of(&self.target_id).map(|p| p.id.clone()) else {
+            return false;
+        };
+        if !root.move_node(&self.node_id, &parent_id) {
+            return false;
+        }
+        let Some(parent) = root.find_by_id_mut(&parent_id) else { return false; };
+        let Some(moved) = parent.children.pop() else { return false; };
+        let target = parent
+            .children
+            .iter()
+            .position(|c| c.id == self.target_id)
+            .unwrap_or(parent.children.len());
+        let at = if self.position == DropPosition::After { target + 1 } else { target };
+        parent.children.insert(at, moved);
+        true
+    }
+}
+
+/// Whether `node_id` may be dropped on `target_id` — anywhere except on
+/// itself or inside its own subtree.
+fn can_drop(nodes: &[TreeNode], node_id: &str, target_id: &str) -> bool {
+    find_node(nodes, node_id).is_some_and(|node| node.find_by_id(target_id).is_none())
+}
+
+// ---------------------------------------------------------------------------
+// Checkbox cascade
+// ---------------------------------------------------------------------------
+
+/// Tri-state value of a node. Branches derive it from their children;
+/// leaves and unloaded lazy branches read it from the checked set.
+fn check_state(node: &TreeNode, checked: &HashSet<String>) -> CheckboxState {
+    if node.children.is_empty() {
+        return if checked.contains(&node.id) {
+            CheckboxState::Checked
+        } else {
+            CheckboxState::Unchecked
+        };
+    }
+    let (mut all, mut any) = (true, false);
+    for child in &node.children {
+        match check_state(child, checked) {
+            CheckboxState::Checked => any = true,
+            CheckboxState::Indeterminate => (all, any) = (false, true),
+            CheckboxState::Unchecked => all = false,
+        }
+    }
+    match (all, any) {
+        (true, _) => CheckboxState::Checked,
+        (false, true) => CheckboxState::Indeterminate,
+        (false, false) => CheckboxState::Unchecked,
+    }
+}
+
+/// Toggle `id` and cascade: its whole subtree follows the new value, then
+/// each ancestor is recomputed from its children. Returns the new set.
+fn toggle_checked(nodes: &[TreeNode], checked: &HashSet<String>, id: &str) -> HashSet<String> {
+    let path = nodes
+        .iter()
+        .map(|n| n.find_path_to(id))
+        .find(|p| !p.is_empty())
+        .unwrap_or_default();
+    let Some((node, ancestors)) = path.split_last() else { return checked.clone(); };
+
+    let on = check_state(node, checked) != CheckboxState::Checked;
+    let mut next = checked.clone();
+    for n in node.flatten() {
+        if on {
+            next.insert(n.id.clone());
+        } else {
+            next.remove(&n.id);
+        }
+    }
+    for ancestor in ancestors.iter().rev() {
+        if check_state(ancestor, &next) == CheckboxState::Checked {
+            next.insert(ancestor.id.clone());
+        } else {
+            next.remove(&ancestor.id);
+        }
+    }
+    next
+}
+
+/// Checked ids in depth-first order, as reported by `on_check`.
+fn checked_ids(nodes: &[TreeNode], checked: &HashSet<String>) -> Vec<String> {
+    nodes
+        .iter()
+        .flat_map(|n| n.flatten())
+        .filter(|n| checked.contains(&n.id))
+        .map(|n| n.id.clone())
+        .collect()
+}
+
+fn aria_checked(state: CheckboxState) -> &'static str {
+    match state {
+        CheckboxState::Checked => "true",
+        CheckboxState::Unchecked => "false",
+        CheckboxState::Indeterminate => "mixed",
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Filtering
+// ---------------------------------------------------------------------------
+
+/// A custom [`EqTree`] filter: nodes for which it returns `true` match.
+///
+/// Compared by identity, so create it once (e.g. in `use_hook`) unless it
+/// should count as a new filter on every render.
+#[derive(Clone)]
+pub struct TreePredicate(Rc<dyn Fn(&TreeNode) -> bool>);
+
+impl TreePredicate {
+    pub fn new(predicate: impl Fn(&TreeNode) -> bool + 'static) -> Self {
+        Self(Rc::new(predicate))
+    }
+
+    /// Whether `node` matches.
+    pub fn matches(&self, node: &TreeNode) -> bool {
+        (self.0)(node)
+    }
+}
+
+impl PartialEq for TreePredicate {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// The forest as a filter shows it.
+#[derive(Debug, Default, PartialEq)]
+struct FilterView {
+    /// Matches with their whole subtree, plus the ancestors of matches
+    /// (holding only the children that lead to a match).
+    nodes: Vec<TreeNode>,
+    /// Branches to open: every ancestor of a match.
+    expanded: HashSet<String>,
+    /// Number of matching nodes.
+    matches: usize,
+}
+
+/// Apply a filter in one depth-first pass.
+fn filter_tree(nodes: &[TreeNode], is_match: &dyn Fn(&TreeNode) -> bool) -> FilterView {
+    fn prune(
+        node: &TreeNode,
+        is_match: &dyn Fn(&TreeNode) -> bool,
+        view: &mut FilterView,
+    ) -> Option<TreeNode> {
+        let matched = is_match(node);
+        let kept: Vec<TreeNode> = node
+            .children
+            .iter()
+            .filter_map(|child| prune(child, is_match, view))
+            .collect();
+        if !kept.is_empty() {
+            view.expanded.insert(node.id.clone());
+        }
+        if matched {
+            view.matches += 1;
+        } else if kept.is_empty() {
+            return None;
+        }
+        Some(TreeNode {
+            id: node.id.clone(),
+            label: node.label.clone(),
+            parent_id: node.parent_id.clone(),
+            children: if matched { node.children.clone() } else { kept },
+            lazy: node.lazy,
+        })
+    }
+
+    let mut view = FilterView::default();
+    view.nodes = nodes.iter().filter_map(|n| prune(n, is_match, &mut view)).collect();
+    view
+}
+
+/// Byte range of the first case-insensitive occurrence of `query` in
+/// `label`, for highlighting.
+fn match_range(label: &str, query: &str) -> Option<(usize, usize)> {
+    let lower = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let needle: Vec<char> = query.chars().map(lower).collect();
+    if needle.is_empty() {
+        return None;
+    }
+    let chars: Vec<(usize, char)> = label.char_indices().collect();
+    chars
+        .windows(needle.len())
+        .find(|window| window.iter().zip(&needle).all(|(&(_, c), &n)| lower(c) == n))
+        .map(|window| {
+            let (end, last) = window[window.len() - 1];
+            (window[0].0, end + last.len_utf8())
+        })
+}
+
+// ---------------------------------------------------------------------------
+// ARIA helpers – visible-node collection, lookup & type-ahead
+// ---------------------------------------------------------------------------
+
+/// Indent per level of flat (virtualized) rows, matching nested `CHILDREN`.
+const FLAT_INDENT_REM: f64 = 0.75;
+
+/// Pause after the last keystroke before the type-ahead buffer resets.
+const TYPE_AHEAD_RESET_MS: u64 = 500;
+
+/// A node currently on screen, in depth-first order, with what keyboard
+/// navigation, type-ahead and the flat (virtualized) rows need.
+#[derive(Clone, Debug, PartialEq)]
+struct VisibleRow {
+    id: String,
+    label: String,
+    /// 1-based, for `aria-level`.
     depth: usize,
-    out: &mut Vec<(String, usize, bool)>,
-) {
-    for node in nodes {
-        let is_leaf = node.is_leaf();
-        out.push((node.id.clone(), depth, is_leaf));
-        if !is_leaf && expanded.contains(&node.id) {
-            collect_visible(&node.children, expanded, depth + 1, out);
+    is_leaf: bool,
+    /// Siblings at this level (`aria-setsize`).
+    set_size: usize,
+    /// 1-based position among siblings (`aria-posinset`).
+    pos_in_set: usize,
+    /// Child indices from the root list down to the node, so a flat row
+    /// reaches its node without searching the tree.
+    path: Vec<usize>,
+}
+
+/// Collect all currently visible nodes (depth-first) given the set of
+/// expanded branch IDs.
+fn collect_visible(nodes: &[TreeNode], expanded: &HashSet<String>, out: &mut Vec<VisibleRow>) {
+    fn walk(
+        nodes: &[TreeNode],
+        expanded: &HashSet<String>,
+        path: &mut Vec<usize>,
+        out: &mut Vec<VisibleRow>,
+    ) {
+        for (i, node) in nodes.iter().enumerate() {
+            path.push(i);
+            let is_leaf = node.is_leaf();
+            out.push(VisibleRow {
+                id: node.id.clone(),
+                label: node.label.clone(),
+                depth: path.len(),
+                is_leaf,
+                set_size: nodes.len(),
+                pos_in_set: i + 1,
+                path: path.clone(),
+            });
+            if !is_leaf && expanded.contains(&node.id) {
+                walk(&node.children, expanded, path, out);
+            }
+            path.pop();
         }
     }
+    walk(nodes, expanded, &mut Vec::new(), out);
+}
+
+/// The node at a [`VisibleRow::path`].
+fn node_at<'a>(nodes: &'a [TreeNode], path: &[usize]) -> Option<&'a TreeNode> {
+    let (first, rest) = path.split_first()?;
+    rest.iter()
+        .try_fold(nodes.get(*first)?, |node, &i| node.children.get(i))
+}
+
+/// Find a node by id anywhere in the forest.
+fn find_node<'a>(nodes: &'a [TreeNode], id: &str) -> Option<&'a TreeNode> {
+    nodes.iter().find_map(|n| n.find_by_id(id))
 }
 
 /// Walk the tree to find the direct parent of `target`. Returns `None` for
@@ -257,6 +604,29 @@ fn find_parent_id(nodes: &[TreeNode], target: &str) -> Option<String> {
     None
 }
 
+/// Index of the visible row a type-ahead `query` jumps to, matching label
+/// prefixes case-insensitively and wrapping past the end.
+///
+/// A fresh first character searches from the row after `from`, so pressing
+/// it again cycles through matches; a longer query re-checks `from` first so
+/// the focus stays put while the typed word still fits it. Repeating one
+/// character ("aaa") cycles like pressing it alone.
+fn type_ahead(rows: &[VisibleRow], from: Option<usize>, query: &str) -> Option<usize> {
+    let query = query.to_lowercase();
+    let chars: Vec<char> = query.chars().collect();
+    let first = *chars.first()?;
+    let repeat = chars.iter().all(|&c| c == first);
+    let needle = if repeat { first.to_string() } else { query };
+    let start = match from {
+        Some(i) if repeat => i + 1,
+        Some(i) => i,
+        None => 0,
+    };
+    (0..rows.len())
+        .map(|k| (start + k) % rows.len())
+        .find(|&i| rows[i].label.to_lowercase().starts_with(&needle))
+}
+
 // ---------------------------------------------------------------------------
 // EqTree component
 // ---------------------------------------------------------------------------
@@ -264,23 +634,48 @@ fn find_parent_id(nodes: &[TreeNode], target: &str) -> Option<String> {
 /// A collapsible tree view molecule.
 ///
 /// Renders a list of `TreeNode`s with expand/collapse for branches
-/// and click-to-select for leaves.
+/// and click-to-select for leaves. Optionally:
+///
+/// - **Lazy loading** – branches built with [`TreeNode::new_lazy`] fire
+///   `on_load_children` on first expand and show a spinner until their
+///   children are set.
+/// - **Checkboxes** – `checkable` adds a tri-state [`EqCheckbox`] per node;
+///   checking cascades to descendants and partially checked branches show
+///   the indeterminate state.
+/// - **Drag-and-drop** – `draggable` lets nodes be dropped before, inside
+///   or after others; `on_drop` reports a [`TreeDrop`] to apply with
+///   [`TreeDrop::apply`].
+/// - **Virtualization** – `virtualized` flattens the visible nodes into an
+///   [`EqVirtualList`] and renders only the rows in view, for trees with
+///   100k+ nodes. Keyboard navigation keeps the focused row scrolled into
+///   view, and each flat row carries its `aria-level` / `aria-setsize` /
+///   `aria-posinset`.
+/// - **Filtering** – `filter` (a label query) and/or `filter_predicate`
+///   hide the subtrees without matches, open the ancestors of every match
+///   and highlight the matched text; `on_match_count` reports the count.
+///   Clearing the filter restores the previous expansion state.
 ///
 /// **Accessibility** – implements the WAI-ARIA [Tree View][tv] pattern:
 /// `role="tree"` on the root, `role="treeitem"` on each node,
 /// `role="group"` on children containers, `aria-expanded` / `aria-selected`
-/// / `aria-level` / `aria-setsize` / `aria-posinset` on every item,
-/// roving `tabindex` with programmatic focus, and full keyboard navigation
-/// (Up / Down / Left / Right / Home / End / Enter / Space).
+/// / `aria-checked` / `aria-level` / `aria-setsize` / `aria-posinset` on
+/// every item, roving `tabindex` with programmatic focus, full keyboard
+/// navigation (Up / Down / Left / Right / Home / End / Enter / Space) and
+/// type-ahead: typing a label's first letters focuses the next match.
 ///
 /// [tv]: https://www.w3.org/WAI/ARIA/apg/patterns/treeview/
 #[playground(
     category = Molecule,
     description = "Collapsible tree view for hierarchical data. Branches expand to show children, \
-                   leaves trigger selection events. Optional child count display.",
+                   leaves trigger selection events. Optional child counts, lazy-loaded branches, \
+                   cascading checkboxes, drag-and-drop, type-ahead, filtering with highlighted matches and a virtualized mode for huge trees.",
     examples = [
         ("Basic", "let nodes = vec![\n    TreeNode::new_with_children(\"branch\", \"Branch\", vec![\n        TreeNode::new(\"leaf-1\", \"Leaf 1\"),\n        TreeNode::new(\"leaf-2\", \"Leaf 2\"),\n    ]),\n];\n\nEqTree {\n    nodes: nodes,\n    on_select: move |id: String| { /* handle */ },\n}"),
         ("With counts", "EqTree {\n    nodes,\n    selected: selected(),\n    on_select: move |id: String| selected.set(Some(id)),\n    show_count: true,\n}"),
+        ("Lazy loading", "EqTree {\n    nodes: tree(),\n    on_select: move |_| {},\n    on_load_children: move |id: String| {\n        spawn(async move {\n            let children = fetch_children(&id).await;\n            if let Some(node) = tree.write().iter_mut().find_map(|n| n.find_by_id_mut(&id)) {\n                node.set_children(children);\n            }\n        });\n    },\n}"),
+        ("Checkboxes and drag-and-drop", "EqTree {\n    nodes: tree(),\n    on_select: move |_| {},\n    checkable: true,\n    on_check: move |ids: Vec<String>| checked.set(ids),\n    draggable: true,\n    on_drop: move |drop: TreeDrop| { drop.apply(&mut tree.write()); },\n}"),
+        ("Filter", "EqTree {\n    nodes,\n    on_select: move |_| {},\n    filter: query(),\n    on_match_count: move |n: usize| matches.set(n),\n}"),
+        ("Virtualized", "EqTree {\n    nodes: files,\n    on_select: move |id: String| open(id),\n    virtualized: true,\n    row_height: 36.0,\n    viewport_height: 480.0,\n}"),
     ],
     custom_demo,
     custom_gallery,
@@ -297,6 +692,52 @@ pub fn EqTree(
     /// When `true`, branch nodes show their direct child count, e.g. "Atoms (8)".
     #[props(default)]
     show_count: bool,
+    /// Fires with a lazy node's `id` when it is expanded before its children
+    /// arrived. Load them (async is fine) and hand them over with
+    /// [`TreeNode::set_children`]; the node shows a spinner meanwhile.
+    #[props(default)]
+    on_load_children: Option<EventHandler<String>>,
+    /// When `true`, every node gets a tri-state checkbox. Space toggles the
+    /// focused node's checkbox instead of activating it.
+    #[props(default)]
+    checkable: bool,
+    /// Controlled checked ids. Leave `None` to let the tree track them.
+    #[props(default)]
+    checked: Option<Vec<String>>,
+    /// Fires with every checked id, in depth-first order, after a toggle.
+    #[props(default)]
+    on_check: Option<EventHandler<Vec<String>>>,
+    /// When `true`, nodes can be dragged before, inside or after others.
+    #[props(default)]
+    draggable: bool,
+    /// Fires when a node is dropped on a valid target.
+    #[props(default)]
+    on_drop: Option<EventHandler<TreeDrop>>,
+    /// Render only the rows in view, for very large trees: the visible
+    /// nodes are flattened into an [`EqVirtualList`] of fixed-height rows
+    /// inside a `viewport_height` scroll area.
+    #[props(default)]
+    virtualized: bool,
+    /// Row height in pixels (virtualized mode).
+    #[props(default = 36.0)]
+    row_height: f64,
+    /// Height of the scroll area in pixels (virtualized mode).
+    #[props(default = 400.0)]
+    viewport_height: f64,
+    /// Filter query. Only nodes whose label contains it (ignoring case),
+    /// their subtrees and their ancestors stay visible; the ancestors open
+    /// up and the matched text is highlighted. Clearing it restores the
+    /// expansion state from before filtering.
+    #[props(into, default)]
+    filter: String,
+    /// Custom filter, alone or narrowing `filter`: a node matches when
+    /// it passes both.
+    #[props(default)]
+    filter_predicate: Option<TreePredicate>,
+    /// Fires with the number of matching nodes whenever it changes while
+    /// a filter is active.
+    #[props(default)]
+    on_match_count: Option<EventHandler<usize>>,
     /// Accessible label for screen readers (e.g. "File browser",
     /// "Component list"). Announced as "{label}, tree".
     #[props(into, default = "Tree".to_string())]
@@ -318,14 +759,169 @@ pub fn EqTree(
     // Roving tabindex: the focused node id.
     let mut focused_id: Signal<String> = use_signal(String::new);
 
+    // Checked ids — mirrors `checked` when controlled.
+    let mut checked_set: Signal<HashSet<String>> =
+        use_signal(|| checked.iter().flatten().cloned().collect());
+
+    // Drag-and-drop: the node in flight and the zone under the pointer.
+    let mut dragging: Signal<Option<String>> = use_signal(|| None);
+    let mut drop_target: Signal<Option<(String, DropPosition)>> = use_signal(|| None);
+
+    // Virtualized mode: a keyboard move whose target row is not rendered
+    // yet; the row focuses itself when it mounts.
+    let mut focus_pending: Signal<Option<String>> = use_signal(|| None);
+
+    // The user's expansion state, parked while a filter opens the
+    // ancestors of its matches.
+    let mut saved_expanded: Signal<Option<HashSet<String>>> = use_signal(|| None);
+    let query = use_memo(use_reactive((&filter,), |(filter,)| filter.trim().to_string()));
+
+    // Type-ahead buffer and the pending task that clears it.
+    let mut typed: Signal<String> = use_signal(String::new);
+    let mut typed_reset: Signal<Option<Task>> = use_signal(|| None);
+
+    use_effect(use_reactive((&checked,), move |(checked,)| {
+        if let Some(ids) = checked {
+            checked_set.set(ids.into_iter().collect());
+        }
+    }));
+
     let cls = merge_classes(s::TREE, &class);
 
-    // Pre-compute values needed by the keyboard closure and the rsx iterator.
-    let total_roots = nodes.len();
-    let first_id = nodes.first().map(|n| n.id.clone()).unwrap_or_default();
+    // ── Filtering ────────────────────────────────────────────────
+    let forest = Rc::new(nodes);
+    let view = {
+        let query = query.read();
+        (!query.is_empty() || filter_predicate.is_some()).then(|| {
+            filter_tree(&forest, &|node: &TreeNode| {
+                (query.is_empty() || match_range(&node.label, &query).is_some())
+                    && filter_predicate.as_ref().is_none_or(|p| p.matches(node))
+            })
+        })
+    };
+    let match_count = view.as_ref().map(|v| v.matches);
+    let auto_expanded = view.as_ref().map(|v| v.expanded.clone());
+
+    // Swap the expansion state in and out as filtering starts and stops.
+    use_effect(use_reactive(
+        (&query(), &filter_predicate, &match_count),
+        move |(_, _, match_count)| {
+            match auto_expanded.clone() {
+                Some(auto) => {
+                    if saved_expanded.peek().is_none() {
+                        saved_expanded.set(Some(expanded_set.peek().clone()));
+                    }
+                    expanded_set.set(auto);
+                }
+                None => {
+                    let Some(saved) = saved_expanded.take() else { return; };
+                    expanded_set.set(saved);
+                }
+            }
+            // The focused node may be hidden now; fall back to the first.
+            focused_id.set(String::new());
+            if let (Some(handler), Some(count)) = (on_match_count, match_count) {
+                handler.call(count);
+            }
+        },
+    ));
+
+    // What is on screen: the filtered view or the whole forest.
+    let shown = match view {
+        Some(view) => Rc::new(view.nodes),
+        None => forest.clone(),
+    };
+
+    // Pre-compute values needed by the closures and the rsx iterator.
+    let total_roots = shown.len();
+    let first_id = shown.first().map(|n| n.id.clone()).unwrap_or_default();
+    let controlled = checked.is_some();
+
+    // Expand (firing a lazy load when needed) or collapse a branch.
+    let set_expanded = use_callback({
+        let forest = forest.clone();
+        move |(id, open): (String, bool)| {
+            if !open {
+                expanded_set.write().remove(&id);
+                return;
+            }
+            let newly_opened = expanded_set.write().insert(id.clone());
+            if newly_opened
+                && let Some(handler) = on_load_children
+                && find_node(&forest, &id).is_some_and(TreeNode::is_pending)
+            {
+                handler.call(id);
+            }
+        }
+    });
+
+    let toggle_check = use_callback({
+        let forest = forest.clone();
+        move |id: String| {
+            let next = toggle_checked(&forest, &checked_set.peek(), &id);
+            if let Some(handler) = on_check {
+                handler.call(checked_ids(&forest, &next));
+            }
+            if !controlled {
+                checked_set.set(next);
+            }
+        }
+    });
+
+    let drop_allowed = use_callback({
+        let forest = forest.clone();
+        move |target_id: String| {
+            dragging
+                .peek()
+                .as_deref()
+                .is_some_and(|id| can_drop(&forest, id, &target_id))
+        }
+    });
+
+    let drop_on = use_callback(move |(target_id, position): (String, DropPosition)| {
+        drop_target.set(None);
+        let Some(node_id) = dragging.take() else { return; };
+        if position == DropPosition::Inside {
+            expanded_set.write().insert(target_id.clone());
+        }
+        if let Some(handler) = on_drop {
+            handler.call(TreeDrop { node_id, target_id, position });
+        }
+    });
+
+    let ctx = TreeCtx {
+        query,
+        show_count,
+        checkable,
+        draggable,
+        expanded: expanded_set,
+        focused: focused_id,
+        checked: checked_set,
+        dragging,
+        drop_target,
+        on_select,
+        set_expanded,
+        toggle_check,
+        drop_allowed,
+        drop_on,
+    };
 
-    // Clone nodes for the keyboard handler closure (it needs its own copy).
-    let nodes_kb = nodes.clone();
+    // Virtualized mode: the flattened visible rows and the focused one,
+    // which the list keeps scrolled into view.
+    let rows = if virtualized {
+        let mut rows = Vec::new();
+        collect_visible(&shown, &expanded_set.read(), &mut rows);
+        rows
+    } else {
+        Vec::new()
+    };
+    let focused_row = rows.iter().position(|row| row.id == *focused_id.read());
+    let rows = Rc::new(rows);
+    let forest_vl = shown.clone();
+    let prefix_vl = tree_id_prefix.clone();
+    let selected_vl = selected.clone();
+
+    let forest_kb = shown.clone();
     let prefix_kb = tree_id_prefix.clone();
 
     rsx! {
@@ -333,26 +929,46 @@ pub fn EqTree(
             class: "{cls}",
             role: "tree",
             "aria-label": "{aria_label}",
+            "aria-multiselectable": if checkable { Some("true") } else { None },
+
+            ondragend: move |_| {
+                dragging.set(None);
+                drop_target.set(None);
+            },
+            onfocusin: move |_| {
+                if focus_pending.peek().is_some() {
+                    focus_pending.set(None);
+                }
+            },
 
             // ── Keyboard navigation ──────────────────────────────
             onkeydown: move |evt: Event<KeyboardData>| {
                 let key = evt.key();
                 let expanded = expanded_set();
-                let mut visible: Vec<(String, usize, bool)> = Vec::new();
-                collect_visible(&nodes_kb, &expanded, 1, &mut visible);
+                let mut visible: Vec<VisibleRow> = Vec::new();
+                collect_visible(&forest_kb, &expanded, &mut visible);
                 if visible.is_empty() { return; }
 
                 let cur = focused_id();
-                let cur_idx = visible.iter().position(|(id, _, _)| *id == cur);
+                let cur_idx = visible.iter().position(|row| row.id == cur);
 
                 // Helper: focus a node by index in the visible list.
                 let mut focus = |idx: usize| {
-                    let target = &visible[idx].0;
+                    let target = &visible[idx].id;
                     focused_id.set(target.clone());
                     let dom_id = format!("{}-{}", prefix_kb, target);
-                    document::eval(&format!(
-                        "document.getElementById('{dom_id}')?.focus()"
-                    ));
+                    if virtualized {
+                        // The list scrolls the row into view; it may only
+                        // exist once that has rendered.
+                        focus_pending.set(Some(target.clone()));
+                        document::eval(&format!(
+                            "document.getElementById('{dom_id}')?.focus({{ preventScroll: true }})"
+                        ));
+                    } else {
+                        document::eval(&format!(
+                            "document.getElementById('{dom_id}')?.focus()"
+                        ));
+                    }
                 };
 
                 if key == Key::ArrowDown {
@@ -372,14 +988,12 @@ pub fn EqTree(
                 } else if key == Key::ArrowRight {
                     evt.prevent_default();
                     if let Some(i) = cur_idx {
-                        let (ref id, _, is_leaf) = visible[i];
-                        if !is_leaf {
-                            if !expanded.contains(id) {
+                        let row = &visible[i];
+                        if !row.is_leaf {
+                            if !expanded.contains(&row.id) {
                                 // Expand (focus stays on current node per WAI-ARIA).
-                                let mut set = expanded_set();
-                                set.insert(id.clone());
-                                expanded_set.set(set);
-                            } else if i + 1 < visible.len() && visible[i + 1].1 > visible[i].1 {
+                                set_expanded.call((row.id.clone(), true));
+                            } else if i + 1 < visible.len() && visible[i + 1].depth > row.depth {
                                 // Already expanded — move to first child.
                                 focus(i + 1);
                             }
@@ -388,17 +1002,15 @@ pub fn EqTree(
                 } else if key == Key::ArrowLeft {
                     evt.prevent_default();
                     if let Some(i) = cur_idx {
-                        let (ref id, _, is_leaf) = visible[i];
-                        if !is_leaf && expanded.contains(id) {
+                        let row = &visible[i];
+                        if !row.is_leaf && expanded.contains(&row.id) {
                             // Collapse (focus stays).
-                            let mut set = expanded_set();
-                            set.remove(id);
-                            expanded_set.set(set);
+                            set_expanded.call((row.id.clone(), false));
                         } else {
                             // Move to parent.
-                            let parent = find_parent_id(&nodes_kb, id);
+                            let parent = find_parent_id(&forest_kb, &row.id);
                             if let Some(pi) = parent
-                                .and_then(|p| visible.iter().position(|(pid, _, _)| *pid == p))
+                                .and_then(|p| visible.iter().position(|r| r.id == p))
                             {
                                 focus(pi);
                             }
@@ -410,39 +1022,87 @@ pub fn EqTree(
                 } else if key == Key::End {
                     evt.prevent_default();
                     focus(visible.len() - 1);
+                } else if checkable && key == Key::Character(" ".into()) {
+                    evt.prevent_default();
+                    if let Some(i) = cur_idx {
+                        toggle_check.call(visible[i].id.clone());
+                    }
                 } else if key == Key::Enter || key == Key::Character(" ".into()) {
                     evt.prevent_default();
                     if let Some(i) = cur_idx {
-                        let (ref id, _, is_leaf) = visible[i];
-                        if is_leaf {
-                            on_select.call(id.clone());
+                        let row = &visible[i];
+                        if row.is_leaf {
+                            on_select.call(row.id.clone());
                         } else {
-                            let mut set = expanded_set();
-                            if set.contains(id) {
-                                set.remove(id);
-                            } else {
-                                set.insert(id.clone());
-                            }
-                            expanded_set.set(set);
+                            set_expanded.call((row.id.clone(), !expanded.contains(&row.id)));
                         }
                     }
+                } else if let Key::Character(ref text) = key
+                    && !evt.modifiers().intersects(Modifiers::CONTROL | Modifiers::ALT | Modifiers::META)
+                {
+                    // Type-ahead: extend the buffer and restart its reset timer.
+                    let query = {
+                        let mut buf = typed.write();
+                        buf.push_str(text);
+                        buf.clone()
+                    };
+                    if let Some(task) = typed_reset.take() {
+                        task.cancel();
+                    }
+                    typed_reset.set(Some(spawn(async move {
+                        crate::timer::sleep(TYPE_AHEAD_RESET_MS).await;
+                        typed.set(String::new());
+                    })));
+                    if let Some(i) = type_ahead(&visible, cur_idx, &query) {
+                        focus(i);
+                    }
                 }
             },
 
-            for (i, node) in nodes.into_iter().enumerate() {
-                TreeBranch {
-                    key: "{node.id}",
-                    node: node,
-                    on_select: on_select,
-                    selected: selected.clone(),
-                    show_count: show_count,
-                    expanded_set: expanded_set,
-                    focused_id: focused_id,
-                    tree_id_prefix: tree_id_prefix.clone(),
-                    first_node_id: first_id.clone(),
-                    depth: 1,
-                    set_size: total_roots,
-                    pos_in_set: i + 1,
+            if virtualized {
+                EqVirtualList {
+                    item_count: rows.len(),
+                    item_size: row_height,
+                    viewport_size: viewport_height,
+                    scroll_to_index: focused_row,
+                    scroll_align: ScrollAlign::Nearest,
+                    class: s::VIRTUAL_VIEWPORT,
+                    render_item: move |idx: usize| {
+                        let Some(row) = rows.get(idx) else { return rsx! {} };
+                        let Some(node) = node_at(&forest_vl, &row.path) else { return rsx! {} };
+                        let is_expanded = !row.is_leaf && expanded_set.read().contains(&row.id);
+                        rsx! {
+                            FlatTreeItem {
+                                ctx,
+                                row: row.clone(),
+                                tree_id_prefix: prefix_vl.clone(),
+                                focus_target: match focused_row {
+                                    Some(f) => f == idx,
+                                    None => idx == 0,
+                                },
+                                focus_pending,
+                                is_selected: selected_vl.as_deref() == Some(row.id.as_str()),
+                                is_expanded,
+                                loading: is_expanded && node.is_pending(),
+                                child_count: node.children.len(),
+                                check: checkable.then(|| check_state(node, &checked_set.read())),
+                            }
+                        }
+                    },
+                }
+            } else {
+                for (i, node) in shown.iter().enumerate() {
+                    TreeBranch {
+                        key: "{node.id}",
+                        node: node.clone(),
+                        ctx: ctx,
+                        selected: selected.clone(),
+                        tree_id_prefix: tree_id_prefix.clone(),
+                        first_node_id: first_id.clone(),
+                        depth: 1,
+                        set_size: total_roots,
+                        pos_in_set: i + 1,
+                    }
                 }
             }
         }
@@ -450,19 +1110,38 @@ pub fn EqTree(
 }
 
 // ---------------------------------------------------------------------------
-// Internal recursive renderer
+// Internal renderers
 // ---------------------------------------------------------------------------
 
+/// Shared state and behaviour EqTree hands to every node it renders.
+#[derive(Clone, Copy, PartialEq)]
+struct TreeCtx {
+    /// Active filter query, for highlighting.
+    query: Memo<String>,
+    show_count: bool,
+    checkable: bool,
+    draggable: bool,
+    /// Shared expansion state.
+    expanded: Signal<HashSet<String>>,
+    /// Currently focused node id (roving tabindex).
+    focused: Signal<String>,
+    checked: Signal<HashSet<String>>,
+    dragging: Signal<Option<String>>,
+    drop_target: Signal<Option<(String, DropPosition)>>,
+    on_select: EventHandler<String>,
+    /// Expand (`true`) or collapse a branch, loading lazy children.
+    set_expanded: Callback<(String, bool)>,
+    toggle_check: Callback<String>,
+    /// Whether the node in flight may be dropped on the given id.
+    drop_allowed: Callback<String, bool>,
+    drop_on: Callback<(String, DropPosition)>,
+}
+
 #[component]
 fn TreeBranch(
     node: TreeNode,
-    on_select: EventHandler<String>,
+    ctx: TreeCtx,
     selected: Option<String>,
-    show_count: bool,
-    /// Shared expansion state managed by EqTree.
-    expanded_set: Signal<HashSet<String>>,
-    /// Currently focused node id (roving tabindex).
-    focused_id: Signal<String>,
     /// DOM-id prefix unique to this tree instance.
     tree_id_prefix: String,
     /// ID of the first root node (fallback focus target).
@@ -476,23 +1155,20 @@ fn TreeBranch(
 ) -> Element {
     let is_leaf = node.is_leaf();
     let is_selected = selected.as_deref() == Some(node.id.as_str());
-    let is_expanded = !is_leaf && expanded_set().contains(&node.id);
+    let is_expanded = !is_leaf && ctx.expanded.read().contains(&node.id);
+    let loading = is_expanded && node.is_pending();
+    let check = ctx.checkable.then(|| check_state(&node, &ctx.checked.read()));
 
     // Roving tabindex: the focused node gets 0, everything else -1.
     // When nothing is focused yet the first root node wins.
-    let cur_focused = focused_id();
+    let cur_focused = (ctx.focused)();
     let is_focus_target = if cur_focused.is_empty() {
         node.id == first_node_id
     } else {
         cur_focused == node.id
     };
 
-    let row_class = if is_selected { s::NODE_ACTIVE } else { s::NODE_ROW };
-
-    let node_id_click = node.id.clone();
-    let label = node.label.clone();
     let child_count = node.children.len();
-    let chevron_rotate = if is_expanded { s::CHEVRON_EXPANDED } else { "" };
     let dom_id = format!("{}-{}", tree_id_prefix, node.id);
     let tab_idx = if is_focus_target { "0" } else { "-1" };
 
@@ -502,57 +1178,28 @@ fn TreeBranch(
             id: "{dom_id}",
             "aria-expanded": if !is_leaf { if is_expanded { "true" } else { "false" } } else { "" },
             "aria-selected": if is_selected { "true" } else { "false" },
+            "aria-checked": check.map(aria_checked),
+            "aria-busy": if loading { Some("true") } else { None },
             "aria-level": "{depth}",
             "aria-setsize": "{set_size}",
             "aria-posinset": "{pos_in_set}",
-            "aria-label": "{label}",
+            "aria-label": "{node.label}",
             tabindex: "{tab_idx}",
 
-            // Node row (visual only — interaction handled by treeitem above via keyboard)
-            div {
-                class: row_class,
-                onclick: move |_| {
-                    focused_id.set(node_id_click.clone());
-                    if is_leaf {
-                        on_select.call(node_id_click.clone());
-                    } else {
-                        let mut set = expanded_set();
-                        if set.contains(&node_id_click) {
-                            set.remove(&node_id_click);
-                        } else {
-                            set.insert(node_id_click.clone());
-                        }
-                        expanded_set.set(set);
-                    }
-                },
-
-                // Chevron or spacer
-                if !is_leaf {
-                    svg {
-                        class: "{s::CHEVRON} {chevron_rotate}",
-                        xmlns: "http://www.w3.org/2000/svg",
-                        fill: "none",
-                        view_box: "0 0 24 24",
-                        stroke_width: "2",
-                        stroke: "currentColor",
-                        "aria-hidden": "true",
-                        path { d: "m9 5 7 7-7 7" }
-                    }
-                } else {
-                    span { class: s::LEAF_SPACER, "aria-hidden": "true" }
-                }
-
-                // Label
-                span { class: s::LABEL, "{label}" }
-
-                // Direct child count for branches
-                if show_count && !is_leaf {
-                    span { class: s::COUNT, "aria-hidden": "true", "({child_count})" }
-                }
+            TreeRow {
+                ctx,
+                id: node.id.clone(),
+                label: node.label.clone(),
+                is_leaf,
+                is_expanded,
+                is_selected,
+                loading,
+                child_count,
+                check,
             }
 
-            // Children (shown when expanded)
-            if !is_leaf && is_expanded {
+            // Children (shown when expanded and loaded)
+            if is_expanded && !loading {
                 div {
                     class: s::CHILDREN,
                     role: "group",
@@ -560,11 +1207,8 @@ fn TreeBranch(
                         TreeBranch {
                             key: "{child.id}",
                             node: child,
-                            on_select: on_select,
+                            ctx,
                             selected: selected.clone(),
-                            show_count: show_count,
-                            expanded_set: expanded_set,
-                            focused_id: focused_id,
                             tree_id_prefix: tree_id_prefix.clone(),
                             first_node_id: first_node_id.clone(),
                             depth: depth + 1,
@@ -578,6 +1222,218 @@ fn TreeBranch(
     }
 }
 
+/// A treeitem in virtualized mode: one flat, fixed-height row indented by
+/// depth, carrying the level / set-size / position ARIA attributes that
+/// the nested groups would otherwise imply.
+#[component]
+fn FlatTreeItem(
+    ctx: TreeCtx,
+    row: VisibleRow,
+    tree_id_prefix: String,
+    /// Holds the roving tab stop.
+    focus_target: bool,
+    /// Node a keyboard move is waiting to focus.
+    focus_pending: Signal<Option<String>>,
+    is_selected: bool,
+    is_expanded: bool,
+    loading: bool,
+    child_count: usize,
+    check: Option<CheckboxState>,
+) -> Element {
+    let mut focus_pending = focus_pending;
+    let dom_id = format!("{}-{}", tree_id_prefix, row.id);
+    let tab_idx = if focus_target { "0" } else { "-1" };
+    let indent = (row.depth - 1) as f64 * FLAT_INDENT_REM;
+    let id_mount = row.id.clone();
+
+    rsx! {
+        div {
+            role: "treeitem",
+            id: "{dom_id}",
+            class: s::FLAT_ITEM,
+            style: "padding-left: {indent}rem;",
+            "aria-expanded": if !row.is_leaf { if is_expanded { "true" } else { "false" } } else { "" },
+            "aria-selected": if is_selected { "true" } else { "false" },
+            "aria-checked": check.map(aria_checked),
+            "aria-busy": if loading { Some("true") } else { None },
+            "aria-level": "{row.depth}",
+            "aria-setsize": "{row.set_size}",
+            "aria-posinset": "{row.pos_in_set}",
+            "aria-label": "{row.label}",
+            tabindex: "{tab_idx}",
+            onmounted: move |evt: MountedEvent| {
+                if focus_pending.peek().as_deref() == Some(id_mount.as_str()) {
+                    focus_pending.set(None);
+                    spawn(async move {
+                        let _ = evt.set_focus(true).await;
+                    });
+                }
+            },
+
+            TreeRow {
+                ctx,
+                id: row.id.clone(),
+                label: row.label.clone(),
+                is_leaf: row.is_leaf,
+                is_expanded,
+                is_selected,
+                loading,
+                child_count,
+                check,
+            }
+        }
+    }
+}
+
+/// The visible row of a node — chevron or spinner, optional checkbox,
+/// label and count — plus its click and drag-and-drop wiring.
+#[component]
+fn TreeRow(
+    ctx: TreeCtx,
+    id: String,
+    label: String,
+    is_leaf: bool,
+    is_expanded: bool,
+    is_selected: bool,
+    /// Expanded lazy branch still waiting for its children.
+    loading: bool,
+    child_count: usize,
+    /// Checkbox state, when the tree is `checkable`.
+    check: Option<CheckboxState>,
+) -> Element {
+    let TreeCtx { mut focused, mut dragging, mut drop_target, .. } = ctx;
+
+    let row_class = if is_selected { s::NODE_ACTIVE } else { s::NODE_ROW };
+    let highlight = match_range(&label, &ctx.query.read());
+    let chevron_rotate = if is_expanded { s::CHEVRON_EXPANDED } else { "" };
+
+    // Drop zones only exist while a drag is in flight over a valid target.
+    let drag_active = dragging.read().is_some();
+    let is_dragged = dragging.read().as_deref() == Some(id.as_str());
+    let droppable = drag_active && ctx.drop_allowed.call(id.clone());
+    let indicator = match &*drop_target.read() {
+        Some((target, position)) if *target == id => match position {
+            DropPosition::Before => s::DROP_BEFORE,
+            DropPosition::Inside => s::DROP_INSIDE,
+            DropPosition::After => s::DROP_AFTER,
+        },
+        _ => "",
+    };
+    let drag_cls = if is_dragged { s::DRAGGING } else { "" };
+    let zones: &[(DropPosition, &str)] = if is_leaf {
+        &[
+            (DropPosition::Before, s::DROP_ZONE_LEAF_BEFORE),
+            (DropPosition::After, s::DROP_ZONE_LEAF_AFTER),
+        ]
+    } else {
+        &[
+            (DropPosition::Before, s::DROP_ZONE_BEFORE),
+            (DropPosition::Inside, s::DROP_ZONE_INSIDE),
+            (DropPosition::After, s::DROP_ZONE_AFTER),
+        ]
+    };
+
+    let id_click = id.clone();
+    let id_drag = id.clone();
+    let id_check = id.clone();
+
+    rsx! {
+        div {
+            class: "{row_class} {indicator} {drag_cls}",
+            draggable: if ctx.draggable { "true" } else { "false" },
+            onclick: move |_| {
+                focused.set(id_click.clone());
+                if is_leaf {
+                    ctx.on_select.call(id_click.clone());
+                } else {
+                    ctx.set_expanded.call((id_click.clone(), !is_expanded));
+                }
+            },
+            ondragstart: move |evt: Event<DragData>| {
+                if !ctx.draggable { return; }
+                let transfer = evt.data_transfer();
+                let _ = transfer.set_data("text/plain", &id_drag);
+                transfer.set_effect_allowed("move");
+                dragging.set(Some(id_drag.clone()));
+            },
+
+            // Spinner, chevron or spacer
+            if loading {
+                span { class: s::SPINNER, "aria-hidden": "true" }
+            } else if !is_leaf {
+                svg {
+                    class: "{s::CHEVRON} {chevron_rotate}",
+                    xmlns: "http://www.w3.org/2000/svg",
+                    fill: "none",
+                    view_box: "0 0 24 24",
+                    stroke_width: "2",
+                    stroke: "currentColor",
+                    "aria-hidden": "true",
+                    path { d: "m9 5 7 7-7 7" }
+                }
+            } else {
+                span { class: s::LEAF_SPACER, "aria-hidden": "true" }
+            }
+
+            // Checkbox — state is exposed on the treeitem, so it takes no focus.
+            if let Some(state) = check {
+                EqCheckbox {
+                    state,
+                    focusable: false,
+                    class: s::CHECKBOX,
+                    on_change: move |_| {
+                        focused.set(id_check.clone());
+                        ctx.toggle_check.call(id_check.clone());
+                    },
+                }
+            }
+
+            // Label, with the filter match highlighted
+            if let Some((start, end)) = highlight {
+                span { class: s::LABEL,
+                    "{&label[..start]}"
+                    mark { class: s::MATCH, "{&label[start..end]}" }
+                    "{&label[end..]}"
+                }
+            } else {
+                span { class: s::LABEL, "{label}" }
+            }
+
+            // Direct child count for branches
+            if ctx.show_count && !is_leaf && !loading {
+                span { class: s::COUNT, "aria-hidden": "true", "({child_count})" }
+            }
+
+            if droppable {
+                for (position, zone_cls) in zones.iter().copied() {
+                    div {
+                        key: "{position:?}",
+                        class: zone_cls,
+                        ondragover: {
+                            let id = id.clone();
+                            move |evt: Event<DragData>| {
+                                evt.prevent_default();
+                                evt.data_transfer().set_drop_effect("move");
+                                let target = Some((id.clone(), position));
+                                if *drop_target.peek() != target {
+                                    drop_target.set(target);
+                                }
+                            }
+                        },
+                        ondrop: {
+                            let id = id.clone();
+                            move |evt: Event<DragData>| {
+                                evt.prevent_default();
+                                ctx.drop_on.call((id.clone(), position));
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
 // ── Interactive demo ───────────────────────────────────────────────
 
 #[cfg(feature = "playground")]
@@ -585,32 +1441,40 @@ fn TreeBranch(
 fn DemoEqTree() -> Element {
     let mut selected = use_signal(|| Option::<String>::None);
     let mut show_count = use_signal(|| true);
+    let mut checkable = use_signal(|| false);
+    let mut draggable = use_signal(|| false);
+    let mut checked = use_signal(Vec::<String>::new);
+    let mut filter = use_signal(String::new);
+    let mut match_count = use_signal(|| 0_usize);
+
+    let mut tree = use_signal(|| {
+        vec![
+            TreeNode::new_with_children(
+                "demo-branch-a",
+                "Branch A",
+                vec![
+                    TreeNode::new("leaf-1", "Leaf 1"),
+                    TreeNode::new("leaf-2", "Leaf 2"),
+                    TreeNode::new_with_children(
+                        "nested-branch",
+                        "Nested Branch",
+                        vec![TreeNode::new("deep-leaf", "Deep Leaf")],
+                    ),
+                ],
+            ),
+            TreeNode::new_with_children(
+                "demo-branch-b",
+                "Branch B",
+                vec![
+                    TreeNode::new("leaf-3", "Leaf 3"),
+                    TreeNode::new("leaf-4", "Leaf 4"),
+                ],
+            ),
+            TreeNode::new_lazy("demo-remote", "Remote (lazy)"),
+        ]
+    });
 
-    let tree = vec![
-        TreeNode::new_with_children(
-            "demo-branch-a",
-            "Branch A",
-            vec![
-                TreeNode::new("leaf-1", "Leaf 1"),
-                TreeNode::new("leaf-2", "Leaf 2"),
-                TreeNode::new_with_children(
-                    "nested-branch",
-                    "Nested Branch",
-                    vec![TreeNode::new("deep-leaf", "Deep Leaf")],
-                ),
-            ],
-        ),
-        TreeNode::new_with_children(
-            "demo-branch-b",
-            "Branch B",
-            vec![
-                TreeNode::new("leaf-3", "Leaf 3"),
-                TreeNode::new("leaf-4", "Leaf 4"),
-            ],
-        ),
-    ];
-
-    let code = "let nodes = vec![\n    TreeNode::new_with_children(\"branch\", \"Branch\", vec![\n        TreeNode::new(\"leaf-1\", \"Leaf 1\"),\n        TreeNode::new(\"leaf-2\", \"Leaf 2\"),\n    ]),\n];\n\nEqTree {\n    nodes: nodes,\n    selected: selected(),\n    on_select: move |id: String| selected.set(Some(id)),\n    show_count: true,\n}".to_string();
+    let code = "let mut tree = use_signal(|| vec![\n    TreeNode::new_with_children(\"branch\", \"Branch\", vec![\n        TreeNode::new(\"leaf-1\", \"Leaf 1\"),\n        TreeNode::new(\"leaf-2\", \"Leaf 2\"),\n    ]),\n    TreeNode::new_lazy(\"remote\", \"Remote\"),\n]);\n\nEqTree {\n    nodes: tree(),\n    selected: selected(),\n    on_select: move |id: String| selected.set(Some(id)),\n    show_count: true,\n    on_load_children: move |id: String| {\n        spawn(async move {\n            let children = fetch_children(&id).await;\n            if let Some(node) = tree.write().iter_mut().find_map(|n| n.find_by_id_mut(&id)) {\n                node.set_children(children);\n            }\n        });\n    },\n    checkable: true,\n    on_check: move |ids: Vec<String>| checked.set(ids),\n    draggable: true,\n    on_drop: move |drop: TreeDrop| { drop.apply(&mut tree.write()); },\n    filter: query(),\n    on_match_count: move |n: usize| matches.set(n),\n}".to_string();
 
     rsx! {
         DemoSection { title: "EqTree",
@@ -625,28 +1489,109 @@ fn DemoEqTree() -> Element {
                     value: show_count(),
                     onchange: move |v: bool| show_count.set(v),
                 }
+                PropToggle {
+                    label: "checkable",
+                    value: checkable(),
+                    onchange: move |v: bool| checkable.set(v),
+                }
+                PropToggle {
+                    label: "draggable",
+                    value: draggable(),
+                    onchange: move |v: bool| draggable.set(v),
+                }
+                PropInput {
+                    label: "filter",
+                    value: filter(),
+                    placeholder: "e.g. leaf",
+                    onchange: move |v: String| filter.set(v),
+                }
             }
             div { class: "flex gap-6",
                 div { class: "w-64 h-64 flex flex-col border border-[var(--color-card-border)] rounded-lg p-3",
                     EqScrollableSpace {
                         EqTree {
-                            nodes: tree,
+                            nodes: tree(),
                             selected: selected(),
                             on_select: move |id: String| selected.set(Some(id)),
                             show_count: show_count(),
+                            on_load_children: move |id: String| {
+                                spawn(async move {
+                                    crate::timer::sleep(800).await;
+                                    let children = (1..=3)
+                                        .map(|i| TreeNode::new(format!("{id}-{i}"), format!("Remote item {i}")))
+                                        .collect();
+                                    if let Some(node) = tree.write().iter_mut().find_map(|n| n.find_by_id_mut(&id)) {
+                                        node.set_children(children);
+                                    }
+                                });
+                            },
+                            checkable: checkable(),
+                            on_check: move |ids: Vec<String>| checked.set(ids),
+                            draggable: draggable(),
+                            on_drop: move |drop: TreeDrop| {
+                                drop.apply(&mut tree.write());
+                            },
+                            filter: filter(),
+                            on_match_count: move |n: usize| match_count.set(n),
                         }
                     }
                 }
-                div { class: "flex-1 flex items-center justify-center rounded-lg border border-[var(--color-card-border)] p-6 min-h-[16rem]",
+                div { class: "flex-1 flex flex-col items-center justify-center gap-2 rounded-lg border border-[var(--color-card-border)] p-6 min-h-[16rem]",
                     if let Some(id) = selected() {
                         EqText { variant: TextVariant::H3, "Selected: {id}" }
                     } else {
                         EqText { variant: TextVariant::Muted, "Click a leaf node" }
                     }
+                    if checkable() {
+                        EqText { variant: TextVariant::Muted, "Checked: {checked().len()}" }
+                    }
+                    if !filter().trim().is_empty() {
+                        EqText { variant: TextVariant::Muted, "Matches: {match_count}" }
+                    }
                 }
             }
             StyleInfo { file: "eq_tree_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
+        }
+        DemoVirtualTree {}
+    }
+}
+
+/// Virtualized mode: 100 folders of 1,000 files each.
+#[cfg(feature = "playground")]
+#[component]
+fn DemoVirtualTree() -> Element {
+    let mut selected = use_signal(|| Option::<String>::None);
+    let tree = use_hook(|| {
+        (0..100)
+            .map(|f| {
+                let files = (0..1_000)
+                    .map(|i| TreeNode::new(format!("f{f}-{i}"), format!("file_{i:04}.rs")))
+                    .collect();
+                TreeNode::new_with_children(format!("f{f}"), format!("folder_{f:03}"), files)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    rsx! {
+        DemoSection { title: "Virtualized (100,100 nodes)",
+            div { class: "rounded-lg border border-dashed border-[var(--color-card-border)] p-6 space-y-2",
+                EqText {
+                    variant: TextVariant::Muted,
+                    "Only the rows in view are rendered. Expand a folder and hold Down, or type a name."
+                }
+                div { class: "w-80 border border-[var(--color-card-border)] rounded-lg p-2",
+                    EqTree {
+                        nodes: tree,
+                        selected: selected(),
+                        on_select: move |id: String| selected.set(Some(id)),
+                        show_count: true,
+                        virtualized: true,
+                        viewport_height: 320.0,
+                        aria_label: "File browser",
+                    }
+                }
+            }
         }
     }
 }
@@ -657,6 +1602,7 @@ fn DemoEqTree() -> Element {
 #[component]
 fn GalleryEqTree() -> Element {
     let mut selected = use_signal(|| Option::<String>::None);
+    let mut checked = use_signal(|| vec!["item-b2".to_string()]);
 
     let tree = vec![
         TreeNode::new_with_children(
@@ -704,6 +1650,20 @@ fn GalleryEqTree() -> Element {
                     }
                 }
             }
+
+            div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-4",
+                EqText { variant: TextVariant::Caption, class: "font-semibold uppercase tracking-wider", "Checkboxes" }
+
+                div { class: "w-48 h-40 border border-[var(--color-card-border)] rounded-lg p-3 overflow-auto",
+                    EqTree {
+                        nodes: tree,
+                        on_select: move |_| {},
+                        checkable: true,
+                        checked: checked(),
+                        on_check: move |ids: Vec<String>| checked.set(ids),
+                    }
+                }
+            }
         }
     }
 }
@@ -759,4 +1719,251 @@ mod tests {
         assert!(root.find_by_id("a").is_some());
         assert!(root.find_by_id("missing").is_none());
     }
+
+    fn sample() -> Vec<TreeNode> {
+        vec![
+            TreeNode::new_with_children(
+                "a",
+                "Apple",
+                vec![TreeNode::new("a1", "Apricot"), TreeNode::new("a2", "Avocado")],
+            ),
+            TreeNode::new("b", "Banana"),
+            TreeNode::new("c", "Cherry"),
+        ]
+    }
+
+    fn ids(nodes: &[TreeNode]) -> Vec<&str> {
+        nodes.iter().map(|n| n.id.as_str()).collect()
+    }
+
+    #[test]
+    fn smoke_renders_checkable_draggable() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                EqTree {
+                    nodes: vec![
+                        TreeNode::new_with_children("a", "Alpha", vec![TreeNode::new("b", "Beta")]),
+                        TreeNode::new_lazy("c", "Gamma"),
+                    ],
+                    on_select: move |_| {},
+                    checkable: true,
+                    checked: vec!["b".to_string()],
+                    draggable: true,
+                }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn lazy_node_is_a_pending_branch_until_loaded() {
+        let mut n = TreeNode::new_lazy("x", "X");
+        assert!(!n.is_leaf());
+        assert!(n.is_pending());
+        n.set_children(vec![TreeNode::new("y", "Y")]);
+        assert!(!n.is_pending());
+        assert_eq!(n.children[0].parent_id.as_deref(), Some("x"));
+
+        // An empty load turns the node into a plain leaf.
+        let mut empty = TreeNode::new_lazy("e", "E");
+        empty.set_children(Vec::new());
+        assert!(empty.is_leaf());
+    }
+
+    #[test]
+    fn move_node_keeps_node_when_parent_missing() {
+        let mut root = TreeNode::new_with_children("r", "R", vec![TreeNode::new("a", "A")]);
+        assert!(!root.move_node("a", "missing"));
+        assert!(root.find_by_id("a").is_some());
+    }
+
+    #[test]
+    fn checking_a_branch_cascades_and_derives_ancestors() {
+        let nodes = sample();
+        let set = toggle_checked(&nodes, &HashSet::new(), "a");
+        assert_eq!(checked_ids(&nodes, &set), vec!["a", "a1", "a2"]);
+
+        let set = toggle_checked(&nodes, &set, "a1");
+        assert_eq!(check_state(&nodes[0], &set), CheckboxState::Indeterminate);
+        assert_eq!(checked_ids(&nodes, &set), vec!["a2"]);
+
+        let set = toggle_checked(&nodes, &set, "a1");
+        assert_eq!(check_state(&nodes[0], &set), CheckboxState::Checked);
+        assert!(set.contains("a"));
+    }
+
+    #[test]
+    fn indeterminate_branch_toggles_to_checked() {
+        let nodes = sample();
+        let partial: HashSet<String> = ["a1".to_string()].into_iter().collect();
+        let set = toggle_checked(&nodes, &partial, "a");
+        assert_eq!(checked_ids(&nodes, &set), vec!["a", "a1", "a2"]);
+    }
+
+    #[test]
+    fn drop_before_and_after_reorders_roots() {
+        let mut nodes = sample();
+        let drop = TreeDrop { node_id: "c".into(), target_id: "a".into(), position: DropPosition::Before };
+        assert!(drop.apply(&mut nodes));
+        assert_eq!(ids(&nodes), vec!["c", "a", "b"]);
+        assert!(nodes.iter().all(|n| n.parent_id.is_none()));
+
+        let drop = TreeDrop { node_id: "c".into(), target_id: "b".into(), position: DropPosition::After };
+        assert!(drop.apply(&mut nodes));
+        assert_eq!(ids(&nodes), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn drop_inside_and_beside_nested_nodes() {
+        let mut nodes = sample();
+        let drop = TreeDrop { node_id: "b".into(), target_id: "a".into(), position: DropPosition::Inside };
+        assert!(drop.apply(&mut nodes));
+        assert_eq!(ids(&nodes[0].children), vec!["a1", "a2", "b"]);
+        assert_eq!(nodes[0].children[2].parent_id.as_deref(), Some("a"));
+
+        let drop = TreeDrop { node_id: "c".into(), target_id: "a2".into(), position: DropPosition::Before };
+        assert!(drop.apply(&mut nodes));
+        assert_eq!(ids(&nodes[0].children), vec!["a1", "c", "a2", "b"]);
+        assert_eq!(ids(&nodes), vec!["a"]);
+    }
+
+    #[test]
+    fn drop_into_own_subtree_is_rejected() {
+        let mut nodes = sample();
+        let before = nodes.clone();
+        let drop = TreeDrop { node_id: "a".into(), target_id: "a1".into(), position: DropPosition::After };
+        assert!(!drop.apply(&mut nodes));
+        assert_eq!(nodes, before);
+        assert!(!can_drop(&nodes, "a", "a2"));
+        assert!(!can_drop(&nodes, "a", "a"));
+        assert!(can_drop(&nodes, "a1", "b"));
+    }
+
+    #[test]
+    fn smoke_renders_virtualized() {
+        let mut dom = VirtualDom::new(|| {
+            let nodes = (0..50)
+                .map(|f| {
+                    let files = (0..100).map(|i| TreeNode::new(format!("{f}-{i}"), "file")).collect();
+                    TreeNode::new_with_children(format!("{f}"), "folder", files)
+                })
+                .collect::<Vec<_>>();
+            rsx! {
+                EqTree {
+                    nodes,
+                    on_select: move |_| {},
+                    checkable: true,
+                    virtualized: true,
+                    viewport_height: 200.0,
+                }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn visible_rows_carry_aria_positions_and_paths() {
+        let nodes = sample();
+        let expanded: HashSet<String> = ["a".to_string()].into_iter().collect();
+        let mut rows = Vec::new();
+        collect_visible(&nodes, &expanded, &mut rows);
+
+        let summary: Vec<_> = rows
+            .iter()
+            .map(|r| (r.id.as_str(), r.depth, r.set_size, r.pos_in_set))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("a", 1, 3, 1), ("a1", 2, 2, 1), ("a2", 2, 2, 2), ("b", 1, 3, 2), ("c", 1, 3, 3)]
+        );
+        for row in &rows {
+            assert_eq!(node_at(&nodes, &row.path).map(|n| n.id.as_str()), Some(row.id.as_str()));
+        }
+        assert!(node_at(&nodes, &[7]).is_none());
+        assert!(node_at(&nodes, &[]).is_none());
+    }
+
+    #[test]
+    fn smoke_renders_filtered() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                EqTree {
+                    nodes: sample(),
+                    on_select: move |_| {},
+                    filter: "AV",
+                    filter_predicate: TreePredicate::new(|n: &TreeNode| n.is_leaf()),
+                }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn filter_keeps_matches_and_their_ancestors() {
+        let nodes = vec![
+            TreeNode::new_with_children(
+                "src",
+                "src",
+                vec![
+                    TreeNode::new_with_children(
+                        "ui",
+                        "ui",
+                        vec![TreeNode::new("tree", "tree.rs"), TreeNode::new("list", "list.rs")],
+                    ),
+                    TreeNode::new("lib", "lib.rs"),
+                ],
+            ),
+            TreeNode::new("readme", "README.md"),
+        ];
+        let view = filter_tree(&nodes, &|n: &TreeNode| match_range(&n.label, "TREE").is_some());
+
+        assert_eq!(view.matches, 1);
+        assert_eq!(ids(&view.nodes), vec!["src"]);
+        assert_eq!(ids(&view.nodes[0].children), vec!["ui"]);
+        assert_eq!(ids(&view.nodes[0].children[0].children), vec!["tree"]);
+        let expanded: HashSet<String> = ["src".to_string(), "ui".to_string()].into_iter().collect();
+        assert_eq!(view.expanded, expanded);
+
+        // A matching branch keeps its whole subtree, closed.
+        let view = filter_tree(&nodes, &|n: &TreeNode| n.id == "ui");
+        assert_eq!(ids(&view.nodes[0].children[0].children), vec!["tree", "list"]);
+        assert!(!view.expanded.contains("ui"));
+
+        let view = filter_tree(&nodes, &|_: &TreeNode| false);
+        assert!(view.nodes.is_empty());
+        assert_eq!(view.matches, 0);
+    }
+
+    #[test]
+    fn match_range_ignores_case_and_handles_unicode() {
+        assert_eq!(match_range("Apricot", "RIC"), Some((2, 5)));
+        assert_eq!(match_range("Crème brûlée", "BRÛ"), Some((7, 11)));
+        assert_eq!(match_range("Apple", "pear"), None);
+        assert_eq!(match_range("Apple", ""), None);
+    }
+
+    #[test]
+    fn tree_predicate_compares_by_identity() {
+        let leaf = TreePredicate::new(|n: &TreeNode| n.is_leaf());
+        assert!(leaf == leaf.clone());
+        assert!(leaf != TreePredicate::new(|n: &TreeNode| n.is_leaf()));
+        assert!(leaf.matches(&TreeNode::new("a", "A")));
+    }
+
+    #[test]
+    fn type_ahead_matches_prefixes_and_cycles() {
+        let nodes = sample();
+        let expanded: HashSet<String> = ["a".to_string()].into_iter().collect();
+        let mut rows = Vec::new();
+        collect_visible(&nodes, &expanded, &mut rows);
+        // Apple, Apricot, Avocado, Banana, Cherry
+
+        assert_eq!(type_ahead(&rows, None, "b"), Some(3));
+        assert_eq!(type_ahead(&rows, Some(0), "a"), Some(1));
+        assert_eq!(type_ahead(&rows, Some(2), "a"), Some(0));
+        assert_eq!(type_ahead(&rows, Some(1), "aa"), Some(2));
+        assert_eq!(type_ahead(&rows, Some(1), "APR"), Some(1));
+        assert_eq!(type_ahead(&rows, Some(0), "av"), Some(2));
+        assert_eq!(type_ahead(&rows, Some(0), "z"), None);
+    }
 }
@@ -5,11 +5,11 @@ pub const TREE: &str = "flex flex-col gap-0.5 text-sm select-none";
 
 /// A single tree node row (branch or leaf).
 pub const NODE_ROW: &str =
-    "flex items-center gap-1.5 px-2 py-2 md:py-1.5 rounded-md cursor-pointer transition-colors hover:bg-[var(--color-card)]/60 active:bg-[var(--color-card)]/60";
+    "relative flex items-center gap-1.5 px-2 py-2 md:py-1.5 rounded-md cursor-pointer transition-colors hover:bg-[var(--color-card)]/60 active:bg-[var(--color-card)]/60";
 
 /// Active/selected node highlight.
 pub const NODE_ACTIVE: &str =
-    "flex items-center gap-1.5 px-2 py-2 md:py-1.5 rounded-md cursor-pointer bg-[var(--color-primary)]/15 text-[var(--color-primary)]";
+    "relative flex items-center gap-1.5 px-2 py-2 md:py-1.5 rounded-md cursor-pointer bg-[var(--color-primary)]/15 text-[var(--color-primary)]";
 
 /// Chevron icon for branch nodes.
 pub const CHEVRON: &str = "size-4 shrink-0 transition-transform duration-[var(--transition-fast)]";
@@ -23,12 +23,58 @@ pub const LEAF_SPACER: &str = "w-4 shrink-0";
 /// Node label text.
 pub const LABEL: &str = "truncate text-[var(--color-label)]";
 
+/// Highlighted filter match inside a label.
+pub const MATCH: &str =
+    "rounded-sm bg-[var(--color-accent-primary)]/25 text-inherit";
+
 /// Child count shown next to branch labels, e.g. "(8)".
 pub const COUNT: &str = "ml-1 text-[var(--color-label-secondary)] opacity-60";
 
 /// Children container with left indentation.
 pub const CHILDREN: &str = "ml-3";
 
+/// Scroll viewport of a virtualized tree — replaces (`!`) EqVirtualList's
+/// card-styled viewport so the tree keeps its plain look.
+pub const VIRTUAL_VIEWPORT: &str = "!overflow-auto relative";
+
+/// A flat, fixed-height treeitem in virtualized mode.
+pub const FLAT_ITEM: &str = "h-full flex flex-col justify-center outline-none";
+
+/// Spinner replacing the chevron while a lazy branch loads.
+pub const SPINNER: &str =
+    "size-4 shrink-0 rounded-full animate-spin motion-reduce:animate-none \
+     border-2 border-[var(--color-label-secondary)]/30 border-t-[var(--color-accent-primary)]";
+
+/// Checkbox placed before the label in checkable trees.
+pub const CHECKBOX: &str = "shrink-0";
+
+/// The row being dragged.
+pub const DRAGGING: &str = "opacity-50";
+
+/// Drop indicator: line above the target row.
+pub const DROP_BEFORE: &str = "shadow-[inset_0_2px_0_0_var(--color-accent-primary)]";
+
+/// Drop indicator: outline around the target branch.
+pub const DROP_INSIDE: &str = "ring-2 ring-inset ring-[var(--color-accent-primary)]";
+
+/// Drop indicator: line below the target row.
+pub const DROP_AFTER: &str = "shadow-[inset_0_-2px_0_0_var(--color-accent-primary)]";
+
+/// Invisible hit area over the top quarter of a branch row while dragging.
+pub const DROP_ZONE_BEFORE: &str = "absolute inset-x-0 top-0 h-1/4 z-10";
+
+/// Hit area over the middle half of a branch row (drop inside).
+pub const DROP_ZONE_INSIDE: &str = "absolute inset-x-0 top-1/4 h-1/2 z-10";
+
+/// Hit area over the bottom quarter of a branch row.
+pub const DROP_ZONE_AFTER: &str = "absolute inset-x-0 bottom-0 h-1/4 z-10";
+
+/// Hit area over the top half of a leaf row (leaves take no children).
+pub const DROP_ZONE_LEAF_BEFORE: &str = "absolute inset-x-0 top-0 h-1/2 z-10";
+
+/// Hit area over the bottom half of a leaf row.
+pub const DROP_ZONE_LEAF_AFTER: &str = "absolute inset-x-0 bottom-0 h-1/2 z-10";
+
 /// All style tokens for playground introspection.
 pub fn catalog() -> Vec<(&'static str, &'static str)> {
     vec![
@@ -39,7 +85,21 @@ pub fn catalog() -> Vec<(&'static str, &'static str)> {
         ("CHEVRON_EXPANDED", CHEVRON_EXPANDED),
         ("LEAF_SPACER", LEAF_SPACER),
         ("LABEL", LABEL),
+        ("MATCH", MATCH),
         ("COUNT", COUNT),
         ("CHILDREN", CHILDREN),
+        ("VIRTUAL_VIEWPORT", VIRTUAL_VIEWPORT),
+        ("FLAT_ITEM", FLAT_ITEM),
+        ("SPINNER", SPINNER),
+        ("CHECKBOX", CHECKBOX),
+        ("DRAGGING", DRAGGING),
+        ("DROP_BEFORE", DROP_BEFORE),
+        ("DROP_INSIDE", DROP_INSIDE),
+        ("DROP_AFTER", DROP_AFTER),
+        ("DROP_ZONE_BEFORE", DROP_ZONE_BEFORE),
+        ("DROP_ZONE_INSIDE", DROP_ZONE_INSIDE),
+        ("DROP_ZONE_AFTER", DROP_ZONE_AFTER),
+        ("DROP_ZONE_LEAF_BEFORE", DROP_ZONE_LEAF_BEFORE),
+        ("DROP_ZONE_LEAF_AFTER", DROP_ZONE_LEAF_AFTER),
     ]
 }
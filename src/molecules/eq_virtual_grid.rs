@@ -0,0 +1,768 @@
+//! EqVirtualGrid — two-dimensional windowed grid molecule.
+//!
+//! Lays items out in rows of cells and renders only the rows (and, when
+//! the grid is wider than its viewport, the columns) in view plus an
+//! overscan buffer. The column count is either fixed or derived from
+//! the container width, like a tile gallery.
+//!
+//! ```no_run
+//! use eq_ui::prelude::*;
+//! use eq_ui::molecules::EqVirtualGrid;
+//!
+//! let _: Element = rsx! {
+//!     EqVirtualGrid {
+//!         item_count: 10_000,
+//!         cell_width: 160.0,
+//!         cell_height: 120.0,
+//!         gap: 8.0,
+//!         viewport_height: 480.0,
+//!         render_cell: move |idx: usize| rsx! {
+//!             div { "Tile {idx}" }
+//!         },
+//!     }
+//! };
+//! ```
+
+use super::eq_virtual_grid_styles as s;
+use crate::theme::merge_classes;
+use crate::playground;
+use dioxus::prelude::*;
+use std::ops::Range;
+
+#[cfg(feature = "playground")]
+use crate::playground::playground_helpers::{
+    DemoSection, PropInput, PropToggle, StyleInfo, format_catalog,
+};
+#[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
+use crate::atoms::{EqText, TextVariant};
+#[cfg(feature = "playground")]
+use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
+
+// ── Layout ───────────────────────────────────────────────────────
+
+/// Geometry of a grid of equally sized cells, filled row by row.
+///
+/// Pure data: the component builds one per render and asks it which
+/// rows and columns to draw and where each cell goes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GridLayout {
+    /// Number of items laid out.
+    pub items: usize,
+    /// Cells per row (at least 1).
+    pub columns: usize,
+    /// Width of one cell in pixels.
+    pub cell_width: f64,
+    /// Height of one cell in pixels.
+    pub cell_height: f64,
+    /// Space between cells, both ways.
+    pub gap: f64,
+}
+
+/// Rows and columns to render.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GridWindow {
+    pub rows: Range<usize>,
+    pub columns: Range<usize>,
+}
+
+impl GridLayout {
+    /// `columns` cells of `cell_width` per row; the grid scrolls
+    /// sideways when it is wider than the viewport.
+    pub fn fixed(items: usize, columns: usize, cell_width: f64, cell_height: f64, gap: f64) -> Self {
+        Self {
+            items,
+            columns: columns.max(1),
+            cell_width: cell_width.max(0.0),
+            cell_height: cell_height.max(0.0),
+            gap: gap.max(0.0),
+        }
+    }
+
+    /// As many columns of at least `min_cell_width` as fit in `width`,
+    /// stretched to fill it exactly.
+    pub fn responsive(items: usize, width: f64, min_cell_width: f64, cell_height: f64, gap: f64) -> Self {
+        let gap = gap.max(0.0);
+        let span = min_cell_width.max(1.0) + gap;
+        let columns = (((width + gap) / span).floor() as usize).max(1);
+        let cell_width = ((width - gap * (columns - 1) as f64) / columns as f64).max(0.0);
+        Self::fixed(items, columns, cell_width, cell_height, gap)
+    }
+
+    /// Number of rows, the last one possibly partial.
+    pub fn rows(&self) -> usize {
+        self.items.div_ceil(self.columns)
+    }
+
+    /// Width of the whole grid.
+    pub fn width(&self) -> f64 {
+        extent(self.columns, self.cell_width, self.gap)
+    }
+
+    /// Height of the whole grid.
+    pub fn height(&self) -> f64 {
+        extent(self.rows(), self.cell_height, self.gap)
+    }
+
+    /// Row and column of item `index`.
+    pub fn position(&self, index: usize) -> (usize, usize) {
+        (index / self.columns, index % self.columns)
+    }
+
+    /// Item at `row`, `col`, if there is one (the last row may be short).
+    pub fn index(&self, row: usize, col: usize) -> Option<usize> {
+        let index = row * self.columns + col;
+        (col < self.columns && index < self.items).then_some(index)
+    }
+
+    /// Top-left corner of item `index`, in pixels.
+    pub fn origin(&self, index: usize) -> (f64, f64) {
+        let (row, col) = self.position(index);
+        (col as f64 * (self.cell_width + self.gap), row as f64 * (self.cell_height + self.gap))
+    }
+
+    /// Rows and columns overlapping a `width` x `height` viewport
+    /// scrolled to (`scroll_x`, `scroll_y`), plus `overscan` on each side.
+    pub fn window(&self, scroll_x: f64, scroll_y: f64, width: f64, height: f64, overscan: usize) -> GridWindow {
+        if self.items == 0 {
+            return GridWindow { rows: 0..0, columns: 0..0 };
+        }
+        GridWindow {
+            rows: axis_window(scroll_y, height, self.cell_height + self.gap, self.rows(), overscan),
+            columns: axis_window(scroll_x, width, self.cell_width + self.gap, self.columns, overscan),
+        }
+    }
+
+    /// Item focused after `movement` from `index`. `page` is the number
+    /// of rows PageUp / PageDown jump.
+    pub fn navigate(&self, index: usize, movement: GridMove, page: usize) -> usize {
+        if self.items == 0 {
+            return 0;
+        }
+        let last = self.items - 1;
+        let index = index.min(last);
+        let (row, col) = self.position(index);
+        let last_col = (self.columns - 1).min(last - row * self.columns);
+        // Moving down into the short last row lands on its last cell.
+        let to_row = |row: usize| self.index(row, col).unwrap_or(last);
+        match movement {
+            GridMove::Left => index.saturating_sub(1),
+            GridMove::Right => (index + 1).min(last),
+            GridMove::Up => index.checked_sub(self.columns).unwrap_or(index),
+            GridMove::Down if row + 1 < self.rows() => to_row(row + 1),
+            GridMove::Down => index,
+            GridMove::RowStart => row * self.columns,
+            GridMove::RowEnd => row * self.columns + last_col,
+            GridMove::PageUp => to_row(row.saturating_sub(page.max(1))),
+            GridMove::PageDown => to_row((row + page.max(1)).min(self.rows() - 1)),
+            GridMove::First => 0,
+            GridMove::Last => last,
+        }
+    }
+}
+
+/// Keyboard movements across the grid, per the WAI-ARIA grid pattern.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GridMove {
+    Left,
+    Right,
+    Up,
+    Down,
+    /// Home: first cell of the row.
+    RowStart,
+    /// End: last cell of the row.
+    RowEnd,
+    PageUp,
+    PageDown,
+    /// Ctrl+Home.
+    First,
+    /// Ctrl+End.
+    Last,
+}
+
+impl GridMove {
+    /// Movement for a key press, if the grid handles that key.
+    pub fn from_key(key: &Key, ctrl: bool) -> Option<Self> {
+        Some(match key {
+            Key::ArrowLeft => Self::Left,
+            Key::ArrowRight => Self::Right,
+            Key::ArrowUp => Self::Up,
+            Key::ArrowDown => Self::Down,
+            Key::Home if ctrl => Self::First,
+            Key::End if ctrl => Self::Last,
+            Key::Home => Self::RowStart,
+            Key::End => Self::RowEnd,
+            Key::PageUp => Self::PageUp,
+            Key::PageDown => Self::PageDown,
+            _ => return None,
+        })
+    }
+}
+
+/// Length of `count` cells of `size` with `gap` between them.
+fn extent(count: usize, size: f64, gap: f64) -> f64 {
+    if count == 0 { 0.0 } else { count as f64 * (size + gap) - gap }
+}
+
+/// Cells of `span` (size plus gap) overlapping `viewport` pixels from
+/// `scroll`, plus `overscan` on each side, within `0..count`.
+fn axis_window(scroll: f64, viewport: f64, span: f64, count: usize, overscan: usize) -> Range<usize> {
+    if count == 0 {
+        return 0..0;
+    }
+    if span <= 0.0 {
+        return 0..count;
+    }
+    let first = ((scroll.max(0.0) / span).floor() as usize).min(count - 1);
+    let last = (((scroll.max(0.0) + viewport.max(0.0)) / span).floor() as usize).min(count - 1);
+    first.saturating_sub(overscan)..(last + 1 + overscan).min(count)
+}
+
+/// Scroll offset that brings `start..start + size` into a viewport of
+/// `viewport` pixels scrolled to `scroll`, moving as little as possible.
+fn reveal(scroll: f64, start: f64, size: f64, viewport: f64) -> f64 {
+    if start < scroll {
+        start
+    } else if start + size > scroll + viewport {
+        (start + size - viewport).max(0.0)
+    } else {
+        scroll
+    }
+}
+
+// ── Component ────────────────────────────────────────────────────
+
+/// Windowed grid of equally sized cells.
+///
+/// With `columns` set the grid has that many columns of `cell_width`
+/// and windows both axes. Without it the column count follows the
+/// container width: as many cells of at least `cell_width` as fit,
+/// stretched to fill the row.
+///
+/// The grid is a single tab stop. Arrow keys, Home / End (with Ctrl for
+/// the first / last cell) and PageUp / PageDown move between cells,
+/// scrolling them into view; Enter or Space calls `on_activate`.
+#[playground(
+    category = Molecule,
+    description = "Windowed grid for large tiled layouts. Windows rows \
+                   and columns together, fixed or responsive column \
+                   count, gap, keyboard navigation across cells and \
+                   role=\"grid\" semantics.",
+    examples = [
+        ("Responsive tiles", "EqVirtualGrid {\n    item_count: photos.len(),\n    cell_width: 160.0,\n    cell_height: 120.0,\n    gap: 8.0,\n    viewport_height: 480.0,\n    render_cell: move |idx: usize| rsx! {\n        Thumbnail { photo: photos.read()[idx].clone() }\n    },\n}"),
+        ("Fixed columns", "EqVirtualGrid {\n    item_count: 365 * 24,\n    columns: 365,\n    cell_width: 14.0,\n    cell_height: 14.0,\n    gap: 2.0,\n    viewport_height: 400.0,\n    render_cell: move |idx: usize| rsx! { HeatCell { idx } },\n}"),
+        ("Activation", "EqVirtualGrid {\n    item_count: 1_000,\n    cell_width: 120.0,\n    cell_height: 120.0,\n    viewport_height: 400.0,\n    on_activate: move |idx: usize| open(idx),\n    render_cell: move |idx: usize| rsx! { \"{idx}\" },\n}"),
+    ],
+    custom_demo,
+    custom_gallery,
+)]
+#[component]
+pub fn EqVirtualGrid(
+    /// Total number of cells.
+    item_count: usize,
+    /// Width of a cell in pixels - the minimum width when `columns` is
+    /// not set.
+    cell_width: f64,
+    /// Height of a cell in pixels.
+    cell_height: f64,
+    /// Render callback — receives the item index, returns the cell content.
+    render_cell: Callback<usize, Element>,
+    /// Height of the scrollable viewport in pixels.
+    viewport_height: f64,
+    /// Fixed column count. When unset, columns follow the container width.
+    #[props(default)]
+    columns: Option<usize>,
+    /// Space between cells in pixels, both ways.
+    #[props(default = 0.0)]
+    gap: f64,
+    /// Extra rows (and columns) rendered beyond the viewport on each side.
+    #[props(default = 2)]
+    overscan: usize,
+    /// Container width assumed until it has been measured, e.g. during
+    /// server rendering.
+    #[props(default = 800.0)]
+    initial_width: f64,
+    /// Called with the focused cell's index on Enter or Space, and on click.
+    #[props(default)]
+    on_activate: Option<EventHandler<usize>>,
+    /// Accessible name of the grid.
+    #[props(into, default = "Grid".to_string())]
+    aria_label: String,
+    /// Optional class override on the viewport container.
+    #[props(into, default)]
+    class: String,
+) -> Element {
+    // Stable unique ID for the viewport element.
+    static COUNTER: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+    let vp_id = use_hook(|| {
+        format!("eq-vg-{}", COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed))
+    });
+
+    // ── Scroll and size state ────────────────────────────────────
+    let mut scroll_x = use_signal(|| 0.0_f64);
+    let mut scroll_y = use_signal(|| 0.0_f64);
+    let mut width = use_signal(|| initial_width);
+    let mut viewport_el: Signal<Option<MountedEvent>> = use_signal(|| None);
+
+    // ── Focus state ──────────────────────────────────────────────
+    let mut active = use_signal(|| 0_usize);
+    // Set by keyboard moves; focusing after render keeps the DOM focus
+    // on the newly rendered cell.
+    let mut focus_request = use_signal(|| None::<usize>);
+    let vp_id_focus = vp_id.clone();
+    use_effect(move || {
+        if let Some(idx) = focus_request() {
+            document::eval(&format!(
+                "document.getElementById('{vp_id_focus}-{idx}')?.focus({{ preventScroll: true }})"
+            ));
+        }
+    });
+
+    // ── Windowing math ───────────────────────────────────────────
+    let layout = match columns {
+        Some(columns) => GridLayout::fixed(item_count, columns, cell_width, cell_height, gap),
+        None => GridLayout::responsive(item_count, width(), cell_width, cell_height, gap),
+    };
+    let window = layout.window(scroll_x(), scroll_y(), width(), viewport_height, overscan);
+    let active_idx = active().min(item_count.saturating_sub(1));
+
+    // The tab stop: the active cell, or the first one in view when the
+    // active cell has scrolled out.
+    let (active_row, active_col) = layout.position(active_idx);
+    let tab_stop = if window.rows.contains(&active_row) && window.columns.contains(&active_col) {
+        active_idx
+    } else {
+        layout.index(window.rows.start, window.columns.start).unwrap_or(0)
+    };
+
+    // Rows per PageUp / PageDown.
+    let page_rows = (viewport_height / (cell_height + gap).max(1.0)).floor().max(1.0) as usize;
+
+    // ── Styles ───────────────────────────────────────────────────
+    let viewport_cls = merge_classes(s::VIEWPORT, &class);
+    let viewport_style = format!("height: {viewport_height}px;");
+    let sizer_style = format!("width: {}px; height: {}px;", layout.width(), layout.height());
+    let cell_size = format!("width: {}px; height: {}px;", layout.cell_width, layout.cell_height);
+    let row_height = layout.cell_height;
+    let cell_active_cls = format!("{} {}", s::CELL, s::CELL_ACTIVE);
+
+    let vp_id_key = vp_id.clone();
+
+    rsx! {
+        div {
+            id: "{vp_id}",
+            class: "{viewport_cls}",
+            style: "{viewport_style}",
+            role: "grid",
+            "aria-label": "{aria_label}",
+            "aria-rowcount": "{layout.rows()}",
+            "aria-colcount": "{layout.columns}",
+            onmounted: move |evt: MountedEvent| {
+                viewport_el.set(Some(evt));
+            },
+            onresize: move |evt: Event<ResizeData>| {
+                if let Ok(size) = evt.get_content_box_size()
+                    && size.width > 0.0
+                    && size.width != *width.peek()
+                {
+                    width.set(size.width);
+                }
+            },
+            onscroll: move |_| {
+                if let Some(el) = viewport_el() {
+                    spawn(async move {
+                        let Ok(offset) = el.get_scroll_offset().await else { return };
+                        scroll_x.set(offset.x);
+                        scroll_y.set(offset.y);
+                    });
+                }
+            },
+            onkeydown: move |evt: Event<KeyboardData>| {
+                let key = evt.key();
+                if key == Key::Enter || key == Key::Character(" ".into()) {
+                    evt.prevent_default();
+                    if let (Some(handler), true) = (on_activate, item_count > 0) {
+                        handler.call(active_idx);
+                    }
+                    return;
+                }
+                let Some(movement) = GridMove::from_key(&key, evt.modifiers().ctrl()) else { return };
+                evt.prevent_default();
+                let next = layout.navigate(active_idx, movement, page_rows);
+                active.set(next);
+
+                // Scroll the cell into view, then render and focus it.
+                let (x, y) = layout.origin(next);
+                let left = reveal(*scroll_x.peek(), x, layout.cell_width, *width.peek());
+                let top = reveal(*scroll_y.peek(), y, layout.cell_height, viewport_height);
+                scroll_x.set(left);
+                scroll_y.set(top);
+                document::eval(&format!(
+                    "const el = document.getElementById('{vp_id_key}'); \
+                     if (el) el.scrollTo({{ left: {left}, top: {top} }});"
+                ));
+                focus_request.set(Some(next));
+            },
+
+            // Sizer — sets the total scrollable extent
+            div {
+                class: s::SIZER,
+                style: "{sizer_style}",
+
+                for row in window.rows.clone() {
+                    div {
+                        key: "vg-row-{row}",
+                        class: s::ROW,
+                        style: "top: {row as f64 * (row_height + gap)}px; height: {row_height}px;",
+                        role: "row",
+                        "aria-rowindex": "{row + 1}",
+
+                        for idx in window.columns.clone().filter_map(|col| layout.index(row, col)) {
+                            div {
+                                key: "vg-{idx}",
+                                id: "{vp_id}-{idx}",
+                                class: if idx == active_idx { cell_active_cls.as_str() } else { s::CELL },
+                                style: "left: {layout.origin(idx).0}px; {cell_size}",
+                                role: "gridcell",
+                                "aria-colindex": "{layout.position(idx).1 + 1}",
+                                "aria-selected": if idx == active_idx { "true" } else { "false" },
+                                tabindex: if idx == tab_stop { "0" } else { "-1" },
+                                onfocus: move |_| {
+                                    if *active.peek() != idx {
+                                        active.set(idx);
+                                    }
+                                },
+                                onclick: move |_| {
+                                    active.set(idx);
+                                    if let Some(handler) = on_activate {
+                                        handler.call(idx);
+                                    }
+                                },
+                                {render_cell.call(idx)}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── Interactive demo ─────────────────────────────────────────────
+
+#[cfg(feature = "playground")]
+#[component]
+fn DemoEqVirtualGrid() -> Element {
+    let mut count_str = use_signal(|| "10000".to_string());
+    let mut cell_w_str = use_signal(|| "140".to_string());
+    let mut cell_h_str = use_signal(|| "100".to_string());
+    let mut gap_str = use_signal(|| "8".to_string());
+    let mut viewport_str = use_signal(|| "420".to_string());
+    let mut fixed = use_signal(|| false);
+    let mut columns_str = use_signal(|| "100".to_string());
+    let mut activated = use_signal(|| None::<usize>);
+
+    let count: usize = count_str().parse().unwrap_or(10_000);
+    let cell_w: f64 = cell_w_str().parse().unwrap_or(140.0_f64).max(8.0);
+    let cell_h: f64 = cell_h_str().parse().unwrap_or(100.0_f64).max(8.0);
+    let gap: f64 = gap_str().parse().unwrap_or(8.0_f64).max(0.0);
+    let vp: f64 = viewport_str().parse().unwrap_or(420.0_f64).max(100.0);
+    let columns: Option<usize> = if fixed() { columns_str().parse().ok() } else { None };
+
+    let code = if fixed() {
+        r#"EqVirtualGrid {
+    item_count: 10_000,
+    columns: 100,
+    cell_width: 140.0,
+    cell_height: 100.0,
+    gap: 8.0,
+    viewport_height: 420.0,
+    render_cell: move |idx: usize| rsx! { Tile { idx } },
+}"#.to_string()
+    } else {
+        r#"EqVirtualGrid {
+    item_count: 10_000,
+    cell_width: 140.0, // minimum; columns follow the width
+    cell_height: 100.0,
+    gap: 8.0,
+    viewport_height: 420.0,
+    on_activate: move |idx: usize| open(idx),
+    render_cell: move |idx: usize| rsx! { Tile { idx } },
+}"#.to_string()
+    };
+
+    rsx! {
+        DemoSection { title: "EqVirtualGrid",
+            div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-3",
+                EqText {
+                    variant: TextVariant::Caption,
+                    class: "font-semibold uppercase tracking-wider",
+                    "Props"
+                }
+                PropInput {
+                    label: "item_count",
+                    value: count_str(),
+                    placeholder: "10000",
+                    onchange: move |v: String| count_str.set(v),
+                }
+                PropInput {
+                    label: "cell_width (px)",
+                    value: cell_w_str(),
+                    placeholder: "140",
+                    onchange: move |v: String| cell_w_str.set(v),
+                }
+                PropInput {
+                    label: "cell_height (px)",
+                    value: cell_h_str(),
+                    placeholder: "100",
+                    onchange: move |v: String| cell_h_str.set(v),
+                }
+                PropInput {
+                    label: "gap (px)",
+                    value: gap_str(),
+                    placeholder: "8",
+                    onchange: move |v: String| gap_str.set(v),
+                }
+                PropInput {
+                    label: "viewport_height (px)",
+                    value: viewport_str(),
+                    placeholder: "420",
+                    onchange: move |v: String| viewport_str.set(v),
+                }
+                PropToggle {
+                    label: "fixed columns",
+                    value: fixed(),
+                    onchange: move |v: bool| fixed.set(v),
+                }
+                if fixed() {
+                    PropInput {
+                        label: "columns",
+                        value: columns_str(),
+                        placeholder: "100",
+                        onchange: move |v: String| columns_str.set(v),
+                    }
+                }
+            }
+
+            div { class: "rounded-lg border border-dashed border-[var(--color-card-border)] p-6 space-y-2",
+                EqText { variant: TextVariant::Muted,
+                    if let Some(idx) = activated() {
+                        "Activated tile {idx}. Click a tile, then use the arrow keys, Home / End and PageUp / PageDown."
+                    } else {
+                        "{count} tiles — only those in view hit the DOM. Click a tile, then use the arrow keys."
+                    }
+                }
+                EqVirtualGrid {
+                    item_count: count,
+                    columns,
+                    cell_width: cell_w,
+                    cell_height: cell_h,
+                    gap,
+                    viewport_height: vp,
+                    on_activate: move |idx: usize| activated.set(Some(idx)),
+                    render_cell: move |idx: usize| {
+                        let hue = idx * 37 % 360;
+                        rsx! {
+                            div {
+                                class: "w-full h-full rounded-md flex items-center justify-center \
+                                        text-xs font-mono text-[var(--color-label-primary)]",
+                                style: "background: hsl({hue} 45% 40% / 0.35);",
+                                "#{idx}"
+                            }
+                        }
+                    },
+                }
+            }
+
+            StyleInfo { file: "eq_virtual_grid_styles.rs", styles: format_catalog(&s::catalog()) }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
+        }
+    }
+}
+
+// ── Gallery ──────────────────────────────────────────────────────
+
+#[cfg(feature = "playground")]
+#[component]
+fn GalleryEqVirtualGrid() -> Element {
+    rsx! {
+        div { class: "space-y-4",
+            div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-4",
+                EqText { variant: TextVariant::Caption, class: "font-semibold uppercase tracking-wider", "VirtualGrid Gallery" }
+
+                div { class: "grid gap-4 md:grid-cols-2",
+                    div { class: "space-y-1",
+                        EqText { variant: TextVariant::Muted, "Responsive tiles — 5k items" }
+                        EqVirtualGrid {
+                            item_count: 5_000,
+                            cell_width: 72.0,
+                            cell_height: 72.0,
+                            gap: 6.0,
+                            viewport_height: 240.0,
+                            initial_width: 360.0,
+                            render_cell: move |idx: usize| rsx! {
+                                div {
+                                    class: "w-full h-full rounded bg-[var(--color-primary-dark)]/40 \
+                                            flex items-center justify-center text-[10px] \
+                                            text-[var(--color-label-secondary)]",
+                                    "{idx}"
+                                }
+                            },
+                        }
+                    }
+                    div { class: "space-y-1",
+                        EqText { variant: TextVariant::Muted, "Heatmap — 365 × 24, both axes" }
+                        EqVirtualGrid {
+                            item_count: 365 * 24,
+                            columns: 365,
+                            cell_width: 12.0,
+                            cell_height: 12.0,
+                            gap: 2.0,
+                            viewport_height: 240.0,
+                            aria_label: "Hourly activity",
+                            render_cell: move |idx: usize| {
+                                let level = (idx * 7919 % 5) as f64 / 4.0;
+                                rsx! {
+                                    div {
+                                        class: "w-full h-full rounded-sm bg-[var(--color-accent-primary)]",
+                                        style: "opacity: {0.1 + level * 0.9};",
+                                    }
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── Smoke tests ─────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smoke_renders() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                EqVirtualGrid {
+                    item_count: 1_000,
+                    cell_width: 100.0,
+                    cell_height: 80.0,
+                    gap: 4.0,
+                    viewport_height: 300.0,
+                    render_cell: move |idx: usize| rsx! { div { "{idx}" } },
+                }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn smoke_renders_fixed_columns_and_empty() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                EqVirtualGrid {
+                    item_count: 10_000,
+                    columns: 500,
+                    cell_width: 12.0,
+                    cell_height: 12.0,
+                    viewport_height: 200.0,
+                    on_activate: move |_| {},
+                    render_cell: move |idx: usize| rsx! { span { "{idx}" } },
+                }
+                EqVirtualGrid {
+                    item_count: 0,
+                    cell_width: 50.0,
+                    cell_height: 50.0,
+                    viewport_height: 200.0,
+                    render_cell: move |idx: usize| rsx! { span { "{idx}" } },
+                }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn responsive_fits_columns_and_stretches_cells() {
+        // (800 + 10) / (150 + 10) = 5.06 -> 5 columns of 152px.
+        let layout = GridLayout::responsive(23, 800.0, 150.0, 100.0, 10.0);
+        assert_eq!(layout.columns, 5);
+        assert_eq!(layout.cell_width, 152.0);
+        assert_eq!(layout.width(), 800.0);
+        assert_eq!(layout.rows(), 5);
+        assert_eq!(layout.height(), 5.0 * 110.0 - 10.0);
+        // Narrower than one cell still gives a column.
+        assert_eq!(GridLayout::responsive(3, 50.0, 150.0, 100.0, 10.0).columns, 1);
+    }
+
+    #[test]
+    fn positions_and_indices_round_trip() {
+        let layout = GridLayout::fixed(10, 4, 50.0, 30.0, 5.0);
+        assert_eq!(layout.position(6), (1, 2));
+        assert_eq!(layout.origin(6), (110.0, 35.0));
+        assert_eq!(layout.index(1, 2), Some(6));
+        // The last row holds items 8 and 9 only.
+        assert_eq!(layout.index(2, 1), Some(9));
+        assert_eq!(layout.index(2, 2), None);
+        assert_eq!(layout.index(0, 4), None);
+    }
+
+    #[test]
+    fn window_covers_both_axes_plus_overscan() {
+        // 100 x 100 cells of 40px with a 10px gap: a span of 50px.
+        let layout = GridLayout::fixed(10_000, 100, 40.0, 40.0, 10.0);
+        let w = layout.window(0.0, 0.0, 200.0, 120.0, 0);
+        assert_eq!(w, GridWindow { rows: 0..3, columns: 0..5 });
+
+        let w = layout.window(1_000.0, 2_020.0, 200.0, 120.0, 2);
+        assert_eq!(w.columns, 18..27);
+        assert_eq!(w.rows, 38..45);
+
+        // Scrolled past the end stays within the grid.
+        let w = layout.window(99_999.0, 99_999.0, 200.0, 120.0, 1);
+        assert_eq!(w, GridWindow { rows: 98..100, columns: 98..100 });
+
+        assert_eq!(GridLayout::fixed(0, 3, 10.0, 10.0, 0.0).window(0.0, 0.0, 100.0, 100.0, 2).rows, 0..0);
+    }
+
+    #[test]
+    fn navigation_stays_on_existing_cells() {
+        // 3 columns, 8 items: the last row holds 6 and 7.
+        let layout = GridLayout::fixed(8, 3, 10.0, 10.0, 0.0);
+        assert_eq!(layout.navigate(4, GridMove::Left, 1), 3);
+        assert_eq!(layout.navigate(0, GridMove::Left, 1), 0);
+        assert_eq!(layout.navigate(7, GridMove::Right, 1), 7);
+        assert_eq!(layout.navigate(1, GridMove::Up, 1), 1);
+        assert_eq!(layout.navigate(4, GridMove::Up, 1), 1);
+        assert_eq!(layout.navigate(4, GridMove::Down, 1), 7);
+        // Below a missing cell lands on the last item.
+        assert_eq!(layout.navigate(5, GridMove::Down, 1), 7);
+        assert_eq!(layout.navigate(7, GridMove::Down, 1), 7);
+        assert_eq!(layout.navigate(4, GridMove::RowStart, 1), 3);
+        assert_eq!(layout.navigate(6, GridMove::RowEnd, 1), 7);
+        assert_eq!(layout.navigate(1, GridMove::PageDown, 5), 7);
+        assert_eq!(layout.navigate(7, GridMove::PageUp, 2), 1);
+        assert_eq!(layout.navigate(4, GridMove::First, 1), 0);
+        assert_eq!(layout.navigate(0, GridMove::Last, 1), 7);
+    }
+
+    #[test]
+    fn keys_map_to_movements() {
+        assert_eq!(GridMove::from_key(&Key::ArrowDown, false), Some(GridMove::Down));
+        assert_eq!(GridMove::from_key(&Key::Home, false), Some(GridMove::RowStart));
+        assert_eq!(GridMove::from_key(&Key::Home, true), Some(GridMove::First));
+        assert_eq!(GridMove::from_key(&Key::End, true), Some(GridMove::Last));
+        assert_eq!(GridMove::from_key(&Key::Tab, false), None);
+    }
+
+    #[test]
+    fn reveal_scrolls_only_as_far_as_needed() {
+        assert_eq!(reveal(100.0, 150.0, 50.0, 200.0), 100.0);
+        assert_eq!(reveal(100.0, 40.0, 50.0, 200.0), 40.0);
+        assert_eq!(reveal(100.0, 280.0, 50.0, 200.0), 130.0);
+    }
+}
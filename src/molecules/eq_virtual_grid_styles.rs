@@ -0,0 +1,35 @@
+//! Style constants for EqVirtualGrid.
+
+/// Outer scrollable viewport container.
+pub const VIEWPORT: &str =
+    "overflow-auto relative \
+     bg-[var(--color-card)] \
+     border border-[var(--color-card-border)] rounded-lg";
+
+/// Inner sizer div — sized to the whole grid so both scrollbars are
+/// right without rendering every cell.
+pub const SIZER: &str = "relative";
+
+/// A positioned row of cells.
+pub const ROW: &str = "absolute left-0 w-full";
+
+/// A single positioned cell.
+pub const CELL: &str =
+    "absolute top-0 outline-none \
+     focus-visible:ring-2 focus-visible:ring-[var(--color-accent-primary)] \
+     focus-visible:ring-offset-1 focus-visible:ring-offset-[var(--color-card)] \
+     rounded-md";
+
+/// Extra classes on the active (keyboard) cell.
+pub const CELL_ACTIVE: &str = "z-10";
+
+/// All style tokens for playground introspection.
+pub fn catalog() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("VIEWPORT", VIEWPORT),
+        ("SIZER", SIZER),
+        ("ROW", ROW),
+        ("CELL", CELL),
+        ("CELL_ACTIVE", CELL_ACTIVE),
+    ]
+}
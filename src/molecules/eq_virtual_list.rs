@@ -20,16 +20,23 @@
 //! };
 //! ```
 
+use super::eq_virtual_list_offsets::OffsetIndex;
 use super::eq_virtual_list_styles as s;
 use crate::theme::merge_classes;
 use crate::playground;
 use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
 
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropInput, PropToggle, StyleInfo, format_catalog,
+    DemoSection, PropInput, PropSelect, PropToggle, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
+use crate::timer::sleep;
+#[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
 #[cfg(feature = "playground")]
 use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
@@ -63,29 +70,209 @@ impl StickyHeader {
     }
 }
 
+// ── Scroll alignment ─────────────────────────────────────────────
+
+/// Where `scroll_to_index` puts the target item in the viewport.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub enum ScrollAlign {
+    /// Item at the top (left) edge (default).
+    #[default]
+    Start,
+    /// Item centred.
+    Center,
+    /// Item at the bottom (right) edge.
+    End,
+    /// Scroll by the least amount that brings the item fully into view,
+    /// or not at all when it already is — for following keyboard focus.
+    Nearest,
+}
+
+/// Scroll offset showing an item at `start` of size `size` aligned as
+/// asked from the current offset `scroll`, never before the start of
+/// the list.
+fn scroll_target(start: f64, size: f64, viewport: f64, scroll: f64, align: ScrollAlign) -> f64 {
+    let target = match align {
+        ScrollAlign::Start => start,
+        ScrollAlign::Center => start + size / 2.0 - viewport / 2.0,
+        ScrollAlign::End => start + size - viewport,
+        ScrollAlign::Nearest if start < scroll => start,
+        ScrollAlign::Nearest if start + size > scroll + viewport => start + size - viewport,
+        ScrollAlign::Nearest => scroll,
+    };
+    target.max(0.0)
+}
+
+// ── Edges ────────────────────────────────────────────────────────
+
+/// How close (px) to the end a reverse list still counts as pinned.
+const PIN_TOLERANCE: f64 = 32.0;
+
+/// Whether a list scrolled to `scroll` shows its last pixel, give or
+/// take [`PIN_TOLERANCE`].
+fn at_end(scroll: f64, viewport: f64, scroll_size: f64) -> bool {
+    scroll_size - (scroll + viewport) <= PIN_TOLERANCE
+}
+
+/// Fires `on_start_reached` / `on_end_reached` once per approach: when
+/// the visible range comes within the threshold, and again only after
+/// the list changed at that end (`key`) or the user left and came back.
+#[derive(Default)]
+struct EdgeTrigger {
+    fired_for: Option<usize>,
+}
+
+impl EdgeTrigger {
+    fn update(&mut self, near: bool, key: usize) -> bool {
+        if !near {
+            self.fired_for = None;
+            return false;
+        }
+        if self.fired_for == Some(key) {
+            return false;
+        }
+        self.fired_for = Some(key);
+        true
+    }
+}
+
+/// Infinite-loading and reverse-mode bookkeeping. Held in a `RefCell`
+/// because the post-render effects update it on every pass, and a
+/// signal write there would schedule yet another render.
+#[derive(Default)]
+struct Edges {
+    start: EdgeTrigger,
+    end: EdgeTrigger,
+    /// `first_index + item_count` at the last render - grows when
+    /// items are appended, not when they are prepended.
+    end_key: Option<usize>,
+}
+
+impl Edges {
+    /// Items appended since the last call.
+    fn appended(&mut self, end_key: usize) -> usize {
+        let added = self.end_key.map_or(0, |prev| end_key.saturating_sub(prev));
+        self.end_key = Some(end_key);
+        added
+    }
+}
+
+// ── Variable sizes ───────────────────────────────────────────────
+
+/// Size assumed for unmeasured items when neither `item_size` nor
+/// `estimated_size` is given.
+const DEFAULT_ESTIMATED_SIZE: f64 = 40.0;
+
+/// Measured sizes in variable-size mode. Render syncs it with the item
+/// count, which must not write signals, and the `onresize` handlers
+/// write into it and bump a revision signal only when a size changed.
+#[derive(Default)]
+struct Measured {
+    index: Option<OffsetIndex>,
+    /// `first_index` the index was last synced with.
+    first_index: usize,
+    /// Scroll correction for items added or removed at the front,
+    /// applied once the DOM has caught up.
+    pending_scroll: f64,
+}
+
+impl Measured {
+    /// Bring the index in line with the props: items prepended or
+    /// dropped at the front (a lower or higher `first_index`) queue a
+    /// scroll correction so the visible items stay put; other count
+    /// changes happen at the end.
+    fn sync(&mut self, estimate: f64, item_count: usize, first_index: usize) -> &OffsetIndex {
+        let index = self.index.get_or_insert_with(|| OffsetIndex::new(0, estimate));
+        if index.is_empty() {
+            // Nothing measured yet: no anchor to keep.
+            self.first_index = first_index;
+        } else if first_index < self.first_index {
+            self.pending_scroll += index.prepend(self.first_index - first_index);
+        } else if first_index > self.first_index {
+            self.pending_scroll -= index.remove_front(first_index - self.first_index);
+        }
+        self.first_index = first_index;
+        index.resize(item_count);
+        index
+    }
+
+    /// Record the size of the item at absolute position `key`
+    /// (`first_index + idx`), with the list scrolled to `scroll`.
+    /// Returns whether anything changed and how far to scroll to keep
+    /// the first visible item in place - items above it push it down.
+    fn measure(&mut self, key: usize, size: f64, scroll: f64) -> (bool, f64) {
+        let (Some(index), Some(i)) = (self.index.as_mut(), key.checked_sub(self.first_index)) else {
+            return (false, 0.0);
+        };
+        let anchor = index.index_at(scroll);
+        let delta = index.set_size(i, size);
+        (delta != 0.0, if i < anchor { delta } else { 0.0 })
+    }
+}
+
+/// Scroll the viewport to `target`, a JS expression evaluated with the
+/// element as `el` (a pixel offset, or `el.scrollHeight` for the end).
+fn scroll_viewport_to(vp_id: &str, is_horizontal: bool, target: &str, smooth: bool) {
+    let prop = if is_horizontal { "left" } else { "top" };
+    let behavior = if smooth { "smooth" } else { "auto" };
+    document::eval(&format!(
+        "const el = document.getElementById('{vp_id}'); \
+         if (el) el.scrollTo({{ {prop}: {target}, behavior: '{behavior}' }});"
+    ));
+}
+
+/// Scroll the viewport by `delta` pixels along its axis.
+fn scroll_viewport_by(vp_id: &str, is_horizontal: bool, delta: f64) {
+    let prop = if is_horizontal { "scrollLeft" } else { "scrollTop" };
+    document::eval(&format!(
+        "const el = document.getElementById('{vp_id}'); if (el) el.{prop} += {delta};"
+    ));
+}
+
 // ── Component ────────────────────────────────────────────────────
 
 /// High-performance windowed list.
 ///
 /// Renders only items visible in the viewport plus an overscan buffer.
-/// Supports vertical or horizontal scrolling, fixed-size items,
-/// scroll-to-index, and sticky section headers.
+/// Supports vertical or horizontal scrolling, fixed or measured item
+/// sizes, scroll-to-index, and sticky section headers.
 ///
 /// **How it works** — a sizer div is set to the total content size
 /// (item_count × item_size) so the scrollbar thumb reflects the real
 /// extent. Inside, an absolutely-positioned window div translates to
 /// the current scroll offset and renders only the visible slice.
 ///
+/// **Variable sizes** — with `estimated_size` set, items keep their
+/// natural size. Each one is observed while rendered (a ResizeObserver
+/// via `onresize`) and the sizes are kept in an [`OffsetIndex`], so
+/// offsets stay O(log n) to look up. When items above the first visible
+/// one turn out, or later become, larger or smaller, or items are prepended (by lowering `first_index`),
+/// the list scrolls by the difference so what the user is reading does
+/// not move.
+///
+/// **Infinite loading** — `on_end_reached` / `on_start_reached` fire
+/// once when the visible range comes within `reach_threshold` items of
+/// an end, and again after items were added there. While
+/// `loading_end` / `loading_start` is set the callback is held back and
+/// a loading row (`render_loading`) is shown at that end.
+///
+/// **Reverse mode** — for chats: the list opens scrolled to the end and
+/// follows new items while the user is at the end. Scrolled up, it stays
+/// put and shows a pill counting the new items that jumps back down.
+///
 /// **Accessibility** — the viewport has `role="list"` and each item
 /// is wrapped in `role="listitem"`.
 #[playground(
     category = Molecule,
     description = "Windowed list rendering only visible items for \
-                   smooth scrolling over thousands of rows. Fixed-size \
-                   items, overscan buffer, scroll-to-index, sticky \
-                   headers, vertical/horizontal modes.",
+                   smooth scrolling over thousands of rows. Fixed or \
+                   measured variable-size items, overscan buffer, \
+                   infinite loading at either end, reverse (chat) mode, \
+                   aligned scroll-to-index, sticky headers, vertical/horizontal modes.",
     examples = [
         ("Basic", "EqVirtualList {\n    item_count: 10_000,\n    item_size: 40.0,\n    viewport_size: 400.0,\n    render_item: move |idx: usize| rsx! {\n        div { \"Row {idx}\" }\n    },\n}"),
+        ("Variable sizes", "EqVirtualList {\n    item_count: messages.len(),\n    estimated_size: 64.0,\n    viewport_size: 480.0,\n    // Lower by n when prepending n messages\n    first_index: first_index(),\n    render_item: move |idx: usize| rsx! {\n        Message { msg: messages.read()[idx].clone() }\n    },\n}"),
+        ("Chat", "EqVirtualList {\n    reverse: true,\n    item_count: messages.len(),\n    estimated_size: 64.0,\n    first_index: first_index(),\n    viewport_size: 480.0,\n    on_start_reached: move |_| load_history(),\n    loading_start: loading(),\n    render_item: move |idx: usize| rsx! { Message { idx } },\n}"),
+        ("Infinite feed", "EqVirtualList {\n    item_count: posts.len(),\n    item_size: 96.0,\n    viewport_size: 600.0,\n    on_end_reached: move |_| load_next_page(),\n    loading_end: loading(),\n    render_item: move |idx: usize| rsx! { Post { idx } },\n}"),
         ("Horizontal", "EqVirtualList {\n    direction: VirtualListDirection::Horizontal,\n    item_count: 500,\n    item_size: 120.0,\n    viewport_size: 600.0,\n    render_item: move |idx| rsx! {\n        div { class: \"p-4\", \"Col {idx}\" }\n    },\n}"),
     ],
     custom_demo,
@@ -96,7 +283,21 @@ pub fn EqVirtualList(
     /// Total number of items in the list.
     item_count: usize,
     /// Height (vertical) or width (horizontal) of each item in pixels.
+    /// When neither this nor `estimated_size` is set, items are measured
+    /// as in variable-size mode, starting from a 40px estimate.
+    #[props(default)]
     item_size: f64,
+    /// Switches to variable-size mode: items are measured while they
+    /// render, and this is the size assumed for the ones not rendered
+    /// yet. A close guess keeps the scrollbar steady.
+    #[props(default)]
+    estimated_size: Option<f64>,
+    /// Position of item 0 in a longer sequence (variable-size mode).
+    /// Lower it by `n` when prepending `n` items, raise it when
+    /// dropping items from the front, and the view stays on the same
+    /// items. Start from a large number if you will prepend.
+    #[props(default)]
+    first_index: usize,
     /// Height (vertical) or width (horizontal) of the viewport in pixels.
     viewport_size: f64,
     /// Render callback — receives the item index, returns an Element.
@@ -108,10 +309,44 @@ pub fn EqVirtualList(
     /// visible window. Reduces blank flashes during fast scrolling.
     #[props(default = 3)]
     overscan: usize,
-    /// Programmatic scroll target — when set, the list scrolls to put
-    /// this index at the top of the viewport.
+    /// Programmatic scroll target — when set (or changed), the list
+    /// scrolls to this index, placed as `scroll_align` says.
     #[props(default)]
     scroll_to_index: Option<usize>,
+    /// Where `scroll_to_index` puts the item: start (default), center
+    /// or end of the viewport, or just into view (`Nearest`).
+    #[props(default)]
+    scroll_align: ScrollAlign,
+    /// Animate `scroll_to_index` and the jump to new items.
+    #[props(default = false)]
+    smooth_scroll: bool,
+    /// Called when the visible range gets within `reach_threshold`
+    /// items of the end - load the next page here.
+    #[props(default)]
+    on_end_reached: Option<EventHandler<()>>,
+    /// Called when the visible range gets within `reach_threshold`
+    /// items of the start - load older items here and prepend them by
+    /// lowering `first_index`.
+    #[props(default)]
+    on_start_reached: Option<EventHandler<()>>,
+    /// Distance, in items, from an end at which the reach callbacks fire.
+    #[props(default = 5)]
+    reach_threshold: usize,
+    /// A page is loading at the end: shows the loading row there and
+    /// holds back `on_end_reached`.
+    #[props(default = false)]
+    loading_end: bool,
+    /// Older items are loading: shows the loading row at the start and
+    /// holds back `on_start_reached`.
+    #[props(default = false)]
+    loading_start: bool,
+    /// Content of the loading rows. Defaults to a "Loading…" line.
+    #[props(default)]
+    render_loading: Option<Callback<(), Element>>,
+    /// Chat mode: open at the end, follow new items while there, and
+    /// show a "new items" pill when scrolled up. Meant for vertical lists.
+    #[props(default = false)]
+    reverse: bool,
     /// Sticky section headers. Each header pins at the top (vertical)
     /// or left (horizontal) edge when its section scrolls past.
     #[props(default)]
@@ -126,6 +361,12 @@ pub fn EqVirtualList(
 ) -> Element {
     let is_horizontal = direction == VirtualListDirection::Horizontal;
 
+    // Without a usable fixed size, measure the items instead.
+    let estimated_size = match estimated_size {
+        None if item_size <= 0.0 => Some(DEFAULT_ESTIMATED_SIZE),
+        estimate => estimate,
+    };
+
     // Stable unique ID for the viewport element.
     static COUNTER: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
     let vp_id = use_hook(|| {
@@ -136,36 +377,121 @@ pub fn EqVirtualList(
     let mut scroll_offset = use_signal(|| 0.0_f64);
     let mut viewport_el: Signal<Option<MountedEvent>> = use_signal(|| None);
 
-    // ── Scroll-to-index effect ───────────────────────────────────
-    let scroll_idx = scroll_to_index;
-    let vp_id_eff = vp_id.clone();
-    use_effect(move || {
-        if let Some(idx) = scroll_idx {
-            let target = idx as f64 * item_size;
-            let prop = if is_horizontal { "scrollLeft" } else { "scrollTop" };
-            let js = format!(
-                "const el = document.getElementById('{}'); if(el) el.{} = {};",
-                vp_id_eff, prop, target
-            );
-            document::eval(&js);
+    // ── Variable-size state ──────────────────────────────────────
+    let measured = use_hook(|| Rc::new(RefCell::new(Measured::default())));
+    // Bumped by measurements so the window is recomputed.
+    let mut revision = use_signal(|| 0_u64);
+    let _ = revision();
+
+    // Apply the correction queued by a prepend once it has rendered.
+    let measured_eff = measured.clone();
+    let vp_id_shift = vp_id.clone();
+    use_effect(use_reactive((&first_index,), move |_| {
+        let shift = std::mem::take(&mut measured_eff.borrow_mut().pending_scroll);
+        if shift != 0.0 {
+            *scroll_offset.write() += shift;
+            scroll_viewport_by(&vp_id_shift, is_horizontal, shift);
         }
-    });
+    }));
 
     // ── Windowing math ───────────────────────────────────────────
-    let total_size = item_count as f64 * item_size;
-    let visible_count = (viewport_size / item_size).ceil() as usize;
-
-    let first_visible = if item_size > 0.0 {
-        (scroll_offset() / item_size).floor() as usize
-    } else {
-        0
+    let scroll = scroll_offset();
+    let (total_size, win_start, win_end, offset_px, first_visible, last_visible) = match estimated_size {
+        Some(estimate) => {
+            let mut state = measured.borrow_mut();
+            let index = state.sync(estimate, item_count, first_index);
+            let window = index.window(scroll, viewport_size, overscan);
+            (
+                index.total(),
+                window.start,
+                window.end,
+                index.offset(window.start),
+                index.index_at(scroll),
+                index.index_at(scroll + viewport_size),
+            )
+        }
+        None => {
+            let visible_count = (viewport_size / item_size).ceil() as usize;
+            let first_visible = if item_size > 0.0 {
+                (scroll / item_size).floor() as usize
+            } else {
+                0
+            };
+            let first_visible = first_visible.min(item_count.saturating_sub(1));
+            let win_start = first_visible.saturating_sub(overscan);
+            let win_end = first_visible
+                .saturating_add(visible_count)
+                .saturating_add(overscan)
+                .min(item_count);
+            let last_visible = first_visible
+                .saturating_add(visible_count)
+                .saturating_sub(1)
+                .min(item_count.saturating_sub(1));
+            (item_count as f64 * item_size, win_start, win_end, win_start as f64 * item_size, first_visible, last_visible)
+        }
     };
-    let first_visible = first_visible.min(item_count.saturating_sub(1));
 
-    let win_start = first_visible.saturating_sub(overscan);
-    let win_end = (first_visible + visible_count + overscan).min(item_count);
+    // ── Scroll-to-index effect ───────────────────────────────────
+    let measured_idx = measured.clone();
+    let vp_id_eff = vp_id.clone();
+    use_effect(use_reactive((&scroll_to_index, &scroll_align), move |(scroll_idx, align)| {
+        if let Some(idx) = scroll_idx {
+            let (start, size) = match measured_idx.borrow().index.as_ref() {
+                Some(index) if estimated_size.is_some() => (index.offset(idx), index.size(idx)),
+                _ => (idx as f64 * item_size, item_size),
+            };
+            let current = *scroll_offset.peek();
+            let target = scroll_target(start, size, viewport_size, current, align);
+            if target == current {
+                return;
+            }
+            scroll_viewport_to(&vp_id_eff, is_horizontal, &target.to_string(), smooth_scroll);
+        }
+    }));
+
+    // ── Reverse mode: follow the end ─────────────────────────────
+    let mut pinned = use_signal(|| true);
+    let mut unseen = use_signal(|| 0_usize);
+    let edges = use_hook(|| Rc::new(RefCell::new(Edges::default())));
+    let edges_tail = edges.clone();
+    let vp_id_tail = vp_id.clone();
+    use_effect(use_reactive((&item_count, &first_index), move |(count, first)| {
+        // Measurements change the total too; stay at the very end.
+        let _ = revision();
+        let added = edges_tail.borrow_mut().appended(first + count);
+        if !reverse {
+            return;
+        }
+        if *pinned.peek() {
+            scroll_viewport_to(&vp_id_tail, is_horizontal, "el.scrollHeight", false);
+        } else if added > 0 {
+            *unseen.write() += added;
+        }
+    }));
 
-    let offset_px = win_start as f64 * item_size;
+    // ── Infinite loading ─────────────────────────────────────────
+    let edges_reach = edges.clone();
+    use_effect(use_reactive(
+        (&first_visible, &last_visible, &item_count, &first_index, &loading_start, &loading_end),
+        move |(first_visible, last_visible, count, first, loading_start, loading_end)| {
+            let (start, end) = {
+                let mut edges = edges_reach.borrow_mut();
+                let start = !loading_start
+                    && on_start_reached.is_some()
+                    && edges.start.update(count > 0 && first_visible <= reach_threshold, first);
+                let end = !loading_end
+                    && on_end_reached.is_some()
+                    && edges.end.update(count > 0 && last_visible + reach_threshold + 1 >= count, first + count);
+                (start, end)
+            };
+            if let (true, Some(handler)) = (start, on_start_reached) {
+                handler.call(());
+            }
+            if let (true, Some(handler)) = (end, on_end_reached) {
+                handler.call(());
+            }
+        },
+    ));
 
     // ── Sticky header: find the active one ───────────────────────
     let active_sticky: Option<&StickyHeader> = sticky_headers
@@ -181,28 +507,40 @@ pub fn EqVirtualList(
     let item_cls = if is_horizontal { s::ITEM_HORIZONTAL } else { s::ITEM };
     let sticky_cls = if is_horizontal { s::STICKY_HEADER_HORIZONTAL } else { s::STICKY_HEADER };
 
-    let viewport_style = if is_horizontal {
-        format!("width: {viewport_size}px; height: {item_size}px;")
-    } else {
-        format!("height: {viewport_size}px;")
+    let viewport_style = match (is_horizontal, estimated_size) {
+        (true, None) => format!("width: {viewport_size}px; height: {item_size}px;"),
+        (true, Some(_)) => format!("width: {viewport_size}px;"),
+        (false, _) => format!("height: {viewport_size}px;"),
     };
 
     let sizer_style = if is_horizontal {
         format!("width: {total_size}px; height: 100%;")
+    } else if reverse && total_size < viewport_size {
+        // A short chat sits at the bottom, like a long one scrolled down.
+        format!("height: {total_size}px; margin-top: {}px;", viewport_size - total_size)
     } else {
         format!("height: {total_size}px;")
     };
 
+    let loading_row = move || match render_loading {
+        Some(render) => render.call(()),
+        None => rsx! {
+            div { class: s::LOADING_ROW, "Loading…" }
+        },
+    };
+    let vp_id_pill = vp_id.clone();
+
     let window_style = if is_horizontal {
         format!("transform: translateX({offset_px}px);")
     } else {
         format!("transform: translateY({offset_px}px);")
     };
 
-    let item_style = if is_horizontal {
-        format!("width: {item_size}px;")
-    } else {
-        format!("height: {item_size}px;")
+    // Measured items keep their natural size.
+    let item_style = match (is_horizontal, estimated_size) {
+        (_, Some(_)) => String::new(),
+        (true, None) => format!("width: {item_size}px;"),
+        (false, None) => format!("height: {item_size}px;"),
     };
 
     rsx! {
@@ -219,17 +557,30 @@ pub fn EqVirtualList(
                 if let Some(el) = viewport_el() {
                     let is_hz = is_horizontal;
                     spawn(async move {
-                        if let Ok(offset) = el.get_scroll_offset().await {
-                            if is_hz {
-                                scroll_offset.set(offset.x);
-                            } else {
-                                scroll_offset.set(offset.y);
+                        let Ok(offset) = el.get_scroll_offset().await else { return };
+                        let position = if is_hz { offset.x } else { offset.y };
+                        scroll_offset.set(position);
+                        if reverse && let Ok(size) = el.get_scroll_size().await {
+                            let extent = if is_hz { size.width } else { size.height };
+                            let end = at_end(position, viewport_size, extent);
+                            if end != *pinned.peek() {
+                                pinned.set(end);
+                            }
+                            if end && *unseen.peek() > 0 {
+                                unseen.set(0);
                             }
                         }
                     });
                 }
             },
 
+            // Loading older items - overlaid so offsets do not shift
+            if loading_start {
+                div { class: s::LOADING_START, role: "status",
+                    {loading_row()}
+                }
+            }
+
             // Active sticky header
             if let Some(header) = active_sticky {
                 {
@@ -264,15 +615,60 @@ pub fn EqVirtualList(
 
                     for idx in win_start..win_end {
                         div {
-                            key: "vl-{idx}",
+                            key: "vl-{first_index + idx}",
                             class: "{item_cls}",
                             style: "{item_style}",
                             role: "listitem",
+                            // Observed for as long as the item is rendered, so
+                            // content that grows or shrinks later (images
+                            // loading, sections expanding) is measured again.
+                            onresize: {
+                                let measured = measured.clone();
+                                let vp_id = vp_id.clone();
+                                let key = first_index + idx;
+                                move |evt: Event<ResizeData>| {
+                                    if estimated_size.is_none() {
+                                        return;
+                                    }
+                                    let Ok(size) = evt.get_border_box_size() else { return };
+                                    let size = if is_horizontal { size.width } else { size.height };
+                                    let (changed, shift) =
+                                        measured.borrow_mut().measure(key, size, *scroll_offset.peek());
+                                    if shift != 0.0 {
+                                        *scroll_offset.write() += shift;
+                                        scroll_viewport_by(&vp_id, is_horizontal, shift);
+                                    }
+                                    if changed {
+                                        *revision.write() += 1;
+                                    }
+                                }
+                            },
                             {render_item.call(idx)}
                         }
                     }
                 }
             }
+
+            // Loading the next page
+            if loading_end {
+                div { role: "status", {loading_row()} }
+            }
+
+            // New items arrived while scrolled away from the end
+            if reverse && unseen() > 0 {
+                div { class: s::NEW_ITEMS_DOCK,
+                    button {
+                        r#type: "button",
+                        class: s::NEW_ITEMS_PILL,
+                        onclick: move |_| {
+                            scroll_viewport_to(&vp_id_pill, is_horizontal, "el.scrollHeight", true);
+                            pinned.set(true);
+                            unseen.set(0);
+                        },
+                        if unseen() == 1 { "1 new item ↓" } else { "{unseen} new items ↓" }
+                    }
+                }
+            }
         }
     }
 }
@@ -288,13 +684,24 @@ fn DemoEqVirtualList() -> Element {
     let mut overscan_str = use_signal(|| "3".to_string());
     let mut horizontal = use_signal(|| false);
     let mut show_sticky = use_signal(|| false);
+    let mut variable = use_signal(|| false);
+    // Items prepended with the button, counted down from a large base.
+    let mut prepended = use_signal(|| 0_usize);
     let mut scroll_idx_str = use_signal(String::new);
+    let mut align_str = use_signal(|| "start".to_string());
+    let mut smooth = use_signal(|| false);
 
-    let count: usize = count_str().parse().unwrap_or(10_000).max(1);
+    let count: usize = count_str().parse().unwrap_or(10_000).max(1) + prepended();
     let row_h: f64 = row_height_str().parse().unwrap_or(40.0_f64).max(10.0);
     let vp: f64 = viewport_str().parse().unwrap_or(400.0_f64).max(100.0);
     let overscan: usize = overscan_str().parse().unwrap_or(3);
     let scroll_idx: Option<usize> = scroll_idx_str().parse().ok();
+    let scroll_align = match align_str().as_str() {
+        "center" => ScrollAlign::Center,
+        "end" => ScrollAlign::End,
+        "nearest" => ScrollAlign::Nearest,
+        _ => ScrollAlign::Start,
+    };
 
     let sticky_headers = if show_sticky() {
         // Create section headers every 50 items.
@@ -311,7 +718,19 @@ fn DemoEqVirtualList() -> Element {
         VirtualListDirection::Vertical
     };
 
-    let code = r#"EqVirtualList {
+    let code = if variable() {
+        r#"EqVirtualList {
+    item_count: messages.len(),
+    estimated_size: 64.0,
+    viewport_size: 400.0,
+    // Prepending n messages? Lower first_index by n.
+    first_index: first_index(),
+    render_item: move |idx: usize| rsx! {
+        div { class: "px-4 py-2", "{messages.read()[idx]}" }
+    },
+}"#.to_string()
+    } else {
+        r#"EqVirtualList {
     item_count: 10_000,
     item_size: 40.0,
     viewport_size: 400.0,
@@ -323,7 +742,8 @@ fn DemoEqVirtualList() -> Element {
             "Row {idx}"
         }
     },
-}"#.to_string();
+}"#.to_string()
+    };
 
     rsx! {
         DemoSection { title: "EqVirtualList",
@@ -363,6 +783,17 @@ fn DemoEqVirtualList() -> Element {
                     placeholder: "(none)",
                     onchange: move |v: String| scroll_idx_str.set(v),
                 }
+                PropSelect {
+                    label: "scroll_align",
+                    value: align_str(),
+                    options: vec!["start", "center", "end", "nearest"],
+                    onchange: move |v: String| align_str.set(v),
+                }
+                PropToggle {
+                    label: "smooth_scroll",
+                    value: smooth(),
+                    onchange: move |v: bool| smooth.set(v),
+                }
                 PropToggle {
                     label: "horizontal",
                     value: horizontal(),
@@ -373,6 +804,20 @@ fn DemoEqVirtualList() -> Element {
                     value: show_sticky(),
                     onchange: move |v: bool| show_sticky.set(v),
                 }
+                PropToggle {
+                    label: "variable sizes (estimated_size)",
+                    value: variable(),
+                    onchange: move |v: bool| variable.set(v),
+                }
+                if variable() {
+                    button {
+                        r#type: "button",
+                        class: "px-3 py-1.5 text-sm rounded-md border border-[var(--color-card-border)] \
+                                text-[var(--color-label-primary)] hover:border-[var(--color-accent-primary)]",
+                        onclick: move |_| *prepended.write() += 20,
+                        "Prepend 20 rows"
+                    }
+                }
             }
 
             div { class: "rounded-lg border border-dashed border-[var(--color-card-border)] p-6 space-y-2",
@@ -380,30 +825,161 @@ fn DemoEqVirtualList() -> Element {
                     "Rendering {count} items — only visible rows hit the DOM."
                 }
 
+                if variable() {
+                    EqVirtualList {
+                        item_count: count,
+                        estimated_size: row_h,
+                        first_index: 1_000_000 - prepended(),
+                        viewport_size: vp,
+                        overscan,
+                        direction,
+                        scroll_to_index: scroll_idx,
+                        scroll_align,
+                        smooth_scroll: smooth(),
+                        sticky_headers: sticky_headers.clone(),
+                        render_item: move |idx: usize| {
+                            // Stable per row across prepends.
+                            let id = 1_000_000 - prepended() + idx;
+                            let text = "lorem ipsum dolor sit amet ".repeat(1 + id * 7 % 6);
+                            rsx! {
+                                div {
+                                    class: "px-4 py-2 text-sm border-b border-[var(--color-card-border)]/20 \
+                                            text-[var(--color-label-primary)]",
+                                    div { class: "text-xs text-[var(--color-label-secondary)]", "Message {id}" }
+                                    "{text}"
+                                }
+                            }
+                        },
+                    }
+                } else {
+                    EqVirtualList {
+                        item_count: count,
+                        item_size: row_h,
+                        viewport_size: vp,
+                        overscan,
+                        direction,
+                        scroll_to_index: scroll_idx,
+                        scroll_align,
+                        smooth_scroll: smooth(),
+                        sticky_headers,
+                        render_item: move |idx: usize| {
+                            let bg = if idx.is_multiple_of(2) { "bg-[var(--color-primary-dark)]/20" } else { "" };
+                            rsx! {
+                                div {
+                                    class: "px-4 flex items-center text-sm \
+                                            text-[var(--color-label-primary)] {bg}",
+                                    style: "height: 100%; width: 100%;",
+                                    "Row {idx}"
+                                }
+                            }
+                        },
+                    }
+                }
+            }
+
+            StyleInfo { file: "eq_virtual_list_styles.rs", styles: format_catalog(&s::catalog()) }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
+        }
+        DemoVirtualChat {}
+        DemoVirtualFeed {}
+    }
+}
+
+/// Reverse mode: a chat that loads history on the way up and receives
+/// new messages at the bottom.
+#[cfg(feature = "playground")]
+#[component]
+fn DemoVirtualChat() -> Element {
+    // Messages are numbered; history counts down from 1000.
+    let mut first = use_signal(|| 1_000_usize);
+    let mut last = use_signal(|| 1_030_usize);
+    let mut loading = use_signal(|| false);
+
+    let load_history = move |_| {
+        if first() <= 1 {
+            return;
+        }
+        loading.set(true);
+        spawn(async move {
+            sleep(600).await;
+            first.set(first().saturating_sub(20).max(1));
+            loading.set(false);
+        });
+    };
+
+    rsx! {
+        DemoSection { title: "Chat (reverse)",
+            div { class: "rounded-lg border border-dashed border-[var(--color-card-border)] p-6 space-y-2",
+                EqText { variant: TextVariant::Muted,
+                    "Opens at the newest message. Scroll up to load history, or scroll up and send to see the pill."
+                }
+                button {
+                    r#type: "button",
+                    class: "px-3 py-1.5 text-sm rounded-md border border-[var(--color-card-border)] \
+                            text-[var(--color-label-primary)] hover:border-[var(--color-accent-primary)]",
+                    onclick: move |_| *last.write() += 1,
+                    "Receive a message"
+                }
                 EqVirtualList {
-                    item_count: count,
-                    item_size: row_h,
-                    viewport_size: vp,
-                    overscan,
-                    direction,
-                    scroll_to_index: scroll_idx,
-                    sticky_headers,
+                    reverse: true,
+                    item_count: last() - first(),
+                    estimated_size: 56.0,
+                    first_index: first(),
+                    viewport_size: 360.0,
+                    on_start_reached: load_history,
+                    loading_start: loading(),
                     render_item: move |idx: usize| {
-                        let bg = if idx.is_multiple_of(2) { "bg-[var(--color-primary-dark)]/20" } else { "" };
+                        let id = first() + idx;
+                        let text = "lorem ipsum dolor sit amet ".repeat(1 + id * 5 % 4);
                         rsx! {
-                            div {
-                                class: "px-4 flex items-center text-sm \
-                                        text-[var(--color-label-primary)] {bg}",
-                                style: "height: 100%; width: 100%;",
-                                "Row {idx}"
+                            div { class: "px-4 py-2 text-sm text-[var(--color-label-primary)]",
+                                div { class: "text-xs text-[var(--color-label-secondary)]", "Message {id}" }
+                                "{text}"
                             }
                         }
                     },
                 }
             }
+        }
+    }
+}
 
-            StyleInfo { file: "eq_virtual_list_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+/// Infinite loading: pages of 50 rows fetched as the end comes near.
+#[cfg(feature = "playground")]
+#[component]
+fn DemoVirtualFeed() -> Element {
+    let mut count = use_signal(|| 50_usize);
+    let mut loading = use_signal(|| false);
+
+    let load_page = move |_| {
+        loading.set(true);
+        spawn(async move {
+            sleep(800).await;
+            *count.write() += 50;
+            loading.set(false);
+        });
+    };
+
+    rsx! {
+        DemoSection { title: "Infinite loading",
+            div { class: "rounded-lg border border-dashed border-[var(--color-card-border)] p-6 space-y-2",
+                EqText { variant: TextVariant::Muted, "{count} rows loaded." }
+                EqVirtualList {
+                    item_count: count(),
+                    item_size: 40.0,
+                    viewport_size: 300.0,
+                    on_end_reached: load_page,
+                    loading_end: loading(),
+                    render_item: move |idx: usize| rsx! {
+                        div {
+                            class: "px-4 flex items-center text-sm text-[var(--color-label-primary)] \
+                                    border-b border-[var(--color-card-border)]/20",
+                            style: "height: 100%;",
+                            "Row {idx}"
+                        }
+                    },
+                }
+            }
         }
     }
 }
@@ -503,6 +1079,141 @@ mod tests {
         dom.rebuild_in_place();
     }
 
+    #[test]
+    fn smoke_renders_variable_sizes() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                EqVirtualList {
+                    item_count: 500,
+                    estimated_size: 48.0,
+                    first_index: 1_000,
+                    viewport_size: 300.0,
+                    scroll_to_index: 20,
+                    render_item: move |idx: usize| rsx! { p { "{idx}" } },
+                }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn measuring_items_above_the_view_keeps_it_in_place() {
+        let mut m = Measured::default();
+        m.sync(50.0, 100, 0);
+        // Scrolled to item 4 (offset 200).
+        assert_eq!(m.measure(1, 80.0, 200.0), (true, 30.0));
+        // Item 4 itself and items below it do not move the view.
+        assert_eq!(m.measure(4, 20.0, 230.0), (true, 0.0));
+        assert_eq!(m.measure(9, 50.0, 230.0), (false, 0.0));
+        // Unknown keys are ignored.
+        assert_eq!(m.measure(500, 10.0, 230.0), (false, 0.0));
+    }
+
+    #[test]
+    fn prepending_queues_a_scroll_by_the_new_items() {
+        let mut m = Measured::default();
+        m.sync(50.0, 10, 100);
+        m.measure(100, 70.0, 0.0);
+
+        let index = m.sync(50.0, 13, 97);
+        assert_eq!(index.len(), 13);
+        // The measured item moved with its key.
+        assert_eq!(index.size(3), 70.0);
+        assert_eq!(m.pending_scroll, 150.0);
+        assert_eq!(m.measure(100, 60.0, 150.0), (true, 0.0));
+
+        // Dropping two from the front scrolls back up by their size.
+        m.pending_scroll = 0.0;
+        m.sync(50.0, 11, 99);
+        assert_eq!(m.pending_scroll, -100.0);
+    }
+
+    #[test]
+    fn smoke_renders_reverse_with_loading_rows() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                EqVirtualList {
+                    reverse: true,
+                    item_count: 3,
+                    estimated_size: 40.0,
+                    viewport_size: 300.0,
+                    on_start_reached: move |_| {},
+                    loading_start: true,
+                    loading_end: true,
+                    scroll_to_index: 2,
+                    scroll_align: ScrollAlign::End,
+                    smooth_scroll: true,
+                    render_item: move |idx: usize| rsx! { p { "{idx}" } },
+                }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn smoke_renders_without_a_size() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                EqVirtualList {
+                    item_count: 100,
+                    viewport_size: 400.0,
+                    render_item: move |idx: usize| rsx! { p { "{idx}" } },
+                }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn scroll_target_aligns_within_the_viewport() {
+        // Item at 1000..1040 in a 400px viewport.
+        assert_eq!(scroll_target(1_000.0, 40.0, 400.0, 0.0, ScrollAlign::Start), 1_000.0);
+        assert_eq!(scroll_target(1_000.0, 40.0, 400.0, 0.0, ScrollAlign::Center), 820.0);
+        assert_eq!(scroll_target(1_000.0, 40.0, 400.0, 0.0, ScrollAlign::End), 640.0);
+        // Never before the top of the list.
+        assert_eq!(scroll_target(40.0, 40.0, 400.0, 0.0, ScrollAlign::End), 0.0);
+        // Nearest: below the view aligns the end, above aligns the start,
+        // inside leaves the offset alone.
+        assert_eq!(scroll_target(1_000.0, 40.0, 400.0, 0.0, ScrollAlign::Nearest), 640.0);
+        assert_eq!(scroll_target(1_000.0, 40.0, 400.0, 2_000.0, ScrollAlign::Nearest), 1_000.0);
+        assert_eq!(scroll_target(1_000.0, 40.0, 400.0, 800.0, ScrollAlign::Nearest), 800.0);
+        assert_eq!(ScrollAlign::default(), ScrollAlign::Start);
+    }
+
+    #[test]
+    fn at_end_allows_a_small_tolerance() {
+        assert!(at_end(600.0, 400.0, 1_000.0));
+        assert!(at_end(600.0 - PIN_TOLERANCE, 400.0, 1_000.0));
+        assert!(!at_end(500.0, 400.0, 1_000.0));
+        // Content shorter than the viewport is always at the end.
+        assert!(at_end(0.0, 400.0, 100.0));
+    }
+
+    #[test]
+    fn edge_trigger_fires_once_per_approach_and_change() {
+        let mut t = EdgeTrigger::default();
+        assert!(!t.update(false, 50));
+        assert!(t.update(true, 50));
+        // Still near, nothing loaded yet.
+        assert!(!t.update(true, 50));
+        // A page arrived while still near.
+        assert!(t.update(true, 100));
+        // Leaving and coming back fires again for the same list.
+        assert!(!t.update(false, 100));
+        assert!(t.update(true, 100));
+    }
+
+    #[test]
+    fn appended_counts_only_growth_at_the_end() {
+        let mut e = Edges::default();
+        assert_eq!(e.appended(130), 0);
+        assert_eq!(e.appended(133), 3);
+        // Prepending lowers first_index and raises item_count alike.
+        assert_eq!(e.appended(133), 0);
+        assert_eq!(e.appended(120), 0);
+        assert_eq!(e.appended(121), 1);
+    }
+
     #[test]
     fn default_direction_is_vertical() {
         let d: VirtualListDirection = Default::default();
@@ -0,0 +1,243 @@
+//! Item offsets for [`EqVirtualList`](super::EqVirtualList) in
+//! variable-size mode.
+//!
+//! A Fenwick (binary indexed) tree over item sizes: updating one size,
+//! reading the offset of an item and finding the item at a scroll
+//! position are all O(log n), so measuring rows as they mount stays
+//! cheap on lists of any length.
+
+use std::ops::Range;
+
+/// Sizes and offsets of a list of items, some measured, the rest at an
+/// estimate.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OffsetIndex {
+    sizes: Vec<f64>,
+    /// 1-based Fenwick tree; `tree[i]` sums `sizes[i - lowbit(i)..i]`.
+    tree: Vec<f64>,
+    estimate: f64,
+}
+
+impl OffsetIndex {
+    /// `len` items, each `estimate` pixels until measured.
+    pub fn new(len: usize, estimate: f64) -> Self {
+        let estimate = estimate.max(0.0);
+        let mut index = Self { sizes: vec![estimate; len], tree: Vec::new(), estimate };
+        index.rebuild();
+        index
+    }
+
+    pub fn len(&self) -> usize {
+        self.sizes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sizes.is_empty()
+    }
+
+    /// Size given to items that have not been measured.
+    pub fn estimate(&self) -> f64 {
+        self.estimate
+    }
+
+    /// Size of item `i` (the estimate until it is measured).
+    pub fn size(&self, i: usize) -> f64 {
+        self.sizes.get(i).copied().unwrap_or(0.0)
+    }
+
+    /// Record the measured size of item `i`. Returns how much the item
+    /// grew (negative when it shrank), which is how far everything
+    /// after it moved.
+    pub fn set_size(&mut self, i: usize, size: f64) -> f64 {
+        let Some(old) = self.sizes.get_mut(i) else { return 0.0 };
+        let delta = size.max(0.0) - *old;
+        if delta == 0.0 {
+            return 0.0;
+        }
+        *old += delta;
+        let mut node = i + 1;
+        while node < self.tree.len() {
+            self.tree[node] += delta;
+            node += lowbit(node);
+        }
+        delta
+    }
+
+    /// Distance from the start of the list to the start of item `i`;
+    /// `offset(len())` is the total size.
+    pub fn offset(&self, i: usize) -> f64 {
+        let mut node = i.min(self.len());
+        let mut sum = 0.0;
+        while node > 0 {
+            sum += self.tree[node];
+            node -= lowbit(node);
+        }
+        sum
+    }
+
+    /// Size of the whole list.
+    pub fn total(&self) -> f64 {
+        self.offset(self.len())
+    }
+
+    /// Index of the item covering `offset`, clamped to the list.
+    /// Zero-size items are skipped over.
+    pub fn index_at(&self, offset: f64) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+        // Largest prefix whose sum stays at or below `offset`.
+        let mut node = 0;
+        let mut remaining = offset.max(0.0);
+        let mut step = (self.len() + 1).next_power_of_two();
+        while step > 0 {
+            let next = node + step;
+            if next < self.tree.len() && self.tree[next] <= remaining {
+                node = next;
+                remaining -= self.tree[next];
+            }
+            step /= 2;
+        }
+        node.min(self.len() - 1)
+    }
+
+    /// Items to render for a viewport of `viewport` pixels scrolled to
+    /// `scroll`, plus `overscan` items on each side.
+    pub fn window(&self, scroll: f64, viewport: f64, overscan: usize) -> Range<usize> {
+        if self.is_empty() {
+            return 0..0;
+        }
+        let first = self.index_at(scroll);
+        let last = self.index_at(scroll + viewport.max(0.0));
+        first.saturating_sub(overscan)..(last + 1 + overscan).min(self.len())
+    }
+
+    /// Grow (with estimated items at the end) or shrink to `len` items.
+    pub fn resize(&mut self, len: usize) {
+        if len != self.len() {
+            self.sizes.resize(len, self.estimate);
+            self.rebuild();
+        }
+    }
+
+    /// Add `count` estimated items before the first one. Returns their
+    /// combined size - how far the existing items moved down.
+    pub fn prepend(&mut self, count: usize) -> f64 {
+        if count == 0 {
+            return 0.0;
+        }
+        self.sizes.splice(0..0, std::iter::repeat_n(self.estimate, count));
+        self.rebuild();
+        self.offset(count)
+    }
+
+    /// Remove `count` items from the front. Returns their combined size.
+    pub fn remove_front(&mut self, count: usize) -> f64 {
+        let count = count.min(self.len());
+        let removed = self.offset(count);
+        self.sizes.drain(..count);
+        self.rebuild();
+        removed
+    }
+
+    /// O(n) construction: each node passes its sum on to its parent.
+    fn rebuild(&mut self) {
+        self.tree = std::iter::once(0.0).chain(self.sizes.iter().copied()).collect();
+        for node in 1..self.tree.len() {
+            let parent = node + lowbit(node);
+            if parent < self.tree.len() {
+                self.tree[parent] += self.tree[node];
+            }
+        }
+    }
+}
+
+fn lowbit(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Offsets the slow way.
+    fn naive_offset(sizes: &[f64], i: usize) -> f64 {
+        sizes[..i].iter().sum()
+    }
+
+    #[test]
+    fn offsets_match_prefix_sums_after_updates() {
+        let mut index = OffsetIndex::new(37, 20.0);
+        let mut sizes = vec![20.0; 37];
+        for (i, size) in [(0, 55.0), (5, 3.0), (16, 120.0), (36, 0.0), (5, 41.0)] {
+            let delta = index.set_size(i, size);
+            assert_eq!(delta, size - sizes[i]);
+            sizes[i] = size;
+        }
+        for i in 0..=37 {
+            assert_eq!(index.offset(i), naive_offset(&sizes, i), "offset({i})");
+        }
+        assert_eq!(index.total(), sizes.iter().sum::<f64>());
+        assert_eq!(index.set_size(99, 10.0), 0.0);
+    }
+
+    #[test]
+    fn index_at_finds_the_covering_item() {
+        let mut index = OffsetIndex::new(5, 10.0);
+        index.set_size(2, 30.0);
+        // Items span 0-10, 10-20, 20-50, 50-60, 60-70.
+        assert_eq!(index.index_at(0.0), 0);
+        assert_eq!(index.index_at(9.9), 0);
+        assert_eq!(index.index_at(10.0), 1);
+        assert_eq!(index.index_at(49.0), 2);
+        assert_eq!(index.index_at(50.0), 3);
+        assert_eq!(index.index_at(1_000.0), 4);
+        assert_eq!(index.index_at(-5.0), 0);
+        // A collapsed item is never the one at an offset.
+        index.set_size(1, 0.0);
+        assert_eq!(index.index_at(10.0), 2);
+        assert_eq!(OffsetIndex::new(0, 10.0).index_at(5.0), 0);
+    }
+
+    #[test]
+    fn window_covers_the_viewport_plus_overscan() {
+        let index = OffsetIndex::new(100, 40.0);
+        assert_eq!(index.window(0.0, 200.0, 0), 0..6);
+        assert_eq!(index.window(400.0, 200.0, 2), 8..18);
+        assert_eq!(index.window(3_900.0, 200.0, 3), 94..100);
+        assert_eq!(OffsetIndex::new(0, 40.0).window(0.0, 200.0, 3), 0..0);
+    }
+
+    #[test]
+    fn resize_and_prepend_keep_measured_sizes() {
+        let mut index = OffsetIndex::new(3, 10.0);
+        index.set_size(1, 25.0);
+        index.resize(5);
+        assert_eq!(index.total(), 10.0 + 25.0 + 10.0 * 3.0);
+
+        assert_eq!(index.prepend(2), 20.0);
+        assert_eq!(index.len(), 7);
+        assert_eq!(index.size(3), 25.0);
+        assert_eq!(index.offset(3), 30.0);
+
+        assert_eq!(index.remove_front(4), 55.0);
+        assert_eq!(index.len(), 3);
+        index.resize(1);
+        assert_eq!(index.total(), 10.0);
+    }
+
+    #[test]
+    fn large_lists_agree_with_the_naive_sum() {
+        let sizes: Vec<f64> = (0..1_000).map(|i| 20.0 + (i * 7 % 13) as f64).collect();
+        let mut index = OffsetIndex::new(sizes.len(), 20.0);
+        for (i, s) in sizes.iter().enumerate() {
+            index.set_size(i, *s);
+        }
+        for i in [0, 1, 2, 511, 512, 513, 999, 1_000] {
+            assert_eq!(index.offset(i), naive_offset(&sizes, i));
+        }
+        for i in [0, 250, 777, 999] {
+            assert_eq!(index.index_at(naive_offset(&sizes, i) + 0.5), i);
+        }
+    }
+}
@@ -43,6 +43,26 @@ pub const STICKY_HEADER_HORIZONTAL: &str =
      bg-[var(--color-card)] \
      border-r border-[var(--color-card-border)]";
 
+/// Default content of the loading rows.
+pub const LOADING_ROW: &str =
+    "flex items-center justify-center py-3 \
+     text-xs text-[var(--color-label-secondary)]";
+
+/// Wrapper of the loading row at the start — a zero-height sticky
+/// overlay, so it does not push the items down.
+pub const LOADING_START: &str =
+    "sticky top-0 z-20 h-0 \
+     [&>*]:bg-[var(--color-card)]";
+
+/// Zero-height sticky dock pinning the new-items pill to the bottom.
+pub const NEW_ITEMS_DOCK: &str = "sticky bottom-0 z-20 h-0 flex justify-center";
+
+/// "N new items" pill shown in reverse mode while scrolled up.
+pub const NEW_ITEMS_PILL: &str =
+    "-translate-y-[calc(100%+0.75rem)] px-3 py-1.5 rounded-full \
+     text-xs font-medium shadow-lg cursor-pointer \
+     bg-[var(--btn-primary-bg-solid)] text-[var(--btn-primary-text)]";
+
 /// All style tokens for playground introspection.
 pub fn catalog() -> Vec<(&'static str, &'static str)> {
     vec![
@@ -56,5 +76,9 @@ pub fn catalog() -> Vec<(&'static str, &'static str)> {
         ("ITEM_HORIZONTAL", ITEM_HORIZONTAL),
         ("STICKY_HEADER", STICKY_HEADER),
         ("STICKY_HEADER_HORIZONTAL", STICKY_HEADER_HORIZONTAL),
+        ("LOADING_ROW", LOADING_ROW),
+        ("LOADING_START", LOADING_START),
+        ("NEW_ITEMS_DOCK", NEW_ITEMS_DOCK),
+        ("NEW_ITEMS_PILL", NEW_ITEMS_PILL),
     ]
 }
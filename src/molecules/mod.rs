@@ -17,7 +17,7 @@ pub mod eq_tree_styles;
 pub mod eq_accordion;
 pub mod eq_accordion_styles;
 
-pub use eq_tree::{EqTree, TreeNode};
+pub use eq_tree::{DropPosition, EqTree, TreeDrop, TreeNode, TreePredicate};
 pub use eq_accordion::{EqAccordion, AccordionItem, AccordionMode};
 
 pub mod eq_nav_item;
@@ -36,14 +36,39 @@ pub use eq_modal::{EqModal, ModalSize};
 
 pub mod eq_toast;
 pub mod eq_toast_styles;
+pub mod eq_toaster;
 
-pub use eq_toast::{EqToastList, ToastData, ToastSeverity, ToastPosition};
+pub use eq_toast::{EqToastList, ToastAction, ToastData, ToastSeverity, ToastPosition};
+pub use eq_toaster::{EqToaster, Toaster, use_toaster};
+
+pub mod eq_dialogs;
+pub mod eq_dialogs_styles;
+
+pub use eq_dialogs::{ConfirmOptions, Dialogs, EqDialogHost, PromptOptions, PromptValidator, use_dialogs};
+
+pub mod eq_menu;
+pub mod eq_menu_styles;
+
+pub use eq_menu::{toggle_item, MenuItem, MenuItemKind};
 
 pub mod eq_dropdown;
 pub mod eq_dropdown_styles;
 
 pub use eq_dropdown::{EqDropdown, DropdownItem, DropdownPosition};
 
+pub mod eq_context_menu;
+pub mod eq_context_menu_styles;
+
+pub use eq_context_menu::EqContextMenu;
+
+pub mod eq_popover;
+pub mod eq_popover_styles;
+pub mod eq_hover_card;
+pub mod eq_hover_card_styles;
+
+pub use eq_popover::{EqPopover, PopoverPosition};
+pub use eq_hover_card::EqHoverCard;
+
 pub mod eq_date_picker;
 pub mod eq_date_picker_styles;
 
@@ -55,11 +80,32 @@ pub mod eq_calendar_styles;
 pub use eq_calendar::{EqCalendar, CalendarEvent, CalendarMode, EventColor};
 
 pub mod eq_virtual_list;
+pub mod eq_virtual_list_offsets;
 pub mod eq_virtual_list_styles;
 
-pub use eq_virtual_list::{EqVirtualList, VirtualListDirection, StickyHeader};
+pub use eq_virtual_list::{EqVirtualList, VirtualListDirection, StickyHeader, ScrollAlign};
+pub use eq_virtual_list_offsets::OffsetIndex;
+
+pub mod eq_virtual_grid;
+pub mod eq_virtual_grid_styles;
+
+pub use eq_virtual_grid::{EqVirtualGrid, GridLayout, GridMove, GridWindow};
 
 pub mod eq_device_frame;
 pub mod eq_device_frame_styles;
 
 pub use eq_device_frame::{EqDeviceFrame, DeviceModel};
+
+pub mod eq_markdown;
+pub mod eq_markdown_parser;
+pub mod eq_markdown_styles;
+
+pub use eq_markdown::EqMarkdown;
+pub use eq_markdown_parser::{MdAlign, MdBlock, MdInline, MdList, MdListItem, MdTable, parse_markdown};
+
+pub mod eq_color_picker;
+pub mod eq_color_picker_color;
+pub mod eq_color_picker_styles;
+
+pub use eq_color_picker::{EqColorPicker, THEME_SWATCHES};
+pub use eq_color_picker_color::{Color, ColorFormat, Hsva};
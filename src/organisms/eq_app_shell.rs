@@ -3,7 +3,9 @@ use crate::theme::{merge_classes, APP, CONTAINER_LAYOUT, MAIN_CONTENT, MAIN_INNE
 use crate::playground;
 
 #[cfg(feature = "playground")]
-use crate::playground::playground_helpers::{CodeBlock, DemoSection, StyleInfo, format_catalog};
+use crate::playground::playground_helpers::{DemoSection, StyleInfo, format_catalog};
+#[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
 #[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
 #[cfg(feature = "playground")]
@@ -65,7 +67,7 @@ fn DemoEqAppShell() -> Element {
                 ("MAIN_CONTENT", "flex-1"),
                 ("MAIN_INNER", "py-10"),
             ]) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
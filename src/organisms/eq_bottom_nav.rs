@@ -44,9 +44,11 @@ use crate::atoms::{EqText, TextVariant};
 use crate::molecules::EqDeviceFrame;
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, StyleInfo, format_catalog,
+    DemoSection, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::playground::playground_types::{
     ComponentCategory, ComponentDescriptor, UsageExample,
 };
@@ -334,7 +336,7 @@ EqBottomNav {
                 file: "eq_bottom_nav_styles.rs",
                 styles: format_catalog(&s::catalog()),
             }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
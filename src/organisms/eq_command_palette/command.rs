@@ -0,0 +1,268 @@
+//! Commands and the registry the palette lists.
+//!
+//! [`EqCommandPalette`](super::EqCommandPalette) provides a
+//! [`CommandRegistry`] to its subtree. Any component below it adds
+//! commands with [`use_commands`]; they are listed while the component
+//! is mounted, and their shortcuts are bound app-wide through the
+//! [`ShortcutManager`](crate::shortcut::ShortcutManager).
+
+use crate::shortcut::{use_shortcuts, Chord};
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// How many recently run commands are remembered (and boosted).
+pub const RECENT_LIMIT: usize = 8;
+
+/// One entry in the palette.
+#[derive(Clone, PartialEq)]
+pub struct Command {
+    /// Unique identifier, used for recent-use tracking.
+    pub id: String,
+    /// Title shown in the list and matched against the query.
+    pub title: String,
+    /// Section heading. Empty for ungrouped commands.
+    pub group: String,
+    /// Extra search terms that are not displayed.
+    pub keywords: Vec<String>,
+    /// Shortcut spec such as `"mod+shift+p"` (see
+    /// [`Chord::parse`]). Bound app-wide and shown as a hint.
+    pub shortcut: Option<String>,
+    /// Runs when the command is chosen.
+    pub action: Option<Callback<()>>,
+    /// Nested page: choosing the command lists these instead of
+    /// running an action.
+    pub page: Vec<Command>,
+}
+
+impl Command {
+    /// Create a command with no action yet.
+    pub fn new(id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            group: String::new(),
+            keywords: Vec::new(),
+            shortcut: None,
+            action: None,
+            page: Vec::new(),
+        }
+    }
+
+    /// Builder: section heading.
+    pub fn group(mut self, group: impl Into<String>) -> Self {
+        self.group = group.into();
+        self
+    }
+
+    /// Builder: extra search terms.
+    pub fn keywords<I, S>(mut self, keywords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.keywords = keywords.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Builder: keyboard shortcut spec.
+    pub fn shortcut(mut self, spec: impl Into<String>) -> Self {
+        self.shortcut = Some(spec.into());
+        self
+    }
+
+    /// Builder: action to run.
+    pub fn on_run(mut self, action: impl FnMut(()) + 'static) -> Self {
+        self.action = Some(Callback::new(action));
+        self
+    }
+
+    /// Builder: open a nested page of commands instead of running.
+    pub fn page(mut self, commands: Vec<Command>) -> Self {
+        self.page = commands;
+        self
+    }
+
+    /// Whether choosing this command opens a page.
+    pub fn has_page(&self) -> bool {
+        !self.page.is_empty()
+    }
+
+    /// Parsed shortcut, if the spec is valid.
+    pub fn chord(&self) -> Option<Chord> {
+        self.shortcut.as_deref().and_then(Chord::parse)
+    }
+}
+
+/// Moves `id` to the front of `recent`, keeping at most
+/// [`RECENT_LIMIT`] entries.
+pub(crate) fn push_recent(recent: &mut Vec<String>, id: &str) {
+    recent.retain(|r| r != id);
+    recent.insert(0, id.to_string());
+    recent.truncate(RECENT_LIMIT);
+}
+
+/// Commands registered under an [`EqCommandPalette`](super::EqCommandPalette),
+/// plus its open state and recent-use history.
+#[derive(Clone, Copy, PartialEq)]
+pub struct CommandRegistry {
+    sources: Signal<Vec<(u64, Vec<Command>)>>,
+    recent: Signal<Vec<String>>,
+    open: Signal<bool>,
+}
+
+impl CommandRegistry {
+    pub(crate) fn new(open: Signal<bool>) -> Self {
+        Self { sources: Signal::new(Vec::new()), recent: Signal::new(Vec::new()), open }
+    }
+
+    /// All top-level commands, in registration order.
+    pub fn commands(&self) -> Vec<Command> {
+        self.sources.read().iter().flat_map(|(_, cmds)| cmds.iter().cloned()).collect()
+    }
+
+    /// Ids of recently run commands, most recent first.
+    pub fn recent(&self) -> Vec<String> {
+        self.recent.read().clone()
+    }
+
+    /// Whether the palette is open.
+    pub fn is_open(&self) -> bool {
+        (self.open)()
+    }
+
+    /// Open the palette.
+    pub fn open(&self) {
+        let mut open = self.open;
+        open.set(true);
+    }
+
+    /// Close the palette.
+    pub fn close(&self) {
+        let mut open = self.open;
+        open.set(false);
+    }
+
+    /// Open or close the palette.
+    pub fn toggle(&self) {
+        let mut open = self.open;
+        open.toggle();
+    }
+
+    /// Run a command's action and record it as recently used. Commands
+    /// with a page have nothing to run; the palette opens the page.
+    pub fn run(&self, command: &Command) {
+        let mut recent = self.recent;
+        push_recent(&mut recent.write(), &command.id);
+        if let Some(action) = command.action {
+            action.call(());
+        }
+    }
+
+    fn set(&self, source: u64, commands: Vec<Command>) {
+        let mut sources = self.sources;
+        let mut sources = sources.write();
+        match sources.iter_mut().find(|(id, _)| *id == source) {
+            Some(entry) => entry.1 = commands,
+            None => sources.push((source, commands)),
+        }
+    }
+
+    fn remove(&self, source: u64) {
+        let mut sources = self.sources;
+        if let Ok(mut sources) = sources.try_write() {
+            sources.retain(|(id, _)| *id != source);
+        }
+    }
+}
+
+/// The registry of the nearest [`EqCommandPalette`](super::EqCommandPalette).
+pub fn use_command_registry() -> Option<CommandRegistry> {
+    try_use_context::<CommandRegistry>()
+}
+
+/// Adds commands to the nearest palette while this component is
+/// mounted, and binds their shortcuts app-wide.
+///
+/// `build` runs after render and again whenever a signal it reads
+/// changes, so commands can depend on state.
+pub fn use_commands(build: impl FnMut() -> Vec<Command> + 'static) {
+    let registry = use_command_registry();
+    let shortcuts = use_shortcuts();
+    let source = use_hook(|| {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    });
+    // Shortcut ids from the last registration. The effect swaps them on
+    // each rebuild without re-rendering, and `use_drop` still needs them
+    // to unregister after this scope's signals are dropped.
+    let bound = use_hook(|| Rc::new(RefCell::new(Vec::<u64>::new())));
+
+    let mut build = build;
+    let effect_bound = bound.clone();
+    use_effect(move || {
+        let commands = build();
+        let Some(registry) = registry else { return };
+
+        for id in effect_bound.borrow_mut().drain(..) {
+            shortcuts.unregister(id);
+        }
+        let mut pending: Vec<&Command> = commands.iter().collect();
+        while let Some(command) = pending.pop() {
+            pending.extend(command.page.iter());
+            if command.action.is_none() {
+                continue;
+            }
+            if let Some(chord) = command.chord() {
+                let command_for_run = command.clone();
+                let handler = Callback::new(move |_| registry.run(&command_for_run));
+                effect_bound.borrow_mut().push(shortcuts.register(chord, command.title.clone(), handler));
+            }
+        }
+        registry.set(source, commands);
+    });
+
+    use_drop(move || {
+        for id in bound.borrow_mut().drain(..) {
+            shortcuts.unregister(id);
+        }
+        if let Some(registry) = registry {
+            registry.remove(source);
+        }
+    });
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recent_moves_to_front_and_caps() {
+        let mut recent = Vec::new();
+        for i in 0..10 {
+            push_recent(&mut recent, &format!("c{i}"));
+        }
+        push_recent(&mut recent, "c5");
+        assert_eq!(recent.len(), RECENT_LIMIT);
+        assert_eq!(recent[0], "c5");
+        assert_eq!(recent[1], "c9");
+        assert_eq!(recent.iter().filter(|r| *r == "c5").count(), 1);
+    }
+
+    #[test]
+    fn builder_sets_fields() {
+        let cmd = Command::new("theme", "Change theme")
+            .group("Appearance")
+            .keywords(["color", "dark"])
+            .shortcut("mod+shift+t")
+            .page(vec![Command::new("dark", "Dark")]);
+        assert_eq!(cmd.group, "Appearance");
+        assert_eq!(cmd.keywords, vec!["color", "dark"]);
+        assert!(cmd.has_page());
+        assert!(cmd.chord().is_some());
+        assert!(Command::new("x", "X").shortcut("bogus+x").chord().is_none());
+    }
+}
@@ -0,0 +1,19 @@
+//! EqCommandPalette organism - Cmd+K command palette.
+//!
+//! Split into focused modules for maintainability:
+//!
+//! - **command** - `Command` model, the `CommandRegistry` context and `use_commands`
+//! - **search** - fuzzy matching and ranking with recent-use boost
+//! - **styles** - co-located Tailwind class constants
+//! - **palette** - the `EqCommandPalette` component
+//!
+//! Shortcuts are bound through the app-wide [`crate::shortcut`] manager,
+//! so they work while the palette is closed.
+
+pub mod command;
+pub mod search;
+pub mod styles;
+pub mod palette;
+
+pub use command::{Command, CommandRegistry, use_command_registry, use_commands};
+pub use palette::EqCommandPalette;
@@ -0,0 +1,633 @@
+//! EqCommandPalette - keyboard-driven command palette.
+//!
+//! Wraps the app (or part of it) and provides a [`CommandRegistry`].
+//! Components below it add commands with
+//! [`use_commands`](super::use_commands); the palette opens with
+//! Cmd+K / Ctrl+K (configurable) and lists them, fuzzy-ranked by the
+//! query with recently used commands boosted.
+
+use super::command::{Command, CommandRegistry};
+use super::search::{highlight_segments, rank, Ranked};
+use super::styles as s;
+use crate::overlay::{EqFocusGuard, EqPortal, FocusEdge, FocusTarget, use_focus_trap};
+use crate::playground;
+use crate::shortcut::{use_shortcut_with, use_shortcuts, Platform, ShortcutOptions};
+#[cfg(feature = "playground")]
+use crate::shortcut::{Chord, ShortcutScope};
+use dioxus::document;
+use dioxus::prelude::*;
+
+#[cfg(feature = "playground")]
+use super::command::{use_command_registry, use_commands};
+#[cfg(feature = "playground")]
+use crate::atoms::{EqButton, EqText, TextVariant};
+#[cfg(feature = "playground")]
+use crate::eq_theme::EqTheme;
+#[cfg(feature = "playground")]
+use crate::playground::playground_helpers::{DemoSection, PropInput, StyleInfo, format_catalog};
+#[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
+use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
+
+// ── Sections ────────────────────────────────────────────────────────
+
+/// A heading and the ranked commands listed under it.
+#[derive(Clone, PartialEq, Debug)]
+struct Section {
+    label: String,
+    items: Vec<Ranked>,
+}
+
+/// Arranges ranked results for display. While browsing (empty query)
+/// recently used commands form a "Recent" section and the rest are
+/// grouped by [`Command::group`] in order of first appearance; while
+/// searching, results stay in one list in rank order.
+fn sections(commands: &[Command], ranked: Vec<Ranked>, recent: &[String], browsing: bool) -> Vec<Section> {
+    if !browsing {
+        return vec![Section { label: String::new(), items: ranked }];
+    }
+    let mut out: Vec<Section> = Vec::new();
+    let (recents, rest): (Vec<Ranked>, Vec<Ranked>) =
+        ranked.into_iter().partition(|r| recent.contains(&commands[r.index].id));
+    if !recents.is_empty() {
+        out.push(Section { label: "Recent".to_string(), items: recents });
+    }
+    let first_group = out.len();
+    for r in rest {
+        let group = &commands[r.index].group;
+        match out[first_group..].iter_mut().find(|section| &section.label == group) {
+            Some(section) => section.items.push(r),
+            None => out.push(Section { label: group.clone(), items: vec![r] }),
+        }
+    }
+    out
+}
+
+// ── Component ─────────────────────────────────────────────────────
+
+/// Command palette over `children`.
+///
+/// `shortcut` toggles it from anywhere in the app; an empty string
+/// disables the shortcut (open it with [`CommandRegistry::open`]).
+/// Choosing a command closes the palette and runs it, unless the
+/// command has a page, which is listed in place with its title as a
+/// breadcrumb. Backspace in an empty field or Escape goes back a page;
+/// Escape on the top level closes.
+///
+/// **Accessibility** - the panel is a modal `dialog` with a focus trap.
+/// The input is a `combobox` controlling a `listbox`; the highlighted
+/// option is tracked with `aria-activedescendant`, so focus stays in
+/// the input while Up / Down move through the results.
+#[playground(
+    category = Organism,
+    description = "Cmd+K command palette: commands registered from anywhere in the tree, \
+                   fuzzy search with recent-use boost, nested pages, and app-wide shortcuts.",
+    examples = [
+        ("Basic", "EqCommandPalette {\n    App {}\n}\n\n#[component]\nfn App() -> Element {\n    use_commands(|| vec![\n        Command::new(\"new\", \"New file\").group(\"File\").shortcut(\"mod+alt+n\")\n            .on_run(|_| log::info!(\"new\")),\n        Command::new(\"save\", \"Save\").group(\"File\").shortcut(\"mod+s\")\n            .on_run(|_| log::info!(\"save\")),\n    ]);\n    rsx! { /* … */ }\n}"),
+        ("Nested page", "Command::new(\"theme\", \"Change theme…\").keywords([\"color\"]).page(\n    EqTheme::build_in_variants()\n        .into_iter()\n        .map(|(name, variant)| {\n            Command::new(name, name).on_run(move |_| theme.set(variant.clone()))\n        })\n        .collect(),\n)"),
+        ("Open from code", "let registry = use_command_registry().unwrap();\n\nEqButton { on_click: move |_| registry.open(), \"Commands\" }"),
+    ],
+    custom_demo,
+    custom_gallery,
+)]
+#[component]
+pub fn EqCommandPalette(
+    /// Shortcut that toggles the palette. Empty disables it.
+    #[props(into, default = "mod+k".to_string())]
+    shortcut: String,
+    /// Placeholder for the search field.
+    #[props(into, default = "Type a command or search…".to_string())]
+    placeholder: String,
+    /// The app; commands registered below this show up in the palette.
+    children: Element,
+) -> Element {
+    let open = use_signal(|| false);
+    let registry = use_context_provider(|| CommandRegistry::new(open));
+    use_shortcut_with(
+        &shortcut,
+        ShortcutOptions::new("Command palette").global(),
+        move |_| registry.toggle(),
+    );
+
+    let trap = use_focus_trap(open(), FocusTarget::First);
+    let panel_id = trap.id();
+
+    rsx! {
+        {children}
+
+        EqPortal {
+            active: open(),
+            lock_scroll: true,
+            restore_focus: true,
+            on_escape: move |_| registry.close(),
+
+            div {
+                class: "{s::BACKDROP}",
+                onclick: move |_| registry.close(),
+
+                EqFocusGuard { trap, edge: FocusEdge::Start }
+
+                div {
+                    id: "{panel_id}",
+                    class: "{s::PANEL}",
+                    role: "dialog",
+                    "aria-modal": "true",
+                    "aria-label": "Command palette",
+                    tabindex: "-1",
+                    onclick: move |evt| evt.stop_propagation(),
+
+                    if open() {
+                        PaletteBody {
+                            registry,
+                            placeholder: placeholder.clone(),
+                            base_id: panel_id.clone(),
+                        }
+                    }
+                }
+
+                EqFocusGuard { trap, edge: FocusEdge::End }
+            }
+        }
+    }
+}
+
+/// Search field, results and footer. Mounted only while open, so the
+/// query and page stack reset each time the palette opens.
+#[component]
+fn PaletteBody(registry: CommandRegistry, placeholder: String, base_id: String) -> Element {
+    let mut query = use_signal(String::new);
+    let mut pages = use_signal(Vec::<Command>::new);
+    let mut active = use_signal(|| 0usize);
+    let platform = use_shortcuts().platform();
+
+    let commands = pages.read().last().map(|p| p.page.clone()).unwrap_or_else(|| registry.commands());
+    let recent = registry.recent();
+    let q = query();
+    let browsing = q.trim().is_empty();
+    let groups = sections(&commands, rank(&commands, &q, &recent), &recent, browsing);
+    let flat: Vec<usize> = groups.iter().flat_map(|g| g.items.iter().map(|r| r.index)).collect();
+    let count = flat.len();
+    let current = active().min(count.saturating_sub(1));
+
+    let list_id = format!("{base_id}-list");
+    let option_id = move |n: usize| format!("{base_id}-option-{n}");
+    let active_id = if count > 0 { option_id(current) } else { String::new() };
+
+    let mut choose = move |command: Command| {
+        if command.has_page() {
+            pages.write().push(command);
+            query.set(String::new());
+            active.set(0);
+        } else {
+            registry.close();
+            registry.run(&command);
+        }
+    };
+
+    let key_commands = commands.clone();
+    let key_flat = flat.clone();
+    let key_option_id = option_id.clone();
+    let onkeydown = move |evt: KeyboardEvent| {
+        let step = |delta: isize| {
+            if count == 0 {
+                return None;
+            }
+            Some((current as isize + delta).rem_euclid(count as isize) as usize)
+        };
+        match evt.key() {
+            Key::ArrowDown | Key::ArrowUp => {
+                evt.prevent_default();
+                let delta = if evt.key() == Key::ArrowDown { 1 } else { -1 };
+                if let Some(next) = step(delta) {
+                    active.set(next);
+                    let id = key_option_id(next);
+                    document::eval(&format!(
+                        "document.getElementById('{id}')?.scrollIntoView({{block:'nearest'}})"
+                    ));
+                }
+            }
+            Key::Enter => {
+                evt.prevent_default();
+                if let Some(&index) = key_flat.get(current) {
+                    choose(key_commands[index].clone());
+                }
+            }
+            Key::Backspace if query.peek().is_empty() && !pages.peek().is_empty() => {
+                evt.prevent_default();
+                pages.write().pop();
+                active.set(0);
+            }
+            // Back out of a page before the portal closes the palette.
+            Key::Escape if !pages.peek().is_empty() => {
+                evt.prevent_default();
+                evt.stop_propagation();
+                pages.write().pop();
+                query.set(String::new());
+                active.set(0);
+            }
+            _ => {}
+        }
+    };
+
+    let escape_hint = if pages.read().is_empty() { "close" } else { "back" };
+
+    // Flat position of each section's first item.
+    let mut offset = 0;
+    let rendered: Vec<(Section, usize)> = groups
+        .into_iter()
+        .map(|g| {
+            let start = offset;
+            offset += g.items.len();
+            (g, start)
+        })
+        .collect();
+
+    rsx! {
+        div { class: "{s::SEARCH_ROW}",
+            for page in pages.read().iter() {
+                span { class: "{s::CRUMB}", "{page.title}" }
+            }
+            input {
+                class: "{s::INPUT}",
+                r#type: "text",
+                role: "combobox",
+                "aria-expanded": "true",
+                "aria-controls": "{list_id}",
+                "aria-autocomplete": "list",
+                "aria-activedescendant": "{active_id}",
+                "aria-label": "Search commands",
+                autocomplete: "off",
+                spellcheck: "false",
+                placeholder: "{placeholder}",
+                value: "{q}",
+                oninput: move |e| {
+                    query.set(e.value());
+                    active.set(0);
+                },
+                onkeydown,
+            }
+        }
+
+        div {
+            id: "{list_id}",
+            class: "{s::LIST}",
+            role: "listbox",
+            "aria-label": "Commands",
+
+            if count == 0 {
+                div { class: "{s::EMPTY}",
+                    if browsing { "No commands" } else { "No results for \u{201c}{q}\u{201d}" }
+                }
+            }
+
+            for (section, start) in rendered {
+                div {
+                    key: "{section.label}",
+                    role: "group",
+                    "aria-label": if !section.label.is_empty() { "{section.label}" },
+                    if !section.label.is_empty() {
+                        div { class: "{s::GROUP_LABEL}", "aria-hidden": "true", "{section.label}" }
+                    }
+                    for (i, ranked) in section.items.into_iter().enumerate() {
+                        PaletteOption {
+                            key: "{commands[ranked.index].id}",
+                            id: option_id(start + i),
+                            command: commands[ranked.index].clone(),
+                            positions: ranked.positions,
+                            active: start + i == current,
+                            show_group: !browsing,
+                            platform,
+                            on_hover: move |_| active.set(start + i),
+                            on_choose: move |command: Command| choose(command),
+                        }
+                    }
+                }
+            }
+        }
+
+        div { class: "{s::FOOTER}",
+            span { "\u{2191}\u{2193} navigate" }
+            span { "\u{21b5} select" }
+            span { "esc {escape_hint}" }
+        }
+    }
+}
+
+/// One result row.
+#[component]
+fn PaletteOption(
+    id: String,
+    command: Command,
+    positions: Vec<usize>,
+    active: bool,
+    show_group: bool,
+    platform: Platform,
+    on_hover: EventHandler<()>,
+    on_choose: EventHandler<Command>,
+) -> Element {
+    let row_cls = if active { format!("{} {}", s::OPTION, s::OPTION_ACTIVE) } else { s::OPTION.to_string() };
+    let segments = highlight_segments(&command.title, &positions);
+    let shortcut = command.chord().map(|chord| chord.label(platform));
+    let has_page = command.has_page();
+    let group = command.group.clone();
+
+    rsx! {
+        div {
+            id: "{id}",
+            class: "{row_cls}",
+            role: "option",
+            "aria-selected": if active { "true" } else { "false" },
+            onmousemove: move |_| {
+                if !active {
+                    on_hover.call(());
+                }
+            },
+            // Keep focus in the input.
+            onmousedown: move |evt| evt.prevent_default(),
+            onclick: move |_| on_choose.call(command.clone()),
+
+            span { class: "{s::OPTION_TITLE}",
+                for (text, hit) in segments {
+                    if hit {
+                        span { class: "{s::MATCH}", "{text}" }
+                    } else {
+                        "{text}"
+                    }
+                }
+            }
+            if show_group && !group.is_empty() {
+                span { class: "{s::OPTION_GROUP}", "{group}" }
+            }
+            if let Some(label) = shortcut {
+                kbd { class: "{s::SHORTCUT}", "{label}" }
+            }
+            if has_page {
+                span { class: "{s::OPTION_CHEVRON}", "aria-hidden": "true", "\u{203a}" }
+            }
+        }
+    }
+}
+
+// ── Interactive demo ───────────────────────────────────────────────
+
+/// Registers the demo commands; rendered inside the palette.
+#[cfg(feature = "playground")]
+#[component]
+fn DemoCommands() -> Element {
+    let mut count = use_signal(|| 0);
+    let mut last = use_signal(|| "(none)".to_string());
+    let theme = try_use_context::<Signal<EqTheme>>();
+    let registry = use_command_registry();
+    let mut help = use_signal(|| false);
+    let shortcuts = use_shortcuts();
+    use_shortcut_with("?", ShortcutOptions::new("Toggle shortcut help"), move |_| help.toggle());
+
+    use_commands(move || {
+        let mut commands = vec![
+            Command::new("new", "New file")
+                .group("File")
+                .shortcut("mod+alt+n")
+                .on_run(move |_| last.set("New file".into())),
+            Command::new("save", "Save")
+                .group("File")
+                .keywords(["write", "store"])
+                .shortcut("mod+s")
+                .on_run(move |_| last.set("Save".into())),
+            Command::new("increment", format!("Increment counter ({})", count()))
+                .group("Demo")
+                .keywords(["add", "plus"])
+                .shortcut("alt+i")
+                .on_run(move |_| count += 1),
+            Command::new("reset", "Reset counter")
+                .group("Demo")
+                .on_run(move |_| count.set(0)),
+        ];
+        if let Some(mut theme) = theme {
+            let variants = EqTheme::build_in_variants()
+                .into_iter()
+                .map(|(name, variant)| {
+                    Command::new(format!("theme-{name}"), name)
+                        .on_run(move |_| theme.set(variant.clone()))
+                })
+                .collect();
+            commands.push(
+                Command::new("theme", "Change theme…")
+                    .group("Appearance")
+                    .keywords(["color", "dark", "light"])
+                    .page(variants),
+            );
+        }
+        commands
+    });
+
+    rsx! {
+        div { class: "flex items-center gap-4",
+            EqButton {
+                on_click: move |_| {
+                    if let Some(registry) = registry {
+                        registry.open();
+                    }
+                },
+                "Open palette"
+            }
+            EqText { variant: TextVariant::Muted, "Counter: {count} · Last run: {last}" }
+        }
+        if help() {
+            div { class: "space-y-1",
+                for binding in shortcuts.bindings() {
+                    div { key: "{binding.id}", class: "flex items-center gap-3",
+                        kbd { class: "{s::SHORTCUT}", "{binding.chord.label(shortcuts.platform())}" }
+                        EqText { "{binding.description}" }
+                        EqText { variant: TextVariant::Muted, "{scope_note(&binding.scope)}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "playground")]
+#[component]
+fn DemoEqCommandPalette() -> Element {
+    let mut shortcut = use_signal(|| "mod+k".to_string());
+
+    let hint = Chord::parse(&shortcut())
+        .map(|chord| chord.label(use_shortcuts().platform()))
+        .unwrap_or_else(|| "(disabled)".to_string());
+
+    let code = r#"EqCommandPalette {
+    shortcut: "mod+k",
+    App {}
+}
+
+#[component]
+fn App() -> Element {
+    let mut count = use_signal(|| 0);
+    let mut theme = EqTheme::use_theme();
+
+    use_commands(move || vec![
+        Command::new("increment", format!("Increment counter ({})", count()))
+            .group("Demo")
+            .shortcut("alt+i")
+            .on_run(move |_| count += 1),
+        Command::new("theme", "Change theme…").page(
+            EqTheme::build_in_variants()
+                .into_iter()
+                .map(|(name, variant)| {
+                    Command::new(name, name).on_run(move |_| theme.set(variant.clone()))
+                })
+                .collect(),
+        ),
+    ]);
+
+    rsx! { /* … */ }
+}"#
+    .to_string();
+
+    rsx! {
+        DemoSection { title: "EqCommandPalette",
+            div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-3",
+                EqText {
+                    variant: TextVariant::Caption,
+                    class: "font-semibold uppercase tracking-wider",
+                    "Props"
+                }
+                PropInput {
+                    label: "shortcut",
+                    value: shortcut(),
+                    placeholder: "mod+k",
+                    onchange: move |v: String| shortcut.set(v),
+                }
+            }
+
+            div { class: "rounded-lg border border-dashed border-[var(--color-card-border)] p-6 space-y-3",
+                EqText { "Press {hint} or use the button. Try \u{201c}theme\u{201d} for a nested page, or ? to list shortcuts." }
+                EqCommandPalette {
+                    shortcut: shortcut(),
+                    DemoCommands {}
+                }
+            }
+
+            StyleInfo { file: "eq_command_palette/styles.rs", styles: format_catalog(&s::catalog()) }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
+        }
+    }
+}
+
+// ── Gallery ───────────────────────────────────────────────────────
+
+#[cfg(feature = "playground")]
+fn scope_note(scope: &ShortcutScope) -> String {
+    match scope {
+        ShortcutScope::Global => "everywhere".to_string(),
+        ShortcutScope::Layer => "in its layer".to_string(),
+        ShortcutScope::Focus(id) => format!("inside #{id}"),
+    }
+}
+
+#[cfg(feature = "playground")]
+#[component]
+fn GalleryEqCommandPalette() -> Element {
+    let shortcuts = use_shortcuts();
+    let platform = shortcuts.platform();
+    let bindings = shortcuts.bindings();
+
+    rsx! {
+        div { class: "space-y-4",
+            div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-4",
+                EqText { variant: TextVariant::Caption, class: "font-semibold uppercase tracking-wider", "Command Palette Gallery" }
+                EqText { variant: TextVariant::Muted,
+                    "The palette is an overlay; open the Demo tab to try it. Shortcuts currently bound app-wide:"
+                }
+                if bindings.is_empty() {
+                    EqText { variant: TextVariant::Muted, "(none)" }
+                }
+                div { class: "space-y-1",
+                    for binding in bindings {
+                        div { key: "{binding.id}", class: "flex items-center gap-3",
+                            kbd { class: "{s::SHORTCUT}", "{binding.chord.label(platform)}" }
+                            EqText { "{binding.description}" }
+                            EqText { variant: TextVariant::Muted, "{scope_note(&binding.scope)}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── Smoke tests ─────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::command::use_commands;
+
+    fn ids(commands: &[Command], sections: &[Section]) -> Vec<(String, Vec<String>)> {
+        sections
+            .iter()
+            .map(|s| (s.label.clone(), s.items.iter().map(|r| commands[r.index].id.clone()).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn browsing_groups_with_recent_first() {
+        let commands = vec![
+            Command::new("new", "New").group("File"),
+            Command::new("dark", "Dark").group("Theme"),
+            Command::new("save", "Save").group("File"),
+            Command::new("about", "About"),
+        ];
+        let recent = vec!["save".to_string()];
+        let out = sections(&commands, rank(&commands, "", &recent), &recent, true);
+        let expected = vec![
+            ("Recent".to_string(), vec!["save".to_string()]),
+            ("File".to_string(), vec!["new".to_string()]),
+            ("Theme".to_string(), vec!["dark".to_string()]),
+            (String::new(), vec!["about".to_string()]),
+        ];
+        assert_eq!(ids(&commands, &out), expected);
+    }
+
+    #[test]
+    fn searching_keeps_rank_order() {
+        let commands = vec![
+            Command::new("new", "New file").group("File"),
+            Command::new("find", "Find in files").group("Search"),
+        ];
+        let out = sections(&commands, rank(&commands, "fi", &[]), &[], false);
+        assert_eq!(out.len(), 1);
+        assert!(out[0].label.is_empty());
+        assert_eq!(out[0].items.len(), 2);
+    }
+
+    #[test]
+    fn smoke_renders_closed() {
+        #[component]
+        fn Commands() -> Element {
+            use_commands(|| vec![Command::new("a", "Alpha").shortcut("mod+alt+a").on_run(|_| {})]);
+            rsx! { "app" }
+        }
+
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                crate::overlay::EqOverlayRoot {
+                    EqCommandPalette { Commands {} }
+                }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn smoke_renders_body() {
+        let mut dom = VirtualDom::new(|| {
+            let registry = use_hook(|| CommandRegistry::new(Signal::new(true)));
+            rsx! {
+                PaletteBody { registry, placeholder: "Search", base_id: "p" }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+}
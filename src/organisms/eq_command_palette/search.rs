@@ -0,0 +1,244 @@
+//! Fuzzy matching and ranking for the command palette.
+//!
+//! A query matches when its characters appear in order in the text,
+//! case-insensitively. Matches score higher at word starts and in
+//! consecutive runs, and lower across gaps. Recently used commands
+//! get a boost.
+
+use super::command::Command;
+
+/// Score for each matched character.
+const MATCH: i32 = 1;
+/// Bonus for a match at the start of a word.
+const WORD_START: i32 = 8;
+/// Bonus for a match directly after the previous one.
+const CONSECUTIVE: i32 = 5;
+/// Penalty per skipped character between matches, capped.
+const GAP: i32 = 1;
+const MAX_GAP_PENALTY: i32 = 3;
+/// Keywords and groups are matched too, but rank below the title.
+const KEYWORD_PENALTY: i32 = 2;
+const GROUP_PENALTY: i32 = 4;
+/// Boost for the most recently used command; each older entry gets
+/// two points less.
+const RECENT_BOOST: i32 = 12;
+
+/// Result of matching a query against one string.
+#[derive(Clone, PartialEq, Debug)]
+pub struct FuzzyMatch {
+    pub score: i32,
+    /// Char indices of the matched characters in the text.
+    pub positions: Vec<usize>,
+}
+
+fn is_word_start(chars: &[char], i: usize) -> bool {
+    if i == 0 {
+        return true;
+    }
+    let (prev, cur) = (chars[i - 1], chars[i]);
+    !prev.is_alphanumeric() || (prev.is_lowercase() && cur.is_uppercase())
+}
+
+/// Best-scoring way to match `query` as a subsequence of `text`, or
+/// `None` when it does not match. Whitespace in the query is ignored.
+pub fn fuzzy_match(query: &str, text: &str) -> Option<FuzzyMatch> {
+    let query: Vec<char> = query.chars().filter(|c| !c.is_whitespace()).flat_map(char::to_lowercase).collect();
+    let chars: Vec<char> = text.chars().collect();
+    if query.is_empty() {
+        return Some(FuzzyMatch { score: 0, positions: Vec::new() });
+    }
+    let lower: Vec<char> = chars.iter().map(|c| c.to_lowercase().next().unwrap_or(*c)).collect();
+    let n = chars.len();
+
+    // best[i][j]: best score with query[i] matched at text[j], and the
+    // text index query[i - 1] was matched at.
+    let mut best: Vec<Vec<Option<(i32, usize)>>> = vec![vec![None; n]; query.len()];
+    for (i, &q) in query.iter().enumerate() {
+        for j in 0..n {
+            if lower[j] != q {
+                continue;
+            }
+            let word_start = is_word_start(&chars, j);
+            let bonus = MATCH + if word_start { WORD_START } else { 0 };
+            if i == 0 {
+                // Starting mid-word costs like a gap; starting a later
+                // word does not.
+                let lead = if word_start { 0 } else { (j as i32 * GAP).min(MAX_GAP_PENALTY) };
+                best[i][j] = Some((bonus - lead, 0));
+                continue;
+            }
+            best[i][j] = (0..j)
+                .filter_map(|k| best[i - 1][k].map(|(score, _)| (score, k)))
+                .map(|(score, k)| {
+                    let link = if k + 1 == j {
+                        CONSECUTIVE
+                    } else {
+                        -((j - k - 1) as i32 * GAP).min(MAX_GAP_PENALTY)
+                    };
+                    (score + bonus + link, k)
+                })
+                .max_by_key(|&(score, k)| (score, std::cmp::Reverse(k)));
+        }
+    }
+
+    let last = query.len() - 1;
+    let (mut j, (score, _)) = (0..n)
+        .filter_map(|j| best[last][j].map(|b| (j, b)))
+        .max_by_key(|&(j, (score, _))| (score, std::cmp::Reverse(j)))?;
+    let mut positions = vec![0; query.len()];
+    for i in (0..query.len()).rev() {
+        positions[i] = j;
+        if let Some((_, prev)) = best[i][j] {
+            j = prev;
+        }
+    }
+    Some(FuzzyMatch { score, positions })
+}
+
+/// A command that matched, by index into the searched slice.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Ranked {
+    pub index: usize,
+    pub score: i32,
+    /// Matched char indices in the title (empty when the match came
+    /// from a keyword or the group).
+    pub positions: Vec<usize>,
+}
+
+fn recent_boost(recent: &[String], id: &str) -> i32 {
+    recent
+        .iter()
+        .position(|r| r == id)
+        .map_or(0, |p| (RECENT_BOOST - 2 * p as i32).max(0))
+}
+
+/// Ranks `commands` against `query`.
+///
+/// With an empty query, recently used commands come first (most recent
+/// first), then the rest in their original order. Otherwise only
+/// matching commands are returned, best first; ties keep the original
+/// order.
+pub fn rank(commands: &[Command], query: &str, recent: &[String]) -> Vec<Ranked> {
+    if query.trim().is_empty() {
+        let mut out: Vec<Ranked> = recent
+            .iter()
+            .filter_map(|id| commands.iter().position(|c| &c.id == id))
+            .map(|index| Ranked { index, score: 0, positions: Vec::new() })
+            .collect();
+        for index in 0..commands.len() {
+            if !out.iter().any(|r| r.index == index) {
+                out.push(Ranked { index, score: 0, positions: Vec::new() });
+            }
+        }
+        return out;
+    }
+
+    let mut out: Vec<Ranked> = commands
+        .iter()
+        .enumerate()
+        .filter_map(|(index, command)| {
+            let title = fuzzy_match(query, &command.title).map(|m| (m.score, m.positions));
+            let keyword = command
+                .keywords
+                .iter()
+                .filter_map(|k| fuzzy_match(query, k))
+                .map(|m| (m.score - KEYWORD_PENALTY, Vec::new()))
+                .max_by_key(|(score, _)| *score);
+            let group = (!command.group.is_empty())
+                .then(|| fuzzy_match(query, &command.group))
+                .flatten()
+                .map(|m| (m.score - GROUP_PENALTY, Vec::new()));
+            let (score, positions) = [title, keyword, group]
+                .into_iter()
+                .flatten()
+                .reduce(|a, b| if b.0 > a.0 { b } else { a })?;
+            Some(Ranked { index, score: score + recent_boost(recent, &command.id), positions })
+        })
+        .collect();
+    out.sort_by(|a, b| b.score.cmp(&a.score).then(a.index.cmp(&b.index)));
+    out
+}
+
+/// Splits `text` into runs of `(segment, highlighted)` for rendering
+/// matched characters.
+pub fn highlight_segments(text: &str, positions: &[usize]) -> Vec<(String, bool)> {
+    let mut out: Vec<(String, bool)> = Vec::new();
+    for (i, c) in text.chars().enumerate() {
+        let hit = positions.contains(&i);
+        match out.last_mut() {
+            Some((segment, h)) if *h == hit => segment.push(c),
+            _ => out.push((c.to_string(), hit)),
+        }
+    }
+    out
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn titles(commands: &[Command], ranked: &[Ranked]) -> Vec<String> {
+        ranked.iter().map(|r| commands[r.index].title.clone()).collect()
+    }
+
+    #[test]
+    fn matches_subsequences_case_insensitively() {
+        assert!(fuzzy_match("ctm", "Change Theme").is_some());
+        assert!(fuzzy_match("CH TH", "change theme").is_some());
+        assert!(fuzzy_match("xyz", "Change Theme").is_none());
+        assert!(fuzzy_match("themee", "Theme").is_none());
+    }
+
+    #[test]
+    fn prefers_word_starts_and_runs() {
+        let m = fuzzy_match("ct", "Change Theme").unwrap();
+        assert_eq!(m.positions, vec![0, 7]);
+        let start = fuzzy_match("set", "Settings").unwrap().score;
+        let middle = fuzzy_match("set", "Reset view").unwrap().score;
+        assert!(start > middle);
+        let camel = fuzzy_match("fp", "openFilePicker").unwrap();
+        assert_eq!(camel.positions, vec![4, 8]);
+    }
+
+    #[test]
+    fn ranks_titles_above_keywords() {
+        let commands = vec![
+            Command::new("a", "Toggle sidebar").keywords(["theme"]),
+            Command::new("b", "Change theme"),
+            Command::new("c", "Open file"),
+        ];
+        let ranked = rank(&commands, "theme", &[]);
+        assert_eq!(titles(&commands, &ranked), ["Change theme", "Toggle sidebar"]);
+    }
+
+    #[test]
+    fn recent_commands_are_boosted() {
+        let commands = vec![
+            Command::new("save", "Save file"),
+            Command::new("saveas", "Save as…"),
+            Command::new("print", "Print"),
+        ];
+        let recent = vec!["saveas".to_string()];
+        assert_eq!(titles(&commands, &rank(&commands, "sa", &recent))[0], "Save as…");
+        assert_eq!(titles(&commands, &rank(&commands, "", &recent)), ["Save as…", "Save file", "Print"]);
+    }
+
+    #[test]
+    fn groups_match_with_a_penalty() {
+        let commands = vec![Command::new("dark", "Dark").group("Theme")];
+        let ranked = rank(&commands, "theme", &[]);
+        assert_eq!(ranked.len(), 1);
+        assert!(ranked[0].positions.is_empty());
+    }
+
+    #[test]
+    fn segments_follow_positions() {
+        assert_eq!(
+            highlight_segments("Open file", &[0, 1, 5]),
+            vec![("Op".to_string(), true), ("en ".to_string(), false), ("f".to_string(), true), ("ile".to_string(), false)]
+        );
+        assert_eq!(highlight_segments("", &[]), vec![]);
+    }
+}
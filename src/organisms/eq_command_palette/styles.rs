@@ -0,0 +1,100 @@
+//! Style constants for EqCommandPalette.
+
+// ── Frame ───────────────────────────────────────────────────────────
+
+/// Full-viewport backdrop; the panel sits in the upper third.
+pub const BACKDROP: &str =
+    "fixed inset-0 z-50 flex items-start justify-center pt-[15vh] \
+     bg-[var(--color-surface-overlay)]";
+
+/// The palette panel.
+pub const PANEL: &str =
+    "w-full max-w-xl mx-4 rounded-xl overflow-hidden \
+     border border-[var(--color-card-border)] \
+     bg-[var(--color-card)] shadow-2xl shadow-black/40 \
+     flex flex-col max-h-[60vh]";
+
+// ── Search row ──────────────────────────────────────────────────────
+
+/// Row holding the page breadcrumb and the input.
+pub const SEARCH_ROW: &str =
+    "flex items-center gap-2 px-4 py-3 \
+     border-b border-[var(--color-card-border)]";
+
+/// Breadcrumb chip for an open page.
+pub const CRUMB: &str =
+    "shrink-0 px-2 py-0.5 rounded-md text-xs font-medium \
+     bg-[var(--color-accent-primary)]/15 text-[var(--color-label-primary)]";
+
+/// The search input.
+pub const INPUT: &str =
+    "flex-1 min-w-0 bg-transparent text-sm \
+     text-[var(--color-label-primary)] placeholder:text-[var(--color-label-secondary)] \
+     focus:outline-none";
+
+// ── Results ─────────────────────────────────────────────────────────
+
+/// Scrollable result list.
+pub const LIST: &str = "overflow-y-auto py-2";
+
+/// Group heading.
+pub const GROUP_LABEL: &str =
+    "px-4 pt-2 pb-1 text-xs font-semibold uppercase tracking-wider \
+     text-[var(--color-label-secondary)]";
+
+/// A result row.
+pub const OPTION: &str =
+    "flex items-center gap-3 px-4 py-2 text-sm cursor-pointer \
+     text-[var(--color-label-primary)]";
+
+/// The highlighted row.
+pub const OPTION_ACTIVE: &str = "bg-[var(--color-accent-primary)]/15";
+
+/// Row title.
+pub const OPTION_TITLE: &str = "flex-1 truncate";
+
+/// Matched characters in a title.
+pub const MATCH: &str = "font-semibold text-[var(--color-accent-primary)]";
+
+/// Group shown beside a ranked result.
+pub const OPTION_GROUP: &str = "text-xs text-[var(--color-label-secondary)]";
+
+/// Chevron on commands that open a page.
+pub const OPTION_CHEVRON: &str = "text-[var(--color-label-secondary)]";
+
+/// Shortcut hint.
+pub const SHORTCUT: &str =
+    "px-1.5 py-0.5 rounded border border-[var(--color-card-border)] \
+     text-xs text-[var(--color-label-secondary)]";
+
+/// Shown when nothing matches.
+pub const EMPTY: &str =
+    "px-4 py-8 text-center text-sm text-[var(--color-label-secondary)]";
+
+/// Footer with key hints.
+pub const FOOTER: &str =
+    "flex items-center gap-4 px-4 py-2 text-xs \
+     border-t border-[var(--color-card-border)] \
+     text-[var(--color-label-secondary)]";
+
+/// All style tokens for playground introspection.
+pub fn catalog() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("BACKDROP", BACKDROP),
+        ("PANEL", PANEL),
+        ("SEARCH_ROW", SEARCH_ROW),
+        ("CRUMB", CRUMB),
+        ("INPUT", INPUT),
+        ("LIST", LIST),
+        ("GROUP_LABEL", GROUP_LABEL),
+        ("OPTION", OPTION),
+        ("OPTION_ACTIVE", OPTION_ACTIVE),
+        ("OPTION_TITLE", OPTION_TITLE),
+        ("MATCH", MATCH),
+        ("OPTION_GROUP", OPTION_GROUP),
+        ("OPTION_CHEVRON", OPTION_CHEVRON),
+        ("SHORTCUT", SHORTCUT),
+        ("EMPTY", EMPTY),
+        ("FOOTER", FOOTER),
+    ]
+}
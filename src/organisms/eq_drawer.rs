@@ -4,6 +4,15 @@
 //! overlay. Supports four sides, four size presets, optional header/footer
 //! slots, close-on-backdrop, close-on-Escape, and WAI-ARIA dialog pattern.
 //!
+//! **Bottom sheet** — with `side: DrawerSide::Bottom` and `snap_points`,
+//! the drawer becomes a sheet that rests at each snap point (peek, half,
+//! full, …) and follows touch drags between them. A downward flick past
+//! the lowest point dismisses it. Drags that start in the scrollable body
+//! scroll it first and only move the sheet once it is scrolled to the top
+//! (or, going up, while the sheet is below its highest point). The
+//! gesture math lives in [`super::eq_drawer_sheet`]; the drag handle is a
+//! button that steps through the snap points for keyboard users.
+//!
 //! ```no_run
 //! use eq_ui::prelude::*;
 //! use eq_ui::organisms::{EqDrawer, DrawerSide};
@@ -25,16 +34,27 @@
 //! }
 //! ```
 
+use super::eq_drawer_sheet::{
+    Release, SnapPoint, VelocityTracker, drag_height, highest, next_snap, release,
+};
 use super::eq_drawer_styles as s;
 use crate::theme::merge_classes;
+use crate::overlay::{EqFocusGuard, EqPortal, FocusEdge, FocusTarget, use_focus_trap};
 use crate::{PlaygroundEnum, playground};
+use dioxus::core::Task;
+use dioxus::document;
 use dioxus::prelude::*;
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::rc::Rc;
 
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, PropSelect, PropToggle, StyleInfo, format_catalog,
+    DemoSection, PropSelect, PropToggle, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant, EqButton, ButtonVariant};
 #[cfg(feature = "playground")]
 use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
@@ -75,6 +95,96 @@ fn is_horizontal(side: DrawerSide) -> bool {
     matches!(side, DrawerSide::Left | DrawerSide::Right)
 }
 
+// ── Sheet gestures ────────────────────────────────────────────────
+
+/// Forwards vertical touch drags on the sheet. Decides synchronously
+/// whether a gesture drags the sheet (so it can `preventDefault`) and
+/// leaves every other gesture to native scrolling: inside the body
+/// (`data-eq-sheet-scroll`), a downward drag moves the sheet only when
+/// the body is scrolled to the top, an upward one only while the sheet
+/// is below its highest snap point (`data-eq-sheet-top`).
+const SHEET_JS: &str = r#"
+const id = await dioxus.recv();
+let panel = document.getElementById(id);
+for (let i = 0; !panel && i < 10; i++) {
+    await new Promise((r) => requestAnimationFrame(r));
+    panel = document.getElementById(id);
+}
+if (panel) {
+    let gesture = null;
+    const clientY = (e) => (e.touches.length ? e.touches[0] : e.changedTouches[0]).clientY;
+    const onStart = (e) => {
+        gesture = e.touches.length === 1
+            ? { y0: clientY(e), scroller: e.target.closest('[data-eq-sheet-scroll]'), decided: false, dragging: false }
+            : null;
+    };
+    const onMove = (e) => {
+        if (!gesture) return;
+        const y = clientY(e);
+        if (!gesture.decided) {
+            const dy = y - gesture.y0;
+            if (Math.abs(dy) < 4) return;
+            gesture.decided = true;
+            const top = panel.dataset.eqSheetTop === 'true';
+            gesture.dragging = !gesture.scroller
+                || (dy > 0 ? gesture.scroller.scrollTop <= 0 : !top);
+            if (!gesture.dragging) return;
+            dioxus.send({
+                kind: 'start', y: gesture.y0, t: e.timeStamp,
+                height: panel.getBoundingClientRect().height,
+                viewport: window.innerHeight,
+            });
+        }
+        if (!gesture.dragging) return;
+        e.preventDefault();
+        dioxus.send({ kind: 'move', y, t: e.timeStamp });
+    };
+    const onEnd = (e) => {
+        if (gesture && gesture.dragging) dioxus.send({ kind: 'end', y: clientY(e), t: e.timeStamp });
+        gesture = null;
+    };
+    panel.addEventListener('touchstart', onStart, { passive: true });
+    panel.addEventListener('touchmove', onMove, { passive: false });
+    panel.addEventListener('touchend', onEnd);
+    panel.addEventListener('touchcancel', onEnd);
+    await dioxus.recv();
+    panel.removeEventListener('touchstart', onStart);
+    panel.removeEventListener('touchmove', onMove);
+    panel.removeEventListener('touchend', onEnd);
+    panel.removeEventListener('touchcancel', onEnd);
+}
+"#;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum SampleKind {
+    Start,
+    Move,
+    End,
+}
+
+/// One message from [`SHEET_JS`]. `height` and `viewport` come with
+/// `start` only.
+#[derive(Deserialize)]
+struct SheetSample {
+    kind: SampleKind,
+    y: f64,
+    t: f64,
+    #[serde(default)]
+    height: f64,
+    #[serde(default)]
+    viewport: f64,
+}
+
+type SheetListener = Rc<RefCell<Option<(document::Eval, Task)>>>;
+
+fn stop_sheet_listener(listener: &SheetListener) {
+    if let Some((eval, task)) = listener.borrow_mut().take() {
+        let _ = eval.send(true);
+        task.cancel();
+    }
+}
+
 // ── Component ─────────────────────────────────────────────────────
 
 /// Slide-in drawer panel.
@@ -90,9 +200,11 @@ fn is_horizontal(side: DrawerSide) -> bool {
     category = Organism,
     description = "Slide-in panel from any screen edge with backdrop, \
                    four sides, four sizes, header/body/footer, \
-                   close-on-Escape, WAI-ARIA dialog.",
+                   close-on-Escape, WAI-ARIA dialog, and a bottom sheet \
+                   mode with snap points and swipe gestures.",
     examples = [
         ("Basic", "let mut open = use_signal(|| false);\n\nEqDrawer {\n    open: open(),\n    on_close: move |_| open.set(false),\n    title: \"Settings\",\n    body: rsx! { p { \"Content\" } },\n}"),
+        ("Bottom sheet", "EqDrawer {\n    open: open(),\n    on_close: move |_| open.set(false),\n    title: \"Nearby\",\n    side: DrawerSide::Bottom,\n    snap_points: vec![SnapPoint::PEEK, SnapPoint::HALF, SnapPoint::FULL],\n    default_snap: 1,\n    body: rsx! { PlaceList {} },\n}"),
         ("Left side", "EqDrawer {\n    open: open(),\n    on_close: move |_| open.set(false),\n    title: \"Navigation\",\n    side: DrawerSide::Left,\n    size: DrawerSize::Sm,\n    body: rsx! { p { \"Nav links\" } },\n}"),
     ],
     custom_demo,
@@ -128,6 +240,22 @@ pub fn EqDrawer(
     /// Show the close button in the header.
     #[props(default = true)]
     show_close: bool,
+    /// Element focused when the drawer opens. Defaults to the first
+    /// tabbable element; `FocusTarget::Container` focuses the panel.
+    #[props(default)]
+    initial_focus: FocusTarget,
+    /// Bottom sheet mode: heights the sheet rests at, e.g.
+    /// `vec![SnapPoint::PEEK, SnapPoint::HALF, SnapPoint::FULL]`. Only
+    /// used with `DrawerSide::Bottom`, where it replaces `size`.
+    #[props(default)]
+    snap_points: Vec<SnapPoint>,
+    /// Index into `snap_points` the sheet opens at.
+    #[props(default = 0)]
+    default_snap: usize,
+    /// Fired with the new index when the sheet settles on another snap
+    /// point.
+    #[props(default)]
+    on_snap_change: Option<EventHandler<usize>>,
     /// Optional class override on the panel element.
     #[props(into, default)]
     class: String,
@@ -138,6 +266,32 @@ pub fn EqDrawer(
         }
     };
 
+    // Bottom sheet state. `drag_px` is the live height while a finger
+    // is down; `viewport` is refreshed at the start of every drag.
+    let sheet = side == DrawerSide::Bottom && !snap_points.is_empty();
+    let mut snap = use_signal(|| default_snap);
+    let mut drag_px = use_signal(|| None::<f64>);
+    let mut viewport = use_signal(|| 800.0);
+    let snap_index = snap().min(snap_points.len().saturating_sub(1));
+    let heights: Vec<f64> = snap_points.iter().map(|p| p.resolve(viewport())).collect();
+    let at_top = highest(&heights) == Some(snap_index);
+
+    let mut settle = move |i: usize| {
+        if *snap.peek() != i {
+            snap.set(i);
+            if let Some(handler) = &on_snap_change {
+                handler.call(i);
+            }
+        }
+    };
+
+    // Every opening starts from the default snap point.
+    use_effect(use_reactive((&open, &default_snap), move |(open, default_snap)| {
+        if open && *snap.peek() != default_snap {
+            snap.set(default_snap);
+        }
+    }));
+
     // Backdrop state.
     let backdrop_state = if open { s::BACKDROP_OPEN } else { s::BACKDROP_CLOSED };
 
@@ -179,69 +333,165 @@ pub fn EqDrawer(
     );
 
     let has_title = !title.is_empty();
+    let trap = use_focus_trap(open, initial_focus);
+    let title_id = format!("{}-title", trap.id());
+    let on_escape = if close_on_escape { on_close } else { None };
+
+    // The eval and its task outlive any one effect run, and `use_drop`
+    // has to stop them after the drawer's signals are already gone.
+    let listener: SheetListener = use_hook(|| Rc::new(RefCell::new(None)));
+    {
+        let listener = listener.clone();
+        let panel_id = trap.id().to_string();
+        use_effect(use_reactive((&open, &sheet, &snap_points), move |(open, sheet, snap_points)| {
+            stop_sheet_listener(&listener);
+            drag_px.set(None);
+            if !(open && sheet) {
+                return;
+            }
+            let mut eval = document::eval(SHEET_JS);
+            let _ = eval.send(panel_id.clone());
+            let task = spawn(async move {
+                let mut start = (0.0, 0.0);
+                let mut tracker = VelocityTracker::default();
+                while let Ok(sample) = eval.recv::<SheetSample>().await {
+                    tracker.push(sample.t, sample.y);
+                    let heights: Vec<f64> =
+                        snap_points.iter().map(|p| p.resolve(*viewport.peek())).collect();
+                    let max = heights.iter().copied().fold(0.0, f64::max);
+                    match sample.kind {
+                        SampleKind::Start => {
+                            viewport.set(sample.viewport);
+                            start = (sample.height, sample.y);
+                            tracker = VelocityTracker::default();
+                            tracker.push(sample.t, sample.y);
+                            drag_px.set(Some(sample.height));
+                        }
+                        SampleKind::Move => {
+                            drag_px.set(Some(drag_height(start.0, start.1, sample.y, max)));
+                        }
+                        SampleKind::End => {
+                            let height = drag_px.peek().unwrap_or(start.0);
+                            drag_px.set(None);
+                            match release(&heights, height, tracker.velocity()) {
+                                Release::Snap(i) => settle(i),
+                                Release::Dismiss => close(),
+                            }
+                        }
+                    }
+                }
+            });
+            *listener.borrow_mut() = Some((eval, task));
+        }));
+    }
+    use_drop(move || stop_sheet_listener(&listener));
+
+    let (panel_cls, panel_style) = if sheet {
+        let motion = if drag_px().is_some() { s::SHEET_DRAGGING } else { s::SHEET_SETTLING };
+        let closed = if open { s::PANEL_OPEN } else { s::SIDE_BOTTOM_CLOSED };
+        let height = match drag_px() {
+            Some(px) => format!("{px}px"),
+            None => snap_points[snap_index].css(),
+        };
+        (
+            merge_classes(&format!("{} {} {}", s::SHEET_PANEL, motion, closed), &class),
+            format!("height: {height};"),
+        )
+    } else {
+        (panel_cls, String::new())
+    };
+    let body_cls = if sheet { format!("{} {}", s::BODY, s::SHEET_BODY) } else { s::BODY.to_string() };
+    let handle_target = next_snap(&heights, snap_index);
+    let handle_label = if at_top { "Collapse sheet" } else { "Expand sheet" };
 
     rsx! {
-        // Backdrop
-        div {
-            class: "{s::BACKDROP} {backdrop_state}",
-            onclick: move |_| {
-                if close_on_backdrop { close(); }
-            },
-            onkeydown: move |evt: KeyboardEvent| {
-                if close_on_escape && evt.key() == Key::Escape {
-                    close();
+        EqPortal {
+            active: open,
+            lock_scroll: true,
+            restore_focus: true,
+            on_escape,
+
+            // Backdrop - a sibling of the panel, so it opts out of the
+            // focus trap's inert background to keep close-on-click.
+            div {
+                class: "{s::BACKDROP} {backdrop_state}",
+                "data-eq-backdrop": "true",
+                onclick: move |_| {
+                    if close_on_backdrop { close(); }
+                },
+            }
+
+            EqFocusGuard { trap, edge: FocusEdge::Start }
+
+            // Panel
+            div {
+                id: trap.id(),
+                class: "{panel_cls}",
+                style: "{panel_style}",
+                "data-eq-sheet-top": if sheet { "{at_top}" },
+                role: "dialog",
+                "aria-modal": "true",
+                "aria-labelledby": if has_title { Some(title_id.clone()) } else { None },
+                tabindex: "-1",
+                onclick: move |evt| { evt.stop_propagation(); },
+
+                // Drag handle (bottom sheet only)
+                if sheet {
+                    button {
+                        class: "{s::SHEET_HANDLE}",
+                        "aria-label": "{handle_label}",
+                        onclick: move |_| settle(handle_target),
+                        span { class: "{s::SHEET_GRIP}", "aria-hidden": "true" }
+                    }
                 }
-            },
-        }
 
-        // Panel
-        div {
-            class: "{panel_cls}",
-            role: "dialog",
-            "aria-modal": "true",
-            "aria-labelledby": if has_title { Some("eq-drawer-title") } else { None },
-            onclick: move |evt| { evt.stop_propagation(); },
-
-            // Header
-            if has_title || show_close {
-                div { class: "{s::HEADER}",
-                    if has_title {
-                        h2 {
-                            id: "eq-drawer-title",
-                            class: "{s::HEADER_TITLE}",
-                            "{title}"
+                // Header
+                if has_title || show_close {
+                    div { class: "{s::HEADER}",
+                        if has_title {
+                            h2 {
+                                id: "{title_id}",
+                                class: "{s::HEADER_TITLE}",
+                                "{title}"
+                            }
                         }
-                    }
-                    if show_close {
-                        button {
-                            class: "{s::CLOSE_BUTTON}",
-                            "aria-label": "Close drawer",
-                            onclick: move |_| close(),
-                            svg {
-                                xmlns: "http://www.w3.org/2000/svg",
-                                fill: "none",
-                                view_box: "0 0 24 24",
-                                stroke_width: "2",
-                                stroke: "currentColor",
-                                width: "20",
-                                height: "20",
-                                "aria-hidden": "true",
-                                path { d: "M6 18 18 6M6 6l12 12" }
+                        if show_close {
+                            button {
+                                class: "{s::CLOSE_BUTTON}",
+                                "aria-label": "Close drawer",
+                                onclick: move |_| close(),
+                                svg {
+                                    xmlns: "http://www.w3.org/2000/svg",
+                                    fill: "none",
+                                    view_box: "0 0 24 24",
+                                    stroke_width: "2",
+                                    stroke: "currentColor",
+                                    width: "20",
+                                    height: "20",
+                                    "aria-hidden": "true",
+                                    path { d: "M6 18 18 6M6 6l12 12" }
+                                }
                             }
                         }
                     }
                 }
-            }
 
-            // Body
-            if let Some(body_content) = body {
-                div { class: "{s::BODY}", {body_content} }
-            }
+                // Body
+                if let Some(body_content) = body {
+                    div {
+                        class: "{body_cls}",
+                        "data-eq-sheet-scroll": if sheet { "true" },
+                        {body_content}
+                    }
+                }
 
-            // Footer
-            if let Some(footer_content) = footer {
-                div { class: "{s::FOOTER}", {footer_content} }
+                // Footer
+                if let Some(footer_content) = footer {
+                    div { class: "{s::FOOTER}", {footer_content} }
+                }
             }
+
+            EqFocusGuard { trap, edge: FocusEdge::End }
         }
     }
 }
@@ -256,6 +506,7 @@ fn DemoEqDrawer() -> Element {
     let mut size_str = use_signal(|| "Md".to_string());
     let mut close_on_backdrop = use_signal(|| true);
     let mut show_close = use_signal(|| true);
+    let mut sheet = use_signal(|| false);
 
     let side = match side_str().as_str() {
         "Left" => DrawerSide::Left,
@@ -283,7 +534,7 @@ EqDrawer {{
     side: DrawerSide::{side},
     size: DrawerSize::{size},
     close_on_backdrop: {cob},
-    show_close: {sc},
+    show_close: {sc},{snaps}
     body: rsx! {{ p {{ "Your content here." }} }},
     footer: rsx! {{
         EqButton {{ variant: ButtonVariant::Ghost, on_click: move |_| open.set(false), "Cancel" }}
@@ -294,7 +545,17 @@ EqDrawer {{
         size = size_str(),
         cob = close_on_backdrop(),
         sc = show_close(),
+        snaps = if sheet() && side == DrawerSide::Bottom {
+            "\n    snap_points: vec![SnapPoint::PEEK, SnapPoint::HALF, SnapPoint::FULL],\n    default_snap: 1,"
+        } else {
+            ""
+        },
     );
+    let snap_points = if sheet() {
+        vec![SnapPoint::PEEK, SnapPoint::HALF, SnapPoint::FULL]
+    } else {
+        Vec::new()
+    };
 
     rsx! {
         DemoSection { title: "EqDrawer",
@@ -326,6 +587,11 @@ EqDrawer {{
                     value: show_close(),
                     onchange: move |v: bool| show_close.set(v),
                 }
+                PropToggle {
+                    label: "snap_points (Bottom)",
+                    value: sheet(),
+                    onchange: move |v: bool| sheet.set(v),
+                }
             }
 
             div { class: "rounded-lg border border-dashed border-[var(--color-card-border)] p-6",
@@ -344,9 +610,14 @@ EqDrawer {{
                 size,
                 close_on_backdrop: close_on_backdrop(),
                 show_close: show_close(),
+                snap_points,
+                default_snap: 1,
                 body: rsx! {
                     div { class: "space-y-3",
                         EqText { variant: TextVariant::Body, "This is the drawer body content. It scrolls if the content exceeds the available height." }
+                        if sheet() && side == DrawerSide::Bottom {
+                            EqText { variant: TextVariant::Muted, "On a touch screen, drag the handle between peek, half and full; flick down from peek to dismiss. The handle also steps through the snap points when clicked." }
+                        }
                         EqText { variant: TextVariant::Muted, "You can put any content here — forms, navigation links, settings panels, detail views, etc." }
                         for i in 1..6 {
                             div { class: "rounded-md border border-[var(--color-card-border)] p-3",
@@ -369,7 +640,7 @@ EqDrawer {{
             }
 
             StyleInfo { file: "eq_drawer_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
@@ -383,6 +654,7 @@ fn GalleryEqDrawer() -> Element {
     let mut right_open = use_signal(|| false);
     let mut top_open = use_signal(|| false);
     let mut bottom_open = use_signal(|| false);
+    let mut sheet_open = use_signal(|| false);
 
     rsx! {
         div { class: "space-y-4",
@@ -411,6 +683,11 @@ fn GalleryEqDrawer() -> Element {
                         on_click: move |_| bottom_open.set(true),
                         "Bottom"
                     }
+                    EqButton {
+                        variant: ButtonVariant::Outline,
+                        on_click: move |_| sheet_open.set(true),
+                        "Bottom sheet"
+                    }
                 }
             }
 
@@ -462,6 +739,24 @@ fn GalleryEqDrawer() -> Element {
                     }
                 },
             }
+
+            EqDrawer {
+                open: sheet_open(),
+                on_close: move |_| sheet_open.set(false),
+                title: "Nearby",
+                side: DrawerSide::Bottom,
+                snap_points: vec![SnapPoint::PEEK, SnapPoint::HALF, SnapPoint::FULL],
+                show_close: false,
+                body: rsx! {
+                    div { class: "space-y-2",
+                        for i in 1..=20 {
+                            div { class: "rounded-md border border-[var(--color-card-border)] p-3",
+                                EqText { variant: TextVariant::Body, "Place {i}" }
+                            }
+                        }
+                    }
+                },
+            }
         }
     }
 }
@@ -478,6 +773,20 @@ mod tests {
         dom.rebuild_in_place();
     }
 
+    #[test]
+    fn smoke_renders_bottom_sheet() {
+        let mut dom = VirtualDom::new(|| rsx! {
+            EqDrawer {
+                open: true,
+                side: DrawerSide::Bottom,
+                snap_points: vec![SnapPoint::PEEK, SnapPoint::HALF],
+                default_snap: 5,
+                body: rsx! { "content" },
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
     #[test]
     fn default_side_is_right() {
         let s: DrawerSide = Default::default();
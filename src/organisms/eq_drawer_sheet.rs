@@ -0,0 +1,218 @@
+//! Bottom sheet geometry for [`EqDrawer`](super::EqDrawer).
+//!
+//! Snap point resolution, drag velocity and the release decision
+//! (settle on a snap point or dismiss) are plain Rust so they can be
+//! unit-tested; the drawer only feeds them touch samples.
+
+use std::collections::VecDeque;
+
+/// A height the bottom sheet can rest at.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SnapPoint {
+    /// Fixed height in CSS pixels.
+    Px(f64),
+    /// Fraction of the viewport height (`0.5` = half).
+    Fraction(f64),
+}
+
+impl SnapPoint {
+    /// Just the handle and header showing.
+    pub const PEEK: SnapPoint = SnapPoint::Px(120.0);
+    /// Half the viewport.
+    pub const HALF: SnapPoint = SnapPoint::Fraction(0.5);
+    /// Nearly full height, leaving a strip of the page visible.
+    pub const FULL: SnapPoint = SnapPoint::Fraction(0.92);
+
+    /// Height in pixels for a viewport `viewport` pixels tall.
+    pub fn resolve(self, viewport: f64) -> f64 {
+        match self {
+            SnapPoint::Px(px) => px.min(viewport),
+            SnapPoint::Fraction(f) => f.clamp(0.0, 1.0) * viewport,
+        }
+    }
+
+    /// CSS height, so a resting sheet needs no measurement.
+    pub(crate) fn css(self) -> String {
+        match self {
+            SnapPoint::Px(px) => format!("min({px}px, 100dvh)"),
+            SnapPoint::Fraction(f) => format!("{}dvh", (f.clamp(0.0, 1.0) * 10_000.0).round() / 100.0),
+        }
+    }
+}
+
+/// Downward or upward speed (px/ms) that counts as a flick.
+const FLICK_VELOCITY: f64 = 0.5;
+
+/// How far ahead a slow release is projected before picking the
+/// nearest snap point.
+const PROJECTION_MS: f64 = 150.0;
+
+/// Share of the drag past the highest snap point that still moves the
+/// sheet.
+const OVERDRAG: f64 = 0.2;
+
+/// Samples older than this are ignored for velocity.
+const VELOCITY_WINDOW_MS: f64 = 100.0;
+
+/// Where a released sheet goes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Release {
+    /// Settle on `snap_points[i]`.
+    Snap(usize),
+    /// Close the drawer.
+    Dismiss,
+}
+
+/// Recent pointer positions for velocity.
+#[derive(Default)]
+pub(crate) struct VelocityTracker {
+    samples: VecDeque<(f64, f64)>,
+}
+
+impl VelocityTracker {
+    /// Record `y` at time `t` (ms).
+    pub fn push(&mut self, t: f64, y: f64) {
+        self.samples.push_back((t, y));
+        while let Some(&(first, _)) = self.samples.front() {
+            if t - first > VELOCITY_WINDOW_MS {
+                self.samples.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Speed in px/ms over the window; positive is downward.
+    pub fn velocity(&self) -> f64 {
+        match (self.samples.front(), self.samples.back()) {
+            (Some(&(t0, y0)), Some(&(t1, y1))) if t1 > t0 => (y1 - y0) / (t1 - t0),
+            _ => 0.0,
+        }
+    }
+}
+
+/// Sheet height while dragging: follows the finger, with resistance
+/// past `max` and never below zero.
+pub(crate) fn drag_height(start_height: f64, start_y: f64, y: f64, max: f64) -> f64 {
+    let height = start_height + (start_y - y);
+    if height > max {
+        max + (height - max) * OVERDRAG
+    } else {
+        height.max(0.0)
+    }
+}
+
+/// Where the sheet goes when released at `height` moving at
+/// `velocity` (px/ms, positive downward). `heights` are the resolved
+/// snap points in any order.
+///
+/// A flick moves to the next snap point in its direction — or
+/// dismisses when flicked down past the lowest one. A slow release
+/// settles on the snap point nearest to where the motion was heading,
+/// unless the sheet was dragged below half of the lowest.
+pub(crate) fn release(heights: &[f64], height: f64, velocity: f64) -> Release {
+    let Some(lowest) = heights.iter().copied().reduce(f64::min) else {
+        return Release::Dismiss;
+    };
+    if velocity >= FLICK_VELOCITY {
+        return closest_by(heights, |h| h < height - 1.0, |h| -h).map_or(Release::Dismiss, Release::Snap);
+    }
+    if velocity <= -FLICK_VELOCITY {
+        let next = closest_by(heights, |h| h > height + 1.0, |h| h)
+            .or_else(|| closest_by(heights, |_| true, |h| -h));
+        return next.map_or(Release::Dismiss, Release::Snap);
+    }
+    if height < lowest / 2.0 {
+        return Release::Dismiss;
+    }
+    let projected = height - velocity * PROJECTION_MS;
+    closest_by(heights, |_| true, |h| (h - projected).abs()).map_or(Release::Dismiss, Release::Snap)
+}
+
+/// Index of the height passing `keep` with the smallest `key`.
+fn closest_by(heights: &[f64], keep: impl Fn(f64) -> bool, key: impl Fn(f64) -> f64) -> Option<usize> {
+    heights
+        .iter()
+        .enumerate()
+        .filter(|(_, h)| keep(**h))
+        .min_by(|(_, a), (_, b)| key(**a).total_cmp(&key(**b)))
+        .map(|(i, _)| i)
+}
+
+/// Index of the highest snap point.
+pub(crate) fn highest(heights: &[f64]) -> Option<usize> {
+    closest_by(heights, |_| true, |h| -h)
+}
+
+/// The snap point above `current`, wrapping to the lowest - what the
+/// drag handle moves to when activated without a drag.
+pub(crate) fn next_snap(heights: &[f64], current: usize) -> usize {
+    let height = heights.get(current).copied().unwrap_or(0.0);
+    closest_by(heights, |h| h > height, |h| h)
+        .or_else(|| closest_by(heights, |_| true, |h| h))
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Peek, half, full on an 800px viewport.
+    const HEIGHTS: [f64; 3] = [120.0, 400.0, 736.0];
+
+    #[test]
+    fn snap_points_resolve_against_the_viewport() {
+        assert_eq!(SnapPoint::HALF.resolve(800.0), 400.0);
+        assert_eq!(SnapPoint::Px(1_000.0).resolve(800.0), 800.0);
+        assert_eq!(SnapPoint::Fraction(1.5).resolve(800.0), 800.0);
+        assert_eq!(SnapPoint::HALF.css(), "50dvh");
+        assert_eq!(SnapPoint::FULL.css(), "92dvh");
+    }
+
+    #[test]
+    fn velocity_uses_recent_samples() {
+        let mut tracker = VelocityTracker::default();
+        assert_eq!(tracker.velocity(), 0.0);
+        tracker.push(0.0, 0.0);
+        tracker.push(500.0, 10.0);
+        tracker.push(550.0, 40.0);
+        tracker.push(600.0, 60.0);
+        // The sample at t=0 fell out of the window.
+        assert_eq!(tracker.velocity(), 0.5);
+    }
+
+    #[test]
+    fn drag_follows_the_finger_with_overdrag_resistance() {
+        assert_eq!(drag_height(400.0, 500.0, 600.0, 736.0), 300.0);
+        assert_eq!(drag_height(400.0, 500.0, 0.0, 736.0), 736.0 + (900.0 - 736.0) * OVERDRAG);
+        assert_eq!(drag_height(120.0, 500.0, 900.0, 736.0), 0.0);
+    }
+
+    #[test]
+    fn slow_release_settles_on_the_nearest_snap() {
+        assert_eq!(release(&HEIGHTS, 380.0, 0.0), Release::Snap(1));
+        assert_eq!(release(&HEIGHTS, 600.0, 0.0), Release::Snap(2));
+        // Moving slowly down from 300 projects past the midpoint to peek.
+        assert_eq!(release(&HEIGHTS, 300.0, 0.4), Release::Snap(0));
+        assert_eq!(release(&HEIGHTS, 50.0, 0.0), Release::Dismiss);
+    }
+
+    #[test]
+    fn flicks_step_one_snap_or_dismiss() {
+        assert_eq!(release(&HEIGHTS, 700.0, 1.2), Release::Snap(1));
+        assert_eq!(release(&HEIGHTS, 390.0, 0.8), Release::Snap(0));
+        assert_eq!(release(&HEIGHTS, 118.0, 0.8), Release::Dismiss);
+        assert_eq!(release(&HEIGHTS, 130.0, -0.9), Release::Snap(1));
+        assert_eq!(release(&HEIGHTS, 750.0, -0.9), Release::Snap(2));
+        assert_eq!(highest(&HEIGHTS), Some(2));
+    }
+
+    #[test]
+    fn handle_cycles_upward_and_wraps() {
+        assert_eq!(next_snap(&HEIGHTS, 0), 1);
+        assert_eq!(next_snap(&HEIGHTS, 1), 2);
+        assert_eq!(next_snap(&HEIGHTS, 2), 0);
+        // Order of the snap points does not matter.
+        assert_eq!(next_snap(&[400.0, 120.0], 1), 0);
+    }
+}
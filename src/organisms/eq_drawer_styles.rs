@@ -85,6 +85,35 @@ pub const FOOTER: &str =
     "flex items-center justify-end gap-2 px-5 py-4 shrink-0 \
      border-t border-[var(--color-card-border)]";
 
+// ── Bottom sheet (snap points) ────────────────────────────────────
+
+/// Sheet panel. Height comes from the active snap point.
+pub const SHEET_PANEL: &str =
+    "fixed z-50 flex flex-col inset-x-0 bottom-0 max-h-[100dvh] \
+     rounded-t-2xl border-t \
+     bg-[var(--color-card)] \
+     border-[var(--color-card-border)] \
+     shadow-2xl shadow-black/40";
+
+/// Sheet settling on a snap point (or sliding in / out).
+pub const SHEET_SETTLING: &str = "transition-[transform,height] duration-300 ease-out";
+
+/// Sheet following a finger.
+pub const SHEET_DRAGGING: &str = "transition-none";
+
+/// Drag handle - a button that also cycles snap points.
+pub const SHEET_HANDLE: &str =
+    "mx-auto mt-2 mb-1 flex h-6 w-16 shrink-0 items-center justify-center \
+     rounded-full cursor-grab touch-none \
+     focus:outline-none focus-visible:ring-2 focus-visible:ring-[var(--color-accent-primary)]/40";
+
+/// The visible grip bar inside the handle.
+pub const SHEET_GRIP: &str =
+    "block h-1.5 w-10 rounded-full bg-[var(--color-label-secondary)]/50";
+
+/// Added to the body so its scrolling does not chain to the page.
+pub const SHEET_BODY: &str = "overscroll-contain";
+
 /// All style tokens for playground introspection.
 pub fn catalog() -> Vec<(&'static str, &'static str)> {
     vec![
@@ -108,5 +137,11 @@ pub fn catalog() -> Vec<(&'static str, &'static str)> {
         ("CLOSE_BUTTON", CLOSE_BUTTON),
         ("BODY", BODY),
         ("FOOTER", FOOTER),
+        ("SHEET_PANEL", SHEET_PANEL),
+        ("SHEET_SETTLING", SHEET_SETTLING),
+        ("SHEET_DRAGGING", SHEET_DRAGGING),
+        ("SHEET_HANDLE", SHEET_HANDLE),
+        ("SHEET_GRIP", SHEET_GRIP),
+        ("SHEET_BODY", SHEET_BODY),
     ]
 }
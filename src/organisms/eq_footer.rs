@@ -4,7 +4,9 @@ use crate::theme::merge_classes;
 use crate::playground;
 
 #[cfg(feature = "playground")]
-use crate::playground::playground_helpers::{CodeBlock, DemoSection, PropInput, PropSelect, StyleInfo, format_catalog};
+use crate::playground::playground_helpers::{DemoSection, PropInput, PropSelect, StyleInfo, format_catalog};
+#[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
 #[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
 #[cfg(feature = "playground")]
@@ -164,7 +166,7 @@ fn DemoEqFooter() -> Element {
                 EqFooter { copyright_holder, year, tagline }
             }
             StyleInfo { file: "eq_footer_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
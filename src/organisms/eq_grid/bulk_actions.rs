@@ -7,6 +7,7 @@ use super::column_def::EqColumnDef;
 use super::export;
 use super::styles as s;
 use super::types::ExportFormat;
+use crate::molecules::{ConfirmOptions, Dialogs};
 use dioxus::prelude::*;
 use std::collections::HashSet;
 
@@ -14,7 +15,8 @@ use std::collections::HashSet;
 ///
 /// Only call this when `selected_count > 0`. The bar shows:
 /// - Selection count label
-/// - Delete button (if `on_delete` is provided)
+/// - Delete button (if `on_delete` is provided), confirmed through
+///   `dialogs` when given
 /// - Export dropdown (if `export` is enabled)
 /// - Clipboard button (if `on_clipboard` is provided)
 /// - Change Status dropdown (if `status_column` + `on_status_change` are provided)
@@ -28,6 +30,7 @@ pub(super) fn render_bulk_actions<T: Clone + PartialEq + 'static>(
     selected_count: usize,
     // Action callbacks
     on_delete: &Option<EventHandler<Vec<usize>>>,
+    dialogs: Option<Dialogs>,
     export_enabled: bool,
     on_export: &Option<EventHandler<(ExportFormat, Vec<u8>)>>,
     on_clipboard: &Option<EventHandler<String>>,
@@ -106,9 +109,28 @@ pub(super) fn render_bulk_actions<T: Clone + PartialEq + 'static>(
                         // re-render triggered by the consumer's data mutation
                         // sees an empty selection set.
                         let rows = del_indices.clone();
-                        selected_rows.write().clear();
-                        if let Some(ref handler) = del_handler {
-                            handler.call(rows);
+                        let Some(handler) = del_handler else { return };
+                        match dialogs {
+                            Some(dialogs) => {
+                                spawn(async move {
+                                    let confirmed = dialogs
+                                        .confirm(
+                                            ConfirmOptions::new(format!("Delete {} row(s)?", rows.len()))
+                                                .message("This cannot be undone.")
+                                                .confirm_label("Delete")
+                                                .danger(),
+                                        )
+                                        .await;
+                                    if confirmed {
+                                        selected_rows.write().clear();
+                                        handler.call(rows);
+                                    }
+                                });
+                            }
+                            None => {
+                                selected_rows.write().clear();
+                                handler.call(rows);
+                            }
                         }
                     },
                     "Delete"
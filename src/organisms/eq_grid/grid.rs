@@ -14,15 +14,20 @@ use super::styles as s;
 use super::types::{ExportFormat, GridDensity, GridDragPayload, GridNavigation, ResizeState, RowSelection, SortDirection, SortState};
 use crate::atoms::eq_icon_paths;
 use crate::atoms::EqIcon;
+use crate::molecules::Dialogs;
 use crate::theme::merge_classes;
 use dioxus::prelude::*;
 use std::collections::{HashMap, HashSet};
 
 #[cfg(feature = "playground")]
-use crate::playground::playground_helpers::{CodeBlock, DemoSection, PropSelect, PropToggle, StyleInfo, format_catalog};
+use crate::playground::playground_helpers::{DemoSection, PropSelect, PropToggle, StyleInfo, format_catalog};
+#[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
 #[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant, EqTab, TabItem, TabVariant};
 #[cfg(feature = "playground")]
+use crate::molecules::EqDialogHost;
+#[cfg(feature = "playground")]
 use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
 
 /// Feature-rich data grid organism.
@@ -76,6 +81,11 @@ pub fn EqGrid<T: Clone + PartialEq + 'static>(
     /// appears in the action bar. Receives sorted indices of selected rows.
     #[props(default)]
     on_delete: Option<EventHandler<Vec<usize>>>,
+    /// Ask "Delete N rows?" before firing `on_delete`. Needs an
+    /// [`EqDialogHost`](crate::molecules::EqDialogHost) above the grid;
+    /// without one the rows are deleted straight away.
+    #[props(default = false)]
+    confirm_delete: bool,
     /// Enable the Export dropdown in the bulk action bar.
     #[props(default = false)]
     export: bool,
@@ -160,6 +170,8 @@ pub fn EqGrid<T: Clone + PartialEq + 'static>(
     // The consumer must wrap both grids in a context provider:
     //   use_context_provider(|| Signal::new(Option::<GridDragPayload>::None));
     let drag_ctx: Option<Signal<Option<GridDragPayload>>> = try_consume_context();
+    // Confirmation for bulk delete (`confirm_delete`).
+    let dialogs: Option<Dialogs> = try_consume_context();
     let mut drop_hover = use_signal(|| false);
     // Reorder state - grip handles and drag logic.
     let reorder_from: Signal<Option<usize>> = use_signal(|| None);
@@ -636,6 +648,7 @@ pub fn EqGrid<T: Clone + PartialEq + 'static>(
                         selected_rows,
                         count,
                         &on_delete,
+                        confirm_delete.then_some(dialogs).flatten(),
                         export,
                         &on_export,
                         &on_clipboard,
@@ -992,7 +1005,7 @@ fn DemoEqGridDragDrop() -> Element {
                 }
             }
 
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
@@ -1075,7 +1088,7 @@ fn DemoEqGridReorder() -> Element {
                 },
             }
 
-            CodeBlock { code: code.to_string() }
+            EqCodeBlock { code: code.to_string(), language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
@@ -1249,85 +1262,89 @@ fn DemoEqGrid() -> Element {
                 }
             }
 
-            EqGrid {
-                data: employees(),
-                columns: demo_columns(),
-                navigation: navigation,
-                page_size: page_size,
-                row_selection: selection,
-                density: density,
-                striped: striped(),
-                column_borders: col_borders(),
-                quick_filter: quick_filter(),
-                reorderable: reorderable(),
-                on_reorder: move |(from, to): (usize, usize)| {
-                    let mut data = employees.write();
-                    let row = data.remove(from);
-                    data.insert(to, row);
-                    for (i, e) in data.iter_mut().enumerate() {
-                        e.index = i + 1;
-                    }
-                },
-                on_selection_change: move |rows: Vec<usize>| {
-                    selection_count.set(rows.len());
-                },
-                // Bulk actions - these actually mutate the data signal
-                on_delete: move |rows: Vec<usize>| {
-                    let count = rows.len();
-                    let mut data = employees.write();
-                    // Remove in reverse order so indices stay valid.
-                    for &idx in rows.iter().rev() {
-                        if idx < data.len() {
-                            data.remove(idx);
+            // Hosts the bulk-delete confirmation.
+            EqDialogHost {
+                EqGrid {
+                    data: employees(),
+                    columns: demo_columns(),
+                    navigation: navigation,
+                    page_size: page_size,
+                    row_selection: selection,
+                    density: density,
+                    striped: striped(),
+                    column_borders: col_borders(),
+                    quick_filter: quick_filter(),
+                    reorderable: reorderable(),
+                    on_reorder: move |(from, to): (usize, usize)| {
+                        let mut data = employees.write();
+                        let row = data.remove(from);
+                        data.insert(to, row);
+                        for (i, e) in data.iter_mut().enumerate() {
+                            e.index = i + 1;
                         }
-                    }
-                    drop(data);
-                    selection_count.set(0);
-                    bulk_status.set(format!("Deleted {} row(s)", count));
-                },
-                export: true,
-                on_export: move |payload: (ExportFormat, Vec<u8>)| {
-                    let (fmt, bytes) = payload;
-                    let label = match fmt {
-                        ExportFormat::Csv => "CSV",
-                        ExportFormat::Json => "JSON",
-                        ExportFormat::Txt => "TXT",
-                        ExportFormat::Ods => "ODS",
-                    };
-                    // Show text content for text formats, byte count for binary.
-                    let preview = match fmt {
-                        ExportFormat::Ods => format!("[Binary ODS: {} bytes]", bytes.len()),
-                        _ => String::from_utf8(bytes.clone()).unwrap_or_else(|_| format!("[{} bytes]", bytes.len())),
-                    };
-                    clipboard_preview.set(String::new());
-                    export_preview.set(preview);
-                    bulk_status.set(format!("Exported {} ({} bytes)", label, bytes.len()));
-                },
-                on_clipboard: move |content: String| {
-                    let len = content.len();
-                    export_preview.set(String::new());
-                    clipboard_preview.set(content.clone());
-                    // Write to the system clipboard via document::eval (cross-platform).
-                    let escaped = content.replace('\\', "\\\\").replace('`', "\\`");
-                    let js = format!("navigator.clipboard.writeText(`{}`)", escaped);
-                    let _ = document::eval(&js);
-                    bulk_status.set(format!("Copied to clipboard ({} chars)", len));
-                },
-                status_column: "status",
-                status_options: vec!["Active".into(), "Inactive".into(), "On Leave".into()],
-                on_status_change: move |payload: (Vec<usize>, String)| {
-                    let (rows, new_status) = payload;
-                    let count = rows.len();
-                    let mut data = employees.write();
-                    for &idx in &rows {
-                        if idx < data.len() {
-                            data[idx].status = new_status.clone();
+                    },
+                    on_selection_change: move |rows: Vec<usize>| {
+                        selection_count.set(rows.len());
+                    },
+                    // Bulk actions - these actually mutate the data signal
+                    confirm_delete: true,
+                    on_delete: move |rows: Vec<usize>| {
+                        let count = rows.len();
+                        let mut data = employees.write();
+                        // Remove in reverse order so indices stay valid.
+                        for &idx in rows.iter().rev() {
+                            if idx < data.len() {
+                                data.remove(idx);
+                            }
                         }
-                    }
-                    drop(data);
-                    bulk_status.set(format!("Changed {} row(s) to '{}'", count, new_status));
-                },
-                aggregation_columns: vec!["salary"],
+                        drop(data);
+                        selection_count.set(0);
+                        bulk_status.set(format!("Deleted {} row(s)", count));
+                    },
+                    export: true,
+                    on_export: move |payload: (ExportFormat, Vec<u8>)| {
+                        let (fmt, bytes) = payload;
+                        let label = match fmt {
+                            ExportFormat::Csv => "CSV",
+                            ExportFormat::Json => "JSON",
+                            ExportFormat::Txt => "TXT",
+                            ExportFormat::Ods => "ODS",
+                        };
+                        // Show text content for text formats, byte count for binary.
+                        let preview = match fmt {
+                            ExportFormat::Ods => format!("[Binary ODS: {} bytes]", bytes.len()),
+                            _ => String::from_utf8(bytes.clone()).unwrap_or_else(|_| format!("[{} bytes]", bytes.len())),
+                        };
+                        clipboard_preview.set(String::new());
+                        export_preview.set(preview);
+                        bulk_status.set(format!("Exported {} ({} bytes)", label, bytes.len()));
+                    },
+                    on_clipboard: move |content: String| {
+                        let len = content.len();
+                        export_preview.set(String::new());
+                        clipboard_preview.set(content.clone());
+                        // Write to the system clipboard via document::eval (cross-platform).
+                        let escaped = content.replace('\\', "\\\\").replace('`', "\\`");
+                        let js = format!("navigator.clipboard.writeText(`{}`)", escaped);
+                        let _ = document::eval(&js);
+                        bulk_status.set(format!("Copied to clipboard ({} chars)", len));
+                    },
+                    status_column: "status",
+                    status_options: vec!["Active".into(), "Inactive".into(), "On Leave".into()],
+                    on_status_change: move |payload: (Vec<usize>, String)| {
+                        let (rows, new_status) = payload;
+                        let count = rows.len();
+                        let mut data = employees.write();
+                        for &idx in &rows {
+                            if idx < data.len() {
+                                data[idx].status = new_status.clone();
+                            }
+                        }
+                        drop(data);
+                        bulk_status.set(format!("Changed {} row(s) to '{}'", count, new_status));
+                    },
+                    aggregation_columns: vec!["salary"],
+                }
             }
 
             // Export preview
@@ -1365,7 +1382,7 @@ fn DemoEqGrid() -> Element {
             }
 
             StyleInfo { file: "eq_grid/styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
                 } // end _ => rsx!
             } // end match
         }
@@ -4,7 +4,9 @@ use crate::theme::{merge_classes, CONTAINER_LAYOUT};
 use crate::playground;
 
 #[cfg(feature = "playground")]
-use crate::playground::playground_helpers::{CodeBlock, DemoSection, PropSelect, StyleInfo, format_catalog};
+use crate::playground::playground_helpers::{DemoSection, PropSelect, StyleInfo, format_catalog};
+#[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
 #[cfg(feature = "playground")]
 use crate::atoms::EqText;
 #[cfg(feature = "playground")]
@@ -137,7 +139,7 @@ fn DemoEqHeader() -> Element {
                 }
             }
             StyleInfo { file: "eq_header_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
@@ -6,7 +6,9 @@ use crate::playground;
 #[cfg(feature = "playground")]
 use super::eq_hero_shell_styles as s;
 #[cfg(feature = "playground")]
-use crate::playground::playground_helpers::{CodeBlock, DemoSection, PropInput, PropToggle, StyleInfo, format_catalog};
+use crate::playground::playground_helpers::{DemoSection, PropInput, PropToggle, StyleInfo, format_catalog};
+#[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
 #[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
 #[cfg(feature = "playground")]
@@ -187,7 +189,7 @@ fn DemoEqHeroShell() -> Element {
                 }
             }
             StyleInfo { file: "eq_hero_shell_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
@@ -50,9 +50,11 @@ use crate::molecules::EqDeviceFrame;
 use crate::organisms::{EqToolbar, EqBottomNav, BottomNavItem, BottomNavBadge};
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, StyleInfo, format_catalog,
+    DemoSection, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::playground::playground_types::{
     ComponentCategory, ComponentDescriptor, UsageExample,
 };
@@ -195,7 +197,7 @@ EqMobileAppShell {
                 file: "eq_mobile_app_shell_styles.rs",
                 styles: format_catalog(&s::catalog()),
             }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
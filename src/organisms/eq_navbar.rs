@@ -2,7 +2,9 @@ use dioxus::prelude::*;
 use crate::playground;
 
 #[cfg(feature = "playground")]
-use crate::playground::playground_helpers::{CodeBlock, DemoSection, StyleInfo, format_catalog};
+use crate::playground::playground_helpers::{DemoSection, StyleInfo, format_catalog};
+#[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
 #[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
 #[cfg(feature = "playground")]
@@ -58,7 +60,7 @@ fn DemoEqNavbar() -> Element {
             StyleInfo { file: "navbar.css", styles: format_catalog(&[
                 ("NAVBAR", "id=\"navbar\" - styled via navbar.css"),
             ]) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
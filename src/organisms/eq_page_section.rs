@@ -6,7 +6,9 @@ use crate::playground;
 #[cfg(feature = "playground")]
 use super::eq_page_section_styles as s;
 #[cfg(feature = "playground")]
-use crate::playground::playground_helpers::{CodeBlock, DemoSection, PropInput, StyleInfo, format_catalog};
+use crate::playground::playground_helpers::{DemoSection, PropInput, StyleInfo, format_catalog};
+#[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
 #[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
 #[cfg(feature = "playground")]
@@ -111,7 +113,7 @@ fn DemoEqPageSection() -> Element {
                 }
             }
             StyleInfo { file: "eq_page_section_styles.rs", styles: format_catalog(&s::catalog()) }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
@@ -0,0 +1,135 @@
+//! Editor commands and their keyboard shortcuts.
+//!
+//! The key → command mapping is a pure function so the shortcut table
+//! can be tested without a browser. `mod` means Ctrl on Windows/Linux
+//! and Cmd on macOS; both are accepted.
+
+use super::document::{BlockKind, Mark};
+
+/// An action the toolbar or a shortcut can trigger.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EditorCommand {
+    /// Toggle an inline mark on the selection.
+    ToggleMark(Mark),
+    /// Change the block type of the selected blocks.
+    SetBlock(BlockKind),
+    /// Open the link editor for the selection.
+    Link,
+    /// Undo the last edit.
+    Undo,
+    /// Redo the last undone edit.
+    Redo,
+}
+
+/// Modifier state of a key press.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct KeyModifiers {
+    pub ctrl: bool,
+    pub meta: bool,
+    pub shift: bool,
+    pub alt: bool,
+}
+
+/// Maps a key press to an editor command.
+///
+/// `key` is the `KeyboardEvent.key` string (e.g. `"b"`, `"Z"`); `code`
+/// is `KeyboardEvent.code` and is used for digits, which produce
+/// different `key` values with Alt/Option held on some layouts.
+///
+/// | Shortcut | Command |
+/// |---|---|
+/// | mod+B / mod+I / mod+U | bold / italic / underline |
+/// | mod+Shift+X | strikethrough |
+/// | mod+E | inline code |
+/// | mod+K | link |
+/// | mod+Z | undo |
+/// | mod+Shift+Z, mod+Y | redo |
+/// | mod+Alt+1…3 | heading 1…3 |
+/// | mod+Alt+0 | paragraph |
+/// | mod+Shift+7 / mod+Shift+8 | ordered / bullet list |
+pub fn command_for_key(key: &str, code: &str, m: KeyModifiers) -> Option<EditorCommand> {
+    if !(m.ctrl || m.meta) {
+        return None;
+    }
+    let key = key.to_ascii_lowercase();
+    let digit = code.strip_prefix("Digit").and_then(|d| d.parse::<u8>().ok());
+
+    if m.alt {
+        return match digit {
+            Some(0) => Some(EditorCommand::SetBlock(BlockKind::Paragraph)),
+            Some(level @ 1..=3) => Some(EditorCommand::SetBlock(BlockKind::Heading(level))),
+            _ => None,
+        };
+    }
+
+    if m.shift {
+        return match (key.as_str(), digit) {
+            ("x", _) => Some(EditorCommand::ToggleMark(Mark::Strike)),
+            ("z", _) => Some(EditorCommand::Redo),
+            (_, Some(7)) => Some(EditorCommand::SetBlock(BlockKind::OrderedItem)),
+            (_, Some(8)) => Some(EditorCommand::SetBlock(BlockKind::BulletItem)),
+            _ => None,
+        };
+    }
+
+    match key.as_str() {
+        "b" => Some(EditorCommand::ToggleMark(Mark::Bold)),
+        "i" => Some(EditorCommand::ToggleMark(Mark::Italic)),
+        "u" => Some(EditorCommand::ToggleMark(Mark::Underline)),
+        "e" => Some(EditorCommand::ToggleMark(Mark::Code)),
+        "k" => Some(EditorCommand::Link),
+        "z" => Some(EditorCommand::Undo),
+        "y" => Some(EditorCommand::Redo),
+        _ => None,
+    }
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CTRL: KeyModifiers = KeyModifiers { ctrl: true, meta: false, shift: false, alt: false };
+    const CMD: KeyModifiers = KeyModifiers { ctrl: false, meta: true, shift: false, alt: false };
+
+    #[test]
+    fn marks_with_ctrl_or_cmd() {
+        assert_eq!(command_for_key("b", "KeyB", CTRL), Some(EditorCommand::ToggleMark(Mark::Bold)));
+        assert_eq!(command_for_key("i", "KeyI", CMD), Some(EditorCommand::ToggleMark(Mark::Italic)));
+        assert_eq!(command_for_key("U", "KeyU", CTRL), Some(EditorCommand::ToggleMark(Mark::Underline)));
+        assert_eq!(command_for_key("e", "KeyE", CMD), Some(EditorCommand::ToggleMark(Mark::Code)));
+    }
+
+    #[test]
+    fn requires_a_modifier() {
+        assert_eq!(command_for_key("b", "KeyB", KeyModifiers::default()), None);
+        let shift_only = KeyModifiers { shift: true, ..Default::default() };
+        assert_eq!(command_for_key("Z", "KeyZ", shift_only), None);
+    }
+
+    #[test]
+    fn undo_and_redo() {
+        assert_eq!(command_for_key("z", "KeyZ", CTRL), Some(EditorCommand::Undo));
+        assert_eq!(command_for_key("Z", "KeyZ", KeyModifiers { shift: true, ..CMD }), Some(EditorCommand::Redo));
+        assert_eq!(command_for_key("y", "KeyY", CTRL), Some(EditorCommand::Redo));
+    }
+
+    #[test]
+    fn strike_and_link() {
+        assert_eq!(command_for_key("X", "KeyX", KeyModifiers { shift: true, ..CTRL }), Some(EditorCommand::ToggleMark(Mark::Strike)));
+        assert_eq!(command_for_key("k", "KeyK", CTRL), Some(EditorCommand::Link));
+    }
+
+    #[test]
+    fn block_shortcuts_use_physical_digits() {
+        let alt = KeyModifiers { alt: true, ..CMD };
+        // Option+2 on macOS yields "™" as the key; the code is still Digit2.
+        assert_eq!(command_for_key("™", "Digit2", alt), Some(EditorCommand::SetBlock(BlockKind::Heading(2))));
+        assert_eq!(command_for_key("0", "Digit0", alt), Some(EditorCommand::SetBlock(BlockKind::Paragraph)));
+        assert_eq!(command_for_key("4", "Digit4", alt), None);
+        let shift = KeyModifiers { shift: true, ..CTRL };
+        assert_eq!(command_for_key("*", "Digit8", shift), Some(EditorCommand::SetBlock(BlockKind::BulletItem)));
+        assert_eq!(command_for_key("&", "Digit7", shift), Some(EditorCommand::SetBlock(BlockKind::OrderedItem)));
+    }
+}
@@ -0,0 +1,890 @@
+//! Rust-side document model for EqRichTextEditor.
+//!
+//! A document is a flat list of [`Block`]s. Each block has a
+//! [`BlockKind`] and a list of [`TextRun`]s — contiguous text sharing
+//! the same inline [`Marks`] and optional link. Lists are modelled as
+//! consecutive `BulletItem` / `OrderedItem` blocks; the serializers
+//! group them into `<ul>` / `<ol>` (or `-` / `1.` lines).
+//!
+//! Positions are `(block, offset)` pairs where `offset` counts Unicode
+//! scalar values (`char`s) from the start of the block's text. All
+//! editing operations are plain Rust and need no browser, so the model
+//! can be unit-tested headless.
+
+use crate::sanitize::safe_url;
+
+// ── Marks ──────────────────────────────────────────────────────────
+
+/// A single inline formatting mark.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Mark {
+    Bold,
+    Italic,
+    Underline,
+    Strike,
+    Code,
+}
+
+/// The set of inline marks applied to a [`TextRun`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Marks {
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub strike: bool,
+    pub code: bool,
+}
+
+impl Marks {
+    /// Whether `mark` is set.
+    pub fn has(&self, mark: Mark) -> bool {
+        match mark {
+            Mark::Bold => self.bold,
+            Mark::Italic => self.italic,
+            Mark::Underline => self.underline,
+            Mark::Strike => self.strike,
+            Mark::Code => self.code,
+        }
+    }
+
+    /// Set or clear `mark`.
+    pub fn set(&mut self, mark: Mark, on: bool) {
+        match mark {
+            Mark::Bold => self.bold = on,
+            Mark::Italic => self.italic = on,
+            Mark::Underline => self.underline = on,
+            Mark::Strike => self.strike = on,
+            Mark::Code => self.code = on,
+        }
+    }
+
+    /// Builder: returns a copy with `mark` set.
+    pub fn with(mut self, mark: Mark) -> Self {
+        self.set(mark, true);
+        self
+    }
+}
+
+// ── Runs and blocks ────────────────────────────────────────────────
+
+/// Contiguous text sharing the same marks and link target.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct TextRun {
+    pub text: String,
+    pub marks: Marks,
+    /// Link target (`href`). Already sanitized when set through the
+    /// document API or the HTML parser.
+    pub link: Option<String>,
+}
+
+impl TextRun {
+    /// Plain, unmarked text.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into(), ..Default::default() }
+    }
+
+    /// Builder: add an inline mark.
+    pub fn mark(mut self, mark: Mark) -> Self {
+        self.marks.set(mark, true);
+        self
+    }
+
+    /// Builder: make this run a link.
+    pub fn link(mut self, href: impl Into<String>) -> Self {
+        self.link = Some(href.into());
+        self
+    }
+
+    /// Length in `char`s.
+    pub fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    /// Whether the run holds no text.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    fn same_format(&self, other: &TextRun) -> bool {
+        self.marks == other.marks && self.link == other.link
+    }
+}
+
+/// Block-level element type.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum BlockKind {
+    #[default]
+    Paragraph,
+    /// Heading level 1–6.
+    Heading(u8),
+    Quote,
+    /// One line of a fenced code block. Consecutive code lines are
+    /// serialized as a single fence.
+    CodeBlock,
+    BulletItem,
+    OrderedItem,
+}
+
+impl BlockKind {
+    /// Stable string identifier, used for toolbar menu items.
+    pub fn id(&self) -> &'static str {
+        match self {
+            BlockKind::Paragraph => "paragraph",
+            BlockKind::Heading(1) => "h1",
+            BlockKind::Heading(2) => "h2",
+            BlockKind::Heading(3) => "h3",
+            BlockKind::Heading(4) => "h4",
+            BlockKind::Heading(5) => "h5",
+            BlockKind::Heading(_) => "h6",
+            BlockKind::Quote => "quote",
+            BlockKind::CodeBlock => "code",
+            BlockKind::BulletItem => "bullet",
+            BlockKind::OrderedItem => "ordered",
+        }
+    }
+
+    /// Inverse of [`BlockKind::id`].
+    pub fn from_id(id: &str) -> Option<Self> {
+        Some(match id {
+            "paragraph" => BlockKind::Paragraph,
+            "h1" => BlockKind::Heading(1),
+            "h2" => BlockKind::Heading(2),
+            "h3" => BlockKind::Heading(3),
+            "h4" => BlockKind::Heading(4),
+            "h5" => BlockKind::Heading(5),
+            "h6" => BlockKind::Heading(6),
+            "quote" => BlockKind::Quote,
+            "code" => BlockKind::CodeBlock,
+            "bullet" => BlockKind::BulletItem,
+            "ordered" => BlockKind::OrderedItem,
+            _ => return None,
+        })
+    }
+
+    /// Human-readable label for menus.
+    pub fn label(&self) -> &'static str {
+        match self {
+            BlockKind::Paragraph => "Paragraph",
+            BlockKind::Heading(1) => "Heading 1",
+            BlockKind::Heading(2) => "Heading 2",
+            BlockKind::Heading(3) => "Heading 3",
+            BlockKind::Heading(4) => "Heading 4",
+            BlockKind::Heading(5) => "Heading 5",
+            BlockKind::Heading(_) => "Heading 6",
+            BlockKind::Quote => "Quote",
+            BlockKind::CodeBlock => "Code block",
+            BlockKind::BulletItem => "Bulleted list",
+            BlockKind::OrderedItem => "Numbered list",
+        }
+    }
+
+    /// Whether this kind renders as a list item.
+    pub fn is_list(&self) -> bool {
+        matches!(self, BlockKind::BulletItem | BlockKind::OrderedItem)
+    }
+}
+
+/// A block-level element: a paragraph, heading, list item, etc.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Block {
+    pub kind: BlockKind,
+    pub runs: Vec<TextRun>,
+}
+
+impl Block {
+    /// An empty block of the given kind.
+    pub fn new(kind: BlockKind) -> Self {
+        Self { kind, runs: Vec::new() }
+    }
+
+    /// Builder: append a run.
+    pub fn run(mut self, run: TextRun) -> Self {
+        self.runs.push(run);
+        self
+    }
+
+    /// Builder: append plain text.
+    pub fn text(self, text: impl Into<String>) -> Self {
+        self.run(TextRun::new(text))
+    }
+
+    /// Length in `char`s.
+    pub fn len(&self) -> usize {
+        self.runs.iter().map(TextRun::len).sum()
+    }
+
+    /// Whether the block holds no text.
+    pub fn is_empty(&self) -> bool {
+        self.runs.iter().all(TextRun::is_empty)
+    }
+
+    /// Concatenated text of all runs.
+    pub fn plain_text(&self) -> String {
+        self.runs.iter().map(|r| r.text.as_str()).collect()
+    }
+
+    /// Ensures a run boundary at `offset` and returns the index of the
+    /// first run starting at or after it.
+    fn boundary(&mut self, offset: usize) -> usize {
+        let mut pos = 0;
+        for i in 0..self.runs.len() {
+            if offset == pos {
+                return i;
+            }
+            let len = self.runs[i].len();
+            if offset < pos + len {
+                let byte = char_to_byte(&self.runs[i].text, offset - pos);
+                let tail = self.runs[i].text.split_off(byte);
+                let mut next = self.runs[i].clone();
+                next.text = tail;
+                self.runs.insert(i + 1, next);
+                return i + 1;
+            }
+            pos += len;
+        }
+        self.runs.len()
+    }
+
+    /// Removes and returns every run after `offset`.
+    fn split_off(&mut self, offset: usize) -> Vec<TextRun> {
+        let i = self.boundary(offset);
+        self.runs.split_off(i)
+    }
+
+    /// Drops empty runs and merges neighbours with identical formatting.
+    fn normalize(&mut self) {
+        let mut out: Vec<TextRun> = Vec::with_capacity(self.runs.len());
+        for run in self.runs.drain(..) {
+            if run.is_empty() {
+                continue;
+            }
+            match out.last_mut() {
+                Some(prev) if prev.same_format(&run) => prev.text.push_str(&run.text),
+                _ => out.push(run),
+            }
+        }
+        self.runs = out;
+    }
+
+    /// The run containing the character just before `offset` (the one
+    /// newly typed text would inherit formatting from).
+    fn run_before(&self, offset: usize) -> Option<&TextRun> {
+        let mut pos = 0;
+        for run in &self.runs {
+            let len = run.len();
+            if offset > pos && offset <= pos + len {
+                return Some(run);
+            }
+            pos += len;
+        }
+        self.runs.first()
+    }
+}
+
+fn char_to_byte(s: &str, chars: usize) -> usize {
+    s.char_indices().nth(chars).map(|(b, _)| b).unwrap_or(s.len())
+}
+
+// ── Positions ──────────────────────────────────────────────────────
+
+/// A caret position: block index plus `char` offset within the block.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, PartialOrd, Ord)]
+pub struct DocPosition {
+    pub block: usize,
+    pub offset: usize,
+}
+
+impl DocPosition {
+    pub fn new(block: usize, offset: usize) -> Self {
+        Self { block, offset }
+    }
+}
+
+/// A selection between `anchor` (where it started) and `focus`
+/// (where the caret is). Either order is valid.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct DocSelection {
+    pub anchor: DocPosition,
+    pub focus: DocPosition,
+}
+
+impl DocSelection {
+    /// A collapsed selection (a caret).
+    pub fn caret(pos: DocPosition) -> Self {
+        Self { anchor: pos, focus: pos }
+    }
+
+    pub fn new(anchor: DocPosition, focus: DocPosition) -> Self {
+        Self { anchor, focus }
+    }
+
+    /// `(start, end)` in document order.
+    pub fn ordered(&self) -> (DocPosition, DocPosition) {
+        if self.anchor <= self.focus {
+            (self.anchor, self.focus)
+        } else {
+            (self.focus, self.anchor)
+        }
+    }
+
+    /// Whether anchor and focus coincide.
+    pub fn is_collapsed(&self) -> bool {
+        self.anchor == self.focus
+    }
+}
+
+// ── Document ───────────────────────────────────────────────────────
+
+/// A rich text document.
+///
+/// Always holds at least one block; an empty document is a single
+/// empty paragraph.
+#[derive(Clone, PartialEq, Debug)]
+pub struct RichDocument {
+    pub blocks: Vec<Block>,
+}
+
+impl Default for RichDocument {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RichDocument {
+    /// An empty document (one empty paragraph).
+    pub fn new() -> Self {
+        Self { blocks: vec![Block::default()] }
+    }
+
+    /// Build a document from blocks. An empty list becomes one empty
+    /// paragraph.
+    pub fn from_blocks(blocks: Vec<Block>) -> Self {
+        let mut doc = Self { blocks };
+        doc.normalize();
+        doc
+    }
+
+    /// One paragraph per line of `text`.
+    pub fn from_plain_text(text: &str) -> Self {
+        Self::from_blocks(
+            text.lines()
+                .map(|line| Block::new(BlockKind::Paragraph).text(line))
+                .collect(),
+        )
+    }
+
+    /// The text of every block, joined with `\n`.
+    pub fn plain_text(&self) -> String {
+        self.blocks.iter().map(Block::plain_text).collect::<Vec<_>>().join("\n")
+    }
+
+    /// Whether the document holds no text at all.
+    pub fn is_empty(&self) -> bool {
+        self.blocks.iter().all(Block::is_empty)
+    }
+
+    /// Position at the very end of the document.
+    pub fn end(&self) -> DocPosition {
+        let last = self.blocks.len().saturating_sub(1);
+        DocPosition::new(last, self.blocks.get(last).map(Block::len).unwrap_or(0))
+    }
+
+    /// Clamps `pos` to an existing block and offset.
+    pub fn clamp(&self, pos: DocPosition) -> DocPosition {
+        if pos.block >= self.blocks.len() {
+            return self.end();
+        }
+        DocPosition::new(pos.block, pos.offset.min(self.blocks[pos.block].len()))
+    }
+
+    fn clamp_selection(&self, sel: DocSelection) -> (DocPosition, DocPosition) {
+        let (start, end) = sel.ordered();
+        (self.clamp(start), self.clamp(end))
+    }
+
+    /// Merges adjacent runs and guarantees at least one block.
+    pub fn normalize(&mut self) {
+        for block in &mut self.blocks {
+            block.normalize();
+        }
+        if self.blocks.is_empty() {
+            self.blocks.push(Block::default());
+        }
+    }
+
+    // ── Text editing ───────────────────────────────────────────────
+
+    /// Inserts `text` at `pos` and returns the caret after it.
+    ///
+    /// The new text inherits the marks and link of the character before
+    /// the caret. Each `\n` splits the block (see [`split_block`]).
+    ///
+    /// [`split_block`]: RichDocument::split_block
+    pub fn insert_text(&mut self, pos: DocPosition, text: &str) -> DocPosition {
+        let mut pos = self.clamp(pos);
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                pos = self.split_block(pos);
+            }
+            if line.is_empty() {
+                continue;
+            }
+            let block = &mut self.blocks[pos.block];
+            let format = block.run_before(pos.offset).cloned().unwrap_or_default();
+            let idx = block.boundary(pos.offset);
+            block.runs.insert(idx, TextRun { text: line.to_string(), ..format });
+            block.normalize();
+            pos.offset += line.chars().count();
+        }
+        pos
+    }
+
+    /// Deletes the selected content and returns the collapsed caret.
+    pub fn delete_range(&mut self, sel: DocSelection) -> DocPosition {
+        let (start, end) = self.clamp_selection(sel);
+        if start == end {
+            return start;
+        }
+        if start.block == end.block {
+            let block = &mut self.blocks[start.block];
+            let i = block.boundary(start.offset);
+            let j = block.boundary(end.offset);
+            block.runs.drain(i..j);
+            block.normalize();
+        } else {
+            let tail = self.blocks[end.block].split_off(end.offset);
+            let first = &mut self.blocks[start.block];
+            first.split_off(start.offset);
+            first.runs.extend(tail);
+            first.normalize();
+            self.blocks.drain(start.block + 1..=end.block);
+        }
+        start
+    }
+
+    /// Replaces the selection with `text`.
+    pub fn replace_range(&mut self, sel: DocSelection, text: &str) -> DocPosition {
+        let pos = self.delete_range(sel);
+        self.insert_text(pos, text)
+    }
+
+    /// Splits the block at `pos` (the Enter key) and returns the caret
+    /// at the start of the new block.
+    ///
+    /// List items, quotes and code lines continue their kind; other
+    /// blocks continue as paragraphs. Pressing Enter on an empty list
+    /// item or quote turns it into a paragraph instead of adding another.
+    pub fn split_block(&mut self, pos: DocPosition) -> DocPosition {
+        let pos = self.clamp(pos);
+        let block = &mut self.blocks[pos.block];
+        let continues = matches!(
+            block.kind,
+            BlockKind::BulletItem | BlockKind::OrderedItem | BlockKind::CodeBlock | BlockKind::Quote
+        );
+        if block.is_empty() && block.kind != BlockKind::CodeBlock && continues {
+            block.kind = BlockKind::Paragraph;
+            return pos;
+        }
+        let kind = if continues { block.kind } else { BlockKind::Paragraph };
+        if pos.offset == 0 && !block.is_empty() {
+            // Caret at the start: open an empty block above and keep
+            // the current one (and its kind) intact.
+            self.blocks.insert(pos.block, Block::new(kind));
+            return DocPosition::new(pos.block + 1, 0);
+        }
+        let tail = block.split_off(pos.offset);
+        block.normalize();
+        let mut next = Block { kind, runs: tail };
+        next.normalize();
+        self.blocks.insert(pos.block + 1, next);
+        DocPosition::new(pos.block + 1, 0)
+    }
+
+    /// Joins block `index` onto the end of the previous block (the
+    /// Backspace key at offset 0) and returns the caret at the seam.
+    pub fn merge_with_previous(&mut self, index: usize) -> DocPosition {
+        if index == 0 || index >= self.blocks.len() {
+            return DocPosition::new(index.min(self.blocks.len() - 1), 0);
+        }
+        let removed = self.blocks.remove(index);
+        let prev = &mut self.blocks[index - 1];
+        let seam = prev.len();
+        prev.runs.extend(removed.runs);
+        prev.normalize();
+        DocPosition::new(index - 1, seam)
+    }
+
+    /// Inserts another document at `pos` (e.g. sanitized paste) and
+    /// returns the caret after the inserted content.
+    ///
+    /// The first pasted block is merged into the block at `pos`; the
+    /// remaining blocks keep their own kinds.
+    pub fn insert_fragment(&mut self, pos: DocPosition, fragment: &RichDocument) -> DocPosition {
+        let pos = self.clamp(pos);
+        let mut blocks = fragment.blocks.clone();
+        if blocks.is_empty() {
+            return pos;
+        }
+        let tail = self.blocks[pos.block].split_off(pos.offset);
+        let first = blocks.remove(0);
+        let target = &mut self.blocks[pos.block];
+        let mut caret = DocPosition::new(pos.block, target.len() + first.len());
+        target.runs.extend(first.runs);
+        target.normalize();
+
+        let mut at = pos.block;
+        for block in blocks {
+            at += 1;
+            caret = DocPosition::new(at, block.len());
+            self.blocks.insert(at, block);
+        }
+        let last = &mut self.blocks[at];
+        last.runs.extend(tail);
+        last.normalize();
+        caret
+    }
+
+    // ── Formatting ─────────────────────────────────────────────────
+
+    /// Non-empty runs overlapping `start..end`, without splitting.
+    fn runs_in(&self, start: DocPosition, end: DocPosition) -> Vec<&TextRun> {
+        let mut out = Vec::new();
+        for b in start.block..=end.block {
+            let block = &self.blocks[b];
+            let from = if b == start.block { start.offset } else { 0 };
+            let to = if b == end.block { end.offset } else { block.len() };
+            let mut pos = 0;
+            for run in &block.runs {
+                let len = run.len();
+                if len > 0 && pos < to && pos + len > from {
+                    out.push(run);
+                }
+                pos += len;
+            }
+        }
+        out
+    }
+
+    /// Visits every run inside the selection, splitting runs at the
+    /// selection edges first.
+    fn for_each_run_in(&mut self, start: DocPosition, end: DocPosition, mut f: impl FnMut(&mut TextRun)) {
+        for b in start.block..=end.block {
+            let block = &mut self.blocks[b];
+            let from = if b == start.block { start.offset } else { 0 };
+            let to = if b == end.block { end.offset } else { block.len() };
+            let i = block.boundary(from);
+            let j = block.boundary(to);
+            for run in &mut block.runs[i..j] {
+                f(run);
+            }
+            block.normalize();
+        }
+    }
+
+    /// Whether every character in the selection carries `mark`. A
+    /// collapsed selection reports the mark of the character before it.
+    pub fn has_mark(&self, sel: DocSelection, mark: Mark) -> bool {
+        self.marks_in(sel).has(mark)
+    }
+
+    /// Marks shared by every character in the selection. A collapsed
+    /// selection reports the marks of the character before it.
+    pub fn marks_in(&self, sel: DocSelection) -> Marks {
+        let (start, end) = self.clamp_selection(sel);
+        if start == end {
+            return self.blocks[start.block]
+                .run_before(start.offset)
+                .map(|r| r.marks)
+                .unwrap_or_default();
+        }
+        let mut shared: Option<Marks> = None;
+        for run in self.runs_in(start, end) {
+            let m = shared.get_or_insert(run.marks);
+            for mark in [Mark::Bold, Mark::Italic, Mark::Underline, Mark::Strike, Mark::Code] {
+                if !run.marks.has(mark) {
+                    m.set(mark, false);
+                }
+            }
+        }
+        shared.unwrap_or_default()
+    }
+
+    /// Adds `mark` to the selection, or removes it when every selected
+    /// character already has it.
+    ///
+    /// A collapsed selection applies to the word around the caret.
+    pub fn toggle_mark(&mut self, sel: DocSelection, mark: Mark) {
+        let sel = self.expand_collapsed(sel);
+        let (start, end) = self.clamp_selection(sel);
+        if start == end {
+            return;
+        }
+        let on = !self.has_mark(sel, mark);
+        self.for_each_run_in(start, end, |run| run.marks.set(mark, on));
+    }
+
+    /// Sets (or with `None`, removes) the link on the selection. A
+    /// collapsed selection applies to the word around the caret.
+    /// Unsafe URLs (e.g. `javascript:`) are rejected and leave the
+    /// document unchanged; returns whether the link was applied.
+    pub fn set_link(&mut self, sel: DocSelection, href: Option<&str>) -> bool {
+        let href = match href {
+            Some(raw) => match safe_url(raw) {
+                Some(clean) => Some(clean),
+                None => return false,
+            },
+            None => None,
+        };
+        let sel = self.expand_collapsed(sel);
+        let (start, end) = self.clamp_selection(sel);
+        if start == end {
+            return false;
+        }
+        self.for_each_run_in(start, end, |run| run.link = href.clone());
+        true
+    }
+
+    /// The link under the caret or shared by the whole selection.
+    pub fn link_in(&self, sel: DocSelection) -> Option<String> {
+        let (start, end) = self.clamp_selection(sel);
+        if start == end {
+            return self.blocks[start.block].run_before(start.offset).and_then(|r| r.link.clone());
+        }
+        let mut link: Option<Option<String>> = None;
+        for run in self.runs_in(start, end) {
+            match &link {
+                None => link = Some(run.link.clone()),
+                Some(l) if *l != run.link => link = Some(None),
+                _ => {}
+            }
+        }
+        link.flatten()
+    }
+
+    /// Changes the kind of every block touched by the selection.
+    pub fn set_block_kind(&mut self, sel: DocSelection, kind: BlockKind) {
+        let (start, end) = self.clamp_selection(sel);
+        for block in &mut self.blocks[start.block..=end.block] {
+            block.kind = kind;
+        }
+    }
+
+    /// The kind of the block holding the selection start.
+    pub fn block_kind_at(&self, pos: DocPosition) -> BlockKind {
+        self.blocks[self.clamp(pos).block].kind
+    }
+
+    /// Range of the word surrounding `pos` (letters, digits, `_`, `'`).
+    pub fn word_at(&self, pos: DocPosition) -> DocSelection {
+        let pos = self.clamp(pos);
+        let chars: Vec<char> = self.blocks[pos.block].plain_text().chars().collect();
+        let is_word = |c: char| c.is_alphanumeric() || c == '_' || c == '\'';
+        let mut from = pos.offset;
+        while from > 0 && is_word(chars[from - 1]) {
+            from -= 1;
+        }
+        let mut to = pos.offset;
+        while to < chars.len() && is_word(chars[to]) {
+            to += 1;
+        }
+        DocSelection::new(DocPosition::new(pos.block, from), DocPosition::new(pos.block, to))
+    }
+
+    fn expand_collapsed(&self, sel: DocSelection) -> DocSelection {
+        if sel.is_collapsed() { self.word_at(sel.focus) } else { sel }
+    }
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn doc(lines: &[&str]) -> RichDocument {
+        RichDocument::from_blocks(lines.iter().map(|l| Block::new(BlockKind::Paragraph).text(*l)).collect())
+    }
+
+    fn sel(b0: usize, o0: usize, b1: usize, o1: usize) -> DocSelection {
+        DocSelection::new(DocPosition::new(b0, o0), DocPosition::new(b1, o1))
+    }
+
+    #[test]
+    fn empty_document_has_one_paragraph() {
+        let d = RichDocument::new();
+        assert_eq!(d.blocks.len(), 1);
+        assert!(d.is_empty());
+        assert_eq!(RichDocument::from_blocks(vec![]).blocks.len(), 1);
+    }
+
+    #[test]
+    fn insert_text_inherits_previous_marks() {
+        let mut d = RichDocument::from_blocks(vec![
+            Block::new(BlockKind::Paragraph).run(TextRun::new("bold").mark(Mark::Bold)).text(" plain"),
+        ]);
+        let caret = d.insert_text(DocPosition::new(0, 4), "er");
+        assert_eq!(caret, DocPosition::new(0, 6));
+        assert_eq!(d.blocks[0].runs[0].text, "bolder");
+        assert!(d.blocks[0].runs[0].marks.bold);
+        assert_eq!(d.blocks[0].runs[1].text, " plain");
+    }
+
+    #[test]
+    fn insert_text_with_newlines_splits_blocks() {
+        let mut d = doc(&["ab"]);
+        let caret = d.insert_text(DocPosition::new(0, 1), "x\ny");
+        assert_eq!(d.plain_text(), "ax\nyb");
+        assert_eq!(caret, DocPosition::new(1, 1));
+    }
+
+    #[test]
+    fn insert_counts_chars_not_bytes() {
+        let mut d = doc(&["héllo"]);
+        d.insert_text(DocPosition::new(0, 2), "✓");
+        assert_eq!(d.plain_text(), "hé✓llo");
+    }
+
+    #[test]
+    fn delete_within_block() {
+        let mut d = doc(&["hello world"]);
+        let caret = d.delete_range(sel(0, 5, 0, 11));
+        assert_eq!(d.plain_text(), "hello");
+        assert_eq!(caret, DocPosition::new(0, 5));
+    }
+
+    #[test]
+    fn delete_across_blocks_merges_them() {
+        let mut d = doc(&["first", "middle", "last"]);
+        d.delete_range(sel(2, 2, 0, 3));
+        assert_eq!(d.plain_text(), "first");
+        assert_eq!(d.blocks.len(), 1);
+        let mut d = doc(&["abc", "def"]);
+        d.delete_range(sel(0, 1, 1, 2));
+        assert_eq!(d.plain_text(), "af");
+    }
+
+    #[test]
+    fn split_block_continues_lists_and_ends_headings() {
+        let mut d = RichDocument::from_blocks(vec![
+            Block::new(BlockKind::Heading(1)).text("Title"),
+            Block::new(BlockKind::BulletItem).text("item"),
+        ]);
+        d.split_block(DocPosition::new(0, 5));
+        assert_eq!(d.blocks[1].kind, BlockKind::Paragraph);
+        let caret = d.split_block(DocPosition::new(2, 4));
+        assert_eq!(d.blocks[3].kind, BlockKind::BulletItem);
+        // Enter on the empty item leaves the list.
+        d.split_block(caret);
+        assert_eq!(d.blocks[3].kind, BlockKind::Paragraph);
+        assert_eq!(d.blocks.len(), 4);
+    }
+
+    #[test]
+    fn merge_with_previous_joins_text() {
+        let mut d = doc(&["ab", "cd"]);
+        let caret = d.merge_with_previous(1);
+        assert_eq!(d.plain_text(), "abcd");
+        assert_eq!(caret, DocPosition::new(0, 2));
+    }
+
+    #[test]
+    fn toggle_mark_adds_then_removes() {
+        let mut d = doc(&["hello world"]);
+        d.toggle_mark(sel(0, 0, 0, 5), Mark::Bold);
+        assert_eq!(d.blocks[0].runs.len(), 2);
+        assert!(d.blocks[0].runs[0].marks.bold);
+        assert!(d.has_mark(sel(0, 0, 0, 5), Mark::Bold));
+        d.toggle_mark(sel(0, 0, 0, 5), Mark::Bold);
+        assert_eq!(d.blocks[0].runs.len(), 1);
+        assert!(!d.blocks[0].runs[0].marks.bold);
+    }
+
+    #[test]
+    fn toggle_mark_on_mixed_selection_applies_everywhere() {
+        let mut d = doc(&["hello world"]);
+        d.toggle_mark(sel(0, 0, 0, 5), Mark::Italic);
+        d.toggle_mark(sel(0, 0, 0, 11), Mark::Italic);
+        assert_eq!(d.blocks[0].runs.len(), 1);
+        assert!(d.blocks[0].runs[0].marks.italic);
+    }
+
+    #[test]
+    fn toggle_mark_with_caret_targets_word() {
+        let mut d = doc(&["one two three"]);
+        d.toggle_mark(DocSelection::caret(DocPosition::new(0, 5)), Mark::Underline);
+        let underlined: Vec<_> = d.blocks[0].runs.iter().filter(|r| r.marks.underline).map(|r| r.text.as_str()).collect();
+        assert_eq!(underlined, vec!["two"]);
+    }
+
+    #[test]
+    fn toggle_mark_across_blocks() {
+        let mut d = doc(&["abc", "def"]);
+        d.toggle_mark(sel(0, 1, 1, 2), Mark::Strike);
+        assert_eq!(d.blocks[0].runs[1].text, "bc");
+        assert!(d.blocks[0].runs[1].marks.strike);
+        assert_eq!(d.blocks[1].runs[0].text, "de");
+        assert!(d.blocks[1].runs[0].marks.strike);
+    }
+
+    #[test]
+    fn set_link_rejects_javascript() {
+        let mut d = doc(&["click here"]);
+        assert!(!d.set_link(sel(0, 0, 0, 5), Some("javascript:alert(1)")));
+        assert!(!d.set_link(sel(0, 0, 0, 5), Some(" JaVa\tScRiPt:alert(1)")));
+        assert!(d.blocks[0].runs[0].link.is_none());
+        assert!(d.set_link(sel(0, 0, 0, 5), Some("https://example.com")));
+        assert_eq!(d.link_in(sel(0, 1, 0, 3)).as_deref(), Some("https://example.com"));
+        d.set_link(sel(0, 0, 0, 5), None);
+        assert_eq!(d.link_in(sel(0, 0, 0, 5)), None);
+    }
+
+    #[test]
+    fn set_block_kind_covers_selection() {
+        let mut d = doc(&["a", "b", "c"]);
+        d.set_block_kind(sel(0, 0, 1, 1), BlockKind::BulletItem);
+        assert_eq!(d.blocks[0].kind, BlockKind::BulletItem);
+        assert_eq!(d.blocks[1].kind, BlockKind::BulletItem);
+        assert_eq!(d.blocks[2].kind, BlockKind::Paragraph);
+    }
+
+    #[test]
+    fn insert_fragment_merges_first_and_last_blocks() {
+        let mut d = doc(&["start end"]);
+        let frag = RichDocument::from_blocks(vec![
+            Block::new(BlockKind::Paragraph).text("one"),
+            Block::new(BlockKind::Heading(2)).text("two"),
+        ]);
+        let caret = d.insert_fragment(DocPosition::new(0, 6), &frag);
+        assert_eq!(d.plain_text(), "start one\ntwoend");
+        assert_eq!(d.blocks[1].kind, BlockKind::Heading(2));
+        assert_eq!(caret, DocPosition::new(1, 3));
+    }
+
+    #[test]
+    fn marks_in_reports_shared_marks() {
+        let d = RichDocument::from_blocks(vec![Block::new(BlockKind::Paragraph)
+            .run(TextRun::new("ab").mark(Mark::Bold).mark(Mark::Italic))
+            .run(TextRun::new("cd").mark(Mark::Bold))]);
+        let m = d.marks_in(sel(0, 0, 0, 4));
+        assert!(m.bold);
+        assert!(!m.italic);
+        assert!(d.marks_in(DocSelection::caret(DocPosition::new(0, 1))).italic);
+    }
+
+    #[test]
+    fn block_kind_ids_round_trip() {
+        for kind in [
+            BlockKind::Paragraph, BlockKind::Heading(1), BlockKind::Heading(3),
+            BlockKind::Quote, BlockKind::CodeBlock, BlockKind::BulletItem, BlockKind::OrderedItem,
+        ] {
+            assert_eq!(BlockKind::from_id(kind.id()), Some(kind));
+        }
+    }
+
+    #[test]
+    fn clamp_handles_out_of_range_positions() {
+        let d = doc(&["abc"]);
+        assert_eq!(d.clamp(DocPosition::new(5, 9)), DocPosition::new(0, 3));
+        assert_eq!(d.clamp(DocPosition::new(0, 9)), DocPosition::new(0, 3));
+    }
+}
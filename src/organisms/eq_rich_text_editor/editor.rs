@@ -0,0 +1,714 @@
+//! EqRichTextEditor - WYSIWYG editor backed by a Rust document model.
+//!
+//! The [`RichDocument`] is the source of truth. The surface is a
+//! `contenteditable` element whose HTML is rendered from the model;
+//! typing is left to the browser and read back into the model on
+//! `input`, while structural edits (Enter, formatting, paste, undo)
+//! are applied to the model and re-rendered.
+
+use super::commands::{EditorCommand, KeyModifiers, command_for_key};
+use super::document::{BlockKind, DocPosition, DocSelection, RichDocument};
+use super::history::{EditKind, History, Snapshot};
+use super::sanitize::parse_html;
+use super::serialize::render_html;
+use super::styles as s;
+use super::toolbar::{ToolbarState, render_link_bar, render_toolbar};
+use crate::playground;
+use crate::shortcut::use_platform;
+use crate::theme::merge_classes;
+use dioxus::prelude::*;
+
+#[cfg(feature = "playground")]
+use crate::playground::playground_helpers::{DemoSection, PropInput, PropToggle, StyleInfo, format_catalog};
+#[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
+use crate::atoms::{EqText, TextVariant, EqTab, TabItem, TabVariant};
+#[cfg(feature = "playground")]
+use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
+
+// ── Selection bridge ───────────────────────────────────────────────
+
+/// Defines `window.__eqRte` once: converts DOM selections to
+/// `[anchor_block, anchor_offset, focus_block, focus_offset]` (offsets
+/// in code points, matching [`DocPosition`]) and back.
+const SELECTION_JS: &str = r#"
+if (!window.__eqRte) {
+    const blocks = (root) => {
+        const out = [];
+        for (const c of root.children) {
+            if (c.tagName === 'UL' || c.tagName === 'OL') {
+                for (const li of c.children) out.push(li);
+            } else {
+                out.push(c);
+            }
+        }
+        return out;
+    };
+    const chars = (s) => Array.from(s).length;
+    const locate = (root, node, off) => {
+        const bs = blocks(root);
+        for (let i = 0; i < bs.length; i++) {
+            if (bs[i] === node || bs[i].contains(node)) {
+                const r = document.createRange();
+                r.setStart(bs[i], 0);
+                r.setEnd(node, off);
+                return [i, chars(r.toString())];
+            }
+        }
+        const p = document.createRange();
+        p.setStart(node, off);
+        for (let i = 0; i < bs.length; i++) {
+            if (p.comparePoint(bs[i], 0) >= 0) return [i, 0];
+        }
+        const last = Math.max(bs.length - 1, 0);
+        return [last, bs.length ? chars(bs[last].textContent) : 0];
+    };
+    const place = (root, b, o) => {
+        const bs = blocks(root);
+        if (!bs.length) return [root, 0];
+        const el = bs[Math.min(b, bs.length - 1)];
+        const w = document.createTreeWalker(el, NodeFilter.SHOW_TEXT);
+        let n, rest = o, last = null;
+        while ((n = w.nextNode())) {
+            last = n;
+            const cs = Array.from(n.data);
+            if (rest <= cs.length) return [n, cs.slice(0, rest).join('').length];
+            rest -= cs.length;
+        }
+        return last ? [last, last.data.length] : [el, 0];
+    };
+    window.__eqRte = {
+        get(root) {
+            const s = window.getSelection();
+            if (!s || !s.rangeCount || !root.contains(s.anchorNode)) return null;
+            return [...locate(root, s.anchorNode, s.anchorOffset), ...locate(root, s.focusNode, s.focusOffset)];
+        },
+        set(root, a, ao, f, fo) {
+            root.focus({ preventScroll: true });
+            const [an, aoff] = place(root, a, ao);
+            const [fn, foff] = place(root, f, fo);
+            window.getSelection().setBaseAndExtent(an, aoff, fn, foff);
+        },
+    };
+}
+"#;
+
+/// Parse `[ab, ao, fb, fo]` from the selection bridge.
+fn parse_selection(val: &serde_json::Value) -> Option<DocSelection> {
+    let arr = val.as_array()?;
+    let n = |i: usize| arr.get(i).and_then(|v| v.as_u64()).map(|v| v as usize);
+    Some(DocSelection::new(
+        DocPosition::new(n(0)?, n(1)?),
+        DocPosition::new(n(2)?, n(3)?),
+    ))
+}
+
+/// Read the surface's current DOM selection.
+async fn read_selection(surface_id: &str) -> Option<DocSelection> {
+    let js = format!(
+        "{SELECTION_JS}\nconst el = document.getElementById('{surface_id}');\nreturn el ? window.__eqRte.get(el) : null;"
+    );
+    let val = document::eval(&js).await.ok()?;
+    parse_selection(&val)
+}
+
+// Dioxus clipboard events carry no data, so a native listener on the
+// (stable) wrapper captures the clipboard contents and cancels the
+// browser's own insertion; the Rust `onpaste` handler reads them back.
+
+/// Install the native paste listener on the wrapper element.
+fn install_paste_handler(wrapper_id: &str) {
+    let js = format!(
+        r#"
+        const el = document.getElementById('{}');
+        if (el && !el._eqPasteInstalled) {{
+            el._eqPasteInstalled = true;
+            el.addEventListener('paste', (e) => {{
+                if (!e.target.closest || !e.target.closest('[data-eq-surface]')) return;
+                e.preventDefault();
+                el._eqPaste = {{
+                    html: e.clipboardData.getData('text/html'),
+                    text: e.clipboardData.getData('text/plain'),
+                }};
+            }});
+        }}
+        "#,
+        wrapper_id
+    );
+    document::eval(&js);
+}
+
+// ── Editor state ───────────────────────────────────────────────────
+
+/// Signals shared by the editor's event handlers.
+#[derive(Clone, Copy)]
+struct EditorState {
+    /// Live model, kept in sync with the surface.
+    doc: Signal<RichDocument>,
+    /// Model the surface HTML was last rendered from.
+    rendered: Signal<RichDocument>,
+    /// Bumped on every re-render so the surface HTML always changes.
+    render_rev: Signal<u64>,
+    /// Last known selection.
+    selection: Signal<DocSelection>,
+    /// Selection to restore after the next re-render.
+    pending_selection: Signal<Option<DocSelection>>,
+    history: Signal<History>,
+    on_change: Option<EventHandler<RichDocument>>,
+}
+
+impl EditorState {
+    fn snapshot(&self) -> Snapshot {
+        Snapshot { doc: self.doc.peek().clone(), selection: *self.selection.peek() }
+    }
+
+    /// Apply a model edit: record history, re-render, restore the caret.
+    fn commit(mut self, next: RichDocument, sel: DocSelection, kind: EditKind) {
+        if next != *self.doc.peek() {
+            let before = self.snapshot();
+            self.history.write().record(before, kind);
+            if let Some(h) = self.on_change {
+                h.call(next.clone());
+            }
+        }
+        self.show(next, sel);
+    }
+
+    /// Adopt a model read back from the surface after native typing.
+    /// The DOM already shows it, so nothing is re-rendered.
+    fn sync(mut self, next: RichDocument, sel: DocSelection) {
+        if next != *self.doc.peek() {
+            let before = self.snapshot();
+            self.history.write().record(before, EditKind::Typing);
+            self.doc.set(next.clone());
+            if let Some(h) = self.on_change {
+                h.call(next);
+            }
+        }
+        self.selection.set(sel);
+    }
+
+    /// Replace the document without touching history.
+    fn show(mut self, next: RichDocument, sel: DocSelection) {
+        let sel = DocSelection::new(next.clamp(sel.anchor), next.clamp(sel.focus));
+        self.doc.set(next.clone());
+        self.rendered.set(next);
+        self.selection.set(sel);
+        self.pending_selection.set(Some(sel));
+        *self.render_rev.write() += 1;
+    }
+
+    fn undo(self) {
+        let current = self.snapshot();
+        let mut history = self.history;
+        let prev = history.write().undo(current);
+        if let Some(prev) = prev {
+            if let Some(h) = self.on_change {
+                h.call(prev.doc.clone());
+            }
+            self.show(prev.doc, prev.selection);
+        }
+    }
+
+    fn redo(self) {
+        let current = self.snapshot();
+        let mut history = self.history;
+        let next = history.write().redo(current);
+        if let Some(next) = next {
+            if let Some(h) = self.on_change {
+                h.call(next.doc.clone());
+            }
+            self.show(next.doc, next.selection);
+        }
+    }
+}
+
+// ── Component ──────────────────────────────────────────────────────
+
+/// Rich text editor with a Rust document model.
+///
+/// Supports paragraphs, headings, quotes, code blocks and lists; bold,
+/// italic, underline, strikethrough and inline code; and links. The
+/// document is exposed as a [`RichDocument`] via `on_change` and can
+/// be serialized with [`RichDocument::to_html`] or
+/// [`RichDocument::to_markdown`]. Pasted HTML is sanitized down to
+/// what the model can express.
+///
+/// **Keyboard** — Ctrl/Cmd+B, I, U, E (code), Shift+X (strike), K
+/// (link), Z / Shift+Z / Y (undo/redo), Alt+0–3 (paragraph/headings),
+/// Shift+7 / Shift+8 (lists).
+///
+/// **Accessibility** — the surface is `role="textbox"` with
+/// `aria-multiline`; the toolbar is `role="toolbar"` and every button
+/// has an accessible name that includes its shortcut.
+#[playground(
+    category = Organism,
+    description = "WYSIWYG rich text editor over a Rust document model: blocks, inline marks, \
+                   links, undo/redo, sanitized paste, keyboard shortcuts, HTML and Markdown output.",
+    examples = [
+        ("Basic", "let mut doc = use_signal(RichDocument::new);\n\nEqRichTextEditor {\n    placeholder: \"Write something…\",\n    on_change: move |d: RichDocument| doc.set(d),\n}\n\n// Later: doc().to_html() or doc().to_markdown()"),
+        ("Initial content", "EqRichTextEditor {\n    initial_value: RichDocument::from_html(\"<h2>Notes</h2><p>Hello <strong>world</strong></p>\"),\n}"),
+        ("Read-only", "EqRichTextEditor {\n    initial_value: doc(),\n    readonly: true,\n    show_toolbar: false,\n}"),
+    ],
+    custom_demo,
+    custom_gallery,
+)]
+#[component]
+pub fn EqRichTextEditor(
+    /// Document shown when the editor mounts. Later changes to this
+    /// prop are ignored; the editor owns its document after mount.
+    #[props(default)]
+    initial_value: RichDocument,
+    /// Fired with the full document after every edit.
+    #[props(default)]
+    on_change: Option<EventHandler<RichDocument>>,
+    /// Placeholder text shown while the document is empty.
+    #[props(into, default)]
+    placeholder: String,
+    /// Disables editing (the toolbar is hidden too).
+    #[props(default = false)]
+    readonly: bool,
+    /// Show the formatting toolbar.
+    #[props(default = true)]
+    show_toolbar: bool,
+    /// Accessible label for the editing surface.
+    #[props(into, default = "Rich text editor".to_string())]
+    aria_label: String,
+    /// Optional class override on the wrapper.
+    #[props(into, default)]
+    class: String,
+) -> Element {
+    let (wrapper_id, surface_id) = use_hook(|| {
+        static COUNTER: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+        let id = COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        (format!("eq-rte-{id}"), format!("eq-rte-surface-{id}"))
+    });
+
+    let initial = initial_value.clone();
+    let doc = use_signal(|| initial.clone());
+    let rendered = use_signal(|| initial_value.clone());
+    let render_rev = use_signal(|| 0u64);
+    let selection = use_signal(|| DocSelection::caret(DocPosition::default()));
+    let pending_selection = use_signal(|| None::<DocSelection>);
+    let history = use_signal(History::default);
+    let mut link_open = use_signal(|| false);
+    let mut link_draft = use_signal(String::new);
+    let mut link_error = use_signal(|| false);
+    let platform = use_platform();
+
+    let state = EditorState { doc, rendered, render_rev, selection, pending_selection, history, on_change };
+
+    // Install the paste listener once the wrapper exists.
+    let wrapper_id_effect = wrapper_id.clone();
+    use_effect(move || {
+        install_paste_handler(&wrapper_id_effect);
+    });
+
+    // After a re-render, put the caret back where the edit left it.
+    let surface_id_effect = surface_id.clone();
+    let mut pending_effect = pending_selection;
+    use_effect(move || {
+        let _rev = render_rev();
+        let Some(sel) = pending_effect.write().take() else { return };
+        let js = format!(
+            "{SELECTION_JS}\nconst el = document.getElementById('{}');\nif (el) window.__eqRte.set(el, {}, {}, {}, {});",
+            surface_id_effect, sel.anchor.block, sel.anchor.offset, sel.focus.block, sel.focus.offset,
+        );
+        document::eval(&js);
+    });
+
+    let run = use_callback(move |cmd: EditorCommand| {
+        if readonly {
+            return;
+        }
+        let sel = *state.selection.peek();
+        match cmd {
+            EditorCommand::ToggleMark(mark) => {
+                let mut d = state.doc.peek().clone();
+                d.toggle_mark(sel, mark);
+                state.commit(d, sel, EditKind::Other);
+            }
+            EditorCommand::SetBlock(kind) => {
+                let mut d = state.doc.peek().clone();
+                // Choosing the current type again reverts to a paragraph.
+                let target = if kind != BlockKind::Paragraph && d.block_kind_at(sel.focus) == kind {
+                    BlockKind::Paragraph
+                } else {
+                    kind
+                };
+                d.set_block_kind(sel, target);
+                state.commit(d, sel, EditKind::Other);
+            }
+            EditorCommand::Link => {
+                link_draft.set(state.doc.peek().link_in(sel).unwrap_or_default());
+                link_error.set(false);
+                link_open.set(true);
+            }
+            EditorCommand::Undo => state.undo(),
+            EditorCommand::Redo => state.redo(),
+        }
+    });
+
+    let apply_link = use_callback(move |_: ()| {
+        let sel = *state.selection.peek();
+        let mut d = state.doc.peek().clone();
+        if d.set_link(sel, Some(link_draft.peek().trim())) {
+            link_open.set(false);
+            state.commit(d, sel, EditKind::Other);
+        } else {
+            link_error.set(true);
+        }
+    });
+    let remove_link = use_callback(move |_: ()| {
+        let sel = *state.selection.peek();
+        let mut d = state.doc.peek().clone();
+        d.set_link(sel, None);
+        link_open.set(false);
+        state.commit(d, sel, EditKind::Other);
+    });
+    let cancel_link = use_callback(move |_: ()| link_open.set(false));
+
+    let current = doc();
+    let sel = selection();
+    let toolbar_state = ToolbarState {
+        marks: current.marks_in(sel),
+        block: current.block_kind_at(sel.focus),
+        link_active: current.link_in(sel).is_some(),
+        can_undo: history.read().can_undo(),
+        can_redo: history.read().can_redo(),
+        platform,
+    };
+    let has_link = toolbar_state.link_active;
+
+    // The revision comment forces a fresh innerHTML even when the model
+    // renders to the same markup the surface started from.
+    let html = format!("<!--{}-->{}", render_rev(), render_html(&rendered.read(), true));
+    let surface_cls = if readonly { format!("{} {}", s::SURFACE, s::SURFACE_READONLY) } else { s::SURFACE.to_string() };
+    let wrapper_cls = merge_classes(s::WRAPPER, &class);
+
+    let sid_keydown = surface_id.clone();
+    let sid_keyup = surface_id.clone();
+    let sid_mouseup = surface_id.clone();
+    let sid_input = surface_id.clone();
+    let sid_paste = wrapper_id.clone();
+    let sid_paste_sel = surface_id.clone();
+
+    rsx! {
+        div { id: "{wrapper_id}", class: "{wrapper_cls}",
+
+            if show_toolbar && !readonly {
+                {render_toolbar(toolbar_state, run)}
+            }
+            if link_open() && !readonly {
+                {render_link_bar(link_draft, link_error(), has_link, apply_link, remove_link, cancel_link)}
+            }
+
+            div { class: s::SURFACE_AREA,
+                if current.is_empty() && !placeholder.is_empty() {
+                    span { class: s::PLACEHOLDER, "aria-hidden": "true", "{placeholder}" }
+                }
+                div {
+                    id: "{surface_id}",
+                    class: "{surface_cls}",
+                    contenteditable: if readonly { "false" } else { "true" },
+                    role: "textbox",
+                    spellcheck: "true",
+                    "aria-multiline": "true",
+                    "aria-label": "{aria_label}",
+                    "aria-readonly": "{readonly}",
+                    "aria-placeholder": "{placeholder}",
+                    "data-eq-surface": "true",
+                    dangerous_inner_html: "{html}",
+
+                    onkeydown: move |evt: KeyboardEvent| {
+                        if readonly {
+                            return;
+                        }
+                        let mods = evt.modifiers();
+                        let m = KeyModifiers {
+                            ctrl: mods.ctrl(),
+                            meta: mods.meta(),
+                            shift: mods.shift(),
+                            alt: mods.alt(),
+                        };
+                        let sid = sid_keydown.clone();
+                        if evt.key() == Key::Enter && !m.ctrl && !m.meta {
+                            // Block splits are structural: do them in the model.
+                            evt.prevent_default();
+                            spawn(async move {
+                                let sel = read_selection(&sid).await.unwrap_or(*state.selection.peek());
+                                let mut d = state.doc.peek().clone();
+                                let caret = d.delete_range(sel);
+                                let pos = d.split_block(caret);
+                                state.commit(d, DocSelection::caret(pos), EditKind::Other);
+                            });
+                            return;
+                        }
+                        let key = evt.key().to_string();
+                        let code = evt.code().to_string();
+                        if let Some(cmd) = command_for_key(&key, &code, m) {
+                            evt.prevent_default();
+                            let mut selection = state.selection;
+                            spawn(async move {
+                                if let Some(sel) = read_selection(&sid).await {
+                                    selection.set(sel);
+                                }
+                                run.call(cmd);
+                            });
+                        }
+                    },
+                    onkeyup: move |evt: KeyboardEvent| {
+                        let sid = sid_keyup.clone();
+                        let navigation = matches!(
+                            evt.key(),
+                            Key::ArrowLeft | Key::ArrowRight | Key::ArrowUp | Key::ArrowDown
+                                | Key::Home | Key::End | Key::PageUp | Key::PageDown
+                        );
+                        let mut selection = state.selection;
+                        let mut history = state.history;
+                        spawn(async move {
+                            if let Some(sel) = read_selection(&sid).await {
+                                selection.set(sel);
+                            }
+                            if navigation {
+                                history.write().break_group();
+                            }
+                        });
+                    },
+                    onmouseup: move |_| {
+                        let sid = sid_mouseup.clone();
+                        let mut selection = state.selection;
+                        let mut history = state.history;
+                        spawn(async move {
+                            if let Some(sel) = read_selection(&sid).await {
+                                selection.set(sel);
+                            }
+                            history.write().break_group();
+                        });
+                    },
+                    oninput: move |_| {
+                        let sid = sid_input.clone();
+                        spawn(async move {
+                            let js = format!(
+                                "{SELECTION_JS}\nconst el = document.getElementById('{sid}');\n\
+                                 if (!el) return null;\n\
+                                 return {{ html: el.innerHTML, sel: window.__eqRte.get(el) }};"
+                            );
+                            let Ok(val) = document::eval(&js).await else { return };
+                            let Some(html) = val.get("html").and_then(|v| v.as_str()) else { return };
+                            let next = parse_html(html);
+                            let sel = val
+                                .get("sel")
+                                .and_then(parse_selection)
+                                .unwrap_or_else(|| DocSelection::caret(next.end()));
+                            state.sync(next, sel);
+                        });
+                    },
+                    onpaste: move |_| {
+                        if readonly {
+                            return;
+                        }
+                        let wid = sid_paste.clone();
+                        let sid = sid_paste_sel.clone();
+                        spawn(async move {
+                            let js = format!(
+                                "const el = document.getElementById('{wid}');\n\
+                                 const data = el && el._eqPaste;\n\
+                                 if (el) el._eqPaste = null;\n\
+                                 return data || null;"
+                            );
+                            let Ok(val) = document::eval(&js).await else { return };
+                            let html = val.get("html").and_then(|v| v.as_str()).unwrap_or("");
+                            let text = val.get("text").and_then(|v| v.as_str()).unwrap_or("");
+                            if html.is_empty() && text.is_empty() {
+                                return;
+                            }
+                            let sel = read_selection(&sid).await.unwrap_or(*state.selection.peek());
+                            let mut d = state.doc.peek().clone();
+                            let caret = d.delete_range(sel);
+                            // Code keeps its exact text; elsewhere prefer rich HTML.
+                            let fragment = if html.is_empty() || d.block_kind_at(caret) == BlockKind::CodeBlock {
+                                RichDocument::from_plain_text(text)
+                            } else {
+                                parse_html(html)
+                            };
+                            let pos = d.insert_fragment(caret, &fragment);
+                            state.commit(d, DocSelection::caret(pos), EditKind::Other);
+                        });
+                    },
+                }
+            }
+        }
+    }
+}
+
+// ── Interactive demo ───────────────────────────────────────────────
+
+#[cfg(feature = "playground")]
+const DEMO_HTML: &str = "<h2>Release notes</h2>\
+    <p>The editor keeps a <strong>Rust document model</strong> in sync with the page, \
+    so you can read it back as <em>HTML</em> or <code>Markdown</code>.</p>\
+    <ul><li>Select text and press Ctrl+B</li><li>Paste from a word processor</li></ul>\
+    <blockquote><p>Links are checked: <a href=\"https://dioxuslabs.com\">dioxuslabs.com</a></p></blockquote>";
+
+#[cfg(feature = "playground")]
+#[component]
+fn DemoEqRichTextEditor() -> Element {
+    let mut doc = use_signal(|| RichDocument::from_html(DEMO_HTML));
+    let mut readonly = use_signal(|| false);
+    let mut show_toolbar = use_signal(|| true);
+    let mut placeholder = use_signal(|| "Start writing…".to_string());
+    let mut output_tab = use_signal(|| 0usize);
+
+    let output = match output_tab() {
+        1 => doc().to_html(),
+        2 => doc().plain_text(),
+        _ => doc().to_markdown(),
+    };
+
+    let code = r#"let mut doc = use_signal(RichDocument::new);
+
+EqRichTextEditor {
+    placeholder: "Start writing…",
+    on_change: move |d: RichDocument| doc.set(d),
+}
+
+// Serialize whenever you need it:
+let html = doc().to_html();
+let markdown = doc().to_markdown();"#.to_string();
+
+    rsx! {
+        DemoSection { title: "EqRichTextEditor",
+            div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-3",
+                EqText {
+                    variant: TextVariant::Caption,
+                    class: "font-semibold uppercase tracking-wider",
+                    "Props"
+                }
+                PropToggle {
+                    label: "readonly",
+                    value: readonly(),
+                    onchange: move |v: bool| readonly.set(v),
+                }
+                PropToggle {
+                    label: "show_toolbar",
+                    value: show_toolbar(),
+                    onchange: move |v: bool| show_toolbar.set(v),
+                }
+                PropInput {
+                    label: "placeholder",
+                    value: placeholder(),
+                    placeholder: "Shown when empty",
+                    onchange: move |v: String| placeholder.set(v),
+                }
+            }
+
+            div { class: "rounded-lg border border-dashed border-[var(--color-card-border)] p-6 space-y-4",
+                EqRichTextEditor {
+                    initial_value: RichDocument::from_html(DEMO_HTML),
+                    readonly: readonly(),
+                    show_toolbar: show_toolbar(),
+                    placeholder: placeholder(),
+                    on_change: move |d: RichDocument| doc.set(d),
+                }
+
+                EqTab {
+                    tabs: vec![
+                        TabItem::new("Markdown"),
+                        TabItem::new("HTML"),
+                        TabItem::new("Plain text"),
+                    ],
+                    active: output_tab(),
+                    variant: TabVariant::Pill,
+                    on_change: move |i: usize| output_tab.set(i),
+                }
+                pre { class: "text-xs whitespace-pre-wrap break-words rounded-lg p-3 \
+                              bg-[var(--color-code-bg)] text-[var(--color-code-text)]",
+                    "{output}"
+                }
+            }
+
+            StyleInfo { file: "eq_rich_text_editor/styles.rs", styles: format_catalog(&s::catalog()) }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
+        }
+    }
+}
+
+// ── Gallery ────────────────────────────────────────────────────────
+
+#[cfg(feature = "playground")]
+#[component]
+fn GalleryEqRichTextEditor() -> Element {
+    rsx! {
+        div { class: "space-y-4",
+            div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-4",
+                EqText { variant: TextVariant::Caption, class: "font-semibold uppercase tracking-wider", "RichTextEditor Gallery" }
+
+                div { class: "grid gap-6 md:grid-cols-2",
+                    div { class: "space-y-1",
+                        EqText { variant: TextVariant::Muted, "Empty with placeholder" }
+                        EqRichTextEditor { placeholder: "Write a comment…" }
+                    }
+                    div { class: "space-y-1",
+                        EqText { variant: TextVariant::Muted, "Without toolbar (shortcuts only)" }
+                        EqRichTextEditor {
+                            show_toolbar: false,
+                            initial_value: RichDocument::from_html("<p>Try <strong>Ctrl+B</strong> or <em>Ctrl+I</em>.</p>"),
+                        }
+                    }
+                    div { class: "space-y-1 md:col-span-2",
+                        EqText { variant: TextVariant::Muted, "Read-only" }
+                        EqRichTextEditor {
+                            readonly: true,
+                            initial_value: RichDocument::from_html(DEMO_HTML),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── Smoke tests ─────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smoke_renders() {
+        let mut dom = VirtualDom::new(|| rsx! { EqRichTextEditor {} });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn smoke_renders_with_content_readonly() {
+        let mut dom = VirtualDom::new(|| rsx! {
+            EqRichTextEditor {
+                initial_value: RichDocument::from_html("<h1>T</h1><p>a <b>b</b></p><ol><li>x</li></ol>"),
+                readonly: true,
+                placeholder: "Empty",
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn smoke_renders_without_toolbar() {
+        let mut dom = VirtualDom::new(|| rsx! {
+            EqRichTextEditor { show_toolbar: false, placeholder: "Write…" }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn parses_bridge_selection() {
+        let val = serde_json::json!([1, 2, 0, 5]);
+        let sel = parse_selection(&val).unwrap();
+        assert_eq!(sel.anchor, DocPosition::new(1, 2));
+        assert_eq!(sel.focus, DocPosition::new(0, 5));
+        assert!(parse_selection(&serde_json::Value::Null).is_none());
+        assert!(parse_selection(&serde_json::json!([1, 2])).is_none());
+    }
+}
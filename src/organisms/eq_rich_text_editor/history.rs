@@ -0,0 +1,171 @@
+//! Undo/redo history for EqRichTextEditor.
+//!
+//! Snapshot based: every recorded edit stores the document (and caret)
+//! as it was *before* the edit. Consecutive typing is coalesced into a
+//! single undo step so `Ctrl+Z` removes a word or phrase rather than
+//! one character at a time.
+
+use super::document::{DocSelection, RichDocument};
+
+/// What kind of edit produced a history entry.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EditKind {
+    /// Plain typing — merged with the previous typing entry.
+    Typing,
+    /// Any other edit (formatting, paste, block changes, Enter…).
+    Other,
+}
+
+/// One restorable state.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Snapshot {
+    pub doc: RichDocument,
+    pub selection: DocSelection,
+}
+
+/// Bounded undo/redo stacks.
+#[derive(Clone, PartialEq, Debug)]
+pub struct History {
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    /// Maximum number of undo steps kept.
+    limit: usize,
+    /// Typing edits merged into the current top entry.
+    typing_run: usize,
+    last_kind: Option<EditKind>,
+}
+
+/// Typing edits merged into one undo step before a new step starts.
+const TYPING_GROUP: usize = 20;
+
+impl Default for History {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
+impl History {
+    /// Empty history keeping at most `limit` undo steps.
+    pub fn new(limit: usize) -> Self {
+        Self { undo: Vec::new(), redo: Vec::new(), limit: limit.max(1), typing_run: 0, last_kind: None }
+    }
+
+    /// Records the state *before* an edit of the given kind. Clears the
+    /// redo stack.
+    pub fn record(&mut self, before: Snapshot, kind: EditKind) {
+        self.redo.clear();
+        let coalesce = kind == EditKind::Typing
+            && self.last_kind == Some(EditKind::Typing)
+            && self.typing_run < TYPING_GROUP
+            && !self.undo.is_empty();
+        if coalesce {
+            self.typing_run += 1;
+        } else {
+            self.undo.push(before);
+            self.typing_run = 1;
+            if self.undo.len() > self.limit {
+                self.undo.remove(0);
+            }
+        }
+        self.last_kind = Some(kind);
+    }
+
+    /// Ends the current typing group so the next edit starts a new
+    /// undo step (e.g. after the caret moves).
+    pub fn break_group(&mut self) {
+        self.last_kind = None;
+    }
+
+    /// Pops the last undo step, pushing `current` onto the redo stack.
+    pub fn undo(&mut self, current: Snapshot) -> Option<Snapshot> {
+        let prev = self.undo.pop()?;
+        self.redo.push(current);
+        self.last_kind = None;
+        Some(prev)
+    }
+
+    /// Pops the last redo step, pushing `current` onto the undo stack.
+    pub fn redo(&mut self, current: Snapshot) -> Option<Snapshot> {
+        let next = self.redo.pop()?;
+        self.undo.push(current);
+        self.last_kind = None;
+        Some(next)
+    }
+
+    /// Whether an undo step is available.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Whether a redo step is available.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::organisms::eq_rich_text_editor::document::DocPosition;
+
+    fn snap(text: &str) -> Snapshot {
+        Snapshot {
+            doc: RichDocument::from_plain_text(text),
+            selection: DocSelection::caret(DocPosition::new(0, text.chars().count())),
+        }
+    }
+
+    #[test]
+    fn typing_is_coalesced() {
+        let mut h = History::default();
+        h.record(snap(""), EditKind::Typing);
+        h.record(snap("a"), EditKind::Typing);
+        h.record(snap("ab"), EditKind::Typing);
+        let restored = h.undo(snap("abc")).unwrap();
+        assert_eq!(restored.doc.plain_text(), "");
+        assert!(!h.can_undo());
+    }
+
+    #[test]
+    fn other_edits_are_separate_steps() {
+        let mut h = History::default();
+        h.record(snap("a"), EditKind::Other);
+        h.record(snap("b"), EditKind::Other);
+        assert_eq!(h.undo(snap("c")).unwrap().doc.plain_text(), "b");
+        assert_eq!(h.undo(snap("b")).unwrap().doc.plain_text(), "a");
+    }
+
+    #[test]
+    fn break_group_starts_new_typing_step() {
+        let mut h = History::default();
+        h.record(snap(""), EditKind::Typing);
+        h.break_group();
+        h.record(snap("a"), EditKind::Typing);
+        assert_eq!(h.undo(snap("ab")).unwrap().doc.plain_text(), "a");
+    }
+
+    #[test]
+    fn redo_restores_and_new_edit_clears_it() {
+        let mut h = History::default();
+        h.record(snap("a"), EditKind::Other);
+        let prev = h.undo(snap("ab")).unwrap();
+        assert!(h.can_redo());
+        assert_eq!(h.redo(prev.clone()).unwrap().doc.plain_text(), "ab");
+        h.undo(snap("ab"));
+        h.record(prev, EditKind::Other);
+        assert!(!h.can_redo());
+    }
+
+    #[test]
+    fn limit_drops_oldest() {
+        let mut h = History::new(2);
+        h.record(snap("1"), EditKind::Other);
+        h.record(snap("2"), EditKind::Other);
+        h.record(snap("3"), EditKind::Other);
+        assert_eq!(h.undo(snap("4")).unwrap().doc.plain_text(), "3");
+        assert_eq!(h.undo(snap("3")).unwrap().doc.plain_text(), "2");
+        assert!(h.undo(snap("2")).is_none());
+    }
+}
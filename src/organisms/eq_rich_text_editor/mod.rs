@@ -0,0 +1,28 @@
+//! EqRichTextEditor organism - WYSIWYG editing over a Rust document model.
+//!
+//! Split into focused modules for maintainability:
+//!
+//! - **document** - `RichDocument` model (blocks, runs, marks, links) and editing operations
+//! - **serialize** - HTML and Markdown output
+//! - **sanitize** - tolerant HTML parser used for paste and DOM read-back, on top of [`crate::sanitize`]
+//! - **history** - snapshot undo/redo with typing coalescing
+//! - **commands** - editor commands and the keyboard shortcut table
+//! - **styles** - co-located Tailwind class constants
+//! - **toolbar** - formatting toolbar rendering
+//! - **editor** - the `EqRichTextEditor` component that orchestrates everything
+
+pub mod document;
+pub mod serialize;
+pub mod sanitize;
+pub mod history;
+pub mod commands;
+pub mod styles;
+
+mod toolbar;
+pub mod editor;
+
+pub use document::{
+    Block, BlockKind, DocPosition, DocSelection, Mark, Marks, RichDocument, TextRun,
+};
+pub use commands::EditorCommand;
+pub use editor::EqRichTextEditor;
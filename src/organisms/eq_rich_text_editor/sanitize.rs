@@ -0,0 +1,385 @@
+//! HTML → [`RichDocument`] parsing with sanitization.
+//!
+//! Used for pasted content and for reading the editor's DOM back into
+//! the model. Input first goes through the crate-wide
+//! [`sanitize_html`] allowlist, so scripts, frames, event handlers and
+//! unsafe URLs are gone before parsing starts. Of what remains, only
+//! the structure the document model can express survives: block tags,
+//! inline marks and links.
+//!
+//! The parser is deliberately forgiving — unclosed tags, stray closing
+//! tags and unknown elements are tolerated, since pasted HTML from
+//! word processors is rarely well-formed.
+
+use super::document::{Block, BlockKind, Marks, RichDocument, TextRun};
+use crate::sanitize::{decode_entities, parse_attrs, read_tag, safe_url, sanitize_html};
+
+impl RichDocument {
+    /// Parses (and sanitizes) an HTML fragment into a document.
+    pub fn from_html(html: &str) -> Self {
+        parse_html(html)
+    }
+}
+
+/// Elements that start a new block.
+const BLOCKS: &[&str] = &[
+    "p", "div", "h1", "h2", "h3", "h4", "h5", "h6", "blockquote", "pre",
+    "li", "ul", "ol", "section", "article", "header", "footer", "table",
+    "tr", "hr",
+];
+
+#[derive(Clone)]
+struct Frame {
+    tag: String,
+    marks: Marks,
+    link: Option<String>,
+    kind: BlockKind,
+    in_pre: bool,
+    /// Number of finished blocks when this element opened.
+    blocks_at_open: usize,
+}
+
+struct Parser {
+    blocks: Vec<Block>,
+    current: Option<Block>,
+    stack: Vec<Frame>,
+    marks: Marks,
+    link: Option<String>,
+    kind: BlockKind,
+    in_pre: bool,
+}
+
+/// Parses an HTML fragment into a sanitized document.
+pub fn parse_html(html: &str) -> RichDocument {
+    let mut p = Parser {
+        blocks: Vec::new(),
+        current: None,
+        stack: Vec::new(),
+        marks: Marks::default(),
+        link: None,
+        kind: BlockKind::Paragraph,
+        in_pre: false,
+    };
+
+    let clean = sanitize_html(html);
+    let mut rest = clean.as_str();
+    while !rest.is_empty() {
+        if let Some(tag) = read_tag(rest) {
+            rest = &rest[tag.len..];
+            if tag.closing {
+                p.close(&tag.name);
+            } else {
+                p.open(&tag.name, &parse_attrs(tag.attrs));
+            }
+            continue;
+        }
+        // Text up to the next tag; the sanitizer escaped any stray '<'.
+        let first = rest.chars().next().map_or(1, char::len_utf8);
+        let next = rest[first..].find('<').map(|i| i + first).unwrap_or(rest.len());
+        p.text(&decode_entities(&rest[..next]));
+        rest = &rest[next..];
+    }
+
+    p.flush(false);
+    RichDocument::from_blocks(p.blocks)
+}
+
+impl Parser {
+    /// Opens `tag`. The sanitizer only emits `br`, `hr` and `img` as
+    /// void elements, so every other tag gets a matching close.
+    fn open(&mut self, tag: &str, attrs: &[(String, String)]) {
+        let attr = |name: &str| attrs.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str());
+        match tag {
+            "br" => {
+                self.flush(true);
+                return;
+            }
+            "img" => return,
+            "hr" => {
+                self.flush(false);
+                return;
+            }
+            _ => {}
+        }
+
+        let frame = Frame {
+            tag: tag.to_string(),
+            marks: self.marks,
+            link: self.link.clone(),
+            kind: self.kind,
+            in_pre: self.in_pre,
+            blocks_at_open: self.blocks.len(),
+        };
+
+        if BLOCKS.contains(&tag) {
+            self.flush(false);
+            match tag {
+                "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                    self.kind = BlockKind::Heading(tag.as_bytes()[1] - b'0');
+                }
+                "blockquote" => self.kind = BlockKind::Quote,
+                "pre" => {
+                    self.kind = BlockKind::CodeBlock;
+                    self.in_pre = true;
+                }
+                "ul" => self.kind = BlockKind::BulletItem,
+                "ol" => self.kind = BlockKind::OrderedItem,
+                "li" if !self.kind.is_list() => self.kind = BlockKind::BulletItem,
+                _ => {}
+            }
+        }
+
+        match tag {
+            "strong" | "b" => self.marks.bold = true,
+            "em" | "i" => self.marks.italic = true,
+            "u" | "ins" => self.marks.underline = true,
+            "s" | "strike" | "del" => self.marks.strike = true,
+            "code" | "kbd" | "samp" | "tt" if !self.in_pre => self.marks.code = true,
+            "a" => {
+                if let Some(href) = attr("href").and_then(safe_url) {
+                    self.link = Some(href);
+                }
+            }
+            _ => {}
+        }
+        if let Some(style) = attr("style") {
+            apply_inline_style(&mut self.marks, style);
+        }
+
+        self.stack.push(frame);
+    }
+
+    fn close(&mut self, tag: &str) {
+        // Find the matching open element; ignore stray closing tags.
+        let Some(depth) = self.stack.iter().rposition(|f| f.tag == tag) else { return };
+        let frame = self.stack[depth].clone();
+        self.stack.truncate(depth);
+
+        if BLOCKS.contains(&tag) && !matches!(tag, "ul" | "ol") {
+            // An element that produced no blocks of its own (e.g.
+            // `<p></p>`) still represents one empty line.
+            let produced = self.blocks.len() > frame.blocks_at_open || self.current.is_some();
+            if produced || matches!(tag, "div" | "section" | "article" | "header" | "footer" | "table" | "tr") {
+                self.flush(false);
+            } else {
+                self.blocks.push(Block::new(self.kind));
+            }
+        } else if matches!(tag, "ul" | "ol") {
+            self.flush(false);
+        }
+
+        self.marks = frame.marks;
+        self.link = frame.link;
+        self.kind = frame.kind;
+        self.in_pre = frame.in_pre;
+    }
+
+    fn text(&mut self, raw: &str) {
+        if raw.is_empty() {
+            return;
+        }
+        if self.in_pre {
+            for (i, line) in raw.split('\n').enumerate() {
+                if i > 0 {
+                    self.flush(true);
+                }
+                if !line.is_empty() {
+                    self.push_text(line.to_string());
+                }
+            }
+            return;
+        }
+
+        // Collapse whitespace like a browser would; &nbsp; (already
+        // decoded to U+00A0) survives collapsing and trimming and only
+        // becomes a plain space when the block is flushed.
+        let mut collapsed = String::with_capacity(raw.len());
+        let mut prev_space = self
+            .current
+            .as_ref()
+            .map(|b| b.plain_text().ends_with(' ') || b.is_empty())
+            .unwrap_or(true);
+        for ch in raw.chars() {
+            if ch.is_whitespace() && ch != '\u{a0}' {
+                if !prev_space {
+                    collapsed.push(' ');
+                }
+                prev_space = true;
+            } else {
+                collapsed.push(ch);
+                prev_space = false;
+            }
+        }
+        if collapsed.is_empty() {
+            return;
+        }
+        self.push_text(collapsed);
+    }
+
+    fn push_text(&mut self, text: String) {
+        let kind = self.kind;
+        let block = self.current.get_or_insert_with(|| Block::new(kind));
+        block.runs.push(TextRun { text, marks: self.marks, link: self.link.clone() });
+    }
+
+    /// Finishes the current block. With `force`, an empty block is
+    /// emitted even when no text was collected (a `<br>` line).
+    fn flush(&mut self, force: bool) {
+        match self.current.take() {
+            Some(mut block) => {
+                // Trailing collapsible space is invisible in HTML.
+                if !self.in_pre {
+                    let last_len = block.runs.last().map(|r| r.text.trim_end_matches(' ').len());
+                    if let Some(trimmed) = last_len {
+                        if trimmed == 0 && block.runs.len() > 1 {
+                            block.runs.pop();
+                        } else if let Some(last) = block.runs.last_mut() {
+                            last.text.truncate(trimmed);
+                        }
+                    }
+                }
+                // Non-breaking spaces survived collapsing; store them as
+                // plain spaces.
+                for run in &mut block.runs {
+                    if run.text.contains('\u{a0}') {
+                        run.text = run.text.replace('\u{a0}', " ");
+                    }
+                }
+                self.blocks.push(block);
+            }
+            None if force => self.blocks.push(Block::new(self.kind)),
+            None => {}
+        }
+    }
+}
+
+/// Maps inline CSS emitted by word processors (Google Docs, Word) to marks.
+fn apply_inline_style(marks: &mut Marks, style: &str) {
+    for decl in style.split(';') {
+        let Some((prop, value)) = decl.split_once(':') else { continue };
+        let prop = prop.trim().to_ascii_lowercase();
+        let value = value.trim().to_ascii_lowercase();
+        match prop.as_str() {
+            "font-weight" => {
+                let heavy = value == "bold"
+                    || value == "bolder"
+                    || value.parse::<u16>().map(|w| w >= 600).unwrap_or(false);
+                // Google Docs wraps whole pastes in `font-weight:normal`.
+                marks.bold = heavy;
+            }
+            "font-style" => marks.italic = value == "italic" || value == "oblique",
+            "text-decoration" | "text-decoration-line" => {
+                if value.contains("underline") {
+                    marks.underline = true;
+                }
+                if value.contains("line-through") {
+                    marks.strike = true;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_blocks_and_marks() {
+        let doc = RichDocument::from_html(
+            "<h1>Title</h1><p>Hello <b>bold</b> and <em>it</em></p><ul><li>a</li><li>b</li></ul>",
+        );
+        assert_eq!(doc.blocks.len(), 4);
+        assert_eq!(doc.blocks[0].kind, BlockKind::Heading(1));
+        assert_eq!(doc.blocks[1].plain_text(), "Hello bold and it");
+        assert!(doc.blocks[1].runs[1].marks.bold);
+        assert!(doc.blocks[1].runs[3].marks.italic);
+        assert_eq!(doc.blocks[2].kind, BlockKind::BulletItem);
+        assert_eq!(doc.blocks[3].plain_text(), "b");
+    }
+
+    #[test]
+    fn strips_scripts_handlers_and_unsafe_links() {
+        let doc = RichDocument::from_html(
+            "<p onclick=\"evil()\">safe<script>alert(1)</script> \
+             <a href=\"javascript:alert(1)\">bad</a> \
+             <a href=\"https://ok.example\">good</a><img src=x onerror=alert(1)></p>\
+             <style>p{}</style><iframe src=\"x\"><p>inner</p></iframe>",
+        );
+        assert_eq!(doc.plain_text(), "safe bad good");
+        let links: Vec<_> = doc.blocks[0].runs.iter().filter_map(|r| r.link.clone()).collect();
+        assert_eq!(links, vec!["https://ok.example".to_string()]);
+        assert!(!doc.to_html().contains("script"));
+        assert!(!doc.to_html().contains("onclick"));
+    }
+
+    #[test]
+    fn br_splits_blocks_and_empty_paragraphs_survive() {
+        let doc = RichDocument::from_html("<p>a<br>b</p><p><br></p><p>c<br></p>");
+        assert_eq!(doc.plain_text(), "a\nb\n\nc");
+    }
+
+    #[test]
+    fn collapses_whitespace_but_keeps_nbsp() {
+        let doc = RichDocument::from_html("<p>  a \n\t b&nbsp;&nbsp;c  </p>\n  <p>d</p>");
+        assert_eq!(doc.plain_text(), "a b  c\nd");
+    }
+
+    #[test]
+    fn pre_keeps_whitespace_and_splits_lines() {
+        let doc = RichDocument::from_html("<pre><code>fn main() {\n    x();\n}</code></pre>");
+        assert_eq!(doc.blocks.len(), 3);
+        assert!(doc.blocks.iter().all(|b| b.kind == BlockKind::CodeBlock));
+        assert_eq!(doc.blocks[1].plain_text(), "    x();");
+        assert!(!doc.blocks[1].runs[0].marks.code);
+    }
+
+    #[test]
+    fn decodes_entities() {
+        let doc = RichDocument::from_html("<p>&lt;tag&gt; &amp; &#65;&#x42; &bogus; a&b</p>");
+        assert_eq!(doc.plain_text(), "<tag> & AB &bogus; a&b");
+    }
+
+    #[test]
+    fn google_docs_style_spans_map_to_marks() {
+        let doc = RichDocument::from_html(
+            "<b style=\"font-weight:normal\"><p><span style=\"font-weight:700\">B</span>\
+             <span style=\"font-style:italic;text-decoration:underline\">I</span>plain</p></b>",
+        );
+        let runs = &doc.blocks[0].runs;
+        assert!(runs[0].marks.bold);
+        assert!(runs[1].marks.italic && runs[1].marks.underline && !runs[1].marks.bold);
+        assert!(!runs[2].marks.bold);
+    }
+
+    #[test]
+    fn tolerates_malformed_markup() {
+        let doc = RichDocument::from_html("<p>open <b>bold</i> text</p></div>< 3 <unclosed");
+        assert_eq!(doc.blocks[0].plain_text(), "open bold text");
+        assert_eq!(doc.blocks[1].plain_text(), "< 3 <unclosed");
+    }
+
+    #[test]
+    fn quotes_and_nested_lists() {
+        let doc = RichDocument::from_html("<blockquote><p>q1</p><p>q2</p></blockquote><ol><li>x</li></ol>");
+        assert_eq!(doc.blocks[0].kind, BlockKind::Quote);
+        assert_eq!(doc.blocks[1].kind, BlockKind::Quote);
+        assert_eq!(doc.blocks[2].kind, BlockKind::OrderedItem);
+    }
+
+    #[test]
+    fn non_ascii_text_at_block_start() {
+        let doc = RichDocument::from_html("<p>Über <b>élan</b></p><p>🎉 party</p>");
+        assert_eq!(doc.plain_text(), "Über élan\n🎉 party");
+    }
+
+    #[test]
+    fn round_trips_own_html() {
+        let html = "<h2>T</h2><p>a <strong><em>b</em></strong> <a href=\"/x\">c</a></p><ol><li>1</li></ol>";
+        let doc = RichDocument::from_html(html);
+        assert_eq!(doc.to_html(), html);
+    }
+}
@@ -0,0 +1,433 @@
+//! HTML and Markdown serialization for [`RichDocument`].
+//!
+//! Output is deterministic: the same document always produces the same
+//! string, so serialized content can be diffed and stored as-is.
+
+use super::document::{Block, BlockKind, RichDocument, TextRun};
+use crate::sanitize::safe_url;
+
+impl RichDocument {
+    /// Serializes the document to an HTML fragment.
+    ///
+    /// Consecutive list items are wrapped in `<ul>` / `<ol>` and
+    /// consecutive code lines share one `<pre><code>`. Text is escaped
+    /// and link targets are re-checked with [`safe_url`].
+    pub fn to_html(&self) -> String {
+        render_html(self, false)
+    }
+
+    /// Serializes the document to CommonMark (with `~~strike~~` and
+    /// `<u>` for underline, which have no CommonMark syntax).
+    pub fn to_markdown(&self) -> String {
+        render_markdown(self)
+    }
+}
+
+// ── HTML ───────────────────────────────────────────────────────────
+
+/// Renders `doc` as HTML. With `editable`, every block element gets a
+/// `data-eq-block` index, each code line is its own `<pre>`, and spaces
+/// that a browser would collapse are emitted as `&nbsp;` so the caret
+/// can sit on them.
+pub(crate) fn render_html(doc: &RichDocument, editable: bool) -> String {
+    let mut out = String::new();
+    let mut i = 0;
+    while i < doc.blocks.len() {
+        let block = &doc.blocks[i];
+        match block.kind {
+            BlockKind::BulletItem | BlockKind::OrderedItem => {
+                let tag = if block.kind == BlockKind::BulletItem { "ul" } else { "ol" };
+                out.push_str(&format!("<{tag}>"));
+                while i < doc.blocks.len() && doc.blocks[i].kind == block.kind {
+                    open_block(&mut out, "li", i, editable);
+                    render_runs(&mut out, &doc.blocks[i], editable);
+                    out.push_str("</li>");
+                    i += 1;
+                }
+                out.push_str(&format!("</{tag}>"));
+            }
+            BlockKind::CodeBlock if !editable => {
+                out.push_str("<pre><code>");
+                let mut first = true;
+                while i < doc.blocks.len() && doc.blocks[i].kind == BlockKind::CodeBlock {
+                    if !first {
+                        out.push('\n');
+                    }
+                    first = false;
+                    escape_into(&mut out, &doc.blocks[i].plain_text());
+                    i += 1;
+                }
+                out.push_str("</code></pre>");
+            }
+            kind => {
+                let tag = match kind {
+                    BlockKind::Heading(level) => heading_tag(level),
+                    BlockKind::Quote => "blockquote",
+                    BlockKind::CodeBlock => "pre",
+                    _ => "p",
+                };
+                open_block(&mut out, tag, i, editable);
+                render_runs(&mut out, block, editable);
+                out.push_str(&format!("</{tag}>"));
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+fn heading_tag(level: u8) -> &'static str {
+    match level {
+        0 | 1 => "h1",
+        2 => "h2",
+        3 => "h3",
+        4 => "h4",
+        5 => "h5",
+        _ => "h6",
+    }
+}
+
+fn open_block(out: &mut String, tag: &str, index: usize, editable: bool) {
+    if editable {
+        out.push_str(&format!("<{tag} data-eq-block=\"{index}\">"));
+    } else {
+        out.push_str(&format!("<{tag}>"));
+    }
+}
+
+fn render_runs(out: &mut String, block: &Block, editable: bool) {
+    if block.is_empty() {
+        // An empty block needs a <br> to keep its height (and to give
+        // the caret somewhere to go) while editing.
+        if editable {
+            out.push_str("<br>");
+        }
+        return;
+    }
+    let text = block.plain_text();
+    let last = text.chars().count();
+    let mut pos = 0;
+    let mut prev_space = false;
+    for run in &block.runs {
+        let href = run.link.as_deref().and_then(safe_url);
+        if let Some(h) = &href {
+            out.push_str("<a href=\"");
+            escape_attr_into(out, h);
+            out.push_str("\">");
+        }
+        let tags = mark_tags(run);
+        for t in &tags {
+            out.push_str(&format!("<{t}>"));
+        }
+        if editable {
+            for ch in run.text.chars() {
+                let at_edge = pos == 0 || pos + 1 == last;
+                if ch == ' ' && (prev_space || at_edge) {
+                    out.push_str("&nbsp;");
+                } else {
+                    escape_char_into(out, ch);
+                }
+                prev_space = ch == ' ';
+                pos += 1;
+            }
+        } else {
+            escape_into(out, &run.text);
+        }
+        for t in tags.iter().rev() {
+            out.push_str(&format!("</{t}>"));
+        }
+        if href.is_some() {
+            out.push_str("</a>");
+        }
+    }
+}
+
+fn mark_tags(run: &TextRun) -> Vec<&'static str> {
+    let mut tags = Vec::new();
+    if run.marks.bold {
+        tags.push("strong");
+    }
+    if run.marks.italic {
+        tags.push("em");
+    }
+    if run.marks.underline {
+        tags.push("u");
+    }
+    if run.marks.strike {
+        tags.push("s");
+    }
+    if run.marks.code {
+        tags.push("code");
+    }
+    tags
+}
+
+fn escape_char_into(out: &mut String, ch: char) {
+    match ch {
+        '&' => out.push_str("&amp;"),
+        '<' => out.push_str("&lt;"),
+        '>' => out.push_str("&gt;"),
+        _ => out.push(ch),
+    }
+}
+
+fn escape_into(out: &mut String, text: &str) {
+    for ch in text.chars() {
+        escape_char_into(out, ch);
+    }
+}
+
+fn escape_attr_into(out: &mut String, text: &str) {
+    for ch in text.chars() {
+        match ch {
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => escape_char_into(out, ch),
+        }
+    }
+}
+
+// ── Markdown ───────────────────────────────────────────────────────
+
+fn render_markdown(doc: &RichDocument) -> String {
+    let mut out = String::new();
+    let mut i = 0;
+    while i < doc.blocks.len() {
+        if i > 0 {
+            out.push_str("\n\n");
+        }
+        let block = &doc.blocks[i];
+        match block.kind {
+            BlockKind::BulletItem | BlockKind::OrderedItem => {
+                let kind = block.kind;
+                let mut n = 1;
+                while i < doc.blocks.len() && doc.blocks[i].kind == kind {
+                    if n > 1 {
+                        out.push('\n');
+                    }
+                    if kind == BlockKind::BulletItem {
+                        out.push_str("- ");
+                    } else {
+                        out.push_str(&format!("{n}. "));
+                    }
+                    markdown_runs(&mut out, &doc.blocks[i]);
+                    n += 1;
+                    i += 1;
+                }
+            }
+            BlockKind::CodeBlock => {
+                let mut lines = Vec::new();
+                while i < doc.blocks.len() && doc.blocks[i].kind == BlockKind::CodeBlock {
+                    lines.push(doc.blocks[i].plain_text());
+                    i += 1;
+                }
+                let body = lines.join("\n");
+                let fence = code_fence(&body);
+                out.push_str(&format!("{fence}\n{body}\n{fence}"));
+            }
+            kind => {
+                match kind {
+                    BlockKind::Heading(level) => {
+                        out.push_str(&"#".repeat(level.clamp(1, 6) as usize));
+                        out.push(' ');
+                    }
+                    BlockKind::Quote => out.push_str("> "),
+                    _ => {}
+                }
+                markdown_runs(&mut out, block);
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+/// A backtick fence longer than any backtick run inside `body`.
+fn code_fence(body: &str) -> String {
+    let mut longest = 0;
+    let mut current = 0;
+    for ch in body.chars() {
+        if ch == '`' {
+            current += 1;
+            longest = longest.max(current);
+        } else {
+            current = 0;
+        }
+    }
+    "`".repeat(longest.max(2) + 1)
+}
+
+fn markdown_runs(out: &mut String, block: &Block) {
+    for (i, run) in block.runs.iter().enumerate() {
+        let mut text = String::new();
+        if run.marks.code {
+            let ticks = if run.text.contains('`') { "``" } else { "`" };
+            text.push_str(&format!("{ticks}{}{ticks}", run.text));
+        } else {
+            escape_markdown_into(&mut text, &run.text, i == 0);
+        }
+        // Emphasis markers must hug non-space text, so whitespace at the
+        // run edges is moved outside the markers.
+        let trimmed_start = text.trim_start();
+        let lead = &text[..text.len() - trimmed_start.len()];
+        let core = trimmed_start.trim_end();
+        let trail = &trimmed_start[core.len()..];
+
+        out.push_str(lead);
+        if core.is_empty() {
+            out.push_str(trail);
+            continue;
+        }
+        let href = run.link.as_deref().and_then(safe_url);
+        let mut open = String::new();
+        let mut close = String::new();
+        if href.is_some() {
+            open.push('[');
+        }
+        if run.marks.bold {
+            open.push_str("**");
+            close.insert_str(0, "**");
+        }
+        if run.marks.italic {
+            open.push('*');
+            close.insert(0, '*');
+        }
+        if run.marks.strike {
+            open.push_str("~~");
+            close.insert_str(0, "~~");
+        }
+        if run.marks.underline {
+            open.push_str("<u>");
+            close.insert_str(0, "</u>");
+        }
+        out.push_str(&open);
+        out.push_str(core);
+        out.push_str(&close);
+        if let Some(href) = href {
+            out.push_str(&format!("]({})", href.replace(' ', "%20").replace(')', "%29")));
+        }
+        out.push_str(trail);
+    }
+}
+
+fn escape_markdown_into(out: &mut String, text: &str, block_start: bool) {
+    for (i, ch) in text.chars().enumerate() {
+        let at_start = block_start && i == 0;
+        match ch {
+            '\\' | '*' | '_' | '`' | '[' | ']' | '~' | '<' => {
+                out.push('\\');
+                out.push(ch);
+            }
+            '#' | '>' | '-' | '+' if at_start => {
+                out.push('\\');
+                out.push(ch);
+            }
+            _ => out.push(ch),
+        }
+    }
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::organisms::eq_rich_text_editor::document::{Mark, TextRun};
+
+    fn sample() -> RichDocument {
+        RichDocument::from_blocks(vec![
+            Block::new(BlockKind::Heading(2)).text("Notes"),
+            Block::new(BlockKind::Paragraph)
+                .text("Some ")
+                .run(TextRun::new("bold").mark(Mark::Bold))
+                .text(" and ")
+                .run(TextRun::new("a link").link("https://example.com")),
+            Block::new(BlockKind::BulletItem).text("one"),
+            Block::new(BlockKind::BulletItem).text("two"),
+            Block::new(BlockKind::OrderedItem).text("first"),
+            Block::new(BlockKind::CodeBlock).text("let x = 1;"),
+            Block::new(BlockKind::CodeBlock).text("x < 2"),
+            Block::new(BlockKind::Quote).text("quoted"),
+        ])
+    }
+
+    #[test]
+    fn html_groups_lists_and_code() {
+        assert_eq!(
+            sample().to_html(),
+            "<h2>Notes</h2>\
+             <p>Some <strong>bold</strong> and <a href=\"https://example.com\">a link</a></p>\
+             <ul><li>one</li><li>two</li></ul>\
+             <ol><li>first</li></ol>\
+             <pre><code>let x = 1;\nx &lt; 2</code></pre>\
+             <blockquote>quoted</blockquote>"
+        );
+    }
+
+    #[test]
+    fn markdown_round_trips_structure() {
+        assert_eq!(
+            sample().to_markdown(),
+            "## Notes\n\n\
+             Some **bold** and [a link](https://example.com)\n\n\
+             - one\n- two\n\n\
+             1. first\n\n\
+             ```\nlet x = 1;\nx < 2\n```\n\n\
+             > quoted"
+        );
+    }
+
+    #[test]
+    fn html_escapes_text_and_attributes() {
+        let doc = RichDocument::from_blocks(vec![Block::new(BlockKind::Paragraph)
+            .run(TextRun::new("<b>&").link("https://x.y/?a=\"1\""))]);
+        assert_eq!(
+            doc.to_html(),
+            "<p><a href=\"https://x.y/?a=&quot;1&quot;\">&lt;b&gt;&amp;</a></p>"
+        );
+    }
+
+    #[test]
+    fn html_drops_unsafe_links() {
+        let doc = RichDocument::from_blocks(vec![Block::new(BlockKind::Paragraph)
+            .run(TextRun::new("x").link("javascript:alert(1)"))]);
+        assert_eq!(doc.to_html(), "<p>x</p>");
+        assert_eq!(doc.to_markdown(), "x");
+    }
+
+    #[test]
+    fn editable_html_annotates_blocks_and_keeps_spaces() {
+        let doc = RichDocument::from_blocks(vec![
+            Block::new(BlockKind::Paragraph).text("a  b "),
+            Block::new(BlockKind::Paragraph),
+        ]);
+        assert_eq!(
+            render_html(&doc, true),
+            "<p data-eq-block=\"0\">a &nbsp;b&nbsp;</p><p data-eq-block=\"1\"><br></p>"
+        );
+    }
+
+    #[test]
+    fn markdown_escapes_syntax_characters() {
+        let doc = RichDocument::from_plain_text("# not a heading *really*");
+        assert_eq!(doc.to_markdown(), "\\# not a heading \\*really\\*");
+    }
+
+    #[test]
+    fn markdown_moves_edge_spaces_outside_emphasis() {
+        let doc = RichDocument::from_blocks(vec![Block::new(BlockKind::Paragraph)
+            .text("a")
+            .run(TextRun::new(" b ").mark(Mark::Italic).mark(Mark::Strike))
+            .text("c")]);
+        assert_eq!(doc.to_markdown(), "a *~~b~~* c");
+    }
+
+    #[test]
+    fn markdown_code_span_and_fence_handle_backticks() {
+        let doc = RichDocument::from_blocks(vec![
+            Block::new(BlockKind::Paragraph).run(TextRun::new("a`b").mark(Mark::Code)),
+            Block::new(BlockKind::CodeBlock).text("```"),
+        ]);
+        assert_eq!(doc.to_markdown(), "``a`b``\n\n````\n```\n````");
+    }
+}
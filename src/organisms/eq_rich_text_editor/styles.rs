@@ -0,0 +1,89 @@
+//! Style constants for EqRichTextEditor and its toolbar.
+
+// ── Wrapper ─────────────────────────────────────────────────────────
+
+/// Outermost container - border, rounding, themed background.
+pub const WRAPPER: &str = "flex flex-col w-full rounded-xl border border-[var(--color-card-border)] \
+     bg-[var(--color-primary-dark)] overflow-hidden \
+     focus-within:border-[var(--color-accent-primary)]/60 transition-colors";
+
+// ── Toolbar ─────────────────────────────────────────────────────────
+
+/// Toolbar strip above the editing surface.
+pub const TOOLBAR: &str = "flex flex-wrap items-center gap-1 px-2 py-1.5 \
+     border-b border-[var(--color-card-border)] bg-[var(--color-tertiary-dark)]/40";
+
+/// Group of related toolbar controls.
+pub const TOOLBAR_GROUP: &str = "flex items-center gap-0.5";
+
+/// Thin vertical divider between toolbar groups.
+pub const TOOLBAR_DIVIDER: &str = "w-px h-5 mx-1 bg-[var(--color-card-border)]";
+
+/// Square toolbar button (applied over EqButton's Ghost/Sm classes).
+pub const TOOL_BTN: &str = "!px-2 min-w-[2rem] justify-center font-mono";
+
+/// Toolbar button whose mark / state is active.
+pub const TOOL_BTN_ACTIVE: &str = "!bg-[var(--color-accent-primary)]/20 !text-[var(--color-label-primary)]";
+
+/// Block type dropdown in the toolbar.
+pub const BLOCK_SELECT: &str = "min-w-[9rem]";
+
+// ── Link editor ─────────────────────────────────────────────────────
+
+/// Inline row for editing a link's URL.
+pub const LINK_BAR: &str = "flex items-center gap-2 px-2 py-1.5 \
+     border-b border-[var(--color-card-border)]";
+
+/// URL input inside the link bar.
+pub const LINK_INPUT: &str = "flex-1";
+
+/// Validation message for rejected URLs.
+pub const LINK_ERROR: &str = "text-xs text-[var(--color-error)]";
+
+// ── Editing surface ─────────────────────────────────────────────────
+
+/// Positioning context for the surface and its placeholder.
+pub const SURFACE_AREA: &str = "relative";
+
+/// The contenteditable surface. Child selectors style the blocks the
+/// editor renders, since their markup is produced from the model.
+pub const SURFACE: &str = "min-h-[10rem] max-h-[32rem] overflow-y-auto px-4 py-3 outline-none \
+     text-sm leading-relaxed text-[var(--color-label-primary)] break-words \
+     [&_p]:my-1 \
+     [&_h1]:text-2xl [&_h1]:font-bold [&_h1]:my-2 \
+     [&_h2]:text-xl [&_h2]:font-semibold [&_h2]:my-2 \
+     [&_h3]:text-lg [&_h3]:font-semibold [&_h3]:my-1.5 \
+     [&_blockquote]:border-l-4 [&_blockquote]:border-[var(--color-accent-primary)]/50 \
+     [&_blockquote]:pl-3 [&_blockquote]:text-[var(--color-label-secondary)] \
+     [&_pre]:font-mono [&_pre]:text-xs [&_pre]:px-3 [&_pre]:bg-[var(--color-code-bg)] [&_pre]:text-[var(--color-code-text)] \
+     [&_ul]:list-disc [&_ul]:pl-6 [&_ol]:list-decimal [&_ol]:pl-6 \
+     [&_code]:font-mono [&_code]:text-xs [&_code]:px-1 [&_code]:rounded \
+     [&_code]:bg-[var(--color-tertiary-dark)] \
+     [&_a]:text-[var(--color-accent-primary)] [&_a]:underline";
+
+/// Read-only surface modifier.
+pub const SURFACE_READONLY: &str = "cursor-default";
+
+/// Placeholder overlay shown while the document is empty.
+pub const PLACEHOLDER: &str = "absolute top-3 left-4 text-sm pointer-events-none select-none \
+     text-[var(--color-input-placeholder)]";
+
+/// All style tokens for playground introspection.
+pub fn catalog() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("WRAPPER", WRAPPER),
+        ("TOOLBAR", TOOLBAR),
+        ("TOOLBAR_GROUP", TOOLBAR_GROUP),
+        ("TOOLBAR_DIVIDER", TOOLBAR_DIVIDER),
+        ("TOOL_BTN", TOOL_BTN),
+        ("TOOL_BTN_ACTIVE", TOOL_BTN_ACTIVE),
+        ("BLOCK_SELECT", BLOCK_SELECT),
+        ("LINK_BAR", LINK_BAR),
+        ("LINK_INPUT", LINK_INPUT),
+        ("LINK_ERROR", LINK_ERROR),
+        ("SURFACE_AREA", SURFACE_AREA),
+        ("SURFACE", SURFACE),
+        ("SURFACE_READONLY", SURFACE_READONLY),
+        ("PLACEHOLDER", PLACEHOLDER),
+    ]
+}
@@ -0,0 +1,159 @@
+//! Formatting toolbar for EqRichTextEditor.
+
+use super::commands::EditorCommand;
+use super::document::{BlockKind, Mark, Marks};
+use super::styles as s;
+use crate::atoms::{ButtonSize, ButtonVariant, EqButton, EqInput};
+use crate::molecules::{DropdownItem, EqDropdown};
+use crate::shortcut::{Chord, Platform};
+use dioxus::prelude::*;
+
+/// Block types offered in the toolbar dropdown, in menu order.
+const BLOCK_CHOICES: [BlockKind; 8] = [
+    BlockKind::Paragraph,
+    BlockKind::Heading(1),
+    BlockKind::Heading(2),
+    BlockKind::Heading(3),
+    BlockKind::Quote,
+    BlockKind::BulletItem,
+    BlockKind::OrderedItem,
+    BlockKind::CodeBlock,
+];
+
+/// Mark buttons: `(mark, glyph, accessible name, shortcut)`. The
+/// shortcuts mirror [`command_for_key`](super::commands::command_for_key).
+const MARK_BUTTONS: [(Mark, &str, &str, &str); 5] = [
+    (Mark::Bold, "B", "Bold", "mod+b"),
+    (Mark::Italic, "I", "Italic", "mod+i"),
+    (Mark::Underline, "U", "Underline", "mod+u"),
+    (Mark::Strike, "S", "Strikethrough", "mod+shift+x"),
+    (Mark::Code, "</>", "Inline code", "mod+e"),
+];
+
+/// `"Bold (⌘B)"` on Mac, `"Bold (Ctrl+B)"` elsewhere.
+fn hint(name: &str, spec: &str, platform: Platform) -> String {
+    match Chord::parse(spec) {
+        Some(chord) => format!("{name} ({})", chord.label(platform)),
+        None => name.to_string(),
+    }
+}
+
+/// What the toolbar needs to know about the current selection.
+pub(super) struct ToolbarState {
+    pub marks: Marks,
+    pub block: BlockKind,
+    pub link_active: bool,
+    pub can_undo: bool,
+    pub can_redo: bool,
+    /// Decides how shortcut hints are written.
+    pub platform: Platform,
+}
+
+/// A single toolbar button. `mousedown` is cancelled so clicking a
+/// button does not move focus (and the selection) out of the surface.
+fn tool_button(glyph: &'static str, label: String, active: bool, disabled: bool, on_click: impl FnMut(Event<MouseData>) + 'static) -> Element {
+    let cls = if active { format!("{} {}", s::TOOL_BTN, s::TOOL_BTN_ACTIVE) } else { s::TOOL_BTN.to_string() };
+    rsx! {
+        span {
+            title: label,
+            onmousedown: move |evt| evt.prevent_default(),
+            EqButton {
+                variant: ButtonVariant::Ghost,
+                size: ButtonSize::Sm,
+                disabled,
+                class: cls,
+                on_click,
+                span { "aria-hidden": "true", "{glyph}" }
+                span { class: "sr-only", "{label}" }
+            }
+        }
+    }
+}
+
+/// Render the toolbar. `run` applies a command to the editor.
+pub(super) fn render_toolbar(state: ToolbarState, run: Callback<EditorCommand>) -> Element {
+    let items: Vec<DropdownItem> = BLOCK_CHOICES
+        .iter()
+        .map(|k| DropdownItem::new(k.id(), k.label()))
+        .collect();
+    let platform = state.platform;
+
+    rsx! {
+        div {
+            class: s::TOOLBAR,
+            role: "toolbar",
+            "aria-label": "Formatting",
+
+            div { class: s::TOOLBAR_GROUP,
+                for (mark, glyph, name, spec) in MARK_BUTTONS {
+                    {tool_button(glyph, hint(name, spec, platform), state.marks.has(mark), false, move |_| run.call(EditorCommand::ToggleMark(mark)))}
+                }
+                {tool_button("\u{1f517}", hint("Link", "mod+k", platform), state.link_active, false, move |_| run.call(EditorCommand::Link))}
+            }
+
+            span { class: s::TOOLBAR_DIVIDER, "aria-hidden": "true" }
+
+            EqDropdown {
+                label: state.block.label(),
+                items,
+                selected: Some(state.block.id().to_string()),
+                class: s::BLOCK_SELECT,
+                on_select: move |id: String| {
+                    if let Some(kind) = BlockKind::from_id(&id) {
+                        run.call(EditorCommand::SetBlock(kind));
+                    }
+                },
+            }
+
+            span { class: s::TOOLBAR_DIVIDER, "aria-hidden": "true" }
+
+            div { class: s::TOOLBAR_GROUP,
+                {tool_button("\u{21b6}", hint("Undo", "mod+z", platform), false, !state.can_undo, move |_| run.call(EditorCommand::Undo))}
+                {tool_button("\u{21b7}", hint("Redo", "mod+shift+z", platform), false, !state.can_redo, move |_| run.call(EditorCommand::Redo))}
+            }
+        }
+    }
+}
+
+/// Render the inline link editor row.
+pub(super) fn render_link_bar(
+    mut draft: Signal<String>,
+    error: bool,
+    has_link: bool,
+    on_apply: Callback<()>,
+    on_remove: Callback<()>,
+    on_cancel: Callback<()>,
+) -> Element {
+    rsx! {
+        div { class: s::LINK_BAR,
+            EqInput {
+                placeholder: "https://example.com",
+                value: draft(),
+                class: s::LINK_INPUT,
+                oninput: move |evt: FormEvent| draft.set(evt.value()),
+            }
+            if error {
+                span { class: s::LINK_ERROR, role: "alert", "Unsupported link" }
+            }
+            EqButton { size: ButtonSize::Sm, on_click: move |_| on_apply.call(()), "Apply" }
+            if has_link {
+                EqButton { size: ButtonSize::Sm, variant: ButtonVariant::Outline, on_click: move |_| on_remove.call(()), "Remove" }
+            }
+            EqButton { size: ButtonSize::Sm, variant: ButtonVariant::Ghost, on_click: move |_| on_cancel.call(()), "Cancel" }
+        }
+    }
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hints_follow_the_platform() {
+        assert_eq!(hint("Bold", "mod+b", Platform::Other), "Bold (Ctrl+B)");
+        assert_eq!(hint("Bold", "mod+b", Platform::Mac), "Bold (⌘B)");
+        assert_eq!(hint("Redo", "mod+shift+z", Platform::Mac), "Redo (⇧⌘Z)");
+    }
+}
@@ -36,9 +36,11 @@ use crate::atoms::{EqText, TextVariant, EqButton, ButtonVariant, EqInput, InputK
 use crate::molecules::EqDeviceFrame;
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, StyleInfo, format_catalog,
+    DemoSection, StyleInfo, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::playground::playground_types::{
     ComponentCategory, ComponentDescriptor, UsageExample,
 };
@@ -213,7 +215,7 @@ fn DemoEqToolbar() -> Element {
                 file: "eq_toolbar_styles.rs",
                 styles: format_catalog(&s::catalog()),
             }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }
@@ -2,6 +2,8 @@ pub mod eq_app_shell;
 pub mod eq_footer;
 pub mod eq_footer_styles;
 pub mod eq_grid;
+pub mod eq_rich_text_editor;
+pub mod eq_command_palette;
 pub mod eq_header;
 pub mod eq_header_styles;
 pub mod eq_hero_shell;
@@ -16,15 +18,24 @@ pub use eq_grid::{
     EqGrid, EqColumnDef, ColumnAlign, SortDirection, SortState,
     RowSelection, GridDensity, GridNavigation, GridDragPayload, ExportFormat,
 };
+pub use eq_rich_text_editor::{
+    EqRichTextEditor, RichDocument, Block, BlockKind, TextRun, Mark, Marks,
+    DocPosition, DocSelection,
+};
+pub use eq_command_palette::{
+    EqCommandPalette, Command, CommandRegistry, use_command_registry, use_commands,
+};
 pub use eq_header::EqHeader;
 pub use eq_hero_shell::EqHeroShell;
 pub use eq_navbar::EqNavbar;
 pub use eq_page_section::EqPageSection;
 
 pub mod eq_drawer;
+pub mod eq_drawer_sheet;
 pub mod eq_drawer_styles;
 
 pub use eq_drawer::{EqDrawer, DrawerSide, DrawerSize};
+pub use eq_drawer_sheet::SnapPoint;
 
 pub mod eq_file_picker;
 pub mod eq_file_picker_styles;
@@ -0,0 +1,354 @@
+//! Focus trap for modal overlays.
+//!
+//! While active, a trap keeps keyboard focus inside one container:
+//!
+//! - On activation it moves focus to the [`FocusTarget`] given as the
+//!   initial focus and marks everything outside the container `inert`
+//!   and `aria-hidden`, so neither the mouse, Tab nor assistive tech can
+//!   reach the background.
+//! - Two [`EqFocusGuard`] sentinels around the container catch Tab
+//!   leaving either end and wrap focus to the opposite end.
+//! - On deactivation it restores exactly the background it changed.
+//!
+//! The trap itself only issues [`FocusDom`] operations; [`EvalFocusDom`]
+//! performs them through `document::eval`. Other renderers (or tests)
+//! can supply their own implementation.
+//!
+//! ```no_run
+//! use eq_ui::prelude::*;
+//! use eq_ui::overlay::{use_focus_trap, EqFocusGuard, FocusEdge, FocusTarget};
+//!
+//! fn dialog(open: bool) -> Element {
+//!     let trap = use_focus_trap(open, FocusTarget::First);
+//!
+//!     rsx! {
+//!         EqFocusGuard { trap, edge: FocusEdge::Start }
+//!         div { id: trap.id(), tabindex: "-1", role: "dialog",
+//!             button { "OK" }
+//!             button { "Cancel" }
+//!         }
+//!         EqFocusGuard { trap, edge: FocusEdge::End }
+//!     }
+//! }
+//! ```
+
+use super::styles as s;
+use dioxus::document;
+use dioxus::prelude::*;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Where focus goes inside a trap's container.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub enum FocusTarget {
+    /// First tabbable descendant, falling back to the container.
+    #[default]
+    First,
+    /// Last tabbable descendant, falling back to the container.
+    Last,
+    /// The container itself (give it `tabindex="-1"`).
+    Container,
+    /// The element with this DOM id, falling back to `First`.
+    Id(String),
+    /// Leave focus where it is.
+    Keep,
+}
+
+/// Which sentinel focus reached: the one before the container (Shift+Tab
+/// off the first element) or the one after it (Tab off the last).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FocusEdge {
+    Start,
+    End,
+}
+
+impl FocusEdge {
+    /// Where focus wraps to when it reaches this edge.
+    pub fn wrap_target(self) -> FocusTarget {
+        match self {
+            Self::Start => FocusTarget::Last,
+            Self::End => FocusTarget::First,
+        }
+    }
+}
+
+/// DOM operations a focus trap needs.
+pub trait FocusDom {
+    /// Move focus to `target` within the element with id `container`.
+    fn focus(&self, container: &str, target: &FocusTarget);
+    /// Mark everything outside `container` inert and `aria-hidden`, or
+    /// undo exactly what a previous call marked.
+    fn set_background_inert(&self, container: &str, inert: bool);
+}
+
+/// [`FocusDom`] backed by `document::eval`.
+#[derive(Clone, Copy, Default)]
+pub struct EvalFocusDom;
+
+impl FocusDom for EvalFocusDom {
+    fn focus(&self, container: &str, target: &FocusTarget) {
+        if *target != FocusTarget::Keep {
+            document::eval(&focus_js(container, target));
+        }
+    }
+
+    fn set_background_inert(&self, container: &str, inert: bool) {
+        let js = if inert { inert_js(container) } else { release_js(container) };
+        document::eval(&js);
+    }
+}
+
+/// Elements that can take keyboard focus, before filtering out
+/// negative tabindex, guards and hidden elements.
+const TABBABLE: &str = "a[href], area[href], button:not([disabled]), \
+    input:not([disabled]):not([type=\"hidden\"]), select:not([disabled]), \
+    textarea:not([disabled]), iframe, audio[controls], video[controls], \
+    [contenteditable]:not([contenteditable=\"false\"]), [tabindex]";
+
+/// Siblings never marked inert: portaled layers that are not open
+/// (a menu inside the dialog may open later), passive layers, focus
+/// guards, backdrops that sit beside their panel, and live regions.
+const KEEP_LIVE: &str = "[data-eq-layer]:not([data-eq-layer=\"active\"]), \
+    [data-eq-focus-guard], [data-eq-backdrop], [aria-live], script, style, link";
+
+fn js_str(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| "\"\"".into())
+}
+
+fn focus_js(container: &str, target: &FocusTarget) -> String {
+    let pick = match target {
+        FocusTarget::First | FocusTarget::Keep => "all[0] || c".to_string(),
+        FocusTarget::Last => "all[all.length - 1] || c".to_string(),
+        FocusTarget::Container => "c".to_string(),
+        FocusTarget::Id(id) => format!("document.getElementById({}) || all[0] || c", js_str(id)),
+    };
+    // The container may not be mounted (or still be hidden) on the
+    // frame the trap activates; retry for a few frames.
+    format!(
+        r#"
+const id = {container};
+let tries = 0;
+const run = () => {{
+    const c = document.getElementById(id);
+    if (c) {{
+        const all = [...c.querySelectorAll({TABBABLE})].filter((el) =>
+            el.tabIndex >= 0 && !el.closest('[data-eq-focus-guard]') &&
+            el.getClientRects().length > 0 && getComputedStyle(el).visibility !== 'hidden');
+        const el = {pick};
+        el.focus();
+    }}
+    if ((!c || !c.contains(document.activeElement)) && tries++ < 20) requestAnimationFrame(run);
+}};
+requestAnimationFrame(run);
+"#,
+        container = js_str(container),
+        TABBABLE = js_str(TABBABLE),
+    )
+}
+
+fn inert_js(container: &str) -> String {
+    format!(
+        r#"
+const id = {container};
+let tries = 0;
+const run = () => {{
+    const c = document.getElementById(id);
+    if (!c) {{ if (tries++ < 20) requestAnimationFrame(run); return; }}
+    const marked = [];
+    for (let el = c; el.parentElement && el !== document.body; el = el.parentElement) {{
+        for (const sib of el.parentElement.children) {{
+            if (sib === el || sib.inert || sib.matches({keep})) continue;
+            sib.inert = true;
+            sib.setAttribute('aria-hidden', 'true');
+            marked.push(sib);
+        }}
+    }}
+    (window.__eqInert = window.__eqInert || {{}})[id] = marked;
+}};
+run();
+"#,
+        container = js_str(container),
+        keep = js_str(KEEP_LIVE),
+    )
+}
+
+fn release_js(container: &str) -> String {
+    format!(
+        r#"
+const m = window.__eqInert || {{}};
+const id = {container};
+for (const el of m[id] || []) {{
+    el.inert = false;
+    el.removeAttribute('aria-hidden');
+}}
+delete m[id];
+"#,
+        container = js_str(container),
+    )
+}
+
+/// Handle returned by [`use_focus_trap`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FocusTrap {
+    id: u64,
+    active: bool,
+}
+
+impl FocusTrap {
+    /// DOM id to put on the trap's container.
+    pub fn id(&self) -> String {
+        format!("eq-focus-trap-{}", self.id)
+    }
+
+    /// Whether the trap is currently holding focus.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Inert the background and move focus to `initial`.
+    pub fn activate(&self, dom: &impl FocusDom, initial: &FocusTarget) {
+        let id = self.id();
+        dom.set_background_inert(&id, true);
+        dom.focus(&id, initial);
+    }
+
+    /// Restore the background.
+    pub fn deactivate(&self, dom: &impl FocusDom) {
+        dom.set_background_inert(&self.id(), false);
+    }
+
+    /// Focus reached a guard: wrap to the opposite end.
+    pub fn wrap(&self, dom: &impl FocusDom, edge: FocusEdge) {
+        dom.focus(&self.id(), &edge.wrap_target());
+    }
+}
+
+/// Traps focus in a container while `active`.
+///
+/// Put [`FocusTrap::id`] on the container and render an
+/// [`EqFocusGuard`] on each side of it. `initial_focus` is read when the
+/// trap activates.
+pub fn use_focus_trap(active: bool, initial_focus: FocusTarget) -> FocusTrap {
+    let id = use_hook(|| {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    });
+    let trap = FocusTrap { id, active };
+
+    // Compared and flipped in the render body, which must not write
+    // signals; `use_drop` checks it to restore focus only if the trap
+    // was still active.
+    let was_active = use_hook(|| Rc::new(Cell::new(false)));
+    if active != was_active.get() {
+        was_active.set(active);
+        if active {
+            trap.activate(&EvalFocusDom, &initial_focus);
+        } else {
+            trap.deactivate(&EvalFocusDom);
+        }
+    }
+
+    let on_drop = was_active.clone();
+    use_drop(move || {
+        if on_drop.get() {
+            trap.deactivate(&EvalFocusDom);
+        }
+    });
+
+    trap
+}
+
+/// Invisible sentinel next to a trap's container. It is only tabbable
+/// while the trap is active (the background is inert then, so Tab can
+/// only reach it from inside) and sends focus back into the container.
+#[component]
+pub fn EqFocusGuard(trap: FocusTrap, edge: FocusEdge) -> Element {
+    rsx! {
+        span {
+            class: s::GUARD,
+            tabindex: if trap.active { "0" } else { "-1" },
+            "aria-hidden": "true",
+            "data-eq-focus-guard": "true",
+            onfocus: move |_| trap.wrap(&EvalFocusDom, edge),
+        }
+    }
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[derive(Debug, PartialEq)]
+    enum Op {
+        Focus(String, FocusTarget),
+        Inert(String, bool),
+    }
+
+    #[derive(Default)]
+    struct Recorder(RefCell<Vec<Op>>);
+
+    impl FocusDom for Recorder {
+        fn focus(&self, container: &str, target: &FocusTarget) {
+            self.0.borrow_mut().push(Op::Focus(container.into(), target.clone()));
+        }
+
+        fn set_background_inert(&self, container: &str, inert: bool) {
+            self.0.borrow_mut().push(Op::Inert(container.into(), inert));
+        }
+    }
+
+    #[test]
+    fn activation_inerts_then_focuses() {
+        let dom = Recorder::default();
+        let trap = FocusTrap { id: 3, active: true };
+        trap.activate(&dom, &FocusTarget::Id("ok".into()));
+        trap.deactivate(&dom);
+        assert_eq!(
+            dom.0.into_inner(),
+            vec![
+                Op::Inert("eq-focus-trap-3".into(), true),
+                Op::Focus("eq-focus-trap-3".into(), FocusTarget::Id("ok".into())),
+                Op::Inert("eq-focus-trap-3".into(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn guards_wrap_to_opposite_end() {
+        let dom = Recorder::default();
+        let trap = FocusTrap { id: 0, active: true };
+        trap.wrap(&dom, FocusEdge::End);
+        trap.wrap(&dom, FocusEdge::Start);
+        assert_eq!(
+            dom.0.into_inner(),
+            vec![
+                Op::Focus("eq-focus-trap-0".into(), FocusTarget::First),
+                Op::Focus("eq-focus-trap-0".into(), FocusTarget::Last),
+            ]
+        );
+    }
+
+    #[test]
+    fn scripts_quote_ids() {
+        let js = focus_js("trap", &FocusTarget::Id("a'b\"c".into()));
+        assert!(js.contains(r#"document.getElementById("a'b\"c")"#));
+        assert!(release_js("trap").contains(r#"const id = "trap";"#));
+    }
+
+    #[test]
+    fn smoke_renders_guards() {
+        let mut dom = VirtualDom::new(|| {
+            let trap = use_focus_trap(false, FocusTarget::First);
+            rsx! {
+                EqFocusGuard { trap, edge: FocusEdge::Start }
+                div { id: trap.id(), "content" }
+                EqFocusGuard { trap, edge: FocusEdge::End }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+}
@@ -0,0 +1,36 @@
+//! Overlay layer - portals, stacking and modal behaviour.
+//!
+//! - **root** - `EqOverlayRoot`, the app-level layer that renders every
+//!   portaled overlay in a managed stack, plus the `OverlayStack` context
+//! - **portal** - `EqPortal`, which moves its children into that stack
+//! - **focus_trap** - `use_focus_trap` + `EqFocusGuard`, keeping focus
+//!   inside a modal container with the background inert
+//! - **styles** - co-located Tailwind class constants
+//!
+//! Modals, drawers, menus, popups and toasts render through `EqPortal`,
+//! so mounting `EqOverlayRoot` once around the app is enough to get
+//! correct stacking, topmost-only Escape and outside clicks, body
+//! scroll lock and focus return. Without a root they keep rendering in
+//! place as before.
+//!
+//! ```no_run
+//! use eq_ui::prelude::*;
+//! use eq_ui::overlay::EqOverlayRoot;
+//!
+//! fn app() -> Element {
+//!     rsx! {
+//!         EqOverlayRoot {
+//!             "Your app"
+//!         }
+//!     }
+//! }
+//! ```
+
+pub mod root;
+pub mod portal;
+pub mod focus_trap;
+pub mod styles;
+
+pub use root::{EqOverlayRoot, OverlayStack, use_overlay};
+pub use portal::EqPortal;
+pub use focus_trap::{use_focus_trap, EqFocusGuard, EvalFocusDom, FocusDom, FocusEdge, FocusTarget, FocusTrap};
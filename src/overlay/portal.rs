@@ -0,0 +1,225 @@
+//! EqPortal — renders its children into the nearest `EqOverlayRoot`.
+
+use super::root::{CarriedContexts, OverlayLayer, use_overlay};
+use super::styles as s;
+use dioxus::document;
+use dioxus::prelude::*;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// First lock saves the body's overflow / padding and hides the
+/// scrollbar (padding out its width so content does not shift); the
+/// last unlock restores them.
+const LOCK_SCROLL_JS: &str = r#"
+const l = window.__eqScrollLock = window.__eqScrollLock || { n: 0 };
+if (l.n++ === 0) {
+    const b = document.body;
+    l.overflow = b.style.overflow;
+    l.padding = b.style.paddingRight;
+    const bar = window.innerWidth - document.documentElement.clientWidth;
+    b.style.overflow = 'hidden';
+    if (bar > 0) b.style.paddingRight = bar + 'px';
+}
+"#;
+
+const UNLOCK_SCROLL_JS: &str = r#"
+const l = window.__eqScrollLock;
+if (l && l.n > 0 && --l.n === 0) {
+    document.body.style.overflow = l.overflow;
+    document.body.style.paddingRight = l.padding;
+}
+"#;
+
+fn save_focus_js(id: u64) -> String {
+    format!(
+        "(window.__eqFocusReturn = window.__eqFocusReturn || {{}})[{id}] = document.activeElement;"
+    )
+}
+
+fn restore_focus_js(id: u64) -> String {
+    format!(
+        "const m = window.__eqFocusReturn || {{}}; const el = m[{id}]; delete m[{id}]; \
+         if (el && el.isConnected && el.focus) el.focus({{ preventScroll: true }});"
+    )
+}
+
+/// Side effects applied while a portal is active, remembered so they
+/// are undone exactly once even if the props change meanwhile.
+#[derive(Clone, Copy, Default)]
+struct Applied {
+    lock_scroll: bool,
+    restore_focus: bool,
+}
+
+fn apply(id: u64, lock_scroll: bool, restore_focus: bool) -> Applied {
+    if restore_focus {
+        document::eval(&save_focus_js(id));
+    }
+    if lock_scroll {
+        document::eval(LOCK_SCROLL_JS);
+    }
+    Applied { lock_scroll, restore_focus }
+}
+
+fn release(id: u64, applied: Applied) {
+    if applied.lock_scroll {
+        document::eval(UNLOCK_SCROLL_JS);
+    }
+    if applied.restore_focus {
+        document::eval(&restore_focus_js(id));
+    }
+}
+
+/// Renders `children` as a layer of the nearest
+/// [`EqOverlayRoot`](super::EqOverlayRoot).
+///
+/// The content stays mounted while inactive (so exit transitions can
+/// play); `active` decides whether the layer takes part in Escape and
+/// outside-click routing, locks scrolling and captures focus to return.
+///
+/// The library's own contexts (theme, toaster, dialogs, command
+/// registry, grid drag) provided between the root and the portal are
+/// carried into the layer; provide app contexts above the root.
+///
+/// Without an overlay root the children render in place, with Escape
+/// and outside clicks handled locally.
+#[component]
+pub fn EqPortal(
+    /// Whether the overlay is open. A layer moves to the top of the
+    /// stack when it becomes active.
+    #[props(default = true)]
+    active: bool,
+    /// Passive layers (tooltips, toasts) paint above interactive ones
+    /// and never receive Escape or outside clicks.
+    #[props(default = false)]
+    passive: bool,
+    /// Lock body scrolling while active.
+    #[props(default = false)]
+    lock_scroll: bool,
+    /// Return focus to the previously focused element on close.
+    #[props(default = false)]
+    restore_focus: bool,
+    /// Escape pressed while this is the topmost layer.
+    #[props(default)]
+    on_escape: Option<EventHandler<()>>,
+    /// Click outside the content while this is the topmost layer.
+    #[props(default)]
+    on_outside_click: Option<EventHandler<()>>,
+    children: Element,
+) -> Element {
+    let id = use_hook(|| {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    });
+    let stack = use_overlay();
+
+    // Flipped during render as `active` changes, where writing a signal
+    // would queue another render; `use_drop` releases whatever is still
+    // applied when the portal unmounts.
+    let applied = use_hook(|| Rc::new(Cell::new(None::<Applied>)));
+    match (active, applied.get()) {
+        (true, None) => applied.set(Some(apply(id, lock_scroll, restore_focus))),
+        (false, Some(prev)) => {
+            applied.set(None);
+            release(id, prev);
+        }
+        _ => {}
+    }
+
+    let on_drop = applied.clone();
+    use_drop(move || {
+        if let Some(prev) = on_drop.take() {
+            release(id, prev);
+        }
+        if let Some(mut stack) = stack {
+            stack.remove(id);
+        }
+    });
+
+    // Registered after render: the stack lives in the root, and writing
+    // it while rendering would queue the root from inside this render.
+    let contexts = use_hook(CarriedContexts::capture);
+    use_effect(use_reactive(
+        (&children, &active, &passive, &on_escape, &on_outside_click),
+        move |(children, active, passive, on_escape, on_outside_click)| {
+            if let Some(mut stack) = stack {
+                stack.upsert(OverlayLayer {
+                    id,
+                    content: children,
+                    active,
+                    passive,
+                    on_escape: if passive { None } else { on_escape },
+                    on_outside_click: if passive { None } else { on_outside_click },
+                    contexts: contexts.clone(),
+                });
+            }
+        },
+    ));
+
+    if stack.is_some() {
+        return rsx! {};
+    }
+
+    let catch_outside = active && !passive && on_outside_click.is_some();
+    rsx! {
+        div {
+            class: s::INLINE,
+            onkeydown: move |evt: Event<KeyboardData>| {
+                if active && !passive && evt.key() == Key::Escape
+                    && let Some(handler) = on_escape
+                {
+                    evt.prevent_default();
+                    evt.stop_propagation();
+                    handler.call(());
+                }
+            },
+            if catch_outside {
+                div {
+                    class: s::OUTSIDE,
+                    "aria-hidden": "true",
+                    onclick: move |_| {
+                        if let Some(handler) = on_outside_click {
+                            handler.call(());
+                        }
+                    },
+                }
+            }
+            {children}
+        }
+    }
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::overlay::EqOverlayRoot;
+
+    #[test]
+    fn smoke_renders_in_place_without_root() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! { EqPortal { active: false, "inline" } }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn smoke_renders_into_root() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                EqOverlayRoot {
+                    EqPortal { active: false, passive: true, "layer" }
+                }
+            }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn focus_scripts_are_keyed_by_id() {
+        assert!(save_focus_js(7).contains("[7] = document.activeElement"));
+        assert!(restore_focus_js(7).contains("delete m[7]"));
+    }
+}
@@ -0,0 +1,564 @@
+//! EqOverlayRoot — app-level layer for portaled overlays.
+//!
+//! Mount it once around the app. Every [`EqPortal`](super::EqPortal)
+//! below it registers its content as a layer in the shared
+//! [`OverlayStack`]; the root renders all layers after its children,
+//! in stacking order, with increasing `z-index`.
+//!
+//! Ordering rules:
+//!
+//! - A layer moves to the top when it becomes active, so whatever was
+//!   opened last is on top regardless of where it sits in the tree.
+//! - Passive layers (tooltips, toasts) always render above interactive
+//!   ones and never receive Escape or outside clicks.
+//! - Only the topmost active interactive layer receives Escape and
+//!   outside clicks; lower layers stay untouched until it closes.
+
+use super::styles as s;
+use crate::eq_theme::EqTheme;
+use crate::molecules::{Dialogs, Toaster};
+use crate::organisms::{CommandRegistry, GridDragPayload};
+use crate::playground;
+use dioxus::core::Task;
+use dioxus::document;
+use dioxus::prelude::*;
+use std::rc::Rc;
+
+#[cfg(feature = "playground")]
+use super::EqPortal;
+#[cfg(feature = "playground")]
+use crate::playground::playground_helpers::{DemoSection, StyleInfo, format_catalog};
+#[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
+use crate::atoms::{EqText, TextVariant, EqButton, ButtonVariant};
+#[cfg(feature = "playground")]
+use crate::molecules::{EqModal, EqDropdown, DropdownItem};
+#[cfg(feature = "playground")]
+use crate::organisms::EqDrawer;
+#[cfg(feature = "playground")]
+use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
+
+/// `z-index` of the lowest layer; each layer above adds one.
+pub const Z_BASE: usize = 1000;
+
+/// One registered overlay layer.
+#[derive(Clone)]
+pub(crate) struct OverlayLayer {
+    pub id: u64,
+    pub content: Element,
+    pub active: bool,
+    pub passive: bool,
+    pub on_escape: Option<EventHandler<()>>,
+    pub on_outside_click: Option<EventHandler<()>>,
+    pub contexts: CarriedContexts,
+}
+
+/// Insert or refresh a layer. A layer that turns active moves to the
+/// end (top) of the stack; otherwise it keeps its position.
+pub(crate) fn upsert_layer(layers: &mut Vec<OverlayLayer>, layer: OverlayLayer) {
+    match layers.iter().position(|l| l.id == layer.id) {
+        Some(i) if layer.active && !layers[i].active => {
+            layers.remove(i);
+            layers.push(layer);
+        }
+        Some(i) => layers[i] = layer,
+        None => layers.push(layer),
+    }
+}
+
+/// Topmost active interactive layer - the only one that receives
+/// Escape and outside clicks.
+pub(crate) fn top_layer(layers: &[OverlayLayer]) -> Option<&OverlayLayer> {
+    layers.iter().rev().find(|l| l.active && !l.passive)
+}
+
+/// Layers in paint order: interactive ones first, passive ones above.
+pub(crate) fn paint_order(layers: &[OverlayLayer]) -> Vec<&OverlayLayer> {
+    let interactive = layers.iter().filter(|l| !l.passive);
+    let passive = layers.iter().filter(|l| l.passive);
+    interactive.chain(passive).collect()
+}
+
+/// Value of a layer's `data-eq-layer` attribute; focus traps leave
+/// layers that are not `active` out of the inert background.
+fn layer_state(layer: &OverlayLayer) -> &'static str {
+    match (layer.passive, layer.active) {
+        (true, _) => "passive",
+        (false, true) => "active",
+        (false, false) => "idle",
+    }
+}
+
+/// Id of the layer a component renders in, provided to each layer's
+/// content. Components outside any layer see no value.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) struct LayerId(pub u64);
+
+/// The overlay layer the calling component renders in, if any.
+pub(crate) fn current_layer() -> Option<u64> {
+    try_consume_context::<LayerId>().map(|layer| layer.0)
+}
+
+/// Contexts captured where a portal is declared and re-provided
+/// around its layer. Layers render under the root, so anything
+/// provided between the root and the portal would otherwise be out of
+/// reach of the portaled content. Contexts provided above the root, or
+/// with `provide_root_context`, need no carrying.
+#[derive(Clone, Default)]
+pub(crate) struct CarriedContexts(Rc<Vec<Box<dyn Fn()>>>);
+
+impl CarriedContexts {
+    /// Capture the library's contexts visible from the calling scope.
+    pub(crate) fn capture() -> Self {
+        let mut carried = Vec::new();
+        carry::<Signal<EqTheme>>(&mut carried);
+        carry::<Toaster>(&mut carried);
+        carry::<Dialogs>(&mut carried);
+        carry::<CommandRegistry>(&mut carried);
+        carry::<Signal<Option<GridDragPayload>>>(&mut carried);
+        Self(Rc::new(carried))
+    }
+
+    fn provide(&self) {
+        for provide in self.0.iter() {
+            provide();
+        }
+    }
+}
+
+fn carry<T: Clone + 'static>(carried: &mut Vec<Box<dyn Fn()>>) {
+    if let Some(value) = try_consume_context::<T>() {
+        carried.push(Box::new(move || {
+            provide_context(value.clone());
+        }));
+    }
+}
+
+impl PartialEq for CarriedContexts {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Provides [`LayerId`] and the portal's carried contexts to a layer's
+/// content.
+#[component]
+fn LayerScope(id: u64, contexts: CarriedContexts, children: Element) -> Element {
+    use_hook(|| contexts.provide());
+    use_context_provider(|| LayerId(id));
+    children
+}
+
+/// Shared stack of overlay layers, provided by [`EqOverlayRoot`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct OverlayStack {
+    layers: Signal<Vec<OverlayLayer>>,
+}
+
+impl OverlayStack {
+    pub(crate) fn upsert(&mut self, layer: OverlayLayer) {
+        upsert_layer(&mut self.layers.write(), layer);
+    }
+
+    pub(crate) fn remove(&mut self, id: u64) {
+        // The root may already be gone when the whole tree unmounts.
+        if let Ok(mut layers) = self.layers.try_write() {
+            layers.retain(|l| l.id != id);
+        }
+    }
+
+    /// Number of registered layers, open or not.
+    pub fn len(&self) -> usize {
+        self.layers.read().len()
+    }
+
+    /// Whether no layer is registered.
+    pub fn is_empty(&self) -> bool {
+        self.layers.read().is_empty()
+    }
+
+    /// Number of active interactive layers (open dialogs, menus…).
+    pub fn depth(&self) -> usize {
+        self.layers.read().iter().filter(|l| l.active && !l.passive).count()
+    }
+
+    /// Whether the layer with `id` is the topmost interactive one.
+    pub fn is_top(&self, id: u64) -> bool {
+        top_layer(&self.layers.read()).is_some_and(|l| l.id == id)
+    }
+
+    /// Route an Escape press to the topmost layer. Returns `true` when
+    /// a layer was open to take it, even if it ignores Escape.
+    pub fn escape(&self) -> bool {
+        let top = top_layer(&self.layers.peek()).map(|l| l.on_escape);
+        match top {
+            Some(handler) => {
+                if let Some(handler) = handler {
+                    handler.call(());
+                }
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// The overlay stack of the nearest [`EqOverlayRoot`], if any.
+pub fn use_overlay() -> Option<OverlayStack> {
+    try_use_context::<OverlayStack>()
+}
+
+/// Escape presses with nothing focused target `<body>`, outside the
+/// root's element; forward those while a layer is open.
+const BODY_ESCAPE_JS: &str = r#"
+const onKey = (e) => {
+    if (e.key !== 'Escape') return;
+    if (e.target !== document.body && e.target !== document.documentElement) return;
+    e.preventDefault();
+    dioxus.send(true);
+};
+document.addEventListener('keydown', onKey);
+await dioxus.recv();
+document.removeEventListener('keydown', onKey);
+"#;
+
+/// App-level overlay layer.
+///
+/// Wrap the application in it once. Portaled overlays (modals,
+/// drawers, menus, popups, toasts) render here in a managed stack
+/// instead of in place, so their stacking no longer depends on where
+/// they are declared.
+#[playground(
+    category = Organism,
+    description = "Root layer for portaled overlays: managed z-index stack, topmost-only \
+                   Escape and outside clicks, body scroll lock and focus return.",
+    examples = [
+        ("Mount", "rsx! {\n    EqOverlayRoot {\n        App {}\n    }\n}"),
+        ("Custom portal", "EqPortal {\n    active: open(),\n    on_escape: move |_| open.set(false),\n    on_outside_click: move |_| open.set(false),\n    div { class: \"fixed top-4 right-4\", \"Floating panel\" }\n}"),
+    ],
+    custom_demo,
+    custom_gallery,
+)]
+#[component]
+pub fn EqOverlayRoot(children: Element) -> Element {
+    let layers = use_signal(Vec::<OverlayLayer>::new);
+    let stack = use_context_provider(|| OverlayStack { layers });
+
+    // Forward body-level Escape while an interactive layer is open.
+    let listening = top_layer(&layers.read()).is_some();
+    let mut listener = use_signal(|| None::<(document::Eval, Task)>);
+    if listening != listener.peek().is_some() {
+        if listening {
+            let mut eval = document::eval(BODY_ESCAPE_JS);
+            let task = spawn(async move {
+                while eval.recv::<bool>().await.is_ok() {
+                    stack.escape();
+                }
+            });
+            listener.set(Some((eval, task)));
+        } else if let Some((eval, task)) = listener.write().take() {
+            let _ = eval.send(true);
+            task.cancel();
+        }
+    }
+    use_drop(move || {
+        if let Ok(mut listener) = listener.try_write()
+            && let Some((eval, task)) = listener.take()
+        {
+            let _ = eval.send(true);
+            task.cancel();
+        }
+    });
+
+    let layers = layers.read();
+    let painted: Vec<(usize, &'static str, OverlayLayer)> = paint_order(&layers)
+        .into_iter()
+        .enumerate()
+        .map(|(i, layer)| (Z_BASE + i, layer_state(layer), layer.clone()))
+        .collect();
+
+    rsx! {
+        div {
+            class: s::ROOT,
+            onkeydown: move |evt: Event<KeyboardData>| {
+                if evt.key() == Key::Escape && stack.escape() {
+                    evt.prevent_default();
+                    evt.stop_propagation();
+                }
+            },
+
+            {children}
+
+            for (z, state, layer) in painted {
+                div {
+                    key: "{layer.id}",
+                    class: s::LAYER,
+                    style: "z-index: {z};",
+                    "data-eq-layer": state,
+                    "data-eq-layer-id": "{layer.id}",
+                    if layer.active && !layer.passive && layer.on_outside_click.is_some() {
+                        div {
+                            class: s::OUTSIDE,
+                            "aria-hidden": "true",
+                            onclick: move |_| {
+                                if let Some(handler) = layer.on_outside_click {
+                                    handler.call(());
+                                }
+                            },
+                        }
+                    }
+                    LayerScope { id: layer.id, contexts: layer.contexts.clone(), {layer.content} }
+                }
+            }
+        }
+    }
+}
+
+// ── Interactive demo ───────────────────────────────────────────────
+
+#[cfg(feature = "playground")]
+#[component]
+fn DemoEqOverlayRoot() -> Element {
+    let mut drawer_open = use_signal(|| false);
+    let mut modal_open = use_signal(|| false);
+    let mut last_action = use_signal(String::new);
+    let stack = use_overlay();
+    let depth = stack.map_or(0, |s| s.depth());
+
+    let items = vec![
+        DropdownItem::new("edit", "Edit"),
+        DropdownItem::new("duplicate", "Duplicate"),
+        DropdownItem::new("delete", "Delete"),
+    ];
+
+    let code = r#"// main.rs
+rsx! {
+    EqOverlayRoot {
+        App {}
+    }
+}
+
+// Anywhere below: modals, drawers, menus and toasts portal into the
+// root automatically. Custom overlays use EqPortal.
+EqPortal {
+    active: open(),
+    lock_scroll: true,
+    restore_focus: true,
+    on_escape: move |_| open.set(false),
+    div { class: "fixed inset-0 grid place-items-center", "…" }
+}"#
+    .to_string();
+
+    rsx! {
+        DemoSection { title: "EqOverlayRoot",
+            EqText { variant: TextVariant::Muted,
+                "Open the drawer, then the modal inside it, then the menu inside the modal. \
+                 Each layer stacks above the previous one; Escape closes only the topmost, \
+                 and focus returns to the button that opened it."
+            }
+
+            div { class: "flex flex-wrap items-center gap-3 rounded-lg border border-dashed border-[var(--color-card-border)] p-6",
+                EqButton {
+                    variant: ButtonVariant::Primary,
+                    on_click: move |_| drawer_open.set(true),
+                    "Open drawer"
+                }
+                EqText { variant: TextVariant::Caption, "Open layers: {depth}" }
+                if !last_action().is_empty() {
+                    EqText { variant: TextVariant::Caption, "Last action: {last_action}" }
+                }
+            }
+
+            EqDrawer {
+                open: drawer_open(),
+                on_close: move |_| drawer_open.set(false),
+                title: "Drawer",
+                body: rsx! {
+                    div { class: "space-y-3",
+                        EqText { variant: TextVariant::Body, "Body scrolling is locked while this is open." }
+                        EqButton {
+                            variant: ButtonVariant::Outline,
+                            on_click: move |_| modal_open.set(true),
+                            "Open modal"
+                        }
+                    }
+                },
+            }
+
+            EqModal {
+                open: modal_open(),
+                on_close: move |_| modal_open.set(false),
+                title: "Modal above the drawer",
+                body: rsx! {
+                    div { class: "space-y-3",
+                        EqText { variant: TextVariant::Body, "This menu opens above the modal:" }
+                        EqDropdown {
+                            label: "Actions",
+                            items: items.clone(),
+                            on_select: move |v: String| last_action.set(v),
+                        }
+                    }
+                },
+            }
+
+            StyleInfo { file: "overlay/styles.rs", styles: format_catalog(&s::catalog()) }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
+        }
+    }
+}
+
+// ── Gallery ───────────────────────────────────────────────────────
+
+#[cfg(feature = "playground")]
+#[component]
+fn GalleryEqOverlayRoot() -> Element {
+    let mut panel_open = use_signal(|| false);
+
+    rsx! {
+        div { class: "space-y-4",
+            div { class: "rounded-lg border border-[var(--color-card-border)] p-4 space-y-4",
+                EqText { variant: TextVariant::Caption, class: "font-semibold uppercase tracking-wider", "Custom portal" }
+                EqText { variant: TextVariant::Muted,
+                    "An EqPortal rendered from inside a clipped, transformed box still paints above the page."
+                }
+                div { class: "relative h-24 overflow-hidden rounded-md border border-[var(--color-card-border)] p-3 scale-100",
+                    EqButton {
+                        variant: ButtonVariant::Outline,
+                        on_click: move |_| panel_open.toggle(),
+                        if panel_open() { "Close panel" } else { "Open panel" }
+                    }
+                    EqPortal {
+                        active: panel_open(),
+                        restore_focus: true,
+                        on_escape: move |_| panel_open.set(false),
+                        on_outside_click: move |_| panel_open.set(false),
+                        if panel_open() {
+                            div { class: "fixed z-50 bottom-6 right-6 w-64 rounded-xl p-4 shadow-xl bg-[var(--color-card)] border border-[var(--color-card-border)]",
+                                EqText { variant: TextVariant::Body, "Portaled panel. Click outside or press Escape." }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(id: u64, active: bool, passive: bool) -> OverlayLayer {
+        OverlayLayer {
+            id,
+            content: VNode::empty(),
+            active,
+            passive,
+            on_escape: None,
+            on_outside_click: None,
+            contexts: CarriedContexts::default(),
+        }
+    }
+
+    fn ids(layers: &[&OverlayLayer]) -> Vec<u64> {
+        layers.iter().map(|l| l.id).collect()
+    }
+
+    #[test]
+    fn activation_moves_layer_to_top() {
+        let mut layers = Vec::new();
+        upsert_layer(&mut layers, layer(1, false, false));
+        upsert_layer(&mut layers, layer(2, true, false));
+        upsert_layer(&mut layers, layer(1, true, false));
+        assert_eq!(layers.iter().map(|l| l.id).collect::<Vec<_>>(), vec![2, 1]);
+        // Refreshing an already-active layer keeps its position.
+        upsert_layer(&mut layers, layer(2, true, false));
+        assert_eq!(layers.iter().map(|l| l.id).collect::<Vec<_>>(), vec![2, 1]);
+    }
+
+    #[test]
+    fn top_skips_passive_and_inactive() {
+        let layers = vec![layer(1, true, false), layer(2, false, false), layer(3, true, true)];
+        assert_eq!(top_layer(&layers).map(|l| l.id), Some(1));
+        assert!(top_layer(&[layer(4, true, true)]).is_none());
+    }
+
+    #[test]
+    fn passive_layers_paint_last() {
+        let layers = vec![layer(1, true, true), layer(2, true, false), layer(3, false, false)];
+        let refs: Vec<&OverlayLayer> = layers.iter().collect();
+        assert_eq!(ids(&refs), vec![1, 2, 3]);
+        assert_eq!(ids(&paint_order(&layers)), vec![2, 3, 1]);
+    }
+
+    #[test]
+    fn layer_content_sees_its_layer() {
+        use std::cell::Cell;
+        thread_local! {
+            static SEEN: Cell<Option<Option<u64>>> = const { Cell::new(None) };
+        }
+
+        #[component]
+        fn Probe() -> Element {
+            SEEN.with(|seen| seen.set(Some(current_layer())));
+            rsx! {}
+        }
+
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                EqOverlayRoot {
+                    super::super::EqPortal { Probe {} }
+                }
+            }
+        });
+        dom.rebuild_in_place();
+        dom.process_events();
+        dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+        assert!(matches!(SEEN.with(Cell::get), Some(Some(_))));
+    }
+
+    #[test]
+    fn layer_content_sees_contexts_from_the_portal_site() {
+        use std::cell::Cell;
+        thread_local! {
+            static SEEN: Cell<Option<bool>> = const { Cell::new(None) };
+        }
+
+        #[component]
+        fn Themed(children: Element) -> Element {
+            use_context_provider(|| Signal::new(EqTheme::Nord));
+            children
+        }
+
+        #[component]
+        fn Probe() -> Element {
+            SEEN.with(|seen| seen.set(Some(try_consume_context::<Signal<EqTheme>>().is_some())));
+            rsx! {}
+        }
+
+        let mut dom = VirtualDom::new(|| {
+            rsx! {
+                EqOverlayRoot {
+                    Themed {
+                        super::super::EqPortal { Probe {} }
+                    }
+                }
+            }
+        });
+        dom.rebuild_in_place();
+        dom.process_events();
+        dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+        assert_eq!(SEEN.with(Cell::get), Some(true));
+    }
+
+    #[test]
+    fn renders_without_layers() {
+        let mut dom = VirtualDom::new(|| {
+            rsx! { EqOverlayRoot { "content" } }
+        });
+        dom.rebuild_in_place();
+    }
+}
@@ -0,0 +1,30 @@
+//! Style constants for the overlay layer.
+
+/// Root wrapper - layout-neutral so it can sit around the whole app.
+pub const ROOT: &str = "contents";
+
+/// One portaled layer. Anchored to the page origin; fixed-position
+/// content inside it positions against the viewport as usual. The
+/// stacking `z-index` is set inline.
+pub const LAYER: &str = "absolute top-0 left-0";
+
+/// Transparent full-viewport catcher for outside clicks, rendered
+/// beneath the layer's content while the layer is active.
+pub const OUTSIDE: &str = "fixed inset-0 z-40";
+
+/// In-place wrapper used when no `EqOverlayRoot` is mounted.
+pub const INLINE: &str = "contents";
+
+/// Focus-trap sentinel - focusable but invisible and out of layout.
+pub const GUARD: &str = "fixed top-0 left-0 w-px h-px overflow-hidden opacity-0 pointer-events-none";
+
+/// All style tokens for playground introspection.
+pub fn catalog() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("ROOT", ROOT),
+        ("LAYER", LAYER),
+        ("OUTSIDE", OUTSIDE),
+        ("INLINE", INLINE),
+        ("GUARD", GUARD),
+    ]
+}
@@ -29,6 +29,7 @@ use crate::atoms::{EqScrollableSpace, EqText, TextVariant};
 use crate::eq_theme::EqTheme;
 use crate::molecules::TreeNode;
 use crate::organisms::{EqAppShell, EqFooter, EqHeader};
+use crate::overlay::EqOverlayRoot;
 use crate::playground::playground_types::{ComponentCategory, ComponentDescriptor};
 use crate::{UI_TAILWIND_CSS, UI_INDEX_CSS, UI_BUTTONS_CSS};
 use dioxus::prelude::*;
@@ -205,72 +206,74 @@ pub fn EqPlayground(
 
         EqThemeRenderer {}
 
-        EqAppShell {
-            class: "pt-6 px-2",
-            header: rsx! {
-                EqHeader {
-                    site_title: site_title,
-                    nav: rsx! {
-                        // Hamburger button - mobile only
-                        li { class: s::HAMBURGER,
-                            button {
-                                class: s::HAMBURGER_BTN,
-                                onclick: move |_| sidebar_open.set(!sidebar_open()),
-                                svg {
-                                    class: s::HAMBURGER_ICON,
-                                    xmlns: "http://www.w3.org/2000/svg",
-                                    fill: "none",
-                                    view_box: "0 0 24 24",
-                                    stroke_width: "2",
-                                    stroke: "currentColor",
-                                    if sidebar_open() {
-                                        path { d: "M6 18 18 6M6 6l12 12" }
-                                    } else {
-                                        path { d: "M3.75 6.75h16.5M3.75 12h16.5m-16.5 5.25h16.5" }
+        EqOverlayRoot {
+            EqAppShell {
+                class: "pt-6 px-2",
+                header: rsx! {
+                    EqHeader {
+                        site_title: site_title,
+                        nav: rsx! {
+                            // Hamburger button - mobile only
+                            li { class: s::HAMBURGER,
+                                button {
+                                    class: s::HAMBURGER_BTN,
+                                    onclick: move |_| sidebar_open.set(!sidebar_open()),
+                                    svg {
+                                        class: s::HAMBURGER_ICON,
+                                        xmlns: "http://www.w3.org/2000/svg",
+                                        fill: "none",
+                                        view_box: "0 0 24 24",
+                                        stroke_width: "2",
+                                        stroke: "currentColor",
+                                        if sidebar_open() {
+                                            path { d: "M6 18 18 6M6 6l12 12" }
+                                        } else {
+                                            path { d: "M3.75 6.75h16.5M3.75 12h16.5m-16.5 5.25h16.5" }
+                                        }
                                     }
                                 }
                             }
-                        }
-                        li { ThemeSwitcher {} }
-                    },
-                }
-            },
-            footer: rsx! {
-                EqFooter { copyright_holder: copyright_holder }
-            },
+                            li { ThemeSwitcher {} }
+                        },
+                    }
+                },
+                footer: rsx! {
+                    EqFooter { copyright_holder: copyright_holder }
+                },
 
-            // Two-panel layout
-            div { class: s::LAYOUT,
+                // Two-panel layout
+                div { class: s::LAYOUT,
 
-                // Mobile overlay backdrop
-                if sidebar_open() {
-                    div {
-                        class: s::MOBILE_BACKDROP,
-                        onclick: move |_| sidebar_open.set(false),
+                    // Mobile overlay backdrop
+                    if sidebar_open() {
+                        div {
+                            class: s::MOBILE_BACKDROP,
+                            onclick: move |_| sidebar_open.set(false),
+                        }
                     }
-                }
 
-                // Sidebar
-                aside {
-                    class: if sidebar_open() { s::SIDEBAR_MOBILE_OPEN } else { s::SIDEBAR_MOBILE_CLOSED },
-                    EqScrollableSpace {
-                        crate::molecules::EqTree {
-                            nodes: tree,
-                            selected: selected(),
-                            on_select: move |id: String| {
-                                selected.set(Some(id));
-                                sidebar_open.set(false);
-                            },
-                            show_count: true,
+                    // Sidebar
+                    aside {
+                        class: if sidebar_open() { s::SIDEBAR_MOBILE_OPEN } else { s::SIDEBAR_MOBILE_CLOSED },
+                        EqScrollableSpace {
+                            crate::molecules::EqTree {
+                                nodes: tree,
+                                selected: selected(),
+                                on_select: move |id: String| {
+                                    selected.set(Some(id));
+                                    sidebar_open.set(false);
+                                },
+                                show_count: true,
+                            }
                         }
                     }
-                }
 
-                // Right preview panel
-                div { class: s::PREVIEW_PANEL,
-                    PreviewPanel {
-                        selected: selected(),
-                        descriptors: descriptors,
+                    // Right preview panel
+                    div { class: s::PREVIEW_PANEL,
+                        PreviewPanel {
+                            selected: selected(),
+                            descriptors: descriptors,
+                        }
                     }
                 }
             }
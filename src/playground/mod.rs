@@ -4,7 +4,7 @@
 //!
 //! - **`ComponentDescriptor`** - self-describing metadata each component exports
 //! - **`EqPlayground`** - the reusable two-panel showcase organism
-//! - **Helpers** - DemoSection, CodeBlock, StyleInfo, PropSelect, PropInput, PropToggle
+//! - **Helpers** - DemoSection, StyleInfo, PropSelect, PropInput, PropToggle
 //!
 //! ```rust,ignore
 //! use eq_ui::playground::{EqPlayground, ComponentDescriptor};
@@ -24,7 +24,7 @@ pub mod playground_guide;
 
 pub use playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
 pub use playground_helpers::{
-    CodeBlock, DemoSection, StyleInfo, PropSelect, PropInput, PropToggle,
+    DemoSection, StyleInfo, PropSelect, PropInput, PropToggle,
     format_catalog, highlight_rust, highlight_styles,
     PROP_ROW, PROP_LABEL, PROP_CONTROL,
 };
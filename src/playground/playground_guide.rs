@@ -7,7 +7,9 @@
 
 use dioxus::prelude::*;
 use crate::atoms::EqDivider;
-use crate::playground::playground_helpers::{CodeBlock, DemoSection};
+use crate::playground::playground_helpers::{DemoSection};
+#[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
 use crate::playground::playground_types::{ComponentDescriptor, ComponentCategory, UsageExample};
 
 // ── Descriptor ────────────────────────────────────────────────────
@@ -146,7 +148,7 @@ fn GuideContent() -> Element {
     ComponentDescriptor, ComponentCategory, UsageExample,
 };
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, StyleInfo, PropSelect,
+    DemoSection, StyleInfo, PropSelect,
     PropToggle, format_catalog,
 };
 
@@ -216,7 +218,7 @@ fn DemoEqMyWidget() -> Element {
                 file: "eq_my_widget_styles.rs",
                 styles: format_catalog(&s::catalog()),
             }
-            CodeBlock { code }
+            EqCodeBlock { code, language: CodeLanguage::Rust, title: "Example Usage" }
         }
     }
 }"#;
@@ -297,10 +299,12 @@ use dioxus::prelude::*;
 // These imports are only needed for the playground demo
 #[cfg(feature = "playground")]
 use crate::playground::playground_helpers::{
-    CodeBlock, DemoSection, StyleInfo, PropSelect,
+    DemoSection, StyleInfo, PropSelect,
     PropToggle, format_catalog,
 };
 #[cfg(feature = "playground")]
+use crate::atoms::{CodeLanguage, EqCodeBlock};
+#[cfg(feature = "playground")]
 use crate::atoms::{EqText, TextVariant};
 #[cfg(feature = "playground")]
 use crate::playground::playground_types::{
@@ -466,7 +470,7 @@ fn GalleryEqMyWidget() -> Element { /* ... */ }"#;
                 }
             }
 
-            CodeBlock { code: descriptor_code.to_string() }
+            EqCodeBlock { code: descriptor_code, language: CodeLanguage::Rust }
 
             EqDivider {}
 
@@ -494,7 +498,7 @@ fn GalleryEqMyWidget() -> Element { /* ... */ }"#;
                 " so the playground can introspect them."
             }
 
-            CodeBlock { code: styles_code.to_string() }
+            EqCodeBlock { code: styles_code, language: CodeLanguage::Rust }
 
             // Step 2
             Step { number: 2, title: "Create the component file".to_string(),
@@ -510,7 +514,7 @@ fn GalleryEqMyWidget() -> Element { /* ... */ }"#;
             }
 
             GuideSubheading { text: "The descriptor function".to_string() }
-            CodeBlock { code: descriptor_code.to_string() }
+            EqCodeBlock { code: descriptor_code, language: CodeLanguage::Rust }
 
             GuideSubheading { text: "The interactive demo".to_string() }
             GuideParagraph {
@@ -525,17 +529,17 @@ fn GalleryEqMyWidget() -> Element { /* ... */ }"#;
                 ", show a live preview, and end with "
                 InlineCode { text: "StyleInfo".to_string() }
                 " and "
-                InlineCode { text: "CodeBlock".to_string() }
+                InlineCode { text: "EqCodeBlock".to_string() }
                 "."
             }
-            CodeBlock { code: demo_code.to_string() }
+            EqCodeBlock { code: demo_code, language: CodeLanguage::Rust }
 
             GuideSubheading { text: "The gallery".to_string() }
             GuideParagraph {
                 "The gallery should show all variants and sizes in a compact layout \
                  so users can compare them at a glance."
             }
-            CodeBlock { code: gallery_code.to_string() }
+            EqCodeBlock { code: gallery_code, language: CodeLanguage::Rust }
 
             // Step 3
             Step { number: 3, title: "Register the module".to_string(),
@@ -546,7 +550,7 @@ fn GalleryEqMyWidget() -> Element { /* ... */ }"#;
                 ")."
             }
 
-            CodeBlock { code: mod_registration.to_string() }
+            EqCodeBlock { code: mod_registration, language: CodeLanguage::Rust }
 
             // Step 4
             Step { number: 4, title: "Register the descriptor".to_string(),
@@ -559,7 +563,7 @@ fn GalleryEqMyWidget() -> Element { /* ... */ }"#;
                 "."
             }
 
-            CodeBlock { code: lib_registration.to_string() }
+            EqCodeBlock { code: lib_registration, language: CodeLanguage::Rust }
 
             // Step 5
             Step { number: 5, title: "Test it".to_string(),
@@ -595,7 +599,7 @@ fn GalleryEqMyWidget() -> Element { /* ... */ }"#;
                 ". Playground-specific imports (helpers, types) are also gated."
             }
 
-            CodeBlock { code: feature_gate_code.to_string() }
+            EqCodeBlock { code: feature_gate_code, language: CodeLanguage::Rust }
 
             Tip {
                 "The "
@@ -607,7 +611,7 @@ fn GalleryEqMyWidget() -> Element { /* ... */ }"#;
                 ":"
             }
 
-            CodeBlock { code: cargo_toml_code.to_string() }
+            EqCodeBlock { code: cargo_toml_code, language: CodeLanguage::Toml }
 
             EqDivider {}
 
@@ -653,7 +657,7 @@ fn GalleryEqMyWidget() -> Element { /* ... */ }"#;
                 }
             }
 
-            CodeBlock { code: styles_code.to_string() }
+            EqCodeBlock { code: styles_code, language: CodeLanguage::Rust }
 
             EqDivider {}
 
@@ -670,7 +674,7 @@ fn GalleryEqMyWidget() -> Element { /* ... */ }"#;
                 ":"
             }
 
-            CodeBlock { code: launch_code.to_string() }
+            EqCodeBlock { code: launch_code, language: CodeLanguage::Rust }
 
             GuideSubheading { text: "Injecting custom components".to_string() }
 
@@ -680,7 +684,7 @@ fn GalleryEqMyWidget() -> Element { /* ... */ }"#;
                  You can also customise the site title and copyright holder:"
             }
 
-            CodeBlock { code: custom_inject_code.to_string() }
+            EqCodeBlock { code: custom_inject_code, language: CodeLanguage::Rust }
 
             Tip {
                 "The "
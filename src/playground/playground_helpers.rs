@@ -4,7 +4,6 @@
 //! overhead to production builds.  It provides:
 //!
 //! - **DemoSection** - titled wrapper for each demo area
-//! - **CodeBlock** - Gruvbox-themed Rust syntax-highlighted code block
 //! - **StyleInfo** - collapsible style token viewer with Tailwind highlighting
 //! - **PropSelect / PropInput / PropToggle** - uniform controls for live prop editing
 //! - Syntax highlighting utilities (Rust + Tailwind style tokens)
@@ -13,7 +12,6 @@
 //! import helpers from here rather than duplicating them.
 
 use dioxus::prelude::*;
-use crate::atoms::{EqText, TextVariant};
 
 // ── DemoSection ────────────────────────────────────────────────────
 
@@ -297,33 +295,6 @@ pub fn highlight_styles(input: &str) -> String {
     out
 }
 
-// ── CodeBlock component ────────────────────────────────────────────
-
-/// Renders a Gruvbox-themed code block with Rust syntax highlighting.
-#[component]
-pub fn CodeBlock(code: String) -> Element {
-    let highlighted = highlight_rust(&code);
-
-    rsx! {
-        div { class: "mt-6 space-y-2",
-            EqText {
-                variant: TextVariant::Caption,
-                class: "font-semibold uppercase tracking-wider",
-                "Example Usage"
-            }
-            div {
-                class: "rounded-lg overflow-hidden",
-                style: "border: 1px solid #6b2020;",
-                pre {
-                    class: "p-4 overflow-x-auto text-xs leading-relaxed font-mono",
-                    style: format!("background:{};color:{};", GRV_BG, GRV_FG),
-                    code { dangerous_inner_html: "{highlighted}" }
-                }
-            }
-        }
-    }
-}
-
 // ── StyleInfo component ────────────────────────────────────────────
 
 /// Collapsible panel showing which style tokens a component uses,
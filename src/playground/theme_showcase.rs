@@ -265,6 +265,10 @@ fn DemoThemeShowcase() -> Element {
                         ColorSwatch { label: "Code Comment", var_name: "color-code-comment" }
                         ColorSwatch { label: "Code Keyword", var_name: "color-code-keyword" }
                         ColorSwatch { label: "Code String", var_name: "color-code-string" }
+                        ColorSwatch { label: "Code Number", var_name: "color-code-number" }
+                        ColorSwatch { label: "Code Type", var_name: "color-code-type" }
+                        ColorSwatch { label: "Code Function", var_name: "color-code-function" }
+                        ColorSwatch { label: "Code Attribute", var_name: "color-code-attribute" }
                     }
                 }
 
@@ -34,3 +34,6 @@ pub use crate::molecules::{EqCard, EqCardBody, EqCardFooter, EqCardHeader};
 
 // Common organisms
 pub use crate::organisms::{EqAppShell, EqFooter, EqHeader, EqPageSection};
+
+// Overlays
+pub use crate::overlay::{EqOverlayRoot, EqPortal};
@@ -0,0 +1,396 @@
+//! URL and HTML sanitization shared by every component that renders
+//! untrusted markup.
+//!
+//! [`safe_url`] is the single link / image URL allowlist and
+//! [`sanitize_html`] the single tag and attribute allowlist. EqMarkdown
+//! runs raw HTML blocks through it, and EqRichTextEditor runs pasted
+//! HTML through it before mapping the result onto its document model,
+//! so the two can never disagree about what is safe.
+
+/// Returns the URL trimmed if it is safe to put in `href` / `src`:
+/// relative, root-relative and fragment URLs plus the `http`, `https`,
+/// `mailto` and `tel` schemes. Anything else (`javascript:`, `data:`,
+/// `vbscript:` …) and an empty URL are rejected.
+pub fn safe_url(url: &str) -> Option<String> {
+    // Browsers ignore ASCII whitespace and control characters inside a
+    // scheme, so strip them before looking at it.
+    let cleaned: String = url.trim().chars().filter(|c| !c.is_ascii_control()).collect();
+    if cleaned.is_empty() {
+        return None;
+    }
+    match cleaned.find([':', '/', '?', '#']) {
+        Some(i) if cleaned[i..].starts_with(':') => {
+            let scheme = cleaned[..i].to_ascii_lowercase();
+            matches!(scheme.as_str(), "http" | "https" | "mailto" | "tel").then_some(cleaned)
+        }
+        _ => Some(cleaned),
+    }
+}
+
+/// Decodes named (common) and numeric HTML entities. Unknown entities
+/// are left as written.
+pub fn decode_entities(s: &str) -> String {
+    if !s.contains('&') {
+        return s.to_string();
+    }
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(i) = rest.find('&') {
+        out.push_str(&rest[..i]);
+        rest = &rest[i..];
+        let decoded = rest.find(';').filter(|&e| e <= 10).and_then(|end| {
+            let entity = &rest[1..end];
+            let ch = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some('\u{a0}'),
+                "copy" => Some('©'),
+                "mdash" => Some('—'),
+                "ndash" => Some('–'),
+                "hellip" => Some('…'),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            ch.map(|c| (c, end))
+        });
+        match decoded {
+            Some((ch, end)) => {
+                out.push(ch);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+// ── Tokenizing ─────────────────────────────────────────────────────
+
+/// One tag read by [`read_tag`].
+pub(crate) struct Tag<'a> {
+    /// Lowercased name; `!` for doctypes and processing instructions.
+    pub name: String,
+    pub closing: bool,
+    pub self_closing: bool,
+    /// Raw attribute source, without the trailing `/`.
+    pub attrs: &'a str,
+    /// Byte length of the whole tag, including `<` and `>`.
+    pub len: usize,
+}
+
+/// Reads one tag at the start of `s`, respecting quoted attribute
+/// values. Returns `None` when `s` does not start with a complete tag.
+pub(crate) fn read_tag(s: &str) -> Option<Tag<'_>> {
+    let bytes = s.as_bytes();
+    if bytes.first() != Some(&b'<') {
+        return None;
+    }
+    let closing = bytes.get(1) == Some(&b'/');
+    let mut i = if closing { 2 } else { 1 };
+    if matches!(bytes.get(i), Some(b'!' | b'?')) {
+        let end = s.find('>')?;
+        return Some(Tag { name: "!".into(), closing: true, self_closing: false, attrs: "", len: end + 1 });
+    }
+    if !bytes.get(i).is_some_and(u8::is_ascii_alphabetic) {
+        return None;
+    }
+    let name_start = i;
+    while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'-' || bytes[i] == b':') {
+        i += 1;
+    }
+    let name = s[name_start..i].to_ascii_lowercase();
+
+    let attr_start = i;
+    let mut quote: Option<u8> = None;
+    while i < bytes.len() {
+        match (quote, bytes[i]) {
+            (Some(q), c) if c == q => quote = None,
+            (None, b'"' | b'\'') => quote = Some(bytes[i]),
+            (None, b'>') => break,
+            _ => {}
+        }
+        i += 1;
+    }
+    if i >= bytes.len() {
+        return None;
+    }
+    let attrs = s[attr_start..i].trim();
+    Some(Tag {
+        name,
+        closing,
+        self_closing: attrs.ends_with('/'),
+        attrs: attrs.trim_end_matches('/'),
+        len: i + 1,
+    })
+}
+
+/// Parses a raw attribute string into lowercased names and decoded
+/// values.
+pub(crate) fn parse_attrs(src: &str) -> Vec<(String, String)> {
+    let mut attrs = Vec::new();
+    let chars: Vec<char> = src.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        while i < chars.len() && (chars[i].is_whitespace() || chars[i] == '/') {
+            i += 1;
+        }
+        let start = i;
+        while i < chars.len() && !chars[i].is_whitespace() && chars[i] != '=' && chars[i] != '/' {
+            i += 1;
+        }
+        if start == i {
+            i += 1;
+            continue;
+        }
+        let name: String = chars[start..i].iter().collect::<String>().to_ascii_lowercase();
+        while i < chars.len() && chars[i].is_whitespace() {
+            i += 1;
+        }
+        let mut value = String::new();
+        if chars.get(i) == Some(&'=') {
+            i += 1;
+            while i < chars.len() && chars[i].is_whitespace() {
+                i += 1;
+            }
+            if let Some(&q) = chars.get(i).filter(|c| **c == '"' || **c == '\'') {
+                i += 1;
+                while i < chars.len() && chars[i] != q {
+                    value.push(chars[i]);
+                    i += 1;
+                }
+                i += 1;
+            } else {
+                while i < chars.len() && !chars[i].is_whitespace() {
+                    value.push(chars[i]);
+                    i += 1;
+                }
+            }
+        }
+        attrs.push((name, decode_entities(&value)));
+    }
+    attrs
+}
+
+// ── HTML sanitization ──────────────────────────────────────────────
+
+/// Tags kept by [`sanitize_html`].
+const ALLOWED_TAGS: &[&str] = &[
+    "a", "abbr", "article", "b", "blockquote", "br", "code", "dd", "del", "details", "div",
+    "dl", "dt", "em", "figcaption", "figure", "footer", "h1", "h2", "h3", "h4", "h5", "h6",
+    "header", "hr", "i", "img", "ins", "kbd", "li", "mark", "ol", "p", "pre", "s", "samp",
+    "section", "small", "span", "strike", "strong", "sub", "summary", "sup", "table", "tbody",
+    "td", "tfoot", "th", "thead", "tr", "tt", "u", "ul",
+];
+
+/// Tags whose content is dropped along with the tag.
+const DROPPED_TAGS: &[&str] = &[
+    "script", "style", "iframe", "object", "embed", "template", "noscript", "textarea",
+    "select", "button", "svg", "math", "title", "head",
+];
+
+const VOID_TAGS: &[&str] = &["br", "hr", "img"];
+
+/// Attributes kept per tag; URLs are additionally checked with
+/// [`safe_url`] and `style` is reduced by [`safe_style`].
+fn allowed_attr(tag: &str, attr: &str) -> bool {
+    matches!(
+        (tag, attr),
+        (_, "style")
+            | ("a", "href" | "title")
+            | ("img", "src" | "alt" | "title" | "width" | "height")
+            | ("td" | "th", "align" | "colspan" | "rowspan")
+            | ("ol", "start")
+            | ("abbr", "title")
+            | ("details", "open")
+    )
+}
+
+/// Keeps only the font weight, style and decoration declarations of an
+/// inline style (what word processors use for bold, italic, underline
+/// and strikethrough), with plain keyword or numeric values.
+fn safe_style(style: &str) -> Option<String> {
+    let kept: Vec<String> = style
+        .split(';')
+        .filter_map(|decl| {
+            let (prop, value) = decl.split_once(':')?;
+            let prop = prop.trim().to_ascii_lowercase();
+            let value = value.trim();
+            let known = matches!(
+                prop.as_str(),
+                "font-weight" | "font-style" | "text-decoration" | "text-decoration-line"
+            );
+            let plain = !value.is_empty()
+                && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == ' ');
+            (known && plain).then(|| format!("{prop}:{value}"))
+        })
+        .collect();
+    (!kept.is_empty()).then(|| kept.join(";"))
+}
+
+fn escape_html(s: &str, out: &mut String) {
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+/// Reduces an HTML fragment to an allowlist of tags and attributes.
+///
+/// Scripts, styles, frames and their content are removed, event
+/// handler attributes are dropped, URLs must pass [`safe_url`], inline
+/// styles keep only font and text-decoration keywords, unknown tags are
+/// unwrapped (their text is kept), and the output is always
+/// well-formed.
+pub fn sanitize_html(html: &str) -> String {
+    let mut out = String::new();
+    let mut open: Vec<String> = Vec::new();
+    let mut dropping: Option<(String, usize)> = None;
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix("<!--") {
+            rest = after.find("-->").map(|i| &after[i + 3..]).unwrap_or("");
+            continue;
+        }
+        let Some(tag) = read_tag(rest) else {
+            let first = rest.chars().next().map_or(1, char::len_utf8);
+            let next = rest[first..].find('<').map(|i| i + first).unwrap_or(rest.len());
+            if dropping.is_none() {
+                escape_html(&decode_entities(&rest[..next]), &mut out);
+            }
+            rest = &rest[next..];
+            continue;
+        };
+        rest = &rest[tag.len..];
+        let name = tag.name;
+
+        if let Some((drop_tag, depth)) = &mut dropping {
+            if *drop_tag == name && !tag.self_closing {
+                if tag.closing {
+                    *depth -= 1;
+                } else {
+                    *depth += 1;
+                }
+                if *depth == 0 {
+                    dropping = None;
+                }
+            }
+            continue;
+        }
+        if DROPPED_TAGS.contains(&name.as_str()) {
+            if !tag.closing && !tag.self_closing {
+                dropping = Some((name, 1));
+            }
+            continue;
+        }
+        if !ALLOWED_TAGS.contains(&name.as_str()) {
+            continue;
+        }
+
+        if tag.closing {
+            if let Some(pos) = open.iter().rposition(|t| *t == name) {
+                for t in open.drain(pos..).rev() {
+                    out.push_str(&format!("</{t}>"));
+                }
+            }
+            continue;
+        }
+
+        out.push('<');
+        out.push_str(&name);
+        for (attr, value) in parse_attrs(tag.attrs) {
+            if !allowed_attr(&name, &attr) {
+                continue;
+            }
+            let value = match attr.as_str() {
+                "href" | "src" => safe_url(&value),
+                "style" => safe_style(&value),
+                _ => Some(value),
+            };
+            let Some(value) = value else { continue };
+            out.push(' ');
+            out.push_str(&attr);
+            out.push_str("=\"");
+            escape_html(&value, &mut out);
+            out.push('"');
+        }
+        out.push('>');
+        if !VOID_TAGS.contains(&name.as_str()) {
+            open.push(name);
+        }
+    }
+
+    for t in open.into_iter().rev() {
+        out.push_str(&format!("</{t}>"));
+    }
+    out
+}
+
+// ── Tests ──────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn safe_url_rules() {
+        assert_eq!(safe_url(" https://a.b "), Some("https://a.b".into()));
+        assert_eq!(safe_url("mailto:x@y.z"), Some("mailto:x@y.z".into()));
+        assert_eq!(safe_url("/rel:ative"), Some("/rel:ative".into()));
+        assert_eq!(safe_url("page?q=a:b"), Some("page?q=a:b".into()));
+        assert_eq!(safe_url("#frag"), Some("#frag".into()));
+        assert_eq!(safe_url("JavaScript:alert(1)"), None);
+        assert_eq!(safe_url("java\tscript:alert(1)"), None);
+        assert_eq!(safe_url("data:image/png;base64,AA"), None);
+        assert_eq!(safe_url("vbscript:x"), None);
+        assert_eq!(safe_url(""), None);
+        assert_eq!(safe_url("   "), None);
+    }
+
+    #[test]
+    fn sanitize_keeps_safe_markup_and_closes_tags() {
+        assert_eq!(
+            sanitize_html("<p>x<img src=\"/a.png\" alt=\"A\" style=\"x\"><em>open <iframe src=y></iframe>"),
+            "<p>x<img src=\"/a.png\" alt=\"A\"><em>open </em></p>"
+        );
+        assert_eq!(sanitize_html("1 < 2 &amp; <custom>tag</custom>"), "1 &lt; 2 &amp; tag");
+    }
+
+    #[test]
+    fn sanitize_reduces_styles_and_respects_quotes() {
+        assert_eq!(
+            sanitize_html(
+                "<span style=\"font-weight:700; background:url(javascript:x); position:fixed\">b</span>"
+            ),
+            "<span style=\"font-weight:700\">b</span>"
+        );
+        assert_eq!(
+            sanitize_html("<a title=\"a>b\" href=\"/x\" onclick=\"y()\">c</a>"),
+            "<a title=\"a&gt;b\" href=\"/x\">c</a>"
+        );
+        assert_eq!(sanitize_html("<button>no</button><b>yes</b>"), "<b>yes</b>");
+    }
+
+    #[test]
+    fn sanitize_keeps_non_ascii_text() {
+        assert_eq!(sanitize_html("é"), "é");
+        assert_eq!(sanitize_html("<p>élan</p>"), "<p>élan</p>");
+        assert_eq!(sanitize_html("</div><br></div>🎉 <b>Über</b>"), "<br>🎉 <b>Über</b>");
+    }
+}
@@ -0,0 +1,797 @@
+//! App-level keyboard shortcuts.
+//!
+//! [`Chord`] parses specs such as `"mod+k"`, `"ctrl+shift+p"`, `"f2"`
+//! or `"?"`. `mod` is Cmd on Apple platforms and Ctrl elsewhere.
+//! Parsing, matching, scope resolution and labels are plain Rust.
+//!
+//! [`use_shortcut`] binds a chord while a component is mounted.
+//! Bindings follow overlay stacking by default: a binding made inside a
+//! modal or menu is active while that overlay is on top, and page
+//! bindings pause while any overlay is open. [`ShortcutScope::Focus`]
+//! narrows a binding to an element subtree that holds focus, and
+//! [`ShortcutScope::Global`] keeps it active everywhere. Inside text
+//! fields only chords with Ctrl, Cmd or Alt fire unless the binding's
+//! [`InputPolicy`] says otherwise.
+//!
+//! [`ShortcutManager`] owns a single document-level `keydown` listener
+//! for the whole app and lists every binding for help overlays. It
+//! lives in the root scope, so [`use_shortcuts`] works anywhere without
+//! a provider component.
+//!
+//! ```no_run
+//! use eq_ui::prelude::*;
+//! use eq_ui::shortcut::{use_shortcut, use_shortcut_with, ShortcutOptions};
+//!
+//! fn app() -> Element {
+//!     let mut saved = use_signal(|| 0);
+//!     use_shortcut("mod+s", move |_| saved += 1);
+//!     use_shortcut_with("?", ShortcutOptions::new("Show shortcuts").global(), move |_| {});
+//!
+//!     rsx! { "Saved {saved} times" }
+//! }
+//! ```
+
+use crate::overlay::root::current_layer;
+use dioxus::core::{Runtime, provide_root_context};
+use dioxus::document;
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// ── Chords ────────────────────────────────────────────────────────
+
+/// Platform conventions for the `mod` key and labels.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Platform {
+    /// Windows, Linux and everything else: `mod` is Ctrl.
+    #[default]
+    Other,
+    /// macOS and iOS: `mod` is Cmd.
+    Mac,
+}
+
+/// A key plus modifiers, e.g. Ctrl+Shift+P.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Chord {
+    key: String,
+    ctrl: bool,
+    alt: bool,
+    shift: bool,
+    meta: bool,
+    /// `mod`: resolved to Cmd or Ctrl per platform.
+    primary: bool,
+}
+
+/// Single printable characters other than letters and digits. Shift is
+/// part of the character itself (`?` is Shift+/), so it is ignored.
+fn is_symbol(key: &str) -> bool {
+    let mut chars = key.chars();
+    matches!((chars.next(), chars.next()), (Some(c), None) if !c.is_ascii_alphanumeric())
+}
+
+fn key_alias(key: &str) -> String {
+    match key {
+        "esc" => "escape".into(),
+        " " => "space".into(),
+        "up" | "down" | "left" | "right" => format!("arrow{key}"),
+        "return" => "enter".into(),
+        "del" => "delete".into(),
+        "plus" => "+".into(),
+        other => other.into(),
+    }
+}
+
+impl Chord {
+    /// Parses `"mod+shift+k"`-style specs, case-insensitively.
+    /// Modifiers: `mod`, `ctrl`/`control`, `alt`/`option`,
+    /// `shift`, `meta`/`cmd`/`super`. Returns `None` for an empty key
+    /// or an unknown modifier.
+    pub fn parse(spec: &str) -> Option<Self> {
+        let spec = spec.trim().to_lowercase();
+        // A trailing "+" after a separator is the plus key itself.
+        let (mods, key) = match spec.strip_suffix("++") {
+            Some(rest) => (rest, "+".to_string()),
+            None => match spec.rsplit_once('+') {
+                Some((mods, key)) => (mods, key.to_string()),
+                None => ("", spec.clone()),
+            },
+        };
+        let key = key_alias(key.trim());
+        if key.is_empty() {
+            return None;
+        }
+        let mut chord = Self { key, ctrl: false, alt: false, shift: false, meta: false, primary: false };
+        for part in mods.split('+').map(str::trim).filter(|p| !p.is_empty()) {
+            match part {
+                "mod" => chord.primary = true,
+                "ctrl" | "control" => chord.ctrl = true,
+                "alt" | "option" | "opt" => chord.alt = true,
+                "shift" => chord.shift = true,
+                "meta" | "cmd" | "command" | "super" => chord.meta = true,
+                _ => return None,
+            }
+        }
+        if is_symbol(&chord.key) {
+            chord.shift = false;
+        }
+        Some(chord)
+    }
+
+    /// The key name, lowercase (`"k"`, `"escape"`, `"?"`).
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    fn resolved(&self, platform: Platform) -> (bool, bool, bool, bool) {
+        let mac = platform == Platform::Mac;
+        (
+            self.ctrl || (self.primary && !mac),
+            self.alt,
+            self.shift,
+            self.meta || (self.primary && mac),
+        )
+    }
+
+    /// Canonical form shared with key presses: modifiers in the order
+    /// ctrl, alt, shift, meta, then the key.
+    pub fn combo(&self, platform: Platform) -> String {
+        let (ctrl, alt, shift, meta) = self.resolved(platform);
+        combo(ctrl, alt, shift, meta, &self.key)
+    }
+
+    /// Whether a key press triggers this chord.
+    pub fn matches(&self, press: &KeyPress, platform: Platform) -> bool {
+        self.combo(platform) == press.combo()
+    }
+
+    /// Human-readable label: `⌘⇧P` on Mac, `Ctrl+Shift+P` elsewhere.
+    pub fn label(&self, platform: Platform) -> String {
+        let (ctrl, alt, shift, meta) = self.resolved(platform);
+        let key = match self.key.as_str() {
+            "arrowup" => "↑".to_string(),
+            "arrowdown" => "↓".to_string(),
+            "arrowleft" => "←".to_string(),
+            "arrowright" => "→".to_string(),
+            "escape" => "Esc".to_string(),
+            k if k.chars().count() == 1 || (k.starts_with('f') && k[1..].parse::<u8>().is_ok()) => {
+                k.to_uppercase()
+            }
+            k => {
+                let mut chars = k.chars();
+                chars.next().map_or_else(String::new, |c| c.to_uppercase().chain(chars).collect())
+            }
+        };
+        if platform == Platform::Mac {
+            let mut out = String::new();
+            for (on, symbol) in [(ctrl, "⌃"), (alt, "⌥"), (shift, "⇧"), (meta, "⌘")] {
+                if on {
+                    out.push_str(symbol);
+                }
+            }
+            out + &key
+        } else {
+            let mut parts: Vec<&str> = [(ctrl, "Ctrl"), (alt, "Alt"), (shift, "Shift"), (meta, "Meta")]
+                .into_iter()
+                .filter_map(|(on, name)| on.then_some(name))
+                .collect();
+            parts.push(&key);
+            parts.join("+")
+        }
+    }
+}
+
+fn combo(ctrl: bool, alt: bool, shift: bool, meta: bool, key: &str) -> String {
+    let shift = shift && !is_symbol(key);
+    let mut out = String::new();
+    for (on, name) in [(ctrl, "ctrl+"), (alt, "alt+"), (shift, "shift+"), (meta, "meta+")] {
+        if on {
+            out.push_str(name);
+        }
+    }
+    out + key
+}
+
+/// A `keydown` as reported by the listener script.
+#[derive(Clone, PartialEq, Debug, Default, Deserialize)]
+pub struct KeyPress {
+    /// `KeyboardEvent.key`.
+    pub key: String,
+    /// `KeyboardEvent.code`.
+    pub code: String,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub meta: bool,
+    /// The event came from a text input, textarea, select or
+    /// contenteditable element.
+    pub editable: bool,
+    /// Ids of the target and its ancestors, innermost first.
+    #[serde(default)]
+    pub focus_path: Vec<String>,
+    /// Topmost open overlay layer, `None` when none is open.
+    #[serde(default)]
+    pub top_layer: Option<u64>,
+}
+
+impl KeyPress {
+    /// Normalized key name. Letters come from `key` (so layouts map
+    /// naturally) and fall back to `code` when a modifier turned them
+    /// into another character; digits always come from `code`.
+    pub fn key_name(&self) -> String {
+        let lower = self.key.to_lowercase();
+        if lower.len() == 1 && lower.as_bytes()[0].is_ascii_lowercase() {
+            return lower;
+        }
+        if let Some(letter) = self.code.strip_prefix("Key").filter(|c| c.len() == 1) {
+            return letter.to_ascii_lowercase();
+        }
+        if let Some(digit) = self.code.strip_prefix("Digit").filter(|d| d.len() == 1) {
+            return digit.to_string();
+        }
+        key_alias(&lower)
+    }
+
+    /// Canonical form, comparable with [`Chord::combo`].
+    pub fn combo(&self) -> String {
+        combo(self.ctrl, self.alt, self.shift, self.meta, &self.key_name())
+    }
+
+    /// Whether the press can fire inside a text field: only chords with
+    /// Ctrl, Cmd or Alt do.
+    pub fn fires_in_editable(&self) -> bool {
+        self.ctrl || self.meta || self.alt
+    }
+}
+
+// ── Scopes ────────────────────────────────────────────────────────
+
+/// Where a binding is active.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub enum ShortcutScope {
+    /// Active everywhere, even while an overlay is open.
+    Global,
+    /// Active while the overlay layer the binding was registered in is
+    /// the topmost open one; bindings outside any overlay are active
+    /// while no overlay is open.
+    #[default]
+    Layer,
+    /// Active while focus is inside the element with this id. Takes
+    /// precedence over layer and global bindings, innermost first.
+    Focus(String),
+}
+
+/// Whether a binding fires while typing in a text field.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum InputPolicy {
+    /// Only when the chord uses Ctrl, Cmd or Alt, so plain keys type.
+    #[default]
+    Modified,
+    /// Always.
+    Always,
+    /// Never.
+    Never,
+}
+
+impl InputPolicy {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Modified => "modified",
+            Self::Always => "always",
+            Self::Never => "never",
+        }
+    }
+}
+
+/// Options for [`use_shortcut_with`] and [`ShortcutManager::register_with`].
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ShortcutOptions {
+    /// Shown in help overlays.
+    pub description: String,
+    pub scope: ShortcutScope,
+    pub inputs: InputPolicy,
+}
+
+impl ShortcutOptions {
+    /// Layer-scoped options with `description`.
+    pub fn new(description: impl Into<String>) -> Self {
+        Self { description: description.into(), ..Default::default() }
+    }
+
+    /// Builder: active everywhere.
+    pub fn global(mut self) -> Self {
+        self.scope = ShortcutScope::Global;
+        self
+    }
+
+    /// Builder: active while focus is inside the element with `id`.
+    pub fn focus_within(mut self, id: impl Into<String>) -> Self {
+        self.scope = ShortcutScope::Focus(id.into());
+        self
+    }
+
+    /// Builder: behaviour inside text fields.
+    pub fn inputs(mut self, inputs: InputPolicy) -> Self {
+        self.inputs = inputs;
+        self
+    }
+}
+
+/// Scope with the layer resolved at registration.
+#[derive(Clone, PartialEq, Debug)]
+enum BoundScope {
+    Global,
+    /// `None` is the page beneath all overlays.
+    Layer(Option<u64>),
+    Focus(String),
+}
+
+/// The matching-relevant part of a binding.
+#[derive(Clone, PartialEq, Debug)]
+struct Rule {
+    chord: Chord,
+    scope: BoundScope,
+    inputs: InputPolicy,
+}
+
+impl Rule {
+    /// Precedence when eligible for `press`: focus scopes first (the
+    /// closest container highest), then layer, then global.
+    fn priority(&self, press: &KeyPress, platform: Platform) -> Option<(u8, usize)> {
+        if !self.chord.matches(press, platform) {
+            return None;
+        }
+        if press.editable {
+            let allowed = match self.inputs {
+                InputPolicy::Modified => press.fires_in_editable(),
+                InputPolicy::Always => true,
+                InputPolicy::Never => false,
+            };
+            if !allowed {
+                return None;
+            }
+        }
+        match &self.scope {
+            BoundScope::Global => Some((0, 0)),
+            BoundScope::Layer(layer) => (*layer == press.top_layer).then_some((1, 0)),
+            BoundScope::Focus(id) => {
+                let depth = press.focus_path.iter().position(|p| p == id)?;
+                Some((2, usize::MAX - depth))
+            }
+        }
+    }
+}
+
+/// Index of the rule that handles `press`; the latest wins a tie.
+fn select(rules: &[&Rule], press: &KeyPress, platform: Platform) -> Option<usize> {
+    rules
+        .iter()
+        .enumerate()
+        .filter_map(|(i, rule)| rule.priority(press, platform).map(|p| (p, i)))
+        .max()
+        .map(|(_, i)| i)
+}
+
+// ── Manager ───────────────────────────────────────────────────────
+
+/// Reports the platform, then forwards key presses that some binding in
+/// the set Rust last sent is eligible for, preventing their default
+/// action. Key names and eligibility mirror [`KeyPress::key_name`] and
+/// the Rust-side rule matching.
+const LISTEN_JS: &str = r#"
+let bindings = [];
+const keyName = (e) => {
+    const k = e.key.toLowerCase();
+    if (/^[a-z]$/.test(k)) return k;
+    if (/^Key[A-Z]$/.test(e.code)) return e.code.slice(3).toLowerCase();
+    if (/^Digit[0-9]$/.test(e.code)) return e.code.slice(5);
+    return k === ' ' ? 'space' : k;
+};
+const eligible = (b, combo, modified, ctx) => {
+    if (b.combo !== combo) return false;
+    if (ctx.editable && (b.inputs === 'never' || (b.inputs === 'modified' && !modified))) return false;
+    if (b.focus !== null) return ctx.focus_path.includes(b.focus);
+    return b.global || b.layer === ctx.top_layer;
+};
+const onKey = (e) => {
+    if (e.isComposing) return;
+    const name = keyName(e);
+    const symbol = name.length === 1 && !/[a-z0-9]/.test(name);
+    const combo = (e.ctrlKey ? 'ctrl+' : '') + (e.altKey ? 'alt+' : '') +
+        (e.shiftKey && !symbol ? 'shift+' : '') + (e.metaKey ? 'meta+' : '') + name;
+    if (!bindings.some((b) => b.combo === combo)) return;
+    const t = e.target;
+    const editable = !!t && (t.isContentEditable || /^(INPUT|TEXTAREA|SELECT)$/.test(t.tagName));
+    const focus_path = [];
+    for (let n = t; n && n.nodeType === 1; n = n.parentElement) {
+        if (n.id) focus_path.push(n.id);
+    }
+    const layers = document.querySelectorAll('[data-eq-layer="active"]');
+    const top = layers.length ? Number(layers[layers.length - 1].dataset.eqLayerId) : null;
+    const ctx = { editable, focus_path, top_layer: top };
+    const modified = e.ctrlKey || e.metaKey || e.altKey;
+    if (!bindings.some((b) => eligible(b, combo, modified, ctx))) return;
+    e.preventDefault();
+    dioxus.send({ key: e.key, code: e.code, ctrl: e.ctrlKey, alt: e.altKey,
+        shift: e.shiftKey, meta: e.metaKey, ...ctx });
+};
+document.addEventListener('keydown', onKey);
+dioxus.send(/Mac|iPhone|iPad|iPod/.test(navigator.platform || navigator.userAgent));
+while (true) {
+    const next = await dioxus.recv();
+    if (next === null) break;
+    bindings = next;
+}
+document.removeEventListener('keydown', onKey);
+"#;
+
+/// A binding as the listener script sees it.
+#[derive(Serialize)]
+struct ListenerBinding {
+    combo: String,
+    inputs: &'static str,
+    global: bool,
+    layer: Option<u64>,
+    focus: Option<String>,
+}
+
+#[derive(Clone)]
+struct Binding {
+    id: u64,
+    rule: Rule,
+    description: String,
+    handler: Callback<()>,
+}
+
+/// An active binding, for help overlays.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ShortcutInfo {
+    pub id: u64,
+    pub chord: Chord,
+    pub description: String,
+    pub scope: ShortcutScope,
+}
+
+/// App-wide shortcut registry. Get it with [`use_shortcuts`].
+#[derive(Clone, Copy)]
+pub struct ShortcutManager {
+    bindings: Signal<Vec<Binding>>,
+    platform: Signal<Platform>,
+    listener: Signal<Option<document::Eval>>,
+}
+
+impl ShortcutManager {
+    fn new_in_root() -> Self {
+        Self {
+            bindings: Signal::new_in_scope(Vec::new(), ScopeId::ROOT),
+            platform: Signal::new_in_scope(Platform::default(), ScopeId::ROOT),
+            listener: Signal::new_in_scope(None, ScopeId::ROOT),
+        }
+    }
+
+    /// Bind `chord` globally and return an id for
+    /// [`ShortcutManager::unregister`]. When several bindings share a
+    /// chord and scope, the latest one wins.
+    pub fn register(&self, chord: Chord, description: impl Into<String>, handler: Callback<()>) -> u64 {
+        self.register_with(chord, ShortcutOptions::new(description).global(), handler)
+    }
+
+    /// Bind `chord` with explicit options. [`ShortcutScope::Layer`]
+    /// binds to the page beneath all overlays; use [`use_shortcut_with`]
+    /// to bind to the overlay a component renders in.
+    pub fn register_with(&self, chord: Chord, options: ShortcutOptions, handler: Callback<()>) -> u64 {
+        self.register_in(chord, options, None, handler)
+    }
+
+    fn register_in(&self, chord: Chord, options: ShortcutOptions, layer: Option<u64>, handler: Callback<()>) -> u64 {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let scope = match options.scope {
+            ShortcutScope::Global => BoundScope::Global,
+            ShortcutScope::Layer => BoundScope::Layer(layer),
+            ShortcutScope::Focus(id) => BoundScope::Focus(id),
+        };
+        let rule = Rule { chord, scope, inputs: options.inputs };
+        let mut bindings = self.bindings;
+        bindings.write().push(Binding { id, rule, description: options.description, handler });
+        self.listen();
+        self.sync();
+        id
+    }
+
+    /// Remove a binding.
+    pub fn unregister(&self, id: u64) {
+        let mut bindings = self.bindings;
+        // The root may already be gone when the whole tree unmounts.
+        if let Ok(mut bindings) = bindings.try_write() {
+            bindings.retain(|b| b.id != id);
+        }
+        self.sync();
+    }
+
+    /// Platform detected by the listener (`Other` until it reports).
+    pub fn platform(&self) -> Platform {
+        *self.platform.read()
+    }
+
+    /// All registered bindings, oldest first. Reading this subscribes
+    /// the caller, so a help overlay stays current as components mount
+    /// and unmount.
+    pub fn bindings(&self) -> Vec<ShortcutInfo> {
+        self.bindings
+            .read()
+            .iter()
+            .map(|b| ShortcutInfo {
+                id: b.id,
+                chord: b.rule.chord.clone(),
+                description: b.description.clone(),
+                scope: match &b.rule.scope {
+                    BoundScope::Global => ShortcutScope::Global,
+                    BoundScope::Layer(_) => ShortcutScope::Layer,
+                    BoundScope::Focus(id) => ShortcutScope::Focus(id.clone()),
+                },
+            })
+            .collect()
+    }
+
+    /// Run the binding for `press`, if any. Returns whether one ran.
+    pub fn dispatch(&self, press: &KeyPress) -> bool {
+        let platform = *self.platform.peek();
+        let handler = {
+            let bindings = self.bindings.peek();
+            let rules: Vec<&Rule> = bindings.iter().map(|b| &b.rule).collect();
+            select(&rules, press, platform).map(|i| bindings[i].handler)
+        };
+        handler.map(|h| h.call(())).is_some()
+    }
+
+    fn listen(&self) {
+        if self.listener.peek().is_some() {
+            return;
+        }
+        let this = *self;
+        let eval = Runtime::current().in_scope(ScopeId::ROOT, || {
+            let mut eval = document::eval(LISTEN_JS);
+            spawn(async move {
+                let Ok(mac) = eval.recv::<bool>().await else { return };
+                let mut platform = this.platform;
+                platform.set(if mac { Platform::Mac } else { Platform::Other });
+                this.sync();
+                while let Ok(press) = eval.recv::<KeyPress>().await {
+                    this.dispatch(&press);
+                }
+            });
+            eval
+        });
+        let mut listener = self.listener;
+        listener.set(Some(eval));
+    }
+
+    /// Send the current bindings to the listener.
+    fn sync(&self) {
+        let platform = *self.platform.peek();
+        let Ok(bindings) = self.bindings.try_peek() else { return };
+        let sent: Vec<ListenerBinding> = bindings
+            .iter()
+            .map(|b| {
+                let (global, layer, focus) = match &b.rule.scope {
+                    BoundScope::Global => (true, None, None),
+                    BoundScope::Layer(layer) => (false, *layer, None),
+                    BoundScope::Focus(id) => (false, None, Some(id.clone())),
+                };
+                ListenerBinding { combo: b.rule.chord.combo(platform), inputs: b.rule.inputs.as_str(), global, layer, focus }
+            })
+            .collect();
+        if let Ok(listener) = self.listener.try_peek()
+            && let Some(eval) = *listener
+        {
+            let _ = eval.send(sent);
+        }
+    }
+}
+
+/// The app's [`ShortcutManager`], created in the root scope on first use.
+pub fn use_shortcuts() -> ShortcutManager {
+    use_hook(|| {
+        try_consume_context::<ShortcutManager>()
+            .unwrap_or_else(|| provide_root_context(ShortcutManager::new_in_root()))
+    })
+}
+
+/// The platform shortcut labels should be written for. Starts the
+/// shared key listener if nothing has bound a shortcut yet, since that
+/// is what detects it; reads `Other` until it reports.
+pub fn use_platform() -> Platform {
+    let manager = use_shortcuts();
+    use_effect(move || manager.listen());
+    manager.platform()
+}
+
+/// Binds `spec` (see [`Chord::parse`]) while the calling component is
+/// mounted, scoped to the overlay layer it renders in. An invalid spec
+/// binds nothing.
+pub fn use_shortcut(spec: &str, handler: impl FnMut(()) + 'static) {
+    use_shortcut_with(spec, ShortcutOptions::default(), handler)
+}
+
+/// [`use_shortcut`] with a description, scope and input policy. The
+/// binding is replaced when `spec` or `options` change; `handler` is
+/// always the one from the latest render.
+pub fn use_shortcut_with(spec: &str, options: ShortcutOptions, handler: impl FnMut(()) + 'static) {
+    let manager = use_shortcuts();
+    let handler = use_callback(handler);
+    let layer = use_hook(current_layer);
+    // The binding id is replaced by the effect and must still be
+    // readable in `use_drop`, which runs after this scope's signals
+    // have been dropped.
+    let bound = use_hook(|| Rc::new(Cell::new(None::<u64>)));
+
+    let effect_bound = bound.clone();
+    let spec = spec.to_string();
+    use_effect(use_reactive((&spec, &options), move |(spec, options)| {
+        if let Some(id) = effect_bound.take() {
+            manager.unregister(id);
+        }
+        if let Some(chord) = Chord::parse(&spec) {
+            effect_bound.set(Some(manager.register_in(chord, options, layer, handler)));
+        }
+    }));
+
+    use_drop(move || {
+        if let Some(id) = bound.take() {
+            manager.unregister(id);
+        }
+    });
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(key: &str, code: &str) -> KeyPress {
+        KeyPress { key: key.into(), code: code.into(), ..Default::default() }
+    }
+
+    #[test]
+    fn parses_modifiers_and_aliases() {
+        let chord = Chord::parse("Mod+Shift+P").unwrap();
+        assert_eq!(chord.combo(Platform::Other), "ctrl+shift+p");
+        assert_eq!(chord.combo(Platform::Mac), "shift+meta+p");
+        assert_eq!(Chord::parse("esc").unwrap().key(), "escape");
+        assert_eq!(Chord::parse("alt+up").unwrap().combo(Platform::Other), "alt+arrowup");
+        assert_eq!(Chord::parse("ctrl++").unwrap().combo(Platform::Other), "ctrl++");
+        assert_eq!(Chord::parse("cmd+k"), Chord::parse("meta+k"));
+        assert_eq!(Chord::parse("hyper+k"), None);
+        assert_eq!(Chord::parse("ctrl+"), None);
+        assert_eq!(Chord::parse(""), None);
+    }
+
+    #[test]
+    fn symbols_ignore_shift() {
+        assert_eq!(Chord::parse("shift+?"), Chord::parse("?"));
+        let question = KeyPress { shift: true, ..press("?", "Slash") };
+        assert!(Chord::parse("?").unwrap().matches(&question, Platform::Other));
+    }
+
+    #[test]
+    fn presses_normalize_like_chords() {
+        let upper = KeyPress { ctrl: true, shift: true, ..press("P", "KeyP") };
+        assert!(Chord::parse("mod+shift+p").unwrap().matches(&upper, Platform::Other));
+        assert!(!Chord::parse("mod+shift+p").unwrap().matches(&upper, Platform::Mac));
+
+        // Option+S types "ß" on a Mac; the code still says S.
+        let option = KeyPress { alt: true, ..press("ß", "KeyS") };
+        assert_eq!(option.combo(), "alt+s");
+        // Shift+1 types "!"; digits come from the code.
+        let bang = KeyPress { shift: true, ..press("!", "Digit1") };
+        assert_eq!(bang.combo(), "shift+1");
+        assert_eq!(press(" ", "Space").combo(), "space");
+        assert_eq!(press("Escape", "Escape").combo(), "escape");
+    }
+
+    #[test]
+    fn editable_targets_need_a_command_modifier() {
+        let plain = KeyPress { editable: true, ..press("k", "KeyK") };
+        assert!(!plain.fires_in_editable());
+        let with_mod = KeyPress { meta: true, ..plain };
+        assert!(with_mod.fires_in_editable());
+    }
+
+    fn rule(spec: &str, scope: BoundScope) -> Rule {
+        Rule { chord: Chord::parse(spec).unwrap(), scope, inputs: InputPolicy::default() }
+    }
+
+    #[test]
+    fn layer_bindings_follow_the_top_overlay() {
+        let page = rule("mod+s", BoundScope::Layer(None));
+        let dialog = rule("mod+s", BoundScope::Layer(Some(7)));
+        let rules = [&page, &dialog];
+        let save = KeyPress { ctrl: true, ..press("s", "KeyS") };
+        assert_eq!(select(&rules, &save, Platform::Other), Some(0));
+        let in_dialog = KeyPress { top_layer: Some(7), ..save.clone() };
+        assert_eq!(select(&rules, &in_dialog, Platform::Other), Some(1));
+        // Another overlay on top: neither fires.
+        let other = KeyPress { top_layer: Some(9), ..save };
+        assert_eq!(select(&rules, &other, Platform::Other), None);
+    }
+
+    #[test]
+    fn focus_scopes_win_innermost_first() {
+        let global = rule("arrowdown", BoundScope::Global);
+        let outer = rule("arrowdown", BoundScope::Focus("list".into()));
+        let inner = rule("arrowdown", BoundScope::Focus("row".into()));
+        let rules = [&outer, &inner, &global];
+        let down = |path: &[&str]| KeyPress {
+            focus_path: path.iter().map(|p| p.to_string()).collect(),
+            ..press("ArrowDown", "ArrowDown")
+        };
+        assert_eq!(select(&rules, &down(&["row", "list"]), Platform::Other), Some(1));
+        assert_eq!(select(&rules, &down(&["list"]), Platform::Other), Some(0));
+        assert_eq!(select(&rules, &down(&["elsewhere"]), Platform::Other), Some(2));
+    }
+
+    #[test]
+    fn latest_binding_wins_a_tie() {
+        let first = rule("mod+k", BoundScope::Global);
+        let second = rule("mod+k", BoundScope::Global);
+        let press = KeyPress { ctrl: true, ..press("k", "KeyK") };
+        assert_eq!(select(&[&first, &second], &press, Platform::Other), Some(1));
+    }
+
+    #[test]
+    fn input_policy_controls_text_fields() {
+        let plain = KeyPress { editable: true, ..press("/", "Slash") };
+        let mut slash = rule("/", BoundScope::Global);
+        assert_eq!(select(&[&slash], &plain, Platform::Other), None);
+        slash.inputs = InputPolicy::Always;
+        assert_eq!(select(&[&slash], &plain, Platform::Other), Some(0));
+
+        let save = KeyPress { editable: true, ctrl: true, ..press("s", "KeyS") };
+        let mut rule = rule("mod+s", BoundScope::Global);
+        assert_eq!(select(&[&rule], &save, Platform::Other), Some(0));
+        rule.inputs = InputPolicy::Never;
+        assert_eq!(select(&[&rule], &save, Platform::Other), None);
+    }
+
+    #[test]
+    fn labels_follow_platform() {
+        let chord = Chord::parse("mod+shift+p").unwrap();
+        assert_eq!(chord.label(Platform::Mac), "⇧⌘P");
+        assert_eq!(chord.label(Platform::Other), "Ctrl+Shift+P");
+        assert_eq!(Chord::parse("f2").unwrap().label(Platform::Other), "F2");
+        assert_eq!(Chord::parse("alt+down").unwrap().label(Platform::Other), "Alt+↓");
+        assert_eq!(Chord::parse("enter").unwrap().label(Platform::Mac), "Enter");
+    }
+
+    #[test]
+    fn smoke_registers_and_dispatches() {
+        let mut dom = VirtualDom::new(|| {
+            let shortcuts = use_shortcuts();
+            let mut hits = use_signal(|| 0);
+            use_hook(|| {
+                let chord = Chord::parse("mod+k").unwrap();
+                shortcuts.register(chord, "Open", Callback::new(move |_| hits += 1));
+                let ctrl_k = KeyPress { ctrl: true, ..press("k", "KeyK") };
+                assert!(shortcuts.dispatch(&ctrl_k));
+                assert!(!shortcuts.dispatch(&KeyPress { editable: true, ..press("k", "KeyK") }));
+                assert_eq!(shortcuts.bindings().len(), 1);
+            });
+            rsx! { "{hits}" }
+        });
+        dom.rebuild_in_place();
+    }
+
+    #[test]
+    fn smoke_use_shortcut() {
+        let mut dom = VirtualDom::new(|| {
+            let mut saved = use_signal(|| 0);
+            use_shortcut("mod+s", move |_| saved += 1);
+            use_shortcut_with("bogus+x", ShortcutOptions::new("Ignored").focus_within("editor"), |_| {});
+            rsx! { "{saved}" }
+        });
+        dom.rebuild_in_place();
+    }
+}
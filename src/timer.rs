@@ -0,0 +1,218 @@
+//! Renderer-independent async sleep.
+//!
+//! Components that need timers (toast auto-dismiss and the like) await
+//! [`sleep`] inside a Dioxus task instead of round-tripping a
+//! `setTimeout` through `document::eval`, so they also run on
+//! renderers without a JS engine. On wasm it wraps `setTimeout` via
+//! `gloo-timers`; elsewhere one shared timer thread wakes every pending
+//! sleep at its deadline.
+
+/// Milliseconds on a monotonic-enough clock, for measuring how much of
+/// a [`sleep`] actually passed before it was cancelled.
+#[cfg(target_arch = "wasm32")]
+pub fn now_ms() -> u64 {
+    js_sys::Date::now() as u64
+}
+
+/// Milliseconds on a monotonic-enough clock, for measuring how much of
+/// a [`sleep`] actually passed before it was cancelled.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn now_ms() -> u64 {
+    use std::sync::OnceLock;
+    use std::time::Instant;
+
+    static START: OnceLock<Instant> = OnceLock::new();
+    START.get_or_init(Instant::now).elapsed().as_millis() as u64
+}
+
+/// Resolves after `ms` milliseconds.
+#[cfg(target_arch = "wasm32")]
+pub async fn sleep(ms: u64) {
+    gloo_timers::future::TimeoutFuture::new(ms.min(u32::MAX as u64) as u32).await;
+}
+
+/// Resolves after `ms` milliseconds.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn sleep(ms: u64) {
+    use std::task::Poll;
+    use std::time::{Duration, Instant};
+
+    let state = native::schedule(Instant::now() + Duration::from_millis(ms));
+    std::future::poll_fn(|cx| {
+        let mut state = native::lock(&state);
+        if state.done {
+            Poll::Ready(())
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+/// One background thread serves every pending [`sleep`]: it holds the
+/// deadlines in a heap and waits on a condvar until the earliest one.
+/// A sleep whose future was dropped still fires, with nobody to wake.
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use std::cmp::{Ordering, Reverse};
+    use std::collections::BinaryHeap;
+    use std::sync::{Arc, Condvar, Mutex, MutexGuard, OnceLock};
+    use std::task::Waker;
+    use std::time::Instant;
+
+    #[derive(Default)]
+    pub(super) struct State {
+        pub done: bool,
+        pub waker: Option<Waker>,
+    }
+
+    struct Entry {
+        deadline: Instant,
+        seq: u64,
+        state: Arc<Mutex<State>>,
+    }
+
+    impl PartialEq for Entry {
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other) == Ordering::Equal
+        }
+    }
+
+    impl Eq for Entry {}
+
+    impl PartialOrd for Entry {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Entry {
+        fn cmp(&self, other: &Self) -> Ordering {
+            (self.deadline, self.seq).cmp(&(other.deadline, other.seq))
+        }
+    }
+
+    #[derive(Default)]
+    struct Queue {
+        heap: BinaryHeap<Reverse<Entry>>,
+        seq: u64,
+    }
+
+    struct Timer {
+        queue: Mutex<Queue>,
+        changed: Condvar,
+    }
+
+    pub(super) fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+        mutex.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn timer() -> &'static Timer {
+        static TIMER: OnceLock<Timer> = OnceLock::new();
+        TIMER.get_or_init(|| {
+            std::thread::Builder::new()
+                .name("eq_ui-timer".into())
+                .spawn(run)
+                .expect("failed to spawn the timer thread");
+            Timer { queue: Mutex::new(Queue::default()), changed: Condvar::new() }
+        })
+    }
+
+    /// Register a deadline and return the state the timer thread will
+    /// mark done once it passes.
+    pub(super) fn schedule(deadline: Instant) -> Arc<Mutex<State>> {
+        let state = Arc::new(Mutex::new(State::default()));
+        let timer = timer();
+        let mut queue = lock(&timer.queue);
+        queue.seq += 1;
+        let seq = queue.seq;
+        queue.heap.push(Reverse(Entry { deadline, seq, state: state.clone() }));
+        drop(queue);
+        timer.changed.notify_one();
+        state
+    }
+
+    fn run() {
+        let timer = timer();
+        let mut queue = lock(&timer.queue);
+        loop {
+            let now = Instant::now();
+            let mut due = Vec::new();
+            while queue.heap.peek().is_some_and(|Reverse(e)| e.deadline <= now) {
+                if let Some(Reverse(entry)) = queue.heap.pop() {
+                    due.push(entry.state);
+                }
+            }
+            if !due.is_empty() {
+                // Wake outside the queue lock: a woken task may sleep again.
+                drop(queue);
+                for state in due {
+                    let mut state = lock(&state);
+                    state.done = true;
+                    if let Some(waker) = state.waker.take() {
+                        waker.wake();
+                    }
+                }
+                queue = lock(&timer.queue);
+                continue;
+            }
+            queue = match queue.heap.peek().map(|Reverse(e)| e.deadline - now) {
+                Some(wait) => timer.changed.wait_timeout(queue, wait).unwrap_or_else(|e| e.into_inner()).0,
+                None => timer.changed.wait(queue).unwrap_or_else(|e| e.into_inner()),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::future::Future;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::task::{Context, Poll, Wake, Waker};
+
+    struct Flag(AtomicBool);
+
+    impl Wake for Flag {
+        fn wake(self: Arc<Self>) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn sleep_wakes_after_deadline() {
+        let flag = Arc::new(Flag(AtomicBool::new(false)));
+        let waker = Waker::from(flag.clone());
+        let mut cx = Context::from_waker(&waker);
+        let mut fut = Box::pin(sleep(10));
+
+        assert!(fut.as_mut().poll(&mut cx).is_pending());
+        let start = std::time::Instant::now();
+        while !flag.0.load(Ordering::SeqCst) {
+            assert!(start.elapsed().as_secs() < 5, "timer never fired");
+            std::thread::yield_now();
+        }
+        assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready(()));
+    }
+
+    #[test]
+    fn shorter_sleep_fires_before_an_earlier_longer_one() {
+        let long_flag = Arc::new(Flag(AtomicBool::new(false)));
+        let short_flag = Arc::new(Flag(AtomicBool::new(false)));
+        let long_waker = Waker::from(long_flag.clone());
+        let short_waker = Waker::from(short_flag.clone());
+        let mut long = Box::pin(sleep(60_000));
+        let mut short = Box::pin(sleep(10));
+
+        assert!(long.as_mut().poll(&mut Context::from_waker(&long_waker)).is_pending());
+        assert!(short.as_mut().poll(&mut Context::from_waker(&short_waker)).is_pending());
+        let start = std::time::Instant::now();
+        while !short_flag.0.load(Ordering::SeqCst) {
+            assert!(start.elapsed().as_secs() < 5, "timer never fired");
+            std::thread::yield_now();
+        }
+        assert!(!long_flag.0.load(Ordering::SeqCst));
+    }
+}